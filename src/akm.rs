@@ -0,0 +1,124 @@
+// SPDX-License-Identifier: MIT
+
+const WLAN_AKM_SUITE_8021X: u32 = 0x000FAC01;
+const WLAN_AKM_SUITE_PSK: u32 = 0x000FAC02;
+const WLAN_AKM_SUITE_FT_8021X: u32 = 0x000FAC03;
+const WLAN_AKM_SUITE_FT_PSK: u32 = 0x000FAC04;
+const WLAN_AKM_SUITE_8021X_SHA256: u32 = 0x000FAC05;
+const WLAN_AKM_SUITE_PSK_SHA256: u32 = 0x000FAC06;
+const WLAN_AKM_SUITE_TDLS: u32 = 0x000FAC07;
+const WLAN_AKM_SUITE_SAE: u32 = 0x000FAC08;
+const WLAN_AKM_SUITE_FT_OVER_SAE: u32 = 0x000FAC09;
+const WLAN_AKM_SUITE_AP_PEER_KEY: u32 = 0x000FAC0A;
+const WLAN_AKM_SUITE_8021X_SUITE_B: u32 = 0x000FAC0B;
+const WLAN_AKM_SUITE_8021X_SUITE_B_192: u32 = 0x000FAC0C;
+const WLAN_AKM_SUITE_FT_8021X_SHA384: u32 = 0x000FAC0D;
+const WLAN_AKM_SUITE_FILS_SHA256: u32 = 0x000FAC0E;
+const WLAN_AKM_SUITE_FILS_SHA384: u32 = 0x000FAC0F;
+const WLAN_AKM_SUITE_FT_FILS_SHA256: u32 = 0x000FAC10;
+const WLAN_AKM_SUITE_FT_FILS_SHA384: u32 = 0x000FAC11;
+const WLAN_AKM_SUITE_OWE: u32 = 0x000FAC12;
+const WLAN_AKM_SUITE_FT_PSK_SHA384: u32 = 0x000FAC13;
+const WLAN_AKM_SUITE_PSK_SHA384: u32 = 0x000FAC14;
+const WLAN_AKM_SUITE_SAE_EXT_KEY: u32 = 0x000FAC18;
+const WLAN_AKM_SUITE_FT_SAE_EXT_KEY: u32 = 0x000FAC19;
+
+/// Authentication and key management suite selector, the OUI is stored in
+/// the upper 24 bits and the suite type in the lower 8 bits.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Nl80211AkmSuite {
+    /// IEEE 802.1X
+    Ieee8021x,
+    /// Pre-shared key
+    Psk,
+    /// Fast BSS transition with IEEE 802.1X
+    FtIeee8021x,
+    /// Fast BSS transition with pre-shared key
+    FtPsk,
+    Ieee8021xSha256,
+    PskSha256,
+    Tdls,
+    /// Simultaneous authentication of equals
+    Sae,
+    /// Fast BSS transition with SAE
+    FtSae,
+    ApPeerKey,
+    Ieee8021xSuiteB,
+    Ieee8021xSuiteB192,
+    FtIeee8021xSha384,
+    FilsSha256,
+    FilsSha384,
+    FtFilsSha256,
+    FtFilsSha384,
+    /// Opportunistic wireless encryption
+    Owe,
+    FtPskSha384,
+    PskSha384,
+    SaeExtKey,
+    FtSaeExtKey,
+    Other(u32),
+}
+
+impl From<u32> for Nl80211AkmSuite {
+    fn from(d: u32) -> Self {
+        match d {
+            WLAN_AKM_SUITE_8021X => Self::Ieee8021x,
+            WLAN_AKM_SUITE_PSK => Self::Psk,
+            WLAN_AKM_SUITE_FT_8021X => Self::FtIeee8021x,
+            WLAN_AKM_SUITE_FT_PSK => Self::FtPsk,
+            WLAN_AKM_SUITE_8021X_SHA256 => Self::Ieee8021xSha256,
+            WLAN_AKM_SUITE_PSK_SHA256 => Self::PskSha256,
+            WLAN_AKM_SUITE_TDLS => Self::Tdls,
+            WLAN_AKM_SUITE_SAE => Self::Sae,
+            WLAN_AKM_SUITE_FT_OVER_SAE => Self::FtSae,
+            WLAN_AKM_SUITE_AP_PEER_KEY => Self::ApPeerKey,
+            WLAN_AKM_SUITE_8021X_SUITE_B => Self::Ieee8021xSuiteB,
+            WLAN_AKM_SUITE_8021X_SUITE_B_192 => Self::Ieee8021xSuiteB192,
+            WLAN_AKM_SUITE_FT_8021X_SHA384 => Self::FtIeee8021xSha384,
+            WLAN_AKM_SUITE_FILS_SHA256 => Self::FilsSha256,
+            WLAN_AKM_SUITE_FILS_SHA384 => Self::FilsSha384,
+            WLAN_AKM_SUITE_FT_FILS_SHA256 => Self::FtFilsSha256,
+            WLAN_AKM_SUITE_FT_FILS_SHA384 => Self::FtFilsSha384,
+            WLAN_AKM_SUITE_OWE => Self::Owe,
+            WLAN_AKM_SUITE_FT_PSK_SHA384 => Self::FtPskSha384,
+            WLAN_AKM_SUITE_PSK_SHA384 => Self::PskSha384,
+            WLAN_AKM_SUITE_SAE_EXT_KEY => Self::SaeExtKey,
+            WLAN_AKM_SUITE_FT_SAE_EXT_KEY => Self::FtSaeExtKey,
+            _ => Self::Other(d),
+        }
+    }
+}
+
+impl From<Nl80211AkmSuite> for u32 {
+    fn from(v: Nl80211AkmSuite) -> u32 {
+        match v {
+            Nl80211AkmSuite::Ieee8021x => WLAN_AKM_SUITE_8021X,
+            Nl80211AkmSuite::Psk => WLAN_AKM_SUITE_PSK,
+            Nl80211AkmSuite::FtIeee8021x => WLAN_AKM_SUITE_FT_8021X,
+            Nl80211AkmSuite::FtPsk => WLAN_AKM_SUITE_FT_PSK,
+            Nl80211AkmSuite::Ieee8021xSha256 => WLAN_AKM_SUITE_8021X_SHA256,
+            Nl80211AkmSuite::PskSha256 => WLAN_AKM_SUITE_PSK_SHA256,
+            Nl80211AkmSuite::Tdls => WLAN_AKM_SUITE_TDLS,
+            Nl80211AkmSuite::Sae => WLAN_AKM_SUITE_SAE,
+            Nl80211AkmSuite::FtSae => WLAN_AKM_SUITE_FT_OVER_SAE,
+            Nl80211AkmSuite::ApPeerKey => WLAN_AKM_SUITE_AP_PEER_KEY,
+            Nl80211AkmSuite::Ieee8021xSuiteB => WLAN_AKM_SUITE_8021X_SUITE_B,
+            Nl80211AkmSuite::Ieee8021xSuiteB192 => {
+                WLAN_AKM_SUITE_8021X_SUITE_B_192
+            }
+            Nl80211AkmSuite::FtIeee8021xSha384 => {
+                WLAN_AKM_SUITE_FT_8021X_SHA384
+            }
+            Nl80211AkmSuite::FilsSha256 => WLAN_AKM_SUITE_FILS_SHA256,
+            Nl80211AkmSuite::FilsSha384 => WLAN_AKM_SUITE_FILS_SHA384,
+            Nl80211AkmSuite::FtFilsSha256 => WLAN_AKM_SUITE_FT_FILS_SHA256,
+            Nl80211AkmSuite::FtFilsSha384 => WLAN_AKM_SUITE_FT_FILS_SHA384,
+            Nl80211AkmSuite::Owe => WLAN_AKM_SUITE_OWE,
+            Nl80211AkmSuite::FtPskSha384 => WLAN_AKM_SUITE_FT_PSK_SHA384,
+            Nl80211AkmSuite::PskSha384 => WLAN_AKM_SUITE_PSK_SHA384,
+            Nl80211AkmSuite::SaeExtKey => WLAN_AKM_SUITE_SAE_EXT_KEY,
+            Nl80211AkmSuite::FtSaeExtKey => WLAN_AKM_SUITE_FT_SAE_EXT_KEY,
+            Nl80211AkmSuite::Other(d) => d,
+        }
+    }
+}
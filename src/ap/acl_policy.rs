@@ -0,0 +1,38 @@
+// SPDX-License-Identifier: MIT
+
+const NL80211_ACL_POLICY_ACCEPT_UNLESS_LISTED: u32 = 0;
+const NL80211_ACL_POLICY_DENY_UNLESS_LISTED: u32 = 1;
+
+/// Policy of the MAC address access control list of an access point
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Nl80211AclPolicy {
+    /// Deny the listed stations and accept all others
+    AcceptUnlessListed,
+    /// Accept the listed stations and deny all others
+    DenyUnlessListed,
+    Other(u32),
+}
+
+impl From<u32> for Nl80211AclPolicy {
+    fn from(d: u32) -> Self {
+        match d {
+            NL80211_ACL_POLICY_ACCEPT_UNLESS_LISTED => Self::AcceptUnlessListed,
+            NL80211_ACL_POLICY_DENY_UNLESS_LISTED => Self::DenyUnlessListed,
+            _ => Self::Other(d),
+        }
+    }
+}
+
+impl From<Nl80211AclPolicy> for u32 {
+    fn from(v: Nl80211AclPolicy) -> u32 {
+        match v {
+            Nl80211AclPolicy::AcceptUnlessListed => {
+                NL80211_ACL_POLICY_ACCEPT_UNLESS_LISTED
+            }
+            Nl80211AclPolicy::DenyUnlessListed => {
+                NL80211_ACL_POLICY_DENY_UNLESS_LISTED
+            }
+            Nl80211AclPolicy::Other(d) => d,
+        }
+    }
+}
@@ -0,0 +1,235 @@
+// SPDX-License-Identifier: MIT
+
+use byteorder::{ByteOrder, LittleEndian};
+use netlink_packet_utils::Emitable;
+
+use crate::{Nl80211Attr, Nl80211Element};
+
+const ETH_ALEN: usize = 6;
+
+// Frame control of management frame with Beacon subtype
+const BEACON_FRAME_CONTROL: u16 = 0x0080;
+// Frame control, duration, 3 addresses and sequence control
+const MGMT_HEADER_LEN: usize = 24;
+// Timestamp, beacon interval and capability information
+const BEACON_FIXED_LEN: usize = 12;
+
+const CAPABILITY_ESS: u16 = 1 << 0;
+const CAPABILITY_PRIVACY: u16 = 1 << 4;
+const CAPABILITY_SHORT_PREAMBLE: u16 = 1 << 5;
+const CAPABILITY_SHORT_SLOT_TIME: u16 = 1 << 10;
+
+fn elements_len(elements: &[Nl80211Element]) -> usize {
+    elements.iter().map(|e| e.buffer_len()).sum()
+}
+
+fn emit_elements(elements: &[Nl80211Element], buffer: &mut [u8]) {
+    let mut offset = 0;
+    for element in elements {
+        element.emit(&mut buffer[offset..]);
+        offset += element.buffer_len();
+    }
+}
+
+fn elements_to_vec(elements: &[Nl80211Element]) -> Vec<u8> {
+    let mut buffer = vec![0u8; elements_len(elements)];
+    emit_elements(elements, &mut buffer);
+    buffer
+}
+
+/// Beacon frame content up to the TIM element: the management frame header,
+/// the fixed fields and the elements preceding the TIM element, for example
+/// SSID, Supported Rates and DS Parameter Set.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Nl80211BeaconHead {
+    pub bssid: [u8; ETH_ALEN],
+    /// Beacon interval in TUs
+    pub beacon_interval: u16,
+    /// Raw Capability Information field
+    pub capability: u16,
+    pub elements: Vec<Nl80211Element>,
+}
+
+impl Nl80211BeaconHead {
+    /// Beacon of an infrastructure BSS without privacy
+    pub fn new(bssid: [u8; ETH_ALEN], beacon_interval: u16) -> Self {
+        Self {
+            bssid,
+            beacon_interval,
+            capability: CAPABILITY_ESS,
+            elements: Vec::new(),
+        }
+    }
+
+    /// Whether encryption is required for data frames of the BSS
+    pub fn privacy(mut self, enable: bool) -> Self {
+        self.set_capability(CAPABILITY_PRIVACY, enable);
+        self
+    }
+
+    pub fn short_preamble(mut self, enable: bool) -> Self {
+        self.set_capability(CAPABILITY_SHORT_PREAMBLE, enable);
+        self
+    }
+
+    pub fn short_slot_time(mut self, enable: bool) -> Self {
+        self.set_capability(CAPABILITY_SHORT_SLOT_TIME, enable);
+        self
+    }
+
+    /// Append an element, which should be ordered by element ID and precede
+    /// the TIM element
+    pub fn element(mut self, element: Nl80211Element) -> Self {
+        self.elements.push(element);
+        self
+    }
+
+    fn set_capability(&mut self, bit: u16, enable: bool) {
+        if enable {
+            self.capability |= bit;
+        } else {
+            self.capability &= !bit;
+        }
+    }
+}
+
+impl Emitable for Nl80211BeaconHead {
+    fn buffer_len(&self) -> usize {
+        MGMT_HEADER_LEN + BEACON_FIXED_LEN + elements_len(&self.elements)
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        LittleEndian::write_u16(&mut buffer[0..2], BEACON_FRAME_CONTROL);
+        // Duration
+        LittleEndian::write_u16(&mut buffer[2..4], 0);
+        // Destination is broadcast, source and BSSID are the BSSID
+        buffer[4..10].copy_from_slice(&[0xff; ETH_ALEN]);
+        buffer[10..16].copy_from_slice(&self.bssid);
+        buffer[16..22].copy_from_slice(&self.bssid);
+        // Sequence control is filled by driver
+        LittleEndian::write_u16(&mut buffer[22..24], 0);
+        // Timestamp is filled by driver
+        buffer[24..32].copy_from_slice(&[0u8; 8]);
+        LittleEndian::write_u16(&mut buffer[32..34], self.beacon_interval);
+        LittleEndian::write_u16(&mut buffer[34..36], self.capability);
+        emit_elements(
+            &self.elements,
+            &mut buffer[MGMT_HEADER_LEN + BEACON_FIXED_LEN..],
+        );
+    }
+}
+
+impl From<&Nl80211BeaconHead> for Vec<u8> {
+    fn from(head: &Nl80211BeaconHead) -> Self {
+        let mut buffer = vec![0u8; head.buffer_len()];
+        head.emit(&mut buffer);
+        buffer
+    }
+}
+
+/// Beacon and Probe Response templates of an access point, the TIM element
+/// between `head` and `tail` is generated by the kernel or driver.
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct Nl80211BeaconData {
+    /// Raw beacon frame content up to the TIM element
+    pub head: Option<Vec<u8>>,
+    /// Raw beacon frame content following the TIM element
+    pub tail: Option<Vec<u8>>,
+    /// Raw information elements appended to the Beacon and Probe Response
+    /// by drivers building those frames themselves
+    pub ie: Option<Vec<u8>>,
+    /// Raw information elements appended to the Probe Response by drivers
+    /// building it themselves
+    pub ie_probe_resp: Option<Vec<u8>>,
+    /// Raw information elements appended to the (Re)Association Response by
+    /// drivers building it themselves
+    pub ie_assoc_resp: Option<Vec<u8>>,
+    /// Raw Probe Response frame template for drivers offloading Probe
+    /// Response
+    pub probe_resp: Option<Vec<u8>>,
+    /// Raw Multiple BSSID elements of the transmitting interface, one entry
+    /// per beacon when enhanced multi-BSSID advertisements is enabled
+    pub mbssid_elems: Option<Vec<Vec<u8>>>,
+}
+
+impl Nl80211BeaconData {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn head(mut self, head: &Nl80211BeaconHead) -> Self {
+        self.head = Some(head.into());
+        self
+    }
+
+    /// Elements following the TIM element in the beacon, ordered by element
+    /// ID
+    pub fn tail(mut self, elements: &[Nl80211Element]) -> Self {
+        self.tail = Some(elements_to_vec(elements));
+        self
+    }
+
+    /// Elements appended to the Beacon and Probe Response by drivers
+    /// building those frames themselves
+    pub fn ie(mut self, elements: &[Nl80211Element]) -> Self {
+        self.ie = Some(elements_to_vec(elements));
+        self
+    }
+
+    /// Elements appended to the Probe Response by drivers building it
+    /// themselves
+    pub fn ie_probe_resp(mut self, elements: &[Nl80211Element]) -> Self {
+        self.ie_probe_resp = Some(elements_to_vec(elements));
+        self
+    }
+
+    /// Elements appended to the (Re)Association Response by drivers building
+    /// it themselves
+    pub fn ie_assoc_resp(mut self, elements: &[Nl80211Element]) -> Self {
+        self.ie_assoc_resp = Some(elements_to_vec(elements));
+        self
+    }
+
+    /// Raw Probe Response frame template
+    pub fn probe_resp(mut self, frame: Vec<u8>) -> Self {
+        self.probe_resp = Some(frame);
+        self
+    }
+
+    /// Multiple BSSID elements of the transmitting interface, one list of
+    /// elements per beacon when enhanced multi-BSSID advertisements is
+    /// enabled
+    pub fn mbssid_elems(mut self, elements: &[Vec<Nl80211Element>]) -> Self {
+        self.mbssid_elems =
+            Some(elements.iter().map(|e| elements_to_vec(e)).collect());
+        self
+    }
+}
+
+impl From<&Nl80211BeaconData> for Vec<Nl80211Attr> {
+    fn from(data: &Nl80211BeaconData) -> Self {
+        let mut nlas = Vec::new();
+        if let Some(v) = data.head.as_ref() {
+            nlas.push(Nl80211Attr::BeaconHead(v.clone()));
+        }
+        if let Some(v) = data.tail.as_ref() {
+            nlas.push(Nl80211Attr::BeaconTail(v.clone()));
+        }
+        if let Some(v) = data.ie.as_ref() {
+            nlas.push(Nl80211Attr::Ie(v.clone()));
+        }
+        if let Some(v) = data.ie_probe_resp.as_ref() {
+            nlas.push(Nl80211Attr::IeProbeResp(v.clone()));
+        }
+        if let Some(v) = data.ie_assoc_resp.as_ref() {
+            nlas.push(Nl80211Attr::IeAssocResp(v.clone()));
+        }
+        if let Some(v) = data.probe_resp.as_ref() {
+            nlas.push(Nl80211Attr::ProbeResp(v.clone()));
+        }
+        if let Some(v) = data.mbssid_elems.as_ref() {
+            nlas.push(Nl80211Attr::MbssidElems(v.clone()));
+        }
+        nlas
+    }
+}
@@ -0,0 +1,148 @@
+// SPDX-License-Identifier: MIT
+
+use anyhow::Context;
+use netlink_packet_utils::{
+    nla::{DefaultNla, Nla, NlaBuffer, NlasIterator},
+    parsers::parse_u8,
+    DecodeError, Emitable, Parseable,
+};
+
+const NL80211_HE_BSS_COLOR_ATTR_COLOR: u16 = 1;
+const NL80211_HE_BSS_COLOR_ATTR_DISABLED: u16 = 2;
+const NL80211_HE_BSS_COLOR_ATTR_PARTIAL: u16 = 3;
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum Nl80211HeBssColorNla {
+    /// BSS color (1..63)
+    Color(u8),
+    /// BSS coloring is disabled
+    Disabled,
+    /// Partial BSS color is used in the AID equation
+    Partial,
+    Other(DefaultNla),
+}
+
+impl Nla for Nl80211HeBssColorNla {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::Disabled | Self::Partial => 0,
+            Self::Color(_) => 1,
+            Self::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::Color(_) => NL80211_HE_BSS_COLOR_ATTR_COLOR,
+            Self::Disabled => NL80211_HE_BSS_COLOR_ATTR_DISABLED,
+            Self::Partial => NL80211_HE_BSS_COLOR_ATTR_PARTIAL,
+            Self::Other(attr) => attr.kind(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Disabled | Self::Partial => (),
+            Self::Color(d) => buffer[0] = *d,
+            Self::Other(ref attr) => attr.emit(buffer),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for Nl80211HeBssColorNla
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            NL80211_HE_BSS_COLOR_ATTR_COLOR => {
+                let err_msg = format!(
+                    "Invalid NL80211_HE_BSS_COLOR_ATTR_COLOR value {:?}",
+                    payload
+                );
+                Self::Color(parse_u8(payload).context(err_msg)?)
+            }
+            NL80211_HE_BSS_COLOR_ATTR_DISABLED => Self::Disabled,
+            NL80211_HE_BSS_COLOR_ATTR_PARTIAL => Self::Partial,
+            _ => Self::Other(
+                DefaultNla::parse(buf).context("invalid NLA (unknown kind)")?,
+            ),
+        })
+    }
+}
+
+/// BSS color of an HE access point
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct Nl80211HeBssColor {
+    /// BSS color (1..63)
+    pub color: Option<u8>,
+    /// BSS coloring is disabled
+    pub disabled: bool,
+    /// Partial BSS color is used in the AID equation
+    pub partial: bool,
+}
+
+impl Nl80211HeBssColor {
+    pub fn new(color: u8) -> Self {
+        Self {
+            color: Some(color),
+            ..Default::default()
+        }
+    }
+}
+
+impl Emitable for Nl80211HeBssColor {
+    fn buffer_len(&self) -> usize {
+        Vec::<Nl80211HeBssColorNla>::from(self)
+            .as_slice()
+            .buffer_len()
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        Vec::<Nl80211HeBssColorNla>::from(self)
+            .as_slice()
+            .emit(buffer)
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for Nl80211HeBssColor
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let mut ret = Self::default();
+        let payload = buf.value();
+        let err_msg =
+            format!("Invalid NL80211_ATTR_HE_BSS_COLOR value {:?}", payload);
+        for nla in NlasIterator::new(payload) {
+            let nla = &nla.context(err_msg.clone())?;
+            match Nl80211HeBssColorNla::parse(nla).context(err_msg.clone())? {
+                Nl80211HeBssColorNla::Color(d) => ret.color = Some(d),
+                Nl80211HeBssColorNla::Disabled => ret.disabled = true,
+                Nl80211HeBssColorNla::Partial => ret.partial = true,
+                Nl80211HeBssColorNla::Other(attr) => {
+                    log::warn!(
+                        "Got unsupported NL80211_ATTR_HE_BSS_COLOR value {:?}",
+                        attr
+                    )
+                }
+            }
+        }
+        Ok(ret)
+    }
+}
+
+impl From<&Nl80211HeBssColor> for Vec<Nl80211HeBssColorNla> {
+    fn from(conf: &Nl80211HeBssColor) -> Self {
+        let mut nlas = Vec::new();
+        if let Some(v) = conf.color {
+            nlas.push(Nl80211HeBssColorNla::Color(v));
+        }
+        if conf.disabled {
+            nlas.push(Nl80211HeBssColorNla::Disabled);
+        }
+        if conf.partial {
+            nlas.push(Nl80211HeBssColorNla::Partial);
+        }
+        nlas
+    }
+}
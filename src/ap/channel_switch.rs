@@ -0,0 +1,118 @@
+// SPDX-License-Identifier: MIT
+
+use crate::{
+    nl80211_execute_ack, Nl80211Attr, Nl80211BeaconData, Nl80211ChannelDef,
+    Nl80211Cmd, Nl80211Error, Nl80211Handle, Nl80211Message,
+};
+
+pub struct Nl80211ChannelSwitchRequest {
+    handle: Nl80211Handle,
+    if_index: u32,
+    chandef: Nl80211ChannelDef,
+    count: u32,
+    block_tx: bool,
+    beacon: Nl80211BeaconData,
+    csa_beacon: Nl80211BeaconData,
+    counter_offsets_beacon: Vec<u16>,
+    counter_offsets_probe_resp: Vec<u16>,
+}
+
+impl Nl80211ChannelSwitchRequest {
+    pub(crate) fn new(
+        handle: Nl80211Handle,
+        if_index: u32,
+        chandef: Nl80211ChannelDef,
+        count: u32,
+    ) -> Self {
+        Nl80211ChannelSwitchRequest {
+            handle,
+            if_index,
+            chandef,
+            count,
+            block_tx: false,
+            beacon: Nl80211BeaconData::default(),
+            csa_beacon: Nl80211BeaconData::default(),
+            counter_offsets_beacon: Vec::new(),
+            counter_offsets_probe_resp: Vec::new(),
+        }
+    }
+
+    /// Stop transmission until the channel switch completes
+    pub fn block_tx(mut self, enable: bool) -> Self {
+        self.block_tx = enable;
+        self
+    }
+
+    /// Beacon and Probe Response templates used once the channel switch has
+    /// completed, mandatory for access point
+    pub fn beacon(mut self, beacon: Nl80211BeaconData) -> Self {
+        self.beacon = beacon;
+        self
+    }
+
+    /// Beacon and Probe Response templates holding the Channel Switch
+    /// Announcement element, used until the switch completes, mandatory for
+    /// access point
+    pub fn csa_beacon(mut self, beacon: Nl80211BeaconData) -> Self {
+        self.csa_beacon = beacon;
+        self
+    }
+
+    /// Offsets of the channel switch countdown field within the tail of the
+    /// CSA beacon, updated by the kernel or driver on each TBTT
+    pub fn counter_offsets_beacon(mut self, offsets: Vec<u16>) -> Self {
+        self.counter_offsets_beacon = offsets;
+        self
+    }
+
+    /// Offsets of the channel switch countdown field within the CSA Probe
+    /// Response template
+    pub fn counter_offsets_probe_resp(mut self, offsets: Vec<u16>) -> Self {
+        self.counter_offsets_probe_resp = offsets;
+        self
+    }
+
+    pub async fn execute(self) -> Result<(), Nl80211Error> {
+        let Nl80211ChannelSwitchRequest {
+            mut handle,
+            if_index,
+            chandef,
+            count,
+            block_tx,
+            beacon,
+            csa_beacon,
+            counter_offsets_beacon,
+            counter_offsets_probe_resp,
+        } = self;
+
+        chandef.validate()?;
+
+        let mut csa_ies = Vec::<Nl80211Attr>::from(&csa_beacon);
+        if !counter_offsets_beacon.is_empty() {
+            csa_ies.push(Nl80211Attr::CntdwnOffsBeacon(counter_offsets_beacon));
+        }
+        if !counter_offsets_probe_resp.is_empty() {
+            csa_ies
+                .push(Nl80211Attr::CntdwnOffsPresp(counter_offsets_probe_resp));
+        }
+
+        let mut nlas = vec![
+            Nl80211Attr::IfIndex(if_index),
+            Nl80211Attr::ChSwitchCount(count),
+        ];
+        nlas.extend(Vec::<Nl80211Attr>::from(&chandef));
+        if block_tx {
+            nlas.push(Nl80211Attr::ChSwitchBlockTx);
+        }
+        nlas.extend(Vec::<Nl80211Attr>::from(&beacon));
+        if !csa_ies.is_empty() {
+            nlas.push(Nl80211Attr::CsaIes(csa_ies));
+        }
+
+        let nl80211_msg = Nl80211Message {
+            cmd: Nl80211Cmd::ChannelSwitch,
+            nlas,
+        };
+        nl80211_execute_ack(&mut handle, nl80211_msg).await
+    }
+}
@@ -0,0 +1,101 @@
+// SPDX-License-Identifier: MIT
+
+use crate::{
+    nl80211_execute_ack, Nl80211Attr, Nl80211BeaconData, Nl80211Cmd,
+    Nl80211Error, Nl80211Handle, Nl80211Message,
+};
+
+pub struct Nl80211ColorChangeRequest {
+    handle: Nl80211Handle,
+    if_index: u32,
+    color: u8,
+    count: u8,
+    beacon: Nl80211BeaconData,
+    color_change_beacon: Nl80211BeaconData,
+    counter_offsets_beacon: Vec<u16>,
+    counter_offsets_probe_resp: Vec<u16>,
+}
+
+impl Nl80211ColorChangeRequest {
+    pub(crate) fn new(
+        handle: Nl80211Handle,
+        if_index: u32,
+        color: u8,
+        count: u8,
+    ) -> Self {
+        Nl80211ColorChangeRequest {
+            handle,
+            if_index,
+            color,
+            count,
+            beacon: Nl80211BeaconData::default(),
+            color_change_beacon: Nl80211BeaconData::default(),
+            counter_offsets_beacon: Vec::new(),
+            counter_offsets_probe_resp: Vec::new(),
+        }
+    }
+
+    /// Beacon and Probe Response templates used once the color change has
+    /// completed
+    pub fn beacon(mut self, beacon: Nl80211BeaconData) -> Self {
+        self.beacon = beacon;
+        self
+    }
+
+    /// Beacon and Probe Response templates announcing the color change, used
+    /// until it completes
+    pub fn color_change_beacon(mut self, beacon: Nl80211BeaconData) -> Self {
+        self.color_change_beacon = beacon;
+        self
+    }
+
+    /// Offsets of the color change countdown field within the tail of the
+    /// color change beacon, updated by the kernel or driver on each TBTT
+    pub fn counter_offsets_beacon(mut self, offsets: Vec<u16>) -> Self {
+        self.counter_offsets_beacon = offsets;
+        self
+    }
+
+    /// Offsets of the color change countdown field within the color change
+    /// Probe Response template
+    pub fn counter_offsets_probe_resp(mut self, offsets: Vec<u16>) -> Self {
+        self.counter_offsets_probe_resp = offsets;
+        self
+    }
+
+    pub async fn execute(self) -> Result<(), Nl80211Error> {
+        let Nl80211ColorChangeRequest {
+            mut handle,
+            if_index,
+            color,
+            count,
+            beacon,
+            color_change_beacon,
+            counter_offsets_beacon,
+            counter_offsets_probe_resp,
+        } = self;
+
+        let mut elems = Vec::<Nl80211Attr>::from(&color_change_beacon);
+        if !counter_offsets_beacon.is_empty() {
+            elems.push(Nl80211Attr::CntdwnOffsBeacon(counter_offsets_beacon));
+        }
+        if !counter_offsets_probe_resp.is_empty() {
+            elems
+                .push(Nl80211Attr::CntdwnOffsPresp(counter_offsets_probe_resp));
+        }
+
+        let mut nlas = vec![
+            Nl80211Attr::IfIndex(if_index),
+            Nl80211Attr::ColorChangeColor(color),
+            Nl80211Attr::ColorChangeCount(count),
+        ];
+        nlas.extend(Vec::<Nl80211Attr>::from(&beacon));
+        nlas.push(Nl80211Attr::ColorChangeElems(elems));
+
+        let nl80211_msg = Nl80211Message {
+            cmd: Nl80211Cmd::ColorChangeRequest,
+            nlas,
+        };
+        nl80211_execute_ack(&mut handle, nl80211_msg).await
+    }
+}
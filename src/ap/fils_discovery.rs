@@ -0,0 +1,178 @@
+// SPDX-License-Identifier: MIT
+
+use anyhow::Context;
+use byteorder::{ByteOrder, NativeEndian};
+use netlink_packet_utils::{
+    nla::{DefaultNla, Nla, NlaBuffer, NlasIterator},
+    parsers::parse_u32,
+    DecodeError, Emitable, Parseable,
+};
+
+const NL80211_FILS_DISCOVERY_ATTR_INT_MIN: u16 = 1;
+const NL80211_FILS_DISCOVERY_ATTR_INT_MAX: u16 = 2;
+const NL80211_FILS_DISCOVERY_ATTR_TMPL: u16 = 3;
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum Nl80211FilsDiscoveryNla {
+    /// Minimum interval in TUs (0..10000)
+    IntervalMin(u32),
+    /// Maximum interval in TUs (0..10000), 0 to disable
+    IntervalMax(u32),
+    /// Raw FILS Discovery action frame template including the headers
+    Template(Vec<u8>),
+    Other(DefaultNla),
+}
+
+impl Nla for Nl80211FilsDiscoveryNla {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::IntervalMin(_) | Self::IntervalMax(_) => 4,
+            Self::Template(ref d) => d.len(),
+            Self::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::IntervalMin(_) => NL80211_FILS_DISCOVERY_ATTR_INT_MIN,
+            Self::IntervalMax(_) => NL80211_FILS_DISCOVERY_ATTR_INT_MAX,
+            Self::Template(_) => NL80211_FILS_DISCOVERY_ATTR_TMPL,
+            Self::Other(attr) => attr.kind(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::IntervalMin(d) | Self::IntervalMax(d) => {
+                NativeEndian::write_u32(buffer, *d)
+            }
+            Self::Template(ref d) => buffer[..d.len()].copy_from_slice(d),
+            Self::Other(ref attr) => attr.emit(buffer),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for Nl80211FilsDiscoveryNla
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            NL80211_FILS_DISCOVERY_ATTR_INT_MIN => {
+                let err_msg = format!(
+                    "Invalid NL80211_FILS_DISCOVERY_ATTR_INT_MIN \
+                     value {:?}",
+                    payload
+                );
+                Self::IntervalMin(parse_u32(payload).context(err_msg)?)
+            }
+            NL80211_FILS_DISCOVERY_ATTR_INT_MAX => {
+                let err_msg = format!(
+                    "Invalid NL80211_FILS_DISCOVERY_ATTR_INT_MAX \
+                     value {:?}",
+                    payload
+                );
+                Self::IntervalMax(parse_u32(payload).context(err_msg)?)
+            }
+            NL80211_FILS_DISCOVERY_ATTR_TMPL => {
+                Self::Template(payload.to_vec())
+            }
+            _ => Self::Other(
+                DefaultNla::parse(buf).context("invalid NLA (unknown kind)")?,
+            ),
+        })
+    }
+}
+
+/// FILS Discovery frame configuration of an access point
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct Nl80211FilsDiscovery {
+    /// Minimum interval in TUs (0..10000)
+    pub interval_min: Option<u32>,
+    /// Maximum interval in TUs (0..10000), 0 to disable
+    pub interval_max: Option<u32>,
+    /// Raw FILS Discovery action frame template including the headers
+    pub template: Option<Vec<u8>>,
+}
+
+impl Nl80211FilsDiscovery {
+    /// Send the FILS Discovery frame `template` with interval between
+    /// `interval_min` and `interval_max` TUs
+    pub fn new(
+        interval_min: u32,
+        interval_max: u32,
+        template: Vec<u8>,
+    ) -> Self {
+        Self {
+            interval_min: Some(interval_min),
+            interval_max: Some(interval_max),
+            template: Some(template),
+        }
+    }
+}
+
+impl Emitable for Nl80211FilsDiscovery {
+    fn buffer_len(&self) -> usize {
+        Vec::<Nl80211FilsDiscoveryNla>::from(self)
+            .as_slice()
+            .buffer_len()
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        Vec::<Nl80211FilsDiscoveryNla>::from(self)
+            .as_slice()
+            .emit(buffer)
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for Nl80211FilsDiscovery
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let mut ret = Self::default();
+        let payload = buf.value();
+        let err_msg = format!(
+            "Invalid NL80211_ATTR_FILS_DISCOVERY \
+             value {:?}",
+            payload
+        );
+        for nla in NlasIterator::new(payload) {
+            let nla = &nla.context(err_msg.clone())?;
+            match Nl80211FilsDiscoveryNla::parse(nla)
+                .context(err_msg.clone())?
+            {
+                Nl80211FilsDiscoveryNla::IntervalMin(d) => {
+                    ret.interval_min = Some(d)
+                }
+                Nl80211FilsDiscoveryNla::IntervalMax(d) => {
+                    ret.interval_max = Some(d)
+                }
+                Nl80211FilsDiscoveryNla::Template(d) => ret.template = Some(d),
+                Nl80211FilsDiscoveryNla::Other(attr) => {
+                    log::warn!(
+                        "Got unsupported NL80211_ATTR_FILS_DISCOVERY \
+                         value {:?}",
+                        attr
+                    )
+                }
+            }
+        }
+        Ok(ret)
+    }
+}
+
+impl From<&Nl80211FilsDiscovery> for Vec<Nl80211FilsDiscoveryNla> {
+    fn from(conf: &Nl80211FilsDiscovery) -> Self {
+        let mut nlas = Vec::new();
+        if let Some(v) = conf.interval_min {
+            nlas.push(Nl80211FilsDiscoveryNla::IntervalMin(v));
+        }
+        if let Some(v) = conf.interval_max {
+            nlas.push(Nl80211FilsDiscoveryNla::IntervalMax(v));
+        }
+        if let Some(v) = conf.template.as_ref() {
+            nlas.push(Nl80211FilsDiscoveryNla::Template(v.clone()));
+        }
+        nlas
+    }
+}
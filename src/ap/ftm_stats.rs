@@ -0,0 +1,319 @@
+// SPDX-License-Identifier: MIT
+
+use anyhow::Context;
+use byteorder::{ByteOrder, NativeEndian};
+use netlink_packet_utils::{
+    nla::{DefaultNla, Nla, NlaBuffer, NlasIterator},
+    parsers::{parse_u32, parse_u64},
+    DecodeError, Emitable, Parseable,
+};
+
+const NL80211_FTM_STATS_SUCCESS_NUM: u16 = 1;
+const NL80211_FTM_STATS_PARTIAL_NUM: u16 = 2;
+const NL80211_FTM_STATS_FAILED_NUM: u16 = 3;
+const NL80211_FTM_STATS_ASAP_NUM: u16 = 4;
+const NL80211_FTM_STATS_NON_ASAP_NUM: u16 = 5;
+const NL80211_FTM_STATS_TOTAL_DURATION_MSEC: u16 = 6;
+const NL80211_FTM_STATS_UNKNOWN_TRIGGERS_NUM: u16 = 7;
+const NL80211_FTM_STATS_RESCHEDULE_REQUESTS_NUM: u16 = 8;
+const NL80211_FTM_STATS_OUT_OF_WINDOW_TRIGGERS_NUM: u16 = 9;
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum Nl80211FtmResponderStatsNla {
+    /// Number of FTM sessions in which all frames were successfully answered
+    SuccessNum(u32),
+    /// Number of FTM sessions in which part of the frames were successfully
+    /// answered
+    PartialNum(u32),
+    /// Number of failed FTM sessions
+    FailedNum(u32),
+    /// Number of ASAP sessions
+    AsapNum(u32),
+    /// Number of non-ASAP sessions
+    NonAsapNum(u32),
+    /// Total duration of sessions in milliseconds
+    TotalDurationMsec(u64),
+    /// Number of FTM triggers from initiators which did not finish the
+    /// negotiation with the responder
+    UnknownTriggersNum(u32),
+    /// Number of FTM reschedule requests from initiators already having a
+    /// scheduled FTM slot
+    RescheduleRequestsNum(u32),
+    /// Number of FTM triggers out of the scheduled window
+    OutOfWindowTriggersNum(u32),
+    Other(DefaultNla),
+}
+
+impl Nla for Nl80211FtmResponderStatsNla {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::SuccessNum(_)
+            | Self::PartialNum(_)
+            | Self::FailedNum(_)
+            | Self::AsapNum(_)
+            | Self::NonAsapNum(_)
+            | Self::UnknownTriggersNum(_)
+            | Self::RescheduleRequestsNum(_)
+            | Self::OutOfWindowTriggersNum(_) => 4,
+            Self::TotalDurationMsec(_) => 8,
+            Self::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::SuccessNum(_) => NL80211_FTM_STATS_SUCCESS_NUM,
+            Self::PartialNum(_) => NL80211_FTM_STATS_PARTIAL_NUM,
+            Self::FailedNum(_) => NL80211_FTM_STATS_FAILED_NUM,
+            Self::AsapNum(_) => NL80211_FTM_STATS_ASAP_NUM,
+            Self::NonAsapNum(_) => NL80211_FTM_STATS_NON_ASAP_NUM,
+            Self::TotalDurationMsec(_) => NL80211_FTM_STATS_TOTAL_DURATION_MSEC,
+            Self::UnknownTriggersNum(_) => {
+                NL80211_FTM_STATS_UNKNOWN_TRIGGERS_NUM
+            }
+            Self::RescheduleRequestsNum(_) => {
+                NL80211_FTM_STATS_RESCHEDULE_REQUESTS_NUM
+            }
+            Self::OutOfWindowTriggersNum(_) => {
+                NL80211_FTM_STATS_OUT_OF_WINDOW_TRIGGERS_NUM
+            }
+            Self::Other(attr) => attr.kind(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::SuccessNum(d)
+            | Self::PartialNum(d)
+            | Self::FailedNum(d)
+            | Self::AsapNum(d)
+            | Self::NonAsapNum(d)
+            | Self::UnknownTriggersNum(d)
+            | Self::RescheduleRequestsNum(d)
+            | Self::OutOfWindowTriggersNum(d) => {
+                NativeEndian::write_u32(buffer, *d)
+            }
+            Self::TotalDurationMsec(d) => NativeEndian::write_u64(buffer, *d),
+            Self::Other(ref attr) => attr.emit(buffer),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for Nl80211FtmResponderStatsNla
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            NL80211_FTM_STATS_SUCCESS_NUM => {
+                let err_msg = format!(
+                    "Invalid NL80211_FTM_STATS_SUCCESS_NUM \
+                     value {:?}",
+                    payload
+                );
+                Self::SuccessNum(parse_u32(payload).context(err_msg)?)
+            }
+            NL80211_FTM_STATS_PARTIAL_NUM => {
+                let err_msg = format!(
+                    "Invalid NL80211_FTM_STATS_PARTIAL_NUM \
+                     value {:?}",
+                    payload
+                );
+                Self::PartialNum(parse_u32(payload).context(err_msg)?)
+            }
+            NL80211_FTM_STATS_FAILED_NUM => {
+                let err_msg = format!(
+                    "Invalid NL80211_FTM_STATS_FAILED_NUM \
+                     value {:?}",
+                    payload
+                );
+                Self::FailedNum(parse_u32(payload).context(err_msg)?)
+            }
+            NL80211_FTM_STATS_ASAP_NUM => {
+                let err_msg = format!(
+                    "Invalid NL80211_FTM_STATS_ASAP_NUM \
+                     value {:?}",
+                    payload
+                );
+                Self::AsapNum(parse_u32(payload).context(err_msg)?)
+            }
+            NL80211_FTM_STATS_NON_ASAP_NUM => {
+                let err_msg = format!(
+                    "Invalid NL80211_FTM_STATS_NON_ASAP_NUM \
+                     value {:?}",
+                    payload
+                );
+                Self::NonAsapNum(parse_u32(payload).context(err_msg)?)
+            }
+            NL80211_FTM_STATS_TOTAL_DURATION_MSEC => {
+                let err_msg = format!(
+                    "Invalid NL80211_FTM_STATS_TOTAL_DURATION_MSEC \
+                     value {:?}",
+                    payload
+                );
+                Self::TotalDurationMsec(parse_u64(payload).context(err_msg)?)
+            }
+            NL80211_FTM_STATS_UNKNOWN_TRIGGERS_NUM => {
+                let err_msg = format!(
+                    "Invalid NL80211_FTM_STATS_UNKNOWN_TRIGGERS_NUM \
+                     value {:?}",
+                    payload
+                );
+                Self::UnknownTriggersNum(parse_u32(payload).context(err_msg)?)
+            }
+            NL80211_FTM_STATS_RESCHEDULE_REQUESTS_NUM => {
+                let err_msg = format!(
+                    "Invalid NL80211_FTM_STATS_RESCHEDULE_REQUESTS_NUM \
+                     value {:?}",
+                    payload
+                );
+                Self::RescheduleRequestsNum(
+                    parse_u32(payload).context(err_msg)?,
+                )
+            }
+            NL80211_FTM_STATS_OUT_OF_WINDOW_TRIGGERS_NUM => {
+                let err_msg = format!(
+                    "Invalid NL80211_FTM_STATS_OUT_OF_WINDOW_TRIGGERS_NUM \
+                     value {:?}",
+                    payload
+                );
+                Self::OutOfWindowTriggersNum(
+                    parse_u32(payload).context(err_msg)?,
+                )
+            }
+            _ => Self::Other(
+                DefaultNla::parse(buf).context("invalid NLA (unknown kind)")?,
+            ),
+        })
+    }
+}
+
+/// Fine timing measurement responder statistics
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct Nl80211FtmResponderStats {
+    /// Number of FTM sessions in which all frames were successfully answered
+    pub success_num: Option<u32>,
+    /// Number of FTM sessions in which part of the frames were successfully
+    /// answered
+    pub partial_num: Option<u32>,
+    /// Number of failed FTM sessions
+    pub failed_num: Option<u32>,
+    /// Number of ASAP sessions
+    pub asap_num: Option<u32>,
+    /// Number of non-ASAP sessions
+    pub non_asap_num: Option<u32>,
+    /// Total duration of sessions in milliseconds
+    pub total_duration_msec: Option<u64>,
+    /// Number of FTM triggers from initiators which did not finish the
+    /// negotiation with the responder
+    pub unknown_triggers_num: Option<u32>,
+    /// Number of FTM reschedule requests from initiators already having a
+    /// scheduled FTM slot
+    pub reschedule_requests_num: Option<u32>,
+    /// Number of FTM triggers out of the scheduled window
+    pub out_of_window_triggers_num: Option<u32>,
+}
+
+impl Emitable for Nl80211FtmResponderStats {
+    fn buffer_len(&self) -> usize {
+        Vec::<Nl80211FtmResponderStatsNla>::from(self)
+            .as_slice()
+            .buffer_len()
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        Vec::<Nl80211FtmResponderStatsNla>::from(self)
+            .as_slice()
+            .emit(buffer)
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for Nl80211FtmResponderStats
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let mut ret = Self::default();
+        let payload = buf.value();
+        let err_msg = format!(
+            "Invalid NL80211_ATTR_FTM_RESPONDER_STATS \
+             value {:?}",
+            payload
+        );
+        for nla in NlasIterator::new(payload) {
+            let nla = &nla.context(err_msg.clone())?;
+            match Nl80211FtmResponderStatsNla::parse(nla)
+                .context(err_msg.clone())?
+            {
+                Nl80211FtmResponderStatsNla::SuccessNum(d) => {
+                    ret.success_num = Some(d)
+                }
+                Nl80211FtmResponderStatsNla::PartialNum(d) => {
+                    ret.partial_num = Some(d)
+                }
+                Nl80211FtmResponderStatsNla::FailedNum(d) => {
+                    ret.failed_num = Some(d)
+                }
+                Nl80211FtmResponderStatsNla::AsapNum(d) => {
+                    ret.asap_num = Some(d)
+                }
+                Nl80211FtmResponderStatsNla::NonAsapNum(d) => {
+                    ret.non_asap_num = Some(d)
+                }
+                Nl80211FtmResponderStatsNla::TotalDurationMsec(d) => {
+                    ret.total_duration_msec = Some(d)
+                }
+                Nl80211FtmResponderStatsNla::UnknownTriggersNum(d) => {
+                    ret.unknown_triggers_num = Some(d)
+                }
+                Nl80211FtmResponderStatsNla::RescheduleRequestsNum(d) => {
+                    ret.reschedule_requests_num = Some(d)
+                }
+                Nl80211FtmResponderStatsNla::OutOfWindowTriggersNum(d) => {
+                    ret.out_of_window_triggers_num = Some(d)
+                }
+                Nl80211FtmResponderStatsNla::Other(attr) => {
+                    log::warn!(
+                        "Got unsupported NL80211_ATTR_FTM_RESPONDER_STATS \
+                         value {:?}",
+                        attr
+                    )
+                }
+            }
+        }
+        Ok(ret)
+    }
+}
+
+impl From<&Nl80211FtmResponderStats> for Vec<Nl80211FtmResponderStatsNla> {
+    fn from(stats: &Nl80211FtmResponderStats) -> Self {
+        let mut nlas = Vec::new();
+        if let Some(v) = stats.success_num {
+            nlas.push(Nl80211FtmResponderStatsNla::SuccessNum(v));
+        }
+        if let Some(v) = stats.partial_num {
+            nlas.push(Nl80211FtmResponderStatsNla::PartialNum(v));
+        }
+        if let Some(v) = stats.failed_num {
+            nlas.push(Nl80211FtmResponderStatsNla::FailedNum(v));
+        }
+        if let Some(v) = stats.asap_num {
+            nlas.push(Nl80211FtmResponderStatsNla::AsapNum(v));
+        }
+        if let Some(v) = stats.non_asap_num {
+            nlas.push(Nl80211FtmResponderStatsNla::NonAsapNum(v));
+        }
+        if let Some(v) = stats.total_duration_msec {
+            nlas.push(Nl80211FtmResponderStatsNla::TotalDurationMsec(v));
+        }
+        if let Some(v) = stats.unknown_triggers_num {
+            nlas.push(Nl80211FtmResponderStatsNla::UnknownTriggersNum(v));
+        }
+        if let Some(v) = stats.reschedule_requests_num {
+            nlas.push(Nl80211FtmResponderStatsNla::RescheduleRequestsNum(v));
+        }
+        if let Some(v) = stats.out_of_window_triggers_num {
+            nlas.push(Nl80211FtmResponderStatsNla::OutOfWindowTriggersNum(v));
+        }
+        nlas
+    }
+}
@@ -0,0 +1,123 @@
+// SPDX-License-Identifier: MIT
+
+use crate::{
+    Nl80211AclPolicy, Nl80211ApStartRequest, Nl80211ApStopRequest,
+    Nl80211BeaconSetRequest, Nl80211BssSetRequest, Nl80211ChannelDef,
+    Nl80211ChannelSwitchRequest, Nl80211ColorChangeRequest,
+    Nl80211FtmResponderStatsGetRequest, Nl80211Handle, Nl80211MacAclSetRequest,
+    Nl80211ProbeClientRequest, Nl80211RadarNotifyRequest,
+};
+
+pub struct Nl80211ApHandle(Nl80211Handle);
+
+impl Nl80211ApHandle {
+    pub fn new(handle: Nl80211Handle) -> Self {
+        Nl80211ApHandle(handle)
+    }
+
+    /// Start operating as access point on specified interface with the
+    /// beacon template starting with `beacon_head`, beacon interval in TUs
+    /// and DTIM period in beacon intervals
+    /// (equivalent to `iw dev DEVICE ap start ...`)
+    pub fn start(
+        &mut self,
+        if_index: u32,
+        beacon_head: Vec<u8>,
+        beacon_interval: u32,
+        dtim_period: u32,
+    ) -> Nl80211ApStartRequest {
+        Nl80211ApStartRequest::new(
+            self.0.clone(),
+            if_index,
+            beacon_head,
+            beacon_interval,
+            dtim_period,
+        )
+    }
+
+    /// Update the beacon and Probe Response templates of the running access
+    /// point, only the templates set are changed
+    pub fn set_beacon(&mut self, if_index: u32) -> Nl80211BeaconSetRequest {
+        Nl80211BeaconSetRequest::new(self.0.clone(), if_index)
+    }
+
+    /// Change BSS parameters of the running access point
+    pub fn set_bss(&mut self, if_index: u32) -> Nl80211BssSetRequest {
+        Nl80211BssSetRequest::new(self.0.clone(), if_index)
+    }
+
+    /// Announce and perform a switch to `chandef` after `count` TBTTs, also
+    /// used by mesh and IBSS interfaces. The
+    /// `Nl80211Cmd::ChannelSwitchStartedNotify` and
+    /// `Nl80211Cmd::ChannelSwitchNotify` events are sent to the `mlme`
+    /// multicast group when the switch starts and completes.
+    pub fn channel_switch(
+        &mut self,
+        if_index: u32,
+        chandef: Nl80211ChannelDef,
+        count: u32,
+    ) -> Nl80211ChannelSwitchRequest {
+        Nl80211ChannelSwitchRequest::new(
+            self.0.clone(),
+            if_index,
+            chandef,
+            count,
+        )
+    }
+
+    /// Switch the BSS color of the running access point to `color` after
+    /// `count` TBTTs
+    pub fn color_change(
+        &mut self,
+        if_index: u32,
+        color: u8,
+        count: u8,
+    ) -> Nl80211ColorChangeRequest {
+        Nl80211ColorChangeRequest::new(self.0.clone(), if_index, color, count)
+    }
+
+    /// Set the MAC address access control list of the running access point,
+    /// replacing the one set before
+    pub fn set_mac_acl(
+        &mut self,
+        if_index: u32,
+        policy: Nl80211AclPolicy,
+    ) -> Nl80211MacAclSetRequest {
+        Nl80211MacAclSetRequest::new(self.0.clone(), if_index, policy)
+    }
+
+    /// Probe an associated station by sending a null data frame to it
+    pub fn probe_client(
+        &mut self,
+        if_index: u32,
+        mac: [u8; 6],
+    ) -> Nl80211ProbeClientRequest {
+        Nl80211ProbeClientRequest::new(self.0.clone(), if_index, mac)
+    }
+
+    /// Inform kernel of a radar detected on `chandef`, for example by a
+    /// dedicated radar detection chain or a neighboring device, so the
+    /// affected channels are marked unavailable
+    pub fn notify_radar(
+        &mut self,
+        if_index: u32,
+        chandef: Nl80211ChannelDef,
+    ) -> Nl80211RadarNotifyRequest {
+        Nl80211RadarNotifyRequest::new(self.0.clone(), if_index, chandef)
+    }
+
+    /// Retrieve the fine timing measurement responder statistics of the
+    /// running access point
+    pub fn ftm_responder_stats(
+        &mut self,
+        if_index: u32,
+    ) -> Nl80211FtmResponderStatsGetRequest {
+        Nl80211FtmResponderStatsGetRequest::new(self.0.clone(), if_index)
+    }
+
+    /// Stop operating as access point on specified interface
+    /// (equivalent to `iw dev DEVICE ap stop`)
+    pub fn stop(&mut self, if_index: u32) -> Nl80211ApStopRequest {
+        Nl80211ApStopRequest::new(self.0.clone(), if_index)
+    }
+}
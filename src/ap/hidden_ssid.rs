@@ -0,0 +1,41 @@
+// SPDX-License-Identifier: MIT
+
+const NL80211_HIDDEN_SSID_NOT_IN_USE: u32 = 0;
+const NL80211_HIDDEN_SSID_ZERO_LEN: u32 = 1;
+const NL80211_HIDDEN_SSID_ZERO_CONTENTS: u32 = 2;
+
+/// Whether the SSID is hidden in the Beacon of an access point
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Nl80211HiddenSsid {
+    /// SSID is included in the Beacon
+    NotInUse,
+    /// SSID element is sent with zero length
+    ZeroLen,
+    /// SSID element is sent with its original length but zero contents
+    ZeroContents,
+    Other(u32),
+}
+
+impl From<u32> for Nl80211HiddenSsid {
+    fn from(d: u32) -> Self {
+        match d {
+            NL80211_HIDDEN_SSID_NOT_IN_USE => Self::NotInUse,
+            NL80211_HIDDEN_SSID_ZERO_LEN => Self::ZeroLen,
+            NL80211_HIDDEN_SSID_ZERO_CONTENTS => Self::ZeroContents,
+            _ => Self::Other(d),
+        }
+    }
+}
+
+impl From<Nl80211HiddenSsid> for u32 {
+    fn from(v: Nl80211HiddenSsid) -> u32 {
+        match v {
+            Nl80211HiddenSsid::NotInUse => NL80211_HIDDEN_SSID_NOT_IN_USE,
+            Nl80211HiddenSsid::ZeroLen => NL80211_HIDDEN_SSID_ZERO_LEN,
+            Nl80211HiddenSsid::ZeroContents => {
+                NL80211_HIDDEN_SSID_ZERO_CONTENTS
+            }
+            Nl80211HiddenSsid::Other(d) => d,
+        }
+    }
+}
@@ -0,0 +1,61 @@
+// SPDX-License-Identifier: MIT
+
+use crate::{
+    nl80211_execute_ack, Nl80211AclPolicy, Nl80211Attr, Nl80211Cmd,
+    Nl80211Error, Nl80211Handle, Nl80211Message,
+};
+
+pub struct Nl80211MacAclSetRequest {
+    handle: Nl80211Handle,
+    if_index: u32,
+    policy: Nl80211AclPolicy,
+    mac_addrs: Vec<[u8; 6]>,
+}
+
+impl Nl80211MacAclSetRequest {
+    pub(crate) fn new(
+        handle: Nl80211Handle,
+        if_index: u32,
+        policy: Nl80211AclPolicy,
+    ) -> Self {
+        Nl80211MacAclSetRequest {
+            handle,
+            if_index,
+            policy,
+            mac_addrs: Vec::new(),
+        }
+    }
+
+    /// Add a station to the access control list
+    pub fn mac(mut self, mac: [u8; 6]) -> Self {
+        self.mac_addrs.push(mac);
+        self
+    }
+
+    /// Stations of the access control list, replacing those added before
+    pub fn mac_addrs(mut self, mac_addrs: Vec<[u8; 6]>) -> Self {
+        self.mac_addrs = mac_addrs;
+        self
+    }
+
+    pub async fn execute(self) -> Result<(), Nl80211Error> {
+        let Nl80211MacAclSetRequest {
+            mut handle,
+            if_index,
+            policy,
+            mac_addrs,
+        } = self;
+
+        let nlas = vec![
+            Nl80211Attr::IfIndex(if_index),
+            Nl80211Attr::AclPolicy(policy),
+            Nl80211Attr::MacAddrs(mac_addrs),
+        ];
+
+        let nl80211_msg = Nl80211Message {
+            cmd: Nl80211Cmd::MacAclSet,
+            nlas,
+        };
+        nl80211_execute_ack(&mut handle, nl80211_msg).await
+    }
+}
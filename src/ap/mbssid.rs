@@ -0,0 +1,224 @@
+// SPDX-License-Identifier: MIT
+
+use anyhow::Context;
+use byteorder::{ByteOrder, NativeEndian};
+use netlink_packet_utils::{
+    nla::{DefaultNla, Nla, NlaBuffer, NlasIterator},
+    parsers::{parse_u32, parse_u8},
+    DecodeError, Emitable, Parseable,
+};
+
+const NL80211_MBSSID_CONFIG_ATTR_MAX_INTERFACES: u16 = 1;
+const NL80211_MBSSID_CONFIG_ATTR_MAX_EMA_PROFILE_PERIODICITY: u16 = 2;
+const NL80211_MBSSID_CONFIG_ATTR_INDEX: u16 = 3;
+const NL80211_MBSSID_CONFIG_ATTR_TX_IFINDEX: u16 = 4;
+const NL80211_MBSSID_CONFIG_ATTR_EMA: u16 = 5;
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum Nl80211MbssidConfigNla {
+    /// Maximum number of interfaces in a multiple BSSID set supported by
+    /// the wiphy
+    MaxInterfaces(u8),
+    /// Maximum profile periodicity supported by the wiphy for enhanced
+    /// multi-BSSID advertisements
+    MaxEmaProfilePeriodicity(u8),
+    /// Index of this BSS in the multiple BSSID set, 0 for the transmitting
+    /// interface
+    Index(u8),
+    /// Interface index of the transmitting interface
+    TxIfIndex(u32),
+    /// Enable enhanced multi-BSSID advertisements
+    Ema,
+    Other(DefaultNla),
+}
+
+impl Nla for Nl80211MbssidConfigNla {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::Ema => 0,
+            Self::MaxInterfaces(_)
+            | Self::MaxEmaProfilePeriodicity(_)
+            | Self::Index(_) => 1,
+            Self::TxIfIndex(_) => 4,
+            Self::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::MaxInterfaces(_) => NL80211_MBSSID_CONFIG_ATTR_MAX_INTERFACES,
+            Self::MaxEmaProfilePeriodicity(_) => {
+                NL80211_MBSSID_CONFIG_ATTR_MAX_EMA_PROFILE_PERIODICITY
+            }
+            Self::Index(_) => NL80211_MBSSID_CONFIG_ATTR_INDEX,
+            Self::TxIfIndex(_) => NL80211_MBSSID_CONFIG_ATTR_TX_IFINDEX,
+            Self::Ema => NL80211_MBSSID_CONFIG_ATTR_EMA,
+            Self::Other(attr) => attr.kind(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Ema => (),
+            Self::MaxInterfaces(d)
+            | Self::MaxEmaProfilePeriodicity(d)
+            | Self::Index(d) => buffer[0] = *d,
+            Self::TxIfIndex(d) => NativeEndian::write_u32(buffer, *d),
+            Self::Other(ref attr) => attr.emit(buffer),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for Nl80211MbssidConfigNla
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            NL80211_MBSSID_CONFIG_ATTR_MAX_INTERFACES => {
+                let err_msg = format!(
+                    "Invalid NL80211_MBSSID_CONFIG_ATTR_MAX_INTERFACES \
+                     value {:?}",
+                    payload
+                );
+                Self::MaxInterfaces(parse_u8(payload).context(err_msg)?)
+            }
+            NL80211_MBSSID_CONFIG_ATTR_MAX_EMA_PROFILE_PERIODICITY => {
+                let err_msg = format!(
+                    "Invalid \
+                     NL80211_MBSSID_CONFIG_ATTR_MAX_EMA_PROFILE_PERIODICITY \
+                     value {:?}",
+                    payload
+                );
+                Self::MaxEmaProfilePeriodicity(
+                    parse_u8(payload).context(err_msg)?,
+                )
+            }
+            NL80211_MBSSID_CONFIG_ATTR_INDEX => {
+                let err_msg = format!(
+                    "Invalid NL80211_MBSSID_CONFIG_ATTR_INDEX value {:?}",
+                    payload
+                );
+                Self::Index(parse_u8(payload).context(err_msg)?)
+            }
+            NL80211_MBSSID_CONFIG_ATTR_TX_IFINDEX => {
+                let err_msg = format!(
+                    "Invalid NL80211_MBSSID_CONFIG_ATTR_TX_IFINDEX value {:?}",
+                    payload
+                );
+                Self::TxIfIndex(parse_u32(payload).context(err_msg)?)
+            }
+            NL80211_MBSSID_CONFIG_ATTR_EMA => Self::Ema,
+            _ => Self::Other(
+                DefaultNla::parse(buf).context("invalid NLA (unknown kind)")?,
+            ),
+        })
+    }
+}
+
+/// Multiple BSSID configuration of an access point, also used by kernel
+/// to advertise the MBSSID support of the wiphy
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct Nl80211MbssidConfig {
+    /// Maximum number of interfaces in a multiple BSSID set supported by
+    /// the wiphy
+    pub max_interfaces: Option<u8>,
+    /// Maximum profile periodicity supported by the wiphy for enhanced
+    /// multi-BSSID advertisements
+    pub max_ema_profile_periodicity: Option<u8>,
+    /// Index of this BSS in the multiple BSSID set, 0 for the transmitting
+    /// interface
+    pub index: Option<u8>,
+    /// Interface index of the transmitting interface
+    pub tx_if_index: Option<u32>,
+    /// Enable enhanced multi-BSSID advertisements
+    pub ema: bool,
+}
+
+impl Nl80211MbssidConfig {
+    /// Configuration of the BSS at `index` in the multiple BSSID set
+    /// transmitted by interface `tx_if_index`
+    pub fn new(index: u8, tx_if_index: u32) -> Self {
+        Self {
+            index: Some(index),
+            tx_if_index: Some(tx_if_index),
+            ..Default::default()
+        }
+    }
+
+    /// Enable enhanced multi-BSSID advertisements
+    pub fn ema(mut self, enable: bool) -> Self {
+        self.ema = enable;
+        self
+    }
+}
+
+impl Emitable for Nl80211MbssidConfig {
+    fn buffer_len(&self) -> usize {
+        Vec::<Nl80211MbssidConfigNla>::from(self)
+            .as_slice()
+            .buffer_len()
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        Vec::<Nl80211MbssidConfigNla>::from(self)
+            .as_slice()
+            .emit(buffer)
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for Nl80211MbssidConfig
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let mut ret = Self::default();
+        let payload = buf.value();
+        let err_msg =
+            format!("Invalid NL80211_ATTR_MBSSID_CONFIG value {:?}", payload);
+        for nla in NlasIterator::new(payload) {
+            let nla = &nla.context(err_msg.clone())?;
+            match Nl80211MbssidConfigNla::parse(nla).context(err_msg.clone())? {
+                Nl80211MbssidConfigNla::MaxInterfaces(d) => {
+                    ret.max_interfaces = Some(d)
+                }
+                Nl80211MbssidConfigNla::MaxEmaProfilePeriodicity(d) => {
+                    ret.max_ema_profile_periodicity = Some(d)
+                }
+                Nl80211MbssidConfigNla::Index(d) => ret.index = Some(d),
+                Nl80211MbssidConfigNla::TxIfIndex(d) => {
+                    ret.tx_if_index = Some(d)
+                }
+                Nl80211MbssidConfigNla::Ema => ret.ema = true,
+                Nl80211MbssidConfigNla::Other(attr) => {
+                    log::warn!(
+                        "Got unsupported NL80211_ATTR_MBSSID_CONFIG value {:?}",
+                        attr
+                    )
+                }
+            }
+        }
+        Ok(ret)
+    }
+}
+
+impl From<&Nl80211MbssidConfig> for Vec<Nl80211MbssidConfigNla> {
+    fn from(conf: &Nl80211MbssidConfig) -> Self {
+        let mut nlas = Vec::new();
+        if let Some(v) = conf.max_interfaces {
+            nlas.push(Nl80211MbssidConfigNla::MaxInterfaces(v));
+        }
+        if let Some(v) = conf.max_ema_profile_periodicity {
+            nlas.push(Nl80211MbssidConfigNla::MaxEmaProfilePeriodicity(v));
+        }
+        if let Some(v) = conf.index {
+            nlas.push(Nl80211MbssidConfigNla::Index(v));
+        }
+        if let Some(v) = conf.tx_if_index {
+            nlas.push(Nl80211MbssidConfigNla::TxIfIndex(v));
+        }
+        if conf.ema {
+            nlas.push(Nl80211MbssidConfigNla::Ema);
+        }
+        nlas
+    }
+}
@@ -0,0 +1,48 @@
+// SPDX-License-Identifier: MIT
+
+mod acl_policy;
+mod beacon;
+mod bss_color;
+mod channel_switch;
+mod color_change;
+mod fils_discovery;
+mod ftm_stats;
+mod ftm_stats_get;
+mod handle;
+mod hidden_ssid;
+mod mac_acl;
+mod mbssid;
+mod notify_radar;
+mod obss_pd;
+mod probe_client;
+mod set_beacon;
+mod set_bss;
+mod start;
+mod stop;
+mod unsol_bcast_probe_resp;
+
+pub use acl_policy::Nl80211AclPolicy;
+pub use beacon::{Nl80211BeaconData, Nl80211BeaconHead};
+pub use bss_color::{Nl80211HeBssColor, Nl80211HeBssColorNla};
+pub use channel_switch::Nl80211ChannelSwitchRequest;
+pub use color_change::Nl80211ColorChangeRequest;
+pub use fils_discovery::{Nl80211FilsDiscovery, Nl80211FilsDiscoveryNla};
+pub use ftm_stats::{Nl80211FtmResponderStats, Nl80211FtmResponderStatsNla};
+pub use ftm_stats_get::Nl80211FtmResponderStatsGetRequest;
+pub use handle::Nl80211ApHandle;
+pub use hidden_ssid::Nl80211HiddenSsid;
+pub use mac_acl::Nl80211MacAclSetRequest;
+pub use mbssid::{Nl80211MbssidConfig, Nl80211MbssidConfigNla};
+pub use notify_radar::Nl80211RadarNotifyRequest;
+pub use obss_pd::{Nl80211HeObssPd, Nl80211HeObssPdNla};
+pub use probe_client::{
+    nl80211_probe_client_status, Nl80211ProbeClientRequest,
+    Nl80211ProbeClientStatus,
+};
+pub use set_beacon::Nl80211BeaconSetRequest;
+pub use set_bss::Nl80211BssSetRequest;
+pub use start::Nl80211ApStartRequest;
+pub use stop::Nl80211ApStopRequest;
+pub use unsol_bcast_probe_resp::{
+    Nl80211UnsolBcastProbeResp, Nl80211UnsolBcastProbeRespNla,
+};
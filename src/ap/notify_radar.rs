@@ -0,0 +1,45 @@
+// SPDX-License-Identifier: MIT
+
+use crate::{
+    nl80211_execute_ack, Nl80211Attr, Nl80211ChannelDef, Nl80211Cmd,
+    Nl80211Error, Nl80211Handle, Nl80211Message,
+};
+
+pub struct Nl80211RadarNotifyRequest {
+    handle: Nl80211Handle,
+    if_index: u32,
+    chandef: Nl80211ChannelDef,
+}
+
+impl Nl80211RadarNotifyRequest {
+    pub(crate) fn new(
+        handle: Nl80211Handle,
+        if_index: u32,
+        chandef: Nl80211ChannelDef,
+    ) -> Self {
+        Nl80211RadarNotifyRequest {
+            handle,
+            if_index,
+            chandef,
+        }
+    }
+
+    pub async fn execute(self) -> Result<(), Nl80211Error> {
+        let Nl80211RadarNotifyRequest {
+            mut handle,
+            if_index,
+            chandef,
+        } = self;
+
+        chandef.validate()?;
+
+        let mut nlas = vec![Nl80211Attr::IfIndex(if_index)];
+        nlas.extend(Vec::<Nl80211Attr>::from(&chandef));
+
+        let nl80211_msg = Nl80211Message {
+            cmd: Nl80211Cmd::RadarNotify,
+            nlas,
+        };
+        nl80211_execute_ack(&mut handle, nl80211_msg).await
+    }
+}
@@ -0,0 +1,236 @@
+// SPDX-License-Identifier: MIT
+
+use anyhow::Context;
+use byteorder::{ByteOrder, LittleEndian};
+use netlink_packet_utils::{
+    nla::{DefaultNla, Nla, NlaBuffer, NlasIterator},
+    parsers::parse_u8,
+    DecodeError, Emitable, Parseable,
+};
+
+const NL80211_HE_OBSS_PD_ATTR_MIN_OFFSET: u16 = 1;
+const NL80211_HE_OBSS_PD_ATTR_MAX_OFFSET: u16 = 2;
+const NL80211_HE_OBSS_PD_ATTR_NON_SRG_MAX_OFFSET: u16 = 3;
+const NL80211_HE_OBSS_PD_ATTR_BSS_COLOR_BITMAP: u16 = 4;
+const NL80211_HE_OBSS_PD_ATTR_PARTIAL_BSSID_BITMAP: u16 = 5;
+const NL80211_HE_OBSS_PD_ATTR_SR_CTRL: u16 = 6;
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum Nl80211HeObssPdNla {
+    /// SRG OBSS PD minimum TX power offset
+    MinOffset(u8),
+    /// SRG OBSS PD maximum TX power offset
+    MaxOffset(u8),
+    /// Non-SRG OBSS PD maximum TX power offset
+    NonSrgMaxOffset(u8),
+    /// BSS colors used by members of the SRG, bit N set for color N
+    BssColorBitmap(u64),
+    /// Partial BSSID values used by members of the SRG, bit N set
+    /// for value N
+    PartialBssidBitmap(u64),
+    /// Raw SR Control field of the Spatial Reuse Parameter Set element
+    SrCtrl(u8),
+    Other(DefaultNla),
+}
+
+impl Nla for Nl80211HeObssPdNla {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::MinOffset(_)
+            | Self::MaxOffset(_)
+            | Self::NonSrgMaxOffset(_)
+            | Self::SrCtrl(_) => 1,
+            Self::BssColorBitmap(_) | Self::PartialBssidBitmap(_) => 8,
+            Self::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::MinOffset(_) => NL80211_HE_OBSS_PD_ATTR_MIN_OFFSET,
+            Self::MaxOffset(_) => NL80211_HE_OBSS_PD_ATTR_MAX_OFFSET,
+            Self::NonSrgMaxOffset(_) => {
+                NL80211_HE_OBSS_PD_ATTR_NON_SRG_MAX_OFFSET
+            }
+            Self::BssColorBitmap(_) => NL80211_HE_OBSS_PD_ATTR_BSS_COLOR_BITMAP,
+            Self::PartialBssidBitmap(_) => {
+                NL80211_HE_OBSS_PD_ATTR_PARTIAL_BSSID_BITMAP
+            }
+            Self::SrCtrl(_) => NL80211_HE_OBSS_PD_ATTR_SR_CTRL,
+            Self::Other(attr) => attr.kind(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::MinOffset(d)
+            | Self::MaxOffset(d)
+            | Self::NonSrgMaxOffset(d)
+            | Self::SrCtrl(d) => buffer[0] = *d,
+            Self::BssColorBitmap(d) | Self::PartialBssidBitmap(d) => {
+                LittleEndian::write_u64(buffer, *d)
+            }
+            Self::Other(ref attr) => attr.emit(buffer),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for Nl80211HeObssPdNla
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            NL80211_HE_OBSS_PD_ATTR_MIN_OFFSET => {
+                let err_msg = format!(
+                    "Invalid NL80211_HE_OBSS_PD_ATTR_MIN_OFFSET value {:?}",
+                    payload
+                );
+                Self::MinOffset(parse_u8(payload).context(err_msg)?)
+            }
+            NL80211_HE_OBSS_PD_ATTR_MAX_OFFSET => {
+                let err_msg = format!(
+                    "Invalid NL80211_HE_OBSS_PD_ATTR_MAX_OFFSET value {:?}",
+                    payload
+                );
+                Self::MaxOffset(parse_u8(payload).context(err_msg)?)
+            }
+            NL80211_HE_OBSS_PD_ATTR_NON_SRG_MAX_OFFSET => {
+                let err_msg = format!(
+                    "Invalid NL80211_HE_OBSS_PD_ATTR_NON_SRG_MAX_OFFSET \
+                     value {:?}",
+                    payload
+                );
+                Self::NonSrgMaxOffset(parse_u8(payload).context(err_msg)?)
+            }
+            NL80211_HE_OBSS_PD_ATTR_BSS_COLOR_BITMAP => {
+                let err_msg = format!(
+                    "Invalid NL80211_HE_OBSS_PD_ATTR_BSS_COLOR_BITMAP \
+                     value {:?}",
+                    payload
+                );
+                Self::BssColorBitmap(
+                    payload
+                        .get(..8)
+                        .map(LittleEndian::read_u64)
+                        .ok_or_else(|| DecodeError::from(err_msg))?,
+                )
+            }
+            NL80211_HE_OBSS_PD_ATTR_PARTIAL_BSSID_BITMAP => {
+                let err_msg = format!(
+                    "Invalid NL80211_HE_OBSS_PD_ATTR_PARTIAL_BSSID_BITMAP \
+                     value {:?}",
+                    payload
+                );
+                Self::PartialBssidBitmap(
+                    payload
+                        .get(..8)
+                        .map(LittleEndian::read_u64)
+                        .ok_or_else(|| DecodeError::from(err_msg))?,
+                )
+            }
+            NL80211_HE_OBSS_PD_ATTR_SR_CTRL => {
+                let err_msg = format!(
+                    "Invalid NL80211_HE_OBSS_PD_ATTR_SR_CTRL value {:?}",
+                    payload
+                );
+                Self::SrCtrl(parse_u8(payload).context(err_msg)?)
+            }
+            _ => Self::Other(
+                DefaultNla::parse(buf).context("invalid NLA (unknown kind)")?,
+            ),
+        })
+    }
+}
+
+/// OBSS packet detection (spatial reuse) parameters of an HE access point
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct Nl80211HeObssPd {
+    /// SRG OBSS PD minimum TX power offset
+    pub min_offset: Option<u8>,
+    /// SRG OBSS PD maximum TX power offset
+    pub max_offset: Option<u8>,
+    /// Non-SRG OBSS PD maximum TX power offset
+    pub non_srg_max_offset: Option<u8>,
+    /// BSS colors used by members of the SRG, bit N set for color N
+    pub bss_color_bitmap: Option<u64>,
+    /// Partial BSSID values used by members of the SRG, bit N set
+    /// for value N
+    pub partial_bssid_bitmap: Option<u64>,
+    /// Raw SR Control field of the Spatial Reuse Parameter Set element
+    pub sr_ctrl: Option<u8>,
+}
+
+impl Emitable for Nl80211HeObssPd {
+    fn buffer_len(&self) -> usize {
+        Vec::<Nl80211HeObssPdNla>::from(self)
+            .as_slice()
+            .buffer_len()
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        Vec::<Nl80211HeObssPdNla>::from(self)
+            .as_slice()
+            .emit(buffer)
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for Nl80211HeObssPd
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let mut ret = Self::default();
+        let payload = buf.value();
+        let err_msg =
+            format!("Invalid NL80211_ATTR_HE_OBSS_PD value {:?}", payload);
+        for nla in NlasIterator::new(payload) {
+            let nla = &nla.context(err_msg.clone())?;
+            match Nl80211HeObssPdNla::parse(nla).context(err_msg.clone())? {
+                Nl80211HeObssPdNla::MinOffset(d) => ret.min_offset = Some(d),
+                Nl80211HeObssPdNla::MaxOffset(d) => ret.max_offset = Some(d),
+                Nl80211HeObssPdNla::NonSrgMaxOffset(d) => {
+                    ret.non_srg_max_offset = Some(d)
+                }
+                Nl80211HeObssPdNla::BssColorBitmap(d) => {
+                    ret.bss_color_bitmap = Some(d)
+                }
+                Nl80211HeObssPdNla::PartialBssidBitmap(d) => {
+                    ret.partial_bssid_bitmap = Some(d)
+                }
+                Nl80211HeObssPdNla::SrCtrl(d) => ret.sr_ctrl = Some(d),
+                Nl80211HeObssPdNla::Other(attr) => {
+                    log::warn!(
+                        "Got unsupported NL80211_ATTR_HE_OBSS_PD value {:?}",
+                        attr
+                    )
+                }
+            }
+        }
+        Ok(ret)
+    }
+}
+
+impl From<&Nl80211HeObssPd> for Vec<Nl80211HeObssPdNla> {
+    fn from(conf: &Nl80211HeObssPd) -> Self {
+        let mut nlas = Vec::new();
+        if let Some(v) = conf.min_offset {
+            nlas.push(Nl80211HeObssPdNla::MinOffset(v));
+        }
+        if let Some(v) = conf.max_offset {
+            nlas.push(Nl80211HeObssPdNla::MaxOffset(v));
+        }
+        if let Some(v) = conf.non_srg_max_offset {
+            nlas.push(Nl80211HeObssPdNla::NonSrgMaxOffset(v));
+        }
+        if let Some(v) = conf.bss_color_bitmap {
+            nlas.push(Nl80211HeObssPdNla::BssColorBitmap(v));
+        }
+        if let Some(v) = conf.partial_bssid_bitmap {
+            nlas.push(Nl80211HeObssPdNla::PartialBssidBitmap(v));
+        }
+        if let Some(v) = conf.sr_ctrl {
+            nlas.push(Nl80211HeObssPdNla::SrCtrl(v));
+        }
+        nlas
+    }
+}
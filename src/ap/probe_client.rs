@@ -0,0 +1,117 @@
+// SPDX-License-Identifier: MIT
+
+use futures::{channel::mpsc::UnboundedReceiver, StreamExt};
+use genetlink::message::RawGenlMessage;
+use netlink_packet_core::NetlinkMessage;
+use netlink_sys::SocketAddr;
+
+use crate::{
+    frame::{nl80211_execute_cookie, parse_unsolicited},
+    Nl80211Attr, Nl80211Cmd, Nl80211Error, Nl80211Handle, Nl80211Message,
+};
+
+pub struct Nl80211ProbeClientRequest {
+    handle: Nl80211Handle,
+    if_index: u32,
+    mac: [u8; 6],
+}
+
+impl Nl80211ProbeClientRequest {
+    pub(crate) fn new(
+        handle: Nl80211Handle,
+        if_index: u32,
+        mac: [u8; 6],
+    ) -> Self {
+        Nl80211ProbeClientRequest {
+            handle,
+            if_index,
+            mac,
+        }
+    }
+
+    /// Return the cookie identifying the `Nl80211Cmd::ProbeClient` event
+    /// sent to the `mlme` multicast group once the null data frame is
+    /// transmitted, see `nl80211_probe_client_status()`.
+    pub async fn execute(self) -> Result<u64, Nl80211Error> {
+        let Nl80211ProbeClientRequest {
+            mut handle,
+            if_index,
+            mac,
+        } = self;
+
+        let nl80211_msg = Nl80211Message {
+            cmd: Nl80211Cmd::ProbeClient,
+            nlas: vec![Nl80211Attr::IfIndex(if_index), Nl80211Attr::Mac(mac)],
+        };
+        nl80211_execute_cookie(&mut handle, nl80211_msg)
+            .await?
+            .ok_or_else(|| {
+                Nl80211Error::RequestFailed(
+                    "No cookie in the reply of probe client request"
+                        .to_string(),
+                )
+            })
+    }
+}
+
+/// Result of probing a station by `Nl80211ProbeClientRequest`
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct Nl80211ProbeClientStatus {
+    pub if_index: Option<u32>,
+    /// Address of the probed station
+    pub mac: Option<[u8; 6]>,
+    /// Cookie returned by the probe request
+    pub cookie: u64,
+    /// Whether the station acknowledged the null data frame
+    pub ack: bool,
+    /// Signal strength of the ACK frame in dBm
+    pub ack_signal: Option<i32>,
+}
+
+impl Nl80211ProbeClientStatus {
+    /// Extract the status from a `Nl80211Cmd::ProbeClient` event, `None` for
+    /// other messages
+    pub fn from_message(msg: &Nl80211Message) -> Option<Self> {
+        if msg.cmd != Nl80211Cmd::ProbeClient {
+            return None;
+        }
+        let mut ret = Self::default();
+        let mut cookie = None;
+        for nla in msg.nlas.iter() {
+            match nla {
+                Nl80211Attr::IfIndex(d) => ret.if_index = Some(*d),
+                Nl80211Attr::Mac(d) => ret.mac = Some(*d),
+                Nl80211Attr::Cookie(d) => cookie = Some(*d),
+                Nl80211Attr::Ack => ret.ack = true,
+                Nl80211Attr::AckSignal(d) => ret.ack_signal = Some(*d),
+                _ => (),
+            }
+        }
+        ret.cookie = cookie?;
+        Some(ret)
+    }
+}
+
+/// Wait for the result of the probe identified by `cookie` among the
+/// unsolicited messages returned by `new_connection()`. The result is sent to
+/// the `mlme` multicast group, hence the socket of the connection has to join
+/// it before probing the station. Messages received before it are dropped,
+/// `None` if the connection is closed first.
+pub async fn nl80211_probe_client_status(
+    messages: &mut UnboundedReceiver<(
+        NetlinkMessage<RawGenlMessage>,
+        SocketAddr,
+    )>,
+    cookie: u64,
+) -> Option<Nl80211ProbeClientStatus> {
+    while let Some((msg, _)) = messages.next().await {
+        if let Some(status) = parse_unsolicited(msg)
+            .and_then(|msg| Nl80211ProbeClientStatus::from_message(&msg))
+        {
+            if status.cookie == cookie {
+                return Some(status);
+            }
+        }
+    }
+    None
+}
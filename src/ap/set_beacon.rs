@@ -0,0 +1,115 @@
+// SPDX-License-Identifier: MIT
+
+use crate::{
+    nl80211_execute_ack, Nl80211Attr, Nl80211BeaconData, Nl80211Cmd,
+    Nl80211Error, Nl80211FilsDiscovery, Nl80211Handle, Nl80211Message,
+    Nl80211UnsolBcastProbeResp,
+};
+
+pub struct Nl80211BeaconSetRequest {
+    handle: Nl80211Handle,
+    if_index: u32,
+    beacon: Nl80211BeaconData,
+    unsol_bcast_probe_resp: Option<Nl80211UnsolBcastProbeResp>,
+    fils_discovery: Option<Nl80211FilsDiscovery>,
+}
+
+impl Nl80211BeaconSetRequest {
+    pub(crate) fn new(handle: Nl80211Handle, if_index: u32) -> Self {
+        Nl80211BeaconSetRequest {
+            handle,
+            if_index,
+            beacon: Nl80211BeaconData::default(),
+            unsol_bcast_probe_resp: None,
+            fils_discovery: None,
+        }
+    }
+
+    /// Beacon and Probe Response templates, replacing those set before
+    pub fn beacon(mut self, beacon: Nl80211BeaconData) -> Self {
+        self.beacon = beacon;
+        self
+    }
+
+    /// Raw beacon frame content up to the TIM element
+    pub fn beacon_head(mut self, head: Vec<u8>) -> Self {
+        self.beacon.head = Some(head);
+        self
+    }
+
+    /// Raw beacon frame content following the TIM element
+    pub fn beacon_tail(mut self, tail: Vec<u8>) -> Self {
+        self.beacon.tail = Some(tail);
+        self
+    }
+
+    /// Raw information elements appended to the Beacon and Probe Response by
+    /// drivers building those frames themselves
+    pub fn ie(mut self, ie: Vec<u8>) -> Self {
+        self.beacon.ie = Some(ie);
+        self
+    }
+
+    /// Raw information elements appended to the Probe Response by drivers
+    /// building it themselves
+    pub fn ie_probe_resp(mut self, ie: Vec<u8>) -> Self {
+        self.beacon.ie_probe_resp = Some(ie);
+        self
+    }
+
+    /// Raw information elements appended to the (Re)Association Response by
+    /// drivers building it themselves
+    pub fn ie_assoc_resp(mut self, ie: Vec<u8>) -> Self {
+        self.beacon.ie_assoc_resp = Some(ie);
+        self
+    }
+
+    /// Raw Probe Response frame template for drivers offloading Probe
+    /// Response
+    pub fn probe_resp(mut self, frame: Vec<u8>) -> Self {
+        self.beacon.probe_resp = Some(frame);
+        self
+    }
+
+    /// Unsolicited broadcast Probe Response of an access point in the 6 GHz
+    /// band
+    pub fn unsol_bcast_probe_resp(
+        mut self,
+        conf: Nl80211UnsolBcastProbeResp,
+    ) -> Self {
+        self.unsol_bcast_probe_resp = Some(conf);
+        self
+    }
+
+    /// FILS Discovery frame transmission, mainly for access points in the 6 GHz
+    /// band
+    pub fn fils_discovery(mut self, conf: Nl80211FilsDiscovery) -> Self {
+        self.fils_discovery = Some(conf);
+        self
+    }
+
+    pub async fn execute(self) -> Result<(), Nl80211Error> {
+        let Nl80211BeaconSetRequest {
+            mut handle,
+            if_index,
+            beacon,
+            unsol_bcast_probe_resp,
+            fils_discovery,
+        } = self;
+
+        let mut nlas = vec![Nl80211Attr::IfIndex(if_index)];
+        nlas.extend(Vec::<Nl80211Attr>::from(&beacon));
+        if let Some(conf) = unsol_bcast_probe_resp {
+            nlas.push(Nl80211Attr::UnsolBcastProbeResp(conf));
+        }
+        if let Some(conf) = fils_discovery {
+            nlas.push(Nl80211Attr::FilsDiscovery(conf));
+        }
+
+        let nl80211_msg = Nl80211Message {
+            cmd: Nl80211Cmd::BeaconSet,
+            nlas,
+        };
+        nl80211_execute_ack(&mut handle, nl80211_msg).await
+    }
+}
@@ -0,0 +1,73 @@
+// SPDX-License-Identifier: MIT
+
+use crate::{
+    nl80211_execute_ack, Nl80211Attr, Nl80211Cmd, Nl80211Error, Nl80211Handle,
+    Nl80211Message,
+};
+
+pub struct Nl80211BssSetRequest {
+    handle: Nl80211Handle,
+    if_index: u32,
+    ap_isolate: Option<bool>,
+    p2p_ctwindow: Option<u8>,
+    p2p_opp_ps: Option<bool>,
+}
+
+impl Nl80211BssSetRequest {
+    pub(crate) fn new(handle: Nl80211Handle, if_index: u32) -> Self {
+        Nl80211BssSetRequest {
+            handle,
+            if_index,
+            ap_isolate: None,
+            p2p_ctwindow: None,
+            p2p_opp_ps: None,
+        }
+    }
+
+    /// Prevent stations of the access point from communicating with each
+    /// other
+    pub fn ap_isolate(mut self, enable: bool) -> Self {
+        self.ap_isolate = Some(enable);
+        self
+    }
+
+    /// P2P GO Client Traffic Window in TUs, 0 to disable
+    pub fn p2p_ctwindow(mut self, ctwindow: u8) -> Self {
+        self.p2p_ctwindow = Some(ctwindow);
+        self
+    }
+
+    /// P2P GO opportunistic power save, sleeping once all clients are
+    /// asleep after the Client Traffic Window
+    pub fn p2p_opp_ps(mut self, enable: bool) -> Self {
+        self.p2p_opp_ps = Some(enable);
+        self
+    }
+
+    pub async fn execute(self) -> Result<(), Nl80211Error> {
+        let Nl80211BssSetRequest {
+            mut handle,
+            if_index,
+            ap_isolate,
+            p2p_ctwindow,
+            p2p_opp_ps,
+        } = self;
+
+        let mut nlas = vec![Nl80211Attr::IfIndex(if_index)];
+        if let Some(enable) = ap_isolate {
+            nlas.push(Nl80211Attr::ApIsolate(enable));
+        }
+        if let Some(ctwindow) = p2p_ctwindow {
+            nlas.push(Nl80211Attr::P2pCtwindow(ctwindow));
+        }
+        if let Some(enable) = p2p_opp_ps {
+            nlas.push(Nl80211Attr::P2pOppPs(enable));
+        }
+
+        let nl80211_msg = Nl80211Message {
+            cmd: Nl80211Cmd::BssSet,
+            nlas,
+        };
+        nl80211_execute_ack(&mut handle, nl80211_msg).await
+    }
+}
@@ -0,0 +1,291 @@
+// SPDX-License-Identifier: MIT
+
+use crate::{
+    nl80211_execute_ack, Nl80211Attr, Nl80211AuthType, Nl80211BeaconData,
+    Nl80211ChannelDef, Nl80211Cmd, Nl80211CryptoSettings, Nl80211Error,
+    Nl80211FilsDiscovery, Nl80211Handle, Nl80211HeBssColor, Nl80211HeObssPd,
+    Nl80211HiddenSsid, Nl80211MbssidConfig, Nl80211Message, Nl80211TxRates,
+    Nl80211UnsolBcastProbeResp,
+};
+
+pub struct Nl80211ApStartRequest {
+    handle: Nl80211Handle,
+    if_index: u32,
+    beacon: Nl80211BeaconData,
+    beacon_interval: u32,
+    dtim_period: u32,
+    ssid: Option<String>,
+    hidden_ssid: Option<Nl80211HiddenSsid>,
+    chandef: Option<Nl80211ChannelDef>,
+    auth_type: Option<Nl80211AuthType>,
+    crypto: Option<Nl80211CryptoSettings>,
+    inactivity_timeout: Option<u16>,
+    p2p_ctwindow: Option<u8>,
+    p2p_opp_ps: Option<bool>,
+    unsol_bcast_probe_resp: Option<Nl80211UnsolBcastProbeResp>,
+    fils_discovery: Option<Nl80211FilsDiscovery>,
+    he_obss_pd: Option<Nl80211HeObssPd>,
+    he_bss_color: Option<Nl80211HeBssColor>,
+    mbssid_config: Option<Nl80211MbssidConfig>,
+    beacon_tx_rate: Option<Nl80211TxRates>,
+}
+
+impl Nl80211ApStartRequest {
+    pub(crate) fn new(
+        handle: Nl80211Handle,
+        if_index: u32,
+        beacon_head: Vec<u8>,
+        beacon_interval: u32,
+        dtim_period: u32,
+    ) -> Self {
+        Nl80211ApStartRequest {
+            handle,
+            if_index,
+            beacon: Nl80211BeaconData {
+                head: Some(beacon_head),
+                ..Default::default()
+            },
+            beacon_interval,
+            dtim_period,
+            ssid: None,
+            hidden_ssid: None,
+            chandef: None,
+            auth_type: None,
+            crypto: None,
+            inactivity_timeout: None,
+            p2p_ctwindow: None,
+            p2p_opp_ps: None,
+            unsol_bcast_probe_resp: None,
+            fils_discovery: None,
+            he_obss_pd: None,
+            he_bss_color: None,
+            mbssid_config: None,
+            beacon_tx_rate: None,
+        }
+    }
+
+    /// Beacon and Probe Response templates, the beacon head given on
+    /// creation is kept when `beacon` has none
+    pub fn beacon(mut self, beacon: Nl80211BeaconData) -> Self {
+        let head = self.beacon.head.take();
+        self.beacon = beacon;
+        if self.beacon.head.is_none() {
+            self.beacon.head = head;
+        }
+        self
+    }
+
+    /// Raw beacon frame content following the TIM element
+    pub fn beacon_tail(mut self, tail: Vec<u8>) -> Self {
+        self.beacon.tail = Some(tail);
+        self
+    }
+
+    /// SSID of the BSS, required by drivers building the Probe Response
+    /// themselves
+    pub fn ssid(mut self, ssid: &str) -> Self {
+        self.ssid = Some(ssid.to_string());
+        self
+    }
+
+    /// Hide the SSID in the Beacon, the SSID is still needed by `ssid()`
+    /// for drivers answering Probe Requests themselves
+    pub fn hidden_ssid(mut self, hidden_ssid: Nl80211HiddenSsid) -> Self {
+        self.hidden_ssid = Some(hidden_ssid);
+        self
+    }
+
+    /// Channel of the BSS, the kernel picks the current or a preset channel
+    /// when not set
+    pub fn chandef(mut self, chandef: Nl80211ChannelDef) -> Self {
+        self.chandef = Some(chandef);
+        self
+    }
+
+    /// Authentication type accepted from stations, any when not set
+    pub fn auth_type(mut self, auth_type: Nl80211AuthType) -> Self {
+        self.auth_type = Some(auth_type);
+        self
+    }
+
+    /// Raw information elements appended to the Beacon and Probe Response by
+    /// drivers building those frames themselves
+    pub fn ie(mut self, ie: Vec<u8>) -> Self {
+        self.beacon.ie = Some(ie);
+        self
+    }
+
+    /// Raw information elements appended to the Probe Response by drivers
+    /// building it themselves
+    pub fn ie_probe_resp(mut self, ie: Vec<u8>) -> Self {
+        self.beacon.ie_probe_resp = Some(ie);
+        self
+    }
+
+    /// Raw information elements appended to the (Re)Association Response by
+    /// drivers building it themselves
+    pub fn ie_assoc_resp(mut self, ie: Vec<u8>) -> Self {
+        self.beacon.ie_assoc_resp = Some(ie);
+        self
+    }
+
+    /// Raw Probe Response frame template for drivers offloading Probe
+    /// Response
+    pub fn probe_resp(mut self, frame: Vec<u8>) -> Self {
+        self.beacon.probe_resp = Some(frame);
+        self
+    }
+
+    /// Security settings, open network when not set
+    pub fn crypto(mut self, crypto: Nl80211CryptoSettings) -> Self {
+        self.crypto = Some(crypto);
+        self
+    }
+
+    /// Disconnect stations inactive for the specified seconds, only for
+    /// drivers handling station inactivity themselves
+    pub fn inactivity_timeout(mut self, timeout: u16) -> Self {
+        self.inactivity_timeout = Some(timeout);
+        self
+    }
+
+    /// P2P GO Client Traffic Window in TUs, 0 to disable
+    pub fn p2p_ctwindow(mut self, ctwindow: u8) -> Self {
+        self.p2p_ctwindow = Some(ctwindow);
+        self
+    }
+
+    /// P2P GO opportunistic power save, sleeping once all clients are
+    /// asleep after the Client Traffic Window
+    pub fn p2p_opp_ps(mut self, enable: bool) -> Self {
+        self.p2p_opp_ps = Some(enable);
+        self
+    }
+
+    /// Unsolicited broadcast Probe Response of an access point in the 6 GHz
+    /// band
+    pub fn unsol_bcast_probe_resp(
+        mut self,
+        conf: Nl80211UnsolBcastProbeResp,
+    ) -> Self {
+        self.unsol_bcast_probe_resp = Some(conf);
+        self
+    }
+
+    /// FILS Discovery frame transmission, mainly for access points in the 6 GHz
+    /// band
+    pub fn fils_discovery(mut self, conf: Nl80211FilsDiscovery) -> Self {
+        self.fils_discovery = Some(conf);
+        self
+    }
+
+    /// OBSS packet detection (spatial reuse) parameters of an HE access point
+    pub fn he_obss_pd(mut self, conf: Nl80211HeObssPd) -> Self {
+        self.he_obss_pd = Some(conf);
+        self
+    }
+
+    /// BSS color of an HE access point
+    pub fn he_bss_color(mut self, conf: Nl80211HeBssColor) -> Self {
+        self.he_bss_color = Some(conf);
+        self
+    }
+
+    /// Multiple BSSID configuration of this interface
+    pub fn mbssid_config(mut self, conf: Nl80211MbssidConfig) -> Self {
+        self.mbssid_config = Some(conf);
+        self
+    }
+
+    /// Rate of the beacons, the rates of the band should be restricted to a
+    /// single rate. Requires the `Nl80211ExtFeature::BeaconRate*` feature of
+    /// the rate type, e.g. `BeaconRateHt` for an HT MCS.
+    pub fn beacon_tx_rate(mut self, rates: Nl80211TxRates) -> Self {
+        self.beacon_tx_rate = Some(rates);
+        self
+    }
+
+    pub async fn execute(self) -> Result<(), Nl80211Error> {
+        let Nl80211ApStartRequest {
+            mut handle,
+            if_index,
+            beacon,
+            beacon_interval,
+            dtim_period,
+            ssid,
+            hidden_ssid,
+            chandef,
+            auth_type,
+            crypto,
+            inactivity_timeout,
+            p2p_ctwindow,
+            p2p_opp_ps,
+            unsol_bcast_probe_resp,
+            fils_discovery,
+            he_obss_pd,
+            he_bss_color,
+            mbssid_config,
+            beacon_tx_rate,
+        } = self;
+
+        let mut nlas = vec![
+            Nl80211Attr::IfIndex(if_index),
+            Nl80211Attr::BeaconInterval(beacon_interval),
+            Nl80211Attr::DtimPeriod(dtim_period),
+        ];
+        nlas.extend(Vec::<Nl80211Attr>::from(&beacon));
+        if let Some(ssid) = ssid {
+            nlas.push(Nl80211Attr::Ssid(ssid));
+        }
+        if let Some(hidden_ssid) = hidden_ssid {
+            nlas.push(Nl80211Attr::HiddenSsid(hidden_ssid));
+        }
+        if let Some(chandef) = chandef {
+            chandef.validate()?;
+            nlas.extend(Vec::<Nl80211Attr>::from(&chandef));
+        }
+        // The kernel rejects NL80211_AUTHTYPE_AUTOMATIC, it is expressed by
+        // leaving out the attribute
+        if let Some(auth_type) =
+            auth_type.filter(|t| *t != Nl80211AuthType::Automatic)
+        {
+            nlas.push(Nl80211Attr::AuthType(auth_type));
+        }
+        if let Some(crypto) = crypto {
+            nlas.extend(Vec::<Nl80211Attr>::from(&crypto));
+        }
+        if let Some(timeout) = inactivity_timeout {
+            nlas.push(Nl80211Attr::InactivityTimeout(timeout));
+        }
+        if let Some(ctwindow) = p2p_ctwindow {
+            nlas.push(Nl80211Attr::P2pCtwindow(ctwindow));
+        }
+        if let Some(enable) = p2p_opp_ps {
+            nlas.push(Nl80211Attr::P2pOppPs(enable));
+        }
+        if let Some(conf) = unsol_bcast_probe_resp {
+            nlas.push(Nl80211Attr::UnsolBcastProbeResp(conf));
+        }
+        if let Some(conf) = fils_discovery {
+            nlas.push(Nl80211Attr::FilsDiscovery(conf));
+        }
+        if let Some(conf) = he_obss_pd {
+            nlas.push(Nl80211Attr::HeObssPd(conf));
+        }
+        if let Some(conf) = he_bss_color {
+            nlas.push(Nl80211Attr::HeBssColor(conf));
+        }
+        if let Some(conf) = mbssid_config {
+            nlas.push(Nl80211Attr::MbssidConfig(conf));
+        }
+        if let Some(rates) = beacon_tx_rate {
+            nlas.push(Nl80211Attr::TxRates(vec![rates]));
+        }
+
+        let nl80211_msg = Nl80211Message {
+            cmd: Nl80211Cmd::ApStart,
+            nlas,
+        };
+        nl80211_execute_ack(&mut handle, nl80211_msg).await
+    }
+}
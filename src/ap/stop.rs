@@ -0,0 +1,30 @@
+// SPDX-License-Identifier: MIT
+
+use crate::{
+    nl80211_execute_ack, Nl80211Attr, Nl80211Cmd, Nl80211Error, Nl80211Handle,
+    Nl80211Message,
+};
+
+pub struct Nl80211ApStopRequest {
+    handle: Nl80211Handle,
+    if_index: u32,
+}
+
+impl Nl80211ApStopRequest {
+    pub(crate) fn new(handle: Nl80211Handle, if_index: u32) -> Self {
+        Nl80211ApStopRequest { handle, if_index }
+    }
+
+    pub async fn execute(self) -> Result<(), Nl80211Error> {
+        let Nl80211ApStopRequest {
+            mut handle,
+            if_index,
+        } = self;
+
+        let nl80211_msg = Nl80211Message {
+            cmd: Nl80211Cmd::ApStop,
+            nlas: vec![Nl80211Attr::IfIndex(if_index)],
+        };
+        nl80211_execute_ack(&mut handle, nl80211_msg).await
+    }
+}
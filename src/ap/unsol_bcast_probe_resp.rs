@@ -0,0 +1,153 @@
+// SPDX-License-Identifier: MIT
+
+use anyhow::Context;
+use byteorder::{ByteOrder, NativeEndian};
+use netlink_packet_utils::{
+    nla::{DefaultNla, Nla, NlaBuffer, NlasIterator},
+    parsers::parse_u32,
+    DecodeError, Emitable, Parseable,
+};
+
+const NL80211_UNSOL_BCAST_PROBE_RESP_ATTR_INT: u16 = 1;
+const NL80211_UNSOL_BCAST_PROBE_RESP_ATTR_TMPL: u16 = 2;
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum Nl80211UnsolBcastProbeRespNla {
+    /// Maximum interval in TUs (0..20), 0 to disable
+    Interval(u32),
+    /// Raw Probe Response frame template
+    Template(Vec<u8>),
+    Other(DefaultNla),
+}
+
+impl Nla for Nl80211UnsolBcastProbeRespNla {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::Interval(_) => 4,
+            Self::Template(ref d) => d.len(),
+            Self::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::Interval(_) => NL80211_UNSOL_BCAST_PROBE_RESP_ATTR_INT,
+            Self::Template(_) => NL80211_UNSOL_BCAST_PROBE_RESP_ATTR_TMPL,
+            Self::Other(attr) => attr.kind(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Interval(d) => NativeEndian::write_u32(buffer, *d),
+            Self::Template(ref d) => buffer[..d.len()].copy_from_slice(d),
+            Self::Other(ref attr) => attr.emit(buffer),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for Nl80211UnsolBcastProbeRespNla
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            NL80211_UNSOL_BCAST_PROBE_RESP_ATTR_INT => {
+                let err_msg = format!(
+                    "Invalid NL80211_UNSOL_BCAST_PROBE_RESP_ATTR_INT \
+                     value {:?}",
+                    payload
+                );
+                Self::Interval(parse_u32(payload).context(err_msg)?)
+            }
+            NL80211_UNSOL_BCAST_PROBE_RESP_ATTR_TMPL => {
+                Self::Template(payload.to_vec())
+            }
+            _ => Self::Other(
+                DefaultNla::parse(buf).context("invalid NLA (unknown kind)")?,
+            ),
+        })
+    }
+}
+
+/// Unsolicited broadcast Probe Response configuration of an access point
+/// in the 6 GHz band
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct Nl80211UnsolBcastProbeResp {
+    /// Maximum interval in TUs (0..20), 0 to disable
+    pub interval: Option<u32>,
+    /// Raw Probe Response frame template
+    pub template: Option<Vec<u8>>,
+}
+
+impl Nl80211UnsolBcastProbeResp {
+    /// Send the Probe Response `template` at most every `interval` TUs
+    pub fn new(interval: u32, template: Vec<u8>) -> Self {
+        Self {
+            interval: Some(interval),
+            template: Some(template),
+        }
+    }
+}
+
+impl Emitable for Nl80211UnsolBcastProbeResp {
+    fn buffer_len(&self) -> usize {
+        Vec::<Nl80211UnsolBcastProbeRespNla>::from(self)
+            .as_slice()
+            .buffer_len()
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        Vec::<Nl80211UnsolBcastProbeRespNla>::from(self)
+            .as_slice()
+            .emit(buffer)
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for Nl80211UnsolBcastProbeResp
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let mut ret = Self::default();
+        let payload = buf.value();
+        let err_msg = format!(
+            "Invalid NL80211_ATTR_UNSOL_BCAST_PROBE_RESP \
+             value {:?}",
+            payload
+        );
+        for nla in NlasIterator::new(payload) {
+            let nla = &nla.context(err_msg.clone())?;
+            match Nl80211UnsolBcastProbeRespNla::parse(nla)
+                .context(err_msg.clone())?
+            {
+                Nl80211UnsolBcastProbeRespNla::Interval(d) => {
+                    ret.interval = Some(d)
+                }
+                Nl80211UnsolBcastProbeRespNla::Template(d) => {
+                    ret.template = Some(d)
+                }
+                Nl80211UnsolBcastProbeRespNla::Other(attr) => {
+                    log::warn!(
+                        "Got unsupported NL80211_ATTR_UNSOL_BCAST_PROBE_RESP \
+                         value {:?}",
+                        attr
+                    )
+                }
+            }
+        }
+        Ok(ret)
+    }
+}
+
+impl From<&Nl80211UnsolBcastProbeResp> for Vec<Nl80211UnsolBcastProbeRespNla> {
+    fn from(conf: &Nl80211UnsolBcastProbeResp) -> Self {
+        let mut nlas = Vec::new();
+        if let Some(v) = conf.interval {
+            nlas.push(Nl80211UnsolBcastProbeRespNla::Interval(v));
+        }
+        if let Some(v) = conf.template.as_ref() {
+            nlas.push(Nl80211UnsolBcastProbeRespNla::Template(v.clone()));
+        }
+        nlas
+    }
+}
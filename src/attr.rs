@@ -4,33 +4,233 @@ use anyhow::Context;
 use byteorder::{ByteOrder, NativeEndian};
 use netlink_packet_utils::{
     nla::{DefaultNla, Nla, NlaBuffer, NlasIterator},
-    parsers::{parse_string, parse_u32, parse_u64, parse_u8},
+    parsers::{
+        parse_i32, parse_mac, parse_string, parse_u16, parse_u32, parse_u64,
+        parse_u8,
+    },
     DecodeError, Emitable, Parseable,
 };
 
 use crate::{
+    akm::Nl80211AkmSuite,
+    ap::{
+        Nl80211AclPolicy, Nl80211FilsDiscovery, Nl80211FtmResponderStats,
+        Nl80211HeBssColor, Nl80211HeObssPd, Nl80211HiddenSsid,
+        Nl80211MbssidConfig, Nl80211UnsolBcastProbeResp,
+    },
+    auth_type::Nl80211AuthType,
     channel::{Nl80211ChannelWidth, Nl80211WiPhyChannelType},
-    iface::Nl80211InterfaceType,
+    cipher::Nl80211CipherSuite,
+    coalesce::{Nl80211CoalesceRule, Nl80211CoalesceRuleSupport},
+    cqm::Nl80211Cqm,
+    crypto::{Nl80211Mfp, Nl80211WpaVersions},
+    iface::{
+        iftypes_to_nlas, parse_iftypes, Nl80211CritProtoId,
+        Nl80211InterfaceType, Nl80211MonitorFlags, Nl80211PowerSaveState,
+        Nl80211QosMap,
+    },
+    key::{Nl80211Key, Nl80211KeyType},
+    mesh::{Nl80211MeshConfig, Nl80211MeshSetup, Nl80211MpathInfo},
+    message::Nl80211Cmd,
+    nan::{Nl80211NanFunction, Nl80211NanMatch},
+    nested::{bytes_array_to_nlas, mac_addrs_to_nlas, nested_array},
+    phy::{
+        Nl80211ExtFeatures, Nl80211ExtendedCapability, Nl80211IftypeExtCapa,
+        Nl80211ProtocolFeatures, Nl80211TxqParams, Nl80211WiPhyBand,
+    },
+    pmsr::Nl80211PeerMeasurement,
+    reg::{Nl80211DfsRegion, Nl80211RegRule},
+    scan::{
+        Nl80211Bss, Nl80211RssiAdjust, Nl80211ScanFlags,
+        Nl80211SchedScanMatchSet,
+    },
+    station::{
+        Nl80211PlinkAction, Nl80211PlinkState, Nl80211StationFlag,
+        Nl80211StationFlagUpdate, Nl80211StationInfo,
+    },
     stats::Nl80211TransmitQueueStat,
+    survey::Nl80211SurveyInfo,
+    tdls::{Nl80211TdlsAction, Nl80211TdlsOperation},
+    tid_config::{Nl80211TidConfig, Nl80211TidConfigSupport},
+    tx_rates::Nl80211TxRates,
+    wowlan::{Nl80211WowlanTriggers, Nl80211WowlanTriggersSupported},
 };
 
 const NL80211_ATTR_WIPHY: u16 = 1;
+const NL80211_ATTR_WIPHY_NAME: u16 = 2;
 const NL80211_ATTR_IFINDEX: u16 = 3;
 const NL80211_ATTR_IFNAME: u16 = 4;
 const NL80211_ATTR_IFTYPE: u16 = 5;
 const NL80211_ATTR_MAC: u16 = 6;
+const NL80211_ATTR_KEY_DATA: u16 = 7;
+const NL80211_ATTR_KEY_IDX: u16 = 8;
+const NL80211_ATTR_KEY_CIPHER: u16 = 9;
+const NL80211_ATTR_KEY_SEQ: u16 = 10;
+const NL80211_ATTR_KEY_DEFAULT: u16 = 11;
+const NL80211_ATTR_BEACON_INTERVAL: u16 = 12;
+const NL80211_ATTR_DTIM_PERIOD: u16 = 13;
+const NL80211_ATTR_BEACON_HEAD: u16 = 14;
+const NL80211_ATTR_BEACON_TAIL: u16 = 15;
+const NL80211_ATTR_STA_AID: u16 = 16;
+const NL80211_ATTR_STA_FLAGS: u16 = 17;
+const NL80211_ATTR_STA_LISTEN_INTERVAL: u16 = 18;
+const NL80211_ATTR_STA_SUPPORTED_RATES: u16 = 19;
+const NL80211_ATTR_STA_INFO: u16 = 21;
+const NL80211_ATTR_WIPHY_BANDS: u16 = 22;
+const NL80211_ATTR_MNTR_FLAGS: u16 = 23;
+const NL80211_ATTR_MESH_ID: u16 = 24;
+const NL80211_ATTR_STA_PLINK_ACTION: u16 = 25;
+const NL80211_ATTR_MPATH_NEXT_HOP: u16 = 26;
+const NL80211_ATTR_MPATH_INFO: u16 = 27;
+const NL80211_ATTR_SUPPORTED_IFTYPES: u16 = 32;
+const NL80211_ATTR_REG_ALPHA2: u16 = 33;
+const NL80211_ATTR_REG_RULES: u16 = 34;
+const NL80211_ATTR_MESH_CONFIG: u16 = 35;
+const NL80211_ATTR_BSS_BASIC_RATES: u16 = 36;
+const NL80211_ATTR_WIPHY_TXQ_PARAMS: u16 = 37;
 const NL80211_ATTR_WIPHY_FREQ: u16 = 38;
 const NL80211_ATTR_WIPHY_CHANNEL_TYPE: u16 = 39;
+const NL80211_ATTR_KEY_DEFAULT_MGMT: u16 = 40;
+const NL80211_ATTR_IE: u16 = 42;
+const NL80211_ATTR_SCAN_FREQUENCIES: u16 = 44;
+const NL80211_ATTR_SCAN_SSIDS: u16 = 45;
 const NL80211_ATTR_GENERATION: u16 = 46;
+const NL80211_ATTR_BSS: u16 = 47;
+const NL80211_ATTR_SUPPORTED_COMMANDS: u16 = 50;
+const NL80211_ATTR_FRAME: u16 = 51;
 const NL80211_ATTR_SSID: u16 = 52;
+const NL80211_ATTR_AUTH_TYPE: u16 = 53;
+const NL80211_ATTR_KEY_TYPE: u16 = 55;
+const NL80211_ATTR_FREQ_FIXED: u16 = 60;
+const NL80211_ATTR_WIPHY_RETRY_SHORT: u16 = 61;
+const NL80211_ATTR_WIPHY_RETRY_LONG: u16 = 62;
+const NL80211_ATTR_WIPHY_FRAG_THRESHOLD: u16 = 63;
+const NL80211_ATTR_WIPHY_RTS_THRESHOLD: u16 = 64;
+const NL80211_ATTR_USE_MFP: u16 = 66;
+const NL80211_ATTR_STA_FLAGS2: u16 = 67;
+const NL80211_ATTR_CONTROL_PORT: u16 = 68;
+const NL80211_ATTR_PRIVACY: u16 = 70;
+const NL80211_ATTR_STATUS_CODE: u16 = 72;
+const NL80211_ATTR_CIPHER_SUITES_PAIRWISE: u16 = 73;
+const NL80211_ATTR_CIPHER_SUITE_GROUP: u16 = 74;
+const NL80211_ATTR_WPA_VERSIONS: u16 = 75;
+const NL80211_ATTR_AKM_SUITES: u16 = 76;
+const NL80211_ATTR_PREV_BSSID: u16 = 79;
+const NL80211_ATTR_KEY: u16 = 80;
+const NL80211_ATTR_KEYS: u16 = 81;
+const NL80211_ATTR_PID: u16 = 82;
 const NL80211_ATTR_4ADDR: u16 = 83;
+const NL80211_ATTR_SURVEY_INFO: u16 = 84;
+const NL80211_ATTR_PMKID: u16 = 85;
+const NL80211_ATTR_DURATION: u16 = 87;
+const NL80211_ATTR_COOKIE: u16 = 88;
+const NL80211_ATTR_WIPHY_COVERAGE_CLASS: u16 = 89;
+const NL80211_ATTR_TX_RATES: u16 = 90;
+const NL80211_ATTR_FRAME_MATCH: u16 = 91;
+const NL80211_ATTR_ACK: u16 = 92;
+const NL80211_ATTR_PS_STATE: u16 = 93;
+const NL80211_ATTR_CQM: u16 = 94;
+const NL80211_ATTR_LOCAL_STATE_CHANGE: u16 = 95;
+const NL80211_ATTR_AP_ISOLATE: u16 = 96;
 const NL80211_ATTR_WIPHY_TX_POWER_LEVEL: u16 = 98;
+const NL80211_ATTR_FRAME_TYPE: u16 = 101;
+const NL80211_ATTR_WIPHY_ANTENNA_TX: u16 = 105;
+const NL80211_ATTR_WIPHY_ANTENNA_RX: u16 = 106;
+const NL80211_ATTR_MCAST_RATE: u16 = 107;
+const NL80211_ATTR_OFFCHANNEL_TX_OK: u16 = 108;
+const NL80211_ATTR_MESH_SETUP: u16 = 112;
+const NL80211_ATTR_WIPHY_ANTENNA_AVAIL_TX: u16 = 113;
+const NL80211_ATTR_WIPHY_ANTENNA_AVAIL_RX: u16 = 114;
+const NL80211_ATTR_STA_PLINK_STATE: u16 = 116;
+const NL80211_ATTR_WOWLAN_TRIGGERS: u16 = 117;
+const NL80211_ATTR_WOWLAN_TRIGGERS_SUPPORTED: u16 = 118;
+const NL80211_ATTR_SCHED_SCAN_INTERVAL: u16 = 119;
+const NL80211_ATTR_SOFTWARE_IFTYPES: u16 = 121;
+const NL80211_ATTR_HIDDEN_SSID: u16 = 126;
+const NL80211_ATTR_IE_PROBE_RESP: u16 = 127;
+const NL80211_ATTR_IE_ASSOC_RESP: u16 = 128;
+const NL80211_ATTR_SCHED_SCAN_MATCH: u16 = 132;
+const NL80211_ATTR_TX_NO_CCK_RATE: u16 = 135;
+const NL80211_ATTR_TDLS_ACTION: u16 = 136;
+const NL80211_ATTR_TDLS_DIALOG_TOKEN: u16 = 137;
+const NL80211_ATTR_TDLS_OPERATION: u16 = 138;
+const NL80211_ATTR_TDLS_SUPPORT: u16 = 139;
+const NL80211_ATTR_TDLS_EXTERNAL_SETUP: u16 = 140;
+const NL80211_ATTR_DONT_WAIT_FOR_ACK: u16 = 142;
+const NL80211_ATTR_PROBE_RESP: u16 = 145;
+const NL80211_ATTR_DFS_REGION: u16 = 146;
+const NL80211_ATTR_NOACK_MAP: u16 = 149;
+const NL80211_ATTR_INACTIVITY_TIMEOUT: u16 = 150;
+const NL80211_ATTR_RX_SIGNAL_DBM: u16 = 151;
 const NL80211_ATTR_WDEV: u16 = 153;
+const NL80211_ATTR_AUTH_DATA: u16 = 156;
+const NL80211_ATTR_SCAN_FLAGS: u16 = 158;
 const NL80211_ATTR_CHANNEL_WIDTH: u16 = 159;
 const NL80211_ATTR_CENTER_FREQ1: u16 = 160;
 const NL80211_ATTR_CENTER_FREQ2: u16 = 161;
+const NL80211_ATTR_P2P_CTWINDOW: u16 = 162;
+const NL80211_ATTR_P2P_OPPPS: u16 = 163;
+const NL80211_ATTR_ACL_POLICY: u16 = 165;
+const NL80211_ATTR_MAC_ADDRS: u16 = 166;
+const NL80211_ATTR_MAC_ACL_MAX: u16 = 167;
+const NL80211_ATTR_EXT_CAPA: u16 = 169;
+const NL80211_ATTR_EXT_CAPA_MASK: u16 = 170;
+const NL80211_ATTR_PROTOCOL_FEATURES: u16 = 173;
+const NL80211_ATTR_SPLIT_WIPHY_DUMP: u16 = 174;
+const NL80211_ATTR_CRIT_PROT_ID: u16 = 179;
+const NL80211_ATTR_MAX_CRIT_PROT_DURATION: u16 = 180;
+const NL80211_ATTR_COALESCE_RULE: u16 = 182;
+const NL80211_ATTR_CH_SWITCH_COUNT: u16 = 183;
+const NL80211_ATTR_CH_SWITCH_BLOCK_TX: u16 = 184;
+const NL80211_ATTR_CSA_IES: u16 = 185;
+const NL80211_ATTR_CNTDWN_OFFS_BEACON: u16 = 186;
+const NL80211_ATTR_CNTDWN_OFFS_PRESP: u16 = 187;
+const NL80211_ATTR_QOS_MAP: u16 = 199;
+const NL80211_ATTR_MAC_HINT: u16 = 200;
+const NL80211_ATTR_WIPHY_FREQ_HINT: u16 = 201;
+const NL80211_ATTR_TDLS_PEER_CAPABILITY: u16 = 203;
+const NL80211_ATTR_TDLS_INITIATOR: u16 = 207;
+const NL80211_ATTR_TSID: u16 = 210;
+const NL80211_ATTR_USER_PRIO: u16 = 211;
+const NL80211_ATTR_ADMITTED_TIME: u16 = 212;
+const NL80211_ATTR_MAC_MASK: u16 = 215;
+const NL80211_ATTR_WIPHY_SELF_MANAGED_REG: u16 = 216;
+const NL80211_ATTR_EXT_FEATURES: u16 = 217;
+const NL80211_ATTR_NETNS_FD: u16 = 219;
+const NL80211_ATTR_IFTYPE_EXT_CAPA: u16 = 230;
+const NL80211_ATTR_NAN_MASTER_PREF: u16 = 238;
+const NL80211_ATTR_BANDS: u16 = 239;
+const NL80211_ATTR_NAN_FUNC: u16 = 240;
+const NL80211_ATTR_NAN_MATCH: u16 = 241;
+const NL80211_ATTR_MULTICAST_TO_UNICAST_ENABLED: u16 = 244;
+const NL80211_ATTR_SCHED_SCAN_RELATIVE_RSSI: u16 = 246;
+const NL80211_ATTR_SCHED_SCAN_RSSI_ADJUST: u16 = 247;
+const NL80211_ATTR_PMK: u16 = 254;
+const NL80211_ATTR_WANT_1X_4WAY_HS: u16 = 257;
+const NL80211_ATTR_PMKR0_NAME: u16 = 258;
+const NL80211_ATTR_ACK_SIGNAL: u16 = 263;
 const NL80211_ATTR_TXQ_STATS: u16 = 265;
+const NL80211_ATTR_FTM_RESPONDER_STATS: u16 = 271;
+const NL80211_ATTR_TIMEOUT: u16 = 272;
+const NL80211_ATTR_PEER_MEASUREMENTS: u16 = 273;
+const NL80211_ATTR_AIRTIME_WEIGHT: u16 = 274;
+const NL80211_ATTR_SAE_PASSWORD: u16 = 277;
+const NL80211_ATTR_HE_OBSS_PD: u16 = 279;
+const NL80211_ATTR_WIPHY_EDMG_CHANNELS: u16 = 280;
+const NL80211_ATTR_WIPHY_EDMG_BW_CONFIG: u16 = 281;
+const NL80211_ATTR_HE_BSS_COLOR: u16 = 283;
+const NL80211_ATTR_TID_CONFIG: u16 = 285;
+const NL80211_ATTR_PMK_LIFETIME: u16 = 287;
+const NL80211_ATTR_PMK_REAUTH_THRESHOLD: u16 = 288;
+const NL80211_ATTR_RECEIVE_MULTICAST: u16 = 289;
 const NL80211_ATTR_WIPHY_FREQ_OFFSET: u16 = 290;
+const NL80211_ATTR_FILS_DISCOVERY: u16 = 294;
+const NL80211_ATTR_UNSOL_BCAST_PROBE_RESP: u16 = 295;
+const NL80211_ATTR_COLOR_CHANGE_COUNT: u16 = 303;
+const NL80211_ATTR_COLOR_CHANGE_COLOR: u16 = 304;
+const NL80211_ATTR_COLOR_CHANGE_ELEMS: u16 = 305;
+const NL80211_ATTR_MBSSID_CONFIG: u16 = 306;
+const NL80211_ATTR_MBSSID_ELEMS: u16 = 307;
 const NL80211_ATTR_MLO_LINKS: u16 = 312;
 const NL80211_ATTR_MLO_LINK_ID: u16 = 313;
 
@@ -43,6 +243,9 @@ pub enum Nl80211Attr {
     IfName(String),
     IfType(Nl80211InterfaceType),
     Mac([u8; ETH_ALEN]),
+    /// MAC address mask, bits set to 1 are taken from `Mac`, others are
+    /// randomized
+    MacMask([u8; ETH_ALEN]),
     Wdev(u64),
     Generation(u32),
     Use4Addr(bool),
@@ -54,8 +257,334 @@ pub enum Nl80211Attr {
     CenterFreq2(u32),
     WiPhyTxPowerLevel(u32),
     Ssid(String),
+    /// Information elements in raw bytes
+    Ie(Vec<u8>),
+    /// SSIDs to probe for, an empty SSID means wildcard scan
+    ScanSsids(Vec<String>),
+    /// Frequencies(MHz) to scan
+    ScanFrequencies(Vec<u32>),
+    ScanFlags(Nl80211ScanFlags),
+    /// Interval(milliseconds) between scheduled scan cycles
+    SchedScanInterval(u32),
+    SchedScanMatch(Vec<Nl80211SchedScanMatchSet>),
+    /// Only report BSS with RSSI better than current connected BSS by this
+    /// value(dB)
+    SchedScanRelativeRssi(i8),
+    SchedScanRssiAdjust(Nl80211RssiAdjust),
+    Bss(Nl80211Bss),
+    StationInfo(Nl80211StationInfo),
     TransmitQueueStats(Vec<Nl80211TransmitQueueStat>),
     MloLinks(Vec<Nl80211MloLink>),
+    /// Station flags to set, flags not listed are cleared
+    StationFlags(Vec<Nl80211StationFlag>),
+    StationPlinkAction(Nl80211PlinkAction),
+    /// Station weight for the airtime fairness scheduler
+    AirtimeWeight(u16),
+    StationFlags2(Nl80211StationFlagUpdate),
+    SurveyInfo(Nl80211SurveyInfo),
+    /// ISO-3166-alpha2 country code, `00` for world regulatory domain
+    RegAlpha2(String),
+    RegRules(Vec<Nl80211RegRule>),
+    DfsRegion(Nl80211DfsRegion),
+    /// The regulatory domain is managed by the wiphy itself
+    WiPhySelfManagedReg,
+    MonitorFlags(Nl80211MonitorFlags),
+    WiPhyName(String),
+    /// Allow kernel to split the wiphy information into multiple messages
+    SplitWiPhyDump,
+    /// Information about the bands supported by the wiphy
+    WiPhyBands(Vec<Nl80211WiPhyBand>),
+    /// Bitmap of the 2.16 GHz channels allowed for EDMG transmissions
+    WiPhyEdmgChannels(u8),
+    /// EDMG channel BW configuration as defined by IEEE 802.11ay
+    WiPhyEdmgBwConfig(u8),
+    /// Interface types supported by the wiphy
+    SupportedIftypes(Vec<Nl80211InterfaceType>),
+    /// Interface types purely managed in software, which can be added to any
+    /// combination of interfaces
+    SoftwareIftypes(Vec<Nl80211InterfaceType>),
+    /// Commands supported by the wiphy
+    SupportedCommands(Vec<Nl80211Cmd>),
+    /// Extended features supported by the wiphy
+    ExtFeatures(Nl80211ExtFeatures),
+    /// Extended capabilities the driver wants to advertise
+    ExtCapa(Nl80211ExtendedCapability),
+    /// Bits of `ExtCapa` which are valid
+    ExtCapaMask(Nl80211ExtendedCapability),
+    /// Extended capabilities per interface type
+    IftypeExtCapa(Vec<Nl80211IftypeExtCapa>),
+    /// Wake on WLAN triggers supported by the wiphy
+    WowlanTriggersSupported(Nl80211WowlanTriggersSupported),
+    /// Wake on WLAN triggers, not present when Wake on WLAN is disabled
+    WowlanTriggers(Nl80211WowlanTriggers),
+    /// Authentication type
+    AuthType(Nl80211AuthType),
+    /// BSSID hint, the kernel may still pick another BSS of the same ESS
+    MacHint([u8; ETH_ALEN]),
+    /// Frequency hint in MHz, the kernel may still pick another channel
+    WiPhyFreqHint(u32),
+    /// Userspace handles the EAPOL frames of the controlled port
+    ControlPort,
+    /// Privacy is required for the connection
+    Privacy,
+    /// Allowed pairwise cipher suites
+    CipherSuitesPairwise(Vec<Nl80211CipherSuite>),
+    /// Group cipher suite
+    CipherSuiteGroup(Nl80211CipherSuite),
+    /// Allowed WPA versions
+    WpaVersions(Nl80211WpaVersions),
+    /// Allowed authentication and key management suites
+    AkmSuites(Vec<Nl80211AkmSuite>),
+    /// Raw management frame including the header
+    Frame(Vec<u8>),
+    /// Only update the local state without sending frames to the peer
+    LocalStateChange,
+    /// Authentication fields following the Authentication transaction
+    /// sequence number, e.g. SAE commit and confirm
+    AuthData(Vec<u8>),
+    /// Whether management frame protection is used
+    UseMfp(Nl80211Mfp),
+    /// BSSID of the current association, used for reassociation
+    PrevBssid([u8; ETH_ALEN]),
+    /// Password for SAE authentication offloaded to the driver
+    SaePassword(Vec<u8>),
+    /// Pairwise master key, or PMK-R0 when `PmkR0Name` is present
+    Pmk(Vec<u8>),
+    /// Offload the 4-way handshake of 802.1X authentication to the driver
+    Want1x4WayHs,
+    /// PMK-R0 Name for offloaded fast BSS transition
+    PmkR0Name(Vec<u8>),
+    /// IEEE 802.11 status code
+    StatusCode(u16),
+    /// PMK identifier for PMKSA caching
+    Pmkid(Vec<u8>),
+    /// Maximum lifetime of the PMKSA in seconds
+    PmkLifetime(u32),
+    /// Percentage of the PMKSA lifetime after which a full authentication is
+    /// triggered
+    PmkReauthThreshold(u8),
+    /// Key material
+    KeyData(Vec<u8>),
+    /// Key index, 0-3 for data keys, 4-5 for management frame protection keys
+    /// and 6-7 for beacon protection keys
+    KeyIdx(u8),
+    /// Cipher suite of the key
+    KeyCipher(Nl80211CipherSuite),
+    /// Transmit key sequence number (IV/PN), least significant byte first
+    KeySeq(Vec<u8>),
+    /// Use the key as default key
+    KeyDefault,
+    /// Use the key as default management frame protection key
+    KeyDefaultMgmt,
+    /// Key type
+    KeyType(Nl80211KeyType),
+    /// Encryption key in nested form, preferred over the `Key*` attributes
+    Key(Nl80211Key),
+    /// Static WEP keys used when connecting or joining an IBSS
+    Keys(Vec<Nl80211Key>),
+    /// Beacon interval in TUs
+    BeaconInterval(u32),
+    /// Basic rates of the BSS in units of 500 kbps
+    BssBasicRates(Vec<u8>),
+    /// Do not search for an existing IBSS on other channels
+    FreqFixed,
+    /// Mesh ID, the SSID equivalent of a mesh network
+    MeshId(String),
+    /// Mesh configuration parameters
+    MeshConfig(Nl80211MeshConfig),
+    /// Mesh setup parameters, only used when joining a mesh
+    MeshSetup(Nl80211MeshSetup),
+    /// DTIM period in beacon intervals
+    DtimPeriod(u32),
+    /// Multicast transmit rate in units of 100 kbps
+    McastRate(u32),
+    /// MAC address of the next hop of a mesh path
+    MpathNextHop([u8; ETH_ALEN]),
+    /// Mesh path information
+    MpathInfo(Nl80211MpathInfo),
+    /// State of the mesh peer link, set by userspace mesh peering management
+    StationPlinkState(Nl80211PlinkState),
+    /// Supported rates of the station in units of 500 kbps
+    StationSupportedRates(Vec<u8>),
+    /// Listen interval of the station in beacon intervals
+    StationListenInterval(u16),
+    /// Association ID of the station
+    StationAid(u16),
+    /// Raw beacon frame content up to the TIM element
+    BeaconHead(Vec<u8>),
+    /// Raw beacon frame content following the TIM element
+    BeaconTail(Vec<u8>),
+    /// Raw information elements appended to the Probe Response by drivers
+    /// building it themselves
+    IeProbeResp(Vec<u8>),
+    /// Raw information elements appended to the (Re)Association Response by
+    /// drivers building it themselves
+    IeAssocResp(Vec<u8>),
+    /// Raw Probe Response frame template for drivers offloading Probe Response
+    ProbeResp(Vec<u8>),
+    /// Whether the SSID is hidden in the Beacon
+    HiddenSsid(Nl80211HiddenSsid),
+    /// Whether stations of the access point are prevented from
+    /// communicating with each other
+    ApIsolate(bool),
+    /// Time in seconds after which inactive stations are disconnected by
+    /// drivers handling station inactivity themselves
+    InactivityTimeout(u16),
+    /// P2P GO Client Traffic Window in TUs, 0 to disable
+    P2pCtwindow(u8),
+    /// EDCA parameters of access categories
+    WiPhyTxqParams(Vec<Nl80211TxqParams>),
+    /// Fine timing measurement responder statistics
+    FtmResponderStats(Nl80211FtmResponderStats),
+    /// Identifier of an operation, for example a peer measurement
+    Cookie(u64),
+    /// Timeout of the operation in milliseconds
+    Timeout(u32),
+    /// Peer measurement request, result or capabilities of the wiphy
+    PeerMeasurements(Nl80211PeerMeasurement),
+    /// Unsolicited broadcast Probe Response configuration of an access point
+    /// in the 6 GHz band
+    UnsolBcastProbeResp(Nl80211UnsolBcastProbeResp),
+    /// FILS Discovery frame configuration of an access point
+    FilsDiscovery(Nl80211FilsDiscovery),
+    /// Offsets of the countdown field within the beacon tail
+    CntdwnOffsBeacon(Vec<u16>),
+    /// Offsets of the countdown field within the Probe Response template
+    CntdwnOffsPresp(Vec<u16>),
+    /// Number of TBTTs until the BSS color change
+    ColorChangeCount(u8),
+    /// BSS color to switch to
+    ColorChangeColor(u8),
+    /// Beacon templates and countdown offsets used while the BSS color
+    /// change is in progress
+    ColorChangeElems(Vec<Nl80211Attr>),
+    HeObssPd(Nl80211HeObssPd),
+    HeBssColor(Nl80211HeBssColor),
+    MbssidConfig(Nl80211MbssidConfig),
+    /// Raw Multiple BSSID elements, one entry per beacon of enhanced
+    /// multi-BSSID advertisements
+    MbssidElems(Vec<Vec<u8>>),
+    AclPolicy(Nl80211AclPolicy),
+    /// MAC addresses of the access control list
+    MacAddrs(Vec<[u8; ETH_ALEN]>),
+    /// Maximum number of MAC addresses in the access control list supported
+    /// by the wiphy
+    MacAclMax(u32),
+    /// Frame was acknowledged by the recipient
+    Ack,
+    /// Signal strength (dBm) of the acknowledgement
+    AckSignal(i32),
+    /// Signal strength (dBm) of the received frame
+    RxSignalDbm(i32),
+    /// Leading bytes of the frame body a registration matches, for example
+    /// the category of Action frames
+    FrameMatch(Vec<u8>),
+    /// Frame Control field of the management frame type and subtype, for
+    /// example `0x00d0` for Action frames
+    FrameType(u16),
+    /// Also receive the registered frames sent to multicast addresses
+    ReceiveMulticast,
+    /// Duration(milliseconds) of an offchannel operation
+    Duration(u32),
+    /// Allow transmitting the frame on another channel than the operating one
+    OffchannelTxOk,
+    /// Do not use CCK rates for the frame in the 2.4 GHz band
+    TxNoCckRate,
+    /// Do not report the TX status of the frame
+    DontWaitForAck,
+    /// Number of TBTTs until the channel switch
+    ChSwitchCount(u32),
+    /// Stop transmission until the channel switch completes
+    ChSwitchBlockTx,
+    /// Beacon templates and countdown offsets used while the channel switch
+    /// is in progress
+    CsaIes(Vec<Nl80211Attr>),
+    /// Retry limit for frames not longer than the RTS threshold
+    WiPhyRetryShort(u8),
+    /// Retry limit for frames longer than the RTS threshold
+    WiPhyRetryLong(u8),
+    /// Fragmentation threshold in octets, `u32::MAX` when disabled
+    WiPhyFragThreshold(u32),
+    /// RTS threshold in octets, `u32::MAX` when disabled
+    WiPhyRtsThreshold(u32),
+    /// Coverage class as defined by IEEE 802.11, adjusting the slot time
+    /// and ACK timeout for long distance links
+    WiPhyCoverageClass(u8),
+    /// Bitmap of antennas used for transmitting
+    WiPhyAntennaTx(u32),
+    /// Bitmap of antennas used for receiving
+    WiPhyAntennaRx(u32),
+    /// Bitmap of antennas which can be configured for transmitting
+    WiPhyAntennaAvailTx(u32),
+    /// Bitmap of antennas which can be configured for receiving
+    WiPhyAntennaAvailRx(u32),
+    /// Process ID whose network namespace the wiphy is moved to
+    Pid(u32),
+    /// File descriptor of the network namespace the wiphy is moved to
+    NetnsFd(u32),
+    /// Features of the nl80211 protocol supported by the kernel
+    ProtocolFeatures(Nl80211ProtocolFeatures),
+    /// Power save state of the interface
+    PsState(Nl80211PowerSaveState),
+    /// Connection quality monitor configuration or event
+    Cqm(Nl80211Cqm),
+    /// TDLS action frame to send
+    TdlsAction(Nl80211TdlsAction),
+    /// Dialog token of the TDLS action frame
+    TdlsDialogToken(u8),
+    /// TDLS operation to perform
+    TdlsOperation(Nl80211TdlsOperation),
+    /// The wiphy supports TDLS
+    TdlsSupport,
+    /// The TDLS setup exchange is performed by userspace via
+    /// `Nl80211Cmd::TdlsMgmt`, while the driver only handles the link
+    TdlsExternalSetup,
+    /// Raw TDLS peer capability flags
+    TdlsPeerCapability(u32),
+    /// This station initiated the TDLS setup
+    TdlsInitiator,
+    /// Allowed TX rates per band, or the beacon rate when starting an access
+    /// point or joining a mesh
+    TxRates(Vec<Nl80211TxRates>),
+    /// Bitmap of the TIDs sent without requesting an ACK, bit N set for TID N
+    NoackMap(u16),
+    /// Mapping of IP DSCP values to user priorities
+    QosMap(Nl80211QosMap),
+    /// TID configuration supported by the wiphy, the kernel reports
+    /// `NL80211_ATTR_TID_CONFIG` in wiphy dumps with this layout
+    TidConfigSupport(Nl80211TidConfigSupport),
+    /// Array of TID configurations, the layout of `NL80211_ATTR_TID_CONFIG`
+    /// sent to the kernel, never parsed
+    TidConfig(Vec<Nl80211TidConfig>),
+    /// Traffic stream ID, 0 to 7
+    Tsid(u8),
+    /// User priority of the traffic stream, 0 to 7
+    UserPrio(u8),
+    /// Admitted medium time in units of 32 microseconds per second
+    AdmittedTime(u16),
+    /// Convert multicast frames to unicast frames for each associated station
+    MulticastToUnicastEnabled,
+    /// Packet coalescing capabilities of the wiphy, the layout of
+    /// `NL80211_ATTR_COALESCE_RULE` in wiphy dumps
+    CoalesceRuleSupport(Nl80211CoalesceRuleSupport),
+    /// Packet coalesce rules, the layout of `NL80211_ATTR_COALESCE_RULE`
+    /// sent to and reported by `Nl80211Cmd::CoalesceGet`
+    CoalesceRules(Vec<Nl80211CoalesceRule>),
+    /// Critical protocol requiring increased connection reliability
+    CritProtId(Nl80211CritProtoId),
+    /// Duration in milliseconds of the increased connection reliability
+    MaxCritProtDuration(u16),
+    /// NAN master preference used in the master election, 1 to 254
+    NanMasterPref(u8),
+    /// Bitmap of the bands, bit N set for `Nl80211Band` N
+    Bands(u32),
+    /// NAN service discovery function
+    NanFunc(Nl80211NanFunction),
+    /// Match between a local and a peer NAN function
+    NanMatch(Nl80211NanMatch),
+    /// P2P GO opportunistic power save, requires a non-zero Client Traffic
+    /// Window
+    P2pOppPs(bool),
     Other(DefaultNla),
 }
 
@@ -72,13 +601,197 @@ impl Nla for Nl80211Attr {
             | Self::CenterFreq1(_)
             | Self::CenterFreq2(_)
             | Self::WiPhyTxPowerLevel(_)
-            | Self::ChannelWidth(_) => 4,
-            Self::Wdev(_) => 8,
-            Self::IfName(ref s) | Self::Ssid(ref s) => s.len() + 1,
-            Self::Mac(_) => ETH_ALEN,
-            Self::Use4Addr(_) => 1,
+            | Self::SchedScanInterval(_)
+            | Self::ScanFlags(_)
+            | Self::ChannelWidth(_)
+            | Self::WiPhyFreqHint(_)
+            | Self::AuthType(_)
+            | Self::UseMfp(_)
+            | Self::CipherSuiteGroup(_)
+            | Self::WpaVersions(_)
+            | Self::PmkLifetime(_)
+            | Self::BeaconInterval(_)
+            | Self::DtimPeriod(_)
+            | Self::McastRate(_)
+            | Self::Timeout(_)
+            | Self::MacAclMax(_)
+            | Self::AckSignal(_)
+            | Self::RxSignalDbm(_)
+            | Self::Duration(_)
+            | Self::ChSwitchCount(_)
+            | Self::WiPhyFragThreshold(_)
+            | Self::WiPhyRtsThreshold(_)
+            | Self::WiPhyAntennaTx(_)
+            | Self::WiPhyAntennaRx(_)
+            | Self::WiPhyAntennaAvailTx(_)
+            | Self::WiPhyAntennaAvailRx(_)
+            | Self::Pid(_)
+            | Self::NetnsFd(_)
+            | Self::TdlsPeerCapability(_)
+            | Self::Bands(_) => 4,
+            Self::Wdev(_) | Self::Cookie(_) => 8,
+            Self::IfName(ref s)
+            | Self::RegAlpha2(ref s)
+            | Self::WiPhyName(ref s) => s.len() + 1,
+            // SSID is binary data without NULL terminator
+            Self::Ssid(ref s) | Self::MeshId(ref s) => s.len(),
+            Self::Mac(_)
+            | Self::MacMask(_)
+            | Self::MacHint(_)
+            | Self::PrevBssid(_)
+            | Self::MpathNextHop(_) => ETH_ALEN,
+            Self::Use4Addr(_)
+            | Self::SchedScanRelativeRssi(_)
+            | Self::StationPlinkAction(_)
+            | Self::WiPhyEdmgChannels(_)
+            | Self::WiPhyEdmgBwConfig(_)
+            | Self::PmkReauthThreshold(_)
+            | Self::KeyIdx(_)
+            | Self::ApIsolate(_)
+            | Self::P2pCtwindow(_)
+            | Self::ColorChangeCount(_)
+            | Self::ColorChangeColor(_)
+            | Self::WiPhyRetryShort(_)
+            | Self::WiPhyRetryLong(_)
+            | Self::WiPhyCoverageClass(_)
+            | Self::TdlsDialogToken(_)
+            | Self::Tsid(_)
+            | Self::UserPrio(_)
+            | Self::NanMasterPref(_)
+            | Self::P2pOppPs(_) => 1,
+            Self::Ie(ref v) | Self::Frame(ref v) | Self::AuthData(ref v) => {
+                v.len()
+            }
+            Self::ScanSsids(ref ssids) => {
+                scan_ssids_to_nlas(ssids).as_slice().buffer_len()
+            }
+            Self::ScanFrequencies(ref freqs) => {
+                u32_array_to_nlas(freqs).as_slice().buffer_len()
+            }
+            Self::SchedScanMatch(ref sets) => {
+                nested_array(sets).as_slice().buffer_len()
+            }
+            Self::SchedScanRssiAdjust(ref d) => d.buffer_len(),
+            Self::Bss(ref d) => d.buffer_len(),
+            Self::StationInfo(ref d) => d.buffer_len(),
             Self::TransmitQueueStats(ref nlas) => nlas.as_slice().buffer_len(),
             Self::MloLinks(ref links) => links.as_slice().buffer_len(),
+            Self::StationFlags(ref flags) => {
+                station_flags_to_nlas(flags).as_slice().buffer_len()
+            }
+            Self::AirtimeWeight(_)
+            | Self::StatusCode(_)
+            | Self::StationListenInterval(_)
+            | Self::StationAid(_)
+            | Self::InactivityTimeout(_)
+            | Self::FrameType(_)
+            | Self::NoackMap(_)
+            | Self::AdmittedTime(_)
+            | Self::MaxCritProtDuration(_) => 2,
+            Self::StationFlags2(ref d) => d.buffer_len(),
+            Self::SurveyInfo(ref d) => d.buffer_len(),
+            Self::RegRules(ref rules) => {
+                nested_array(rules).as_slice().buffer_len()
+            }
+            Self::DfsRegion(_) => 1,
+            Self::WiPhySelfManagedReg
+            | Self::SplitWiPhyDump
+            | Self::ControlPort
+            | Self::Privacy
+            | Self::LocalStateChange
+            | Self::Want1x4WayHs
+            | Self::KeyDefault
+            | Self::KeyDefaultMgmt
+            | Self::FreqFixed
+            | Self::Ack
+            | Self::ReceiveMulticast
+            | Self::OffchannelTxOk
+            | Self::TxNoCckRate
+            | Self::DontWaitForAck
+            | Self::ChSwitchBlockTx
+            | Self::TdlsSupport
+            | Self::TdlsExternalSetup
+            | Self::TdlsInitiator
+            | Self::MulticastToUnicastEnabled => 0,
+            Self::MonitorFlags(ref d) => d.buffer_len(),
+            Self::WiPhyBands(ref nlas) => nlas.as_slice().buffer_len(),
+            Self::SupportedIftypes(ref d) | Self::SoftwareIftypes(ref d) => {
+                iftypes_to_nlas(d).as_slice().buffer_len()
+            }
+            Self::SupportedCommands(ref cmds) => {
+                u32_array_to_nlas(&cmds_to_u32(cmds))
+                    .as_slice()
+                    .buffer_len()
+            }
+            Self::ExtFeatures(ref d) => d.buffer_len(),
+            Self::ExtCapa(ref d) | Self::ExtCapaMask(ref d) => d.buffer_len(),
+            Self::IftypeExtCapa(ref d) => {
+                nested_array(d).as_slice().buffer_len()
+            }
+            Self::WowlanTriggersSupported(ref d) => d.buffer_len(),
+            Self::WowlanTriggers(ref d) => d.buffer_len(),
+            Self::CipherSuitesPairwise(ref d) => d.len() * 4,
+            Self::AkmSuites(ref d) => d.len() * 4,
+            Self::SaePassword(ref v)
+            | Self::Pmk(ref v)
+            | Self::PmkR0Name(ref v)
+            | Self::Pmkid(ref v)
+            | Self::KeyData(ref v)
+            | Self::KeySeq(ref v)
+            | Self::BssBasicRates(ref v)
+            | Self::StationSupportedRates(ref v)
+            | Self::BeaconHead(ref v)
+            | Self::BeaconTail(ref v)
+            | Self::IeProbeResp(ref v)
+            | Self::IeAssocResp(ref v)
+            | Self::ProbeResp(ref v)
+            | Self::FrameMatch(ref v) => v.len(),
+            Self::KeyCipher(_) => 4,
+            Self::KeyType(_) => 4,
+            Self::Key(ref d) => d.buffer_len(),
+            Self::Keys(ref d) => nested_array(d).as_slice().buffer_len(),
+            Self::MeshConfig(ref d) => d.buffer_len(),
+            Self::MeshSetup(ref d) => d.buffer_len(),
+            Self::MpathInfo(ref d) => d.buffer_len(),
+            Self::StationPlinkState(_) => 1,
+            Self::HiddenSsid(_) => 4,
+            Self::WiPhyTxqParams(ref d) => {
+                nested_array(d).as_slice().buffer_len()
+            }
+            Self::FtmResponderStats(ref d) => d.buffer_len(),
+            Self::PeerMeasurements(ref d) => d.buffer_len(),
+            Self::UnsolBcastProbeResp(ref d) => d.buffer_len(),
+            Self::FilsDiscovery(ref d) => d.buffer_len(),
+            Self::CntdwnOffsBeacon(ref d) => d.len() * 2,
+            Self::CntdwnOffsPresp(ref d) => d.len() * 2,
+            Self::ColorChangeElems(ref d) => d.as_slice().buffer_len(),
+            Self::HeObssPd(ref d) => d.buffer_len(),
+            Self::HeBssColor(ref d) => d.buffer_len(),
+            Self::MbssidConfig(ref d) => d.buffer_len(),
+            Self::MbssidElems(ref d) => {
+                bytes_array_to_nlas(d).as_slice().buffer_len()
+            }
+            Self::AclPolicy(_) => 4,
+            Self::MacAddrs(ref d) => {
+                mac_addrs_to_nlas(d).as_slice().buffer_len()
+            }
+            Self::CsaIes(ref d) => d.as_slice().buffer_len(),
+            Self::ProtocolFeatures(_) => 4,
+            Self::PsState(_) => 4,
+            Self::Cqm(ref d) => d.buffer_len(),
+            Self::TdlsAction(_) => 1,
+            Self::TdlsOperation(_) => 1,
+            Self::TxRates(ref nlas) => nlas.as_slice().buffer_len(),
+            Self::QosMap(ref d) => d.buffer_len(),
+            Self::TidConfigSupport(ref d) => d.buffer_len(),
+            Self::TidConfig(ref d) => nested_array(d).as_slice().buffer_len(),
+            Self::CoalesceRuleSupport(ref d) => d.buffer_len(),
+            Self::CoalesceRules(ref d) => {
+                nested_array(d).as_slice().buffer_len()
+            }
+            Self::CritProtId(_) => 2,
+            Self::NanFunc(ref d) => d.buffer_len(),
+            Self::NanMatch(ref d) => d.buffer_len(),
             Self::Other(attr) => attr.value_len(),
         }
     }
@@ -90,6 +803,7 @@ impl Nla for Nl80211Attr {
             Self::IfName(_) => NL80211_ATTR_IFNAME,
             Self::IfType(_) => NL80211_ATTR_IFTYPE,
             Self::Mac(_) => NL80211_ATTR_MAC,
+            Self::MacMask(_) => NL80211_ATTR_MAC_MASK,
             Self::Wdev(_) => NL80211_ATTR_WDEV,
             Self::Generation(_) => NL80211_ATTR_GENERATION,
             Self::Use4Addr(_) => NL80211_ATTR_4ADDR,
@@ -101,8 +815,175 @@ impl Nla for Nl80211Attr {
             Self::CenterFreq2(_) => NL80211_ATTR_CENTER_FREQ2,
             Self::WiPhyTxPowerLevel(_) => NL80211_ATTR_WIPHY_TX_POWER_LEVEL,
             Self::Ssid(_) => NL80211_ATTR_SSID,
+            Self::Ie(_) => NL80211_ATTR_IE,
+            Self::ScanSsids(_) => NL80211_ATTR_SCAN_SSIDS,
+            Self::ScanFrequencies(_) => NL80211_ATTR_SCAN_FREQUENCIES,
+            Self::ScanFlags(_) => NL80211_ATTR_SCAN_FLAGS,
+            Self::SchedScanInterval(_) => NL80211_ATTR_SCHED_SCAN_INTERVAL,
+            Self::SchedScanMatch(_) => NL80211_ATTR_SCHED_SCAN_MATCH,
+            Self::SchedScanRelativeRssi(_) => {
+                NL80211_ATTR_SCHED_SCAN_RELATIVE_RSSI
+            }
+            Self::SchedScanRssiAdjust(_) => NL80211_ATTR_SCHED_SCAN_RSSI_ADJUST,
+            Self::Bss(_) => NL80211_ATTR_BSS,
+            Self::StationInfo(_) => NL80211_ATTR_STA_INFO,
             Self::TransmitQueueStats(_) => NL80211_ATTR_TXQ_STATS,
             Self::MloLinks(_) => NL80211_ATTR_MLO_LINKS,
+            Self::StationFlags(_) => NL80211_ATTR_STA_FLAGS,
+            Self::StationPlinkAction(_) => NL80211_ATTR_STA_PLINK_ACTION,
+            Self::AirtimeWeight(_) => NL80211_ATTR_AIRTIME_WEIGHT,
+            Self::StationFlags2(_) => NL80211_ATTR_STA_FLAGS2,
+            Self::SurveyInfo(_) => NL80211_ATTR_SURVEY_INFO,
+            Self::RegAlpha2(_) => NL80211_ATTR_REG_ALPHA2,
+            Self::RegRules(_) => NL80211_ATTR_REG_RULES,
+            Self::DfsRegion(_) => NL80211_ATTR_DFS_REGION,
+            Self::WiPhySelfManagedReg => NL80211_ATTR_WIPHY_SELF_MANAGED_REG,
+            Self::MonitorFlags(_) => NL80211_ATTR_MNTR_FLAGS,
+            Self::WiPhyName(_) => NL80211_ATTR_WIPHY_NAME,
+            Self::SplitWiPhyDump => NL80211_ATTR_SPLIT_WIPHY_DUMP,
+            Self::WiPhyBands(_) => NL80211_ATTR_WIPHY_BANDS,
+            Self::WiPhyEdmgChannels(_) => NL80211_ATTR_WIPHY_EDMG_CHANNELS,
+            Self::WiPhyEdmgBwConfig(_) => NL80211_ATTR_WIPHY_EDMG_BW_CONFIG,
+            Self::SupportedIftypes(_) => NL80211_ATTR_SUPPORTED_IFTYPES,
+            Self::SoftwareIftypes(_) => NL80211_ATTR_SOFTWARE_IFTYPES,
+            Self::SupportedCommands(_) => NL80211_ATTR_SUPPORTED_COMMANDS,
+            Self::ExtFeatures(_) => NL80211_ATTR_EXT_FEATURES,
+            Self::ExtCapa(_) => NL80211_ATTR_EXT_CAPA,
+            Self::ExtCapaMask(_) => NL80211_ATTR_EXT_CAPA_MASK,
+            Self::IftypeExtCapa(_) => NL80211_ATTR_IFTYPE_EXT_CAPA,
+            Self::WowlanTriggersSupported(_) => {
+                NL80211_ATTR_WOWLAN_TRIGGERS_SUPPORTED
+            }
+            Self::WowlanTriggers(_) => NL80211_ATTR_WOWLAN_TRIGGERS,
+            Self::AuthType(_) => NL80211_ATTR_AUTH_TYPE,
+            Self::MacHint(_) => NL80211_ATTR_MAC_HINT,
+            Self::WiPhyFreqHint(_) => NL80211_ATTR_WIPHY_FREQ_HINT,
+            Self::ControlPort => NL80211_ATTR_CONTROL_PORT,
+            Self::Privacy => NL80211_ATTR_PRIVACY,
+            Self::CipherSuitesPairwise(_) => {
+                NL80211_ATTR_CIPHER_SUITES_PAIRWISE
+            }
+            Self::CipherSuiteGroup(_) => NL80211_ATTR_CIPHER_SUITE_GROUP,
+            Self::WpaVersions(_) => NL80211_ATTR_WPA_VERSIONS,
+            Self::AkmSuites(_) => NL80211_ATTR_AKM_SUITES,
+            Self::Frame(_) => NL80211_ATTR_FRAME,
+            Self::LocalStateChange => NL80211_ATTR_LOCAL_STATE_CHANGE,
+            Self::AuthData(_) => NL80211_ATTR_AUTH_DATA,
+            Self::UseMfp(_) => NL80211_ATTR_USE_MFP,
+            Self::PrevBssid(_) => NL80211_ATTR_PREV_BSSID,
+            Self::SaePassword(_) => NL80211_ATTR_SAE_PASSWORD,
+            Self::Pmk(_) => NL80211_ATTR_PMK,
+            Self::Want1x4WayHs => NL80211_ATTR_WANT_1X_4WAY_HS,
+            Self::PmkR0Name(_) => NL80211_ATTR_PMKR0_NAME,
+            Self::StatusCode(_) => NL80211_ATTR_STATUS_CODE,
+            Self::Pmkid(_) => NL80211_ATTR_PMKID,
+            Self::PmkLifetime(_) => NL80211_ATTR_PMK_LIFETIME,
+            Self::PmkReauthThreshold(_) => NL80211_ATTR_PMK_REAUTH_THRESHOLD,
+            Self::KeyData(_) => NL80211_ATTR_KEY_DATA,
+            Self::KeyIdx(_) => NL80211_ATTR_KEY_IDX,
+            Self::KeyCipher(_) => NL80211_ATTR_KEY_CIPHER,
+            Self::KeySeq(_) => NL80211_ATTR_KEY_SEQ,
+            Self::KeyDefault => NL80211_ATTR_KEY_DEFAULT,
+            Self::KeyDefaultMgmt => NL80211_ATTR_KEY_DEFAULT_MGMT,
+            Self::KeyType(_) => NL80211_ATTR_KEY_TYPE,
+            Self::Key(_) => NL80211_ATTR_KEY,
+            Self::Keys(_) => NL80211_ATTR_KEYS,
+            Self::BeaconInterval(_) => NL80211_ATTR_BEACON_INTERVAL,
+            Self::BssBasicRates(_) => NL80211_ATTR_BSS_BASIC_RATES,
+            Self::FreqFixed => NL80211_ATTR_FREQ_FIXED,
+            Self::MeshId(_) => NL80211_ATTR_MESH_ID,
+            Self::MeshConfig(_) => NL80211_ATTR_MESH_CONFIG,
+            Self::MeshSetup(_) => NL80211_ATTR_MESH_SETUP,
+            Self::DtimPeriod(_) => NL80211_ATTR_DTIM_PERIOD,
+            Self::McastRate(_) => NL80211_ATTR_MCAST_RATE,
+            Self::MpathNextHop(_) => NL80211_ATTR_MPATH_NEXT_HOP,
+            Self::MpathInfo(_) => NL80211_ATTR_MPATH_INFO,
+            Self::StationPlinkState(_) => NL80211_ATTR_STA_PLINK_STATE,
+            Self::StationSupportedRates(_) => NL80211_ATTR_STA_SUPPORTED_RATES,
+            Self::StationListenInterval(_) => NL80211_ATTR_STA_LISTEN_INTERVAL,
+            Self::StationAid(_) => NL80211_ATTR_STA_AID,
+            Self::BeaconHead(_) => NL80211_ATTR_BEACON_HEAD,
+            Self::BeaconTail(_) => NL80211_ATTR_BEACON_TAIL,
+            Self::IeProbeResp(_) => NL80211_ATTR_IE_PROBE_RESP,
+            Self::IeAssocResp(_) => NL80211_ATTR_IE_ASSOC_RESP,
+            Self::ProbeResp(_) => NL80211_ATTR_PROBE_RESP,
+            Self::HiddenSsid(_) => NL80211_ATTR_HIDDEN_SSID,
+            Self::ApIsolate(_) => NL80211_ATTR_AP_ISOLATE,
+            Self::InactivityTimeout(_) => NL80211_ATTR_INACTIVITY_TIMEOUT,
+            Self::P2pCtwindow(_) => NL80211_ATTR_P2P_CTWINDOW,
+            Self::WiPhyTxqParams(_) => NL80211_ATTR_WIPHY_TXQ_PARAMS,
+            Self::FtmResponderStats(_) => NL80211_ATTR_FTM_RESPONDER_STATS,
+            Self::Cookie(_) => NL80211_ATTR_COOKIE,
+            Self::Timeout(_) => NL80211_ATTR_TIMEOUT,
+            Self::PeerMeasurements(_) => NL80211_ATTR_PEER_MEASUREMENTS,
+            Self::UnsolBcastProbeResp(_) => NL80211_ATTR_UNSOL_BCAST_PROBE_RESP,
+            Self::FilsDiscovery(_) => NL80211_ATTR_FILS_DISCOVERY,
+            Self::CntdwnOffsBeacon(_) => NL80211_ATTR_CNTDWN_OFFS_BEACON,
+            Self::CntdwnOffsPresp(_) => NL80211_ATTR_CNTDWN_OFFS_PRESP,
+            Self::ColorChangeCount(_) => NL80211_ATTR_COLOR_CHANGE_COUNT,
+            Self::ColorChangeColor(_) => NL80211_ATTR_COLOR_CHANGE_COLOR,
+            Self::ColorChangeElems(_) => NL80211_ATTR_COLOR_CHANGE_ELEMS,
+            Self::HeObssPd(_) => NL80211_ATTR_HE_OBSS_PD,
+            Self::HeBssColor(_) => NL80211_ATTR_HE_BSS_COLOR,
+            Self::MbssidConfig(_) => NL80211_ATTR_MBSSID_CONFIG,
+            Self::MbssidElems(_) => NL80211_ATTR_MBSSID_ELEMS,
+            Self::AclPolicy(_) => NL80211_ATTR_ACL_POLICY,
+            Self::MacAddrs(_) => NL80211_ATTR_MAC_ADDRS,
+            Self::MacAclMax(_) => NL80211_ATTR_MAC_ACL_MAX,
+            Self::Ack => NL80211_ATTR_ACK,
+            Self::AckSignal(_) => NL80211_ATTR_ACK_SIGNAL,
+            Self::RxSignalDbm(_) => NL80211_ATTR_RX_SIGNAL_DBM,
+            Self::FrameMatch(_) => NL80211_ATTR_FRAME_MATCH,
+            Self::FrameType(_) => NL80211_ATTR_FRAME_TYPE,
+            Self::ReceiveMulticast => NL80211_ATTR_RECEIVE_MULTICAST,
+            Self::Duration(_) => NL80211_ATTR_DURATION,
+            Self::OffchannelTxOk => NL80211_ATTR_OFFCHANNEL_TX_OK,
+            Self::TxNoCckRate => NL80211_ATTR_TX_NO_CCK_RATE,
+            Self::DontWaitForAck => NL80211_ATTR_DONT_WAIT_FOR_ACK,
+            Self::ChSwitchCount(_) => NL80211_ATTR_CH_SWITCH_COUNT,
+            Self::ChSwitchBlockTx => NL80211_ATTR_CH_SWITCH_BLOCK_TX,
+            Self::CsaIes(_) => NL80211_ATTR_CSA_IES,
+            Self::WiPhyRetryShort(_) => NL80211_ATTR_WIPHY_RETRY_SHORT,
+            Self::WiPhyRetryLong(_) => NL80211_ATTR_WIPHY_RETRY_LONG,
+            Self::WiPhyFragThreshold(_) => NL80211_ATTR_WIPHY_FRAG_THRESHOLD,
+            Self::WiPhyRtsThreshold(_) => NL80211_ATTR_WIPHY_RTS_THRESHOLD,
+            Self::WiPhyCoverageClass(_) => NL80211_ATTR_WIPHY_COVERAGE_CLASS,
+            Self::WiPhyAntennaTx(_) => NL80211_ATTR_WIPHY_ANTENNA_TX,
+            Self::WiPhyAntennaRx(_) => NL80211_ATTR_WIPHY_ANTENNA_RX,
+            Self::WiPhyAntennaAvailTx(_) => NL80211_ATTR_WIPHY_ANTENNA_AVAIL_TX,
+            Self::WiPhyAntennaAvailRx(_) => NL80211_ATTR_WIPHY_ANTENNA_AVAIL_RX,
+            Self::Pid(_) => NL80211_ATTR_PID,
+            Self::NetnsFd(_) => NL80211_ATTR_NETNS_FD,
+            Self::ProtocolFeatures(_) => NL80211_ATTR_PROTOCOL_FEATURES,
+            Self::PsState(_) => NL80211_ATTR_PS_STATE,
+            Self::Cqm(_) => NL80211_ATTR_CQM,
+            Self::TdlsAction(_) => NL80211_ATTR_TDLS_ACTION,
+            Self::TdlsDialogToken(_) => NL80211_ATTR_TDLS_DIALOG_TOKEN,
+            Self::TdlsOperation(_) => NL80211_ATTR_TDLS_OPERATION,
+            Self::TdlsSupport => NL80211_ATTR_TDLS_SUPPORT,
+            Self::TdlsExternalSetup => NL80211_ATTR_TDLS_EXTERNAL_SETUP,
+            Self::TdlsPeerCapability(_) => NL80211_ATTR_TDLS_PEER_CAPABILITY,
+            Self::TdlsInitiator => NL80211_ATTR_TDLS_INITIATOR,
+            Self::TxRates(_) => NL80211_ATTR_TX_RATES,
+            Self::NoackMap(_) => NL80211_ATTR_NOACK_MAP,
+            Self::QosMap(_) => NL80211_ATTR_QOS_MAP,
+            Self::TidConfigSupport(_) => NL80211_ATTR_TID_CONFIG,
+            Self::TidConfig(_) => NL80211_ATTR_TID_CONFIG,
+            Self::Tsid(_) => NL80211_ATTR_TSID,
+            Self::UserPrio(_) => NL80211_ATTR_USER_PRIO,
+            Self::AdmittedTime(_) => NL80211_ATTR_ADMITTED_TIME,
+            Self::MulticastToUnicastEnabled => {
+                NL80211_ATTR_MULTICAST_TO_UNICAST_ENABLED
+            }
+            Self::CoalesceRuleSupport(_) => NL80211_ATTR_COALESCE_RULE,
+            Self::CoalesceRules(_) => NL80211_ATTR_COALESCE_RULE,
+            Self::CritProtId(_) => NL80211_ATTR_CRIT_PROT_ID,
+            Self::MaxCritProtDuration(_) => NL80211_ATTR_MAX_CRIT_PROT_DURATION,
+            Self::NanMasterPref(_) => NL80211_ATTR_NAN_MASTER_PREF,
+            Self::Bands(_) => NL80211_ATTR_BANDS,
+            Self::NanFunc(_) => NL80211_ATTR_NAN_FUNC,
+            Self::NanMatch(_) => NL80211_ATTR_NAN_MATCH,
+            Self::P2pOppPs(_) => NL80211_ATTR_P2P_OPPPS,
             Self::Other(attr) => attr.kind(),
         }
     }
@@ -116,23 +997,232 @@ impl Nla for Nl80211Attr {
             | Self::WiPhyFreqOffset(d)
             | Self::CenterFreq1(d)
             | Self::CenterFreq2(d)
-            | Self::WiPhyTxPowerLevel(d) => NativeEndian::write_u32(buffer, *d),
-            Self::Wdev(d) => NativeEndian::write_u64(buffer, *d),
+            | Self::WiPhyTxPowerLevel(d)
+            | Self::SchedScanInterval(d)
+            | Self::WiPhyFreqHint(d)
+            | Self::PmkLifetime(d)
+            | Self::BeaconInterval(d)
+            | Self::DtimPeriod(d)
+            | Self::McastRate(d)
+            | Self::Timeout(d)
+            | Self::MacAclMax(d)
+            | Self::Duration(d)
+            | Self::ChSwitchCount(d)
+            | Self::WiPhyFragThreshold(d)
+            | Self::WiPhyRtsThreshold(d)
+            | Self::WiPhyAntennaTx(d)
+            | Self::WiPhyAntennaRx(d)
+            | Self::WiPhyAntennaAvailTx(d)
+            | Self::WiPhyAntennaAvailRx(d) => {
+                NativeEndian::write_u32(buffer, *d)
+            }
+            Self::Wdev(d) | Self::Cookie(d) => {
+                NativeEndian::write_u64(buffer, *d)
+            }
             Self::IfType(d) => NativeEndian::write_u32(buffer, (*d).into()),
-            Self::Mac(ref s) => buffer.copy_from_slice(s),
-            Self::IfName(ref s) | Self::Ssid(ref s) => {
+            Self::Mac(ref s)
+            | Self::MacMask(ref s)
+            | Self::MacHint(ref s)
+            | Self::PrevBssid(ref s)
+            | Self::MpathNextHop(ref s) => buffer.copy_from_slice(s),
+            Self::IfName(ref s) => {
                 buffer[..s.len()].copy_from_slice(s.as_bytes());
                 buffer[s.len()] = 0;
             }
-            Self::Use4Addr(d) => buffer[0] = *d as u8,
+            Self::Ssid(ref s) | Self::MeshId(ref s) => {
+                buffer[..s.len()].copy_from_slice(s.as_bytes())
+            }
+            Self::Use4Addr(d) | Self::ApIsolate(d) | Self::P2pOppPs(d) => {
+                buffer[0] = *d as u8
+            }
+            Self::Ie(ref v) | Self::Frame(ref v) | Self::AuthData(ref v) => {
+                buffer[..v.len()].copy_from_slice(v)
+            }
+            Self::ScanSsids(ref ssids) => {
+                scan_ssids_to_nlas(ssids).as_slice().emit(buffer)
+            }
+            Self::ScanFrequencies(ref freqs) => {
+                u32_array_to_nlas(freqs).as_slice().emit(buffer)
+            }
             Self::WiPhyChannelType(d) => {
                 NativeEndian::write_u32(buffer, (*d).into())
             }
             Self::ChannelWidth(d) => {
                 NativeEndian::write_u32(buffer, (*d).into())
             }
+            Self::ScanFlags(d) => NativeEndian::write_u32(buffer, (*d).into()),
+            Self::SchedScanMatch(ref sets) => {
+                nested_array(sets).as_slice().emit(buffer)
+            }
+            Self::SchedScanRelativeRssi(d) => buffer[0] = *d as u8,
+            Self::SchedScanRssiAdjust(ref d) => d.emit(buffer),
+            Self::Bss(ref d) => d.emit(buffer),
+            Self::StationInfo(ref d) => d.emit(buffer),
             Self::TransmitQueueStats(ref nlas) => nlas.as_slice().emit(buffer),
             Self::MloLinks(ref links) => links.as_slice().emit(buffer),
+            Self::StationFlags(ref flags) => {
+                station_flags_to_nlas(flags).as_slice().emit(buffer)
+            }
+            Self::StationPlinkAction(d) => buffer[0] = (*d).into(),
+            Self::AirtimeWeight(d)
+            | Self::StatusCode(d)
+            | Self::StationListenInterval(d)
+            | Self::StationAid(d)
+            | Self::InactivityTimeout(d)
+            | Self::FrameType(d)
+            | Self::NoackMap(d)
+            | Self::AdmittedTime(d)
+            | Self::MaxCritProtDuration(d) => {
+                NativeEndian::write_u16(buffer, *d)
+            }
+            Self::StationFlags2(ref d) => d.emit(buffer),
+            Self::SurveyInfo(ref d) => d.emit(buffer),
+            Self::RegAlpha2(ref s) => {
+                buffer[..s.len()].copy_from_slice(s.as_bytes());
+                buffer[s.len()] = 0;
+            }
+            Self::RegRules(ref rules) => {
+                nested_array(rules).as_slice().emit(buffer)
+            }
+            Self::DfsRegion(d) => buffer[0] = (*d).into(),
+            Self::WiPhySelfManagedReg
+            | Self::SplitWiPhyDump
+            | Self::ControlPort
+            | Self::Privacy
+            | Self::LocalStateChange
+            | Self::Want1x4WayHs
+            | Self::KeyDefault
+            | Self::KeyDefaultMgmt
+            | Self::FreqFixed
+            | Self::Ack
+            | Self::ReceiveMulticast
+            | Self::OffchannelTxOk
+            | Self::TxNoCckRate
+            | Self::DontWaitForAck
+            | Self::ChSwitchBlockTx
+            | Self::TdlsSupport
+            | Self::TdlsExternalSetup
+            | Self::TdlsInitiator
+            | Self::MulticastToUnicastEnabled => (),
+            Self::MonitorFlags(ref d) => d.emit(buffer),
+            Self::WiPhyName(ref s) => {
+                buffer[..s.len()].copy_from_slice(s.as_bytes());
+                buffer[s.len()] = 0;
+            }
+            Self::WiPhyBands(ref nlas) => nlas.as_slice().emit(buffer),
+            Self::WiPhyEdmgChannels(d) | Self::WiPhyEdmgBwConfig(d) => {
+                buffer[0] = *d
+            }
+            Self::SupportedIftypes(ref d) | Self::SoftwareIftypes(ref d) => {
+                iftypes_to_nlas(d).as_slice().emit(buffer)
+            }
+            Self::SupportedCommands(ref cmds) => {
+                u32_array_to_nlas(&cmds_to_u32(cmds))
+                    .as_slice()
+                    .emit(buffer)
+            }
+            Self::ExtFeatures(ref d) => d.emit(buffer),
+            Self::ExtCapa(ref d) | Self::ExtCapaMask(ref d) => d.emit(buffer),
+            Self::IftypeExtCapa(ref d) => {
+                nested_array(d).as_slice().emit(buffer)
+            }
+            Self::WowlanTriggersSupported(ref d) => d.emit(buffer),
+            Self::WowlanTriggers(ref d) => d.emit(buffer),
+            Self::AuthType(d) => NativeEndian::write_u32(buffer, (*d).into()),
+            Self::CipherSuitesPairwise(ref d) => emit_u32_array(d, buffer),
+            Self::CipherSuiteGroup(d) => {
+                NativeEndian::write_u32(buffer, (*d).into())
+            }
+            Self::WpaVersions(d) => {
+                NativeEndian::write_u32(buffer, (*d).into())
+            }
+            Self::AkmSuites(ref d) => emit_u32_array(d, buffer),
+            Self::UseMfp(d) => NativeEndian::write_u32(buffer, (*d).into()),
+            Self::SaePassword(ref v) | Self::Pmk(ref v) => {
+                buffer[..v.len()].copy_from_slice(v)
+            }
+            Self::PmkR0Name(ref v) | Self::Pmkid(ref v) => {
+                buffer[..v.len()].copy_from_slice(v)
+            }
+            Self::PmkReauthThreshold(d)
+            | Self::KeyIdx(d)
+            | Self::P2pCtwindow(d)
+            | Self::ColorChangeCount(d)
+            | Self::ColorChangeColor(d)
+            | Self::WiPhyRetryShort(d)
+            | Self::WiPhyRetryLong(d)
+            | Self::WiPhyCoverageClass(d)
+            | Self::TdlsDialogToken(d)
+            | Self::Tsid(d)
+            | Self::UserPrio(d)
+            | Self::NanMasterPref(d) => buffer[0] = *d,
+            Self::KeyData(ref v)
+            | Self::KeySeq(ref v)
+            | Self::BssBasicRates(ref v)
+            | Self::StationSupportedRates(ref v)
+            | Self::BeaconHead(ref v)
+            | Self::BeaconTail(ref v)
+            | Self::IeProbeResp(ref v)
+            | Self::IeAssocResp(ref v)
+            | Self::ProbeResp(ref v)
+            | Self::FrameMatch(ref v) => buffer[..v.len()].copy_from_slice(v),
+            Self::KeyCipher(d) => NativeEndian::write_u32(buffer, (*d).into()),
+            Self::KeyType(d) => NativeEndian::write_u32(buffer, (*d).into()),
+            Self::Key(ref d) => d.emit(buffer),
+            Self::Keys(ref d) => nested_array(d).as_slice().emit(buffer),
+            Self::MeshConfig(ref d) => d.emit(buffer),
+            Self::MeshSetup(ref d) => d.emit(buffer),
+            Self::MpathInfo(ref d) => d.emit(buffer),
+            Self::StationPlinkState(d) => buffer[0] = (*d).into(),
+            Self::HiddenSsid(d) => NativeEndian::write_u32(buffer, (*d).into()),
+            Self::WiPhyTxqParams(ref d) => {
+                nested_array(d).as_slice().emit(buffer)
+            }
+            Self::FtmResponderStats(ref d) => d.emit(buffer),
+            Self::PeerMeasurements(ref d) => d.emit(buffer),
+            Self::UnsolBcastProbeResp(ref d) => d.emit(buffer),
+            Self::FilsDiscovery(ref d) => d.emit(buffer),
+            Self::CntdwnOffsBeacon(ref d) => emit_u16_array(d, buffer),
+            Self::CntdwnOffsPresp(ref d) => emit_u16_array(d, buffer),
+            Self::ColorChangeElems(ref d) => d.as_slice().emit(buffer),
+            Self::HeObssPd(ref d) => d.emit(buffer),
+            Self::HeBssColor(ref d) => d.emit(buffer),
+            Self::MbssidConfig(ref d) => d.emit(buffer),
+            Self::MbssidElems(ref d) => {
+                bytes_array_to_nlas(d).as_slice().emit(buffer)
+            }
+            Self::AclPolicy(d) => NativeEndian::write_u32(buffer, (*d).into()),
+            Self::MacAddrs(ref d) => {
+                mac_addrs_to_nlas(d).as_slice().emit(buffer)
+            }
+            Self::AckSignal(d) | Self::RxSignalDbm(d) => {
+                NativeEndian::write_i32(buffer, *d)
+            }
+            Self::CsaIes(ref d) => d.as_slice().emit(buffer),
+            Self::Pid(d) | Self::NetnsFd(d) => {
+                NativeEndian::write_u32(buffer, *d)
+            }
+            Self::ProtocolFeatures(d) => {
+                NativeEndian::write_u32(buffer, (*d).into())
+            }
+            Self::PsState(d) => NativeEndian::write_u32(buffer, (*d).into()),
+            Self::Cqm(ref d) => d.emit(buffer),
+            Self::TdlsAction(d) => buffer[0] = (*d).into(),
+            Self::TdlsOperation(d) => buffer[0] = (*d).into(),
+            Self::TdlsPeerCapability(d) | Self::Bands(d) => {
+                NativeEndian::write_u32(buffer, *d)
+            }
+            Self::TxRates(ref nlas) => nlas.as_slice().emit(buffer),
+            Self::QosMap(ref d) => d.emit(buffer),
+            Self::TidConfigSupport(ref d) => d.emit(buffer),
+            Self::TidConfig(ref d) => nested_array(d).as_slice().emit(buffer),
+            Self::CoalesceRuleSupport(ref d) => d.emit(buffer),
+            Self::CoalesceRules(ref d) => {
+                nested_array(d).as_slice().emit(buffer)
+            }
+            Self::CritProtId(d) => NativeEndian::write_u16(buffer, (*d).into()),
+            Self::NanFunc(ref d) => d.emit(buffer),
+            Self::NanMatch(ref d) => d.emit(buffer),
             Self::Other(ref attr) => attr.emit(buffer),
         }
     }
@@ -178,6 +1268,13 @@ impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for Nl80211Attr {
                 )
                 .into());
             }),
+            NL80211_ATTR_MAC_MASK => {
+                let err_msg = format!(
+                    "Invalid NL80211_ATTR_MAC_MASK value {:?}",
+                    payload
+                );
+                Self::MacMask(parse_mac(payload).context(err_msg)?)
+            }
             NL80211_ATTR_GENERATION => {
                 let err_msg = format!(
                     "Invalid NL80211_ATTR_GENERATION value {:?}",
@@ -246,6 +1343,78 @@ impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for Nl80211Attr {
                     format!("Invalid NL80211_ATTR_SSID value {:?}", payload);
                 Self::Ssid(parse_string(payload).context(err_msg)?)
             }
+            NL80211_ATTR_IE => Self::Ie(payload.to_vec()),
+            NL80211_ATTR_SCAN_SSIDS => {
+                let err_msg = format!(
+                    "Invalid NL80211_ATTR_SCAN_SSIDS value {:?}",
+                    payload
+                );
+                let mut ssids = Vec::new();
+                for nla in NlasIterator::new(payload) {
+                    let nla = &nla.context(err_msg.clone())?;
+                    ssids
+                        .push(String::from_utf8_lossy(nla.value()).to_string());
+                }
+                Self::ScanSsids(ssids)
+            }
+            NL80211_ATTR_SCAN_FREQUENCIES => {
+                let err_msg = format!(
+                    "Invalid NL80211_ATTR_SCAN_FREQUENCIES value {:?}",
+                    payload
+                );
+                let mut freqs = Vec::new();
+                for nla in NlasIterator::new(payload) {
+                    let nla = &nla.context(err_msg.clone())?;
+                    freqs
+                        .push(parse_u32(nla.value()).context(err_msg.clone())?);
+                }
+                Self::ScanFrequencies(freqs)
+            }
+            NL80211_ATTR_SCAN_FLAGS => {
+                let err_msg = format!(
+                    "Invalid NL80211_ATTR_SCAN_FLAGS value {:?}",
+                    payload
+                );
+                Self::ScanFlags(parse_u32(payload).context(err_msg)?.into())
+            }
+            NL80211_ATTR_SCHED_SCAN_INTERVAL => {
+                let err_msg = format!(
+                    "Invalid NL80211_ATTR_SCHED_SCAN_INTERVAL value {:?}",
+                    payload
+                );
+                Self::SchedScanInterval(parse_u32(payload).context(err_msg)?)
+            }
+            NL80211_ATTR_SCHED_SCAN_MATCH => {
+                let err_msg = format!(
+                    "Invalid NL80211_ATTR_SCHED_SCAN_MATCH value {:?}",
+                    payload
+                );
+                let mut sets = Vec::new();
+                for nla in NlasIterator::new(payload) {
+                    let nla = &nla.context(err_msg.clone())?;
+                    sets.push(
+                        Nl80211SchedScanMatchSet::parse(nla)
+                            .context(err_msg.clone())?,
+                    );
+                }
+                Self::SchedScanMatch(sets)
+            }
+            NL80211_ATTR_SCHED_SCAN_RELATIVE_RSSI => {
+                let err_msg = format!(
+                    "Invalid NL80211_ATTR_SCHED_SCAN_RELATIVE_RSSI value {:?}",
+                    payload
+                );
+                Self::SchedScanRelativeRssi(
+                    parse_u8(payload).context(err_msg)? as i8,
+                )
+            }
+            NL80211_ATTR_SCHED_SCAN_RSSI_ADJUST => {
+                Self::SchedScanRssiAdjust(Nl80211RssiAdjust::parse(payload)?)
+            }
+            NL80211_ATTR_BSS => Self::Bss(Nl80211Bss::parse(buf)?),
+            NL80211_ATTR_STA_INFO => {
+                Self::StationInfo(Nl80211StationInfo::parse(buf)?)
+            }
             NL80211_ATTR_TXQ_STATS => {
                 let err_msg = format!(
                     "Invalid NL80211_ATTR_TXQ_STATS value {:?}",
@@ -275,127 +1444,1063 @@ impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for Nl80211Attr {
                 }
                 Self::MloLinks(links)
             }
-            _ => Self::Other(
-                DefaultNla::parse(buf).context("invalid NLA (unknown kind)")?,
-            ),
-        })
-    }
-}
-
-#[derive(Debug, PartialEq, Eq, Clone)]
-pub enum Nl80211MloLinkNla {
-    Id(u8),
-    Mac([u8; ETH_ALEN]),
-    Other(DefaultNla),
-}
-
-impl Nla for Nl80211MloLinkNla {
-    fn value_len(&self) -> usize {
-        match self {
-            Self::Id(_) => 1,
-            Self::Mac(_) => ETH_ALEN,
-            Self::Other(attr) => attr.value_len(),
-        }
-    }
-
-    fn kind(&self) -> u16 {
-        match self {
-            Self::Id(_) => NL80211_ATTR_MLO_LINK_ID,
-            Self::Mac(_) => NL80211_ATTR_MAC,
-            Self::Other(attr) => attr.kind(),
-        }
-    }
-
-    fn emit_value(&self, buffer: &mut [u8]) {
-        match self {
-            Self::Id(d) => buffer[0] = *d,
-            Self::Mac(ref s) => buffer.copy_from_slice(s),
-            Self::Other(ref attr) => attr.emit(buffer),
-        }
-    }
-}
-
-impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
-    for Nl80211MloLinkNla
-{
-    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
-        let payload = buf.value();
-        Ok(match buf.kind() {
-            NL80211_ATTR_MLO_LINK_ID => {
+            NL80211_ATTR_STA_FLAGS => {
                 let err_msg = format!(
-                    "Invalid NL80211_ATTR_MLO_LINK_ID value {:?}",
+                    "Invalid NL80211_ATTR_STA_FLAGS value {:?}",
                     payload
                 );
-                Self::Id(parse_u8(payload).context(err_msg)?)
+                let mut flags = Vec::new();
+                for nla in NlasIterator::new(payload) {
+                    let nla = &nla.context(err_msg.clone())?;
+                    flags.push(nla.kind().into());
+                }
+                Self::StationFlags(flags)
             }
-            NL80211_ATTR_MAC => Self::Mac(if payload.len() == ETH_ALEN {
-                let mut ret = [0u8; ETH_ALEN];
-                ret.copy_from_slice(&payload[..ETH_ALEN]);
-                ret
-            } else {
-                return Err(format!(
-                    "Invalid length of NL80211_ATTR_MAC, expected length {} got {:?}",
-                    ETH_ALEN, payload
+            NL80211_ATTR_STA_PLINK_ACTION => {
+                let err_msg = format!(
+                    "Invalid NL80211_ATTR_STA_PLINK_ACTION value {:?}",
+                    payload
+                );
+                Self::StationPlinkAction(
+                    parse_u8(payload).context(err_msg)?.into(),
                 )
-                .into());
-            }),
-            _ => Self::Other(
-                DefaultNla::parse(buf).context("invalid NLA (unknown kind)")?,
-            ),
-        })
-    }
-}
-
-#[derive(Debug, PartialEq, Eq, Clone, Default)]
-pub struct Nl80211MloLink {
-    pub id: u8,
-    pub mac: [u8; ETH_ALEN],
-}
-
-impl Nla for Nl80211MloLink {
-    fn value_len(&self) -> usize {
-        Vec::<Nl80211MloLinkNla>::from(self).as_slice().buffer_len()
-    }
-
-    fn kind(&self) -> u16 {
-        self.id as u16 + 1
-    }
-
-    fn emit_value(&self, buffer: &mut [u8]) {
-        Vec::<Nl80211MloLinkNla>::from(self).as_slice().emit(buffer)
-    }
-}
-
-impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
-    for Nl80211MloLink
-{
-    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
-        let mut ret = Self::default();
-        let payload = buf.value();
-        let err_msg =
-            format!("Invalid NL80211_ATTR_MLO_LINKS value {:?}", payload);
-        for nla in NlasIterator::new(payload) {
-            let nla = &nla.context(err_msg.clone())?;
-            match Nl80211MloLinkNla::parse(nla).context(err_msg.clone())? {
-                Nl80211MloLinkNla::Id(d) => ret.id = d,
-                Nl80211MloLinkNla::Mac(s) => ret.mac = s,
-                Nl80211MloLinkNla::Other(attr) => {
-                    log::warn!(
-                        "Got unsupported NL80211_ATTR_MLO_LINKS value {:?}",
-                        attr
-                    )
+            }
+            NL80211_ATTR_AIRTIME_WEIGHT => {
+                let err_msg = format!(
+                    "Invalid NL80211_ATTR_AIRTIME_WEIGHT value {:?}",
+                    payload
+                );
+                Self::AirtimeWeight(parse_u16(payload).context(err_msg)?)
+            }
+            NL80211_ATTR_STA_FLAGS2 => {
+                Self::StationFlags2(Nl80211StationFlagUpdate::parse(payload)?)
+            }
+            NL80211_ATTR_SURVEY_INFO => {
+                Self::SurveyInfo(Nl80211SurveyInfo::parse(buf)?)
+            }
+            NL80211_ATTR_REG_ALPHA2 => {
+                let err_msg = format!(
+                    "Invalid NL80211_ATTR_REG_ALPHA2 value {:?}",
+                    payload
+                );
+                Self::RegAlpha2(parse_string(payload).context(err_msg)?)
+            }
+            NL80211_ATTR_REG_RULES => {
+                let err_msg = format!(
+                    "Invalid NL80211_ATTR_REG_RULES value {:?}",
+                    payload
+                );
+                let mut rules = Vec::new();
+                for nla in NlasIterator::new(payload) {
+                    let nla = &nla.context(err_msg.clone())?;
+                    rules.push(
+                        Nl80211RegRule::parse(nla).context(err_msg.clone())?,
+                    );
                 }
+                Self::RegRules(rules)
             }
-        }
-        Ok(ret)
-    }
-}
-
-impl From<&Nl80211MloLink> for Vec<Nl80211MloLinkNla> {
-    fn from(link: &Nl80211MloLink) -> Self {
-        vec![
-            Nl80211MloLinkNla::Id(link.id),
-            Nl80211MloLinkNla::Mac(link.mac),
-        ]
+            NL80211_ATTR_DFS_REGION => {
+                let err_msg = format!(
+                    "Invalid NL80211_ATTR_DFS_REGION value {:?}",
+                    payload
+                );
+                Self::DfsRegion(parse_u8(payload).context(err_msg)?.into())
+            }
+            NL80211_ATTR_WIPHY_SELF_MANAGED_REG => Self::WiPhySelfManagedReg,
+            NL80211_ATTR_MNTR_FLAGS => {
+                Self::MonitorFlags(Nl80211MonitorFlags::parse(payload)?)
+            }
+            NL80211_ATTR_WIPHY_NAME => {
+                let err_msg = format!(
+                    "Invalid NL80211_ATTR_WIPHY_NAME value {:?}",
+                    payload
+                );
+                Self::WiPhyName(parse_string(payload).context(err_msg)?)
+            }
+            NL80211_ATTR_SPLIT_WIPHY_DUMP => Self::SplitWiPhyDump,
+            NL80211_ATTR_WIPHY_BANDS => {
+                let err_msg = format!(
+                    "Invalid NL80211_ATTR_WIPHY_BANDS value {:?}",
+                    payload
+                );
+                let mut nlas = Vec::new();
+                for nla in NlasIterator::new(payload) {
+                    let nla = &nla.context(err_msg.clone())?;
+                    nlas.push(
+                        Nl80211WiPhyBand::parse(nla)
+                            .context(err_msg.clone())?,
+                    );
+                }
+                Self::WiPhyBands(nlas)
+            }
+            NL80211_ATTR_WIPHY_EDMG_CHANNELS => {
+                let err_msg = format!(
+                    "Invalid NL80211_ATTR_WIPHY_EDMG_CHANNELS value {:?}",
+                    payload
+                );
+                Self::WiPhyEdmgChannels(parse_u8(payload).context(err_msg)?)
+            }
+            NL80211_ATTR_WIPHY_EDMG_BW_CONFIG => {
+                let err_msg = format!(
+                    "Invalid NL80211_ATTR_WIPHY_EDMG_BW_CONFIG value {:?}",
+                    payload
+                );
+                Self::WiPhyEdmgBwConfig(parse_u8(payload).context(err_msg)?)
+            }
+            NL80211_ATTR_SUPPORTED_IFTYPES => {
+                let err_msg = format!(
+                    "Invalid NL80211_ATTR_SUPPORTED_IFTYPES value {:?}",
+                    payload
+                );
+                Self::SupportedIftypes(parse_iftypes(payload).context(err_msg)?)
+            }
+            NL80211_ATTR_SOFTWARE_IFTYPES => {
+                let err_msg = format!(
+                    "Invalid NL80211_ATTR_SOFTWARE_IFTYPES value {:?}",
+                    payload
+                );
+                Self::SoftwareIftypes(parse_iftypes(payload).context(err_msg)?)
+            }
+            NL80211_ATTR_SUPPORTED_COMMANDS => {
+                let err_msg = format!(
+                    "Invalid NL80211_ATTR_SUPPORTED_COMMANDS value {:?}",
+                    payload
+                );
+                let mut cmds = Vec::new();
+                for nla in NlasIterator::new(payload) {
+                    let nla = &nla.context(err_msg.clone())?;
+                    cmds.push(Nl80211Cmd::from(
+                        parse_u32(nla.value()).context(err_msg.clone())? as u8,
+                    ));
+                }
+                Self::SupportedCommands(cmds)
+            }
+            NL80211_ATTR_EXT_FEATURES => {
+                Self::ExtFeatures(Nl80211ExtFeatures::parse(payload)?)
+            }
+            NL80211_ATTR_EXT_CAPA => {
+                Self::ExtCapa(Nl80211ExtendedCapability::parse(payload)?)
+            }
+            NL80211_ATTR_EXT_CAPA_MASK => {
+                Self::ExtCapaMask(Nl80211ExtendedCapability::parse(payload)?)
+            }
+            NL80211_ATTR_IFTYPE_EXT_CAPA => {
+                let err_msg = format!(
+                    "Invalid NL80211_ATTR_IFTYPE_EXT_CAPA value {:?}",
+                    payload
+                );
+                let mut capas = Vec::new();
+                for nla in NlasIterator::new(payload) {
+                    let nla = &nla.context(err_msg.clone())?;
+                    capas.push(
+                        Nl80211IftypeExtCapa::parse(nla)
+                            .context(err_msg.clone())?,
+                    );
+                }
+                Self::IftypeExtCapa(capas)
+            }
+            NL80211_ATTR_WOWLAN_TRIGGERS_SUPPORTED => {
+                Self::WowlanTriggersSupported(
+                    Nl80211WowlanTriggersSupported::parse(buf)?,
+                )
+            }
+            NL80211_ATTR_WOWLAN_TRIGGERS => {
+                Self::WowlanTriggers(Nl80211WowlanTriggers::parse(buf)?)
+            }
+            NL80211_ATTR_AUTH_TYPE => {
+                let err_msg = format!(
+                    "Invalid NL80211_ATTR_AUTH_TYPE value {:?}",
+                    payload
+                );
+                Self::AuthType(parse_u32(payload).context(err_msg)?.into())
+            }
+            NL80211_ATTR_MAC_HINT => {
+                let err_msg = format!(
+                    "Invalid NL80211_ATTR_MAC_HINT value {:?}",
+                    payload
+                );
+                Self::MacHint(parse_mac(payload).context(err_msg)?)
+            }
+            NL80211_ATTR_WIPHY_FREQ_HINT => {
+                let err_msg = format!(
+                    "Invalid NL80211_ATTR_WIPHY_FREQ_HINT value {:?}",
+                    payload
+                );
+                Self::WiPhyFreqHint(parse_u32(payload).context(err_msg)?)
+            }
+            NL80211_ATTR_CONTROL_PORT => Self::ControlPort,
+            NL80211_ATTR_PRIVACY => Self::Privacy,
+            NL80211_ATTR_CIPHER_SUITES_PAIRWISE => {
+                Self::CipherSuitesPairwise(parse_u32_array(payload))
+            }
+            NL80211_ATTR_CIPHER_SUITE_GROUP => {
+                let err_msg = format!(
+                    "Invalid NL80211_ATTR_CIPHER_SUITE_GROUP value {:?}",
+                    payload
+                );
+                Self::CipherSuiteGroup(
+                    parse_u32(payload).context(err_msg)?.into(),
+                )
+            }
+            NL80211_ATTR_WPA_VERSIONS => {
+                let err_msg = format!(
+                    "Invalid NL80211_ATTR_WPA_VERSIONS value {:?}",
+                    payload
+                );
+                Self::WpaVersions(parse_u32(payload).context(err_msg)?.into())
+            }
+            NL80211_ATTR_AKM_SUITES => {
+                Self::AkmSuites(parse_u32_array(payload))
+            }
+            NL80211_ATTR_FRAME => Self::Frame(payload.to_vec()),
+            NL80211_ATTR_LOCAL_STATE_CHANGE => Self::LocalStateChange,
+            NL80211_ATTR_AUTH_DATA => Self::AuthData(payload.to_vec()),
+            NL80211_ATTR_USE_MFP => {
+                let err_msg =
+                    format!("Invalid NL80211_ATTR_USE_MFP value {:?}", payload);
+                Self::UseMfp(parse_u32(payload).context(err_msg)?.into())
+            }
+            NL80211_ATTR_PREV_BSSID => {
+                let err_msg = format!(
+                    "Invalid NL80211_ATTR_PREV_BSSID value {:?}",
+                    payload
+                );
+                Self::PrevBssid(parse_mac(payload).context(err_msg)?)
+            }
+            NL80211_ATTR_SAE_PASSWORD => Self::SaePassword(payload.to_vec()),
+            NL80211_ATTR_PMK => Self::Pmk(payload.to_vec()),
+            NL80211_ATTR_WANT_1X_4WAY_HS => Self::Want1x4WayHs,
+            NL80211_ATTR_PMKR0_NAME => Self::PmkR0Name(payload.to_vec()),
+            NL80211_ATTR_STATUS_CODE => {
+                let err_msg = format!(
+                    "Invalid NL80211_ATTR_STATUS_CODE value {:?}",
+                    payload
+                );
+                Self::StatusCode(parse_u16(payload).context(err_msg)?)
+            }
+            NL80211_ATTR_PMKID => Self::Pmkid(payload.to_vec()),
+            NL80211_ATTR_PMK_LIFETIME => {
+                let err_msg = format!(
+                    "Invalid NL80211_ATTR_PMK_LIFETIME value {:?}",
+                    payload
+                );
+                Self::PmkLifetime(parse_u32(payload).context(err_msg)?)
+            }
+            NL80211_ATTR_PMK_REAUTH_THRESHOLD => {
+                let err_msg = format!(
+                    "Invalid NL80211_ATTR_PMK_REAUTH_THRESHOLD value {:?}",
+                    payload
+                );
+                Self::PmkReauthThreshold(parse_u8(payload).context(err_msg)?)
+            }
+            NL80211_ATTR_KEY_DATA => Self::KeyData(payload.to_vec()),
+            NL80211_ATTR_KEY_IDX => {
+                let err_msg =
+                    format!("Invalid NL80211_ATTR_KEY_IDX value {:?}", payload);
+                Self::KeyIdx(parse_u8(payload).context(err_msg)?)
+            }
+            NL80211_ATTR_KEY_CIPHER => {
+                let err_msg = format!(
+                    "Invalid NL80211_ATTR_KEY_CIPHER value {:?}",
+                    payload
+                );
+                Self::KeyCipher(parse_u32(payload).context(err_msg)?.into())
+            }
+            NL80211_ATTR_KEY_SEQ => Self::KeySeq(payload.to_vec()),
+            NL80211_ATTR_KEY_DEFAULT => Self::KeyDefault,
+            NL80211_ATTR_KEY_DEFAULT_MGMT => Self::KeyDefaultMgmt,
+            NL80211_ATTR_KEY_TYPE => {
+                let err_msg = format!(
+                    "Invalid NL80211_ATTR_KEY_TYPE value {:?}",
+                    payload
+                );
+                Self::KeyType(parse_u32(payload).context(err_msg)?.into())
+            }
+            NL80211_ATTR_KEY => Self::Key(Nl80211Key::parse(buf)?),
+            NL80211_ATTR_KEYS => {
+                let err_msg =
+                    format!("Invalid NL80211_ATTR_KEYS value {:?}", payload);
+                let mut keys = Vec::new();
+                for nla in NlasIterator::new(payload) {
+                    let nla = &nla.context(err_msg.clone())?;
+                    keys.push(Nl80211Key::parse(nla).context(err_msg.clone())?);
+                }
+                Self::Keys(keys)
+            }
+            NL80211_ATTR_BEACON_INTERVAL => {
+                let err_msg = format!(
+                    "Invalid NL80211_ATTR_BEACON_INTERVAL value {:?}",
+                    payload
+                );
+                Self::BeaconInterval(parse_u32(payload).context(err_msg)?)
+            }
+            NL80211_ATTR_BSS_BASIC_RATES => {
+                Self::BssBasicRates(payload.to_vec())
+            }
+            NL80211_ATTR_FREQ_FIXED => Self::FreqFixed,
+            NL80211_ATTR_MESH_ID => {
+                let err_msg =
+                    format!("Invalid NL80211_ATTR_MESH_ID value {:?}", payload);
+                Self::MeshId(parse_string(payload).context(err_msg)?)
+            }
+            NL80211_ATTR_MESH_CONFIG => {
+                Self::MeshConfig(Nl80211MeshConfig::parse(buf)?)
+            }
+            NL80211_ATTR_MESH_SETUP => {
+                Self::MeshSetup(Nl80211MeshSetup::parse(buf)?)
+            }
+            NL80211_ATTR_DTIM_PERIOD => {
+                let err_msg = format!(
+                    "Invalid NL80211_ATTR_DTIM_PERIOD value {:?}",
+                    payload
+                );
+                Self::DtimPeriod(parse_u32(payload).context(err_msg)?)
+            }
+            NL80211_ATTR_MCAST_RATE => {
+                let err_msg = format!(
+                    "Invalid NL80211_ATTR_MCAST_RATE value {:?}",
+                    payload
+                );
+                Self::McastRate(parse_u32(payload).context(err_msg)?)
+            }
+            NL80211_ATTR_MPATH_NEXT_HOP => {
+                let err_msg = format!(
+                    "Invalid NL80211_ATTR_MPATH_NEXT_HOP value {:?}",
+                    payload
+                );
+                Self::MpathNextHop(parse_mac(payload).context(err_msg)?)
+            }
+            NL80211_ATTR_MPATH_INFO => {
+                Self::MpathInfo(Nl80211MpathInfo::parse(buf)?)
+            }
+            NL80211_ATTR_STA_PLINK_STATE => {
+                let err_msg = format!(
+                    "Invalid NL80211_ATTR_STA_PLINK_STATE value {:?}",
+                    payload
+                );
+                Self::StationPlinkState(
+                    parse_u8(payload).context(err_msg)?.into(),
+                )
+            }
+            NL80211_ATTR_STA_SUPPORTED_RATES => {
+                Self::StationSupportedRates(payload.to_vec())
+            }
+            NL80211_ATTR_STA_LISTEN_INTERVAL => {
+                let err_msg = format!(
+                    "Invalid NL80211_ATTR_STA_LISTEN_INTERVAL value {:?}",
+                    payload
+                );
+                Self::StationListenInterval(
+                    parse_u16(payload).context(err_msg)?,
+                )
+            }
+            NL80211_ATTR_STA_AID => {
+                let err_msg =
+                    format!("Invalid NL80211_ATTR_STA_AID value {:?}", payload);
+                Self::StationAid(parse_u16(payload).context(err_msg)?)
+            }
+            NL80211_ATTR_BEACON_HEAD => Self::BeaconHead(payload.to_vec()),
+            NL80211_ATTR_BEACON_TAIL => Self::BeaconTail(payload.to_vec()),
+            NL80211_ATTR_IE_PROBE_RESP => Self::IeProbeResp(payload.to_vec()),
+            NL80211_ATTR_IE_ASSOC_RESP => Self::IeAssocResp(payload.to_vec()),
+            NL80211_ATTR_PROBE_RESP => Self::ProbeResp(payload.to_vec()),
+            NL80211_ATTR_HIDDEN_SSID => {
+                let err_msg = format!(
+                    "Invalid NL80211_ATTR_HIDDEN_SSID value {:?}",
+                    payload
+                );
+                Self::HiddenSsid(parse_u32(payload).context(err_msg)?.into())
+            }
+            NL80211_ATTR_AP_ISOLATE => {
+                let err_msg = format!(
+                    "Invalid NL80211_ATTR_AP_ISOLATE value {:?}",
+                    payload
+                );
+                Self::ApIsolate(parse_u8(payload).context(err_msg)? > 0)
+            }
+            NL80211_ATTR_INACTIVITY_TIMEOUT => {
+                let err_msg = format!(
+                    "Invalid NL80211_ATTR_INACTIVITY_TIMEOUT value {:?}",
+                    payload
+                );
+                Self::InactivityTimeout(parse_u16(payload).context(err_msg)?)
+            }
+            NL80211_ATTR_P2P_CTWINDOW => {
+                let err_msg = format!(
+                    "Invalid NL80211_ATTR_P2P_CTWINDOW value {:?}",
+                    payload
+                );
+                Self::P2pCtwindow(parse_u8(payload).context(err_msg)?)
+            }
+            NL80211_ATTR_WIPHY_TXQ_PARAMS => {
+                let err_msg = format!(
+                    "Invalid NL80211_ATTR_WIPHY_TXQ_PARAMS value {:?}",
+                    payload
+                );
+                let mut params = Vec::new();
+                for nla in NlasIterator::new(payload) {
+                    let nla = &nla.context(err_msg.clone())?;
+                    params.push(
+                        Nl80211TxqParams::parse(nla)
+                            .context(err_msg.clone())?,
+                    );
+                }
+                Self::WiPhyTxqParams(params)
+            }
+            NL80211_ATTR_FTM_RESPONDER_STATS => {
+                Self::FtmResponderStats(Nl80211FtmResponderStats::parse(buf)?)
+            }
+            NL80211_ATTR_COOKIE => {
+                let err_msg =
+                    format!("Invalid NL80211_ATTR_COOKIE value {:?}", payload);
+                Self::Cookie(parse_u64(payload).context(err_msg)?)
+            }
+            NL80211_ATTR_TIMEOUT => {
+                let err_msg =
+                    format!("Invalid NL80211_ATTR_TIMEOUT value {:?}", payload);
+                Self::Timeout(parse_u32(payload).context(err_msg)?)
+            }
+            NL80211_ATTR_PEER_MEASUREMENTS => {
+                Self::PeerMeasurements(Nl80211PeerMeasurement::parse(buf)?)
+            }
+            NL80211_ATTR_UNSOL_BCAST_PROBE_RESP => Self::UnsolBcastProbeResp(
+                Nl80211UnsolBcastProbeResp::parse(buf)?,
+            ),
+            NL80211_ATTR_FILS_DISCOVERY => {
+                Self::FilsDiscovery(Nl80211FilsDiscovery::parse(buf)?)
+            }
+            NL80211_ATTR_CNTDWN_OFFS_BEACON => {
+                Self::CntdwnOffsBeacon(parse_u16_array(payload))
+            }
+            NL80211_ATTR_CNTDWN_OFFS_PRESP => {
+                Self::CntdwnOffsPresp(parse_u16_array(payload))
+            }
+            NL80211_ATTR_COLOR_CHANGE_COUNT => {
+                let err_msg = format!(
+                    "Invalid NL80211_ATTR_COLOR_CHANGE_COUNT value {:?}",
+                    payload
+                );
+                Self::ColorChangeCount(parse_u8(payload).context(err_msg)?)
+            }
+            NL80211_ATTR_COLOR_CHANGE_COLOR => {
+                let err_msg = format!(
+                    "Invalid NL80211_ATTR_COLOR_CHANGE_COLOR value {:?}",
+                    payload
+                );
+                Self::ColorChangeColor(parse_u8(payload).context(err_msg)?)
+            }
+            NL80211_ATTR_COLOR_CHANGE_ELEMS => {
+                let err_msg = format!(
+                    "Invalid NL80211_ATTR_COLOR_CHANGE_ELEMS value {:?}",
+                    payload
+                );
+                let mut nlas = Vec::new();
+                for nla in NlasIterator::new(payload) {
+                    let nla = &nla.context(err_msg.clone())?;
+                    nlas.push(
+                        Nl80211Attr::parse(nla).context(err_msg.clone())?,
+                    );
+                }
+                Self::ColorChangeElems(nlas)
+            }
+            NL80211_ATTR_HE_OBSS_PD => {
+                Self::HeObssPd(Nl80211HeObssPd::parse(buf)?)
+            }
+            NL80211_ATTR_HE_BSS_COLOR => {
+                Self::HeBssColor(Nl80211HeBssColor::parse(buf)?)
+            }
+            NL80211_ATTR_MBSSID_CONFIG => {
+                Self::MbssidConfig(Nl80211MbssidConfig::parse(buf)?)
+            }
+            NL80211_ATTR_MBSSID_ELEMS => {
+                let err_msg = format!(
+                    "Invalid NL80211_ATTR_MBSSID_ELEMS value {:?}",
+                    payload
+                );
+                let mut elems = Vec::new();
+                for nla in NlasIterator::new(payload) {
+                    let nla = &nla.context(err_msg.clone())?;
+                    elems.push(nla.value().to_vec());
+                }
+                Self::MbssidElems(elems)
+            }
+            NL80211_ATTR_ACL_POLICY => {
+                let err_msg = format!(
+                    "Invalid NL80211_ATTR_ACL_POLICY value {:?}",
+                    payload
+                );
+                Self::AclPolicy(parse_u32(payload).context(err_msg)?.into())
+            }
+            NL80211_ATTR_MAC_ADDRS => {
+                let err_msg = format!(
+                    "Invalid NL80211_ATTR_MAC_ADDRS value {:?}",
+                    payload
+                );
+                let mut addrs = Vec::new();
+                for nla in NlasIterator::new(payload) {
+                    let nla = &nla.context(err_msg.clone())?;
+                    addrs
+                        .push(parse_mac(nla.value()).context(err_msg.clone())?);
+                }
+                Self::MacAddrs(addrs)
+            }
+            NL80211_ATTR_MAC_ACL_MAX => {
+                let err_msg = format!(
+                    "Invalid NL80211_ATTR_MAC_ACL_MAX value {:?}",
+                    payload
+                );
+                Self::MacAclMax(parse_u32(payload).context(err_msg)?)
+            }
+            NL80211_ATTR_ACK => Self::Ack,
+            NL80211_ATTR_ACK_SIGNAL => {
+                let err_msg = format!(
+                    "Invalid NL80211_ATTR_ACK_SIGNAL value {:?}",
+                    payload
+                );
+                Self::AckSignal(parse_i32(payload).context(err_msg)?)
+            }
+            NL80211_ATTR_RX_SIGNAL_DBM => {
+                let err_msg = format!(
+                    "Invalid NL80211_ATTR_RX_SIGNAL_DBM value {:?}",
+                    payload
+                );
+                Self::RxSignalDbm(parse_i32(payload).context(err_msg)?)
+            }
+            NL80211_ATTR_FRAME_MATCH => Self::FrameMatch(payload.to_vec()),
+            NL80211_ATTR_FRAME_TYPE => {
+                let err_msg = format!(
+                    "Invalid NL80211_ATTR_FRAME_TYPE value {:?}",
+                    payload
+                );
+                Self::FrameType(parse_u16(payload).context(err_msg)?)
+            }
+            NL80211_ATTR_RECEIVE_MULTICAST => Self::ReceiveMulticast,
+            NL80211_ATTR_DURATION => {
+                let err_msg = format!(
+                    "Invalid NL80211_ATTR_DURATION value {:?}",
+                    payload
+                );
+                Self::Duration(parse_u32(payload).context(err_msg)?)
+            }
+            NL80211_ATTR_OFFCHANNEL_TX_OK => Self::OffchannelTxOk,
+            NL80211_ATTR_TX_NO_CCK_RATE => Self::TxNoCckRate,
+            NL80211_ATTR_DONT_WAIT_FOR_ACK => Self::DontWaitForAck,
+            NL80211_ATTR_CH_SWITCH_COUNT => {
+                let err_msg = format!(
+                    "Invalid NL80211_ATTR_CH_SWITCH_COUNT value {:?}",
+                    payload
+                );
+                Self::ChSwitchCount(parse_u32(payload).context(err_msg)?)
+            }
+            NL80211_ATTR_CH_SWITCH_BLOCK_TX => Self::ChSwitchBlockTx,
+            NL80211_ATTR_CSA_IES => {
+                let err_msg =
+                    format!("Invalid NL80211_ATTR_CSA_IES value {:?}", payload);
+                let mut nlas = Vec::new();
+                for nla in NlasIterator::new(payload) {
+                    let nla = &nla.context(err_msg.clone())?;
+                    nlas.push(
+                        Nl80211Attr::parse(nla).context(err_msg.clone())?,
+                    );
+                }
+                Self::CsaIes(nlas)
+            }
+            NL80211_ATTR_WIPHY_RETRY_SHORT => {
+                let err_msg = format!(
+                    "Invalid NL80211_ATTR_WIPHY_RETRY_SHORT value {:?}",
+                    payload
+                );
+                Self::WiPhyRetryShort(parse_u8(payload).context(err_msg)?)
+            }
+            NL80211_ATTR_WIPHY_RETRY_LONG => {
+                let err_msg = format!(
+                    "Invalid NL80211_ATTR_WIPHY_RETRY_LONG value {:?}",
+                    payload
+                );
+                Self::WiPhyRetryLong(parse_u8(payload).context(err_msg)?)
+            }
+            NL80211_ATTR_WIPHY_FRAG_THRESHOLD => {
+                let err_msg = format!(
+                    "Invalid NL80211_ATTR_WIPHY_FRAG_THRESHOLD value {:?}",
+                    payload
+                );
+                Self::WiPhyFragThreshold(parse_u32(payload).context(err_msg)?)
+            }
+            NL80211_ATTR_WIPHY_RTS_THRESHOLD => {
+                let err_msg = format!(
+                    "Invalid NL80211_ATTR_WIPHY_RTS_THRESHOLD value {:?}",
+                    payload
+                );
+                Self::WiPhyRtsThreshold(parse_u32(payload).context(err_msg)?)
+            }
+            NL80211_ATTR_WIPHY_COVERAGE_CLASS => {
+                let err_msg = format!(
+                    "Invalid NL80211_ATTR_WIPHY_COVERAGE_CLASS value {:?}",
+                    payload
+                );
+                Self::WiPhyCoverageClass(parse_u8(payload).context(err_msg)?)
+            }
+            NL80211_ATTR_WIPHY_ANTENNA_TX => {
+                let err_msg = format!(
+                    "Invalid NL80211_ATTR_WIPHY_ANTENNA_TX value {:?}",
+                    payload
+                );
+                Self::WiPhyAntennaTx(parse_u32(payload).context(err_msg)?)
+            }
+            NL80211_ATTR_WIPHY_ANTENNA_RX => {
+                let err_msg = format!(
+                    "Invalid NL80211_ATTR_WIPHY_ANTENNA_RX value {:?}",
+                    payload
+                );
+                Self::WiPhyAntennaRx(parse_u32(payload).context(err_msg)?)
+            }
+            NL80211_ATTR_WIPHY_ANTENNA_AVAIL_TX => {
+                let err_msg = format!(
+                    "Invalid NL80211_ATTR_WIPHY_ANTENNA_AVAIL_TX value {:?}",
+                    payload
+                );
+                Self::WiPhyAntennaAvailTx(parse_u32(payload).context(err_msg)?)
+            }
+            NL80211_ATTR_WIPHY_ANTENNA_AVAIL_RX => {
+                let err_msg = format!(
+                    "Invalid NL80211_ATTR_WIPHY_ANTENNA_AVAIL_RX value {:?}",
+                    payload
+                );
+                Self::WiPhyAntennaAvailRx(parse_u32(payload).context(err_msg)?)
+            }
+            NL80211_ATTR_PID => {
+                let err_msg =
+                    format!("Invalid NL80211_ATTR_PID value {:?}", payload);
+                Self::Pid(parse_u32(payload).context(err_msg)?)
+            }
+            NL80211_ATTR_NETNS_FD => {
+                let err_msg = format!(
+                    "Invalid NL80211_ATTR_NETNS_FD value {:?}",
+                    payload
+                );
+                Self::NetnsFd(parse_u32(payload).context(err_msg)?)
+            }
+            NL80211_ATTR_PROTOCOL_FEATURES => {
+                let err_msg = format!(
+                    "Invalid NL80211_ATTR_PROTOCOL_FEATURES value {:?}",
+                    payload
+                );
+                Self::ProtocolFeatures(
+                    parse_u32(payload).context(err_msg)?.into(),
+                )
+            }
+            NL80211_ATTR_PS_STATE => {
+                let err_msg = format!(
+                    "Invalid NL80211_ATTR_PS_STATE value {:?}",
+                    payload
+                );
+                Self::PsState(parse_u32(payload).context(err_msg)?.into())
+            }
+            NL80211_ATTR_CQM => Self::Cqm(Nl80211Cqm::parse(buf)?),
+            NL80211_ATTR_TDLS_ACTION => {
+                let err_msg = format!(
+                    "Invalid NL80211_ATTR_TDLS_ACTION value {:?}",
+                    payload
+                );
+                Self::TdlsAction(parse_u8(payload).context(err_msg)?.into())
+            }
+            NL80211_ATTR_TDLS_DIALOG_TOKEN => {
+                let err_msg = format!(
+                    "Invalid NL80211_ATTR_TDLS_DIALOG_TOKEN value {:?}",
+                    payload
+                );
+                Self::TdlsDialogToken(parse_u8(payload).context(err_msg)?)
+            }
+            NL80211_ATTR_TDLS_OPERATION => {
+                let err_msg = format!(
+                    "Invalid NL80211_ATTR_TDLS_OPERATION value {:?}",
+                    payload
+                );
+                Self::TdlsOperation(parse_u8(payload).context(err_msg)?.into())
+            }
+            NL80211_ATTR_TDLS_SUPPORT => Self::TdlsSupport,
+            NL80211_ATTR_TDLS_EXTERNAL_SETUP => Self::TdlsExternalSetup,
+            NL80211_ATTR_TDLS_PEER_CAPABILITY => {
+                let err_msg = format!(
+                    "Invalid NL80211_ATTR_TDLS_PEER_CAPABILITY value {:?}",
+                    payload
+                );
+                Self::TdlsPeerCapability(parse_u32(payload).context(err_msg)?)
+            }
+            NL80211_ATTR_TDLS_INITIATOR => Self::TdlsInitiator,
+            NL80211_ATTR_TX_RATES => {
+                let err_msg = format!(
+                    "Invalid NL80211_ATTR_TX_RATES value {:?}",
+                    payload
+                );
+                let mut nlas = Vec::new();
+                for nla in NlasIterator::new(payload) {
+                    let nla = &nla.context(err_msg.clone())?;
+                    nlas.push(
+                        Nl80211TxRates::parse(nla).context(err_msg.clone())?,
+                    );
+                }
+                Self::TxRates(nlas)
+            }
+            NL80211_ATTR_NOACK_MAP => {
+                let err_msg = format!(
+                    "Invalid NL80211_ATTR_NOACK_MAP value {:?}",
+                    payload
+                );
+                Self::NoackMap(parse_u16(payload).context(err_msg)?)
+            }
+            NL80211_ATTR_QOS_MAP => {
+                Self::QosMap(Nl80211QosMap::parse(payload)?)
+            }
+            NL80211_ATTR_TID_CONFIG => {
+                Self::TidConfigSupport(Nl80211TidConfigSupport::parse(buf)?)
+            }
+            NL80211_ATTR_TSID => {
+                let err_msg =
+                    format!("Invalid NL80211_ATTR_TSID value {:?}", payload);
+                Self::Tsid(parse_u8(payload).context(err_msg)?)
+            }
+            NL80211_ATTR_USER_PRIO => {
+                let err_msg = format!(
+                    "Invalid NL80211_ATTR_USER_PRIO value {:?}",
+                    payload
+                );
+                Self::UserPrio(parse_u8(payload).context(err_msg)?)
+            }
+            NL80211_ATTR_ADMITTED_TIME => {
+                let err_msg = format!(
+                    "Invalid NL80211_ATTR_ADMITTED_TIME value {:?}",
+                    payload
+                );
+                Self::AdmittedTime(parse_u16(payload).context(err_msg)?)
+            }
+            NL80211_ATTR_MULTICAST_TO_UNICAST_ENABLED => {
+                Self::MulticastToUnicastEnabled
+            }
+            // The wiphy dump reports the capabilities as a binary struct, the
+            // rules of `Nl80211Cmd::CoalesceGet` are handled by
+            // `parse_with_cmd()`
+            NL80211_ATTR_COALESCE_RULE => Self::CoalesceRuleSupport(
+                Nl80211CoalesceRuleSupport::parse(payload)?,
+            ),
+            NL80211_ATTR_CRIT_PROT_ID => {
+                let err_msg = format!(
+                    "Invalid NL80211_ATTR_CRIT_PROT_ID value {:?}",
+                    payload
+                );
+                Self::CritProtId(parse_u16(payload).context(err_msg)?.into())
+            }
+            NL80211_ATTR_MAX_CRIT_PROT_DURATION => {
+                let err_msg = format!(
+                    "Invalid NL80211_ATTR_MAX_CRIT_PROT_DURATION value {:?}",
+                    payload
+                );
+                Self::MaxCritProtDuration(parse_u16(payload).context(err_msg)?)
+            }
+            NL80211_ATTR_NAN_MASTER_PREF => {
+                let err_msg = format!(
+                    "Invalid NL80211_ATTR_NAN_MASTER_PREF value {:?}",
+                    payload
+                );
+                Self::NanMasterPref(parse_u8(payload).context(err_msg)?)
+            }
+            NL80211_ATTR_BANDS => {
+                let err_msg =
+                    format!("Invalid NL80211_ATTR_BANDS value {:?}", payload);
+                Self::Bands(parse_u32(payload).context(err_msg)?)
+            }
+            NL80211_ATTR_NAN_FUNC => {
+                Self::NanFunc(Nl80211NanFunction::parse(buf)?)
+            }
+            NL80211_ATTR_NAN_MATCH => {
+                Self::NanMatch(Nl80211NanMatch::parse(buf)?)
+            }
+            NL80211_ATTR_P2P_OPPPS => {
+                let err_msg = format!(
+                    "Invalid NL80211_ATTR_P2P_OPPPS value {:?}",
+                    payload
+                );
+                Self::P2pOppPs(parse_u8(payload).context(err_msg)? > 0)
+            }
+            _ => Self::Other(
+                DefaultNla::parse(buf).context("invalid NLA (unknown kind)")?,
+            ),
+        })
+    }
+}
+
+impl Nl80211Attr {
+    // Parse the attribute of a message holding `cmd`, as the kernel uses
+    // different layouts of `NL80211_ATTR_COALESCE_RULE` in wiphy dumps and
+    // `Nl80211Cmd::CoalesceGet` replies
+    pub(crate) fn parse_with_cmd<T: AsRef<[u8]> + ?Sized>(
+        buf: &NlaBuffer<&T>,
+        cmd: Nl80211Cmd,
+    ) -> Result<Self, DecodeError> {
+        match (cmd, buf.kind()) {
+            (Nl80211Cmd::CoalesceGet, NL80211_ATTR_COALESCE_RULE) => {
+                let payload = buf.value();
+                let err_msg = format!(
+                    "Invalid NL80211_ATTR_COALESCE_RULE value {:?}",
+                    payload
+                );
+                let mut rules = Vec::new();
+                for nla in NlasIterator::new(payload) {
+                    let nla = &nla.context(err_msg.clone())?;
+                    rules.push(
+                        Nl80211CoalesceRule::parse(nla)
+                            .context(err_msg.clone())?,
+                    );
+                }
+                Ok(Self::CoalesceRules(rules))
+            }
+            _ => Self::parse(buf),
+        }
+    }
+}
+
+// The SSID in NL80211_ATTR_SCAN_SSIDS is not NULL terminated, the index of
+// each nested attribute is ignored by kernel.
+fn scan_ssids_to_nlas(ssids: &[String]) -> Vec<DefaultNla> {
+    ssids
+        .iter()
+        .enumerate()
+        .map(|(i, ssid)| DefaultNla::new(i as u16, ssid.as_bytes().to_vec()))
+        .collect()
+}
+
+// NL80211_ATTR_STA_FLAGS holds flag attributes with the station flag as kind
+fn station_flags_to_nlas(flags: &[Nl80211StationFlag]) -> Vec<DefaultNla> {
+    flags
+        .iter()
+        .map(|flag| DefaultNla::new((*flag).into(), Vec::new()))
+        .collect()
+}
+
+fn cmds_to_u32(cmds: &[Nl80211Cmd]) -> Vec<u32> {
+    cmds.iter().map(|cmd| u8::from(*cmd) as u32).collect()
+}
+
+fn u32_array_to_nlas(values: &[u32]) -> Vec<DefaultNla> {
+    values
+        .iter()
+        .enumerate()
+        .map(|(i, v)| {
+            let mut buffer = vec![0u8; 4];
+            NativeEndian::write_u32(&mut buffer, *v);
+            DefaultNla::new(i as u16, buffer)
+        })
+        .collect()
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum Nl80211MloLinkNla {
+    Id(u8),
+    Mac([u8; ETH_ALEN]),
+    Other(DefaultNla),
+}
+
+impl Nla for Nl80211MloLinkNla {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::Id(_) => 1,
+            Self::Mac(_) => ETH_ALEN,
+            Self::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::Id(_) => NL80211_ATTR_MLO_LINK_ID,
+            Self::Mac(_) => NL80211_ATTR_MAC,
+            Self::Other(attr) => attr.kind(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Id(d) => buffer[0] = *d,
+            Self::Mac(ref s) => buffer.copy_from_slice(s),
+            Self::Other(ref attr) => attr.emit(buffer),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for Nl80211MloLinkNla
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            NL80211_ATTR_MLO_LINK_ID => {
+                let err_msg = format!(
+                    "Invalid NL80211_ATTR_MLO_LINK_ID value {:?}",
+                    payload
+                );
+                Self::Id(parse_u8(payload).context(err_msg)?)
+            }
+            NL80211_ATTR_MAC => Self::Mac(if payload.len() == ETH_ALEN {
+                let mut ret = [0u8; ETH_ALEN];
+                ret.copy_from_slice(&payload[..ETH_ALEN]);
+                ret
+            } else {
+                return Err(format!(
+                    "Invalid length of NL80211_ATTR_MAC, expected length {} got {:?}",
+                    ETH_ALEN, payload
+                )
+                .into());
+            }),
+            _ => Self::Other(
+                DefaultNla::parse(buf).context("invalid NLA (unknown kind)")?,
+            ),
+        })
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct Nl80211MloLink {
+    pub id: u8,
+    pub mac: [u8; ETH_ALEN],
+}
+
+impl Nla for Nl80211MloLink {
+    fn value_len(&self) -> usize {
+        Vec::<Nl80211MloLinkNla>::from(self).as_slice().buffer_len()
+    }
+
+    fn kind(&self) -> u16 {
+        self.id as u16 + 1
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        Vec::<Nl80211MloLinkNla>::from(self).as_slice().emit(buffer)
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for Nl80211MloLink
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let mut ret = Self::default();
+        let payload = buf.value();
+        let err_msg =
+            format!("Invalid NL80211_ATTR_MLO_LINKS value {:?}", payload);
+        for nla in NlasIterator::new(payload) {
+            let nla = &nla.context(err_msg.clone())?;
+            match Nl80211MloLinkNla::parse(nla).context(err_msg.clone())? {
+                Nl80211MloLinkNla::Id(d) => ret.id = d,
+                Nl80211MloLinkNla::Mac(s) => ret.mac = s,
+                Nl80211MloLinkNla::Other(attr) => {
+                    log::warn!(
+                        "Got unsupported NL80211_ATTR_MLO_LINKS value {:?}",
+                        attr
+                    )
+                }
+            }
+        }
+        Ok(ret)
+    }
+}
+
+impl From<&Nl80211MloLink> for Vec<Nl80211MloLinkNla> {
+    fn from(link: &Nl80211MloLink) -> Self {
+        vec![
+            Nl80211MloLinkNla::Id(link.id),
+            Nl80211MloLinkNla::Mac(link.mac),
+        ]
+    }
+}
+
+// NL80211_ATTR_AKM_SUITES and NL80211_ATTR_CIPHER_SUITES_PAIRWISE hold a
+// plain array of u32 instead of nested attributes
+fn emit_u32_array<T: Copy + Into<u32>>(values: &[T], buffer: &mut [u8]) {
+    for (i, v) in values.iter().enumerate() {
+        NativeEndian::write_u32(&mut buffer[i * 4..(i + 1) * 4], (*v).into());
+    }
+}
+
+fn parse_u32_array<T: From<u32>>(payload: &[u8]) -> Vec<T> {
+    payload
+        .chunks_exact(4)
+        .map(|d| NativeEndian::read_u32(d).into())
+        .collect()
+}
+
+// NL80211_ATTR_CNTDWN_OFFS_BEACON and NL80211_ATTR_CNTDWN_OFFS_PRESP hold a
+// plain array of u16
+fn emit_u16_array(values: &[u16], buffer: &mut [u8]) {
+    for (i, v) in values.iter().enumerate() {
+        NativeEndian::write_u16(&mut buffer[i * 2..(i + 1) * 2], *v);
+    }
+}
+
+fn parse_u16_array(payload: &[u8]) -> Vec<u16> {
+    payload
+        .chunks_exact(2)
+        .map(NativeEndian::read_u16)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        Nl80211CoalesceCondition, Nl80211PacketPattern, Nl80211PatternSupport,
+    };
+
+    fn parse_coalesce_rule(
+        value: Vec<u8>,
+        cmd: Nl80211Cmd,
+    ) -> Result<Nl80211Attr, DecodeError> {
+        let nla = DefaultNla::new(NL80211_ATTR_COALESCE_RULE, value);
+        let mut buffer = vec![0u8; nla.buffer_len()];
+        nla.emit(&mut buffer);
+        Nl80211Attr::parse_with_cmd(
+            &NlaBuffer::new_checked(&buffer[..]).unwrap(),
+            cmd,
+        )
+    }
+
+    #[test]
+    fn coalesce_rule_support_in_wiphy_dump() {
+        let support = Nl80211CoalesceRuleSupport {
+            max_rules: 8,
+            pat: Nl80211PatternSupport {
+                max_patterns: 4,
+                min_pattern_len: 1,
+                max_pattern_len: 64,
+                max_pkt_offset: 128,
+            },
+            max_delay: 1000,
+        };
+        let mut value = vec![0u8; support.buffer_len()];
+        support.emit(&mut value);
+        assert_eq!(
+            parse_coalesce_rule(value.clone(), Nl80211Cmd::WiPhyNew).unwrap(),
+            Nl80211Attr::CoalesceRuleSupport(support)
+        );
+
+        // Fields appended to the struct by newer kernels are ignored
+        value.extend_from_slice(&[0xff; 4]);
+        assert_eq!(
+            parse_coalesce_rule(value, Nl80211Cmd::WiPhyNew).unwrap(),
+            Nl80211Attr::CoalesceRuleSupport(support)
+        );
+    }
+
+    #[test]
+    fn coalesce_rules_in_coalesce_get() {
+        let rules = vec![Nl80211CoalesceRule {
+            delay: Some(25),
+            condition: Some(Nl80211CoalesceCondition::NoMatch),
+            pkt_pattern: vec![Nl80211PacketPattern::new(&[0x08, 0x06])],
+        }];
+        let attr = Nl80211Attr::CoalesceRules(rules);
+        let mut value = vec![0u8; attr.value_len()];
+        attr.emit_value(&mut value);
+        assert_eq!(
+            parse_coalesce_rule(value, Nl80211Cmd::CoalesceGet).unwrap(),
+            attr
+        );
     }
 }
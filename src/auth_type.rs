@@ -0,0 +1,70 @@
+// SPDX-License-Identifier: MIT
+
+const NL80211_AUTHTYPE_OPEN_SYSTEM: u32 = 0;
+const NL80211_AUTHTYPE_SHARED_KEY: u32 = 1;
+const NL80211_AUTHTYPE_FT: u32 = 2;
+const NL80211_AUTHTYPE_NETWORK_EAP: u32 = 3;
+const NL80211_AUTHTYPE_SAE: u32 = 4;
+const NL80211_AUTHTYPE_FILS_SK: u32 = 5;
+const NL80211_AUTHTYPE_FILS_SK_PFS: u32 = 6;
+const NL80211_AUTHTYPE_FILS_PK: u32 = 7;
+const NL80211_AUTHTYPE_AUTOMATIC: u32 = 8;
+
+/// Authentication type
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Nl80211AuthType {
+    /// Open System authentication
+    OpenSystem,
+    /// Shared Key authentication (WEP only)
+    SharedKey,
+    /// Fast BSS Transition (IEEE 802.11r)
+    Ft,
+    /// Network EAP (some Cisco APs and mainly LEAP)
+    NetworkEap,
+    /// Simultaneous authentication of equals
+    Sae,
+    /// Fast Initial Link Setup shared key
+    FilsSk,
+    /// Fast Initial Link Setup shared key with PFS
+    FilsSkPfs,
+    /// Fast Initial Link Setup public key
+    FilsPk,
+    /// Determine automatically, only valid for CONNECT where it is expressed
+    /// by leaving out the attribute
+    Automatic,
+    Other(u32),
+}
+
+impl From<u32> for Nl80211AuthType {
+    fn from(d: u32) -> Self {
+        match d {
+            NL80211_AUTHTYPE_OPEN_SYSTEM => Self::OpenSystem,
+            NL80211_AUTHTYPE_SHARED_KEY => Self::SharedKey,
+            NL80211_AUTHTYPE_FT => Self::Ft,
+            NL80211_AUTHTYPE_NETWORK_EAP => Self::NetworkEap,
+            NL80211_AUTHTYPE_SAE => Self::Sae,
+            NL80211_AUTHTYPE_FILS_SK => Self::FilsSk,
+            NL80211_AUTHTYPE_FILS_SK_PFS => Self::FilsSkPfs,
+            NL80211_AUTHTYPE_FILS_PK => Self::FilsPk,
+            NL80211_AUTHTYPE_AUTOMATIC => Self::Automatic,
+            _ => Self::Other(d),
+        }
+    }
+}
+
+impl From<Nl80211AuthType> for u32 {
+    fn from(v: Nl80211AuthType) -> u32 {
+        match v {
+            Nl80211AuthType::OpenSystem => NL80211_AUTHTYPE_OPEN_SYSTEM,
+            Nl80211AuthType::SharedKey => NL80211_AUTHTYPE_SHARED_KEY,
+            Nl80211AuthType::Ft => NL80211_AUTHTYPE_FT,
+            Nl80211AuthType::NetworkEap => NL80211_AUTHTYPE_NETWORK_EAP,
+            Nl80211AuthType::Sae => NL80211_AUTHTYPE_SAE,
+            Nl80211AuthType::FilsSk => NL80211_AUTHTYPE_FILS_SK,
+            Nl80211AuthType::FilsSkPfs => NL80211_AUTHTYPE_FILS_SK_PFS,
+            Nl80211AuthType::FilsPk => NL80211_AUTHTYPE_FILS_PK,
+            Nl80211AuthType::Automatic => NL80211_AUTHTYPE_AUTOMATIC,
+            Nl80211AuthType::Other(d) => d,
+        }
+    }
+}
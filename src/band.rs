@@ -0,0 +1,53 @@
+// SPDX-License-Identifier: MIT
+
+const NL80211_BAND_2GHZ: u32 = 0;
+const NL80211_BAND_5GHZ: u32 = 1;
+const NL80211_BAND_60GHZ: u32 = 2;
+const NL80211_BAND_6GHZ: u32 = 3;
+const NL80211_BAND_S1GHZ: u32 = 4;
+const NL80211_BAND_LC: u32 = 5;
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Nl80211Band {
+    /// 2.4 GHz ISM band
+    Band2GHz,
+    /// around 5 GHz band (4.9 - 5.7 GHz)
+    Band5GHz,
+    /// around 60 GHz band (58.32 - 69.12 GHz)
+    Band60GHz,
+    /// around 6 GHz band (5.9 - 7.2 GHz)
+    Band6GHz,
+    /// around 900MHz, supported by S1G PHYs
+    BandS1GHz,
+    /// light communication band
+    BandLc,
+    Other(u32),
+}
+
+impl From<u32> for Nl80211Band {
+    fn from(d: u32) -> Self {
+        match d {
+            NL80211_BAND_2GHZ => Self::Band2GHz,
+            NL80211_BAND_5GHZ => Self::Band5GHz,
+            NL80211_BAND_60GHZ => Self::Band60GHz,
+            NL80211_BAND_6GHZ => Self::Band6GHz,
+            NL80211_BAND_S1GHZ => Self::BandS1GHz,
+            NL80211_BAND_LC => Self::BandLc,
+            _ => Self::Other(d),
+        }
+    }
+}
+
+impl From<Nl80211Band> for u32 {
+    fn from(v: Nl80211Band) -> u32 {
+        match v {
+            Nl80211Band::Band2GHz => NL80211_BAND_2GHZ,
+            Nl80211Band::Band5GHz => NL80211_BAND_5GHZ,
+            Nl80211Band::Band60GHz => NL80211_BAND_60GHZ,
+            Nl80211Band::Band6GHz => NL80211_BAND_6GHZ,
+            Nl80211Band::BandS1GHz => NL80211_BAND_S1GHZ,
+            Nl80211Band::BandLc => NL80211_BAND_LC,
+            Nl80211Band::Other(d) => d,
+        }
+    }
+}
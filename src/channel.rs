@@ -1,5 +1,13 @@
 // SPDX-License-Identifier: MIT
 
+use anyhow::Context;
+use netlink_packet_utils::{
+    nla::{NlaBuffer, NlasIterator},
+    DecodeError, Emitable, Parseable,
+};
+
+use crate::{Nl80211Attr, Nl80211Error};
+
 const NL80211_CHAN_NO_HT: u32 = 0;
 const NL80211_CHAN_HT20: u32 = 1;
 const NL80211_CHAN_HT40MINUS: u32 = 2;
@@ -108,3 +116,256 @@ impl From<Nl80211ChannelWidth> for u32 {
         }
     }
 }
+
+/// Channel definition: control channel frequency, channel width and center
+/// frequencies of the occupied segments
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct Nl80211ChannelDef {
+    /// Control channel frequency in MHz
+    pub freq: u32,
+    /// Channel width, 20 MHz without HT when not set
+    pub width: Option<Nl80211ChannelWidth>,
+    /// Center frequency of the first segment in MHz
+    pub center_freq1: Option<u32>,
+    /// Center frequency of the second segment in MHz, only for 80+80 MHz
+    pub center_freq2: Option<u32>,
+    /// Bitmap of the 2.16 GHz channels of the EDMG channel, 60 GHz band only
+    pub edmg_channels: Option<u8>,
+    /// EDMG channel BW configuration as defined by IEEE 802.11ay
+    pub edmg_bw_config: Option<u8>,
+}
+
+impl Nl80211ChannelDef {
+    /// 20 MHz channel without HT on specified frequency in MHz
+    pub fn new(freq: u32) -> Self {
+        Self {
+            freq,
+            width: None,
+            center_freq1: None,
+            center_freq2: None,
+            edmg_channels: None,
+            edmg_bw_config: None,
+        }
+    }
+
+    /// Channel width and center frequency of the first segment in MHz
+    pub fn width(
+        mut self,
+        width: Nl80211ChannelWidth,
+        center_freq1: u32,
+    ) -> Self {
+        self.width = Some(width);
+        self.center_freq1 = Some(center_freq1);
+        self
+    }
+
+    /// Center frequency of the second segment in MHz for 80+80 MHz
+    pub fn center_freq2(mut self, center_freq2: u32) -> Self {
+        self.center_freq2 = Some(center_freq2);
+        self
+    }
+
+    /// EDMG channel of the 60 GHz band: bitmap of the bonded 2.16 GHz
+    /// channels and BW configuration as defined by IEEE 802.11ay
+    pub fn edmg(mut self, channels: u8, bw_config: u8) -> Self {
+        self.edmg_channels = Some(channels);
+        self.edmg_bw_config = Some(bw_config);
+        self
+    }
+
+    /// Check the center frequencies against the channel width: the control
+    /// channel must be one of the 20 MHz channels covered by the first
+    /// segment, and the second segment is only allowed, and required, for
+    /// 80+80 MHz. S1G widths are not checked.
+    pub fn validate(&self) -> Result<(), Nl80211Error> {
+        let invalid = |reason: &str| {
+            Err(Nl80211Error::InvalidArgument(format!(
+                "{} in channel definition {:?}",
+                reason, self
+            )))
+        };
+        let width = self.width.unwrap_or(Nl80211ChannelWidth::NoHt20);
+        let center_freq1 = self.center_freq1.unwrap_or(self.freq);
+        let segment_width = match width {
+            Nl80211ChannelWidth::NoHt20
+            | Nl80211ChannelWidth::Mhz(5)
+            | Nl80211ChannelWidth::Mhz(10)
+            | Nl80211ChannelWidth::Mhz(20) => 20,
+            Nl80211ChannelWidth::Mhz80Plus80 => 80,
+            Nl80211ChannelWidth::Mhz(d @ (40 | 80 | 160 | 320)) => d,
+            Nl80211ChannelWidth::Mhz(1 | 2 | 4 | 8 | 16) => return Ok(()),
+            Nl80211ChannelWidth::Mhz(_) | Nl80211ChannelWidth::Other(_) => {
+                return invalid("Unsupported channel width");
+            }
+        };
+        // Distance of the control channel from the outermost 20 MHz channel
+        // of the segment on its side
+        let edge_offset = (segment_width / 2 - 10)
+            .checked_sub(self.freq.abs_diff(center_freq1));
+        if edge_offset.map(|d| d % 20 != 0).unwrap_or(true) {
+            return invalid("Control channel outside of the first segment");
+        }
+        match (width, self.center_freq2) {
+            (Nl80211ChannelWidth::Mhz80Plus80, None) => {
+                invalid("Missing center_freq2 for 80+80 MHz")
+            }
+            // Adjacent segments form a 160 MHz channel instead
+            (Nl80211ChannelWidth::Mhz80Plus80, Some(d))
+                if d.abs_diff(center_freq1) <= 80 =>
+            {
+                invalid("Overlapping or adjacent 80+80 MHz segments")
+            }
+            (Nl80211ChannelWidth::Mhz80Plus80, Some(_)) | (_, None) => Ok(()),
+            (_, Some(_)) => invalid("Unexpected center_freq2"),
+        }
+    }
+}
+
+impl From<&Nl80211ChannelDef> for Vec<Nl80211Attr> {
+    fn from(chandef: &Nl80211ChannelDef) -> Self {
+        let mut nlas = vec![Nl80211Attr::WiPhyFreq(chandef.freq)];
+        if let Some(v) = chandef.width {
+            nlas.push(Nl80211Attr::ChannelWidth(v));
+        }
+        if let Some(v) = chandef.center_freq1 {
+            nlas.push(Nl80211Attr::CenterFreq1(v));
+        }
+        if let Some(v) = chandef.center_freq2 {
+            nlas.push(Nl80211Attr::CenterFreq2(v));
+        }
+        if let Some(v) = chandef.edmg_channels {
+            nlas.push(Nl80211Attr::WiPhyEdmgChannels(v));
+        }
+        if let Some(v) = chandef.edmg_bw_config {
+            nlas.push(Nl80211Attr::WiPhyEdmgBwConfig(v));
+        }
+        nlas
+    }
+}
+
+// Nested form of the channel definition, e.g. the channel of a peer
+// measurement peer
+impl Emitable for Nl80211ChannelDef {
+    fn buffer_len(&self) -> usize {
+        Vec::<Nl80211Attr>::from(self).as_slice().buffer_len()
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        Vec::<Nl80211Attr>::from(self).as_slice().emit(buffer)
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for Nl80211ChannelDef
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        let err_msg = format!("Invalid channel definition {:?}", payload);
+        let mut freq = None;
+        let mut ret = Self::new(0);
+        for nla in NlasIterator::new(payload) {
+            let nla = &nla.context(err_msg.clone())?;
+            match Nl80211Attr::parse(nla).context(err_msg.clone())? {
+                Nl80211Attr::WiPhyFreq(d) => freq = Some(d),
+                Nl80211Attr::ChannelWidth(d) => ret.width = Some(d),
+                Nl80211Attr::CenterFreq1(d) => ret.center_freq1 = Some(d),
+                Nl80211Attr::CenterFreq2(d) => ret.center_freq2 = Some(d),
+                Nl80211Attr::WiPhyEdmgChannels(d) => {
+                    ret.edmg_channels = Some(d)
+                }
+                Nl80211Attr::WiPhyEdmgBwConfig(d) => {
+                    ret.edmg_bw_config = Some(d)
+                }
+                attr => {
+                    log::warn!("Got unsupported channel definition {:?}", attr)
+                }
+            }
+        }
+        ret.freq = freq.ok_or_else(|| DecodeError::from(err_msg))?;
+        Ok(ret)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use netlink_packet_utils::nla::DefaultNla;
+
+    use super::*;
+
+    fn round_trip(chandef: &Nl80211ChannelDef) -> Nl80211ChannelDef {
+        let mut value = vec![0u8; chandef.buffer_len()];
+        chandef.emit(&mut value);
+        let nla = DefaultNla::new(1, value);
+        let mut buffer = vec![0u8; nla.buffer_len()];
+        nla.emit(&mut buffer);
+        Nl80211ChannelDef::parse(&NlaBuffer::new_checked(&buffer[..]).unwrap())
+            .unwrap()
+    }
+
+    #[test]
+    fn chandef_round_trip() {
+        let chandef = Nl80211ChannelDef::new(5180)
+            .width(Nl80211ChannelWidth::Mhz80Plus80, 5210)
+            .center_freq2(5775);
+        assert_eq!(round_trip(&chandef), chandef);
+    }
+
+    #[test]
+    fn chandef_edmg_round_trip() {
+        let chandef = Nl80211ChannelDef::new(60480).edmg(0b11, 4);
+        assert_eq!(round_trip(&chandef), chandef);
+    }
+
+    #[test]
+    fn validate_valid() {
+        for chandef in [
+            Nl80211ChannelDef::new(2412),
+            Nl80211ChannelDef::new(2412)
+                .width(Nl80211ChannelWidth::Mhz(20), 2412),
+            Nl80211ChannelDef::new(5180)
+                .width(Nl80211ChannelWidth::Mhz(40), 5190),
+            Nl80211ChannelDef::new(5200)
+                .width(Nl80211ChannelWidth::Mhz(80), 5210),
+            Nl80211ChannelDef::new(5240)
+                .width(Nl80211ChannelWidth::Mhz(160), 5250),
+            Nl80211ChannelDef::new(5180)
+                .width(Nl80211ChannelWidth::Mhz80Plus80, 5210)
+                .center_freq2(5775),
+        ] {
+            assert_eq!(chandef.validate(), Ok(()), "{:?}", chandef);
+        }
+    }
+
+    #[test]
+    fn validate_invalid() {
+        for chandef in [
+            // Control channel outside of the segment
+            Nl80211ChannelDef::new(5180)
+                .width(Nl80211ChannelWidth::Mhz(40), 5220),
+            // Center frequency not aligned to the 20 MHz channels
+            Nl80211ChannelDef::new(5180)
+                .width(Nl80211ChannelWidth::Mhz(80), 5205),
+            // Unsupported width
+            Nl80211ChannelDef::new(5180)
+                .width(Nl80211ChannelWidth::Mhz(30), 5190),
+            // Missing, adjacent and unexpected second segment
+            Nl80211ChannelDef::new(5180)
+                .width(Nl80211ChannelWidth::Mhz80Plus80, 5210),
+            Nl80211ChannelDef::new(5180)
+                .width(Nl80211ChannelWidth::Mhz80Plus80, 5210)
+                .center_freq2(5290),
+            Nl80211ChannelDef::new(5180)
+                .width(Nl80211ChannelWidth::Mhz(80), 5210)
+                .center_freq2(5290),
+        ] {
+            assert!(
+                matches!(
+                    chandef.validate(),
+                    Err(Nl80211Error::InvalidArgument(_))
+                ),
+                "{:?}",
+                chandef
+            );
+        }
+    }
+}
@@ -0,0 +1,83 @@
+// SPDX-License-Identifier: MIT
+
+const WLAN_CIPHER_SUITE_USE_GROUP: u32 = 0x000FAC00;
+const WLAN_CIPHER_SUITE_WEP40: u32 = 0x000FAC01;
+const WLAN_CIPHER_SUITE_TKIP: u32 = 0x000FAC02;
+const WLAN_CIPHER_SUITE_CCMP: u32 = 0x000FAC04;
+const WLAN_CIPHER_SUITE_WEP104: u32 = 0x000FAC05;
+const WLAN_CIPHER_SUITE_AES_CMAC: u32 = 0x000FAC06;
+const WLAN_CIPHER_SUITE_GROUP_NOT_ALLOWED: u32 = 0x000FAC07;
+const WLAN_CIPHER_SUITE_GCMP: u32 = 0x000FAC08;
+const WLAN_CIPHER_SUITE_GCMP_256: u32 = 0x000FAC09;
+const WLAN_CIPHER_SUITE_CCMP_256: u32 = 0x000FAC0A;
+const WLAN_CIPHER_SUITE_BIP_GMAC_128: u32 = 0x000FAC0B;
+const WLAN_CIPHER_SUITE_BIP_GMAC_256: u32 = 0x000FAC0C;
+const WLAN_CIPHER_SUITE_BIP_CMAC_256: u32 = 0x000FAC0D;
+
+/// Cipher suite selector, the OUI is stored in the upper 24 bits and the
+/// suite type in the lower 8 bits.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Nl80211CipherSuite {
+    /// Use group cipher suite
+    UseGroup,
+    Wep40,
+    Tkip,
+    /// CCMP-128
+    Ccmp,
+    Wep104,
+    /// BIP-CMAC-128
+    AesCmac,
+    GroupNotAllowed,
+    /// GCMP-128
+    Gcmp,
+    Gcmp256,
+    Ccmp256,
+    BipGmac128,
+    BipGmac256,
+    BipCmac256,
+    Other(u32),
+}
+
+impl From<u32> for Nl80211CipherSuite {
+    fn from(d: u32) -> Self {
+        match d {
+            WLAN_CIPHER_SUITE_USE_GROUP => Self::UseGroup,
+            WLAN_CIPHER_SUITE_WEP40 => Self::Wep40,
+            WLAN_CIPHER_SUITE_TKIP => Self::Tkip,
+            WLAN_CIPHER_SUITE_CCMP => Self::Ccmp,
+            WLAN_CIPHER_SUITE_WEP104 => Self::Wep104,
+            WLAN_CIPHER_SUITE_AES_CMAC => Self::AesCmac,
+            WLAN_CIPHER_SUITE_GROUP_NOT_ALLOWED => Self::GroupNotAllowed,
+            WLAN_CIPHER_SUITE_GCMP => Self::Gcmp,
+            WLAN_CIPHER_SUITE_GCMP_256 => Self::Gcmp256,
+            WLAN_CIPHER_SUITE_CCMP_256 => Self::Ccmp256,
+            WLAN_CIPHER_SUITE_BIP_GMAC_128 => Self::BipGmac128,
+            WLAN_CIPHER_SUITE_BIP_GMAC_256 => Self::BipGmac256,
+            WLAN_CIPHER_SUITE_BIP_CMAC_256 => Self::BipCmac256,
+            _ => Self::Other(d),
+        }
+    }
+}
+
+impl From<Nl80211CipherSuite> for u32 {
+    fn from(v: Nl80211CipherSuite) -> u32 {
+        match v {
+            Nl80211CipherSuite::UseGroup => WLAN_CIPHER_SUITE_USE_GROUP,
+            Nl80211CipherSuite::Wep40 => WLAN_CIPHER_SUITE_WEP40,
+            Nl80211CipherSuite::Tkip => WLAN_CIPHER_SUITE_TKIP,
+            Nl80211CipherSuite::Ccmp => WLAN_CIPHER_SUITE_CCMP,
+            Nl80211CipherSuite::Wep104 => WLAN_CIPHER_SUITE_WEP104,
+            Nl80211CipherSuite::AesCmac => WLAN_CIPHER_SUITE_AES_CMAC,
+            Nl80211CipherSuite::GroupNotAllowed => {
+                WLAN_CIPHER_SUITE_GROUP_NOT_ALLOWED
+            }
+            Nl80211CipherSuite::Gcmp => WLAN_CIPHER_SUITE_GCMP,
+            Nl80211CipherSuite::Gcmp256 => WLAN_CIPHER_SUITE_GCMP_256,
+            Nl80211CipherSuite::Ccmp256 => WLAN_CIPHER_SUITE_CCMP_256,
+            Nl80211CipherSuite::BipGmac128 => WLAN_CIPHER_SUITE_BIP_GMAC_128,
+            Nl80211CipherSuite::BipGmac256 => WLAN_CIPHER_SUITE_BIP_GMAC_256,
+            Nl80211CipherSuite::BipCmac256 => WLAN_CIPHER_SUITE_BIP_CMAC_256,
+            Nl80211CipherSuite::Other(d) => d,
+        }
+    }
+}
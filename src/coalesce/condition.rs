@@ -0,0 +1,37 @@
+// SPDX-License-Identifier: MIT
+
+const NL80211_COALESCE_CONDITION_MATCH: u32 = 0;
+const NL80211_COALESCE_CONDITION_NO_MATCH: u32 = 1;
+
+/// Whether a coalesce rule applies to packets matching its patterns or to
+/// packets matching none of them
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Nl80211CoalesceCondition {
+    /// Coalesce packets matching any of the patterns
+    Match,
+    /// Coalesce packets matching none of the patterns
+    NoMatch,
+    Other(u32),
+}
+
+impl From<u32> for Nl80211CoalesceCondition {
+    fn from(d: u32) -> Self {
+        match d {
+            NL80211_COALESCE_CONDITION_MATCH => Self::Match,
+            NL80211_COALESCE_CONDITION_NO_MATCH => Self::NoMatch,
+            _ => Self::Other(d),
+        }
+    }
+}
+
+impl From<Nl80211CoalesceCondition> for u32 {
+    fn from(v: Nl80211CoalesceCondition) -> u32 {
+        match v {
+            Nl80211CoalesceCondition::Match => NL80211_COALESCE_CONDITION_MATCH,
+            Nl80211CoalesceCondition::NoMatch => {
+                NL80211_COALESCE_CONDITION_NO_MATCH
+            }
+            Nl80211CoalesceCondition::Other(d) => d,
+        }
+    }
+}
@@ -0,0 +1,36 @@
+// SPDX-License-Identifier: MIT
+
+use futures::TryStream;
+use netlink_packet_core::NLM_F_REQUEST;
+use netlink_packet_generic::GenlMessage;
+
+use crate::{
+    nl80211_execute, Nl80211Attr, Nl80211Cmd, Nl80211Error, Nl80211Handle,
+    Nl80211Message,
+};
+
+pub struct Nl80211CoalesceGetRequest {
+    handle: Nl80211Handle,
+    wiphy: u32,
+}
+
+impl Nl80211CoalesceGetRequest {
+    pub(crate) fn new(handle: Nl80211Handle, wiphy: u32) -> Self {
+        Nl80211CoalesceGetRequest { handle, wiphy }
+    }
+
+    /// The reply holds `Nl80211Attr::CoalesceRules` only when rules are
+    /// configured.
+    pub async fn execute(
+        self,
+    ) -> impl TryStream<Ok = GenlMessage<Nl80211Message>, Error = Nl80211Error>
+    {
+        let Nl80211CoalesceGetRequest { mut handle, wiphy } = self;
+
+        let nl80211_msg = Nl80211Message {
+            cmd: Nl80211Cmd::CoalesceGet,
+            nlas: vec![Nl80211Attr::WiPhy(wiphy)],
+        };
+        nl80211_execute(&mut handle, nl80211_msg, NLM_F_REQUEST).await
+    }
+}
@@ -0,0 +1,26 @@
+// SPDX-License-Identifier: MIT
+
+use crate::{
+    Nl80211CoalesceGetRequest, Nl80211CoalesceSetRequest, Nl80211Handle,
+};
+
+pub struct Nl80211CoalesceHandle(Nl80211Handle);
+
+impl Nl80211CoalesceHandle {
+    pub fn new(handle: Nl80211Handle) -> Self {
+        Nl80211CoalesceHandle(handle)
+    }
+
+    /// Retrieve the packet coalesce rules configured on specified wiphy
+    /// (equivalent to `iw phy PHY coalesce show`)
+    pub fn get(&mut self, wiphy: u32) -> Nl80211CoalesceGetRequest {
+        Nl80211CoalesceGetRequest::new(self.0.clone(), wiphy)
+    }
+
+    /// Configure the packet coalesce rules of specified wiphy, the rules
+    /// are cleared when none is set
+    /// (equivalent to `iw phy PHY coalesce enable ...`)
+    pub fn set(&mut self, wiphy: u32) -> Nl80211CoalesceSetRequest {
+        Nl80211CoalesceSetRequest::new(self.0.clone(), wiphy)
+    }
+}
@@ -0,0 +1,15 @@
+// SPDX-License-Identifier: MIT
+
+mod condition;
+mod get;
+mod handle;
+mod rule;
+mod set;
+mod support;
+
+pub use condition::Nl80211CoalesceCondition;
+pub use get::Nl80211CoalesceGetRequest;
+pub use handle::Nl80211CoalesceHandle;
+pub use rule::{Nl80211CoalesceRule, Nl80211CoalesceRuleNla};
+pub use set::Nl80211CoalesceSetRequest;
+pub use support::Nl80211CoalesceRuleSupport;
@@ -0,0 +1,195 @@
+// SPDX-License-Identifier: MIT
+
+use anyhow::Context;
+use byteorder::{ByteOrder, NativeEndian};
+use netlink_packet_utils::{
+    nla::{DefaultNla, Nla, NlaBuffer, NlasIterator},
+    parsers::parse_u32,
+    DecodeError, Emitable, Parseable,
+};
+
+use crate::{
+    nested::nested_array, Nl80211CoalesceCondition, Nl80211PacketPattern,
+};
+
+const NL80211_ATTR_COALESCE_RULE_DELAY: u16 = 1;
+const NL80211_ATTR_COALESCE_RULE_CONDITION: u16 = 2;
+const NL80211_ATTR_COALESCE_RULE_PKT_PATTERN: u16 = 3;
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum Nl80211CoalesceRuleNla {
+    /// Maximum delay in milliseconds the matching packets are held for
+    /// before being delivered to the host
+    Delay(u32),
+    /// Whether the rule applies to packets matching the patterns or not
+    Condition(Nl80211CoalesceCondition),
+    /// Packet patterns of the rule
+    PktPattern(Vec<Nl80211PacketPattern>),
+    Other(DefaultNla),
+}
+
+impl Nla for Nl80211CoalesceRuleNla {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::Delay(_) | Self::Condition(_) => 4,
+            Self::PktPattern(ref d) => nested_array(d).as_slice().buffer_len(),
+            Self::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::Delay(_) => NL80211_ATTR_COALESCE_RULE_DELAY,
+            Self::Condition(_) => NL80211_ATTR_COALESCE_RULE_CONDITION,
+            Self::PktPattern(_) => NL80211_ATTR_COALESCE_RULE_PKT_PATTERN,
+            Self::Other(attr) => attr.kind(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Delay(d) => NativeEndian::write_u32(buffer, *d),
+            Self::Condition(d) => {
+                NativeEndian::write_u32(buffer, u32::from(*d))
+            }
+            Self::PktPattern(ref d) => nested_array(d).as_slice().emit(buffer),
+            Self::Other(ref attr) => attr.emit(buffer),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for Nl80211CoalesceRuleNla
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            NL80211_ATTR_COALESCE_RULE_DELAY => {
+                let err_msg = format!(
+                    "Invalid NL80211_ATTR_COALESCE_RULE_DELAY value {:?}",
+                    payload
+                );
+                Self::Delay(parse_u32(payload).context(err_msg)?)
+            }
+            NL80211_ATTR_COALESCE_RULE_CONDITION => {
+                let err_msg = format!(
+                    "Invalid NL80211_ATTR_COALESCE_RULE_CONDITION value {:?}",
+                    payload
+                );
+                Self::Condition(parse_u32(payload).context(err_msg)?.into())
+            }
+            NL80211_ATTR_COALESCE_RULE_PKT_PATTERN => {
+                let err_msg = format!(
+                    "Invalid NL80211_ATTR_COALESCE_RULE_PKT_PATTERN value {:?}",
+                    payload
+                );
+                let mut values = Vec::new();
+                for nla in NlasIterator::new(payload) {
+                    let nla = &nla.context(err_msg.clone())?;
+                    values.push(
+                        Nl80211PacketPattern::parse(nla)
+                            .context(err_msg.clone())?,
+                    );
+                }
+                Self::PktPattern(values)
+            }
+            _ => Self::Other(
+                DefaultNla::parse(buf).context("invalid NLA (unknown kind)")?,
+            ),
+        })
+    }
+}
+
+/// Packet coalescing rule: received packets the rule applies to are held
+/// by the device for up to `delay` milliseconds to reduce host wakeups
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct Nl80211CoalesceRule {
+    /// Maximum delay in milliseconds the packets are held for
+    pub delay: Option<u32>,
+    /// Whether the rule applies to packets matching the patterns or not,
+    /// defaults to matching
+    pub condition: Option<Nl80211CoalesceCondition>,
+    /// Packet patterns of the rule, at least one is required
+    pub pkt_pattern: Vec<Nl80211PacketPattern>,
+}
+
+impl Nl80211CoalesceRule {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Maximum delay in milliseconds the packets are held for
+    pub fn delay(mut self, delay: u32) -> Self {
+        self.delay = Some(delay);
+        self
+    }
+
+    pub fn condition(mut self, condition: Nl80211CoalesceCondition) -> Self {
+        self.condition = Some(condition);
+        self
+    }
+
+    /// Append a packet pattern, can be used multiple times
+    pub fn pattern(mut self, pattern: Nl80211PacketPattern) -> Self {
+        self.pkt_pattern.push(pattern);
+        self
+    }
+}
+
+impl Emitable for Nl80211CoalesceRule {
+    fn buffer_len(&self) -> usize {
+        Vec::<Nl80211CoalesceRuleNla>::from(self)
+            .as_slice()
+            .buffer_len()
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        Vec::<Nl80211CoalesceRuleNla>::from(self)
+            .as_slice()
+            .emit(buffer)
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for Nl80211CoalesceRule
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let mut ret = Self::default();
+        let payload = buf.value();
+        let err_msg =
+            format!("Invalid NL80211_ATTR_COALESCE_RULE value {:?}", payload);
+        for nla in NlasIterator::new(payload) {
+            let nla = &nla.context(err_msg.clone())?;
+            match Nl80211CoalesceRuleNla::parse(nla).context(err_msg.clone())? {
+                Nl80211CoalesceRuleNla::Delay(d) => ret.delay = Some(d),
+                Nl80211CoalesceRuleNla::Condition(d) => ret.condition = Some(d),
+                Nl80211CoalesceRuleNla::PktPattern(d) => ret.pkt_pattern = d,
+                Nl80211CoalesceRuleNla::Other(attr) => {
+                    log::warn!(
+                        "Got unsupported NL80211_ATTR_COALESCE_RULE value {:?}",
+                        attr
+                    )
+                }
+            }
+        }
+        Ok(ret)
+    }
+}
+
+impl From<&Nl80211CoalesceRule> for Vec<Nl80211CoalesceRuleNla> {
+    fn from(rule: &Nl80211CoalesceRule) -> Self {
+        let mut nlas = Vec::new();
+        if let Some(v) = rule.delay {
+            nlas.push(Nl80211CoalesceRuleNla::Delay(v));
+        }
+        if let Some(v) = rule.condition {
+            nlas.push(Nl80211CoalesceRuleNla::Condition(v));
+        }
+        if !rule.pkt_pattern.is_empty() {
+            nlas.push(Nl80211CoalesceRuleNla::PktPattern(
+                rule.pkt_pattern.clone(),
+            ));
+        }
+        nlas
+    }
+}
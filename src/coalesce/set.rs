@@ -0,0 +1,48 @@
+// SPDX-License-Identifier: MIT
+
+use crate::{
+    nl80211_execute_ack, Nl80211Attr, Nl80211Cmd, Nl80211CoalesceRule,
+    Nl80211Error, Nl80211Handle, Nl80211Message,
+};
+
+pub struct Nl80211CoalesceSetRequest {
+    handle: Nl80211Handle,
+    wiphy: u32,
+    rules: Vec<Nl80211CoalesceRule>,
+}
+
+impl Nl80211CoalesceSetRequest {
+    pub(crate) fn new(handle: Nl80211Handle, wiphy: u32) -> Self {
+        Nl80211CoalesceSetRequest {
+            handle,
+            wiphy,
+            rules: Vec::new(),
+        }
+    }
+
+    /// Append a coalesce rule, can be used multiple times
+    pub fn rule(mut self, rule: Nl80211CoalesceRule) -> Self {
+        self.rules.push(rule);
+        self
+    }
+
+    pub async fn execute(self) -> Result<(), Nl80211Error> {
+        let Nl80211CoalesceSetRequest {
+            mut handle,
+            wiphy,
+            rules,
+        } = self;
+
+        let mut nlas = vec![Nl80211Attr::WiPhy(wiphy)];
+        // Kernel clears the configured rules when no rule is given
+        if !rules.is_empty() {
+            nlas.push(Nl80211Attr::CoalesceRules(rules));
+        }
+
+        let nl80211_msg = Nl80211Message {
+            cmd: Nl80211Cmd::CoalesceSet,
+            nlas,
+        };
+        nl80211_execute_ack(&mut handle, nl80211_msg).await
+    }
+}
@@ -0,0 +1,48 @@
+// SPDX-License-Identifier: MIT
+
+use byteorder::{ByteOrder, NativeEndian};
+use netlink_packet_utils::{DecodeError, Emitable};
+
+use crate::Nl80211PatternSupport;
+
+/// Packet coalescing capabilities of the wiphy
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+pub struct Nl80211CoalesceRuleSupport {
+    /// Maximum number of coalesce rules
+    pub max_rules: u32,
+    /// Packet pattern limits of each rule
+    pub pat: Nl80211PatternSupport,
+    /// Maximum coalescing delay in milliseconds
+    pub max_delay: u32,
+}
+
+impl Nl80211CoalesceRuleSupport {
+    pub(crate) const LENGTH: usize = 8 + Nl80211PatternSupport::LENGTH;
+
+    pub(crate) fn parse(payload: &[u8]) -> Result<Self, DecodeError> {
+        if payload.len() < Self::LENGTH {
+            return Err(format!(
+                "Invalid NL80211_ATTR_COALESCE_RULE {:?}",
+                payload
+            )
+            .into());
+        }
+        Ok(Self {
+            max_rules: NativeEndian::read_u32(&payload[0..4]),
+            pat: Nl80211PatternSupport::parse(&payload[4..20])?,
+            max_delay: NativeEndian::read_u32(&payload[20..24]),
+        })
+    }
+}
+
+impl Emitable for Nl80211CoalesceRuleSupport {
+    fn buffer_len(&self) -> usize {
+        Self::LENGTH
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        NativeEndian::write_u32(&mut buffer[0..4], self.max_rules);
+        self.pat.emit(&mut buffer[4..20]);
+        NativeEndian::write_u32(&mut buffer[20..24], self.max_delay);
+    }
+}
@@ -0,0 +1,317 @@
+// SPDX-License-Identifier: MIT
+
+use anyhow::Context;
+use byteorder::{ByteOrder, NativeEndian};
+use netlink_packet_utils::{
+    nla::{DefaultNla, Nla, NlaBuffer, NlasIterator},
+    parsers::{parse_i32, parse_u32},
+    DecodeError, Emitable, Parseable,
+};
+
+use crate::Nl80211CqmRssiThresholdEvent;
+
+const NL80211_ATTR_CQM_RSSI_THOLD: u16 = 1;
+const NL80211_ATTR_CQM_RSSI_HYST: u16 = 2;
+const NL80211_ATTR_CQM_RSSI_THRESHOLD_EVENT: u16 = 3;
+const NL80211_ATTR_CQM_PKT_LOSS_EVENT: u16 = 4;
+const NL80211_ATTR_CQM_TXE_RATE: u16 = 5;
+const NL80211_ATTR_CQM_TXE_PKTS: u16 = 6;
+const NL80211_ATTR_CQM_TXE_INTVL: u16 = 7;
+const NL80211_ATTR_CQM_BEACON_LOSS_EVENT: u16 = 8;
+const NL80211_ATTR_CQM_RSSI_LEVEL: u16 = 9;
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum Nl80211CqmNla {
+    /// RSSI thresholds in dBm, a single threshold or a list of thresholds
+    /// in ascending order
+    RssiThold(Vec<i32>),
+    /// RSSI hysteresis in dB
+    RssiHyst(u32),
+    /// Direction of the RSSI threshold crossing
+    RssiThresholdEvent(Nl80211CqmRssiThresholdEvent),
+    /// Number of consecutive packets lost to the peer
+    PktLossEvent(u32),
+    /// Minimum percentage of failed transmissions within the interval to
+    /// report
+    TxeRate(u32),
+    /// Minimum number of attempted transmissions within the interval to
+    /// check the TX error rate
+    TxePkts(u32),
+    /// Interval in seconds the TX error rate is checked in, 0 to disable TX
+    /// error reporting
+    TxeIntvl(u32),
+    /// Beacons of the connected BSS are no longer received
+    BeaconLossEvent,
+    /// RSSI in dBm which crossed the threshold
+    RssiLevel(i32),
+    Other(DefaultNla),
+}
+
+impl Nla for Nl80211CqmNla {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::BeaconLossEvent => 0,
+            Self::RssiHyst(_)
+            | Self::RssiThresholdEvent(_)
+            | Self::PktLossEvent(_)
+            | Self::TxeRate(_)
+            | Self::TxePkts(_)
+            | Self::TxeIntvl(_)
+            | Self::RssiLevel(_) => 4,
+            Self::RssiThold(ref d) => d.len() * 4,
+            Self::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::RssiThold(_) => NL80211_ATTR_CQM_RSSI_THOLD,
+            Self::RssiHyst(_) => NL80211_ATTR_CQM_RSSI_HYST,
+            Self::RssiThresholdEvent(_) => {
+                NL80211_ATTR_CQM_RSSI_THRESHOLD_EVENT
+            }
+            Self::PktLossEvent(_) => NL80211_ATTR_CQM_PKT_LOSS_EVENT,
+            Self::TxeRate(_) => NL80211_ATTR_CQM_TXE_RATE,
+            Self::TxePkts(_) => NL80211_ATTR_CQM_TXE_PKTS,
+            Self::TxeIntvl(_) => NL80211_ATTR_CQM_TXE_INTVL,
+            Self::BeaconLossEvent => NL80211_ATTR_CQM_BEACON_LOSS_EVENT,
+            Self::RssiLevel(_) => NL80211_ATTR_CQM_RSSI_LEVEL,
+            Self::Other(attr) => attr.kind(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::BeaconLossEvent => (),
+            Self::RssiHyst(d)
+            | Self::PktLossEvent(d)
+            | Self::TxeRate(d)
+            | Self::TxePkts(d)
+            | Self::TxeIntvl(d) => NativeEndian::write_u32(buffer, *d),
+            Self::RssiLevel(d) => NativeEndian::write_i32(buffer, *d),
+            Self::RssiThresholdEvent(d) => {
+                NativeEndian::write_u32(buffer, (*d).into())
+            }
+            Self::RssiThold(ref d) => {
+                for (i, v) in d.iter().enumerate() {
+                    NativeEndian::write_i32(
+                        &mut buffer[i * 4..(i + 1) * 4],
+                        *v,
+                    );
+                }
+            }
+            Self::Other(ref attr) => attr.emit(buffer),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for Nl80211CqmNla
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            NL80211_ATTR_CQM_RSSI_THOLD => Self::RssiThold(
+                payload
+                    .chunks_exact(4)
+                    .map(NativeEndian::read_i32)
+                    .collect(),
+            ),
+            NL80211_ATTR_CQM_RSSI_HYST => {
+                let err_msg = format!(
+                    "Invalid NL80211_ATTR_CQM_RSSI_HYST value {:?}",
+                    payload
+                );
+                Self::RssiHyst(parse_u32(payload).context(err_msg)?)
+            }
+            NL80211_ATTR_CQM_RSSI_THRESHOLD_EVENT => {
+                let err_msg = format!(
+                    "Invalid NL80211_ATTR_CQM_RSSI_THRESHOLD_EVENT value {:?}",
+                    payload
+                );
+                Self::RssiThresholdEvent(
+                    parse_u32(payload).context(err_msg)?.into(),
+                )
+            }
+            NL80211_ATTR_CQM_PKT_LOSS_EVENT => {
+                let err_msg = format!(
+                    "Invalid NL80211_ATTR_CQM_PKT_LOSS_EVENT value {:?}",
+                    payload
+                );
+                Self::PktLossEvent(parse_u32(payload).context(err_msg)?)
+            }
+            NL80211_ATTR_CQM_TXE_RATE => {
+                let err_msg = format!(
+                    "Invalid NL80211_ATTR_CQM_TXE_RATE value {:?}",
+                    payload
+                );
+                Self::TxeRate(parse_u32(payload).context(err_msg)?)
+            }
+            NL80211_ATTR_CQM_TXE_PKTS => {
+                let err_msg = format!(
+                    "Invalid NL80211_ATTR_CQM_TXE_PKTS value {:?}",
+                    payload
+                );
+                Self::TxePkts(parse_u32(payload).context(err_msg)?)
+            }
+            NL80211_ATTR_CQM_TXE_INTVL => {
+                let err_msg = format!(
+                    "Invalid NL80211_ATTR_CQM_TXE_INTVL value {:?}",
+                    payload
+                );
+                Self::TxeIntvl(parse_u32(payload).context(err_msg)?)
+            }
+            NL80211_ATTR_CQM_BEACON_LOSS_EVENT => Self::BeaconLossEvent,
+            NL80211_ATTR_CQM_RSSI_LEVEL => {
+                let err_msg = format!(
+                    "Invalid NL80211_ATTR_CQM_RSSI_LEVEL value {:?}",
+                    payload
+                );
+                Self::RssiLevel(parse_i32(payload).context(err_msg)?)
+            }
+            _ => Self::Other(
+                DefaultNla::parse(buf).context("invalid NLA (unknown kind)")?,
+            ),
+        })
+    }
+}
+
+/// Connection quality monitor configuration or event
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct Nl80211Cqm {
+    /// RSSI thresholds in dBm, a single threshold or a list of thresholds
+    /// in ascending order
+    pub rssi_thold: Option<Vec<i32>>,
+    /// RSSI hysteresis in dB
+    pub rssi_hyst: Option<u32>,
+    /// Direction of the RSSI threshold crossing
+    pub rssi_threshold_event: Option<Nl80211CqmRssiThresholdEvent>,
+    /// Number of consecutive packets lost to the peer
+    pub pkt_loss_event: Option<u32>,
+    /// Minimum percentage of failed transmissions within the interval to
+    /// report
+    pub txe_rate: Option<u32>,
+    /// Minimum number of attempted transmissions within the interval to
+    /// check the TX error rate
+    pub txe_pkts: Option<u32>,
+    /// Interval in seconds the TX error rate is checked in, 0 to disable TX
+    /// error reporting
+    pub txe_intvl: Option<u32>,
+    /// Beacons of the connected BSS are no longer received
+    pub beacon_loss_event: bool,
+    /// RSSI in dBm which crossed the threshold
+    pub rssi_level: Option<i32>,
+}
+
+impl Nl80211Cqm {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Report RSSI crossing `threshold` (dBm) once it differs from the RSSI
+    /// of the previous report by at least `hysteresis` (dB), 0 `threshold`
+    /// to disable RSSI monitoring
+    pub fn rssi(mut self, threshold: i32, hysteresis: u32) -> Self {
+        self.rssi_thold = Some(vec![threshold]);
+        self.rssi_hyst = Some(hysteresis);
+        self
+    }
+
+    /// Report RSSI crossing any of the `thresholds` (dBm), which should be
+    /// in ascending order, requires
+    /// `Nl80211ExtFeature::CqmRssiList`
+    pub fn rssi_thresholds(
+        mut self,
+        thresholds: &[i32],
+        hysteresis: u32,
+    ) -> Self {
+        self.rssi_thold = Some(thresholds.to_vec());
+        self.rssi_hyst = Some(hysteresis);
+        self
+    }
+
+    /// Report when at least `rate` percent of at least `packets` attempted
+    /// transmissions failed within each `interval` (seconds), 0 `interval`
+    /// to disable TX error reporting
+    pub fn txe(mut self, rate: u32, packets: u32, interval: u32) -> Self {
+        self.txe_rate = Some(rate);
+        self.txe_pkts = Some(packets);
+        self.txe_intvl = Some(interval);
+        self
+    }
+}
+
+impl Emitable for Nl80211Cqm {
+    fn buffer_len(&self) -> usize {
+        Vec::<Nl80211CqmNla>::from(self).as_slice().buffer_len()
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        Vec::<Nl80211CqmNla>::from(self).as_slice().emit(buffer)
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for Nl80211Cqm {
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let mut ret = Self::default();
+        let payload = buf.value();
+        let err_msg = format!("Invalid NL80211_ATTR_CQM value {:?}", payload);
+        for nla in NlasIterator::new(payload) {
+            let nla = &nla.context(err_msg.clone())?;
+            match Nl80211CqmNla::parse(nla).context(err_msg.clone())? {
+                Nl80211CqmNla::RssiThold(d) => ret.rssi_thold = Some(d),
+                Nl80211CqmNla::RssiHyst(d) => ret.rssi_hyst = Some(d),
+                Nl80211CqmNla::RssiThresholdEvent(d) => {
+                    ret.rssi_threshold_event = Some(d)
+                }
+                Nl80211CqmNla::PktLossEvent(d) => ret.pkt_loss_event = Some(d),
+                Nl80211CqmNla::TxeRate(d) => ret.txe_rate = Some(d),
+                Nl80211CqmNla::TxePkts(d) => ret.txe_pkts = Some(d),
+                Nl80211CqmNla::TxeIntvl(d) => ret.txe_intvl = Some(d),
+                Nl80211CqmNla::BeaconLossEvent => ret.beacon_loss_event = true,
+                Nl80211CqmNla::RssiLevel(d) => ret.rssi_level = Some(d),
+                Nl80211CqmNla::Other(attr) => {
+                    log::warn!(
+                        "Got unsupported NL80211_ATTR_CQM value {:?}",
+                        attr
+                    )
+                }
+            }
+        }
+        Ok(ret)
+    }
+}
+
+impl From<&Nl80211Cqm> for Vec<Nl80211CqmNla> {
+    fn from(cqm: &Nl80211Cqm) -> Self {
+        let mut nlas = Vec::new();
+        if let Some(v) = cqm.rssi_thold.as_ref() {
+            nlas.push(Nl80211CqmNla::RssiThold(v.clone()));
+        }
+        if let Some(v) = cqm.rssi_hyst {
+            nlas.push(Nl80211CqmNla::RssiHyst(v));
+        }
+        if let Some(v) = cqm.rssi_threshold_event {
+            nlas.push(Nl80211CqmNla::RssiThresholdEvent(v));
+        }
+        if let Some(v) = cqm.pkt_loss_event {
+            nlas.push(Nl80211CqmNla::PktLossEvent(v));
+        }
+        if let Some(v) = cqm.txe_rate {
+            nlas.push(Nl80211CqmNla::TxeRate(v));
+        }
+        if let Some(v) = cqm.txe_pkts {
+            nlas.push(Nl80211CqmNla::TxePkts(v));
+        }
+        if let Some(v) = cqm.txe_intvl {
+            nlas.push(Nl80211CqmNla::TxeIntvl(v));
+        }
+        if cqm.beacon_loss_event {
+            nlas.push(Nl80211CqmNla::BeaconLossEvent);
+        }
+        if let Some(v) = cqm.rssi_level {
+            nlas.push(Nl80211CqmNla::RssiLevel(v));
+        }
+        nlas
+    }
+}
@@ -0,0 +1,59 @@
+// SPDX-License-Identifier: MIT
+
+use futures::{channel::mpsc::UnboundedReceiver, future, Stream, StreamExt};
+use genetlink::message::RawGenlMessage;
+use netlink_packet_core::NetlinkMessage;
+use netlink_sys::SocketAddr;
+
+use crate::{
+    frame::parse_unsolicited, Nl80211Attr, Nl80211Cmd, Nl80211Cqm,
+    Nl80211Message,
+};
+
+const ETH_ALEN: usize = 6;
+
+/// Connection quality monitor event of an interface
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct Nl80211CqmEvent {
+    pub if_index: Option<u32>,
+    /// Address of the peer, for packet loss and TX error events
+    pub mac: Option<[u8; ETH_ALEN]>,
+    pub cqm: Nl80211Cqm,
+}
+
+impl Nl80211CqmEvent {
+    /// Extract the event from a `Nl80211Cmd::CqmNotify` message, `None` for
+    /// other messages
+    pub fn from_message(msg: &Nl80211Message) -> Option<Self> {
+        if msg.cmd != Nl80211Cmd::CqmNotify {
+            return None;
+        }
+        let mut ret = Self::default();
+        let mut cqm = None;
+        for nla in msg.nlas.iter() {
+            match nla {
+                Nl80211Attr::IfIndex(d) => ret.if_index = Some(*d),
+                Nl80211Attr::Mac(d) => ret.mac = Some(*d),
+                Nl80211Attr::Cqm(d) => cqm = Some(d.clone()),
+                _ => (),
+            }
+        }
+        ret.cqm = cqm?;
+        Some(ret)
+    }
+}
+
+/// Stream of the connection quality monitor events received by the
+/// connection subscribed to the `mlme` multicast group, built from the
+/// unsolicited messages returned by `new_connection()`. Other messages and
+/// those failed to parse are dropped.
+pub fn nl80211_cqm_events(
+    messages: UnboundedReceiver<(NetlinkMessage<RawGenlMessage>, SocketAddr)>,
+) -> impl Stream<Item = Nl80211CqmEvent> {
+    messages.filter_map(|(msg, _)| {
+        future::ready(
+            parse_unsolicited(msg)
+                .and_then(|msg| Nl80211CqmEvent::from_message(&msg)),
+        )
+    })
+}
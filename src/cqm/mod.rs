@@ -0,0 +1,11 @@
+// SPDX-License-Identifier: MIT
+
+mod config;
+mod event;
+mod rssi_event;
+mod set;
+
+pub use config::{Nl80211Cqm, Nl80211CqmNla};
+pub use event::{nl80211_cqm_events, Nl80211CqmEvent};
+pub use rssi_event::Nl80211CqmRssiThresholdEvent;
+pub use set::Nl80211CqmSetRequest;
@@ -0,0 +1,47 @@
+// SPDX-License-Identifier: MIT
+
+const NL80211_CQM_RSSI_THRESHOLD_EVENT_LOW: u32 = 0;
+const NL80211_CQM_RSSI_THRESHOLD_EVENT_HIGH: u32 = 1;
+const NL80211_CQM_RSSI_BEACON_LOSS_EVENT: u32 = 2;
+
+/// Direction of a RSSI threshold crossing reported by the connection quality
+/// monitor
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Nl80211CqmRssiThresholdEvent {
+    /// The RSSI level is lower than the configured threshold
+    Low,
+    /// The RSSI is higher than the configured threshold
+    High,
+    /// Reserved, beacon loss is reported by `Nl80211Cqm::beacon_loss_event`
+    /// instead
+    BeaconLoss,
+    Other(u32),
+}
+
+impl From<u32> for Nl80211CqmRssiThresholdEvent {
+    fn from(d: u32) -> Self {
+        match d {
+            NL80211_CQM_RSSI_THRESHOLD_EVENT_LOW => Self::Low,
+            NL80211_CQM_RSSI_THRESHOLD_EVENT_HIGH => Self::High,
+            NL80211_CQM_RSSI_BEACON_LOSS_EVENT => Self::BeaconLoss,
+            _ => Self::Other(d),
+        }
+    }
+}
+
+impl From<Nl80211CqmRssiThresholdEvent> for u32 {
+    fn from(v: Nl80211CqmRssiThresholdEvent) -> u32 {
+        match v {
+            Nl80211CqmRssiThresholdEvent::Low => {
+                NL80211_CQM_RSSI_THRESHOLD_EVENT_LOW
+            }
+            Nl80211CqmRssiThresholdEvent::High => {
+                NL80211_CQM_RSSI_THRESHOLD_EVENT_HIGH
+            }
+            Nl80211CqmRssiThresholdEvent::BeaconLoss => {
+                NL80211_CQM_RSSI_BEACON_LOSS_EVENT
+            }
+            Nl80211CqmRssiThresholdEvent::Other(d) => d,
+        }
+    }
+}
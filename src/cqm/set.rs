@@ -0,0 +1,42 @@
+// SPDX-License-Identifier: MIT
+
+use crate::{
+    nl80211_execute_ack, Nl80211Attr, Nl80211Cmd, Nl80211Cqm, Nl80211Error,
+    Nl80211Handle, Nl80211Message,
+};
+
+pub struct Nl80211CqmSetRequest {
+    handle: Nl80211Handle,
+    if_index: u32,
+    cqm: Nl80211Cqm,
+}
+
+impl Nl80211CqmSetRequest {
+    pub(crate) fn new(
+        handle: Nl80211Handle,
+        if_index: u32,
+        cqm: Nl80211Cqm,
+    ) -> Self {
+        Nl80211CqmSetRequest {
+            handle,
+            if_index,
+            cqm,
+        }
+    }
+
+    /// Crossed thresholds are reported by `Nl80211Cmd::CqmNotify` events to
+    /// the `mlme` multicast group.
+    pub async fn execute(self) -> Result<(), Nl80211Error> {
+        let Nl80211CqmSetRequest {
+            mut handle,
+            if_index,
+            cqm,
+        } = self;
+
+        let nl80211_msg = Nl80211Message {
+            cmd: Nl80211Cmd::CqmSet,
+            nlas: vec![Nl80211Attr::IfIndex(if_index), Nl80211Attr::Cqm(cqm)],
+        };
+        nl80211_execute_ack(&mut handle, nl80211_msg).await
+    }
+}
@@ -0,0 +1,9 @@
+// SPDX-License-Identifier: MIT
+
+mod mfp;
+mod settings;
+mod wpa_version;
+
+pub use mfp::Nl80211Mfp;
+pub use settings::Nl80211CryptoSettings;
+pub use wpa_version::Nl80211WpaVersions;
@@ -0,0 +1,152 @@
+// SPDX-License-Identifier: MIT
+
+use crate::{
+    Nl80211AkmSuite, Nl80211Attr, Nl80211CipherSuite, Nl80211WpaVersions,
+};
+
+/// Security settings of a connection or association
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct Nl80211CryptoSettings {
+    pub wpa_versions: Nl80211WpaVersions,
+    pub akm_suites: Vec<Nl80211AkmSuite>,
+    pub pairwise_ciphers: Vec<Nl80211CipherSuite>,
+    pub group_cipher: Option<Nl80211CipherSuite>,
+    /// Userspace handles the EAPOL frames of the controlled port, e.g. the
+    /// 4-way handshake
+    pub control_port: bool,
+    /// Privacy is required, set for any encrypted network
+    pub privacy: bool,
+    /// Password for drivers offloading SAE authentication
+    pub sae_password: Option<Vec<u8>>,
+    /// PSK for drivers offloading the 4-way handshake
+    pub pmk: Option<Vec<u8>>,
+    /// Offload the 4-way handshake of 802.1X authentication to the driver
+    pub want_1x_4way_hs: bool,
+}
+
+impl Nl80211CryptoSettings {
+    /// Open network without encryption
+    pub fn open() -> Self {
+        Self::default()
+    }
+
+    /// WPA2-Personal using CCMP, the 4-way handshake is performed by
+    /// userspace over the control port
+    pub fn wpa2_psk() -> Self {
+        Self::rsn(Nl80211WpaVersions::WPA2, Nl80211AkmSuite::Psk)
+    }
+
+    /// WPA2-Enterprise using CCMP
+    pub fn wpa2_eap() -> Self {
+        Self::rsn(Nl80211WpaVersions::WPA2, Nl80211AkmSuite::Ieee8021x)
+    }
+
+    /// WPA3-Personal using CCMP
+    pub fn wpa3_sae() -> Self {
+        Self::rsn(Nl80211WpaVersions::WPA3, Nl80211AkmSuite::Sae)
+    }
+
+    fn rsn(wpa_versions: Nl80211WpaVersions, akm: Nl80211AkmSuite) -> Self {
+        Self {
+            wpa_versions,
+            akm_suites: vec![akm],
+            pairwise_ciphers: vec![Nl80211CipherSuite::Ccmp],
+            group_cipher: Some(Nl80211CipherSuite::Ccmp),
+            control_port: true,
+            privacy: true,
+            sae_password: None,
+            pmk: None,
+            want_1x_4way_hs: false,
+        }
+    }
+
+    pub fn wpa_versions(mut self, versions: Nl80211WpaVersions) -> Self {
+        self.wpa_versions = versions;
+        self
+    }
+
+    /// Add an allowed AKM suite
+    pub fn akm_suite(mut self, akm: Nl80211AkmSuite) -> Self {
+        self.akm_suites.push(akm);
+        self
+    }
+
+    /// Add an allowed pairwise cipher suite
+    pub fn pairwise_cipher(mut self, cipher: Nl80211CipherSuite) -> Self {
+        self.pairwise_ciphers.push(cipher);
+        self
+    }
+
+    pub fn group_cipher(mut self, cipher: Nl80211CipherSuite) -> Self {
+        self.group_cipher = Some(cipher);
+        self
+    }
+
+    pub fn control_port(mut self, enabled: bool) -> Self {
+        self.control_port = enabled;
+        self
+    }
+
+    pub fn privacy(mut self, enabled: bool) -> Self {
+        self.privacy = enabled;
+        self
+    }
+
+    /// Let the driver perform SAE authentication with specified password,
+    /// requires `Nl80211ExtFeature::SaeOffload`
+    pub fn sae_password(mut self, password: &[u8]) -> Self {
+        self.sae_password = Some(password.to_vec());
+        self
+    }
+
+    /// Let the driver perform the 4-way handshake with specified PSK,
+    /// requires `Nl80211ExtFeature::FourWayHandshakeStaPsk`
+    pub fn pmk(mut self, pmk: &[u8]) -> Self {
+        self.pmk = Some(pmk.to_vec());
+        self
+    }
+
+    /// Let the driver perform the 4-way handshake of 802.1X authentication,
+    /// the PMK is provided later via `NL80211_CMD_SET_PMK`. Requires
+    /// `Nl80211ExtFeature::FourWayHandshakeSta1x`
+    pub fn want_1x_4way_hs(mut self, enabled: bool) -> Self {
+        self.want_1x_4way_hs = enabled;
+        self
+    }
+}
+
+impl From<&Nl80211CryptoSettings> for Vec<Nl80211Attr> {
+    fn from(settings: &Nl80211CryptoSettings) -> Self {
+        let mut nlas = Vec::new();
+        if !settings.wpa_versions.is_empty() {
+            nlas.push(Nl80211Attr::WpaVersions(settings.wpa_versions));
+        }
+        if !settings.akm_suites.is_empty() {
+            nlas.push(Nl80211Attr::AkmSuites(settings.akm_suites.clone()));
+        }
+        if !settings.pairwise_ciphers.is_empty() {
+            nlas.push(Nl80211Attr::CipherSuitesPairwise(
+                settings.pairwise_ciphers.clone(),
+            ));
+        }
+        if let Some(cipher) = settings.group_cipher {
+            nlas.push(Nl80211Attr::CipherSuiteGroup(cipher));
+        }
+        if settings.control_port {
+            nlas.push(Nl80211Attr::ControlPort);
+        }
+        if settings.privacy {
+            nlas.push(Nl80211Attr::Privacy);
+        }
+        if let Some(password) = settings.sae_password.as_ref() {
+            nlas.push(Nl80211Attr::SaePassword(password.clone()));
+        }
+        if let Some(pmk) = settings.pmk.as_ref() {
+            nlas.push(Nl80211Attr::Pmk(pmk.clone()));
+        }
+        if settings.want_1x_4way_hs {
+            nlas.push(Nl80211Attr::Want1x4WayHs);
+        }
+        nlas
+    }
+}
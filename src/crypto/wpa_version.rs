@@ -0,0 +1,15 @@
+// SPDX-License-Identifier: MIT
+
+use crate::macros::nl80211_bitflags;
+
+nl80211_bitflags! {
+    /// WPA versions, could be combined via `|` operator.
+    pub struct Nl80211WpaVersions: u32 {
+        /// WPA
+        const WPA1 = 1 << 0;
+        /// WPA2 (RSN)
+        const WPA2 = 1 << 1;
+        /// WPA3
+        const WPA3 = 1 << 2;
+    }
+}
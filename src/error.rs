@@ -18,6 +18,9 @@ pub enum Nl80211Error {
     #[error("A netlink request failed")]
     RequestFailed(String),
 
+    #[error("Invalid argument: {0}")]
+    InvalidArgument(String),
+
     #[error("A bug in this crate")]
     Bug(String),
 }
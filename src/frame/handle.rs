@@ -0,0 +1,84 @@
+// SPDX-License-Identifier: MIT
+
+use crate::{
+    Nl80211BeaconsRegisterRequest, Nl80211FrameRegisterRequest,
+    Nl80211FrameTxRequest, Nl80211Handle, Nl80211RemainOnChannelCancelRequest,
+    Nl80211RemainOnChannelRequest,
+};
+
+pub struct Nl80211FrameHandle(Nl80211Handle);
+
+impl Nl80211FrameHandle {
+    pub fn new(handle: Nl80211Handle) -> Self {
+        Nl80211FrameHandle(handle)
+    }
+
+    /// Receive management frames of `frame_type` whose body starts with
+    /// `frame_match` on specified interface. The frames are sent to the socket
+    /// of the request in `Nl80211Cmd::Frame` messages until the socket is
+    /// closed, see `nl80211_received_frames()`.
+    pub fn register(
+        &mut self,
+        if_index: u32,
+        frame_type: u16,
+        frame_match: Vec<u8>,
+    ) -> Nl80211FrameRegisterRequest {
+        Nl80211FrameRegisterRequest::new(
+            self.0.clone(),
+            if_index,
+            frame_type,
+            frame_match,
+        )
+    }
+
+    /// Transmit the raw management frame including its header on specified
+    /// interface
+    pub fn transmit(
+        &mut self,
+        if_index: u32,
+        frame: Vec<u8>,
+    ) -> Nl80211FrameTxRequest {
+        Nl80211FrameTxRequest::new(self.0.clone(), if_index, frame)
+    }
+
+    /// Stay on the channel of `freq`(MHz) for `duration`(milliseconds), for
+    /// example to exchange Public Action frames while associated on another
+    /// channel
+    pub fn remain_on_channel(
+        &mut self,
+        if_index: u32,
+        freq: u32,
+        duration: u32,
+    ) -> Nl80211RemainOnChannelRequest {
+        Nl80211RemainOnChannelRequest::new(
+            self.0.clone(),
+            if_index,
+            freq,
+            duration,
+        )
+    }
+
+    /// Cancel the remain on channel request identified by `cookie`
+    pub fn cancel_remain_on_channel(
+        &mut self,
+        if_index: u32,
+        cookie: u64,
+    ) -> Nl80211RemainOnChannelCancelRequest {
+        Nl80211RemainOnChannelCancelRequest::new(
+            self.0.clone(),
+            if_index,
+            cookie,
+        )
+    }
+
+    /// Receive beacons of other BSSes on the specified wiphy while any of
+    /// its interfaces operates as access point. The beacons are sent to the
+    /// socket of the request in `Nl80211Cmd::Frame` messages until the socket
+    /// is closed, only one socket per wiphy may register.
+    pub fn register_beacons(
+        &mut self,
+        wiphy: u32,
+    ) -> Nl80211BeaconsRegisterRequest {
+        Nl80211BeaconsRegisterRequest::new(self.0.clone(), wiphy)
+    }
+}
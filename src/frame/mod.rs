@@ -0,0 +1,22 @@
+// SPDX-License-Identifier: MIT
+
+mod handle;
+mod register;
+mod register_beacons;
+mod remain_on_channel;
+mod rx;
+mod tx;
+mod tx_status;
+
+pub use handle::Nl80211FrameHandle;
+pub use register::Nl80211FrameRegisterRequest;
+pub use register_beacons::Nl80211BeaconsRegisterRequest;
+pub use remain_on_channel::{
+    Nl80211RemainOnChannelCancelRequest, Nl80211RemainOnChannelRequest,
+};
+pub use rx::{nl80211_received_frames, Nl80211ReceivedFrame};
+pub use tx::Nl80211FrameTxRequest;
+pub use tx_status::{nl80211_frame_tx_status, Nl80211FrameTxStatus};
+
+pub(crate) use rx::parse_unsolicited;
+pub(crate) use tx::nl80211_execute_cookie;
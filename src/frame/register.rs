@@ -0,0 +1,63 @@
+// SPDX-License-Identifier: MIT
+
+use crate::{
+    nl80211_execute_ack, Nl80211Attr, Nl80211Cmd, Nl80211Error, Nl80211Handle,
+    Nl80211Message,
+};
+
+pub struct Nl80211FrameRegisterRequest {
+    handle: Nl80211Handle,
+    if_index: u32,
+    frame_type: u16,
+    frame_match: Vec<u8>,
+    multicast: bool,
+}
+
+impl Nl80211FrameRegisterRequest {
+    pub(crate) fn new(
+        handle: Nl80211Handle,
+        if_index: u32,
+        frame_type: u16,
+        frame_match: Vec<u8>,
+    ) -> Self {
+        Nl80211FrameRegisterRequest {
+            handle,
+            if_index,
+            frame_type,
+            frame_match,
+            multicast: false,
+        }
+    }
+
+    /// Also receive matching frames sent to multicast addresses, requires
+    /// `Nl80211ExtFeature::MulticastRegistrations`
+    pub fn multicast(mut self, enable: bool) -> Self {
+        self.multicast = enable;
+        self
+    }
+
+    pub async fn execute(self) -> Result<(), Nl80211Error> {
+        let Nl80211FrameRegisterRequest {
+            mut handle,
+            if_index,
+            frame_type,
+            frame_match,
+            multicast,
+        } = self;
+
+        let mut nlas = vec![
+            Nl80211Attr::IfIndex(if_index),
+            Nl80211Attr::FrameType(frame_type),
+            Nl80211Attr::FrameMatch(frame_match),
+        ];
+        if multicast {
+            nlas.push(Nl80211Attr::ReceiveMulticast);
+        }
+
+        let nl80211_msg = Nl80211Message {
+            cmd: Nl80211Cmd::FrameRegister,
+            nlas,
+        };
+        nl80211_execute_ack(&mut handle, nl80211_msg).await
+    }
+}
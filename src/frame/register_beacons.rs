@@ -0,0 +1,27 @@
+// SPDX-License-Identifier: MIT
+
+use crate::{
+    nl80211_execute_ack, Nl80211Attr, Nl80211Cmd, Nl80211Error, Nl80211Handle,
+    Nl80211Message,
+};
+
+pub struct Nl80211BeaconsRegisterRequest {
+    handle: Nl80211Handle,
+    wiphy: u32,
+}
+
+impl Nl80211BeaconsRegisterRequest {
+    pub(crate) fn new(handle: Nl80211Handle, wiphy: u32) -> Self {
+        Nl80211BeaconsRegisterRequest { handle, wiphy }
+    }
+
+    pub async fn execute(self) -> Result<(), Nl80211Error> {
+        let Nl80211BeaconsRegisterRequest { mut handle, wiphy } = self;
+
+        let nl80211_msg = Nl80211Message {
+            cmd: Nl80211Cmd::BeaconsRegister,
+            nlas: vec![Nl80211Attr::WiPhy(wiphy)],
+        };
+        nl80211_execute_ack(&mut handle, nl80211_msg).await
+    }
+}
@@ -0,0 +1,97 @@
+// SPDX-License-Identifier: MIT
+
+use crate::{
+    frame::nl80211_execute_cookie, nl80211_execute_ack, Nl80211Attr,
+    Nl80211Cmd, Nl80211Error, Nl80211Handle, Nl80211Message,
+};
+
+pub struct Nl80211RemainOnChannelRequest {
+    handle: Nl80211Handle,
+    if_index: u32,
+    freq: u32,
+    duration: u32,
+}
+
+impl Nl80211RemainOnChannelRequest {
+    pub(crate) fn new(
+        handle: Nl80211Handle,
+        if_index: u32,
+        freq: u32,
+        duration: u32,
+    ) -> Self {
+        Nl80211RemainOnChannelRequest {
+            handle,
+            if_index,
+            freq,
+            duration,
+        }
+    }
+
+    /// Return the cookie identifying the `Nl80211Cmd::RemainOnChannel` event
+    /// sent once the device is on the channel, and the
+    /// `Nl80211Cmd::RemainOnChannelCancel` event sent when the duration
+    /// expires. Both events are sent to the `mlme` multicast group, which the
+    /// socket has to join to receive them.
+    pub async fn execute(self) -> Result<u64, Nl80211Error> {
+        let Nl80211RemainOnChannelRequest {
+            mut handle,
+            if_index,
+            freq,
+            duration,
+        } = self;
+
+        let nl80211_msg = Nl80211Message {
+            cmd: Nl80211Cmd::RemainOnChannel,
+            nlas: vec![
+                Nl80211Attr::IfIndex(if_index),
+                Nl80211Attr::WiPhyFreq(freq),
+                Nl80211Attr::Duration(duration),
+            ],
+        };
+        nl80211_execute_cookie(&mut handle, nl80211_msg)
+            .await?
+            .ok_or_else(|| {
+                Nl80211Error::RequestFailed(
+                    "No cookie in the reply of remain on channel request"
+                        .to_string(),
+                )
+            })
+    }
+}
+
+pub struct Nl80211RemainOnChannelCancelRequest {
+    handle: Nl80211Handle,
+    if_index: u32,
+    cookie: u64,
+}
+
+impl Nl80211RemainOnChannelCancelRequest {
+    pub(crate) fn new(
+        handle: Nl80211Handle,
+        if_index: u32,
+        cookie: u64,
+    ) -> Self {
+        Nl80211RemainOnChannelCancelRequest {
+            handle,
+            if_index,
+            cookie,
+        }
+    }
+
+    pub async fn execute(self) -> Result<(), Nl80211Error> {
+        let Nl80211RemainOnChannelCancelRequest {
+            mut handle,
+            if_index,
+            cookie,
+        } = self;
+
+        let nl80211_msg = Nl80211Message {
+            cmd: Nl80211Cmd::RemainOnChannelCancel,
+            nlas: vec![
+                Nl80211Attr::IfIndex(if_index),
+                Nl80211Attr::Cookie(cookie),
+            ],
+        };
+        nl80211_execute_ack(&mut handle, nl80211_msg).await
+    }
+}
@@ -0,0 +1,76 @@
+// SPDX-License-Identifier: MIT
+
+use futures::{channel::mpsc::UnboundedReceiver, future, Stream, StreamExt};
+use genetlink::message::RawGenlMessage;
+use netlink_packet_core::{NetlinkMessage, NetlinkPayload};
+use netlink_sys::SocketAddr;
+
+use crate::{Nl80211Attr, Nl80211Cmd, Nl80211Message};
+
+/// Management frame received by a socket registered for it
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct Nl80211ReceivedFrame {
+    pub wiphy: Option<u32>,
+    pub if_index: Option<u32>,
+    pub wdev: Option<u64>,
+    /// Frequency (MHz) the frame was received on
+    pub freq: Option<u32>,
+    /// Offset (KHz) added to `freq`
+    pub freq_offset: Option<u32>,
+    /// Signal strength (dBm) of the frame
+    pub signal_dbm: Option<i32>,
+    /// Raw frame including the management frame header
+    pub frame: Vec<u8>,
+}
+
+impl Nl80211ReceivedFrame {
+    /// Extract the received frame from a `Nl80211Cmd::Frame` message,
+    /// `None` for other messages
+    pub fn from_message(msg: &Nl80211Message) -> Option<Self> {
+        if msg.cmd != Nl80211Cmd::Frame {
+            return None;
+        }
+        let mut ret = Self::default();
+        let mut frame = None;
+        for nla in msg.nlas.iter() {
+            match nla {
+                Nl80211Attr::WiPhy(d) => ret.wiphy = Some(*d),
+                Nl80211Attr::IfIndex(d) => ret.if_index = Some(*d),
+                Nl80211Attr::Wdev(d) => ret.wdev = Some(*d),
+                Nl80211Attr::WiPhyFreq(d) => ret.freq = Some(*d),
+                Nl80211Attr::WiPhyFreqOffset(d) => ret.freq_offset = Some(*d),
+                Nl80211Attr::RxSignalDbm(d) => ret.signal_dbm = Some(*d),
+                Nl80211Attr::Frame(d) => frame = Some(d.clone()),
+                _ => (),
+            }
+        }
+        ret.frame = frame?;
+        Some(ret)
+    }
+}
+
+/// Stream of the management frames received by the connection, built from
+/// the unsolicited messages returned by `new_connection()`. Other messages
+/// and those failed to parse are dropped.
+pub fn nl80211_received_frames(
+    messages: UnboundedReceiver<(NetlinkMessage<RawGenlMessage>, SocketAddr)>,
+) -> impl Stream<Item = Nl80211ReceivedFrame> {
+    messages.filter_map(|(msg, _)| {
+        future::ready(
+            parse_unsolicited(msg)
+                .and_then(|msg| Nl80211ReceivedFrame::from_message(&msg)),
+        )
+    })
+}
+
+pub(crate) fn parse_unsolicited(
+    msg: NetlinkMessage<RawGenlMessage>,
+) -> Option<Nl80211Message> {
+    match msg.payload {
+        NetlinkPayload::InnerMessage(raw) => raw
+            .parse_into_genlmsg::<Nl80211Message>()
+            .ok()
+            .map(|genl_msg| genl_msg.payload),
+        _ => None,
+    }
+}
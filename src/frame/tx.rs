@@ -0,0 +1,128 @@
+// SPDX-License-Identifier: MIT
+
+use futures::TryStreamExt;
+use netlink_packet_core::{NLM_F_ACK, NLM_F_REQUEST};
+
+use crate::{
+    nl80211_execute, Nl80211Attr, Nl80211Cmd, Nl80211Error, Nl80211Handle,
+    Nl80211Message,
+};
+
+pub struct Nl80211FrameTxRequest {
+    handle: Nl80211Handle,
+    if_index: u32,
+    frame: Vec<u8>,
+    freq: Option<u32>,
+    wait: Option<u32>,
+    offchannel: bool,
+    no_cck_rate: bool,
+    dont_wait_for_ack: bool,
+}
+
+impl Nl80211FrameTxRequest {
+    pub(crate) fn new(
+        handle: Nl80211Handle,
+        if_index: u32,
+        frame: Vec<u8>,
+    ) -> Self {
+        Nl80211FrameTxRequest {
+            handle,
+            if_index,
+            frame,
+            freq: None,
+            wait: None,
+            offchannel: false,
+            no_cck_rate: false,
+            dont_wait_for_ack: false,
+        }
+    }
+
+    /// Frequency(MHz) to transmit on, the current operating channel if unset
+    pub fn freq(mut self, freq: u32) -> Self {
+        self.freq = Some(freq);
+        self
+    }
+
+    /// Time(milliseconds) to stay on the channel waiting for a response,
+    /// requires `offchannel()` when not on the operating channel
+    pub fn wait(mut self, wait: u32) -> Self {
+        self.wait = Some(wait);
+        self
+    }
+
+    /// Allow switching to another channel to transmit the frame
+    pub fn offchannel(mut self, enable: bool) -> Self {
+        self.offchannel = enable;
+        self
+    }
+
+    /// Do not use CCK rates in the 2.4 GHz band
+    pub fn no_cck_rate(mut self, enable: bool) -> Self {
+        self.no_cck_rate = enable;
+        self
+    }
+
+    /// Skip the TX status report, no cookie is returned in this case
+    pub fn dont_wait_for_ack(mut self, enable: bool) -> Self {
+        self.dont_wait_for_ack = enable;
+        self
+    }
+
+    /// Return the cookie identifying the `Nl80211Cmd::FrameTxStatus` event
+    /// sent to the `mlme` multicast group once the frame is transmitted, see
+    /// `nl80211_frame_tx_status()`.
+    pub async fn execute(self) -> Result<Option<u64>, Nl80211Error> {
+        let Nl80211FrameTxRequest {
+            mut handle,
+            if_index,
+            frame,
+            freq,
+            wait,
+            offchannel,
+            no_cck_rate,
+            dont_wait_for_ack,
+        } = self;
+
+        let mut nlas = vec![Nl80211Attr::IfIndex(if_index)];
+        if let Some(freq) = freq {
+            nlas.push(Nl80211Attr::WiPhyFreq(freq));
+        }
+        if let Some(wait) = wait {
+            nlas.push(Nl80211Attr::Duration(wait));
+        }
+        if offchannel {
+            nlas.push(Nl80211Attr::OffchannelTxOk);
+        }
+        if no_cck_rate {
+            nlas.push(Nl80211Attr::TxNoCckRate);
+        }
+        if dont_wait_for_ack {
+            nlas.push(Nl80211Attr::DontWaitForAck);
+        }
+        nlas.push(Nl80211Attr::Frame(frame));
+
+        let nl80211_msg = Nl80211Message {
+            cmd: Nl80211Cmd::Frame,
+            nlas,
+        };
+        // Kernel only replies with the cookie when waiting for ACK
+        nl80211_execute_cookie(&mut handle, nl80211_msg).await
+    }
+}
+
+pub(crate) async fn nl80211_execute_cookie(
+    handle: &mut Nl80211Handle,
+    nl80211_msg: Nl80211Message,
+) -> Result<Option<u64>, Nl80211Error> {
+    let mut response =
+        nl80211_execute(handle, nl80211_msg, NLM_F_REQUEST | NLM_F_ACK).await;
+    let mut cookie = None;
+    while let Some(msg) = response.try_next().await? {
+        for nla in msg.payload.nlas {
+            if let Nl80211Attr::Cookie(d) = nla {
+                cookie = Some(d);
+            }
+        }
+    }
+    Ok(cookie)
+}
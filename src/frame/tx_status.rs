@@ -0,0 +1,72 @@
+// SPDX-License-Identifier: MIT
+
+use futures::{channel::mpsc::UnboundedReceiver, StreamExt};
+use genetlink::message::RawGenlMessage;
+use netlink_packet_core::NetlinkMessage;
+use netlink_sys::SocketAddr;
+
+use crate::{
+    frame::parse_unsolicited, Nl80211Attr, Nl80211Cmd, Nl80211Message,
+};
+
+/// Transmission result of a frame sent by `Nl80211FrameTxRequest`
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct Nl80211FrameTxStatus {
+    pub if_index: Option<u32>,
+    pub wdev: Option<u64>,
+    /// Cookie returned by the transmit request
+    pub cookie: u64,
+    /// Whether the frame was acknowledged by the recipient
+    pub ack: bool,
+    /// Raw frame as transmitted
+    pub frame: Vec<u8>,
+}
+
+impl Nl80211FrameTxStatus {
+    /// Extract the status from a `Nl80211Cmd::FrameTxStatus` message, `None`
+    /// for other messages
+    pub fn from_message(msg: &Nl80211Message) -> Option<Self> {
+        if msg.cmd != Nl80211Cmd::FrameTxStatus {
+            return None;
+        }
+        let mut ret = Self::default();
+        let mut cookie = None;
+        for nla in msg.nlas.iter() {
+            match nla {
+                Nl80211Attr::IfIndex(d) => ret.if_index = Some(*d),
+                Nl80211Attr::Wdev(d) => ret.wdev = Some(*d),
+                Nl80211Attr::Cookie(d) => cookie = Some(*d),
+                Nl80211Attr::Ack => ret.ack = true,
+                Nl80211Attr::Frame(d) => ret.frame = d.clone(),
+                _ => (),
+            }
+        }
+        ret.cookie = cookie?;
+        Some(ret)
+    }
+}
+
+/// Wait for the transmission result of the frame identified by `cookie`
+/// among the unsolicited messages returned by `new_connection()`. The status
+/// is sent to the `mlme` multicast group, hence the socket of the connection
+/// has to join it via `add_membership()` with the group ID resolved from the
+/// `nl80211` generic netlink family before transmitting the frame. Messages
+/// received before it are dropped, `None` if the connection is closed first.
+pub async fn nl80211_frame_tx_status(
+    messages: &mut UnboundedReceiver<(
+        NetlinkMessage<RawGenlMessage>,
+        SocketAddr,
+    )>,
+    cookie: u64,
+) -> Option<Nl80211FrameTxStatus> {
+    while let Some((msg, _)) = messages.next().await {
+        if let Some(status) = parse_unsolicited(msg)
+            .and_then(|msg| Nl80211FrameTxStatus::from_message(&msg))
+        {
+            if status.cookie == cookie {
+                return Some(status);
+            }
+        }
+    }
+    None
+}
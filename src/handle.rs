@@ -1,23 +1,87 @@
 // SPDX-License-Identifier: MIT
 
-use futures::{future::Either, FutureExt, Stream, StreamExt, TryStream};
+use std::sync::{Arc, Mutex};
+
+use futures::{
+    future::{self, Either},
+    FutureExt, Stream, StreamExt, TryStream, TryStreamExt,
+};
 use genetlink::GenetlinkHandle;
-use netlink_packet_core::{NetlinkMessage, NLM_F_DUMP, NLM_F_REQUEST};
+use netlink_packet_core::{
+    NetlinkMessage, NetlinkPayload, NLM_F_ACK, NLM_F_REQUEST,
+};
 use netlink_packet_generic::GenlMessage;
-use netlink_packet_utils::DecodeError;
+use netlink_packet_utils::{
+    nla::NlasIterator, parsers::parse_u64, DecodeError,
+};
 
 use crate::{
-    try_nl80211, Nl80211Error, Nl80211InterfaceHandle, Nl80211Message,
+    try_nl80211, Nl80211ApHandle, Nl80211CoalesceHandle, Nl80211Error,
+    Nl80211FrameHandle, Nl80211InterfaceHandle, Nl80211KeyHandle,
+    Nl80211MeshHandle, Nl80211Message, Nl80211NanHandle,
+    Nl80211PeerMeasurementHandle, Nl80211PhyHandle, Nl80211ProtocolFeatures,
+    Nl80211ProtocolFeaturesGetRequest, Nl80211RegHandle, Nl80211ScanHandle,
+    Nl80211StationHandle, Nl80211SurveyHandle, Nl80211WowlanHandle,
 };
 
 #[derive(Clone, Debug)]
 pub struct Nl80211Handle {
     pub handle: GenetlinkHandle,
+    // Shared by the clones, so the kernel is only queried once
+    protocol_features: Arc<Mutex<Option<Nl80211ProtocolFeatures>>>,
 }
 
 impl Nl80211Handle {
     pub(crate) fn new(handle: GenetlinkHandle) -> Self {
-        Nl80211Handle { handle }
+        Nl80211Handle {
+            handle,
+            protocol_features: Arc::new(Mutex::new(None)),
+        }
+    }
+
+    // Protocol features of the kernel, queried on first use. Kernels without
+    // the `GET_PROTOCOL_FEATURES` command support none of them.
+    pub(crate) async fn protocol_features(
+        &self,
+    ) -> Result<Nl80211ProtocolFeatures, Nl80211Error> {
+        let cached = self
+            .protocol_features
+            .lock()
+            .map(|d| *d)
+            .unwrap_or_default();
+        if let Some(features) = cached {
+            return Ok(features);
+        }
+        let features =
+            match Nl80211ProtocolFeaturesGetRequest::new(self.clone())
+                .execute()
+                .await
+            {
+                Ok(d) => d,
+                Err(Nl80211Error::NetlinkError(_)) => {
+                    Nl80211ProtocolFeatures::empty()
+                }
+                Err(e) => return Err(e),
+            };
+        if let Ok(mut cached) = self.protocol_features.lock() {
+            *cached = Some(features);
+        }
+        Ok(features)
+    }
+
+    // equivalent to `iw dev DEVICE ap` command
+    pub fn ap(&self) -> Nl80211ApHandle {
+        Nl80211ApHandle::new(self.clone())
+    }
+
+    // equivalent to `iw phy PHY coalesce` command
+    pub fn coalesce(&self) -> Nl80211CoalesceHandle {
+        Nl80211CoalesceHandle::new(self.clone())
+    }
+
+    // register for and transmit management frames, no `iw` equivalent
+    pub fn frame(&self) -> Nl80211FrameHandle {
+        Nl80211FrameHandle::new(self.clone())
     }
 
     // equivalent to `iw dev` command
@@ -25,6 +89,56 @@ impl Nl80211Handle {
         Nl80211InterfaceHandle::new(self.clone())
     }
 
+    // manage encryption keys, no `iw` equivalent
+    pub fn key(&self) -> Nl80211KeyHandle {
+        Nl80211KeyHandle::new(self.clone())
+    }
+
+    // equivalent to `iw dev DEVICE mesh` command
+    pub fn mesh(&self) -> Nl80211MeshHandle {
+        Nl80211MeshHandle::new(self.clone())
+    }
+
+    // equivalent to `iw wdev WDEV nan` command
+    pub fn nan(&self) -> Nl80211NanHandle {
+        Nl80211NanHandle::new(self.clone())
+    }
+
+    // equivalent to `iw dev DEVICE measurement` command
+    pub fn peer_measurement(&self) -> Nl80211PeerMeasurementHandle {
+        Nl80211PeerMeasurementHandle::new(self.clone())
+    }
+
+    // equivalent to `iw phy` command
+    pub fn phy(&self) -> Nl80211PhyHandle {
+        Nl80211PhyHandle::new(self.clone())
+    }
+
+    // equivalent to `iw dev DEVICE scan` command
+    pub fn scan(&self) -> Nl80211ScanHandle {
+        Nl80211ScanHandle::new(self.clone())
+    }
+
+    // equivalent to `iw dev DEVICE station` command
+    pub fn station(&self) -> Nl80211StationHandle {
+        Nl80211StationHandle::new(self.clone())
+    }
+
+    // equivalent to `iw dev DEVICE survey` command
+    pub fn survey(&self) -> Nl80211SurveyHandle {
+        Nl80211SurveyHandle::new(self.clone())
+    }
+
+    // equivalent to `iw phy PHY wowlan` command
+    pub fn wowlan(&self) -> Nl80211WowlanHandle {
+        Nl80211WowlanHandle::new(self.clone())
+    }
+
+    // equivalent to `iw reg` command
+    pub fn reg(&self) -> Nl80211RegHandle {
+        Nl80211RegHandle::new(self.clone())
+    }
+
     pub async fn request(
         &mut self,
         message: NetlinkMessage<GenlMessage<Nl80211Message>>,
@@ -49,18 +163,28 @@ impl Nl80211Handle {
 pub(crate) async fn nl80211_execute(
     handle: &mut Nl80211Handle,
     nl80211_msg: Nl80211Message,
+    nl_header_flags: u16,
 ) -> impl TryStream<Ok = GenlMessage<Nl80211Message>, Error = Nl80211Error> {
-    let nl_header_flags = NLM_F_REQUEST | NLM_F_DUMP;
-
     let mut nl_msg =
         NetlinkMessage::from(GenlMessage::from_payload(nl80211_msg));
 
     nl_msg.header.flags = nl_header_flags;
 
     match handle.request(nl_msg).await {
-        Ok(response) => {
-            Either::Left(response.map(move |msg| Ok(try_nl80211!(msg))))
-        }
+        Ok(response) => Either::Left(
+            response
+                // The ACK requested by NLM_F_ACK carries no nl80211 payload
+                .filter(|msg| {
+                    future::ready(!matches!(
+                        msg,
+                        Ok(NetlinkMessage {
+                            payload: NetlinkPayload::Ack(_),
+                            ..
+                        })
+                    ))
+                })
+                .map(move |msg| Ok(try_nl80211!(msg))),
+        ),
         Err(e) => Either::Right(
             futures::future::err::<GenlMessage<Nl80211Message>, Nl80211Error>(
                 e,
@@ -69,3 +193,83 @@ pub(crate) async fn nl80211_execute(
         ),
     }
 }
+
+// For commands where kernel only reply with ACK or error
+pub(crate) async fn nl80211_execute_ack(
+    handle: &mut Nl80211Handle,
+    nl80211_msg: Nl80211Message,
+) -> Result<(), Nl80211Error> {
+    let mut response =
+        nl80211_execute(handle, nl80211_msg, NLM_F_REQUEST | NLM_F_ACK).await;
+    while response.try_next().await?.is_some() {}
+    Ok(())
+}
+
+// For commands where kernel reports a cookie in the extended ACK, which is
+// only included when `NETLINK_EXT_ACK` is enabled on the socket
+pub(crate) async fn nl80211_execute_ack_cookie(
+    handle: &mut Nl80211Handle,
+    nl80211_msg: Nl80211Message,
+) -> Result<Option<u64>, Nl80211Error> {
+    let mut nl_msg =
+        NetlinkMessage::from(GenlMessage::from_payload(nl80211_msg));
+    nl_msg.header.flags = NLM_F_REQUEST | NLM_F_ACK;
+
+    let mut response = handle.request(nl_msg).await?;
+    let mut cookie = None;
+    while let Some(msg) = response.next().await {
+        match msg {
+            Ok(NetlinkMessage {
+                payload: NetlinkPayload::Ack(ack),
+                ..
+            }) => cookie = parse_ack_cookie(&ack.header),
+            msg => {
+                try_nl80211!(msg);
+            }
+        }
+    }
+    Ok(cookie)
+}
+
+const NLMSG_HDRLEN: usize = 16;
+const NLMSGERR_ATTR_COOKIE: u16 = 4;
+
+// The ACK holds the header of the request followed by the extended ACK
+// attributes
+fn parse_ack_cookie(payload: &[u8]) -> Option<u64> {
+    for nla in NlasIterator::new(payload.get(NLMSG_HDRLEN..)?) {
+        match nla {
+            Ok(nla) if nla.kind() == NLMSGERR_ATTR_COOKIE => {
+                return parse_u64(nla.value()).ok();
+            }
+            Ok(_) => (),
+            Err(_) => return None,
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use netlink_packet_utils::{nla::DefaultNla, Emitable};
+
+    use super::*;
+
+    #[test]
+    fn ack_cookie() {
+        let mut payload = vec![0u8; NLMSG_HDRLEN];
+        assert_eq!(parse_ack_cookie(&payload), None);
+
+        let nlas = [
+            DefaultNla::new(1, b"msg\0".to_vec()),
+            DefaultNla::new(
+                NLMSGERR_ATTR_COOKIE,
+                0x1122334455u64.to_ne_bytes().to_vec(),
+            ),
+        ];
+        let mut buffer = vec![0u8; nlas.as_slice().buffer_len()];
+        nlas.as_slice().emit(&mut buffer);
+        payload.extend_from_slice(&buffer);
+        assert_eq!(parse_ack_cookie(&payload), Some(0x1122334455));
+    }
+}
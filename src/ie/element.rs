@@ -0,0 +1,327 @@
+// SPDX-License-Identifier: MIT
+
+use netlink_packet_utils::{DecodeError, Emitable};
+
+use super::{
+    operation::{Nl80211HeOperation, Nl80211HtOperation, Nl80211VhtOperation},
+    rsn::Nl80211Rsn,
+    wps::{Nl80211Wps, WPS_OUI, WPS_OUI_TYPE},
+};
+
+const ELEMENT_ID_SSID: u8 = 0;
+const ELEMENT_ID_SUPPORTED_RATES: u8 = 1;
+const ELEMENT_ID_DS_PARAMETER_SET: u8 = 3;
+const ELEMENT_ID_RSN: u8 = 48;
+const ELEMENT_ID_EXTENDED_SUPPORTED_RATES: u8 = 50;
+const ELEMENT_ID_HT_OPERATION: u8 = 61;
+const ELEMENT_ID_VHT_OPERATION: u8 = 192;
+const ELEMENT_ID_VENDOR_SPECIFIC: u8 = 221;
+const ELEMENT_ID_EXTENSION: u8 = 255;
+
+const ELEMENT_ID_EXT_HE_OPERATION: u8 = 36;
+
+const ELEMENT_HEADER_LEN: usize = 2;
+const OUI_LEN: usize = 3;
+
+/// Rate of Supported Rates and Extended Supported Rates element
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct Nl80211ElementRate {
+    /// Whether this rate is part of the BSS basic rate set
+    pub is_basic: bool,
+    /// Rate in unit of 500 kbps
+    pub rate: u8,
+}
+
+impl From<u8> for Nl80211ElementRate {
+    fn from(d: u8) -> Self {
+        Self {
+            is_basic: d & 0x80 > 0,
+            rate: d & 0x7f,
+        }
+    }
+}
+
+impl From<Nl80211ElementRate> for u8 {
+    fn from(v: Nl80211ElementRate) -> u8 {
+        if v.is_basic {
+            v.rate | 0x80
+        } else {
+            v.rate
+        }
+    }
+}
+
+/// IEEE 802.11 information element
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum Nl80211Element {
+    Ssid(String),
+    SupportedRates(Vec<Nl80211ElementRate>),
+    /// Current channel number
+    DsParameterSet(u8),
+    Rsn(Nl80211Rsn),
+    ExtendedSupportedRates(Vec<Nl80211ElementRate>),
+    HtOperation(Nl80211HtOperation),
+    VhtOperation(Nl80211VhtOperation),
+    HeOperation(Nl80211HeOperation),
+    /// Wi-Fi Protected Setup vendor specific element
+    Wps(Nl80211Wps),
+    VendorSpecific {
+        oui: [u8; OUI_LEN],
+        data: Vec<u8>,
+    },
+    /// Unknown element with element ID and raw content. For element with
+    /// ID 255(extension), the content starts with element ID extension.
+    Other(u8, Vec<u8>),
+}
+
+impl Nl80211Element {
+    pub fn id(&self) -> u8 {
+        match self {
+            Self::Ssid(_) => ELEMENT_ID_SSID,
+            Self::SupportedRates(_) => ELEMENT_ID_SUPPORTED_RATES,
+            Self::DsParameterSet(_) => ELEMENT_ID_DS_PARAMETER_SET,
+            Self::Rsn(_) => ELEMENT_ID_RSN,
+            Self::ExtendedSupportedRates(_) => {
+                ELEMENT_ID_EXTENDED_SUPPORTED_RATES
+            }
+            Self::HtOperation(_) => ELEMENT_ID_HT_OPERATION,
+            Self::VhtOperation(_) => ELEMENT_ID_VHT_OPERATION,
+            Self::HeOperation(_) => ELEMENT_ID_EXTENSION,
+            Self::Wps(_) | Self::VendorSpecific { .. } => {
+                ELEMENT_ID_VENDOR_SPECIFIC
+            }
+            Self::Other(id, _) => *id,
+        }
+    }
+
+    fn payload_len(&self) -> usize {
+        match self {
+            Self::Ssid(s) => s.len(),
+            Self::SupportedRates(v) | Self::ExtendedSupportedRates(v) => {
+                v.len()
+            }
+            Self::DsParameterSet(_) => 1,
+            Self::Rsn(v) => v.buffer_len(),
+            Self::Other(_, v) => v.len(),
+            Self::HtOperation(_) => Nl80211HtOperation::LENGTH,
+            Self::VhtOperation(_) => Nl80211VhtOperation::LENGTH,
+            Self::HeOperation(v) => 1 + v.buffer_len(),
+            Self::Wps(v) => OUI_LEN + 1 + v.buffer_len(),
+            Self::VendorSpecific { data, .. } => OUI_LEN + data.len(),
+        }
+    }
+
+    /// Parse element content (without element ID and length)
+    pub fn parse(id: u8, payload: &[u8]) -> Result<Self, DecodeError> {
+        Ok(match id {
+            ELEMENT_ID_SSID => {
+                Self::Ssid(String::from_utf8_lossy(payload).to_string())
+            }
+            ELEMENT_ID_SUPPORTED_RATES => Self::SupportedRates(
+                payload
+                    .iter()
+                    .map(|d| Nl80211ElementRate::from(*d))
+                    .collect(),
+            ),
+            ELEMENT_ID_DS_PARAMETER_SET => {
+                Self::DsParameterSet(*payload.first().ok_or_else(|| {
+                    DecodeError::from("Empty DS Parameter Set element")
+                })?)
+            }
+            ELEMENT_ID_RSN => Self::Rsn(Nl80211Rsn::parse(payload)?),
+            ELEMENT_ID_EXTENDED_SUPPORTED_RATES => {
+                Self::ExtendedSupportedRates(
+                    payload
+                        .iter()
+                        .map(|d| Nl80211ElementRate::from(*d))
+                        .collect(),
+                )
+            }
+            ELEMENT_ID_HT_OPERATION => {
+                Self::HtOperation(Nl80211HtOperation::parse(payload)?)
+            }
+            ELEMENT_ID_VHT_OPERATION => {
+                Self::VhtOperation(Nl80211VhtOperation::parse(payload)?)
+            }
+            ELEMENT_ID_EXTENSION
+                if payload.first() == Some(&ELEMENT_ID_EXT_HE_OPERATION) =>
+            {
+                Self::HeOperation(Nl80211HeOperation::parse(&payload[1..])?)
+            }
+            ELEMENT_ID_VENDOR_SPECIFIC
+                if payload.starts_with(&WPS_OUI)
+                    && payload.get(OUI_LEN) == Some(&WPS_OUI_TYPE) =>
+            {
+                Self::Wps(Nl80211Wps::parse(&payload[OUI_LEN + 1..])?)
+            }
+            ELEMENT_ID_VENDOR_SPECIFIC => {
+                if payload.len() < OUI_LEN {
+                    return Err(format!(
+                        "Invalid Vendor Specific element {:?}",
+                        payload
+                    )
+                    .into());
+                }
+                let mut oui = [0u8; OUI_LEN];
+                oui.copy_from_slice(&payload[..OUI_LEN]);
+                Self::VendorSpecific {
+                    oui,
+                    data: payload[OUI_LEN..].to_vec(),
+                }
+            }
+            _ => Self::Other(id, payload.to_vec()),
+        })
+    }
+}
+
+impl Emitable for Nl80211Element {
+    fn buffer_len(&self) -> usize {
+        ELEMENT_HEADER_LEN + self.payload_len()
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        buffer[0] = self.id();
+        buffer[1] = self.payload_len() as u8;
+        let payload = &mut buffer[ELEMENT_HEADER_LEN..self.buffer_len()];
+        match self {
+            Self::Ssid(s) => payload.copy_from_slice(s.as_bytes()),
+            Self::SupportedRates(v) | Self::ExtendedSupportedRates(v) => {
+                for (i, rate) in v.iter().enumerate() {
+                    payload[i] = (*rate).into();
+                }
+            }
+            Self::DsParameterSet(d) => payload[0] = *d,
+            Self::Rsn(v) => v.emit(payload),
+            Self::Other(_, v) => payload.copy_from_slice(v),
+            Self::HtOperation(v) => v.emit(payload),
+            Self::VhtOperation(v) => v.emit(payload),
+            Self::HeOperation(v) => {
+                payload[0] = ELEMENT_ID_EXT_HE_OPERATION;
+                v.emit(&mut payload[1..]);
+            }
+            Self::Wps(v) => {
+                payload[..OUI_LEN].copy_from_slice(&WPS_OUI);
+                payload[OUI_LEN] = WPS_OUI_TYPE;
+                v.emit(&mut payload[OUI_LEN + 1..]);
+            }
+            Self::VendorSpecific { oui, data } => {
+                payload[..OUI_LEN].copy_from_slice(oui);
+                payload[OUI_LEN..].copy_from_slice(data);
+            }
+        }
+    }
+}
+
+/// Iterator of information elements in raw bytes, for example
+/// `Nl80211Bss.information_elements`.
+pub struct Nl80211ElementIter<'a> {
+    buffer: &'a [u8],
+    position: usize,
+}
+
+impl<'a> Nl80211ElementIter<'a> {
+    pub fn new(buffer: &'a [u8]) -> Self {
+        Self {
+            buffer,
+            position: 0,
+        }
+    }
+}
+
+impl<'a> Iterator for Nl80211ElementIter<'a> {
+    type Item = Result<Nl80211Element, DecodeError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let remains = &self.buffer[self.position..];
+        if remains.is_empty() {
+            return None;
+        }
+        if remains.len() < ELEMENT_HEADER_LEN
+            || remains.len() < ELEMENT_HEADER_LEN + remains[1] as usize
+        {
+            // Stop iteration on truncated element
+            self.position = self.buffer.len();
+            return Some(Err(format!(
+                "Truncated information element {:?}",
+                remains
+            )
+            .into()));
+        }
+        let id = remains[0];
+        let end = ELEMENT_HEADER_LEN + remains[1] as usize;
+        self.position += end;
+        Some(Nl80211Element::parse(id, &remains[ELEMENT_HEADER_LEN..end]))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Nl80211CipherSuite, Nl80211WpsState};
+
+    #[test]
+    fn iterate_elements() {
+        let raw = [
+            0x00, 0x04, b't', b'e', b's', b't', // SSID
+            0x01, 0x02, 0x82, 0x0c, // supported rates 1(B) 6 Mbps
+            0x03, 0x01, 0x06, // DS parameter set
+            0x30, 0x06, 0x01, 0x00, 0x00, 0x0f, 0xac, 0x04, // RSN
+            0xdd, 0x04, 0x00, 0x10, 0x18, 0x02, // vendor specific
+            0xdd, 0x09, 0x00, 0x50, 0xf2, 0x04, // WPS
+            0x10, 0x44, 0x00, 0x01, 0x02, //
+            0x2d, 0x01, 0x00, // unknown
+        ];
+        let elements: Vec<Nl80211Element> = Nl80211ElementIter::new(&raw)
+            .collect::<Result<_, _>>()
+            .unwrap();
+        let expected = vec![
+            Nl80211Element::Ssid("test".to_string()),
+            Nl80211Element::SupportedRates(vec![
+                Nl80211ElementRate {
+                    is_basic: true,
+                    rate: 2,
+                },
+                Nl80211ElementRate {
+                    is_basic: false,
+                    rate: 12,
+                },
+            ]),
+            Nl80211Element::DsParameterSet(6),
+            Nl80211Element::Rsn(Nl80211Rsn {
+                version: 1,
+                group_cipher: Some(Nl80211CipherSuite::Ccmp),
+                ..Default::default()
+            }),
+            Nl80211Element::VendorSpecific {
+                oui: [0x00, 0x10, 0x18],
+                data: vec![0x02],
+            },
+            Nl80211Element::Wps(Nl80211Wps {
+                state: Some(Nl80211WpsState::Configured),
+                ..Default::default()
+            }),
+            Nl80211Element::Other(0x2d, vec![0x00]),
+        ];
+        assert_eq!(elements, expected);
+
+        let mut buffer = Vec::new();
+        for element in expected.iter() {
+            let mut data = vec![0u8; element.buffer_len()];
+            element.emit(&mut data);
+            buffer.extend(data);
+        }
+        assert_eq!(buffer, raw);
+    }
+
+    #[test]
+    fn iterate_truncated_element() {
+        let raw = [0x00, 0x01, b'a', 0x03, 0x05, 0x06];
+        let mut iter = Nl80211ElementIter::new(&raw);
+        assert_eq!(
+            iter.next().unwrap().unwrap(),
+            Nl80211Element::Ssid("a".to_string())
+        );
+        assert!(iter.next().unwrap().is_err());
+        assert!(iter.next().is_none());
+    }
+}
@@ -0,0 +1,13 @@
+// SPDX-License-Identifier: MIT
+
+mod element;
+mod operation;
+mod rsn;
+mod wps;
+
+pub use element::{Nl80211Element, Nl80211ElementIter, Nl80211ElementRate};
+pub use operation::{
+    Nl80211HeOperation, Nl80211HtOperation, Nl80211VhtOperation,
+};
+pub use rsn::Nl80211Rsn;
+pub use wps::{Nl80211Wps, Nl80211WpsState};
@@ -0,0 +1,252 @@
+// SPDX-License-Identifier: MIT
+
+use byteorder::{ByteOrder, LittleEndian};
+use netlink_packet_utils::{DecodeError, Emitable};
+
+const HT_OPERATION_INFO_LEN: usize = 5;
+const HT_BASIC_MCS_SET_LEN: usize = 16;
+
+/// HT Operation element
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+pub struct Nl80211HtOperation {
+    pub primary_channel: u8,
+    /// Raw HT Operation Information field
+    pub info: [u8; HT_OPERATION_INFO_LEN],
+    pub basic_mcs_set: [u8; HT_BASIC_MCS_SET_LEN],
+}
+
+impl Nl80211HtOperation {
+    pub const LENGTH: usize = 1 + HT_OPERATION_INFO_LEN + HT_BASIC_MCS_SET_LEN;
+
+    /// Secondary channel offset: 0 for no secondary channel, 1 for
+    /// secondary channel above primary channel, 3 for below.
+    pub fn secondary_channel_offset(&self) -> u8 {
+        self.info[0] & 0b11
+    }
+
+    /// Whether any channel width in the supported channel width set is
+    /// allowed, otherwise only 20 MHz.
+    pub fn sta_channel_width_any(&self) -> bool {
+        self.info[0] & (1 << 2) > 0
+    }
+
+    /// HT protection mode
+    pub fn ht_protection(&self) -> u8 {
+        self.info[1] & 0b11
+    }
+
+    /// Channel center frequency segment 2
+    pub fn channel_center_freq_seg2(&self) -> u8 {
+        ((LittleEndian::read_u16(&self.info[1..3]) >> 5) & 0xff) as u8
+    }
+
+    pub(crate) fn parse(payload: &[u8]) -> Result<Self, DecodeError> {
+        if payload.len() < Self::LENGTH {
+            return Err(
+                format!("Invalid HT Operation element {:?}", payload).into()
+            );
+        }
+        let mut ret = Self {
+            primary_channel: payload[0],
+            ..Default::default()
+        };
+        ret.info
+            .copy_from_slice(&payload[1..1 + HT_OPERATION_INFO_LEN]);
+        ret.basic_mcs_set
+            .copy_from_slice(&payload[1 + HT_OPERATION_INFO_LEN..Self::LENGTH]);
+        Ok(ret)
+    }
+}
+
+impl Emitable for Nl80211HtOperation {
+    fn buffer_len(&self) -> usize {
+        Self::LENGTH
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        buffer[0] = self.primary_channel;
+        buffer[1..1 + HT_OPERATION_INFO_LEN].copy_from_slice(&self.info);
+        buffer[1 + HT_OPERATION_INFO_LEN..Self::LENGTH]
+            .copy_from_slice(&self.basic_mcs_set);
+    }
+}
+
+/// VHT Operation element
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+pub struct Nl80211VhtOperation {
+    /// 0 for 20 or 40 MHz, 1 for 80, 160 or 80+80 MHz, 2 and 3 are
+    /// deprecated 160 MHz and 80+80 MHz.
+    pub channel_width: u8,
+    /// Channel center frequency segment 0
+    pub center_freq_seg0: u8,
+    /// Channel center frequency segment 1
+    pub center_freq_seg1: u8,
+    pub basic_mcs_set: u16,
+}
+
+impl Nl80211VhtOperation {
+    pub const LENGTH: usize = 5;
+
+    pub(crate) fn parse(payload: &[u8]) -> Result<Self, DecodeError> {
+        if payload.len() < Self::LENGTH {
+            return Err(
+                format!("Invalid VHT Operation element {:?}", payload).into()
+            );
+        }
+        Ok(Self {
+            channel_width: payload[0],
+            center_freq_seg0: payload[1],
+            center_freq_seg1: payload[2],
+            basic_mcs_set: LittleEndian::read_u16(&payload[3..5]),
+        })
+    }
+}
+
+impl Emitable for Nl80211VhtOperation {
+    fn buffer_len(&self) -> usize {
+        Self::LENGTH
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        buffer[0] = self.channel_width;
+        buffer[1] = self.center_freq_seg0;
+        buffer[2] = self.center_freq_seg1;
+        LittleEndian::write_u16(&mut buffer[3..5], self.basic_mcs_set);
+    }
+}
+
+const HE_OPERATION_PARAMS_LEN: usize = 3;
+const HE_OPERATION_FIXED_LEN: usize = HE_OPERATION_PARAMS_LEN + 1 + 2;
+const HE_VHT_OPERATION_INFO_LEN: usize = 3;
+const HE_6GHZ_OPERATION_INFO_LEN: usize = 5;
+
+const HE_OPERATION_VHT_OPER_INFO: u32 = 1 << 14;
+const HE_OPERATION_CO_HOSTED_BSS: u32 = 1 << 15;
+const HE_OPERATION_6GHZ_OP_INFO: u32 = 1 << 17;
+
+/// HE Operation element
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+pub struct Nl80211HeOperation {
+    /// HE Operation Parameters field (24 bits). The presence bits of optional
+    /// fields are updated according to other properties on emit.
+    pub params: u32,
+    /// Raw BSS Color Information field
+    pub bss_color_info: u8,
+    /// Basic HE-MCS and NSS set
+    pub basic_mcs_nss_set: u16,
+    /// Raw VHT Operation Information field: channel width, center frequency
+    /// segment 0 and 1
+    pub vht_operation_info: Option<[u8; HE_VHT_OPERATION_INFO_LEN]>,
+    pub max_co_hosted_bssid_indicator: Option<u8>,
+    /// Raw 6 GHz Operation Information field
+    pub he_6ghz_operation_info: Option<[u8; HE_6GHZ_OPERATION_INFO_LEN]>,
+}
+
+impl Nl80211HeOperation {
+    pub fn bss_color(&self) -> u8 {
+        self.bss_color_info & 0b111111
+    }
+
+    pub fn bss_color_disabled(&self) -> bool {
+        self.bss_color_info & (1 << 7) > 0
+    }
+
+    fn params_to_emit(&self) -> u32 {
+        let mut params = self.params
+            & !(HE_OPERATION_VHT_OPER_INFO
+                | HE_OPERATION_CO_HOSTED_BSS
+                | HE_OPERATION_6GHZ_OP_INFO);
+        if self.vht_operation_info.is_some() {
+            params |= HE_OPERATION_VHT_OPER_INFO;
+        }
+        if self.max_co_hosted_bssid_indicator.is_some() {
+            params |= HE_OPERATION_CO_HOSTED_BSS;
+        }
+        if self.he_6ghz_operation_info.is_some() {
+            params |= HE_OPERATION_6GHZ_OP_INFO;
+        }
+        params
+    }
+
+    // Parse element content after the element ID extension
+    pub(crate) fn parse(payload: &[u8]) -> Result<Self, DecodeError> {
+        let err_msg = format!("Invalid HE Operation element {:?}", payload);
+        if payload.len() < HE_OPERATION_FIXED_LEN {
+            return Err(err_msg.into());
+        }
+        let mut ret = Self {
+            params: LittleEndian::read_u24(&payload[..3]),
+            bss_color_info: payload[3],
+            basic_mcs_nss_set: LittleEndian::read_u16(&payload[4..6]),
+            ..Default::default()
+        };
+        let mut offset = HE_OPERATION_FIXED_LEN;
+        if ret.params & HE_OPERATION_VHT_OPER_INFO > 0 {
+            let mut info = [0u8; HE_VHT_OPERATION_INFO_LEN];
+            info.copy_from_slice(
+                payload
+                    .get(offset..offset + HE_VHT_OPERATION_INFO_LEN)
+                    .ok_or_else(|| DecodeError::from(err_msg.clone()))?,
+            );
+            ret.vht_operation_info = Some(info);
+            offset += HE_VHT_OPERATION_INFO_LEN;
+        }
+        if ret.params & HE_OPERATION_CO_HOSTED_BSS > 0 {
+            ret.max_co_hosted_bssid_indicator = Some(
+                *payload
+                    .get(offset)
+                    .ok_or_else(|| DecodeError::from(err_msg.clone()))?,
+            );
+            offset += 1;
+        }
+        if ret.params & HE_OPERATION_6GHZ_OP_INFO > 0 {
+            let mut info = [0u8; HE_6GHZ_OPERATION_INFO_LEN];
+            info.copy_from_slice(
+                payload
+                    .get(offset..offset + HE_6GHZ_OPERATION_INFO_LEN)
+                    .ok_or_else(|| DecodeError::from(err_msg.clone()))?,
+            );
+            ret.he_6ghz_operation_info = Some(info);
+        }
+        Ok(ret)
+    }
+}
+
+// Emit element content after the element ID extension
+impl Emitable for Nl80211HeOperation {
+    fn buffer_len(&self) -> usize {
+        HE_OPERATION_FIXED_LEN
+            + self
+                .vht_operation_info
+                .map(|_| HE_VHT_OPERATION_INFO_LEN)
+                .unwrap_or_default()
+            + self
+                .max_co_hosted_bssid_indicator
+                .map(|_| 1)
+                .unwrap_or_default()
+            + self
+                .he_6ghz_operation_info
+                .map(|_| HE_6GHZ_OPERATION_INFO_LEN)
+                .unwrap_or_default()
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        LittleEndian::write_u24(&mut buffer[..3], self.params_to_emit());
+        buffer[3] = self.bss_color_info;
+        LittleEndian::write_u16(&mut buffer[4..6], self.basic_mcs_nss_set);
+        let mut offset = HE_OPERATION_FIXED_LEN;
+        if let Some(info) = self.vht_operation_info {
+            buffer[offset..offset + HE_VHT_OPERATION_INFO_LEN]
+                .copy_from_slice(&info);
+            offset += HE_VHT_OPERATION_INFO_LEN;
+        }
+        if let Some(d) = self.max_co_hosted_bssid_indicator {
+            buffer[offset] = d;
+            offset += 1;
+        }
+        if let Some(info) = self.he_6ghz_operation_info {
+            buffer[offset..offset + HE_6GHZ_OPERATION_INFO_LEN]
+                .copy_from_slice(&info);
+        }
+    }
+}
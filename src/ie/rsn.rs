@@ -0,0 +1,321 @@
+// SPDX-License-Identifier: MIT
+
+use byteorder::{BigEndian, ByteOrder, LittleEndian};
+use netlink_packet_utils::{DecodeError, Emitable};
+
+use crate::{Nl80211AkmSuite, Nl80211CipherSuite};
+
+const SUITE_SELECTOR_LEN: usize = 4;
+const PMKID_LEN: usize = 16;
+
+/// RSN element. Optional fields are `None` or empty when element is
+/// truncated before them, the IEEE 802.11 defaults (CCMP-128 for ciphers,
+/// IEEE 802.1X for AKM, BIP-CMAC-128 for group management) apply in that
+/// case.
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct Nl80211Rsn {
+    pub version: u16,
+    pub group_cipher: Option<Nl80211CipherSuite>,
+    pub pairwise_ciphers: Vec<Nl80211CipherSuite>,
+    pub akm_suites: Vec<Nl80211AkmSuite>,
+    /// Raw RSN Capabilities field
+    pub capabilities: Option<u16>,
+    pub pmkids: Vec<[u8; PMKID_LEN]>,
+    pub group_mgmt_cipher: Option<Nl80211CipherSuite>,
+}
+
+impl Nl80211Rsn {
+    /// Whether management frame protection is required
+    pub fn mfp_required(&self) -> bool {
+        self.capabilities.unwrap_or_default() & (1 << 6) > 0
+    }
+
+    /// Whether management frame protection is supported
+    pub fn mfp_capable(&self) -> bool {
+        self.capabilities.unwrap_or_default() & (1 << 7) > 0
+    }
+
+    pub(crate) fn parse(payload: &[u8]) -> Result<Self, DecodeError> {
+        let mut reader = RsnReader { payload, offset: 0 };
+        let mut ret = Self {
+            version: reader.u16()?.ok_or_else(|| {
+                DecodeError::from(format!("Invalid RSN element {:?}", payload))
+            })?,
+            ..Default::default()
+        };
+        ret.group_cipher = reader.suite()?.map(Nl80211CipherSuite::from);
+        if let Some(count) = reader.u16()? {
+            for _ in 0..count {
+                let suite =
+                    BigEndian::read_u32(reader.take(SUITE_SELECTOR_LEN)?);
+                ret.pairwise_ciphers.push(suite.into());
+            }
+        }
+        if let Some(count) = reader.u16()? {
+            for _ in 0..count {
+                let suite =
+                    BigEndian::read_u32(reader.take(SUITE_SELECTOR_LEN)?);
+                ret.akm_suites.push(suite.into());
+            }
+        }
+        ret.capabilities = reader.u16()?;
+        if let Some(count) = reader.u16()? {
+            for _ in 0..count {
+                let mut pmkid = [0u8; PMKID_LEN];
+                pmkid.copy_from_slice(reader.take(PMKID_LEN)?);
+                ret.pmkids.push(pmkid);
+            }
+        }
+        ret.group_mgmt_cipher = reader.suite()?.map(Nl80211CipherSuite::from);
+        Ok(ret)
+    }
+}
+
+// The RSN element could end after any field, but a field should never be
+// partially present.
+struct RsnReader<'a> {
+    payload: &'a [u8],
+    offset: usize,
+}
+
+impl<'a> RsnReader<'a> {
+    fn take(&mut self, len: usize) -> Result<&'a [u8], DecodeError> {
+        match self.payload.get(self.offset..self.offset + len) {
+            Some(data) => {
+                self.offset += len;
+                Ok(data)
+            }
+            None => Err(format!(
+                "Truncated RSN element {:?} at offset {}",
+                self.payload, self.offset
+            )
+            .into()),
+        }
+    }
+
+    fn is_end(&self) -> bool {
+        self.offset >= self.payload.len()
+    }
+
+    fn u16(&mut self) -> Result<Option<u16>, DecodeError> {
+        if self.is_end() {
+            Ok(None)
+        } else {
+            Ok(Some(LittleEndian::read_u16(self.take(2)?)))
+        }
+    }
+
+    fn suite(&mut self) -> Result<Option<u32>, DecodeError> {
+        if self.is_end() {
+            Ok(None)
+        } else {
+            Ok(Some(BigEndian::read_u32(self.take(SUITE_SELECTOR_LEN)?)))
+        }
+    }
+}
+
+impl Nl80211Rsn {
+    // The RSN element is positional: a field could only be omitted when all
+    // following fields are omitted as well. Returns the number of optional
+    // fields to emit.
+    fn emit_field_count(&self) -> usize {
+        if self.group_mgmt_cipher.is_some() {
+            6
+        } else if !self.pmkids.is_empty() {
+            5
+        } else if self.capabilities.is_some() {
+            4
+        } else if !self.akm_suites.is_empty() {
+            3
+        } else if !self.pairwise_ciphers.is_empty() {
+            2
+        } else if self.group_cipher.is_some() {
+            1
+        } else {
+            0
+        }
+    }
+}
+
+impl Emitable for Nl80211Rsn {
+    fn buffer_len(&self) -> usize {
+        let count = self.emit_field_count();
+        let mut len = 2;
+        if count >= 1 {
+            len += SUITE_SELECTOR_LEN;
+        }
+        if count >= 2 {
+            len += 2 + SUITE_SELECTOR_LEN * self.pairwise_ciphers.len();
+        }
+        if count >= 3 {
+            len += 2 + SUITE_SELECTOR_LEN * self.akm_suites.len();
+        }
+        if count >= 4 {
+            len += 2;
+        }
+        if count >= 5 {
+            len += 2 + PMKID_LEN * self.pmkids.len();
+        }
+        if count >= 6 {
+            len += SUITE_SELECTOR_LEN;
+        }
+        len
+    }
+
+    // Absent fields followed by present ones are emitted with the IEEE
+    // 802.11 default: CCMP-128 group cipher, empty lists and no
+    // capabilities.
+    fn emit(&self, buffer: &mut [u8]) {
+        let count = self.emit_field_count();
+        LittleEndian::write_u16(&mut buffer[..2], self.version);
+        let mut offset = 2;
+        if count >= 1 {
+            let suite = self.group_cipher.unwrap_or(Nl80211CipherSuite::Ccmp);
+            BigEndian::write_u32(&mut buffer[offset..], suite.into());
+            offset += SUITE_SELECTOR_LEN;
+        }
+        if count >= 2 {
+            LittleEndian::write_u16(
+                &mut buffer[offset..],
+                self.pairwise_ciphers.len() as u16,
+            );
+            offset += 2;
+            for suite in &self.pairwise_ciphers {
+                BigEndian::write_u32(&mut buffer[offset..], (*suite).into());
+                offset += SUITE_SELECTOR_LEN;
+            }
+        }
+        if count >= 3 {
+            LittleEndian::write_u16(
+                &mut buffer[offset..],
+                self.akm_suites.len() as u16,
+            );
+            offset += 2;
+            for suite in &self.akm_suites {
+                BigEndian::write_u32(&mut buffer[offset..], (*suite).into());
+                offset += SUITE_SELECTOR_LEN;
+            }
+        }
+        if count >= 4 {
+            LittleEndian::write_u16(
+                &mut buffer[offset..],
+                self.capabilities.unwrap_or_default(),
+            );
+            offset += 2;
+        }
+        if count >= 5 {
+            LittleEndian::write_u16(
+                &mut buffer[offset..],
+                self.pmkids.len() as u16,
+            );
+            offset += 2;
+            for pmkid in &self.pmkids {
+                buffer[offset..offset + PMKID_LEN].copy_from_slice(pmkid);
+                offset += PMKID_LEN;
+            }
+        }
+        if let Some(suite) = self.group_mgmt_cipher {
+            BigEndian::write_u32(&mut buffer[offset..], suite.into());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn emit_to_vec(rsn: &Nl80211Rsn) -> Vec<u8> {
+        let mut buffer = vec![0u8; rsn.buffer_len()];
+        rsn.emit(&mut buffer);
+        buffer
+    }
+
+    #[test]
+    fn parse_wpa2_psk() {
+        let raw = [
+            0x01, 0x00, // version
+            0x00, 0x0f, 0xac, 0x04, // group cipher CCMP
+            0x01, 0x00, 0x00, 0x0f, 0xac, 0x04, // pairwise CCMP
+            0x01, 0x00, 0x00, 0x0f, 0xac, 0x02, // AKM PSK
+            0x0c, 0x00, // capabilities
+        ];
+        let expected = Nl80211Rsn {
+            version: 1,
+            group_cipher: Some(Nl80211CipherSuite::Ccmp),
+            pairwise_ciphers: vec![Nl80211CipherSuite::Ccmp],
+            akm_suites: vec![Nl80211AkmSuite::Psk],
+            capabilities: Some(0x000c),
+            ..Default::default()
+        };
+        assert_eq!(Nl80211Rsn::parse(&raw).unwrap(), expected);
+        assert_eq!(emit_to_vec(&expected), raw);
+    }
+
+    #[test]
+    fn parse_truncated_field() {
+        assert!(Nl80211Rsn::parse(&[0x01, 0x00, 0x00, 0x0f]).is_err());
+    }
+
+    #[test]
+    fn emit_fills_absent_leading_fields() {
+        let rsn = Nl80211Rsn {
+            version: 1,
+            akm_suites: vec![Nl80211AkmSuite::Sae],
+            ..Default::default()
+        };
+        let raw = emit_to_vec(&rsn);
+        assert_eq!(
+            raw,
+            [
+                0x01, 0x00, // version
+                0x00, 0x0f, 0xac, 0x04, // default group cipher CCMP
+                0x00, 0x00, // no pairwise cipher
+                0x01, 0x00, 0x00, 0x0f, 0xac, 0x08, // AKM SAE
+            ]
+        );
+        assert_eq!(
+            Nl80211Rsn::parse(&raw).unwrap(),
+            Nl80211Rsn {
+                group_cipher: Some(Nl80211CipherSuite::Ccmp),
+                ..rsn
+            }
+        );
+    }
+
+    #[test]
+    fn round_trip_group_mgmt_cipher() {
+        let rsn = Nl80211Rsn {
+            version: 1,
+            group_cipher: Some(Nl80211CipherSuite::Ccmp),
+            pairwise_ciphers: vec![Nl80211CipherSuite::Ccmp],
+            akm_suites: vec![Nl80211AkmSuite::Sae],
+            capabilities: Some(0x00c0),
+            pmkids: Vec::new(),
+            group_mgmt_cipher: Some(Nl80211CipherSuite::AesCmac),
+        };
+        let raw = emit_to_vec(&rsn);
+        assert_eq!(raw.len(), rsn.buffer_len());
+        let parsed = Nl80211Rsn::parse(&raw).unwrap();
+        assert_eq!(parsed, rsn);
+        assert!(parsed.mfp_required());
+        assert!(parsed.mfp_capable());
+    }
+
+    #[test]
+    fn round_trip_pmkid_without_capabilities() {
+        let rsn = Nl80211Rsn {
+            version: 1,
+            pmkids: vec![[0xaa; PMKID_LEN]],
+            ..Default::default()
+        };
+        let parsed = Nl80211Rsn::parse(&emit_to_vec(&rsn)).unwrap();
+        assert_eq!(
+            parsed,
+            Nl80211Rsn {
+                group_cipher: Some(Nl80211CipherSuite::Ccmp),
+                capabilities: Some(0),
+                ..rsn
+            }
+        );
+    }
+}
@@ -0,0 +1,309 @@
+// SPDX-License-Identifier: MIT
+
+use byteorder::{BigEndian, ByteOrder};
+use netlink_packet_utils::{DecodeError, Emitable};
+
+pub(crate) const WPS_OUI: [u8; 3] = [0x00, 0x50, 0xf2];
+pub(crate) const WPS_OUI_TYPE: u8 = 4;
+
+const WPS_ATTR_CONFIG_METHODS: u16 = 0x1008;
+const WPS_ATTR_DEVICE_NAME: u16 = 0x1011;
+const WPS_ATTR_DEVICE_PASSWORD_ID: u16 = 0x1012;
+const WPS_ATTR_MANUFACTURER: u16 = 0x1021;
+const WPS_ATTR_MODEL_NAME: u16 = 0x1023;
+const WPS_ATTR_MODEL_NUMBER: u16 = 0x1024;
+const WPS_ATTR_RESPONSE_TYPE: u16 = 0x103b;
+const WPS_ATTR_RF_BANDS: u16 = 0x103c;
+const WPS_ATTR_SELECTED_REGISTRAR: u16 = 0x1041;
+const WPS_ATTR_SERIAL_NUMBER: u16 = 0x1042;
+const WPS_ATTR_WPS_STATE: u16 = 0x1044;
+const WPS_ATTR_UUID_E: u16 = 0x1047;
+const WPS_ATTR_VERSION: u16 = 0x104a;
+const WPS_ATTR_SELECTED_REGISTRAR_CONFIG_METHODS: u16 = 0x1053;
+
+const WPS_ATTR_HEADER_LEN: usize = 4;
+const WPS_UUID_LEN: usize = 16;
+
+const WPS_DEV_PASSWORD_ID_PUSH_BUTTON: u16 = 0x0004;
+
+const WPS_STATE_NOT_CONFIGURED: u8 = 1;
+const WPS_STATE_CONFIGURED: u8 = 2;
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Nl80211WpsState {
+    NotConfigured,
+    Configured,
+    Other(u8),
+}
+
+impl From<u8> for Nl80211WpsState {
+    fn from(d: u8) -> Self {
+        match d {
+            WPS_STATE_NOT_CONFIGURED => Self::NotConfigured,
+            WPS_STATE_CONFIGURED => Self::Configured,
+            _ => Self::Other(d),
+        }
+    }
+}
+
+impl From<Nl80211WpsState> for u8 {
+    fn from(v: Nl80211WpsState) -> u8 {
+        match v {
+            Nl80211WpsState::NotConfigured => WPS_STATE_NOT_CONFIGURED,
+            Nl80211WpsState::Configured => WPS_STATE_CONFIGURED,
+            Nl80211WpsState::Other(d) => d,
+        }
+    }
+}
+
+/// Wi-Fi Protected Setup vendor specific element. Unknown WPS attributes
+/// are ignored.
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct Nl80211Wps {
+    pub version: Option<u8>,
+    pub state: Option<Nl80211WpsState>,
+    /// Registrar is active for WPS provisioning
+    pub selected_registrar: Option<bool>,
+    pub device_password_id: Option<u16>,
+    /// Raw Selected Registrar Config Methods field
+    pub selected_registrar_config_methods: Option<u16>,
+    pub response_type: Option<u8>,
+    pub uuid: Option<[u8; WPS_UUID_LEN]>,
+    pub manufacturer: Option<String>,
+    pub model_name: Option<String>,
+    pub model_number: Option<String>,
+    pub serial_number: Option<String>,
+    pub device_name: Option<String>,
+    /// Raw Config Methods field
+    pub config_methods: Option<u16>,
+    pub rf_bands: Option<u8>,
+}
+
+impl Nl80211Wps {
+    /// Whether push button configuration (PBC) is currently active
+    pub fn push_button_active(&self) -> bool {
+        self.selected_registrar == Some(true)
+            && self.device_password_id == Some(WPS_DEV_PASSWORD_ID_PUSH_BUTTON)
+    }
+
+    // Parse the vendor specific element content after OUI type
+    pub(crate) fn parse(payload: &[u8]) -> Result<Self, DecodeError> {
+        let mut ret = Self::default();
+        let mut offset = 0;
+        while offset + WPS_ATTR_HEADER_LEN <= payload.len() {
+            let kind = BigEndian::read_u16(&payload[offset..offset + 2]);
+            let len =
+                BigEndian::read_u16(&payload[offset + 2..offset + 4]) as usize;
+            offset += WPS_ATTR_HEADER_LEN;
+            let value = payload.get(offset..offset + len).ok_or_else(|| {
+                DecodeError::from(format!(
+                    "Truncated WPS attribute {:#06x} in {:?}",
+                    kind, payload
+                ))
+            })?;
+            offset += len;
+            match kind {
+                WPS_ATTR_VERSION => ret.version = Some(parse_u8(value)?),
+                WPS_ATTR_WPS_STATE => ret.state = Some(parse_u8(value)?.into()),
+                WPS_ATTR_SELECTED_REGISTRAR => {
+                    ret.selected_registrar = Some(parse_u8(value)? > 0)
+                }
+                WPS_ATTR_DEVICE_PASSWORD_ID => {
+                    ret.device_password_id = Some(parse_u16(value)?)
+                }
+                WPS_ATTR_SELECTED_REGISTRAR_CONFIG_METHODS => {
+                    ret.selected_registrar_config_methods =
+                        Some(parse_u16(value)?)
+                }
+                WPS_ATTR_RESPONSE_TYPE => {
+                    ret.response_type = Some(parse_u8(value)?)
+                }
+                WPS_ATTR_UUID_E => {
+                    if value.len() != WPS_UUID_LEN {
+                        return Err(format!(
+                            "Invalid WPS UUID-E attribute value {:?}",
+                            value
+                        )
+                        .into());
+                    }
+                    let mut uuid = [0u8; WPS_UUID_LEN];
+                    uuid.copy_from_slice(value);
+                    ret.uuid = Some(uuid);
+                }
+                WPS_ATTR_MANUFACTURER => {
+                    ret.manufacturer = Some(parse_string(value))
+                }
+                WPS_ATTR_MODEL_NAME => {
+                    ret.model_name = Some(parse_string(value))
+                }
+                WPS_ATTR_MODEL_NUMBER => {
+                    ret.model_number = Some(parse_string(value))
+                }
+                WPS_ATTR_SERIAL_NUMBER => {
+                    ret.serial_number = Some(parse_string(value))
+                }
+                WPS_ATTR_DEVICE_NAME => {
+                    ret.device_name = Some(parse_string(value))
+                }
+                WPS_ATTR_CONFIG_METHODS => {
+                    ret.config_methods = Some(parse_u16(value)?)
+                }
+                WPS_ATTR_RF_BANDS => ret.rf_bands = Some(parse_u8(value)?),
+                _ => {
+                    log::debug!(
+                        "Ignoring unsupported WPS attribute {:#06x} {:?}",
+                        kind,
+                        value
+                    );
+                }
+            }
+        }
+        Ok(ret)
+    }
+
+    fn attributes(&self) -> Vec<(u16, Vec<u8>)> {
+        let mut attrs = Vec::new();
+        if let Some(v) = self.version {
+            attrs.push((WPS_ATTR_VERSION, vec![v]));
+        }
+        if let Some(v) = self.state {
+            attrs.push((WPS_ATTR_WPS_STATE, vec![v.into()]));
+        }
+        if let Some(v) = self.selected_registrar {
+            attrs.push((WPS_ATTR_SELECTED_REGISTRAR, vec![v as u8]));
+        }
+        if let Some(v) = self.device_password_id {
+            attrs.push((WPS_ATTR_DEVICE_PASSWORD_ID, v.to_be_bytes().to_vec()));
+        }
+        if let Some(v) = self.selected_registrar_config_methods {
+            attrs.push((
+                WPS_ATTR_SELECTED_REGISTRAR_CONFIG_METHODS,
+                v.to_be_bytes().to_vec(),
+            ));
+        }
+        if let Some(v) = self.response_type {
+            attrs.push((WPS_ATTR_RESPONSE_TYPE, vec![v]));
+        }
+        if let Some(v) = self.uuid {
+            attrs.push((WPS_ATTR_UUID_E, v.to_vec()));
+        }
+        if let Some(v) = self.manufacturer.as_ref() {
+            attrs.push((WPS_ATTR_MANUFACTURER, v.as_bytes().to_vec()));
+        }
+        if let Some(v) = self.model_name.as_ref() {
+            attrs.push((WPS_ATTR_MODEL_NAME, v.as_bytes().to_vec()));
+        }
+        if let Some(v) = self.model_number.as_ref() {
+            attrs.push((WPS_ATTR_MODEL_NUMBER, v.as_bytes().to_vec()));
+        }
+        if let Some(v) = self.serial_number.as_ref() {
+            attrs.push((WPS_ATTR_SERIAL_NUMBER, v.as_bytes().to_vec()));
+        }
+        if let Some(v) = self.device_name.as_ref() {
+            attrs.push((WPS_ATTR_DEVICE_NAME, v.as_bytes().to_vec()));
+        }
+        if let Some(v) = self.config_methods {
+            attrs.push((WPS_ATTR_CONFIG_METHODS, v.to_be_bytes().to_vec()));
+        }
+        if let Some(v) = self.rf_bands {
+            attrs.push((WPS_ATTR_RF_BANDS, vec![v]));
+        }
+        attrs
+    }
+}
+
+// Emit the vendor specific element content after OUI type
+impl Emitable for Nl80211Wps {
+    fn buffer_len(&self) -> usize {
+        self.attributes()
+            .iter()
+            .map(|(_, value)| WPS_ATTR_HEADER_LEN + value.len())
+            .sum()
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        let mut offset = 0;
+        for (kind, value) in self.attributes() {
+            BigEndian::write_u16(&mut buffer[offset..offset + 2], kind);
+            BigEndian::write_u16(
+                &mut buffer[offset + 2..offset + 4],
+                value.len() as u16,
+            );
+            offset += WPS_ATTR_HEADER_LEN;
+            buffer[offset..offset + value.len()].copy_from_slice(&value);
+            offset += value.len();
+        }
+    }
+}
+
+fn parse_u8(value: &[u8]) -> Result<u8, DecodeError> {
+    match value {
+        [d] => Ok(*d),
+        _ => Err(format!("Invalid WPS u8 attribute value {:?}", value).into()),
+    }
+}
+
+fn parse_u16(value: &[u8]) -> Result<u16, DecodeError> {
+    if value.len() == 2 {
+        Ok(BigEndian::read_u16(value))
+    } else {
+        Err(format!("Invalid WPS u16 attribute value {:?}", value).into())
+    }
+}
+
+fn parse_string(value: &[u8]) -> String {
+    String::from_utf8_lossy(value)
+        .trim_end_matches('\0')
+        .to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_push_button() {
+        let raw = [
+            0x10, 0x4a, 0x00, 0x01, 0x10, // version 1.0
+            0x10, 0x44, 0x00, 0x01, 0x02, // configured
+            0x10, 0x41, 0x00, 0x01, 0x01, // selected registrar
+            0x10, 0x12, 0x00, 0x02, 0x00, 0x04, // push button
+            0x10, 0x11, 0x00, 0x03, b'a', b'p', 0x00, // device name
+            0x10, 0xff, 0x00, 0x01, 0x00, // unknown attribute
+        ];
+        let wps = Nl80211Wps::parse(&raw).unwrap();
+        assert_eq!(
+            wps,
+            Nl80211Wps {
+                version: Some(0x10),
+                state: Some(Nl80211WpsState::Configured),
+                selected_registrar: Some(true),
+                device_password_id: Some(WPS_DEV_PASSWORD_ID_PUSH_BUTTON),
+                device_name: Some("ap".to_string()),
+                ..Default::default()
+            }
+        );
+        assert!(wps.push_button_active());
+    }
+
+    #[test]
+    fn parse_truncated_attribute() {
+        assert!(Nl80211Wps::parse(&[0x10, 0x4a, 0x00, 0x02, 0x10]).is_err());
+    }
+
+    #[test]
+    fn round_trip() {
+        let wps = Nl80211Wps {
+            version: Some(0x10),
+            state: Some(Nl80211WpsState::NotConfigured),
+            uuid: Some([0x11; WPS_UUID_LEN]),
+            manufacturer: Some("vendor".to_string()),
+            config_methods: Some(0x0080),
+            rf_bands: Some(0x03),
+            ..Default::default()
+        };
+        let mut buffer = vec![0u8; wps.buffer_len()];
+        wps.emit(&mut buffer);
+        assert_eq!(Nl80211Wps::parse(&buffer).unwrap(), wps);
+    }
+}
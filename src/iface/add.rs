@@ -0,0 +1,104 @@
+// SPDX-License-Identifier: MIT
+
+use futures::TryStreamExt;
+use netlink_packet_core::{NLM_F_ACK, NLM_F_REQUEST};
+use netlink_packet_generic::GenlMessage;
+
+use crate::{
+    nl80211_execute, Nl80211Attr, Nl80211Cmd, Nl80211Error, Nl80211Handle,
+    Nl80211InterfaceType, Nl80211Message, Nl80211MonitorFlags,
+};
+
+pub struct Nl80211InterfaceAddRequest {
+    handle: Nl80211Handle,
+    wiphy: u32,
+    name: String,
+    iftype: Nl80211InterfaceType,
+    mac: Option<[u8; 6]>,
+    use_4addr: Option<bool>,
+    monitor_flags: Option<Nl80211MonitorFlags>,
+}
+
+impl Nl80211InterfaceAddRequest {
+    pub(crate) fn new(
+        handle: Nl80211Handle,
+        wiphy: u32,
+        name: &str,
+        iftype: Nl80211InterfaceType,
+    ) -> Self {
+        Nl80211InterfaceAddRequest {
+            handle,
+            wiphy,
+            name: name.to_string(),
+            iftype,
+            mac: None,
+            use_4addr: None,
+            monitor_flags: None,
+        }
+    }
+
+    /// MAC address of the new interface
+    pub fn mac(mut self, mac: [u8; 6]) -> Self {
+        self.mac = Some(mac);
+        self
+    }
+
+    /// Use 4-address frame format
+    pub fn use_4addr(mut self, use_4addr: bool) -> Self {
+        self.use_4addr = Some(use_4addr);
+        self
+    }
+
+    /// Flags of monitor interface
+    pub fn monitor_flags(mut self, flags: Nl80211MonitorFlags) -> Self {
+        self.monitor_flags = Some(flags);
+        self
+    }
+
+    /// Create the interface and return the kernel reply describing it
+    pub async fn execute(
+        self,
+    ) -> Result<GenlMessage<Nl80211Message>, Nl80211Error> {
+        let Nl80211InterfaceAddRequest {
+            mut handle,
+            wiphy,
+            name,
+            iftype,
+            mac,
+            use_4addr,
+            monitor_flags,
+        } = self;
+
+        let mut nlas = vec![
+            Nl80211Attr::WiPhy(wiphy),
+            Nl80211Attr::IfName(name),
+            Nl80211Attr::IfType(iftype),
+        ];
+        if let Some(mac) = mac {
+            nlas.push(Nl80211Attr::Mac(mac));
+        }
+        if let Some(use_4addr) = use_4addr {
+            nlas.push(Nl80211Attr::Use4Addr(use_4addr));
+        }
+        if let Some(flags) = monitor_flags {
+            nlas.push(Nl80211Attr::MonitorFlags(flags));
+        }
+
+        let nl80211_msg = Nl80211Message {
+            cmd: Nl80211Cmd::InterfaceNew,
+            nlas,
+        };
+        let mut response = nl80211_execute(
+            &mut handle,
+            nl80211_msg,
+            NLM_F_REQUEST | NLM_F_ACK,
+        )
+        .await;
+        match response.try_next().await? {
+            Some(msg) => Ok(msg),
+            None => Err(Nl80211Error::RequestFailed(
+                "No reply for NL80211_CMD_NEW_INTERFACE".to_string(),
+            )),
+        }
+    }
+}
@@ -0,0 +1,119 @@
+// SPDX-License-Identifier: MIT
+
+use crate::{
+    nl80211_execute_ack, Nl80211Attr, Nl80211Cmd, Nl80211CryptoSettings,
+    Nl80211Error, Nl80211Handle, Nl80211Message, Nl80211Mfp,
+};
+
+pub struct Nl80211AssociateRequest {
+    handle: Nl80211Handle,
+    if_index: u32,
+    bssid: [u8; 6],
+    ssid: Option<String>,
+    freq: Option<u32>,
+    prev_bssid: Option<[u8; 6]>,
+    ie: Option<Vec<u8>>,
+    use_mfp: Option<Nl80211Mfp>,
+    crypto: Option<Nl80211CryptoSettings>,
+}
+
+impl Nl80211AssociateRequest {
+    pub(crate) fn new(
+        handle: Nl80211Handle,
+        if_index: u32,
+        bssid: [u8; 6],
+    ) -> Self {
+        Nl80211AssociateRequest {
+            handle,
+            if_index,
+            bssid,
+            ssid: None,
+            freq: None,
+            prev_bssid: None,
+            ie: None,
+            use_mfp: None,
+            crypto: None,
+        }
+    }
+
+    /// SSID of the BSS
+    pub fn ssid(mut self, ssid: &str) -> Self {
+        self.ssid = Some(ssid.to_string());
+        self
+    }
+
+    /// Frequency in MHz of the BSS
+    pub fn freq(mut self, freq: u32) -> Self {
+        self.freq = Some(freq);
+        self
+    }
+
+    /// Send a Reassociation Request to the BSS, moving from the BSS with
+    /// specified BSSID
+    pub fn prev_bssid(mut self, bssid: [u8; 6]) -> Self {
+        self.prev_bssid = Some(bssid);
+        self
+    }
+
+    /// Raw information elements appended to the (Re)Association Request,
+    /// e.g. the RSN element
+    pub fn ie(mut self, ie: Vec<u8>) -> Self {
+        self.ie = Some(ie);
+        self
+    }
+
+    /// Whether management frame protection is used
+    pub fn use_mfp(mut self, mfp: Nl80211Mfp) -> Self {
+        self.use_mfp = Some(mfp);
+        self
+    }
+
+    /// Security settings, open network when not set
+    pub fn crypto(mut self, crypto: Nl80211CryptoSettings) -> Self {
+        self.crypto = Some(crypto);
+        self
+    }
+
+    /// The received (Re)Association Response frame is reported by a
+    /// `NL80211_CMD_ASSOCIATE` multicast event.
+    pub async fn execute(self) -> Result<(), Nl80211Error> {
+        let Nl80211AssociateRequest {
+            mut handle,
+            if_index,
+            bssid,
+            ssid,
+            freq,
+            prev_bssid,
+            ie,
+            use_mfp,
+            crypto,
+        } = self;
+
+        let mut nlas =
+            vec![Nl80211Attr::IfIndex(if_index), Nl80211Attr::Mac(bssid)];
+        if let Some(ssid) = ssid {
+            nlas.push(Nl80211Attr::Ssid(ssid));
+        }
+        if let Some(freq) = freq {
+            nlas.push(Nl80211Attr::WiPhyFreq(freq));
+        }
+        if let Some(bssid) = prev_bssid {
+            nlas.push(Nl80211Attr::PrevBssid(bssid));
+        }
+        if let Some(ie) = ie {
+            nlas.push(Nl80211Attr::Ie(ie));
+        }
+        if let Some(mfp) = use_mfp {
+            nlas.push(Nl80211Attr::UseMfp(mfp));
+        }
+        if let Some(crypto) = crypto {
+            nlas.extend(Vec::<Nl80211Attr>::from(&crypto));
+        }
+
+        let nl80211_msg = Nl80211Message {
+            cmd: Nl80211Cmd::Associate,
+            nlas,
+        };
+        nl80211_execute_ack(&mut handle, nl80211_msg).await
+    }
+}
@@ -0,0 +1,119 @@
+// SPDX-License-Identifier: MIT
+
+use crate::{
+    nl80211_execute_ack, Nl80211Attr, Nl80211AuthType, Nl80211Cmd,
+    Nl80211Error, Nl80211Handle, Nl80211Message,
+};
+
+pub struct Nl80211AuthenticateRequest {
+    handle: Nl80211Handle,
+    if_index: u32,
+    bssid: [u8; 6],
+    ssid: Option<String>,
+    freq: Option<u32>,
+    auth_type: Nl80211AuthType,
+    ie: Option<Vec<u8>>,
+    auth_data: Option<Vec<u8>>,
+    local_state_change: bool,
+}
+
+impl Nl80211AuthenticateRequest {
+    pub(crate) fn new(
+        handle: Nl80211Handle,
+        if_index: u32,
+        bssid: [u8; 6],
+    ) -> Self {
+        Nl80211AuthenticateRequest {
+            handle,
+            if_index,
+            bssid,
+            ssid: None,
+            freq: None,
+            auth_type: Nl80211AuthType::OpenSystem,
+            ie: None,
+            auth_data: None,
+            local_state_change: false,
+        }
+    }
+
+    /// SSID of the BSS
+    pub fn ssid(mut self, ssid: &str) -> Self {
+        self.ssid = Some(ssid.to_string());
+        self
+    }
+
+    /// Frequency in MHz of the BSS
+    pub fn freq(mut self, freq: u32) -> Self {
+        self.freq = Some(freq);
+        self
+    }
+
+    /// Authentication type, Open System by default.
+    /// `Nl80211AuthType::Automatic` is not allowed.
+    pub fn auth_type(mut self, auth_type: Nl80211AuthType) -> Self {
+        self.auth_type = auth_type;
+        self
+    }
+
+    /// Raw information elements appended to the Authentication frame
+    pub fn ie(mut self, ie: Vec<u8>) -> Self {
+        self.ie = Some(ie);
+        self
+    }
+
+    /// Authentication fields following the Authentication transaction
+    /// sequence number, required by SAE and FILS
+    pub fn auth_data(mut self, data: Vec<u8>) -> Self {
+        self.auth_data = Some(data);
+        self
+    }
+
+    /// Only update the local state without sending frames to the BSS
+    pub fn local_state_change(mut self) -> Self {
+        self.local_state_change = true;
+        self
+    }
+
+    /// The received Authentication frame is reported by a
+    /// `NL80211_CMD_AUTHENTICATE` multicast event.
+    pub async fn execute(self) -> Result<(), Nl80211Error> {
+        let Nl80211AuthenticateRequest {
+            mut handle,
+            if_index,
+            bssid,
+            ssid,
+            freq,
+            auth_type,
+            ie,
+            auth_data,
+            local_state_change,
+        } = self;
+
+        let mut nlas = vec![
+            Nl80211Attr::IfIndex(if_index),
+            Nl80211Attr::Mac(bssid),
+            Nl80211Attr::AuthType(auth_type),
+        ];
+        if let Some(ssid) = ssid {
+            nlas.push(Nl80211Attr::Ssid(ssid));
+        }
+        if let Some(freq) = freq {
+            nlas.push(Nl80211Attr::WiPhyFreq(freq));
+        }
+        if let Some(ie) = ie {
+            nlas.push(Nl80211Attr::Ie(ie));
+        }
+        if let Some(data) = auth_data {
+            nlas.push(Nl80211Attr::AuthData(data));
+        }
+        if local_state_change {
+            nlas.push(Nl80211Attr::LocalStateChange);
+        }
+
+        let nl80211_msg = Nl80211Message {
+            cmd: Nl80211Cmd::Authenticate,
+            nlas,
+        };
+        nl80211_execute_ack(&mut handle, nl80211_msg).await
+    }
+}
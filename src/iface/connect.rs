@@ -0,0 +1,153 @@
+// SPDX-License-Identifier: MIT
+
+use crate::{
+    nl80211_execute_ack, Nl80211Attr, Nl80211AuthType, Nl80211Cmd,
+    Nl80211CryptoSettings, Nl80211Error, Nl80211Handle, Nl80211Key,
+    Nl80211Message,
+};
+
+pub struct Nl80211ConnectRequest {
+    handle: Nl80211Handle,
+    if_index: u32,
+    ssid: Option<String>,
+    bssid: Option<[u8; 6]>,
+    bssid_hint: Option<[u8; 6]>,
+    freq: Option<u32>,
+    freq_hint: Option<u32>,
+    auth_type: Option<Nl80211AuthType>,
+    ie: Option<Vec<u8>>,
+    crypto: Option<Nl80211CryptoSettings>,
+    keys: Vec<Nl80211Key>,
+}
+
+impl Nl80211ConnectRequest {
+    pub(crate) fn new(handle: Nl80211Handle, if_index: u32) -> Self {
+        Nl80211ConnectRequest {
+            handle,
+            if_index,
+            ssid: None,
+            bssid: None,
+            bssid_hint: None,
+            freq: None,
+            freq_hint: None,
+            auth_type: None,
+            ie: None,
+            crypto: None,
+            keys: Vec::new(),
+        }
+    }
+
+    /// SSID of the network to connect to
+    pub fn ssid(mut self, ssid: &str) -> Self {
+        self.ssid = Some(ssid.to_string());
+        self
+    }
+
+    /// Only connect to the BSS with specified BSSID
+    pub fn bssid(mut self, bssid: [u8; 6]) -> Self {
+        self.bssid = Some(bssid);
+        self
+    }
+
+    /// Prefer the BSS with specified BSSID, but allow the kernel or driver
+    /// to pick another BSS of the same network
+    pub fn bssid_hint(mut self, bssid: [u8; 6]) -> Self {
+        self.bssid_hint = Some(bssid);
+        self
+    }
+
+    /// Only connect on specified frequency in MHz
+    pub fn freq(mut self, freq: u32) -> Self {
+        self.freq = Some(freq);
+        self
+    }
+
+    /// Prefer specified frequency in MHz, but allow the kernel or driver to
+    /// pick another channel
+    pub fn freq_hint(mut self, freq: u32) -> Self {
+        self.freq_hint = Some(freq);
+        self
+    }
+
+    /// Authentication type, determined automatically when not set
+    pub fn auth_type(mut self, auth_type: Nl80211AuthType) -> Self {
+        self.auth_type = Some(auth_type);
+        self
+    }
+
+    /// Raw information elements appended to the (Re)Association Request
+    pub fn ie(mut self, ie: Vec<u8>) -> Self {
+        self.ie = Some(ie);
+        self
+    }
+
+    /// Security settings, open network when not set
+    pub fn crypto(mut self, crypto: Nl80211CryptoSettings) -> Self {
+        self.crypto = Some(crypto);
+        self
+    }
+
+    /// Add a static WEP key, the key marked as `default` is used for
+    /// transmission
+    pub fn key(mut self, key: Nl80211Key) -> Self {
+        self.keys.push(key);
+        self
+    }
+
+    /// The result of the connection is reported by a `NL80211_CMD_CONNECT`
+    /// multicast event.
+    pub async fn execute(self) -> Result<(), Nl80211Error> {
+        let Nl80211ConnectRequest {
+            mut handle,
+            if_index,
+            ssid,
+            bssid,
+            bssid_hint,
+            freq,
+            freq_hint,
+            auth_type,
+            ie,
+            crypto,
+            keys,
+        } = self;
+
+        let mut nlas = vec![Nl80211Attr::IfIndex(if_index)];
+        if let Some(ssid) = ssid {
+            nlas.push(Nl80211Attr::Ssid(ssid));
+        }
+        if let Some(bssid) = bssid {
+            nlas.push(Nl80211Attr::Mac(bssid));
+        }
+        if let Some(bssid) = bssid_hint {
+            nlas.push(Nl80211Attr::MacHint(bssid));
+        }
+        if let Some(freq) = freq {
+            nlas.push(Nl80211Attr::WiPhyFreq(freq));
+        }
+        if let Some(freq) = freq_hint {
+            nlas.push(Nl80211Attr::WiPhyFreqHint(freq));
+        }
+        // The kernel rejects NL80211_AUTHTYPE_AUTOMATIC, it is expressed by
+        // leaving out the attribute
+        if let Some(auth_type) =
+            auth_type.filter(|t| *t != Nl80211AuthType::Automatic)
+        {
+            nlas.push(Nl80211Attr::AuthType(auth_type));
+        }
+        if let Some(ie) = ie {
+            nlas.push(Nl80211Attr::Ie(ie));
+        }
+        if let Some(crypto) = crypto {
+            nlas.extend(Vec::<Nl80211Attr>::from(&crypto));
+        }
+        if !keys.is_empty() {
+            nlas.push(Nl80211Attr::Keys(keys));
+        }
+
+        let nl80211_msg = Nl80211Message {
+            cmd: Nl80211Cmd::Connect,
+            nlas,
+        };
+        nl80211_execute_ack(&mut handle, nl80211_msg).await
+    }
+}
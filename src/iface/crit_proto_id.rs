@@ -0,0 +1,42 @@
+// SPDX-License-Identifier: MIT
+
+const NL80211_CRIT_PROTO_UNSPEC: u16 = 0;
+const NL80211_CRIT_PROTO_DHCP: u16 = 1;
+const NL80211_CRIT_PROTO_EAPOL: u16 = 2;
+const NL80211_CRIT_PROTO_APIPA: u16 = 3;
+
+/// Critical protocol requiring increased connection reliability
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Nl80211CritProtoId {
+    Unspec,
+    /// BOOTP or DHCPv6
+    Dhcp,
+    Eapol,
+    /// Automatic private IP addressing
+    Apipa,
+    Other(u16),
+}
+
+impl From<u16> for Nl80211CritProtoId {
+    fn from(d: u16) -> Self {
+        match d {
+            NL80211_CRIT_PROTO_UNSPEC => Self::Unspec,
+            NL80211_CRIT_PROTO_DHCP => Self::Dhcp,
+            NL80211_CRIT_PROTO_EAPOL => Self::Eapol,
+            NL80211_CRIT_PROTO_APIPA => Self::Apipa,
+            _ => Self::Other(d),
+        }
+    }
+}
+
+impl From<Nl80211CritProtoId> for u16 {
+    fn from(v: Nl80211CritProtoId) -> u16 {
+        match v {
+            Nl80211CritProtoId::Unspec => NL80211_CRIT_PROTO_UNSPEC,
+            Nl80211CritProtoId::Dhcp => NL80211_CRIT_PROTO_DHCP,
+            Nl80211CritProtoId::Eapol => NL80211_CRIT_PROTO_EAPOL,
+            Nl80211CritProtoId::Apipa => NL80211_CRIT_PROTO_APIPA,
+            Nl80211CritProtoId::Other(d) => d,
+        }
+    }
+}
@@ -0,0 +1,74 @@
+// SPDX-License-Identifier: MIT
+
+use crate::{
+    nl80211_execute_ack, Nl80211Attr, Nl80211Cmd, Nl80211CritProtoId,
+    Nl80211Error, Nl80211Handle, Nl80211Message,
+};
+
+pub struct Nl80211CritProtocolStartRequest {
+    handle: Nl80211Handle,
+    if_index: u32,
+    protocol: Nl80211CritProtoId,
+    duration: u16,
+}
+
+impl Nl80211CritProtocolStartRequest {
+    pub(crate) fn new(
+        handle: Nl80211Handle,
+        if_index: u32,
+        protocol: Nl80211CritProtoId,
+        duration: u16,
+    ) -> Self {
+        Nl80211CritProtocolStartRequest {
+            handle,
+            if_index,
+            protocol,
+            duration,
+        }
+    }
+
+    /// The kernel sends `Nl80211Cmd::CritProtocolStop` to this socket when
+    /// the duration expires before `crit_protocol_stop()` is requested.
+    pub async fn execute(self) -> Result<(), Nl80211Error> {
+        let Nl80211CritProtocolStartRequest {
+            mut handle,
+            if_index,
+            protocol,
+            duration,
+        } = self;
+
+        let nl80211_msg = Nl80211Message {
+            cmd: Nl80211Cmd::CritProtocolStart,
+            nlas: vec![
+                Nl80211Attr::IfIndex(if_index),
+                Nl80211Attr::CritProtId(protocol),
+                Nl80211Attr::MaxCritProtDuration(duration),
+            ],
+        };
+        nl80211_execute_ack(&mut handle, nl80211_msg).await
+    }
+}
+
+pub struct Nl80211CritProtocolStopRequest {
+    handle: Nl80211Handle,
+    if_index: u32,
+}
+
+impl Nl80211CritProtocolStopRequest {
+    pub(crate) fn new(handle: Nl80211Handle, if_index: u32) -> Self {
+        Nl80211CritProtocolStopRequest { handle, if_index }
+    }
+
+    pub async fn execute(self) -> Result<(), Nl80211Error> {
+        let Nl80211CritProtocolStopRequest {
+            mut handle,
+            if_index,
+        } = self;
+
+        let nl80211_msg = Nl80211Message {
+            cmd: Nl80211Cmd::CritProtocolStop,
+            nlas: vec![Nl80211Attr::IfIndex(if_index)],
+        };
+        nl80211_execute_ack(&mut handle, nl80211_msg).await
+    }
+}
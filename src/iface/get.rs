@@ -1,26 +1,63 @@
 // SPDX-License-Identifier: MIT
 
 use futures::TryStream;
+use netlink_packet_core::{NLM_F_DUMP, NLM_F_REQUEST};
 use netlink_packet_generic::GenlMessage;
 
-use crate::{nl80211_execute, Nl80211Error, Nl80211Handle, Nl80211Message};
+use crate::{
+    nl80211_execute, Nl80211Attr, Nl80211Error, Nl80211Handle, Nl80211Message,
+};
 
 pub struct Nl80211InterfaceGetRequest {
     handle: Nl80211Handle,
+    if_index: Option<u32>,
+    wdev: Option<u64>,
 }
 
 impl Nl80211InterfaceGetRequest {
     pub(crate) fn new(handle: Nl80211Handle) -> Self {
-        Nl80211InterfaceGetRequest { handle }
+        Nl80211InterfaceGetRequest {
+            handle,
+            if_index: None,
+            wdev: None,
+        }
+    }
+
+    /// Only query the interface with specified interface index
+    pub fn match_ifindex(mut self, if_index: u32) -> Self {
+        self.if_index = Some(if_index);
+        self
+    }
+
+    /// Only query the wireless device with specified identifier, useful for
+    /// interfaces without netdev like P2P device
+    pub fn match_wdev(mut self, wdev: u64) -> Self {
+        self.wdev = Some(wdev);
+        self
     }
 
     pub async fn execute(
         self,
     ) -> impl TryStream<Ok = GenlMessage<Nl80211Message>, Error = Nl80211Error>
     {
-        let Nl80211InterfaceGetRequest { mut handle } = self;
+        let Nl80211InterfaceGetRequest {
+            mut handle,
+            if_index,
+            wdev,
+        } = self;
 
-        let nl80211_msg = Nl80211Message::new_interface_get();
-        nl80211_execute(&mut handle, nl80211_msg).await
+        let mut nl80211_msg = Nl80211Message::new_interface_get();
+        let flags = if if_index.is_none() && wdev.is_none() {
+            NLM_F_REQUEST | NLM_F_DUMP
+        } else {
+            NLM_F_REQUEST
+        };
+        if let Some(if_index) = if_index {
+            nl80211_msg.nlas.push(Nl80211Attr::IfIndex(if_index));
+        }
+        if let Some(wdev) = wdev {
+            nl80211_msg.nlas.push(Nl80211Attr::Wdev(wdev));
+        }
+        nl80211_execute(&mut handle, nl80211_msg, flags).await
     }
 }
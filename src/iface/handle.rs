@@ -1,6 +1,22 @@
 // SPDX-License-Identifier: MIT
 
-use crate::{Nl80211Handle, Nl80211InterfaceGetRequest};
+use crate::{
+    Nl80211AssociateRequest, Nl80211AuthenticateRequest, Nl80211ChannelDef,
+    Nl80211ChannelSetRequest, Nl80211ConnectRequest, Nl80211Cqm,
+    Nl80211CqmSetRequest, Nl80211CritProtoId, Nl80211CritProtocolStartRequest,
+    Nl80211CritProtocolStopRequest, Nl80211Handle, Nl80211IbssJoinRequest,
+    Nl80211IbssLeaveRequest, Nl80211InterfaceAddRequest,
+    Nl80211InterfaceGetRequest, Nl80211InterfaceSetRequest,
+    Nl80211InterfaceType, Nl80211MulticastToUnicastSetRequest,
+    Nl80211NoackMapSetRequest, Nl80211OcbJoinRequest, Nl80211OcbLeaveRequest,
+    Nl80211PmkDelRequest, Nl80211PmkSetRequest, Nl80211PmksaDelRequest,
+    Nl80211PmksaFlushRequest, Nl80211PmksaSetRequest,
+    Nl80211PowerSaveGetRequest, Nl80211PowerSaveSetRequest,
+    Nl80211PowerSaveState, Nl80211QosMapSetRequest, Nl80211TdlsAction,
+    Nl80211TdlsMgmtRequest, Nl80211TdlsOperRequest, Nl80211TdlsOperation,
+    Nl80211TidConfigSetRequest, Nl80211TxTsAddRequest, Nl80211TxTsDelRequest,
+    Nl80211UpdateOweInfoRequest,
+};
 
 pub struct Nl80211InterfaceHandle(Nl80211Handle);
 
@@ -14,4 +30,307 @@ impl Nl80211InterfaceHandle {
     pub fn get(&mut self) -> Nl80211InterfaceGetRequest {
         Nl80211InterfaceGetRequest::new(self.0.clone())
     }
+
+    /// Create a virtual interface on specified wiphy
+    /// (equivalent to `iw phy PHY interface add NAME type TYPE`)
+    pub fn add(
+        &mut self,
+        wiphy: u32,
+        name: &str,
+        iftype: Nl80211InterfaceType,
+    ) -> Nl80211InterfaceAddRequest {
+        Nl80211InterfaceAddRequest::new(self.0.clone(), wiphy, name, iftype)
+    }
+
+    /// Change the type or 4-address mode of specified interface
+    /// (equivalent to `iw dev DEVICE set type TYPE`)
+    pub fn set(&mut self, if_index: u32) -> Nl80211InterfaceSetRequest {
+        Nl80211InterfaceSetRequest::new(self.0.clone(), if_index)
+    }
+
+    /// Connect specified interface to a network, letting the kernel or
+    /// driver handle authentication and association
+    /// (equivalent to `iw dev DEVICE connect SSID`)
+    pub fn connect(&mut self, if_index: u32) -> Nl80211ConnectRequest {
+        Nl80211ConnectRequest::new(self.0.clone(), if_index)
+    }
+
+    /// Authenticate specified interface with the BSS, for userspace SME
+    /// (equivalent to `iw dev DEVICE auth SSID BSSID FREQ TYPE`)
+    pub fn authenticate(
+        &mut self,
+        if_index: u32,
+        bssid: [u8; 6],
+    ) -> Nl80211AuthenticateRequest {
+        Nl80211AuthenticateRequest::new(self.0.clone(), if_index, bssid)
+    }
+
+    /// Associate specified interface with the BSS it has authenticated
+    /// with, for userspace SME
+    pub fn associate(
+        &mut self,
+        if_index: u32,
+        bssid: [u8; 6],
+    ) -> Nl80211AssociateRequest {
+        Nl80211AssociateRequest::new(self.0.clone(), if_index, bssid)
+    }
+
+    /// Provide the PMK of 802.1X authentication to a driver offloading the
+    /// 4-way handshake with the peer
+    pub fn set_pmk(
+        &mut self,
+        if_index: u32,
+        mac: [u8; 6],
+        pmk: Vec<u8>,
+    ) -> Nl80211PmkSetRequest {
+        Nl80211PmkSetRequest::new(self.0.clone(), if_index, mac, pmk)
+    }
+
+    /// Remove the PMK of the peer previously set by `set_pmk()`
+    pub fn del_pmk(
+        &mut self,
+        if_index: u32,
+        mac: [u8; 6],
+    ) -> Nl80211PmkDelRequest {
+        Nl80211PmkDelRequest::new(self.0.clone(), if_index, mac)
+    }
+
+    /// Add a PMKSA cache entry for the BSS
+    pub fn set_pmksa(
+        &mut self,
+        if_index: u32,
+        bssid: [u8; 6],
+        pmkid: Vec<u8>,
+    ) -> Nl80211PmksaSetRequest {
+        Nl80211PmksaSetRequest::new(self.0.clone(), if_index, bssid, pmkid)
+    }
+
+    /// Delete the PMKSA cache entries of the BSS
+    pub fn del_pmksa(
+        &mut self,
+        if_index: u32,
+        bssid: [u8; 6],
+    ) -> Nl80211PmksaDelRequest {
+        Nl80211PmksaDelRequest::new(self.0.clone(), if_index, bssid)
+    }
+
+    /// Delete all the PMKSA cache entries of specified interface
+    pub fn flush_pmksa(&mut self, if_index: u32) -> Nl80211PmksaFlushRequest {
+        Nl80211PmksaFlushRequest::new(self.0.clone(), if_index)
+    }
+
+    /// Reply to the OWE information update event of the driver with the
+    /// result of the Diffie-Hellman exchange with the peer
+    pub fn update_owe_info(
+        &mut self,
+        if_index: u32,
+        mac: [u8; 6],
+        status_code: u16,
+    ) -> Nl80211UpdateOweInfoRequest {
+        Nl80211UpdateOweInfoRequest::new(
+            self.0.clone(),
+            if_index,
+            mac,
+            status_code,
+        )
+    }
+
+    /// Join the IBSS with specified SSID, or create it on specified channel
+    /// when not found
+    /// (equivalent to `iw dev DEVICE ibss join SSID FREQ`)
+    pub fn join_ibss(
+        &mut self,
+        if_index: u32,
+        ssid: &str,
+        chandef: Nl80211ChannelDef,
+    ) -> Nl80211IbssJoinRequest {
+        Nl80211IbssJoinRequest::new(self.0.clone(), if_index, ssid, chandef)
+    }
+
+    /// Leave the IBSS of specified interface
+    /// (equivalent to `iw dev DEVICE ibss leave`)
+    pub fn leave_ibss(&mut self, if_index: u32) -> Nl80211IbssLeaveRequest {
+        Nl80211IbssLeaveRequest::new(self.0.clone(), if_index)
+    }
+
+    /// Start communicating outside of the context of a BSS (802.11p) on the
+    /// channel, typically 5 or 10 MHz wide in the 5.9 GHz band, the
+    /// interface must be of the `Ocb` type
+    /// (equivalent to `iw dev DEVICE ocb join FREQ 5MHz|10MHz`)
+    pub fn join_ocb(
+        &mut self,
+        if_index: u32,
+        chandef: Nl80211ChannelDef,
+    ) -> Nl80211OcbJoinRequest {
+        Nl80211OcbJoinRequest::new(self.0.clone(), if_index, chandef)
+    }
+
+    /// Stop OCB communication on specified interface
+    /// (equivalent to `iw dev DEVICE ocb leave`)
+    pub fn leave_ocb(&mut self, if_index: u32) -> Nl80211OcbLeaveRequest {
+        Nl80211OcbLeaveRequest::new(self.0.clone(), if_index)
+    }
+
+    /// Retrieve the power save state of specified interface
+    /// (equivalent to `iw dev DEVICE get power_save`)
+    pub fn get_power_save(
+        &mut self,
+        if_index: u32,
+    ) -> Nl80211PowerSaveGetRequest {
+        Nl80211PowerSaveGetRequest::new(self.0.clone(), if_index)
+    }
+
+    /// Enable or disable power save on specified interface
+    /// (equivalent to `iw dev DEVICE set power_save on|off`)
+    pub fn set_power_save(
+        &mut self,
+        if_index: u32,
+        state: Nl80211PowerSaveState,
+    ) -> Nl80211PowerSaveSetRequest {
+        Nl80211PowerSaveSetRequest::new(self.0.clone(), if_index, state)
+    }
+
+    /// Configure the connection quality monitor of specified interface
+    /// (equivalent to `iw dev DEVICE cqm rssi THRESHOLD HYSTERESIS`)
+    pub fn set_cqm(
+        &mut self,
+        if_index: u32,
+        cqm: Nl80211Cqm,
+    ) -> Nl80211CqmSetRequest {
+        Nl80211CqmSetRequest::new(self.0.clone(), if_index, cqm)
+    }
+
+    /// Send a TDLS action frame to the peer, for drivers leaving the TDLS
+    /// setup exchange to userspace
+    pub fn tdls_mgmt(
+        &mut self,
+        if_index: u32,
+        peer: [u8; 6],
+        action: Nl80211TdlsAction,
+        dialog_token: u8,
+    ) -> Nl80211TdlsMgmtRequest {
+        Nl80211TdlsMgmtRequest::new(
+            self.0.clone(),
+            if_index,
+            peer,
+            action,
+            dialog_token,
+        )
+    }
+
+    /// Perform a TDLS operation with the peer, e.g. enable the link once
+    /// the setup exchange completed
+    pub fn tdls_oper(
+        &mut self,
+        if_index: u32,
+        peer: [u8; 6],
+        operation: Nl80211TdlsOperation,
+    ) -> Nl80211TdlsOperRequest {
+        Nl80211TdlsOperRequest::new(self.0.clone(), if_index, peer, operation)
+    }
+
+    /// Send the frames of the TIDs set in `map` (bit N for TID N) without
+    /// requesting an ACK
+    /// (equivalent to `iw dev DEVICE set noack_map MAP`)
+    pub fn set_noack_map(
+        &mut self,
+        if_index: u32,
+        map: u16,
+    ) -> Nl80211NoackMapSetRequest {
+        Nl80211NoackMapSetRequest::new(self.0.clone(), if_index, map)
+    }
+
+    /// Convert multicast frames sent by the AP interface to unicast frames
+    /// for each associated station
+    pub fn set_multicast_to_unicast(
+        &mut self,
+        if_index: u32,
+        enable: bool,
+    ) -> Nl80211MulticastToUnicastSetRequest {
+        Nl80211MulticastToUnicastSetRequest::new(
+            self.0.clone(),
+            if_index,
+            enable,
+        )
+    }
+
+    /// Set the mapping of IP DSCP values to user priorities provided by the
+    /// AP in the QoS Map element, cleared on disassociation
+    pub fn set_qos_map(&mut self, if_index: u32) -> Nl80211QosMapSetRequest {
+        Nl80211QosMapSetRequest::new(self.0.clone(), if_index)
+    }
+
+    /// Configure retry limits, aggregation, RTS/CTS protection and TX rates
+    /// per TID, for all peers of the interface or for a single peer
+    pub fn set_tid_config(
+        &mut self,
+        if_index: u32,
+    ) -> Nl80211TidConfigSetRequest {
+        Nl80211TidConfigSetRequest::new(self.0.clone(), if_index)
+    }
+
+    /// Request increased connection reliability for up to `duration`
+    /// milliseconds (at most 5000) while the critical protocol runs, for
+    /// example during DHCP or EAPOL exchanges
+    pub fn crit_protocol_start(
+        &mut self,
+        if_index: u32,
+        protocol: Nl80211CritProtoId,
+        duration: u16,
+    ) -> Nl80211CritProtocolStartRequest {
+        Nl80211CritProtocolStartRequest::new(
+            self.0.clone(),
+            if_index,
+            protocol,
+            duration,
+        )
+    }
+
+    /// Return the connection reliability to normal once the critical
+    /// protocol completed
+    pub fn crit_protocol_stop(
+        &mut self,
+        if_index: u32,
+    ) -> Nl80211CritProtocolStopRequest {
+        Nl80211CritProtocolStopRequest::new(self.0.clone(), if_index)
+    }
+
+    /// Add a traffic stream with the peer after the AP admitted it in the
+    /// ADDTS exchange, for drivers supporting WMM admission control
+    pub fn add_tx_ts(
+        &mut self,
+        if_index: u32,
+        tsid: u8,
+        peer: [u8; 6],
+        user_prio: u8,
+    ) -> Nl80211TxTsAddRequest {
+        Nl80211TxTsAddRequest::new(
+            self.0.clone(),
+            if_index,
+            tsid,
+            peer,
+            user_prio,
+        )
+    }
+
+    /// Delete a traffic stream previously added with `add_tx_ts()`
+    pub fn del_tx_ts(
+        &mut self,
+        if_index: u32,
+        tsid: u8,
+        peer: [u8; 6],
+    ) -> Nl80211TxTsDelRequest {
+        Nl80211TxTsDelRequest::new(self.0.clone(), if_index, tsid, peer)
+    }
+
+    /// Tune specified interface to the channel, for example a monitor
+    /// interface, after checking the channel definition is consistent
+    /// (equivalent to `iw dev DEVICE set channel ...`)
+    pub fn set_channel(
+        &mut self,
+        if_index: u32,
+        chandef: Nl80211ChannelDef,
+    ) -> Nl80211ChannelSetRequest {
+        Nl80211ChannelSetRequest::new(self.0.clone(), if_index, chandef)
+    }
 }
@@ -0,0 +1,150 @@
+// SPDX-License-Identifier: MIT
+
+use crate::{
+    nl80211_execute_ack, Nl80211Attr, Nl80211ChannelDef, Nl80211Cmd,
+    Nl80211Error, Nl80211Handle, Nl80211Key, Nl80211Message,
+};
+
+pub struct Nl80211IbssJoinRequest {
+    handle: Nl80211Handle,
+    if_index: u32,
+    ssid: String,
+    chandef: Nl80211ChannelDef,
+    freq_fixed: bool,
+    bssid: Option<[u8; 6]>,
+    basic_rates: Option<Vec<u8>>,
+    beacon_interval: Option<u32>,
+    ie: Option<Vec<u8>>,
+    keys: Vec<Nl80211Key>,
+}
+
+impl Nl80211IbssJoinRequest {
+    pub(crate) fn new(
+        handle: Nl80211Handle,
+        if_index: u32,
+        ssid: &str,
+        chandef: Nl80211ChannelDef,
+    ) -> Self {
+        Nl80211IbssJoinRequest {
+            handle,
+            if_index,
+            ssid: ssid.to_string(),
+            chandef,
+            freq_fixed: false,
+            bssid: None,
+            basic_rates: None,
+            beacon_interval: None,
+            ie: None,
+            keys: Vec::new(),
+        }
+    }
+
+    /// Only use the specified channel instead of joining an existing IBSS
+    /// found on other channels
+    pub fn freq_fixed(mut self) -> Self {
+        self.freq_fixed = true;
+        self
+    }
+
+    /// Only join the IBSS with specified BSSID, or use it when creating one
+    pub fn bssid(mut self, bssid: [u8; 6]) -> Self {
+        self.bssid = Some(bssid);
+        self
+    }
+
+    /// Basic rates in units of 500 kbps, used when creating the IBSS
+    pub fn basic_rates(mut self, rates: Vec<u8>) -> Self {
+        self.basic_rates = Some(rates);
+        self
+    }
+
+    /// Beacon interval in TUs, used when creating the IBSS
+    pub fn beacon_interval(mut self, interval: u32) -> Self {
+        self.beacon_interval = Some(interval);
+        self
+    }
+
+    /// Raw information elements appended to the Beacon and Probe Response
+    pub fn ie(mut self, ie: Vec<u8>) -> Self {
+        self.ie = Some(ie);
+        self
+    }
+
+    /// Add a static WEP key, the key marked as `default` is used for
+    /// transmission
+    pub fn key(mut self, key: Nl80211Key) -> Self {
+        self.keys.push(key);
+        self
+    }
+
+    pub async fn execute(self) -> Result<(), Nl80211Error> {
+        let Nl80211IbssJoinRequest {
+            mut handle,
+            if_index,
+            ssid,
+            chandef,
+            freq_fixed,
+            bssid,
+            basic_rates,
+            beacon_interval,
+            ie,
+            keys,
+        } = self;
+
+        chandef.validate()?;
+
+        let mut nlas =
+            vec![Nl80211Attr::IfIndex(if_index), Nl80211Attr::Ssid(ssid)];
+        nlas.extend(Vec::<Nl80211Attr>::from(&chandef));
+        if freq_fixed {
+            nlas.push(Nl80211Attr::FreqFixed);
+        }
+        if let Some(bssid) = bssid {
+            nlas.push(Nl80211Attr::Mac(bssid));
+        }
+        if let Some(rates) = basic_rates {
+            nlas.push(Nl80211Attr::BssBasicRates(rates));
+        }
+        if let Some(interval) = beacon_interval {
+            nlas.push(Nl80211Attr::BeaconInterval(interval));
+        }
+        if let Some(ie) = ie {
+            nlas.push(Nl80211Attr::Ie(ie));
+        }
+        // The kernel ignores the keys unless privacy is requested
+        if !keys.is_empty() {
+            nlas.push(Nl80211Attr::Privacy);
+            nlas.push(Nl80211Attr::Keys(keys));
+        }
+
+        let nl80211_msg = Nl80211Message {
+            cmd: Nl80211Cmd::JoinIbss,
+            nlas,
+        };
+        nl80211_execute_ack(&mut handle, nl80211_msg).await
+    }
+}
+
+pub struct Nl80211IbssLeaveRequest {
+    handle: Nl80211Handle,
+    if_index: u32,
+}
+
+impl Nl80211IbssLeaveRequest {
+    pub(crate) fn new(handle: Nl80211Handle, if_index: u32) -> Self {
+        Nl80211IbssLeaveRequest { handle, if_index }
+    }
+
+    pub async fn execute(self) -> Result<(), Nl80211Error> {
+        let Nl80211IbssLeaveRequest {
+            mut handle,
+            if_index,
+        } = self;
+
+        let nl80211_msg = Nl80211Message {
+            cmd: Nl80211Cmd::LeaveIbss,
+            nlas: vec![Nl80211Attr::IfIndex(if_index)],
+        };
+        nl80211_execute_ack(&mut handle, nl80211_msg).await
+    }
+}
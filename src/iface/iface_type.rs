@@ -1,5 +1,11 @@
 // SPDX-License-Identifier: MIT
 
+use anyhow::Context;
+use netlink_packet_utils::{
+    nla::{DefaultNla, NlasIterator},
+    DecodeError,
+};
+
 const NL80211_IFTYPE_ADHOC: u32 = 1;
 const NL80211_IFTYPE_STATION: u32 = 2;
 const NL80211_IFTYPE_AP: u32 = 3;
@@ -83,3 +89,26 @@ impl From<Nl80211InterfaceType> for u32 {
         }
     }
 }
+
+// Interface types are encoded as nested flag attributes whose kind is the
+// interface type
+pub(crate) fn iftypes_to_nlas(
+    iftypes: &[Nl80211InterfaceType],
+) -> Vec<DefaultNla> {
+    iftypes
+        .iter()
+        .map(|iftype| DefaultNla::new(u32::from(*iftype) as u16, Vec::new()))
+        .collect()
+}
+
+pub(crate) fn parse_iftypes(
+    payload: &[u8],
+) -> Result<Vec<Nl80211InterfaceType>, DecodeError> {
+    let err_msg = format!("Invalid interface types {:?}", payload);
+    let mut iftypes = Vec::new();
+    for nla in NlasIterator::new(payload) {
+        let nla = nla.context(err_msg.clone())?;
+        iftypes.push(Nl80211InterfaceType::from(nla.kind() as u32));
+    }
+    Ok(iftypes)
+}
@@ -1,9 +1,58 @@
 // SPDX-License-Identifier: MIT
 
+mod add;
+mod associate;
+mod authenticate;
+mod connect;
+mod crit_proto_id;
+mod crit_protocol;
 mod get;
 mod handle;
+mod ibss;
 mod iface_type;
+mod monitor_flags;
+mod multicast_to_unicast;
+mod noack_map;
+mod ocb;
+mod owe;
+mod pmk;
+mod pmksa;
+mod power_save;
+mod power_save_state;
+mod qos_map;
+mod set;
+mod set_channel;
+mod tx_ts;
 
+pub use add::Nl80211InterfaceAddRequest;
+pub use associate::Nl80211AssociateRequest;
+pub use authenticate::Nl80211AuthenticateRequest;
+pub use connect::Nl80211ConnectRequest;
+pub use crit_proto_id::Nl80211CritProtoId;
+pub use crit_protocol::{
+    Nl80211CritProtocolStartRequest, Nl80211CritProtocolStopRequest,
+};
 pub use get::Nl80211InterfaceGetRequest;
 pub use handle::Nl80211InterfaceHandle;
+pub use ibss::{Nl80211IbssJoinRequest, Nl80211IbssLeaveRequest};
 pub use iface_type::Nl80211InterfaceType;
+pub use monitor_flags::Nl80211MonitorFlags;
+pub use multicast_to_unicast::Nl80211MulticastToUnicastSetRequest;
+pub use noack_map::Nl80211NoackMapSetRequest;
+pub use ocb::{Nl80211OcbJoinRequest, Nl80211OcbLeaveRequest};
+pub use owe::Nl80211UpdateOweInfoRequest;
+pub use pmk::{Nl80211PmkDelRequest, Nl80211PmkSetRequest};
+pub use pmksa::{
+    Nl80211PmksaDelRequest, Nl80211PmksaFlushRequest, Nl80211PmksaSetRequest,
+};
+pub use power_save::{Nl80211PowerSaveGetRequest, Nl80211PowerSaveSetRequest};
+pub use power_save_state::Nl80211PowerSaveState;
+pub use qos_map::{
+    Nl80211DscpException, Nl80211DscpRange, Nl80211QosMap,
+    Nl80211QosMapSetRequest,
+};
+pub use set::Nl80211InterfaceSetRequest;
+pub use set_channel::Nl80211ChannelSetRequest;
+pub use tx_ts::{Nl80211TxTsAddRequest, Nl80211TxTsDelRequest};
+
+pub(crate) use iface_type::{iftypes_to_nlas, parse_iftypes};
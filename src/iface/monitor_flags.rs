@@ -0,0 +1,65 @@
+// SPDX-License-Identifier: MIT
+
+use netlink_packet_utils::{
+    nla::{DefaultNla, NlasIterator},
+    DecodeError, Emitable,
+};
+
+use crate::macros::nl80211_bitflags;
+
+const NL80211_MNTR_FLAG_FCSFAIL: u16 = 1;
+const NL80211_MNTR_FLAG_PLCPFAIL: u16 = 2;
+const NL80211_MNTR_FLAG_CONTROL: u16 = 3;
+const NL80211_MNTR_FLAG_OTHER_BSS: u16 = 4;
+const NL80211_MNTR_FLAG_COOK_FRAMES: u16 = 5;
+const NL80211_MNTR_FLAG_ACTIVE: u16 = 6;
+
+nl80211_bitflags! {
+    /// Monitor interface configuration flags, could be combined via `|`
+    /// operator.
+    pub struct Nl80211MonitorFlags: u32 {
+        /// Pass frames with bad FCS
+        const FCSFAIL = 1 << NL80211_MNTR_FLAG_FCSFAIL;
+        /// Pass frames with bad PLCP
+        const PLCPFAIL = 1 << NL80211_MNTR_FLAG_PLCPFAIL;
+        /// Pass control frames
+        const CONTROL = 1 << NL80211_MNTR_FLAG_CONTROL;
+        /// Disable BSSID filtering
+        const OTHER_BSS = 1 << NL80211_MNTR_FLAG_OTHER_BSS;
+        /// Report frames after processing, overrides all other flags
+        const COOK_FRAMES = 1 << NL80211_MNTR_FLAG_COOK_FRAMES;
+        /// Use the configured MAC address and ACK incoming unicast packets
+        const ACTIVE = 1 << NL80211_MNTR_FLAG_ACTIVE;
+    }
+}
+
+impl Nl80211MonitorFlags {
+    // Each flag is a nested flag attribute with the flag as kind
+    fn to_nlas(self) -> Vec<DefaultNla> {
+        (0..u32::BITS as u16)
+            .filter(|i| self.0 & (1 << i) > 0)
+            .map(|i| DefaultNla::new(i, Vec::new()))
+            .collect()
+    }
+
+    pub(crate) fn parse(payload: &[u8]) -> Result<Self, DecodeError> {
+        let mut ret = Self::empty();
+        for nla in NlasIterator::new(payload) {
+            let nla = nla?;
+            if let Some(bit) = 1u32.checked_shl(nla.kind().into()) {
+                ret.0 |= bit;
+            }
+        }
+        Ok(ret)
+    }
+}
+
+impl Emitable for Nl80211MonitorFlags {
+    fn buffer_len(&self) -> usize {
+        self.to_nlas().as_slice().buffer_len()
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        self.to_nlas().as_slice().emit(buffer)
+    }
+}
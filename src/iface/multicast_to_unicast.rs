@@ -0,0 +1,45 @@
+// SPDX-License-Identifier: MIT
+
+use crate::{
+    nl80211_execute_ack, Nl80211Attr, Nl80211Cmd, Nl80211Error, Nl80211Handle,
+    Nl80211Message,
+};
+
+pub struct Nl80211MulticastToUnicastSetRequest {
+    handle: Nl80211Handle,
+    if_index: u32,
+    enable: bool,
+}
+
+impl Nl80211MulticastToUnicastSetRequest {
+    pub(crate) fn new(
+        handle: Nl80211Handle,
+        if_index: u32,
+        enable: bool,
+    ) -> Self {
+        Nl80211MulticastToUnicastSetRequest {
+            handle,
+            if_index,
+            enable,
+        }
+    }
+
+    pub async fn execute(self) -> Result<(), Nl80211Error> {
+        let Nl80211MulticastToUnicastSetRequest {
+            mut handle,
+            if_index,
+            enable,
+        } = self;
+
+        let mut nlas = vec![Nl80211Attr::IfIndex(if_index)];
+        if enable {
+            nlas.push(Nl80211Attr::MulticastToUnicastEnabled);
+        }
+
+        let nl80211_msg = Nl80211Message {
+            cmd: Nl80211Cmd::MulticastToUnicastSet,
+            nlas,
+        };
+        nl80211_execute_ack(&mut handle, nl80211_msg).await
+    }
+}
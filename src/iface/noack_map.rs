@@ -0,0 +1,39 @@
+// SPDX-License-Identifier: MIT
+
+use crate::{
+    nl80211_execute_ack, Nl80211Attr, Nl80211Cmd, Nl80211Error, Nl80211Handle,
+    Nl80211Message,
+};
+
+pub struct Nl80211NoackMapSetRequest {
+    handle: Nl80211Handle,
+    if_index: u32,
+    map: u16,
+}
+
+impl Nl80211NoackMapSetRequest {
+    pub(crate) fn new(handle: Nl80211Handle, if_index: u32, map: u16) -> Self {
+        Nl80211NoackMapSetRequest {
+            handle,
+            if_index,
+            map,
+        }
+    }
+
+    pub async fn execute(self) -> Result<(), Nl80211Error> {
+        let Nl80211NoackMapSetRequest {
+            mut handle,
+            if_index,
+            map,
+        } = self;
+
+        let nl80211_msg = Nl80211Message {
+            cmd: Nl80211Cmd::NoackMapSet,
+            nlas: vec![
+                Nl80211Attr::IfIndex(if_index),
+                Nl80211Attr::NoackMap(map),
+            ],
+        };
+        nl80211_execute_ack(&mut handle, nl80211_msg).await
+    }
+}
@@ -0,0 +1,69 @@
+// SPDX-License-Identifier: MIT
+
+use crate::{
+    nl80211_execute_ack, Nl80211Attr, Nl80211ChannelDef, Nl80211Cmd,
+    Nl80211Error, Nl80211Handle, Nl80211Message,
+};
+
+pub struct Nl80211OcbJoinRequest {
+    handle: Nl80211Handle,
+    if_index: u32,
+    chandef: Nl80211ChannelDef,
+}
+
+impl Nl80211OcbJoinRequest {
+    pub(crate) fn new(
+        handle: Nl80211Handle,
+        if_index: u32,
+        chandef: Nl80211ChannelDef,
+    ) -> Self {
+        Nl80211OcbJoinRequest {
+            handle,
+            if_index,
+            chandef,
+        }
+    }
+
+    pub async fn execute(self) -> Result<(), Nl80211Error> {
+        let Nl80211OcbJoinRequest {
+            mut handle,
+            if_index,
+            chandef,
+        } = self;
+
+        chandef.validate()?;
+
+        let mut nlas = vec![Nl80211Attr::IfIndex(if_index)];
+        nlas.extend(Vec::<Nl80211Attr>::from(&chandef));
+
+        let nl80211_msg = Nl80211Message {
+            cmd: Nl80211Cmd::JoinOcb,
+            nlas,
+        };
+        nl80211_execute_ack(&mut handle, nl80211_msg).await
+    }
+}
+
+pub struct Nl80211OcbLeaveRequest {
+    handle: Nl80211Handle,
+    if_index: u32,
+}
+
+impl Nl80211OcbLeaveRequest {
+    pub(crate) fn new(handle: Nl80211Handle, if_index: u32) -> Self {
+        Nl80211OcbLeaveRequest { handle, if_index }
+    }
+
+    pub async fn execute(self) -> Result<(), Nl80211Error> {
+        let Nl80211OcbLeaveRequest {
+            mut handle,
+            if_index,
+        } = self;
+
+        let nl80211_msg = Nl80211Message {
+            cmd: Nl80211Cmd::LeaveOcb,
+            nlas: vec![Nl80211Attr::IfIndex(if_index)],
+        };
+        nl80211_execute_ack(&mut handle, nl80211_msg).await
+    }
+}
@@ -0,0 +1,66 @@
+// SPDX-License-Identifier: MIT
+
+use crate::{
+    nl80211_execute_ack, Nl80211Attr, Nl80211Cmd, Nl80211Error, Nl80211Handle,
+    Nl80211Message,
+};
+
+/// Reply to the `NL80211_CMD_UPDATE_OWE_INFO` event of a driver offloading
+/// OWE processing to userspace. The event holds the MAC address of the peer
+/// in `Nl80211Attr::Mac` and its Diffie-Hellman parameter element in
+/// `Nl80211Attr::Ie`.
+pub struct Nl80211UpdateOweInfoRequest {
+    handle: Nl80211Handle,
+    if_index: u32,
+    mac: [u8; 6],
+    status_code: u16,
+    ie: Option<Vec<u8>>,
+}
+
+impl Nl80211UpdateOweInfoRequest {
+    pub(crate) fn new(
+        handle: Nl80211Handle,
+        if_index: u32,
+        mac: [u8; 6],
+        status_code: u16,
+    ) -> Self {
+        Nl80211UpdateOweInfoRequest {
+            handle,
+            if_index,
+            mac,
+            status_code,
+            ie: None,
+        }
+    }
+
+    /// Diffie-Hellman parameter element of the local side
+    pub fn ie(mut self, ie: Vec<u8>) -> Self {
+        self.ie = Some(ie);
+        self
+    }
+
+    pub async fn execute(self) -> Result<(), Nl80211Error> {
+        let Nl80211UpdateOweInfoRequest {
+            mut handle,
+            if_index,
+            mac,
+            status_code,
+            ie,
+        } = self;
+
+        let mut nlas = vec![
+            Nl80211Attr::IfIndex(if_index),
+            Nl80211Attr::Mac(mac),
+            Nl80211Attr::StatusCode(status_code),
+        ];
+        if let Some(ie) = ie {
+            nlas.push(Nl80211Attr::Ie(ie));
+        }
+
+        let nl80211_msg = Nl80211Message {
+            cmd: Nl80211Cmd::UpdateOweInfo,
+            nlas,
+        };
+        nl80211_execute_ack(&mut handle, nl80211_msg).await
+    }
+}
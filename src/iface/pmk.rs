@@ -0,0 +1,97 @@
+// SPDX-License-Identifier: MIT
+
+use crate::{
+    nl80211_execute_ack, Nl80211Attr, Nl80211Cmd, Nl80211Error, Nl80211Handle,
+    Nl80211Message,
+};
+
+pub struct Nl80211PmkSetRequest {
+    handle: Nl80211Handle,
+    if_index: u32,
+    mac: [u8; 6],
+    pmk: Vec<u8>,
+    pmkr0_name: Option<Vec<u8>>,
+}
+
+impl Nl80211PmkSetRequest {
+    pub(crate) fn new(
+        handle: Nl80211Handle,
+        if_index: u32,
+        mac: [u8; 6],
+        pmk: Vec<u8>,
+    ) -> Self {
+        Nl80211PmkSetRequest {
+            handle,
+            if_index,
+            mac,
+            pmk,
+            pmkr0_name: None,
+        }
+    }
+
+    /// PMK-R0 Name for offloaded fast BSS transition, the PMK is then
+    /// treated as PMK-R0
+    pub fn pmkr0_name(mut self, name: Vec<u8>) -> Self {
+        self.pmkr0_name = Some(name);
+        self
+    }
+
+    pub async fn execute(self) -> Result<(), Nl80211Error> {
+        let Nl80211PmkSetRequest {
+            mut handle,
+            if_index,
+            mac,
+            pmk,
+            pmkr0_name,
+        } = self;
+
+        let mut nlas = vec![
+            Nl80211Attr::IfIndex(if_index),
+            Nl80211Attr::Mac(mac),
+            Nl80211Attr::Pmk(pmk),
+        ];
+        if let Some(name) = pmkr0_name {
+            nlas.push(Nl80211Attr::PmkR0Name(name));
+        }
+
+        let nl80211_msg = Nl80211Message {
+            cmd: Nl80211Cmd::PmkSet,
+            nlas,
+        };
+        nl80211_execute_ack(&mut handle, nl80211_msg).await
+    }
+}
+
+pub struct Nl80211PmkDelRequest {
+    handle: Nl80211Handle,
+    if_index: u32,
+    mac: [u8; 6],
+}
+
+impl Nl80211PmkDelRequest {
+    pub(crate) fn new(
+        handle: Nl80211Handle,
+        if_index: u32,
+        mac: [u8; 6],
+    ) -> Self {
+        Nl80211PmkDelRequest {
+            handle,
+            if_index,
+            mac,
+        }
+    }
+
+    pub async fn execute(self) -> Result<(), Nl80211Error> {
+        let Nl80211PmkDelRequest {
+            mut handle,
+            if_index,
+            mac,
+        } = self;
+
+        let nl80211_msg = Nl80211Message {
+            cmd: Nl80211Cmd::PmkDel,
+            nlas: vec![Nl80211Attr::IfIndex(if_index), Nl80211Attr::Mac(mac)],
+        };
+        nl80211_execute_ack(&mut handle, nl80211_msg).await
+    }
+}
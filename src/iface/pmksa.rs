@@ -0,0 +1,160 @@
+// SPDX-License-Identifier: MIT
+
+use crate::{
+    nl80211_execute_ack, Nl80211Attr, Nl80211Cmd, Nl80211Error, Nl80211Handle,
+    Nl80211Message,
+};
+
+pub struct Nl80211PmksaSetRequest {
+    handle: Nl80211Handle,
+    if_index: u32,
+    bssid: [u8; 6],
+    pmkid: Vec<u8>,
+    pmk: Option<Vec<u8>>,
+    lifetime: Option<u32>,
+    reauth_threshold: Option<u8>,
+}
+
+impl Nl80211PmksaSetRequest {
+    pub(crate) fn new(
+        handle: Nl80211Handle,
+        if_index: u32,
+        bssid: [u8; 6],
+        pmkid: Vec<u8>,
+    ) -> Self {
+        Nl80211PmksaSetRequest {
+            handle,
+            if_index,
+            bssid,
+            pmkid,
+            pmk: None,
+            lifetime: None,
+            reauth_threshold: None,
+        }
+    }
+
+    /// PMK of the PMKSA, for drivers doing PMKSA caching on their own
+    pub fn pmk(mut self, pmk: Vec<u8>) -> Self {
+        self.pmk = Some(pmk);
+        self
+    }
+
+    /// Maximum lifetime of the PMKSA in seconds
+    pub fn lifetime(mut self, seconds: u32) -> Self {
+        self.lifetime = Some(seconds);
+        self
+    }
+
+    /// Percentage (1 to 100) of the lifetime after which the driver should
+    /// roam with a full authentication instead of PMKSA caching
+    pub fn reauth_threshold(mut self, percentage: u8) -> Self {
+        self.reauth_threshold = Some(percentage);
+        self
+    }
+
+    pub async fn execute(self) -> Result<(), Nl80211Error> {
+        let Nl80211PmksaSetRequest {
+            mut handle,
+            if_index,
+            bssid,
+            pmkid,
+            pmk,
+            lifetime,
+            reauth_threshold,
+        } = self;
+
+        let mut nlas = vec![
+            Nl80211Attr::IfIndex(if_index),
+            Nl80211Attr::Mac(bssid),
+            Nl80211Attr::Pmkid(pmkid),
+        ];
+        if let Some(pmk) = pmk {
+            nlas.push(Nl80211Attr::Pmk(pmk));
+        }
+        if let Some(lifetime) = lifetime {
+            nlas.push(Nl80211Attr::PmkLifetime(lifetime));
+        }
+        if let Some(threshold) = reauth_threshold {
+            nlas.push(Nl80211Attr::PmkReauthThreshold(threshold));
+        }
+
+        let nl80211_msg = Nl80211Message {
+            cmd: Nl80211Cmd::PmksaSet,
+            nlas,
+        };
+        nl80211_execute_ack(&mut handle, nl80211_msg).await
+    }
+}
+
+pub struct Nl80211PmksaDelRequest {
+    handle: Nl80211Handle,
+    if_index: u32,
+    bssid: [u8; 6],
+    pmkid: Option<Vec<u8>>,
+}
+
+impl Nl80211PmksaDelRequest {
+    pub(crate) fn new(
+        handle: Nl80211Handle,
+        if_index: u32,
+        bssid: [u8; 6],
+    ) -> Self {
+        Nl80211PmksaDelRequest {
+            handle,
+            if_index,
+            bssid,
+            pmkid: None,
+        }
+    }
+
+    /// Only delete the PMKSA with specified PMK identifier
+    pub fn pmkid(mut self, pmkid: Vec<u8>) -> Self {
+        self.pmkid = Some(pmkid);
+        self
+    }
+
+    pub async fn execute(self) -> Result<(), Nl80211Error> {
+        let Nl80211PmksaDelRequest {
+            mut handle,
+            if_index,
+            bssid,
+            pmkid,
+        } = self;
+
+        let mut nlas =
+            vec![Nl80211Attr::IfIndex(if_index), Nl80211Attr::Mac(bssid)];
+        if let Some(pmkid) = pmkid {
+            nlas.push(Nl80211Attr::Pmkid(pmkid));
+        }
+
+        let nl80211_msg = Nl80211Message {
+            cmd: Nl80211Cmd::PmksaDel,
+            nlas,
+        };
+        nl80211_execute_ack(&mut handle, nl80211_msg).await
+    }
+}
+
+pub struct Nl80211PmksaFlushRequest {
+    handle: Nl80211Handle,
+    if_index: u32,
+}
+
+impl Nl80211PmksaFlushRequest {
+    pub(crate) fn new(handle: Nl80211Handle, if_index: u32) -> Self {
+        Nl80211PmksaFlushRequest { handle, if_index }
+    }
+
+    pub async fn execute(self) -> Result<(), Nl80211Error> {
+        let Nl80211PmksaFlushRequest {
+            mut handle,
+            if_index,
+        } = self;
+
+        let nl80211_msg = Nl80211Message {
+            cmd: Nl80211Cmd::PmksaFlush,
+            nlas: vec![Nl80211Attr::IfIndex(if_index)],
+        };
+        nl80211_execute_ack(&mut handle, nl80211_msg).await
+    }
+}
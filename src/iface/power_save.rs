@@ -0,0 +1,75 @@
+// SPDX-License-Identifier: MIT
+
+use futures::TryStream;
+use netlink_packet_core::NLM_F_REQUEST;
+use netlink_packet_generic::GenlMessage;
+
+use crate::{
+    nl80211_execute, nl80211_execute_ack, Nl80211Attr, Nl80211Cmd,
+    Nl80211Error, Nl80211Handle, Nl80211Message, Nl80211PowerSaveState,
+};
+
+pub struct Nl80211PowerSaveGetRequest {
+    handle: Nl80211Handle,
+    if_index: u32,
+}
+
+impl Nl80211PowerSaveGetRequest {
+    pub(crate) fn new(handle: Nl80211Handle, if_index: u32) -> Self {
+        Nl80211PowerSaveGetRequest { handle, if_index }
+    }
+
+    /// The reply holds `Nl80211Attr::PsState`.
+    pub async fn execute(
+        self,
+    ) -> impl TryStream<Ok = GenlMessage<Nl80211Message>, Error = Nl80211Error>
+    {
+        let Nl80211PowerSaveGetRequest {
+            mut handle,
+            if_index,
+        } = self;
+
+        let nl80211_msg = Nl80211Message {
+            cmd: Nl80211Cmd::PowerSaveGet,
+            nlas: vec![Nl80211Attr::IfIndex(if_index)],
+        };
+        nl80211_execute(&mut handle, nl80211_msg, NLM_F_REQUEST).await
+    }
+}
+
+pub struct Nl80211PowerSaveSetRequest {
+    handle: Nl80211Handle,
+    if_index: u32,
+    state: Nl80211PowerSaveState,
+}
+
+impl Nl80211PowerSaveSetRequest {
+    pub(crate) fn new(
+        handle: Nl80211Handle,
+        if_index: u32,
+        state: Nl80211PowerSaveState,
+    ) -> Self {
+        Nl80211PowerSaveSetRequest {
+            handle,
+            if_index,
+            state,
+        }
+    }
+
+    pub async fn execute(self) -> Result<(), Nl80211Error> {
+        let Nl80211PowerSaveSetRequest {
+            mut handle,
+            if_index,
+            state,
+        } = self;
+
+        let nl80211_msg = Nl80211Message {
+            cmd: Nl80211Cmd::PowerSaveSet,
+            nlas: vec![
+                Nl80211Attr::IfIndex(if_index),
+                Nl80211Attr::PsState(state),
+            ],
+        };
+        nl80211_execute_ack(&mut handle, nl80211_msg).await
+    }
+}
@@ -0,0 +1,34 @@
+// SPDX-License-Identifier: MIT
+
+const NL80211_PS_DISABLED: u32 = 0;
+const NL80211_PS_ENABLED: u32 = 1;
+
+/// Power save state of an interface
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Nl80211PowerSaveState {
+    /// Power save is disabled
+    Disabled,
+    /// Power save is enabled
+    Enabled,
+    Other(u32),
+}
+
+impl From<u32> for Nl80211PowerSaveState {
+    fn from(d: u32) -> Self {
+        match d {
+            NL80211_PS_DISABLED => Self::Disabled,
+            NL80211_PS_ENABLED => Self::Enabled,
+            _ => Self::Other(d),
+        }
+    }
+}
+
+impl From<Nl80211PowerSaveState> for u32 {
+    fn from(v: Nl80211PowerSaveState) -> u32 {
+        match v {
+            Nl80211PowerSaveState::Disabled => NL80211_PS_DISABLED,
+            Nl80211PowerSaveState::Enabled => NL80211_PS_ENABLED,
+            Nl80211PowerSaveState::Other(d) => d,
+        }
+    }
+}
@@ -0,0 +1,155 @@
+// SPDX-License-Identifier: MIT
+
+use netlink_packet_utils::{DecodeError, Emitable};
+
+use crate::{
+    nl80211_execute_ack, Nl80211Attr, Nl80211Cmd, Nl80211Error, Nl80211Handle,
+    Nl80211Message,
+};
+
+const IEEE80211_QOS_MAP_MAX_EX: usize = 21;
+const IEEE80211_QOS_MAP_UP_COUNT: usize = 8;
+const IEEE80211_QOS_MAP_LEN_MIN: usize = IEEE80211_QOS_MAP_UP_COUNT * 2;
+const IEEE80211_QOS_MAP_LEN_MAX: usize =
+    IEEE80211_QOS_MAP_LEN_MIN + IEEE80211_QOS_MAP_MAX_EX * 2;
+
+/// DSCP value mapped to a user priority regardless of the DSCP ranges
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct Nl80211DscpException {
+    pub dscp: u8,
+    /// User priority (0 to 7)
+    pub up: u8,
+}
+
+/// Range of DSCP values mapped to a user priority, both 255 when the user
+/// priority is not used
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct Nl80211DscpRange {
+    pub low: u8,
+    pub high: u8,
+}
+
+impl Default for Nl80211DscpRange {
+    fn default() -> Self {
+        Self {
+            low: u8::MAX,
+            high: u8::MAX,
+        }
+    }
+}
+
+/// Mapping of IP DSCP values to 802.11 user priorities, content of the QoS
+/// Map element (ID 110) sent by the AP
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct Nl80211QosMap {
+    /// Up to 21 exceptions
+    pub exceptions: Vec<Nl80211DscpException>,
+    /// DSCP range of each user priority, indexed by user priority
+    pub ranges: [Nl80211DscpRange; IEEE80211_QOS_MAP_UP_COUNT],
+}
+
+impl Nl80211QosMap {
+    pub fn new(ranges: [Nl80211DscpRange; IEEE80211_QOS_MAP_UP_COUNT]) -> Self {
+        Self {
+            exceptions: Vec::new(),
+            ranges,
+        }
+    }
+
+    pub fn exception(mut self, dscp: u8, up: u8) -> Self {
+        self.exceptions.push(Nl80211DscpException { dscp, up });
+        self
+    }
+
+    /// Parse the content of a QoS Map element
+    pub fn parse(payload: &[u8]) -> Result<Self, DecodeError> {
+        if payload.len() < IEEE80211_QOS_MAP_LEN_MIN
+            || payload.len() > IEEE80211_QOS_MAP_LEN_MAX
+            || !payload.len().is_multiple_of(2)
+        {
+            return Err(
+                format!("Invalid NL80211_ATTR_QOS_MAP {:?}", payload).into()
+            );
+        }
+        let (exceptions, ranges) =
+            payload.split_at(payload.len() - IEEE80211_QOS_MAP_LEN_MIN);
+        let mut ret = Self {
+            exceptions: exceptions
+                .chunks_exact(2)
+                .map(|d| Nl80211DscpException {
+                    dscp: d[0],
+                    up: d[1],
+                })
+                .collect(),
+            ..Default::default()
+        };
+        for (range, d) in ret.ranges.iter_mut().zip(ranges.chunks_exact(2)) {
+            *range = Nl80211DscpRange {
+                low: d[0],
+                high: d[1],
+            };
+        }
+        Ok(ret)
+    }
+}
+
+impl Emitable for Nl80211QosMap {
+    fn buffer_len(&self) -> usize {
+        self.exceptions.len() * 2 + IEEE80211_QOS_MAP_LEN_MIN
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        let mut offset = 0;
+        for exception in self.exceptions.iter() {
+            buffer[offset] = exception.dscp;
+            buffer[offset + 1] = exception.up;
+            offset += 2;
+        }
+        for range in self.ranges.iter() {
+            buffer[offset] = range.low;
+            buffer[offset + 1] = range.high;
+            offset += 2;
+        }
+    }
+}
+
+pub struct Nl80211QosMapSetRequest {
+    handle: Nl80211Handle,
+    if_index: u32,
+    qos_map: Option<Nl80211QosMap>,
+}
+
+impl Nl80211QosMapSetRequest {
+    pub(crate) fn new(handle: Nl80211Handle, if_index: u32) -> Self {
+        Nl80211QosMapSetRequest {
+            handle,
+            if_index,
+            qos_map: None,
+        }
+    }
+
+    /// QoS mapping to use, QoS mapping is disabled when not set
+    pub fn qos_map(mut self, qos_map: Nl80211QosMap) -> Self {
+        self.qos_map = Some(qos_map);
+        self
+    }
+
+    pub async fn execute(self) -> Result<(), Nl80211Error> {
+        let Nl80211QosMapSetRequest {
+            mut handle,
+            if_index,
+            qos_map,
+        } = self;
+
+        let mut nlas = vec![Nl80211Attr::IfIndex(if_index)];
+        if let Some(qos_map) = qos_map {
+            nlas.push(Nl80211Attr::QosMap(qos_map));
+        }
+
+        let nl80211_msg = Nl80211Message {
+            cmd: Nl80211Cmd::QosMapSet,
+            nlas,
+        };
+        nl80211_execute_ack(&mut handle, nl80211_msg).await
+    }
+}
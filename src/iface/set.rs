@@ -0,0 +1,71 @@
+// SPDX-License-Identifier: MIT
+
+use crate::{
+    nl80211_execute_ack, Nl80211Attr, Nl80211Cmd, Nl80211Error, Nl80211Handle,
+    Nl80211InterfaceType, Nl80211Message, Nl80211MonitorFlags,
+};
+
+pub struct Nl80211InterfaceSetRequest {
+    handle: Nl80211Handle,
+    if_index: u32,
+    iftype: Option<Nl80211InterfaceType>,
+    use_4addr: Option<bool>,
+    monitor_flags: Option<Nl80211MonitorFlags>,
+}
+
+impl Nl80211InterfaceSetRequest {
+    pub(crate) fn new(handle: Nl80211Handle, if_index: u32) -> Self {
+        Nl80211InterfaceSetRequest {
+            handle,
+            if_index,
+            iftype: None,
+            use_4addr: None,
+            monitor_flags: None,
+        }
+    }
+
+    /// Change the interface type, the interface normally has to be down
+    pub fn iftype(mut self, iftype: Nl80211InterfaceType) -> Self {
+        self.iftype = Some(iftype);
+        self
+    }
+
+    /// Use 4-address frame format
+    pub fn use_4addr(mut self, use_4addr: bool) -> Self {
+        self.use_4addr = Some(use_4addr);
+        self
+    }
+
+    /// Flags of monitor interface
+    pub fn monitor_flags(mut self, flags: Nl80211MonitorFlags) -> Self {
+        self.monitor_flags = Some(flags);
+        self
+    }
+
+    pub async fn execute(self) -> Result<(), Nl80211Error> {
+        let Nl80211InterfaceSetRequest {
+            mut handle,
+            if_index,
+            iftype,
+            use_4addr,
+            monitor_flags,
+        } = self;
+
+        let mut nlas = vec![Nl80211Attr::IfIndex(if_index)];
+        if let Some(iftype) = iftype {
+            nlas.push(Nl80211Attr::IfType(iftype));
+        }
+        if let Some(use_4addr) = use_4addr {
+            nlas.push(Nl80211Attr::Use4Addr(use_4addr));
+        }
+        if let Some(flags) = monitor_flags {
+            nlas.push(Nl80211Attr::MonitorFlags(flags));
+        }
+
+        let nl80211_msg = Nl80211Message {
+            cmd: Nl80211Cmd::InterfaceSet,
+            nlas,
+        };
+        nl80211_execute_ack(&mut handle, nl80211_msg).await
+    }
+}
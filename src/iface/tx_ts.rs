@@ -0,0 +1,110 @@
+// SPDX-License-Identifier: MIT
+
+use crate::{
+    nl80211_execute_ack, Nl80211Attr, Nl80211Cmd, Nl80211Error, Nl80211Handle,
+    Nl80211Message,
+};
+
+pub struct Nl80211TxTsAddRequest {
+    handle: Nl80211Handle,
+    if_index: u32,
+    tsid: u8,
+    peer: [u8; 6],
+    user_prio: u8,
+    admitted_time: Option<u16>,
+}
+
+impl Nl80211TxTsAddRequest {
+    pub(crate) fn new(
+        handle: Nl80211Handle,
+        if_index: u32,
+        tsid: u8,
+        peer: [u8; 6],
+        user_prio: u8,
+    ) -> Self {
+        Nl80211TxTsAddRequest {
+            handle,
+            if_index,
+            tsid,
+            peer,
+            user_prio,
+            admitted_time: None,
+        }
+    }
+
+    /// Medium time admitted by the AP in units of 32 microseconds per
+    /// second, the traffic stream is not limited if not set
+    pub fn admitted_time(mut self, time: u16) -> Self {
+        self.admitted_time = Some(time);
+        self
+    }
+
+    pub async fn execute(self) -> Result<(), Nl80211Error> {
+        let Nl80211TxTsAddRequest {
+            mut handle,
+            if_index,
+            tsid,
+            peer,
+            user_prio,
+            admitted_time,
+        } = self;
+
+        let mut nlas = vec![
+            Nl80211Attr::IfIndex(if_index),
+            Nl80211Attr::Tsid(tsid),
+            Nl80211Attr::Mac(peer),
+            Nl80211Attr::UserPrio(user_prio),
+        ];
+        if let Some(time) = admitted_time {
+            nlas.push(Nl80211Attr::AdmittedTime(time));
+        }
+
+        let nl80211_msg = Nl80211Message {
+            cmd: Nl80211Cmd::TxTsAdd,
+            nlas,
+        };
+        nl80211_execute_ack(&mut handle, nl80211_msg).await
+    }
+}
+
+pub struct Nl80211TxTsDelRequest {
+    handle: Nl80211Handle,
+    if_index: u32,
+    tsid: u8,
+    peer: [u8; 6],
+}
+
+impl Nl80211TxTsDelRequest {
+    pub(crate) fn new(
+        handle: Nl80211Handle,
+        if_index: u32,
+        tsid: u8,
+        peer: [u8; 6],
+    ) -> Self {
+        Nl80211TxTsDelRequest {
+            handle,
+            if_index,
+            tsid,
+            peer,
+        }
+    }
+
+    pub async fn execute(self) -> Result<(), Nl80211Error> {
+        let Nl80211TxTsDelRequest {
+            mut handle,
+            if_index,
+            tsid,
+            peer,
+        } = self;
+
+        let nl80211_msg = Nl80211Message {
+            cmd: Nl80211Cmd::TxTsDel,
+            nlas: vec![
+                Nl80211Attr::IfIndex(if_index),
+                Nl80211Attr::Tsid(tsid),
+                Nl80211Attr::Mac(peer),
+            ],
+        };
+        nl80211_execute_ack(&mut handle, nl80211_msg).await
+    }
+}
@@ -0,0 +1,101 @@
+// SPDX-License-Identifier: MIT
+
+use crate::{
+    nl80211_execute_ack, Nl80211Attr, Nl80211CipherSuite, Nl80211Cmd,
+    Nl80211Error, Nl80211Handle, Nl80211Key, Nl80211KeyMode, Nl80211KeyType,
+    Nl80211Message,
+};
+
+pub struct Nl80211KeyAddRequest {
+    handle: Nl80211Handle,
+    if_index: u32,
+    key_idx: u8,
+    cipher: Nl80211CipherSuite,
+    data: Vec<u8>,
+    mac: Option<[u8; 6]>,
+    seq: Option<Vec<u8>>,
+    key_type: Option<Nl80211KeyType>,
+    mode: Option<Nl80211KeyMode>,
+}
+
+impl Nl80211KeyAddRequest {
+    pub(crate) fn new(
+        handle: Nl80211Handle,
+        if_index: u32,
+        key_idx: u8,
+        cipher: Nl80211CipherSuite,
+        data: Vec<u8>,
+    ) -> Self {
+        Nl80211KeyAddRequest {
+            handle,
+            if_index,
+            key_idx,
+            cipher,
+            data,
+            mac: None,
+            seq: None,
+            key_type: None,
+            mode: None,
+        }
+    }
+
+    /// Install a pairwise key for specified peer instead of a group key
+    pub fn mac(mut self, mac: [u8; 6]) -> Self {
+        self.mac = Some(mac);
+        self
+    }
+
+    /// Initial receive sequence number (IV/PN), least significant byte first
+    pub fn seq(mut self, seq: Vec<u8>) -> Self {
+        self.seq = Some(seq);
+        self
+    }
+
+    /// Key type, derived from the presence of the MAC address when not set
+    pub fn key_type(mut self, key_type: Nl80211KeyType) -> Self {
+        self.key_type = Some(key_type);
+        self
+    }
+
+    /// Pairwise key mode, use `Nl80211KeyMode::NoTx` to install a key for
+    /// RX only when the extended key ID is in use
+    pub fn mode(mut self, mode: Nl80211KeyMode) -> Self {
+        self.mode = Some(mode);
+        self
+    }
+
+    pub async fn execute(self) -> Result<(), Nl80211Error> {
+        let Nl80211KeyAddRequest {
+            mut handle,
+            if_index,
+            key_idx,
+            cipher,
+            data,
+            mac,
+            seq,
+            key_type,
+            mode,
+        } = self;
+
+        let key = Nl80211Key {
+            data: Some(data),
+            idx: Some(key_idx),
+            cipher: Some(cipher),
+            seq,
+            key_type,
+            mode,
+            ..Default::default()
+        };
+        let mut nlas =
+            vec![Nl80211Attr::IfIndex(if_index), Nl80211Attr::Key(key)];
+        if let Some(mac) = mac {
+            nlas.push(Nl80211Attr::Mac(mac));
+        }
+
+        let nl80211_msg = Nl80211Message {
+            cmd: Nl80211Cmd::KeyNew,
+            nlas,
+        };
+        nl80211_execute_ack(&mut handle, nl80211_msg).await
+    }
+}
@@ -0,0 +1,55 @@
+// SPDX-License-Identifier: MIT
+
+use crate::{
+    nl80211_execute_ack, Nl80211Attr, Nl80211Cmd, Nl80211Error, Nl80211Handle,
+    Nl80211Message,
+};
+
+pub struct Nl80211KeyDelRequest {
+    handle: Nl80211Handle,
+    if_index: u32,
+    key_idx: u8,
+    mac: Option<[u8; 6]>,
+}
+
+impl Nl80211KeyDelRequest {
+    pub(crate) fn new(
+        handle: Nl80211Handle,
+        if_index: u32,
+        key_idx: u8,
+    ) -> Self {
+        Nl80211KeyDelRequest {
+            handle,
+            if_index,
+            key_idx,
+            mac: None,
+        }
+    }
+
+    /// Delete the pairwise key of specified peer instead of the group key
+    pub fn mac(mut self, mac: [u8; 6]) -> Self {
+        self.mac = Some(mac);
+        self
+    }
+
+    pub async fn execute(self) -> Result<(), Nl80211Error> {
+        let Nl80211KeyDelRequest {
+            mut handle,
+            if_index,
+            key_idx,
+            mac,
+        } = self;
+
+        let mut nlas =
+            vec![Nl80211Attr::IfIndex(if_index), Nl80211Attr::KeyIdx(key_idx)];
+        if let Some(mac) = mac {
+            nlas.push(Nl80211Attr::Mac(mac));
+        }
+
+        let nl80211_msg = Nl80211Message {
+            cmd: Nl80211Cmd::KeyDel,
+            nlas,
+        };
+        nl80211_execute_ack(&mut handle, nl80211_msg).await
+    }
+}
@@ -0,0 +1,64 @@
+// SPDX-License-Identifier: MIT
+
+use futures::TryStream;
+use netlink_packet_core::NLM_F_REQUEST;
+use netlink_packet_generic::GenlMessage;
+
+use crate::{
+    nl80211_execute, Nl80211Attr, Nl80211Cmd, Nl80211Error, Nl80211Handle,
+    Nl80211Message,
+};
+
+pub struct Nl80211KeyGetRequest {
+    handle: Nl80211Handle,
+    if_index: u32,
+    key_idx: u8,
+    mac: Option<[u8; 6]>,
+}
+
+impl Nl80211KeyGetRequest {
+    pub(crate) fn new(
+        handle: Nl80211Handle,
+        if_index: u32,
+        key_idx: u8,
+    ) -> Self {
+        Nl80211KeyGetRequest {
+            handle,
+            if_index,
+            key_idx,
+            mac: None,
+        }
+    }
+
+    /// Retrieve the pairwise key of specified peer instead of the group key
+    pub fn mac(mut self, mac: [u8; 6]) -> Self {
+        self.mac = Some(mac);
+        self
+    }
+
+    /// The kernel does not reply the key material, only the cipher and
+    /// sequence number.
+    pub async fn execute(
+        self,
+    ) -> impl TryStream<Ok = GenlMessage<Nl80211Message>, Error = Nl80211Error>
+    {
+        let Nl80211KeyGetRequest {
+            mut handle,
+            if_index,
+            key_idx,
+            mac,
+        } = self;
+
+        let mut nlas =
+            vec![Nl80211Attr::IfIndex(if_index), Nl80211Attr::KeyIdx(key_idx)];
+        if let Some(mac) = mac {
+            nlas.push(Nl80211Attr::Mac(mac));
+        }
+
+        let nl80211_msg = Nl80211Message {
+            cmd: Nl80211Cmd::KeyGet,
+            nlas,
+        };
+        nl80211_execute(&mut handle, nl80211_msg, NLM_F_REQUEST).await
+    }
+}
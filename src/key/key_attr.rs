@@ -0,0 +1,346 @@
+// SPDX-License-Identifier: MIT
+
+use anyhow::Context;
+use byteorder::{ByteOrder, NativeEndian};
+use netlink_packet_utils::{
+    nla::{DefaultNla, Nla, NlaBuffer, NlasIterator},
+    parsers::{parse_u32, parse_u8},
+    DecodeError, Emitable, Parseable,
+};
+
+use crate::{Nl80211CipherSuite, Nl80211KeyMode, Nl80211KeyType};
+
+const NL80211_KEY_DATA: u16 = 1;
+const NL80211_KEY_IDX: u16 = 2;
+const NL80211_KEY_CIPHER: u16 = 3;
+const NL80211_KEY_SEQ: u16 = 4;
+const NL80211_KEY_DEFAULT: u16 = 5;
+const NL80211_KEY_DEFAULT_MGMT: u16 = 6;
+const NL80211_KEY_TYPE: u16 = 7;
+const NL80211_KEY_DEFAULT_TYPES: u16 = 8;
+const NL80211_KEY_MODE: u16 = 9;
+const NL80211_KEY_DEFAULT_BEACON: u16 = 10;
+const NL80211_KEY_DEFAULT_TYPE_UNICAST: u16 = 1;
+const NL80211_KEY_DEFAULT_TYPE_MULTICAST: u16 = 2;
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum Nl80211KeyNla {
+    /// Key material
+    Data(Vec<u8>),
+    /// Key index
+    Idx(u8),
+    /// Cipher suite of the key
+    Cipher(Nl80211CipherSuite),
+    /// Transmit key sequence number (IV/PN), least significant byte first
+    Seq(Vec<u8>),
+    /// Use the key as default key
+    Default,
+    /// Use the key as default management frame protection key
+    DefaultMgmt,
+    /// Key type
+    Type(Nl80211KeyType),
+    /// Traffic types the default key is used for
+    DefaultTypes(Nl80211KeyDefaultTypes),
+    /// Mode of a pairwise key
+    Mode(Nl80211KeyMode),
+    /// Use the key as default beacon protection key
+    DefaultBeacon,
+    Other(DefaultNla),
+}
+
+impl Nla for Nl80211KeyNla {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::Default | Self::DefaultMgmt | Self::DefaultBeacon => 0,
+            Self::Idx(_) | Self::Mode(_) => 1,
+            Self::Cipher(_) | Self::Type(_) => 4,
+            Self::Data(ref d) => d.len(),
+            Self::Seq(ref d) => d.len(),
+            Self::DefaultTypes(ref d) => d.buffer_len(),
+            Self::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::Data(_) => NL80211_KEY_DATA,
+            Self::Idx(_) => NL80211_KEY_IDX,
+            Self::Cipher(_) => NL80211_KEY_CIPHER,
+            Self::Seq(_) => NL80211_KEY_SEQ,
+            Self::Default => NL80211_KEY_DEFAULT,
+            Self::DefaultMgmt => NL80211_KEY_DEFAULT_MGMT,
+            Self::Type(_) => NL80211_KEY_TYPE,
+            Self::DefaultTypes(_) => NL80211_KEY_DEFAULT_TYPES,
+            Self::Mode(_) => NL80211_KEY_MODE,
+            Self::DefaultBeacon => NL80211_KEY_DEFAULT_BEACON,
+            Self::Other(attr) => attr.kind(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Default | Self::DefaultMgmt | Self::DefaultBeacon => (),
+            Self::Idx(d) => buffer[0] = *d,
+            Self::Cipher(d) => NativeEndian::write_u32(buffer, (*d).into()),
+            Self::Type(d) => NativeEndian::write_u32(buffer, (*d).into()),
+            Self::Mode(d) => buffer[0] = (*d).into(),
+            Self::Data(ref d) => buffer[..d.len()].copy_from_slice(d),
+            Self::Seq(ref d) => buffer[..d.len()].copy_from_slice(d),
+            Self::DefaultTypes(ref d) => d.emit(buffer),
+            Self::Other(ref attr) => attr.emit(buffer),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for Nl80211KeyNla
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            NL80211_KEY_DATA => Self::Data(payload.to_vec()),
+            NL80211_KEY_IDX => {
+                let err_msg =
+                    format!("Invalid NL80211_KEY_IDX value {:?}", payload);
+                Self::Idx(parse_u8(payload).context(err_msg)?)
+            }
+            NL80211_KEY_CIPHER => {
+                let err_msg =
+                    format!("Invalid NL80211_KEY_CIPHER value {:?}", payload);
+                Self::Cipher(parse_u32(payload).context(err_msg)?.into())
+            }
+            NL80211_KEY_SEQ => Self::Seq(payload.to_vec()),
+            NL80211_KEY_DEFAULT => Self::Default,
+            NL80211_KEY_DEFAULT_MGMT => Self::DefaultMgmt,
+            NL80211_KEY_TYPE => {
+                let err_msg =
+                    format!("Invalid NL80211_KEY_TYPE value {:?}", payload);
+                Self::Type(parse_u32(payload).context(err_msg)?.into())
+            }
+            NL80211_KEY_DEFAULT_TYPES => {
+                Self::DefaultTypes(Nl80211KeyDefaultTypes::parse(buf)?)
+            }
+            NL80211_KEY_MODE => {
+                let err_msg =
+                    format!("Invalid NL80211_KEY_MODE value {:?}", payload);
+                Self::Mode(parse_u8(payload).context(err_msg)?.into())
+            }
+            NL80211_KEY_DEFAULT_BEACON => Self::DefaultBeacon,
+            _ => Self::Other(
+                DefaultNla::parse(buf).context("invalid NLA (unknown kind)")?,
+            ),
+        })
+    }
+}
+
+/// Encryption key
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct Nl80211Key {
+    /// Key material
+    pub data: Option<Vec<u8>>,
+    /// Key index
+    pub idx: Option<u8>,
+    /// Cipher suite of the key
+    pub cipher: Option<Nl80211CipherSuite>,
+    /// Transmit key sequence number (IV/PN), least significant byte first
+    pub seq: Option<Vec<u8>>,
+    /// Use the key as default key
+    pub default: bool,
+    /// Use the key as default management frame protection key
+    pub default_mgmt: bool,
+    /// Key type
+    pub key_type: Option<Nl80211KeyType>,
+    /// Traffic types the default key is used for
+    pub default_types: Option<Nl80211KeyDefaultTypes>,
+    /// Mode of a pairwise key
+    pub mode: Option<Nl80211KeyMode>,
+    /// Use the key as default beacon protection key
+    pub default_beacon: bool,
+}
+
+impl Emitable for Nl80211Key {
+    fn buffer_len(&self) -> usize {
+        Vec::<Nl80211KeyNla>::from(self).as_slice().buffer_len()
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        Vec::<Nl80211KeyNla>::from(self).as_slice().emit(buffer)
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for Nl80211Key {
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let mut ret = Self::default();
+        let payload = buf.value();
+        let err_msg = format!("Invalid NL80211_ATTR_KEY value {:?}", payload);
+        for nla in NlasIterator::new(payload) {
+            let nla = &nla.context(err_msg.clone())?;
+            match Nl80211KeyNla::parse(nla).context(err_msg.clone())? {
+                Nl80211KeyNla::Data(d) => ret.data = Some(d),
+                Nl80211KeyNla::Idx(d) => ret.idx = Some(d),
+                Nl80211KeyNla::Cipher(d) => ret.cipher = Some(d),
+                Nl80211KeyNla::Seq(d) => ret.seq = Some(d),
+                Nl80211KeyNla::Default => ret.default = true,
+                Nl80211KeyNla::DefaultMgmt => ret.default_mgmt = true,
+                Nl80211KeyNla::Type(d) => ret.key_type = Some(d),
+                Nl80211KeyNla::DefaultTypes(d) => ret.default_types = Some(d),
+                Nl80211KeyNla::Mode(d) => ret.mode = Some(d),
+                Nl80211KeyNla::DefaultBeacon => ret.default_beacon = true,
+                Nl80211KeyNla::Other(attr) => {
+                    log::warn!(
+                        "Got unsupported NL80211_ATTR_KEY value {:?}",
+                        attr
+                    )
+                }
+            }
+        }
+        Ok(ret)
+    }
+}
+
+impl From<&Nl80211Key> for Vec<Nl80211KeyNla> {
+    fn from(key: &Nl80211Key) -> Self {
+        let mut nlas = Vec::new();
+        if let Some(v) = key.data.as_ref() {
+            nlas.push(Nl80211KeyNla::Data(v.clone()));
+        }
+        if let Some(v) = key.idx {
+            nlas.push(Nl80211KeyNla::Idx(v));
+        }
+        if let Some(v) = key.cipher {
+            nlas.push(Nl80211KeyNla::Cipher(v));
+        }
+        if let Some(v) = key.seq.as_ref() {
+            nlas.push(Nl80211KeyNla::Seq(v.clone()));
+        }
+        if key.default {
+            nlas.push(Nl80211KeyNla::Default);
+        }
+        if key.default_mgmt {
+            nlas.push(Nl80211KeyNla::DefaultMgmt);
+        }
+        if let Some(v) = key.key_type {
+            nlas.push(Nl80211KeyNla::Type(v));
+        }
+        if let Some(v) = key.default_types {
+            nlas.push(Nl80211KeyNla::DefaultTypes(v));
+        }
+        if let Some(v) = key.mode {
+            nlas.push(Nl80211KeyNla::Mode(v));
+        }
+        if key.default_beacon {
+            nlas.push(Nl80211KeyNla::DefaultBeacon);
+        }
+        nlas
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum Nl80211KeyDefaultTypeNla {
+    /// Default key for unicast frames
+    Unicast,
+    /// Default key for multicast frames
+    Multicast,
+    Other(DefaultNla),
+}
+
+impl Nla for Nl80211KeyDefaultTypeNla {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::Unicast | Self::Multicast => 0,
+            Self::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::Unicast => NL80211_KEY_DEFAULT_TYPE_UNICAST,
+            Self::Multicast => NL80211_KEY_DEFAULT_TYPE_MULTICAST,
+            Self::Other(attr) => attr.kind(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Unicast | Self::Multicast => (),
+            Self::Other(ref attr) => attr.emit(buffer),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for Nl80211KeyDefaultTypeNla
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        Ok(match buf.kind() {
+            NL80211_KEY_DEFAULT_TYPE_UNICAST => Self::Unicast,
+            NL80211_KEY_DEFAULT_TYPE_MULTICAST => Self::Multicast,
+            _ => Self::Other(
+                DefaultNla::parse(buf).context("invalid NLA (unknown kind)")?,
+            ),
+        })
+    }
+}
+
+/// Traffic types the default key is used for
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+pub struct Nl80211KeyDefaultTypes {
+    /// Default key for unicast frames
+    pub unicast: bool,
+    /// Default key for multicast frames
+    pub multicast: bool,
+}
+
+impl Emitable for Nl80211KeyDefaultTypes {
+    fn buffer_len(&self) -> usize {
+        Vec::<Nl80211KeyDefaultTypeNla>::from(self)
+            .as_slice()
+            .buffer_len()
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        Vec::<Nl80211KeyDefaultTypeNla>::from(self)
+            .as_slice()
+            .emit(buffer)
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for Nl80211KeyDefaultTypes
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let mut ret = Self::default();
+        let payload = buf.value();
+        let err_msg =
+            format!("Invalid NL80211_KEY_DEFAULT_TYPES value {:?}", payload);
+        for nla in NlasIterator::new(payload) {
+            let nla = &nla.context(err_msg.clone())?;
+            match Nl80211KeyDefaultTypeNla::parse(nla)
+                .context(err_msg.clone())?
+            {
+                Nl80211KeyDefaultTypeNla::Unicast => ret.unicast = true,
+                Nl80211KeyDefaultTypeNla::Multicast => ret.multicast = true,
+                Nl80211KeyDefaultTypeNla::Other(attr) => {
+                    log::warn!(
+                        "Got unsupported NL80211_KEY_DEFAULT_TYPES value {:?}",
+                        attr
+                    )
+                }
+            }
+        }
+        Ok(ret)
+    }
+}
+
+impl From<&Nl80211KeyDefaultTypes> for Vec<Nl80211KeyDefaultTypeNla> {
+    fn from(types: &Nl80211KeyDefaultTypes) -> Self {
+        let mut nlas = Vec::new();
+        if types.unicast {
+            nlas.push(Nl80211KeyDefaultTypeNla::Unicast);
+        }
+        if types.multicast {
+            nlas.push(Nl80211KeyDefaultTypeNla::Multicast);
+        }
+        nlas
+    }
+}
@@ -0,0 +1,40 @@
+// SPDX-License-Identifier: MIT
+
+const NL80211_KEY_RX_TX: u8 = 0;
+const NL80211_KEY_NO_TX: u8 = 1;
+const NL80211_KEY_SET_TX: u8 = 2;
+
+/// Mode of a pairwise key, used for extended key ID support
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Nl80211KeyMode {
+    /// Key is used for RX and TX immediately, the default
+    RxTx,
+    /// Key is only used for RX, only valid when installing a key
+    NoTx,
+    /// Key becomes the preferred TX key, only valid when changing a key
+    /// installed with `NoTx`
+    SetTx,
+    Other(u8),
+}
+
+impl From<u8> for Nl80211KeyMode {
+    fn from(d: u8) -> Self {
+        match d {
+            NL80211_KEY_RX_TX => Self::RxTx,
+            NL80211_KEY_NO_TX => Self::NoTx,
+            NL80211_KEY_SET_TX => Self::SetTx,
+            _ => Self::Other(d),
+        }
+    }
+}
+
+impl From<Nl80211KeyMode> for u8 {
+    fn from(v: Nl80211KeyMode) -> u8 {
+        match v {
+            Nl80211KeyMode::RxTx => NL80211_KEY_RX_TX,
+            Nl80211KeyMode::NoTx => NL80211_KEY_NO_TX,
+            Nl80211KeyMode::SetTx => NL80211_KEY_SET_TX,
+            Nl80211KeyMode::Other(d) => d,
+        }
+    }
+}
@@ -0,0 +1,39 @@
+// SPDX-License-Identifier: MIT
+
+const NL80211_KEYTYPE_GROUP: u32 = 0;
+const NL80211_KEYTYPE_PAIRWISE: u32 = 1;
+const NL80211_KEYTYPE_PEERKEY: u32 = 2;
+
+/// Key type
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Nl80211KeyType {
+    /// Group (broadcast/multicast) key
+    Group,
+    /// Pairwise (unicast/individual) key
+    Pairwise,
+    /// PeerKey (DLS)
+    PeerKey,
+    Other(u32),
+}
+
+impl From<u32> for Nl80211KeyType {
+    fn from(d: u32) -> Self {
+        match d {
+            NL80211_KEYTYPE_GROUP => Self::Group,
+            NL80211_KEYTYPE_PAIRWISE => Self::Pairwise,
+            NL80211_KEYTYPE_PEERKEY => Self::PeerKey,
+            _ => Self::Other(d),
+        }
+    }
+}
+
+impl From<Nl80211KeyType> for u32 {
+    fn from(v: Nl80211KeyType) -> u32 {
+        match v {
+            Nl80211KeyType::Group => NL80211_KEYTYPE_GROUP,
+            Nl80211KeyType::Pairwise => NL80211_KEYTYPE_PAIRWISE,
+            Nl80211KeyType::PeerKey => NL80211_KEYTYPE_PEERKEY,
+            Nl80211KeyType::Other(d) => d,
+        }
+    }
+}
@@ -0,0 +1,21 @@
+// SPDX-License-Identifier: MIT
+
+mod add;
+mod del;
+mod get;
+mod handle;
+mod key_attr;
+mod key_mode;
+mod key_type;
+mod set;
+
+pub use add::Nl80211KeyAddRequest;
+pub use del::Nl80211KeyDelRequest;
+pub use get::Nl80211KeyGetRequest;
+pub use handle::Nl80211KeyHandle;
+pub use key_attr::{
+    Nl80211Key, Nl80211KeyDefaultTypeNla, Nl80211KeyDefaultTypes, Nl80211KeyNla,
+};
+pub use key_mode::Nl80211KeyMode;
+pub use key_type::Nl80211KeyType;
+pub use set::Nl80211KeySetRequest;
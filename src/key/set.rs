@@ -0,0 +1,86 @@
+// SPDX-License-Identifier: MIT
+
+use crate::{
+    nl80211_execute_ack, Nl80211Attr, Nl80211Cmd, Nl80211Error, Nl80211Handle,
+    Nl80211Key, Nl80211KeyDefaultTypes, Nl80211Message,
+};
+
+pub struct Nl80211KeySetRequest {
+    handle: Nl80211Handle,
+    if_index: u32,
+    key_idx: u8,
+    default: bool,
+    default_mgmt: bool,
+    default_beacon: bool,
+    default_types: Option<Nl80211KeyDefaultTypes>,
+}
+
+impl Nl80211KeySetRequest {
+    pub(crate) fn new(
+        handle: Nl80211Handle,
+        if_index: u32,
+        key_idx: u8,
+    ) -> Self {
+        Nl80211KeySetRequest {
+            handle,
+            if_index,
+            key_idx,
+            default: false,
+            default_mgmt: false,
+            default_beacon: false,
+            default_types: None,
+        }
+    }
+
+    /// Use the key as default data key
+    pub fn default(mut self) -> Self {
+        self.default = true;
+        self
+    }
+
+    /// Use the key as default management frame protection key
+    pub fn default_mgmt(mut self) -> Self {
+        self.default_mgmt = true;
+        self
+    }
+
+    /// Use the key as default beacon protection key
+    pub fn default_beacon(mut self) -> Self {
+        self.default_beacon = true;
+        self
+    }
+
+    /// Restrict the default data key to unicast or multicast traffic
+    pub fn default_types(mut self, types: Nl80211KeyDefaultTypes) -> Self {
+        self.default_types = Some(types);
+        self
+    }
+
+    pub async fn execute(self) -> Result<(), Nl80211Error> {
+        let Nl80211KeySetRequest {
+            mut handle,
+            if_index,
+            key_idx,
+            default,
+            default_mgmt,
+            default_beacon,
+            default_types,
+        } = self;
+
+        let key = Nl80211Key {
+            idx: Some(key_idx),
+            default,
+            default_mgmt,
+            default_beacon,
+            default_types,
+            ..Default::default()
+        };
+        let nlas = vec![Nl80211Attr::IfIndex(if_index), Nl80211Attr::Key(key)];
+
+        let nl80211_msg = Nl80211Message {
+            cmd: Nl80211Cmd::KeySet,
+            nlas,
+        };
+        nl80211_execute_ack(&mut handle, nl80211_msg).await
+    }
+}
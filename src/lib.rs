@@ -1,26 +1,190 @@
 // SPDX-License-Identifier: MIT
 
+mod akm;
+mod ap;
 mod attr;
+mod auth_type;
+mod band;
 mod channel;
+mod cipher;
+mod coalesce;
 mod connection;
+mod cqm;
+mod crypto;
 mod error;
+mod frame;
 mod handle;
+mod ie;
 mod iface;
+mod key;
 mod macros;
+mod mesh;
 mod message;
+mod nan;
+mod nested;
+mod phy;
+mod pmsr;
+mod reg;
+mod scan;
+mod station;
 mod stats;
+mod survey;
+mod tdls;
+mod tid_config;
+mod tx_rates;
+mod wowlan;
 
+pub use akm::Nl80211AkmSuite;
+pub use ap::{
+    nl80211_probe_client_status, Nl80211AclPolicy, Nl80211ApHandle,
+    Nl80211ApStartRequest, Nl80211ApStopRequest, Nl80211BeaconData,
+    Nl80211BeaconHead, Nl80211BeaconSetRequest, Nl80211BssSetRequest,
+    Nl80211ChannelSwitchRequest, Nl80211ColorChangeRequest,
+    Nl80211FilsDiscovery, Nl80211FilsDiscoveryNla, Nl80211FtmResponderStats,
+    Nl80211FtmResponderStatsGetRequest, Nl80211FtmResponderStatsNla,
+    Nl80211HeBssColor, Nl80211HeBssColorNla, Nl80211HeObssPd,
+    Nl80211HeObssPdNla, Nl80211HiddenSsid, Nl80211MacAclSetRequest,
+    Nl80211MbssidConfig, Nl80211MbssidConfigNla, Nl80211ProbeClientRequest,
+    Nl80211ProbeClientStatus, Nl80211RadarNotifyRequest,
+    Nl80211UnsolBcastProbeResp, Nl80211UnsolBcastProbeRespNla,
+};
 pub use attr::Nl80211Attr;
-pub use channel::Nl80211WiPhyChannelType;
+pub use auth_type::Nl80211AuthType;
+pub use band::Nl80211Band;
+pub use channel::{
+    Nl80211ChannelDef, Nl80211ChannelWidth, Nl80211WiPhyChannelType,
+};
+pub use cipher::Nl80211CipherSuite;
+pub use coalesce::{
+    Nl80211CoalesceCondition, Nl80211CoalesceGetRequest, Nl80211CoalesceHandle,
+    Nl80211CoalesceRule, Nl80211CoalesceRuleNla, Nl80211CoalesceRuleSupport,
+    Nl80211CoalesceSetRequest,
+};
 #[cfg(feature = "tokio_socket")]
 pub use connection::new_connection;
 pub use connection::new_connection_with_socket;
+pub use cqm::{
+    nl80211_cqm_events, Nl80211Cqm, Nl80211CqmEvent, Nl80211CqmNla,
+    Nl80211CqmRssiThresholdEvent, Nl80211CqmSetRequest,
+};
+pub use crypto::{Nl80211CryptoSettings, Nl80211Mfp, Nl80211WpaVersions};
 pub use error::Nl80211Error;
+pub use frame::{
+    nl80211_frame_tx_status, nl80211_received_frames,
+    Nl80211BeaconsRegisterRequest, Nl80211FrameHandle,
+    Nl80211FrameRegisterRequest, Nl80211FrameTxRequest, Nl80211FrameTxStatus,
+    Nl80211ReceivedFrame, Nl80211RemainOnChannelCancelRequest,
+    Nl80211RemainOnChannelRequest,
+};
 pub use handle::Nl80211Handle;
+pub use ie::{
+    Nl80211Element, Nl80211ElementIter, Nl80211ElementRate, Nl80211HeOperation,
+    Nl80211HtOperation, Nl80211Rsn, Nl80211VhtOperation, Nl80211Wps,
+    Nl80211WpsState,
+};
 pub use iface::{
-    Nl80211InterfaceGetRequest, Nl80211InterfaceHandle, Nl80211InterfaceType,
+    Nl80211AssociateRequest, Nl80211AuthenticateRequest,
+    Nl80211ChannelSetRequest, Nl80211ConnectRequest, Nl80211CritProtoId,
+    Nl80211CritProtocolStartRequest, Nl80211CritProtocolStopRequest,
+    Nl80211DscpException, Nl80211DscpRange, Nl80211IbssJoinRequest,
+    Nl80211IbssLeaveRequest, Nl80211InterfaceAddRequest,
+    Nl80211InterfaceGetRequest, Nl80211InterfaceHandle,
+    Nl80211InterfaceSetRequest, Nl80211InterfaceType, Nl80211MonitorFlags,
+    Nl80211MulticastToUnicastSetRequest, Nl80211NoackMapSetRequest,
+    Nl80211OcbJoinRequest, Nl80211OcbLeaveRequest, Nl80211PmkDelRequest,
+    Nl80211PmkSetRequest, Nl80211PmksaDelRequest, Nl80211PmksaFlushRequest,
+    Nl80211PmksaSetRequest, Nl80211PowerSaveGetRequest,
+    Nl80211PowerSaveSetRequest, Nl80211PowerSaveState, Nl80211QosMap,
+    Nl80211QosMapSetRequest, Nl80211TxTsAddRequest, Nl80211TxTsDelRequest,
+    Nl80211UpdateOweInfoRequest,
+};
+pub use key::{
+    Nl80211Key, Nl80211KeyAddRequest, Nl80211KeyDefaultTypeNla,
+    Nl80211KeyDefaultTypes, Nl80211KeyDelRequest, Nl80211KeyGetRequest,
+    Nl80211KeyHandle, Nl80211KeyMode, Nl80211KeyNla, Nl80211KeySetRequest,
+    Nl80211KeyType,
+};
+pub use mesh::{
+    Nl80211MeshConfig, Nl80211MeshConfigGetRequest, Nl80211MeshConfigNla,
+    Nl80211MeshConfigSetRequest, Nl80211MeshHandle, Nl80211MeshJoinRequest,
+    Nl80211MeshLeaveRequest, Nl80211MeshPathDelRequest,
+    Nl80211MeshPathGetRequest, Nl80211MeshPathNewRequest,
+    Nl80211MeshPathSetRequest, Nl80211MeshPowerMode,
+    Nl80211MeshProxyPathGetRequest, Nl80211MeshSetup, Nl80211MeshSetupNla,
+    Nl80211MpathFlags, Nl80211MpathInfo, Nl80211MpathInfoNla,
 };
 pub use message::{Nl80211Cmd, Nl80211Message};
+pub use nan::{
+    nl80211_nan_match_events, Nl80211NanConfigChangeRequest,
+    Nl80211NanFuncTermReason, Nl80211NanFunction, Nl80211NanFunctionAddRequest,
+    Nl80211NanFunctionDelRequest, Nl80211NanFunctionNla,
+    Nl80211NanFunctionType, Nl80211NanHandle, Nl80211NanMatch,
+    Nl80211NanMatchEvent, Nl80211NanMatchNla, Nl80211NanSrf, Nl80211NanSrfNla,
+    Nl80211NanStartRequest, Nl80211NanStopRequest,
+};
+pub use phy::{
+    Nl80211Ac, Nl80211BandIftypeData, Nl80211BandIftypeDataNla,
+    Nl80211BandInfo, Nl80211Bitrate, Nl80211BitrateNla, Nl80211DfsState,
+    Nl80211ExtFeature, Nl80211ExtFeatures, Nl80211ExtendedCapability,
+    Nl80211Frequency, Nl80211FrequencyNla, Nl80211HeMcsNssSupp,
+    Nl80211HtCapability, Nl80211HtMcsInfo, Nl80211IftypeExtCapa,
+    Nl80211IftypeExtCapaNla, Nl80211PhyGetRequest, Nl80211PhyHandle,
+    Nl80211PhyNetnsSetRequest, Nl80211PhySetRequest, Nl80211ProtocolFeatures,
+    Nl80211ProtocolFeaturesGetRequest, Nl80211TxqParams, Nl80211TxqParamsNla,
+    Nl80211VhtCapability, Nl80211VhtMcsInfo, Nl80211WiPhyBand,
+};
+pub use pmsr::{
+    Nl80211PeerMeasurement, Nl80211PeerMeasurementHandle,
+    Nl80211PeerMeasurementNla, Nl80211PeerMeasurementStartRequest,
+    Nl80211PmsrFtmCapa, Nl80211PmsrFtmCapaNla, Nl80211PmsrFtmFailureReason,
+    Nl80211PmsrFtmRequest, Nl80211PmsrFtmRequestNla, Nl80211PmsrFtmResponse,
+    Nl80211PmsrFtmResponseNla, Nl80211PmsrPeer, Nl80211PmsrPeerNla,
+    Nl80211PmsrRequest, Nl80211PmsrRequestData, Nl80211PmsrRequestDataNla,
+    Nl80211PmsrRequestNla, Nl80211PmsrResponse, Nl80211PmsrResponseData,
+    Nl80211PmsrResponseDataNla, Nl80211PmsrResponseNla, Nl80211PmsrStatus,
+    Nl80211PmsrTypeCapa, Nl80211PmsrTypeCapaNla, Nl80211Preamble,
+};
+pub use reg::{
+    Nl80211DfsRegion, Nl80211RegGetRequest, Nl80211RegHandle, Nl80211RegRule,
+    Nl80211RegRuleFlags, Nl80211RegRuleNla, Nl80211RegRulesSetRequest,
+    Nl80211RegSetRequest,
+};
+pub use scan::{
+    Nl80211Bss, Nl80211BssNla, Nl80211BssScanWidth, Nl80211BssStatus,
+    Nl80211RssiAdjust, Nl80211ScanFlags, Nl80211ScanGetRequest,
+    Nl80211ScanHandle, Nl80211ScanScheduleRequest,
+    Nl80211ScanScheduleStopRequest, Nl80211ScanTriggerRequest,
+    Nl80211SchedScanMatchNla, Nl80211SchedScanMatchSet,
+};
+pub use station::{
+    Nl80211GuardInterval, Nl80211PlinkAction, Nl80211PlinkState,
+    Nl80211RateInfo, Nl80211RateInfoBandwidth, Nl80211RateInfoNla,
+    Nl80211StationFlag, Nl80211StationFlagUpdate, Nl80211StationGetRequest,
+    Nl80211StationHandle, Nl80211StationInfo, Nl80211StationInfoNla,
+    Nl80211StationNewRequest, Nl80211StationSetRequest,
+};
 pub use stats::Nl80211TransmitQueueStat;
+pub use survey::{
+    Nl80211SurveyGetRequest, Nl80211SurveyHandle, Nl80211SurveyInfo,
+    Nl80211SurveyInfoNla,
+};
+pub use tdls::{
+    Nl80211TdlsAction, Nl80211TdlsMgmtRequest, Nl80211TdlsOperRequest,
+    Nl80211TdlsOperation,
+};
+pub use tid_config::{
+    Nl80211TidConfig, Nl80211TidConfigNla, Nl80211TidConfigSetRequest,
+    Nl80211TidConfigSupport, Nl80211TxRateSetting,
+};
+pub use tx_rates::{Nl80211TxRateNla, Nl80211TxRates};
+pub use wowlan::{
+    Nl80211PacketPattern, Nl80211PacketPatternNla, Nl80211PatternSupport,
+    Nl80211WowlanGetRequest, Nl80211WowlanHandle, Nl80211WowlanSetRequest,
+    Nl80211WowlanTcp, Nl80211WowlanTcpDataSeq, Nl80211WowlanTcpDataToken,
+    Nl80211WowlanTcpNla, Nl80211WowlanTriggerNla, Nl80211WowlanTriggers,
+    Nl80211WowlanTriggersSupported, Nl80211WowlanTriggersSupportedNla,
+};
 
-pub(crate) use handle::nl80211_execute;
+pub(crate) use handle::{
+    nl80211_execute, nl80211_execute_ack, nl80211_execute_ack_cookie,
+};
@@ -30,3 +30,80 @@ macro_rules! try_nl80211 {
         }
     }};
 }
+
+// Bitmap newtype with associated flag constants, could be combined via `|`
+// operator and converted from/to the raw integer.
+macro_rules! nl80211_bitflags {
+    (
+        $(#[$outer:meta])*
+        pub struct $name:ident: $ty:ty {
+            $(
+                $(#[$inner:meta])*
+                const $flag:ident = $value:expr;
+            )*
+        }
+    ) => {
+        $(#[$outer])*
+        #[derive(Debug, PartialEq, Eq, Clone, Copy, Default, Hash)]
+        pub struct $name($ty);
+
+        impl $name {
+            $(
+                $(#[$inner])*
+                pub const $flag: Self = Self($value);
+            )*
+
+            pub const fn empty() -> Self {
+                Self(0)
+            }
+
+            pub const fn bits(&self) -> $ty {
+                self.0
+            }
+
+            pub const fn contains(&self, other: Self) -> bool {
+                self.0 & other.0 == other.0
+            }
+
+            pub const fn is_empty(&self) -> bool {
+                self.0 == 0
+            }
+        }
+
+        impl From<$ty> for $name {
+            fn from(d: $ty) -> Self {
+                Self(d)
+            }
+        }
+
+        impl From<$name> for $ty {
+            fn from(v: $name) -> $ty {
+                v.0
+            }
+        }
+
+        impl std::ops::BitOr for $name {
+            type Output = Self;
+
+            fn bitor(self, rhs: Self) -> Self {
+                Self(self.0 | rhs.0)
+            }
+        }
+
+        impl std::ops::BitOrAssign for $name {
+            fn bitor_assign(&mut self, rhs: Self) {
+                self.0 |= rhs.0
+            }
+        }
+
+        impl std::ops::BitAnd for $name {
+            type Output = Self;
+
+            fn bitand(self, rhs: Self) -> Self {
+                Self(self.0 & rhs.0)
+            }
+        }
+    };
+}
+
+pub(crate) use nl80211_bitflags;
@@ -0,0 +1,778 @@
+// SPDX-License-Identifier: MIT
+
+use anyhow::Context;
+use byteorder::{ByteOrder, NativeEndian};
+use netlink_packet_utils::{
+    nla::{DefaultNla, Nla, NlaBuffer, NlasIterator},
+    parsers::{parse_i32, parse_u16, parse_u32, parse_u8},
+    DecodeError, Emitable, Parseable,
+};
+
+use crate::Nl80211MeshPowerMode;
+
+const NL80211_MESHCONF_RETRY_TIMEOUT: u16 = 1;
+const NL80211_MESHCONF_CONFIRM_TIMEOUT: u16 = 2;
+const NL80211_MESHCONF_HOLDING_TIMEOUT: u16 = 3;
+const NL80211_MESHCONF_MAX_PEER_LINKS: u16 = 4;
+const NL80211_MESHCONF_MAX_RETRIES: u16 = 5;
+const NL80211_MESHCONF_TTL: u16 = 6;
+const NL80211_MESHCONF_AUTO_OPEN_PLINKS: u16 = 7;
+const NL80211_MESHCONF_HWMP_MAX_PREQ_RETRIES: u16 = 8;
+const NL80211_MESHCONF_PATH_REFRESH_TIME: u16 = 9;
+const NL80211_MESHCONF_MIN_DISCOVERY_TIMEOUT: u16 = 10;
+const NL80211_MESHCONF_HWMP_ACTIVE_PATH_TIMEOUT: u16 = 11;
+const NL80211_MESHCONF_HWMP_PREQ_MIN_INTERVAL: u16 = 12;
+const NL80211_MESHCONF_HWMP_NET_DIAM_TRVS_TIME: u16 = 13;
+const NL80211_MESHCONF_HWMP_ROOTMODE: u16 = 14;
+const NL80211_MESHCONF_ELEMENT_TTL: u16 = 15;
+const NL80211_MESHCONF_HWMP_RANN_INTERVAL: u16 = 16;
+const NL80211_MESHCONF_GATE_ANNOUNCEMENTS: u16 = 17;
+const NL80211_MESHCONF_HWMP_PERR_MIN_INTERVAL: u16 = 18;
+const NL80211_MESHCONF_FORWARDING: u16 = 19;
+const NL80211_MESHCONF_RSSI_THRESHOLD: u16 = 20;
+const NL80211_MESHCONF_SYNC_OFFSET_MAX_NEIGHBOR: u16 = 21;
+const NL80211_MESHCONF_HT_OPMODE: u16 = 22;
+const NL80211_MESHCONF_HWMP_PATH_TO_ROOT_TIMEOUT: u16 = 23;
+const NL80211_MESHCONF_HWMP_ROOT_INTERVAL: u16 = 24;
+const NL80211_MESHCONF_HWMP_CONFIRMATION_INTERVAL: u16 = 25;
+const NL80211_MESHCONF_POWER_MODE: u16 = 26;
+const NL80211_MESHCONF_AWAKE_WINDOW: u16 = 27;
+const NL80211_MESHCONF_PLINK_TIMEOUT: u16 = 28;
+const NL80211_MESHCONF_CONNECTED_TO_GATE: u16 = 29;
+const NL80211_MESHCONF_NOLEARN: u16 = 30;
+const NL80211_MESHCONF_CONNECTED_TO_AS: u16 = 31;
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum Nl80211MeshConfigNla {
+    /// Initial retry timeout of Peer Link Open in milliseconds
+    RetryTimeout(u16),
+    /// Initial confirm timeout of peer link management in milliseconds
+    ConfirmTimeout(u16),
+    /// Holding timeout of peer link management in milliseconds
+    HoldingTimeout(u16),
+    /// Maximum number of peer links
+    MaxPeerLinks(u16),
+    /// Maximum number of Peer Link Open retries
+    MaxRetries(u8),
+    /// TTL of frames originated by this mesh STA
+    Ttl(u8),
+    /// Automatically open peer links with compatible mesh peers
+    AutoOpenPlinks(bool),
+    /// Number of PREQ frames sent to a path target before giving up
+    HwmpMaxPreqRetries(u8),
+    /// Interval of mesh path refreshes in milliseconds
+    PathRefreshTime(u32),
+    /// Minimum path discovery timeout in milliseconds
+    MinDiscoveryTimeout(u16),
+    /// Lifetime of forwarding information learned from a PREQ in TUs
+    HwmpActivePathTimeout(u32),
+    /// Minimum interval between PREQ frames in TUs
+    HwmpPreqMinInterval(u16),
+    /// Time for an HWMP element to propagate across the mesh in TUs
+    HwmpNetDiamTrvsTime(u16),
+    /// HWMP root mode, 0 when this mesh STA is not a root
+    HwmpRootmode(u8),
+    /// TTL of path selection elements originated by this mesh STA
+    ElementTtl(u8),
+    /// Interval between Root Announcements in TUs
+    HwmpRannInterval(u16),
+    /// Advertise access to a network beyond the mesh in Root Announcements
+    GateAnnouncements(bool),
+    /// Minimum interval between PERR frames in TUs
+    HwmpPerrMinInterval(u16),
+    /// Forward frames of other mesh STAs
+    Forwarding(bool),
+    /// Minimum average signal strength in dBm of a candidate peer
+    RssiThreshold(i32),
+    /// Maximum number of neighbors to synchronize to
+    SyncOffsetMaxNeighbor(u32),
+    /// HT protection mode
+    HtOpmode(u16),
+    /// Lifetime of forwarding information learned from a proactive PREQ in
+    /// TUs
+    HwmpPathToRootTimeout(u32),
+    /// Interval between proactive PREQ frames in TUs
+    HwmpRootInterval(u16),
+    /// Minimum interval between PREQ frames for root path confirmation in TUs
+    HwmpConfirmationInterval(u16),
+    /// Default mesh power mode of new peer links
+    PowerMode(Nl80211MeshPowerMode),
+    /// Awake window duration in TUs
+    AwakeWindow(u16),
+    /// Inactivity timeout of peer links in seconds, 0 to never remove inactive
+    /// peers
+    PlinkTimeout(u32),
+    /// Advertise a connection to a mesh gate in the mesh formation field
+    ConnectedToGate(bool),
+    /// Avoid multi-hop path discovery when the destination is a neighbor
+    Nolearn(bool),
+    /// Advertise a connection to an authentication server in the mesh formation
+    /// field
+    ConnectedToAs(bool),
+    Other(DefaultNla),
+}
+
+impl Nla for Nl80211MeshConfigNla {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::MaxRetries(_)
+            | Self::Ttl(_)
+            | Self::AutoOpenPlinks(_)
+            | Self::HwmpMaxPreqRetries(_)
+            | Self::HwmpRootmode(_)
+            | Self::ElementTtl(_)
+            | Self::GateAnnouncements(_)
+            | Self::Forwarding(_)
+            | Self::ConnectedToGate(_)
+            | Self::Nolearn(_)
+            | Self::ConnectedToAs(_) => 1,
+            Self::RetryTimeout(_)
+            | Self::ConfirmTimeout(_)
+            | Self::HoldingTimeout(_)
+            | Self::MaxPeerLinks(_)
+            | Self::MinDiscoveryTimeout(_)
+            | Self::HwmpPreqMinInterval(_)
+            | Self::HwmpNetDiamTrvsTime(_)
+            | Self::HwmpRannInterval(_)
+            | Self::HwmpPerrMinInterval(_)
+            | Self::HtOpmode(_)
+            | Self::HwmpRootInterval(_)
+            | Self::HwmpConfirmationInterval(_)
+            | Self::AwakeWindow(_) => 2,
+            Self::PathRefreshTime(_)
+            | Self::HwmpActivePathTimeout(_)
+            | Self::RssiThreshold(_)
+            | Self::SyncOffsetMaxNeighbor(_)
+            | Self::HwmpPathToRootTimeout(_)
+            | Self::PowerMode(_)
+            | Self::PlinkTimeout(_) => 4,
+            Self::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::RetryTimeout(_) => NL80211_MESHCONF_RETRY_TIMEOUT,
+            Self::ConfirmTimeout(_) => NL80211_MESHCONF_CONFIRM_TIMEOUT,
+            Self::HoldingTimeout(_) => NL80211_MESHCONF_HOLDING_TIMEOUT,
+            Self::MaxPeerLinks(_) => NL80211_MESHCONF_MAX_PEER_LINKS,
+            Self::MaxRetries(_) => NL80211_MESHCONF_MAX_RETRIES,
+            Self::Ttl(_) => NL80211_MESHCONF_TTL,
+            Self::AutoOpenPlinks(_) => NL80211_MESHCONF_AUTO_OPEN_PLINKS,
+            Self::HwmpMaxPreqRetries(_) => {
+                NL80211_MESHCONF_HWMP_MAX_PREQ_RETRIES
+            }
+            Self::PathRefreshTime(_) => NL80211_MESHCONF_PATH_REFRESH_TIME,
+            Self::MinDiscoveryTimeout(_) => {
+                NL80211_MESHCONF_MIN_DISCOVERY_TIMEOUT
+            }
+            Self::HwmpActivePathTimeout(_) => {
+                NL80211_MESHCONF_HWMP_ACTIVE_PATH_TIMEOUT
+            }
+            Self::HwmpPreqMinInterval(_) => {
+                NL80211_MESHCONF_HWMP_PREQ_MIN_INTERVAL
+            }
+            Self::HwmpNetDiamTrvsTime(_) => {
+                NL80211_MESHCONF_HWMP_NET_DIAM_TRVS_TIME
+            }
+            Self::HwmpRootmode(_) => NL80211_MESHCONF_HWMP_ROOTMODE,
+            Self::ElementTtl(_) => NL80211_MESHCONF_ELEMENT_TTL,
+            Self::HwmpRannInterval(_) => NL80211_MESHCONF_HWMP_RANN_INTERVAL,
+            Self::GateAnnouncements(_) => NL80211_MESHCONF_GATE_ANNOUNCEMENTS,
+            Self::HwmpPerrMinInterval(_) => {
+                NL80211_MESHCONF_HWMP_PERR_MIN_INTERVAL
+            }
+            Self::Forwarding(_) => NL80211_MESHCONF_FORWARDING,
+            Self::RssiThreshold(_) => NL80211_MESHCONF_RSSI_THRESHOLD,
+            Self::SyncOffsetMaxNeighbor(_) => {
+                NL80211_MESHCONF_SYNC_OFFSET_MAX_NEIGHBOR
+            }
+            Self::HtOpmode(_) => NL80211_MESHCONF_HT_OPMODE,
+            Self::HwmpPathToRootTimeout(_) => {
+                NL80211_MESHCONF_HWMP_PATH_TO_ROOT_TIMEOUT
+            }
+            Self::HwmpRootInterval(_) => NL80211_MESHCONF_HWMP_ROOT_INTERVAL,
+            Self::HwmpConfirmationInterval(_) => {
+                NL80211_MESHCONF_HWMP_CONFIRMATION_INTERVAL
+            }
+            Self::PowerMode(_) => NL80211_MESHCONF_POWER_MODE,
+            Self::AwakeWindow(_) => NL80211_MESHCONF_AWAKE_WINDOW,
+            Self::PlinkTimeout(_) => NL80211_MESHCONF_PLINK_TIMEOUT,
+            Self::ConnectedToGate(_) => NL80211_MESHCONF_CONNECTED_TO_GATE,
+            Self::Nolearn(_) => NL80211_MESHCONF_NOLEARN,
+            Self::ConnectedToAs(_) => NL80211_MESHCONF_CONNECTED_TO_AS,
+            Self::Other(attr) => attr.kind(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::MaxRetries(d)
+            | Self::Ttl(d)
+            | Self::HwmpMaxPreqRetries(d)
+            | Self::HwmpRootmode(d)
+            | Self::ElementTtl(d) => buffer[0] = *d,
+            Self::RetryTimeout(d)
+            | Self::ConfirmTimeout(d)
+            | Self::HoldingTimeout(d)
+            | Self::MaxPeerLinks(d)
+            | Self::MinDiscoveryTimeout(d)
+            | Self::HwmpPreqMinInterval(d)
+            | Self::HwmpNetDiamTrvsTime(d)
+            | Self::HwmpRannInterval(d)
+            | Self::HwmpPerrMinInterval(d)
+            | Self::HtOpmode(d)
+            | Self::HwmpRootInterval(d)
+            | Self::HwmpConfirmationInterval(d)
+            | Self::AwakeWindow(d) => NativeEndian::write_u16(buffer, *d),
+            Self::PathRefreshTime(d)
+            | Self::HwmpActivePathTimeout(d)
+            | Self::SyncOffsetMaxNeighbor(d)
+            | Self::HwmpPathToRootTimeout(d)
+            | Self::PlinkTimeout(d) => NativeEndian::write_u32(buffer, *d),
+            Self::RssiThreshold(d) => NativeEndian::write_i32(buffer, *d),
+            Self::AutoOpenPlinks(d) => buffer[0] = *d as u8,
+            Self::GateAnnouncements(d) => buffer[0] = *d as u8,
+            Self::Forwarding(d) => buffer[0] = *d as u8,
+            Self::PowerMode(d) => NativeEndian::write_u32(buffer, (*d).into()),
+            Self::ConnectedToGate(d) => buffer[0] = *d as u8,
+            Self::Nolearn(d) => buffer[0] = *d as u8,
+            Self::ConnectedToAs(d) => buffer[0] = *d as u8,
+            Self::Other(ref attr) => attr.emit(buffer),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for Nl80211MeshConfigNla
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            NL80211_MESHCONF_RETRY_TIMEOUT => {
+                let err_msg = format!(
+                    "Invalid NL80211_MESHCONF_RETRY_TIMEOUT value {:?}",
+                    payload
+                );
+                Self::RetryTimeout(parse_u16(payload).context(err_msg)?)
+            }
+            NL80211_MESHCONF_CONFIRM_TIMEOUT => {
+                let err_msg = format!(
+                    "Invalid NL80211_MESHCONF_CONFIRM_TIMEOUT value {:?}",
+                    payload
+                );
+                Self::ConfirmTimeout(parse_u16(payload).context(err_msg)?)
+            }
+            NL80211_MESHCONF_HOLDING_TIMEOUT => {
+                let err_msg = format!(
+                    "Invalid NL80211_MESHCONF_HOLDING_TIMEOUT value {:?}",
+                    payload
+                );
+                Self::HoldingTimeout(parse_u16(payload).context(err_msg)?)
+            }
+            NL80211_MESHCONF_MAX_PEER_LINKS => {
+                let err_msg = format!(
+                    "Invalid NL80211_MESHCONF_MAX_PEER_LINKS value {:?}",
+                    payload
+                );
+                Self::MaxPeerLinks(parse_u16(payload).context(err_msg)?)
+            }
+            NL80211_MESHCONF_MAX_RETRIES => {
+                let err_msg = format!(
+                    "Invalid NL80211_MESHCONF_MAX_RETRIES value {:?}",
+                    payload
+                );
+                Self::MaxRetries(parse_u8(payload).context(err_msg)?)
+            }
+            NL80211_MESHCONF_TTL => {
+                let err_msg =
+                    format!("Invalid NL80211_MESHCONF_TTL value {:?}", payload);
+                Self::Ttl(parse_u8(payload).context(err_msg)?)
+            }
+            NL80211_MESHCONF_AUTO_OPEN_PLINKS => {
+                let err_msg = format!(
+                    "Invalid NL80211_MESHCONF_AUTO_OPEN_PLINKS value {:?}",
+                    payload
+                );
+                Self::AutoOpenPlinks(parse_u8(payload).context(err_msg)? > 0)
+            }
+            NL80211_MESHCONF_HWMP_MAX_PREQ_RETRIES => {
+                let err_msg = format!(
+                    "Invalid NL80211_MESHCONF_HWMP_MAX_PREQ_RETRIES value {:?}",
+                    payload
+                );
+                Self::HwmpMaxPreqRetries(parse_u8(payload).context(err_msg)?)
+            }
+            NL80211_MESHCONF_PATH_REFRESH_TIME => {
+                let err_msg = format!(
+                    "Invalid NL80211_MESHCONF_PATH_REFRESH_TIME value {:?}",
+                    payload
+                );
+                Self::PathRefreshTime(parse_u32(payload).context(err_msg)?)
+            }
+            NL80211_MESHCONF_MIN_DISCOVERY_TIMEOUT => {
+                let err_msg = format!(
+                    "Invalid NL80211_MESHCONF_MIN_DISCOVERY_TIMEOUT value {:?}",
+                    payload
+                );
+                Self::MinDiscoveryTimeout(parse_u16(payload).context(err_msg)?)
+            }
+            NL80211_MESHCONF_HWMP_ACTIVE_PATH_TIMEOUT => {
+                let err_msg = format!(
+                    "Invalid NL80211_MESHCONF_HWMP_ACTIVE_PATH_TIMEOUT \
+                     value {:?}",
+                    payload
+                );
+                Self::HwmpActivePathTimeout(
+                    parse_u32(payload).context(err_msg)?,
+                )
+            }
+            NL80211_MESHCONF_HWMP_PREQ_MIN_INTERVAL => {
+                let err_msg = format!(
+                    "Invalid NL80211_MESHCONF_HWMP_PREQ_MIN_INTERVAL \
+                     value {:?}",
+                    payload
+                );
+                Self::HwmpPreqMinInterval(parse_u16(payload).context(err_msg)?)
+            }
+            NL80211_MESHCONF_HWMP_NET_DIAM_TRVS_TIME => {
+                let err_msg = format!(
+                    "Invalid NL80211_MESHCONF_HWMP_NET_DIAM_TRVS_TIME \
+                     value {:?}",
+                    payload
+                );
+                Self::HwmpNetDiamTrvsTime(parse_u16(payload).context(err_msg)?)
+            }
+            NL80211_MESHCONF_HWMP_ROOTMODE => {
+                let err_msg = format!(
+                    "Invalid NL80211_MESHCONF_HWMP_ROOTMODE value {:?}",
+                    payload
+                );
+                Self::HwmpRootmode(parse_u8(payload).context(err_msg)?)
+            }
+            NL80211_MESHCONF_ELEMENT_TTL => {
+                let err_msg = format!(
+                    "Invalid NL80211_MESHCONF_ELEMENT_TTL value {:?}",
+                    payload
+                );
+                Self::ElementTtl(parse_u8(payload).context(err_msg)?)
+            }
+            NL80211_MESHCONF_HWMP_RANN_INTERVAL => {
+                let err_msg = format!(
+                    "Invalid NL80211_MESHCONF_HWMP_RANN_INTERVAL value {:?}",
+                    payload
+                );
+                Self::HwmpRannInterval(parse_u16(payload).context(err_msg)?)
+            }
+            NL80211_MESHCONF_GATE_ANNOUNCEMENTS => {
+                let err_msg = format!(
+                    "Invalid NL80211_MESHCONF_GATE_ANNOUNCEMENTS value {:?}",
+                    payload
+                );
+                Self::GateAnnouncements(parse_u8(payload).context(err_msg)? > 0)
+            }
+            NL80211_MESHCONF_HWMP_PERR_MIN_INTERVAL => {
+                let err_msg = format!(
+                    "Invalid NL80211_MESHCONF_HWMP_PERR_MIN_INTERVAL \
+                     value {:?}",
+                    payload
+                );
+                Self::HwmpPerrMinInterval(parse_u16(payload).context(err_msg)?)
+            }
+            NL80211_MESHCONF_FORWARDING => {
+                let err_msg = format!(
+                    "Invalid NL80211_MESHCONF_FORWARDING value {:?}",
+                    payload
+                );
+                Self::Forwarding(parse_u8(payload).context(err_msg)? > 0)
+            }
+            NL80211_MESHCONF_RSSI_THRESHOLD => {
+                let err_msg = format!(
+                    "Invalid NL80211_MESHCONF_RSSI_THRESHOLD value {:?}",
+                    payload
+                );
+                Self::RssiThreshold(parse_i32(payload).context(err_msg)?)
+            }
+            NL80211_MESHCONF_SYNC_OFFSET_MAX_NEIGHBOR => {
+                let err_msg = format!(
+                    "Invalid NL80211_MESHCONF_SYNC_OFFSET_MAX_NEIGHBOR \
+                     value {:?}",
+                    payload
+                );
+                Self::SyncOffsetMaxNeighbor(
+                    parse_u32(payload).context(err_msg)?,
+                )
+            }
+            NL80211_MESHCONF_HT_OPMODE => {
+                let err_msg = format!(
+                    "Invalid NL80211_MESHCONF_HT_OPMODE value {:?}",
+                    payload
+                );
+                Self::HtOpmode(parse_u16(payload).context(err_msg)?)
+            }
+            NL80211_MESHCONF_HWMP_PATH_TO_ROOT_TIMEOUT => {
+                let err_msg = format!(
+                    "Invalid NL80211_MESHCONF_HWMP_PATH_TO_ROOT_TIMEOUT \
+                     value {:?}",
+                    payload
+                );
+                Self::HwmpPathToRootTimeout(
+                    parse_u32(payload).context(err_msg)?,
+                )
+            }
+            NL80211_MESHCONF_HWMP_ROOT_INTERVAL => {
+                let err_msg = format!(
+                    "Invalid NL80211_MESHCONF_HWMP_ROOT_INTERVAL value {:?}",
+                    payload
+                );
+                Self::HwmpRootInterval(parse_u16(payload).context(err_msg)?)
+            }
+            NL80211_MESHCONF_HWMP_CONFIRMATION_INTERVAL => {
+                let err_msg = format!(
+                    "Invalid NL80211_MESHCONF_HWMP_CONFIRMATION_INTERVAL \
+                     value {:?}",
+                    payload
+                );
+                Self::HwmpConfirmationInterval(
+                    parse_u16(payload).context(err_msg)?,
+                )
+            }
+            NL80211_MESHCONF_POWER_MODE => {
+                let err_msg = format!(
+                    "Invalid NL80211_MESHCONF_POWER_MODE value {:?}",
+                    payload
+                );
+                Self::PowerMode(parse_u32(payload).context(err_msg)?.into())
+            }
+            NL80211_MESHCONF_AWAKE_WINDOW => {
+                let err_msg = format!(
+                    "Invalid NL80211_MESHCONF_AWAKE_WINDOW value {:?}",
+                    payload
+                );
+                Self::AwakeWindow(parse_u16(payload).context(err_msg)?)
+            }
+            NL80211_MESHCONF_PLINK_TIMEOUT => {
+                let err_msg = format!(
+                    "Invalid NL80211_MESHCONF_PLINK_TIMEOUT value {:?}",
+                    payload
+                );
+                Self::PlinkTimeout(parse_u32(payload).context(err_msg)?)
+            }
+            NL80211_MESHCONF_CONNECTED_TO_GATE => {
+                let err_msg = format!(
+                    "Invalid NL80211_MESHCONF_CONNECTED_TO_GATE value {:?}",
+                    payload
+                );
+                Self::ConnectedToGate(parse_u8(payload).context(err_msg)? > 0)
+            }
+            NL80211_MESHCONF_NOLEARN => {
+                let err_msg = format!(
+                    "Invalid NL80211_MESHCONF_NOLEARN value {:?}",
+                    payload
+                );
+                Self::Nolearn(parse_u8(payload).context(err_msg)? > 0)
+            }
+            NL80211_MESHCONF_CONNECTED_TO_AS => {
+                let err_msg = format!(
+                    "Invalid NL80211_MESHCONF_CONNECTED_TO_AS value {:?}",
+                    payload
+                );
+                Self::ConnectedToAs(parse_u8(payload).context(err_msg)? > 0)
+            }
+            _ => Self::Other(
+                DefaultNla::parse(buf).context("invalid NLA (unknown kind)")?,
+            ),
+        })
+    }
+}
+
+/// Mesh configuration parameters, these can be changed while the mesh is
+/// active. Only the parameters set are changed.
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct Nl80211MeshConfig {
+    /// Initial retry timeout of Peer Link Open in milliseconds
+    pub retry_timeout: Option<u16>,
+    /// Initial confirm timeout of peer link management in milliseconds
+    pub confirm_timeout: Option<u16>,
+    /// Holding timeout of peer link management in milliseconds
+    pub holding_timeout: Option<u16>,
+    /// Maximum number of peer links
+    pub max_peer_links: Option<u16>,
+    /// Maximum number of Peer Link Open retries
+    pub max_retries: Option<u8>,
+    /// TTL of frames originated by this mesh STA
+    pub ttl: Option<u8>,
+    /// Automatically open peer links with compatible mesh peers
+    pub auto_open_plinks: Option<bool>,
+    /// Number of PREQ frames sent to a path target before giving up
+    pub hwmp_max_preq_retries: Option<u8>,
+    /// Interval of mesh path refreshes in milliseconds
+    pub path_refresh_time: Option<u32>,
+    /// Minimum path discovery timeout in milliseconds
+    pub min_discovery_timeout: Option<u16>,
+    /// Lifetime of forwarding information learned from a PREQ in TUs
+    pub hwmp_active_path_timeout: Option<u32>,
+    /// Minimum interval between PREQ frames in TUs
+    pub hwmp_preq_min_interval: Option<u16>,
+    /// Time for an HWMP element to propagate across the mesh in TUs
+    pub hwmp_net_diam_trvs_time: Option<u16>,
+    /// HWMP root mode, 0 when this mesh STA is not a root
+    pub hwmp_rootmode: Option<u8>,
+    /// TTL of path selection elements originated by this mesh STA
+    pub element_ttl: Option<u8>,
+    /// Interval between Root Announcements in TUs
+    pub hwmp_rann_interval: Option<u16>,
+    /// Advertise access to a network beyond the mesh in Root Announcements
+    pub gate_announcements: Option<bool>,
+    /// Minimum interval between PERR frames in TUs
+    pub hwmp_perr_min_interval: Option<u16>,
+    /// Forward frames of other mesh STAs
+    pub forwarding: Option<bool>,
+    /// Minimum average signal strength in dBm of a candidate peer
+    pub rssi_threshold: Option<i32>,
+    /// Maximum number of neighbors to synchronize to
+    pub sync_offset_max_neighbor: Option<u32>,
+    /// HT protection mode
+    pub ht_opmode: Option<u16>,
+    /// Lifetime of forwarding information learned from a proactive PREQ in
+    /// TUs
+    pub hwmp_path_to_root_timeout: Option<u32>,
+    /// Interval between proactive PREQ frames in TUs
+    pub hwmp_root_interval: Option<u16>,
+    /// Minimum interval between PREQ frames for root path confirmation in TUs
+    pub hwmp_confirmation_interval: Option<u16>,
+    /// Default mesh power mode of new peer links
+    pub power_mode: Option<Nl80211MeshPowerMode>,
+    /// Awake window duration in TUs
+    pub awake_window: Option<u16>,
+    /// Inactivity timeout of peer links in seconds, 0 to never remove inactive
+    /// peers
+    pub plink_timeout: Option<u32>,
+    /// Advertise a connection to a mesh gate in the mesh formation field
+    pub connected_to_gate: Option<bool>,
+    /// Avoid multi-hop path discovery when the destination is a neighbor
+    pub nolearn: Option<bool>,
+    /// Advertise a connection to an authentication server in the mesh formation
+    /// field
+    pub connected_to_as: Option<bool>,
+}
+
+impl Emitable for Nl80211MeshConfig {
+    fn buffer_len(&self) -> usize {
+        Vec::<Nl80211MeshConfigNla>::from(self)
+            .as_slice()
+            .buffer_len()
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        Vec::<Nl80211MeshConfigNla>::from(self)
+            .as_slice()
+            .emit(buffer)
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for Nl80211MeshConfig
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let mut ret = Self::default();
+        let payload = buf.value();
+        let err_msg =
+            format!("Invalid NL80211_ATTR_MESH_CONFIG value {:?}", payload);
+        for nla in NlasIterator::new(payload) {
+            let nla = &nla.context(err_msg.clone())?;
+            match Nl80211MeshConfigNla::parse(nla).context(err_msg.clone())? {
+                Nl80211MeshConfigNla::RetryTimeout(d) => {
+                    ret.retry_timeout = Some(d)
+                }
+                Nl80211MeshConfigNla::ConfirmTimeout(d) => {
+                    ret.confirm_timeout = Some(d)
+                }
+                Nl80211MeshConfigNla::HoldingTimeout(d) => {
+                    ret.holding_timeout = Some(d)
+                }
+                Nl80211MeshConfigNla::MaxPeerLinks(d) => {
+                    ret.max_peer_links = Some(d)
+                }
+                Nl80211MeshConfigNla::MaxRetries(d) => {
+                    ret.max_retries = Some(d)
+                }
+                Nl80211MeshConfigNla::Ttl(d) => ret.ttl = Some(d),
+                Nl80211MeshConfigNla::AutoOpenPlinks(d) => {
+                    ret.auto_open_plinks = Some(d)
+                }
+                Nl80211MeshConfigNla::HwmpMaxPreqRetries(d) => {
+                    ret.hwmp_max_preq_retries = Some(d)
+                }
+                Nl80211MeshConfigNla::PathRefreshTime(d) => {
+                    ret.path_refresh_time = Some(d)
+                }
+                Nl80211MeshConfigNla::MinDiscoveryTimeout(d) => {
+                    ret.min_discovery_timeout = Some(d)
+                }
+                Nl80211MeshConfigNla::HwmpActivePathTimeout(d) => {
+                    ret.hwmp_active_path_timeout = Some(d)
+                }
+                Nl80211MeshConfigNla::HwmpPreqMinInterval(d) => {
+                    ret.hwmp_preq_min_interval = Some(d)
+                }
+                Nl80211MeshConfigNla::HwmpNetDiamTrvsTime(d) => {
+                    ret.hwmp_net_diam_trvs_time = Some(d)
+                }
+                Nl80211MeshConfigNla::HwmpRootmode(d) => {
+                    ret.hwmp_rootmode = Some(d)
+                }
+                Nl80211MeshConfigNla::ElementTtl(d) => {
+                    ret.element_ttl = Some(d)
+                }
+                Nl80211MeshConfigNla::HwmpRannInterval(d) => {
+                    ret.hwmp_rann_interval = Some(d)
+                }
+                Nl80211MeshConfigNla::GateAnnouncements(d) => {
+                    ret.gate_announcements = Some(d)
+                }
+                Nl80211MeshConfigNla::HwmpPerrMinInterval(d) => {
+                    ret.hwmp_perr_min_interval = Some(d)
+                }
+                Nl80211MeshConfigNla::Forwarding(d) => ret.forwarding = Some(d),
+                Nl80211MeshConfigNla::RssiThreshold(d) => {
+                    ret.rssi_threshold = Some(d)
+                }
+                Nl80211MeshConfigNla::SyncOffsetMaxNeighbor(d) => {
+                    ret.sync_offset_max_neighbor = Some(d)
+                }
+                Nl80211MeshConfigNla::HtOpmode(d) => ret.ht_opmode = Some(d),
+                Nl80211MeshConfigNla::HwmpPathToRootTimeout(d) => {
+                    ret.hwmp_path_to_root_timeout = Some(d)
+                }
+                Nl80211MeshConfigNla::HwmpRootInterval(d) => {
+                    ret.hwmp_root_interval = Some(d)
+                }
+                Nl80211MeshConfigNla::HwmpConfirmationInterval(d) => {
+                    ret.hwmp_confirmation_interval = Some(d)
+                }
+                Nl80211MeshConfigNla::PowerMode(d) => ret.power_mode = Some(d),
+                Nl80211MeshConfigNla::AwakeWindow(d) => {
+                    ret.awake_window = Some(d)
+                }
+                Nl80211MeshConfigNla::PlinkTimeout(d) => {
+                    ret.plink_timeout = Some(d)
+                }
+                Nl80211MeshConfigNla::ConnectedToGate(d) => {
+                    ret.connected_to_gate = Some(d)
+                }
+                Nl80211MeshConfigNla::Nolearn(d) => ret.nolearn = Some(d),
+                Nl80211MeshConfigNla::ConnectedToAs(d) => {
+                    ret.connected_to_as = Some(d)
+                }
+                Nl80211MeshConfigNla::Other(attr) => {
+                    log::warn!(
+                        "Got unsupported NL80211_ATTR_MESH_CONFIG value {:?}",
+                        attr
+                    )
+                }
+            }
+        }
+        Ok(ret)
+    }
+}
+
+impl From<&Nl80211MeshConfig> for Vec<Nl80211MeshConfigNla> {
+    fn from(config: &Nl80211MeshConfig) -> Self {
+        let mut nlas = Vec::new();
+        if let Some(v) = config.retry_timeout {
+            nlas.push(Nl80211MeshConfigNla::RetryTimeout(v));
+        }
+        if let Some(v) = config.confirm_timeout {
+            nlas.push(Nl80211MeshConfigNla::ConfirmTimeout(v));
+        }
+        if let Some(v) = config.holding_timeout {
+            nlas.push(Nl80211MeshConfigNla::HoldingTimeout(v));
+        }
+        if let Some(v) = config.max_peer_links {
+            nlas.push(Nl80211MeshConfigNla::MaxPeerLinks(v));
+        }
+        if let Some(v) = config.max_retries {
+            nlas.push(Nl80211MeshConfigNla::MaxRetries(v));
+        }
+        if let Some(v) = config.ttl {
+            nlas.push(Nl80211MeshConfigNla::Ttl(v));
+        }
+        if let Some(v) = config.auto_open_plinks {
+            nlas.push(Nl80211MeshConfigNla::AutoOpenPlinks(v));
+        }
+        if let Some(v) = config.hwmp_max_preq_retries {
+            nlas.push(Nl80211MeshConfigNla::HwmpMaxPreqRetries(v));
+        }
+        if let Some(v) = config.path_refresh_time {
+            nlas.push(Nl80211MeshConfigNla::PathRefreshTime(v));
+        }
+        if let Some(v) = config.min_discovery_timeout {
+            nlas.push(Nl80211MeshConfigNla::MinDiscoveryTimeout(v));
+        }
+        if let Some(v) = config.hwmp_active_path_timeout {
+            nlas.push(Nl80211MeshConfigNla::HwmpActivePathTimeout(v));
+        }
+        if let Some(v) = config.hwmp_preq_min_interval {
+            nlas.push(Nl80211MeshConfigNla::HwmpPreqMinInterval(v));
+        }
+        if let Some(v) = config.hwmp_net_diam_trvs_time {
+            nlas.push(Nl80211MeshConfigNla::HwmpNetDiamTrvsTime(v));
+        }
+        if let Some(v) = config.hwmp_rootmode {
+            nlas.push(Nl80211MeshConfigNla::HwmpRootmode(v));
+        }
+        if let Some(v) = config.element_ttl {
+            nlas.push(Nl80211MeshConfigNla::ElementTtl(v));
+        }
+        if let Some(v) = config.hwmp_rann_interval {
+            nlas.push(Nl80211MeshConfigNla::HwmpRannInterval(v));
+        }
+        if let Some(v) = config.gate_announcements {
+            nlas.push(Nl80211MeshConfigNla::GateAnnouncements(v));
+        }
+        if let Some(v) = config.hwmp_perr_min_interval {
+            nlas.push(Nl80211MeshConfigNla::HwmpPerrMinInterval(v));
+        }
+        if let Some(v) = config.forwarding {
+            nlas.push(Nl80211MeshConfigNla::Forwarding(v));
+        }
+        if let Some(v) = config.rssi_threshold {
+            nlas.push(Nl80211MeshConfigNla::RssiThreshold(v));
+        }
+        if let Some(v) = config.sync_offset_max_neighbor {
+            nlas.push(Nl80211MeshConfigNla::SyncOffsetMaxNeighbor(v));
+        }
+        if let Some(v) = config.ht_opmode {
+            nlas.push(Nl80211MeshConfigNla::HtOpmode(v));
+        }
+        if let Some(v) = config.hwmp_path_to_root_timeout {
+            nlas.push(Nl80211MeshConfigNla::HwmpPathToRootTimeout(v));
+        }
+        if let Some(v) = config.hwmp_root_interval {
+            nlas.push(Nl80211MeshConfigNla::HwmpRootInterval(v));
+        }
+        if let Some(v) = config.hwmp_confirmation_interval {
+            nlas.push(Nl80211MeshConfigNla::HwmpConfirmationInterval(v));
+        }
+        if let Some(v) = config.power_mode {
+            nlas.push(Nl80211MeshConfigNla::PowerMode(v));
+        }
+        if let Some(v) = config.awake_window {
+            nlas.push(Nl80211MeshConfigNla::AwakeWindow(v));
+        }
+        if let Some(v) = config.plink_timeout {
+            nlas.push(Nl80211MeshConfigNla::PlinkTimeout(v));
+        }
+        if let Some(v) = config.connected_to_gate {
+            nlas.push(Nl80211MeshConfigNla::ConnectedToGate(v));
+        }
+        if let Some(v) = config.nolearn {
+            nlas.push(Nl80211MeshConfigNla::Nolearn(v));
+        }
+        if let Some(v) = config.connected_to_as {
+            nlas.push(Nl80211MeshConfigNla::ConnectedToAs(v));
+        }
+        nlas
+    }
+}
@@ -0,0 +1,39 @@
+// SPDX-License-Identifier: MIT
+
+use futures::TryStream;
+use netlink_packet_core::NLM_F_REQUEST;
+use netlink_packet_generic::GenlMessage;
+
+use crate::{
+    nl80211_execute, Nl80211Attr, Nl80211Cmd, Nl80211Error, Nl80211Handle,
+    Nl80211Message,
+};
+
+pub struct Nl80211MeshConfigGetRequest {
+    handle: Nl80211Handle,
+    if_index: u32,
+}
+
+impl Nl80211MeshConfigGetRequest {
+    pub(crate) fn new(handle: Nl80211Handle, if_index: u32) -> Self {
+        Nl80211MeshConfigGetRequest { handle, if_index }
+    }
+
+    /// The reply holds `Nl80211Attr::MeshConfig` with all the parameters,
+    /// the kernel fails the request when the interface is not in a mesh.
+    pub async fn execute(
+        self,
+    ) -> impl TryStream<Ok = GenlMessage<Nl80211Message>, Error = Nl80211Error>
+    {
+        let Nl80211MeshConfigGetRequest {
+            mut handle,
+            if_index,
+        } = self;
+
+        let nl80211_msg = Nl80211Message {
+            cmd: Nl80211Cmd::MeshConfigGet,
+            nlas: vec![Nl80211Attr::IfIndex(if_index)],
+        };
+        nl80211_execute(&mut handle, nl80211_msg, NLM_F_REQUEST).await
+    }
+}
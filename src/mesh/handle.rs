@@ -0,0 +1,104 @@
+// SPDX-License-Identifier: MIT
+
+use crate::{
+    Nl80211Handle, Nl80211MeshConfig, Nl80211MeshConfigGetRequest,
+    Nl80211MeshConfigSetRequest, Nl80211MeshJoinRequest,
+    Nl80211MeshLeaveRequest, Nl80211MeshPathDelRequest,
+    Nl80211MeshPathGetRequest, Nl80211MeshPathNewRequest,
+    Nl80211MeshPathSetRequest, Nl80211MeshProxyPathGetRequest,
+};
+
+pub struct Nl80211MeshHandle(Nl80211Handle);
+
+impl Nl80211MeshHandle {
+    pub fn new(handle: Nl80211Handle) -> Self {
+        Nl80211MeshHandle(handle)
+    }
+
+    /// Join the mesh with specified mesh ID on specified mesh point
+    /// interface
+    /// (equivalent to `iw dev DEVICE mesh join MESH_ID`)
+    pub fn join(
+        &mut self,
+        if_index: u32,
+        mesh_id: &str,
+    ) -> Nl80211MeshJoinRequest {
+        Nl80211MeshJoinRequest::new(self.0.clone(), if_index, mesh_id)
+    }
+
+    /// Leave the mesh of specified interface
+    /// (equivalent to `iw dev DEVICE mesh leave`)
+    pub fn leave(&mut self, if_index: u32) -> Nl80211MeshLeaveRequest {
+        Nl80211MeshLeaveRequest::new(self.0.clone(), if_index)
+    }
+
+    /// Retrieve the mesh configuration of specified interface
+    /// (equivalent to `iw dev DEVICE get mesh_param`)
+    pub fn get_config(&mut self, if_index: u32) -> Nl80211MeshConfigGetRequest {
+        Nl80211MeshConfigGetRequest::new(self.0.clone(), if_index)
+    }
+
+    /// Change the mesh configuration of specified interface, only the
+    /// parameters set in `config` are changed
+    /// (equivalent to `iw dev DEVICE set mesh_param PARAM VALUE`)
+    pub fn set_config(
+        &mut self,
+        if_index: u32,
+        config: Nl80211MeshConfig,
+    ) -> Nl80211MeshConfigSetRequest {
+        Nl80211MeshConfigSetRequest::new(self.0.clone(), if_index, config)
+    }
+
+    /// Retrieve the mesh paths of specified interface
+    /// (equivalent to `iw dev DEVICE mpath dump`)
+    pub fn path_dump(&mut self, if_index: u32) -> Nl80211MeshPathGetRequest {
+        Nl80211MeshPathGetRequest::new(self.0.clone(), if_index)
+    }
+
+    /// Add a mesh path to the destination through specified next hop
+    /// (equivalent to `iw dev DEVICE mpath new DST next_hop NEXT_HOP`)
+    pub fn path_new(
+        &mut self,
+        if_index: u32,
+        dst: [u8; 6],
+        next_hop: [u8; 6],
+    ) -> Nl80211MeshPathNewRequest {
+        Nl80211MeshPathNewRequest::new(self.0.clone(), if_index, dst, next_hop)
+    }
+
+    /// Change the next hop of the mesh path to the destination
+    /// (equivalent to `iw dev DEVICE mpath set DST next_hop NEXT_HOP`)
+    pub fn path_set(
+        &mut self,
+        if_index: u32,
+        dst: [u8; 6],
+        next_hop: [u8; 6],
+    ) -> Nl80211MeshPathSetRequest {
+        Nl80211MeshPathSetRequest::new(self.0.clone(), if_index, dst, next_hop)
+    }
+
+    /// Delete the mesh path to the destination
+    /// (equivalent to `iw dev DEVICE mpath del DST`)
+    pub fn path_del(
+        &mut self,
+        if_index: u32,
+        dst: [u8; 6],
+    ) -> Nl80211MeshPathDelRequest {
+        Nl80211MeshPathDelRequest::new(self.0.clone(), if_index, Some(dst))
+    }
+
+    /// Delete all the mesh paths of specified interface
+    pub fn path_flush(&mut self, if_index: u32) -> Nl80211MeshPathDelRequest {
+        Nl80211MeshPathDelRequest::new(self.0.clone(), if_index, None)
+    }
+
+    /// Retrieve the destinations outside the mesh and the mesh gates
+    /// proxying them
+    /// (equivalent to `iw dev DEVICE mpp dump`)
+    pub fn proxy_path_dump(
+        &mut self,
+        if_index: u32,
+    ) -> Nl80211MeshProxyPathGetRequest {
+        Nl80211MeshProxyPathGetRequest::new(self.0.clone(), if_index)
+    }
+}
@@ -0,0 +1,143 @@
+// SPDX-License-Identifier: MIT
+
+use crate::{
+    nl80211_execute_ack, Nl80211Attr, Nl80211ChannelDef, Nl80211Cmd,
+    Nl80211Error, Nl80211Handle, Nl80211MeshConfig, Nl80211MeshSetup,
+    Nl80211Message, Nl80211TxRates,
+};
+
+pub struct Nl80211MeshJoinRequest {
+    handle: Nl80211Handle,
+    if_index: u32,
+    mesh_id: String,
+    chandef: Option<Nl80211ChannelDef>,
+    basic_rates: Option<Vec<u8>>,
+    mcast_rate: Option<u32>,
+    beacon_interval: Option<u32>,
+    dtim_period: Option<u32>,
+    setup: Option<Nl80211MeshSetup>,
+    config: Option<Nl80211MeshConfig>,
+    beacon_tx_rate: Option<Nl80211TxRates>,
+}
+
+impl Nl80211MeshJoinRequest {
+    pub(crate) fn new(
+        handle: Nl80211Handle,
+        if_index: u32,
+        mesh_id: &str,
+    ) -> Self {
+        Nl80211MeshJoinRequest {
+            handle,
+            if_index,
+            mesh_id: mesh_id.to_string(),
+            chandef: None,
+            basic_rates: None,
+            mcast_rate: None,
+            beacon_interval: None,
+            dtim_period: None,
+            setup: None,
+            config: None,
+            beacon_tx_rate: None,
+        }
+    }
+
+    /// Channel of the mesh, the kernel picks one when not set
+    pub fn chandef(mut self, chandef: Nl80211ChannelDef) -> Self {
+        self.chandef = Some(chandef);
+        self
+    }
+
+    /// Basic rates in units of 500 kbps
+    pub fn basic_rates(mut self, rates: Vec<u8>) -> Self {
+        self.basic_rates = Some(rates);
+        self
+    }
+
+    /// Multicast transmit rate in units of 100 kbps
+    pub fn mcast_rate(mut self, rate: u32) -> Self {
+        self.mcast_rate = Some(rate);
+        self
+    }
+
+    /// Beacon interval in TUs
+    pub fn beacon_interval(mut self, interval: u32) -> Self {
+        self.beacon_interval = Some(interval);
+        self
+    }
+
+    /// DTIM period in beacon intervals
+    pub fn dtim_period(mut self, period: u32) -> Self {
+        self.dtim_period = Some(period);
+        self
+    }
+
+    /// Parameters which cannot be changed once the mesh is joined
+    pub fn setup(mut self, setup: Nl80211MeshSetup) -> Self {
+        self.setup = Some(setup);
+        self
+    }
+
+    /// Initial mesh configuration
+    pub fn config(mut self, config: Nl80211MeshConfig) -> Self {
+        self.config = Some(config);
+        self
+    }
+
+    /// Rate of the beacons, the rates of the band should be restricted to a
+    /// single rate. Requires the `Nl80211ExtFeature::BeaconRate*` feature of
+    /// the rate type, e.g. `BeaconRateHt` for an HT MCS.
+    pub fn beacon_tx_rate(mut self, rates: Nl80211TxRates) -> Self {
+        self.beacon_tx_rate = Some(rates);
+        self
+    }
+
+    pub async fn execute(self) -> Result<(), Nl80211Error> {
+        let Nl80211MeshJoinRequest {
+            mut handle,
+            if_index,
+            mesh_id,
+            chandef,
+            basic_rates,
+            mcast_rate,
+            beacon_interval,
+            dtim_period,
+            setup,
+            config,
+            beacon_tx_rate,
+        } = self;
+
+        let mut nlas =
+            vec![Nl80211Attr::IfIndex(if_index), Nl80211Attr::MeshId(mesh_id)];
+        if let Some(chandef) = chandef {
+            chandef.validate()?;
+            nlas.extend(Vec::<Nl80211Attr>::from(&chandef));
+        }
+        if let Some(rates) = basic_rates {
+            nlas.push(Nl80211Attr::BssBasicRates(rates));
+        }
+        if let Some(rate) = mcast_rate {
+            nlas.push(Nl80211Attr::McastRate(rate));
+        }
+        if let Some(interval) = beacon_interval {
+            nlas.push(Nl80211Attr::BeaconInterval(interval));
+        }
+        if let Some(period) = dtim_period {
+            nlas.push(Nl80211Attr::DtimPeriod(period));
+        }
+        if let Some(setup) = setup {
+            nlas.push(Nl80211Attr::MeshSetup(setup));
+        }
+        if let Some(config) = config {
+            nlas.push(Nl80211Attr::MeshConfig(config));
+        }
+        if let Some(rates) = beacon_tx_rate {
+            nlas.push(Nl80211Attr::TxRates(vec![rates]));
+        }
+
+        let nl80211_msg = Nl80211Message {
+            cmd: Nl80211Cmd::JoinMesh,
+            nlas,
+        };
+        nl80211_execute_ack(&mut handle, nl80211_msg).await
+    }
+}
@@ -0,0 +1,33 @@
+// SPDX-License-Identifier: MIT
+
+mod config;
+mod get_config;
+mod handle;
+mod join;
+mod leave;
+mod mpath_flags;
+mod mpath_info;
+mod path_del;
+mod path_get;
+mod path_new;
+mod path_set;
+mod power_mode;
+mod proxy_path_get;
+mod set_config;
+mod setup;
+
+pub use config::{Nl80211MeshConfig, Nl80211MeshConfigNla};
+pub use get_config::Nl80211MeshConfigGetRequest;
+pub use handle::Nl80211MeshHandle;
+pub use join::Nl80211MeshJoinRequest;
+pub use leave::Nl80211MeshLeaveRequest;
+pub use mpath_flags::Nl80211MpathFlags;
+pub use mpath_info::{Nl80211MpathInfo, Nl80211MpathInfoNla};
+pub use path_del::Nl80211MeshPathDelRequest;
+pub use path_get::Nl80211MeshPathGetRequest;
+pub use path_new::Nl80211MeshPathNewRequest;
+pub use path_set::Nl80211MeshPathSetRequest;
+pub use power_mode::Nl80211MeshPowerMode;
+pub use proxy_path_get::Nl80211MeshProxyPathGetRequest;
+pub use set_config::Nl80211MeshConfigSetRequest;
+pub use setup::{Nl80211MeshSetup, Nl80211MeshSetupNla};
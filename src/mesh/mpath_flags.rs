@@ -0,0 +1,19 @@
+// SPDX-License-Identifier: MIT
+
+use crate::macros::nl80211_bitflags;
+
+nl80211_bitflags! {
+    /// Mesh path flags, could be combined via `|` operator.
+    pub struct Nl80211MpathFlags: u8 {
+        /// The mesh path is active
+        const ACTIVE = 1 << 0;
+        /// The mesh path discovery process is running
+        const RESOLVING = 1 << 1;
+        /// The mesh path contains a valid sequence number
+        const SN_VALID = 1 << 2;
+        /// The mesh path has been manually set
+        const FIXED = 1 << 3;
+        /// The mesh path discovery process succeeded
+        const RESOLVED = 1 << 4;
+    }
+}
@@ -0,0 +1,271 @@
+// SPDX-License-Identifier: MIT
+
+use anyhow::Context;
+use byteorder::{ByteOrder, NativeEndian};
+use netlink_packet_utils::{
+    nla::{DefaultNla, Nla, NlaBuffer, NlasIterator},
+    parsers::{parse_u32, parse_u8},
+    DecodeError, Emitable, Parseable,
+};
+
+use crate::Nl80211MpathFlags;
+
+const NL80211_MPATH_INFO_FRAME_QLEN: u16 = 1;
+const NL80211_MPATH_INFO_SN: u16 = 2;
+const NL80211_MPATH_INFO_METRIC: u16 = 3;
+const NL80211_MPATH_INFO_EXPTIME: u16 = 4;
+const NL80211_MPATH_INFO_FLAGS: u16 = 5;
+const NL80211_MPATH_INFO_DISCOVERY_TIMEOUT: u16 = 6;
+const NL80211_MPATH_INFO_DISCOVERY_RETRIES: u16 = 7;
+const NL80211_MPATH_INFO_HOP_COUNT: u16 = 8;
+const NL80211_MPATH_INFO_PATH_CHANGE: u16 = 9;
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum Nl80211MpathInfoNla {
+    /// Number of frames queued for this destination
+    FrameQlen(u32),
+    /// Destination sequence number
+    Sn(u32),
+    /// Metric of the path
+    Metric(u32),
+    /// Milliseconds until the path expires
+    Exptime(u32),
+    /// Mesh path flags
+    Flags(Nl80211MpathFlags),
+    /// Total path discovery timeout in milliseconds
+    DiscoveryTimeout(u32),
+    /// Number of path discovery retries
+    DiscoveryRetries(u8),
+    /// Number of hops to the destination
+    HopCount(u8),
+    /// Number of times the path changed
+    PathChange(u32),
+    Other(DefaultNla),
+}
+
+impl Nla for Nl80211MpathInfoNla {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::Flags(_) | Self::DiscoveryRetries(_) | Self::HopCount(_) => 1,
+            Self::FrameQlen(_)
+            | Self::Sn(_)
+            | Self::Metric(_)
+            | Self::Exptime(_)
+            | Self::DiscoveryTimeout(_)
+            | Self::PathChange(_) => 4,
+            Self::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::FrameQlen(_) => NL80211_MPATH_INFO_FRAME_QLEN,
+            Self::Sn(_) => NL80211_MPATH_INFO_SN,
+            Self::Metric(_) => NL80211_MPATH_INFO_METRIC,
+            Self::Exptime(_) => NL80211_MPATH_INFO_EXPTIME,
+            Self::Flags(_) => NL80211_MPATH_INFO_FLAGS,
+            Self::DiscoveryTimeout(_) => NL80211_MPATH_INFO_DISCOVERY_TIMEOUT,
+            Self::DiscoveryRetries(_) => NL80211_MPATH_INFO_DISCOVERY_RETRIES,
+            Self::HopCount(_) => NL80211_MPATH_INFO_HOP_COUNT,
+            Self::PathChange(_) => NL80211_MPATH_INFO_PATH_CHANGE,
+            Self::Other(attr) => attr.kind(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::DiscoveryRetries(d) | Self::HopCount(d) => buffer[0] = *d,
+            Self::FrameQlen(d)
+            | Self::Sn(d)
+            | Self::Metric(d)
+            | Self::Exptime(d)
+            | Self::DiscoveryTimeout(d)
+            | Self::PathChange(d) => NativeEndian::write_u32(buffer, *d),
+            Self::Flags(d) => buffer[0] = (*d).into(),
+            Self::Other(ref attr) => attr.emit(buffer),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for Nl80211MpathInfoNla
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            NL80211_MPATH_INFO_FRAME_QLEN => {
+                let err_msg = format!(
+                    "Invalid NL80211_MPATH_INFO_FRAME_QLEN value {:?}",
+                    payload
+                );
+                Self::FrameQlen(parse_u32(payload).context(err_msg)?)
+            }
+            NL80211_MPATH_INFO_SN => {
+                let err_msg = format!(
+                    "Invalid NL80211_MPATH_INFO_SN value {:?}",
+                    payload
+                );
+                Self::Sn(parse_u32(payload).context(err_msg)?)
+            }
+            NL80211_MPATH_INFO_METRIC => {
+                let err_msg = format!(
+                    "Invalid NL80211_MPATH_INFO_METRIC value {:?}",
+                    payload
+                );
+                Self::Metric(parse_u32(payload).context(err_msg)?)
+            }
+            NL80211_MPATH_INFO_EXPTIME => {
+                let err_msg = format!(
+                    "Invalid NL80211_MPATH_INFO_EXPTIME value {:?}",
+                    payload
+                );
+                Self::Exptime(parse_u32(payload).context(err_msg)?)
+            }
+            NL80211_MPATH_INFO_FLAGS => {
+                let err_msg = format!(
+                    "Invalid NL80211_MPATH_INFO_FLAGS value {:?}",
+                    payload
+                );
+                Self::Flags(parse_u8(payload).context(err_msg)?.into())
+            }
+            NL80211_MPATH_INFO_DISCOVERY_TIMEOUT => {
+                let err_msg = format!(
+                    "Invalid NL80211_MPATH_INFO_DISCOVERY_TIMEOUT value {:?}",
+                    payload
+                );
+                Self::DiscoveryTimeout(parse_u32(payload).context(err_msg)?)
+            }
+            NL80211_MPATH_INFO_DISCOVERY_RETRIES => {
+                let err_msg = format!(
+                    "Invalid NL80211_MPATH_INFO_DISCOVERY_RETRIES value {:?}",
+                    payload
+                );
+                Self::DiscoveryRetries(parse_u8(payload).context(err_msg)?)
+            }
+            NL80211_MPATH_INFO_HOP_COUNT => {
+                let err_msg = format!(
+                    "Invalid NL80211_MPATH_INFO_HOP_COUNT value {:?}",
+                    payload
+                );
+                Self::HopCount(parse_u8(payload).context(err_msg)?)
+            }
+            NL80211_MPATH_INFO_PATH_CHANGE => {
+                let err_msg = format!(
+                    "Invalid NL80211_MPATH_INFO_PATH_CHANGE value {:?}",
+                    payload
+                );
+                Self::PathChange(parse_u32(payload).context(err_msg)?)
+            }
+            _ => Self::Other(
+                DefaultNla::parse(buf).context("invalid NLA (unknown kind)")?,
+            ),
+        })
+    }
+}
+
+/// Mesh path information
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct Nl80211MpathInfo {
+    /// Number of frames queued for this destination
+    pub frame_qlen: Option<u32>,
+    /// Destination sequence number
+    pub sn: Option<u32>,
+    /// Metric of the path
+    pub metric: Option<u32>,
+    /// Milliseconds until the path expires
+    pub exptime: Option<u32>,
+    /// Mesh path flags
+    pub flags: Option<Nl80211MpathFlags>,
+    /// Total path discovery timeout in milliseconds
+    pub discovery_timeout: Option<u32>,
+    /// Number of path discovery retries
+    pub discovery_retries: Option<u8>,
+    /// Number of hops to the destination
+    pub hop_count: Option<u8>,
+    /// Number of times the path changed
+    pub path_change: Option<u32>,
+}
+
+impl Emitable for Nl80211MpathInfo {
+    fn buffer_len(&self) -> usize {
+        Vec::<Nl80211MpathInfoNla>::from(self)
+            .as_slice()
+            .buffer_len()
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        Vec::<Nl80211MpathInfoNla>::from(self)
+            .as_slice()
+            .emit(buffer)
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for Nl80211MpathInfo
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let mut ret = Self::default();
+        let payload = buf.value();
+        let err_msg =
+            format!("Invalid NL80211_ATTR_MPATH_INFO value {:?}", payload);
+        for nla in NlasIterator::new(payload) {
+            let nla = &nla.context(err_msg.clone())?;
+            match Nl80211MpathInfoNla::parse(nla).context(err_msg.clone())? {
+                Nl80211MpathInfoNla::FrameQlen(d) => ret.frame_qlen = Some(d),
+                Nl80211MpathInfoNla::Sn(d) => ret.sn = Some(d),
+                Nl80211MpathInfoNla::Metric(d) => ret.metric = Some(d),
+                Nl80211MpathInfoNla::Exptime(d) => ret.exptime = Some(d),
+                Nl80211MpathInfoNla::Flags(d) => ret.flags = Some(d),
+                Nl80211MpathInfoNla::DiscoveryTimeout(d) => {
+                    ret.discovery_timeout = Some(d)
+                }
+                Nl80211MpathInfoNla::DiscoveryRetries(d) => {
+                    ret.discovery_retries = Some(d)
+                }
+                Nl80211MpathInfoNla::HopCount(d) => ret.hop_count = Some(d),
+                Nl80211MpathInfoNla::PathChange(d) => ret.path_change = Some(d),
+                Nl80211MpathInfoNla::Other(attr) => {
+                    log::warn!(
+                        "Got unsupported NL80211_ATTR_MPATH_INFO value {:?}",
+                        attr
+                    )
+                }
+            }
+        }
+        Ok(ret)
+    }
+}
+
+impl From<&Nl80211MpathInfo> for Vec<Nl80211MpathInfoNla> {
+    fn from(info: &Nl80211MpathInfo) -> Self {
+        let mut nlas = Vec::new();
+        if let Some(v) = info.frame_qlen {
+            nlas.push(Nl80211MpathInfoNla::FrameQlen(v));
+        }
+        if let Some(v) = info.sn {
+            nlas.push(Nl80211MpathInfoNla::Sn(v));
+        }
+        if let Some(v) = info.metric {
+            nlas.push(Nl80211MpathInfoNla::Metric(v));
+        }
+        if let Some(v) = info.exptime {
+            nlas.push(Nl80211MpathInfoNla::Exptime(v));
+        }
+        if let Some(v) = info.flags {
+            nlas.push(Nl80211MpathInfoNla::Flags(v));
+        }
+        if let Some(v) = info.discovery_timeout {
+            nlas.push(Nl80211MpathInfoNla::DiscoveryTimeout(v));
+        }
+        if let Some(v) = info.discovery_retries {
+            nlas.push(Nl80211MpathInfoNla::DiscoveryRetries(v));
+        }
+        if let Some(v) = info.hop_count {
+            nlas.push(Nl80211MpathInfoNla::HopCount(v));
+        }
+        if let Some(v) = info.path_change {
+            nlas.push(Nl80211MpathInfoNla::PathChange(v));
+        }
+        nlas
+    }
+}
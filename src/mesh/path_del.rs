@@ -0,0 +1,45 @@
+// SPDX-License-Identifier: MIT
+
+use crate::{
+    nl80211_execute_ack, Nl80211Attr, Nl80211Cmd, Nl80211Error, Nl80211Handle,
+    Nl80211Message,
+};
+
+pub struct Nl80211MeshPathDelRequest {
+    handle: Nl80211Handle,
+    if_index: u32,
+    dst: Option<[u8; 6]>,
+}
+
+impl Nl80211MeshPathDelRequest {
+    pub(crate) fn new(
+        handle: Nl80211Handle,
+        if_index: u32,
+        dst: Option<[u8; 6]>,
+    ) -> Self {
+        Nl80211MeshPathDelRequest {
+            handle,
+            if_index,
+            dst,
+        }
+    }
+
+    pub async fn execute(self) -> Result<(), Nl80211Error> {
+        let Nl80211MeshPathDelRequest {
+            mut handle,
+            if_index,
+            dst,
+        } = self;
+
+        let mut nlas = vec![Nl80211Attr::IfIndex(if_index)];
+        if let Some(dst) = dst {
+            nlas.push(Nl80211Attr::Mac(dst));
+        }
+
+        let nl80211_msg = Nl80211Message {
+            cmd: Nl80211Cmd::MpathDel,
+            nlas,
+        };
+        nl80211_execute_ack(&mut handle, nl80211_msg).await
+    }
+}
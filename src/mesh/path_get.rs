@@ -0,0 +1,41 @@
+// SPDX-License-Identifier: MIT
+
+use futures::TryStream;
+use netlink_packet_core::{NLM_F_DUMP, NLM_F_REQUEST};
+use netlink_packet_generic::GenlMessage;
+
+use crate::{
+    nl80211_execute, Nl80211Attr, Nl80211Cmd, Nl80211Error, Nl80211Handle,
+    Nl80211Message,
+};
+
+pub struct Nl80211MeshPathGetRequest {
+    handle: Nl80211Handle,
+    if_index: u32,
+}
+
+impl Nl80211MeshPathGetRequest {
+    pub(crate) fn new(handle: Nl80211Handle, if_index: u32) -> Self {
+        Nl80211MeshPathGetRequest { handle, if_index }
+    }
+
+    /// Each mesh path is replied as `Nl80211Cmd::MpathNew` holding the
+    /// destination in `Nl80211Attr::Mac`, the next hop in
+    /// `Nl80211Attr::MpathNextHop` and `Nl80211Attr::MpathInfo`.
+    pub async fn execute(
+        self,
+    ) -> impl TryStream<Ok = GenlMessage<Nl80211Message>, Error = Nl80211Error>
+    {
+        let Nl80211MeshPathGetRequest {
+            mut handle,
+            if_index,
+        } = self;
+
+        let nl80211_msg = Nl80211Message {
+            cmd: Nl80211Cmd::MpathGet,
+            nlas: vec![Nl80211Attr::IfIndex(if_index)],
+        };
+        nl80211_execute(&mut handle, nl80211_msg, NLM_F_REQUEST | NLM_F_DUMP)
+            .await
+    }
+}
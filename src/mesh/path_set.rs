@@ -0,0 +1,48 @@
+// SPDX-License-Identifier: MIT
+
+use crate::{
+    nl80211_execute_ack, Nl80211Attr, Nl80211Cmd, Nl80211Error, Nl80211Handle,
+    Nl80211Message,
+};
+
+pub struct Nl80211MeshPathSetRequest {
+    handle: Nl80211Handle,
+    if_index: u32,
+    dst: [u8; 6],
+    next_hop: [u8; 6],
+}
+
+impl Nl80211MeshPathSetRequest {
+    pub(crate) fn new(
+        handle: Nl80211Handle,
+        if_index: u32,
+        dst: [u8; 6],
+        next_hop: [u8; 6],
+    ) -> Self {
+        Nl80211MeshPathSetRequest {
+            handle,
+            if_index,
+            dst,
+            next_hop,
+        }
+    }
+
+    pub async fn execute(self) -> Result<(), Nl80211Error> {
+        let Nl80211MeshPathSetRequest {
+            mut handle,
+            if_index,
+            dst,
+            next_hop,
+        } = self;
+
+        let nl80211_msg = Nl80211Message {
+            cmd: Nl80211Cmd::MpathSet,
+            nlas: vec![
+                Nl80211Attr::IfIndex(if_index),
+                Nl80211Attr::Mac(dst),
+                Nl80211Attr::MpathNextHop(next_hop),
+            ],
+        };
+        nl80211_execute_ack(&mut handle, nl80211_msg).await
+    }
+}
@@ -0,0 +1,45 @@
+// SPDX-License-Identifier: MIT
+
+const NL80211_MESH_POWER_UNKNOWN: u32 = 0;
+const NL80211_MESH_POWER_ACTIVE: u32 = 1;
+const NL80211_MESH_POWER_LIGHT_SLEEP: u32 = 2;
+const NL80211_MESH_POWER_DEEP_SLEEP: u32 = 3;
+
+/// Mesh power save mode
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Nl80211MeshPowerMode {
+    /// The mesh power mode of the mesh STA is not known or has not been set
+    /// yet
+    Unknown,
+    /// Active mesh power mode
+    Active,
+    /// Light sleep mode
+    LightSleep,
+    /// Deep sleep mode
+    DeepSleep,
+    Other(u32),
+}
+
+impl From<u32> for Nl80211MeshPowerMode {
+    fn from(d: u32) -> Self {
+        match d {
+            NL80211_MESH_POWER_UNKNOWN => Self::Unknown,
+            NL80211_MESH_POWER_ACTIVE => Self::Active,
+            NL80211_MESH_POWER_LIGHT_SLEEP => Self::LightSleep,
+            NL80211_MESH_POWER_DEEP_SLEEP => Self::DeepSleep,
+            _ => Self::Other(d),
+        }
+    }
+}
+
+impl From<Nl80211MeshPowerMode> for u32 {
+    fn from(v: Nl80211MeshPowerMode) -> u32 {
+        match v {
+            Nl80211MeshPowerMode::Unknown => NL80211_MESH_POWER_UNKNOWN,
+            Nl80211MeshPowerMode::Active => NL80211_MESH_POWER_ACTIVE,
+            Nl80211MeshPowerMode::LightSleep => NL80211_MESH_POWER_LIGHT_SLEEP,
+            Nl80211MeshPowerMode::DeepSleep => NL80211_MESH_POWER_DEEP_SLEEP,
+            Nl80211MeshPowerMode::Other(d) => d,
+        }
+    }
+}
@@ -0,0 +1,43 @@
+// SPDX-License-Identifier: MIT
+
+use crate::{
+    nl80211_execute_ack, Nl80211Attr, Nl80211Cmd, Nl80211Error, Nl80211Handle,
+    Nl80211MeshConfig, Nl80211Message,
+};
+
+pub struct Nl80211MeshConfigSetRequest {
+    handle: Nl80211Handle,
+    if_index: u32,
+    config: Nl80211MeshConfig,
+}
+
+impl Nl80211MeshConfigSetRequest {
+    pub(crate) fn new(
+        handle: Nl80211Handle,
+        if_index: u32,
+        config: Nl80211MeshConfig,
+    ) -> Self {
+        Nl80211MeshConfigSetRequest {
+            handle,
+            if_index,
+            config,
+        }
+    }
+
+    pub async fn execute(self) -> Result<(), Nl80211Error> {
+        let Nl80211MeshConfigSetRequest {
+            mut handle,
+            if_index,
+            config,
+        } = self;
+
+        let nl80211_msg = Nl80211Message {
+            cmd: Nl80211Cmd::MeshConfigSet,
+            nlas: vec![
+                Nl80211Attr::IfIndex(if_index),
+                Nl80211Attr::MeshConfig(config),
+            ],
+        };
+        nl80211_execute_ack(&mut handle, nl80211_msg).await
+    }
+}
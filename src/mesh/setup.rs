@@ -0,0 +1,244 @@
+// SPDX-License-Identifier: MIT
+
+use anyhow::Context;
+use netlink_packet_utils::{
+    nla::{DefaultNla, Nla, NlaBuffer, NlasIterator},
+    parsers::parse_u8,
+    DecodeError, Emitable, Parseable,
+};
+
+const NL80211_MESH_SETUP_ENABLE_VENDOR_PATH_SEL: u16 = 1;
+const NL80211_MESH_SETUP_ENABLE_VENDOR_METRIC: u16 = 2;
+const NL80211_MESH_SETUP_IE: u16 = 3;
+const NL80211_MESH_SETUP_USERSPACE_AUTH: u16 = 4;
+const NL80211_MESH_SETUP_USERSPACE_AMPE: u16 = 5;
+const NL80211_MESH_SETUP_ENABLE_VENDOR_SYNC: u16 = 6;
+const NL80211_MESH_SETUP_USERSPACE_MPM: u16 = 7;
+const NL80211_MESH_SETUP_AUTH_PROTOCOL: u16 = 8;
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum Nl80211MeshSetupNla {
+    /// Use a vendor specific path selection algorithm instead of HWMP
+    EnableVendorPathSel(bool),
+    /// Use a vendor specific path metric instead of the airtime metric
+    EnableVendorMetric(bool),
+    /// Information elements of the mesh, e.g. the RSN element of a secured
+    /// mesh
+    Ie(Vec<u8>),
+    /// Authentication of peers is handled by userspace
+    UserspaceAuth,
+    /// Authenticated mesh peering exchange is handled by userspace
+    UserspaceAmpe,
+    /// Use a vendor specific synchronization method instead of the neighbor
+    /// offset synchronization
+    EnableVendorSync(bool),
+    /// Mesh peering management is handled by userspace
+    UserspaceMpm,
+    /// Authentication protocol identifier advertised in the Mesh Configuration
+    /// element: 0 for none, 1 for SAE, 2 for IEEE 802.1X
+    AuthProtocol(u8),
+    Other(DefaultNla),
+}
+
+impl Nla for Nl80211MeshSetupNla {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::UserspaceAuth | Self::UserspaceAmpe | Self::UserspaceMpm => 0,
+            Self::EnableVendorPathSel(_)
+            | Self::EnableVendorMetric(_)
+            | Self::EnableVendorSync(_)
+            | Self::AuthProtocol(_) => 1,
+            Self::Ie(ref d) => d.len(),
+            Self::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::EnableVendorPathSel(_) => {
+                NL80211_MESH_SETUP_ENABLE_VENDOR_PATH_SEL
+            }
+            Self::EnableVendorMetric(_) => {
+                NL80211_MESH_SETUP_ENABLE_VENDOR_METRIC
+            }
+            Self::Ie(_) => NL80211_MESH_SETUP_IE,
+            Self::UserspaceAuth => NL80211_MESH_SETUP_USERSPACE_AUTH,
+            Self::UserspaceAmpe => NL80211_MESH_SETUP_USERSPACE_AMPE,
+            Self::EnableVendorSync(_) => NL80211_MESH_SETUP_ENABLE_VENDOR_SYNC,
+            Self::UserspaceMpm => NL80211_MESH_SETUP_USERSPACE_MPM,
+            Self::AuthProtocol(_) => NL80211_MESH_SETUP_AUTH_PROTOCOL,
+            Self::Other(attr) => attr.kind(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::UserspaceAuth | Self::UserspaceAmpe | Self::UserspaceMpm => {}
+            Self::AuthProtocol(d) => buffer[0] = *d,
+            Self::EnableVendorPathSel(d) => buffer[0] = *d as u8,
+            Self::EnableVendorMetric(d) => buffer[0] = *d as u8,
+            Self::EnableVendorSync(d) => buffer[0] = *d as u8,
+            Self::Ie(ref d) => buffer[..d.len()].copy_from_slice(d),
+            Self::Other(ref attr) => attr.emit(buffer),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for Nl80211MeshSetupNla
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            NL80211_MESH_SETUP_ENABLE_VENDOR_PATH_SEL => {
+                let err_msg = format!(
+                    "Invalid NL80211_MESH_SETUP_ENABLE_VENDOR_PATH_SEL \
+                     value {:?}",
+                    payload
+                );
+                Self::EnableVendorPathSel(
+                    parse_u8(payload).context(err_msg)? > 0,
+                )
+            }
+            NL80211_MESH_SETUP_ENABLE_VENDOR_METRIC => {
+                let err_msg = format!(
+                    "Invalid NL80211_MESH_SETUP_ENABLE_VENDOR_METRIC \
+                     value {:?}",
+                    payload
+                );
+                Self::EnableVendorMetric(
+                    parse_u8(payload).context(err_msg)? > 0,
+                )
+            }
+            NL80211_MESH_SETUP_IE => Self::Ie(payload.to_vec()),
+            NL80211_MESH_SETUP_USERSPACE_AUTH => Self::UserspaceAuth,
+            NL80211_MESH_SETUP_USERSPACE_AMPE => Self::UserspaceAmpe,
+            NL80211_MESH_SETUP_ENABLE_VENDOR_SYNC => {
+                let err_msg = format!(
+                    "Invalid NL80211_MESH_SETUP_ENABLE_VENDOR_SYNC value {:?}",
+                    payload
+                );
+                Self::EnableVendorSync(parse_u8(payload).context(err_msg)? > 0)
+            }
+            NL80211_MESH_SETUP_USERSPACE_MPM => Self::UserspaceMpm,
+            NL80211_MESH_SETUP_AUTH_PROTOCOL => {
+                let err_msg = format!(
+                    "Invalid NL80211_MESH_SETUP_AUTH_PROTOCOL value {:?}",
+                    payload
+                );
+                Self::AuthProtocol(parse_u8(payload).context(err_msg)?)
+            }
+            _ => Self::Other(
+                DefaultNla::parse(buf).context("invalid NLA (unknown kind)")?,
+            ),
+        })
+    }
+}
+
+/// Mesh setup parameters, these cannot be changed while the mesh is active
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct Nl80211MeshSetup {
+    /// Use a vendor specific path selection algorithm instead of HWMP
+    pub enable_vendor_path_sel: Option<bool>,
+    /// Use a vendor specific path metric instead of the airtime metric
+    pub enable_vendor_metric: Option<bool>,
+    /// Information elements of the mesh, e.g. the RSN element of a secured
+    /// mesh
+    pub ie: Option<Vec<u8>>,
+    /// Authentication of peers is handled by userspace
+    pub userspace_auth: bool,
+    /// Authenticated mesh peering exchange is handled by userspace
+    pub userspace_ampe: bool,
+    /// Use a vendor specific synchronization method instead of the neighbor
+    /// offset synchronization
+    pub enable_vendor_sync: Option<bool>,
+    /// Mesh peering management is handled by userspace
+    pub userspace_mpm: bool,
+    /// Authentication protocol identifier advertised in the Mesh Configuration
+    /// element: 0 for none, 1 for SAE, 2 for IEEE 802.1X
+    pub auth_protocol: Option<u8>,
+}
+
+impl Emitable for Nl80211MeshSetup {
+    fn buffer_len(&self) -> usize {
+        Vec::<Nl80211MeshSetupNla>::from(self)
+            .as_slice()
+            .buffer_len()
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        Vec::<Nl80211MeshSetupNla>::from(self)
+            .as_slice()
+            .emit(buffer)
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for Nl80211MeshSetup
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let mut ret = Self::default();
+        let payload = buf.value();
+        let err_msg =
+            format!("Invalid NL80211_ATTR_MESH_SETUP value {:?}", payload);
+        for nla in NlasIterator::new(payload) {
+            let nla = &nla.context(err_msg.clone())?;
+            match Nl80211MeshSetupNla::parse(nla).context(err_msg.clone())? {
+                Nl80211MeshSetupNla::EnableVendorPathSel(d) => {
+                    ret.enable_vendor_path_sel = Some(d)
+                }
+                Nl80211MeshSetupNla::EnableVendorMetric(d) => {
+                    ret.enable_vendor_metric = Some(d)
+                }
+                Nl80211MeshSetupNla::Ie(d) => ret.ie = Some(d),
+                Nl80211MeshSetupNla::UserspaceAuth => ret.userspace_auth = true,
+                Nl80211MeshSetupNla::UserspaceAmpe => ret.userspace_ampe = true,
+                Nl80211MeshSetupNla::EnableVendorSync(d) => {
+                    ret.enable_vendor_sync = Some(d)
+                }
+                Nl80211MeshSetupNla::UserspaceMpm => ret.userspace_mpm = true,
+                Nl80211MeshSetupNla::AuthProtocol(d) => {
+                    ret.auth_protocol = Some(d)
+                }
+                Nl80211MeshSetupNla::Other(attr) => {
+                    log::warn!(
+                        "Got unsupported NL80211_ATTR_MESH_SETUP value {:?}",
+                        attr
+                    )
+                }
+            }
+        }
+        Ok(ret)
+    }
+}
+
+impl From<&Nl80211MeshSetup> for Vec<Nl80211MeshSetupNla> {
+    fn from(setup: &Nl80211MeshSetup) -> Self {
+        let mut nlas = Vec::new();
+        if let Some(v) = setup.enable_vendor_path_sel {
+            nlas.push(Nl80211MeshSetupNla::EnableVendorPathSel(v));
+        }
+        if let Some(v) = setup.enable_vendor_metric {
+            nlas.push(Nl80211MeshSetupNla::EnableVendorMetric(v));
+        }
+        if let Some(v) = setup.ie.as_ref() {
+            nlas.push(Nl80211MeshSetupNla::Ie(v.clone()));
+        }
+        if setup.userspace_auth {
+            nlas.push(Nl80211MeshSetupNla::UserspaceAuth);
+        }
+        if setup.userspace_ampe {
+            nlas.push(Nl80211MeshSetupNla::UserspaceAmpe);
+        }
+        if let Some(v) = setup.enable_vendor_sync {
+            nlas.push(Nl80211MeshSetupNla::EnableVendorSync(v));
+        }
+        if setup.userspace_mpm {
+            nlas.push(Nl80211MeshSetupNla::UserspaceMpm);
+        }
+        if let Some(v) = setup.auth_protocol {
+            nlas.push(Nl80211MeshSetupNla::AuthProtocol(v));
+        }
+        nlas
+    }
+}
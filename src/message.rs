@@ -4,25 +4,447 @@ use anyhow::Context;
 use netlink_packet_core::DecodeError;
 use netlink_packet_generic::{GenlFamily, GenlHeader};
 use netlink_packet_utils::{
-    nla::NlasIterator, Emitable, Parseable, ParseableParametrized,
+    nla::NlasIterator, Emitable, ParseableParametrized,
 };
 
 use crate::attr::Nl80211Attr;
 
+const NL80211_CMD_GET_WIPHY: u8 = 1;
+const NL80211_CMD_SET_WIPHY: u8 = 2;
+const NL80211_CMD_NEW_WIPHY: u8 = 3;
 const NL80211_CMD_GET_INTERFACE: u8 = 5;
+const NL80211_CMD_SET_INTERFACE: u8 = 6;
 const NL80211_CMD_NEW_INTERFACE: u8 = 7;
+const NL80211_CMD_GET_KEY: u8 = 9;
+const NL80211_CMD_SET_KEY: u8 = 10;
+const NL80211_CMD_NEW_KEY: u8 = 11;
+const NL80211_CMD_DEL_KEY: u8 = 12;
+const NL80211_CMD_SET_BEACON: u8 = 14;
+const NL80211_CMD_START_AP: u8 = 15;
+const NL80211_CMD_STOP_AP: u8 = 16;
+const NL80211_CMD_GET_STATION: u8 = 17;
+const NL80211_CMD_SET_STATION: u8 = 18;
+const NL80211_CMD_NEW_STATION: u8 = 19;
+const NL80211_CMD_GET_MPATH: u8 = 21;
+const NL80211_CMD_SET_MPATH: u8 = 22;
+const NL80211_CMD_NEW_MPATH: u8 = 23;
+const NL80211_CMD_DEL_MPATH: u8 = 24;
+const NL80211_CMD_SET_BSS: u8 = 25;
+const NL80211_CMD_SET_REG: u8 = 26;
+const NL80211_CMD_REQ_SET_REG: u8 = 27;
+const NL80211_CMD_GET_MESH_CONFIG: u8 = 28;
+const NL80211_CMD_SET_MESH_CONFIG: u8 = 29;
+const NL80211_CMD_GET_REG: u8 = 31;
+const NL80211_CMD_GET_SCAN: u8 = 32;
+const NL80211_CMD_TRIGGER_SCAN: u8 = 33;
+const NL80211_CMD_NEW_SCAN_RESULTS: u8 = 34;
+const NL80211_CMD_SCAN_ABORTED: u8 = 35;
+const NL80211_CMD_AUTHENTICATE: u8 = 37;
+const NL80211_CMD_ASSOCIATE: u8 = 38;
+const NL80211_CMD_JOIN_IBSS: u8 = 43;
+const NL80211_CMD_LEAVE_IBSS: u8 = 44;
+const NL80211_CMD_CONNECT: u8 = 46;
+const NL80211_CMD_SET_WIPHY_NETNS: u8 = 49;
+const NL80211_CMD_GET_SURVEY: u8 = 50;
+const NL80211_CMD_NEW_SURVEY_RESULTS: u8 = 51;
+const NL80211_CMD_SET_PMKSA: u8 = 52;
+const NL80211_CMD_DEL_PMKSA: u8 = 53;
+const NL80211_CMD_FLUSH_PMKSA: u8 = 54;
+const NL80211_CMD_REMAIN_ON_CHANNEL: u8 = 55;
+const NL80211_CMD_CANCEL_REMAIN_ON_CHANNEL: u8 = 56;
+const NL80211_CMD_REGISTER_FRAME: u8 = 58;
+const NL80211_CMD_FRAME: u8 = 59;
+const NL80211_CMD_FRAME_TX_STATUS: u8 = 60;
+const NL80211_CMD_SET_POWER_SAVE: u8 = 61;
+const NL80211_CMD_GET_POWER_SAVE: u8 = 62;
+const NL80211_CMD_SET_CQM: u8 = 63;
+const NL80211_CMD_NOTIFY_CQM: u8 = 64;
+const NL80211_CMD_SET_CHANNEL: u8 = 65;
+const NL80211_CMD_JOIN_MESH: u8 = 68;
+const NL80211_CMD_LEAVE_MESH: u8 = 69;
+const NL80211_CMD_GET_WOWLAN: u8 = 73;
+const NL80211_CMD_SET_WOWLAN: u8 = 74;
+const NL80211_CMD_START_SCHED_SCAN: u8 = 75;
+const NL80211_CMD_STOP_SCHED_SCAN: u8 = 76;
+const NL80211_CMD_SCHED_SCAN_RESULTS: u8 = 77;
+const NL80211_CMD_SCHED_SCAN_STOPPED: u8 = 78;
+const NL80211_CMD_TDLS_OPER: u8 = 81;
+const NL80211_CMD_TDLS_MGMT: u8 = 82;
+const NL80211_CMD_PROBE_CLIENT: u8 = 84;
+const NL80211_CMD_REGISTER_BEACONS: u8 = 85;
+const NL80211_CMD_SET_NOACK_MAP: u8 = 87;
+const NL80211_CMD_CH_SWITCH_NOTIFY: u8 = 88;
+const NL80211_CMD_SET_MAC_ACL: u8 = 93;
+const NL80211_CMD_GET_PROTOCOL_FEATURES: u8 = 95;
+const NL80211_CMD_CRIT_PROTOCOL_START: u8 = 98;
+const NL80211_CMD_CRIT_PROTOCOL_STOP: u8 = 99;
+const NL80211_CMD_GET_COALESCE: u8 = 100;
+const NL80211_CMD_SET_COALESCE: u8 = 101;
+const NL80211_CMD_CHANNEL_SWITCH: u8 = 102;
+const NL80211_CMD_SET_QOS_MAP: u8 = 104;
+const NL80211_CMD_ADD_TX_TS: u8 = 105;
+const NL80211_CMD_DEL_TX_TS: u8 = 106;
+const NL80211_CMD_GET_MPP: u8 = 107;
+const NL80211_CMD_JOIN_OCB: u8 = 108;
+const NL80211_CMD_LEAVE_OCB: u8 = 109;
+const NL80211_CMD_CH_SWITCH_STARTED_NOTIFY: u8 = 110;
+const NL80211_CMD_START_NAN: u8 = 115;
+const NL80211_CMD_STOP_NAN: u8 = 116;
+const NL80211_CMD_ADD_NAN_FUNCTION: u8 = 117;
+const NL80211_CMD_DEL_NAN_FUNCTION: u8 = 118;
+const NL80211_CMD_CHANGE_NAN_CONFIG: u8 = 119;
+const NL80211_CMD_NAN_MATCH: u8 = 120;
+const NL80211_CMD_SET_MULTICAST_TO_UNICAST: u8 = 121;
+const NL80211_CMD_SET_PMK: u8 = 123;
+const NL80211_CMD_DEL_PMK: u8 = 124;
+const NL80211_CMD_GET_FTM_RESPONDER_STATS: u8 = 130;
+const NL80211_CMD_PEER_MEASUREMENT_START: u8 = 131;
+const NL80211_CMD_PEER_MEASUREMENT_RESULT: u8 = 132;
+const NL80211_CMD_PEER_MEASUREMENT_COMPLETE: u8 = 133;
+const NL80211_CMD_NOTIFY_RADAR: u8 = 134;
+const NL80211_CMD_UPDATE_OWE_INFO: u8 = 135;
+const NL80211_CMD_SET_TID_CONFIG: u8 = 137;
+const NL80211_CMD_COLOR_CHANGE_REQUEST: u8 = 142;
+const NL80211_CMD_COLOR_CHANGE_STARTED: u8 = 143;
+const NL80211_CMD_COLOR_CHANGE_ABORTED: u8 = 144;
+const NL80211_CMD_COLOR_CHANGE_COMPLETED: u8 = 145;
 
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
 pub enum Nl80211Cmd {
+    WiPhyGet,
+    WiPhyNew,
     InterfaceGet,
+    InterfaceSet,
     InterfaceNew,
+    KeyGet,
+    KeySet,
+    KeyNew,
+    KeyDel,
+    StationGet,
+    StationSet,
+    StationNew,
+    ScanGet,
+    ScanTrigger,
+    NewScanResults,
+    ScanAborted,
+    RegGet,
+    RegSet,
+    RegRequestSet,
+    SurveyGet,
+    NewSurveyResults,
+    SchedScanStart,
+    SchedScanStop,
+    SchedScanResults,
+    SchedScanStopped,
+    WowlanGet,
+    WowlanSet,
+    Connect,
+    Authenticate,
+    Associate,
+    PmkSet,
+    PmkDel,
+    UpdateOweInfo,
+    PmksaSet,
+    PmksaDel,
+    PmksaFlush,
+    JoinIbss,
+    LeaveIbss,
+    JoinMesh,
+    LeaveMesh,
+    MeshConfigGet,
+    MeshConfigSet,
+    MpathGet,
+    MpathSet,
+    MpathNew,
+    MpathDel,
+    MppGet,
+    BeaconSet,
+    ApStart,
+    ApStop,
+    BssSet,
+    WiPhySet,
+    FtmResponderStatsGet,
+    PeerMeasurementStart,
+    PeerMeasurementResult,
+    PeerMeasurementComplete,
+    ColorChangeRequest,
+    ColorChangeStarted,
+    ColorChangeAborted,
+    ColorChangeCompleted,
+    MacAclSet,
+    ProbeClient,
+    Frame,
+    BeaconsRegister,
+    FrameRegister,
+    FrameTxStatus,
+    RemainOnChannel,
+    RemainOnChannelCancel,
+    ChannelSwitch,
+    ChannelSwitchNotify,
+    ChannelSwitchStartedNotify,
+    RadarNotify,
+    ChannelSet,
+    WiPhyNetnsSet,
+    ProtocolFeaturesGet,
+    PowerSaveSet,
+    PowerSaveGet,
+    CqmSet,
+    CqmNotify,
+    TdlsOper,
+    TdlsMgmt,
+    NoackMapSet,
+    QosMapSet,
+    TidConfigSet,
+    TxTsAdd,
+    TxTsDel,
+    MulticastToUnicastSet,
+    CoalesceGet,
+    CoalesceSet,
+    CritProtocolStart,
+    CritProtocolStop,
+    JoinOcb,
+    LeaveOcb,
+    NanStart,
+    NanStop,
+    NanFunctionAdd,
+    NanFunctionDel,
+    NanConfigChange,
+    NanMatch,
+    Other(u8),
+}
+
+impl From<u8> for Nl80211Cmd {
+    fn from(d: u8) -> Self {
+        match d {
+            NL80211_CMD_GET_WIPHY => Self::WiPhyGet,
+            NL80211_CMD_NEW_WIPHY => Self::WiPhyNew,
+            NL80211_CMD_GET_INTERFACE => Self::InterfaceGet,
+            NL80211_CMD_SET_INTERFACE => Self::InterfaceSet,
+            NL80211_CMD_NEW_INTERFACE => Self::InterfaceNew,
+            NL80211_CMD_GET_KEY => Self::KeyGet,
+            NL80211_CMD_SET_KEY => Self::KeySet,
+            NL80211_CMD_NEW_KEY => Self::KeyNew,
+            NL80211_CMD_DEL_KEY => Self::KeyDel,
+            NL80211_CMD_GET_STATION => Self::StationGet,
+            NL80211_CMD_SET_STATION => Self::StationSet,
+            NL80211_CMD_NEW_STATION => Self::StationNew,
+            NL80211_CMD_GET_SCAN => Self::ScanGet,
+            NL80211_CMD_TRIGGER_SCAN => Self::ScanTrigger,
+            NL80211_CMD_NEW_SCAN_RESULTS => Self::NewScanResults,
+            NL80211_CMD_SCAN_ABORTED => Self::ScanAborted,
+            NL80211_CMD_GET_REG => Self::RegGet,
+            NL80211_CMD_SET_REG => Self::RegSet,
+            NL80211_CMD_REQ_SET_REG => Self::RegRequestSet,
+            NL80211_CMD_GET_SURVEY => Self::SurveyGet,
+            NL80211_CMD_NEW_SURVEY_RESULTS => Self::NewSurveyResults,
+            NL80211_CMD_START_SCHED_SCAN => Self::SchedScanStart,
+            NL80211_CMD_STOP_SCHED_SCAN => Self::SchedScanStop,
+            NL80211_CMD_SCHED_SCAN_RESULTS => Self::SchedScanResults,
+            NL80211_CMD_SCHED_SCAN_STOPPED => Self::SchedScanStopped,
+            NL80211_CMD_GET_WOWLAN => Self::WowlanGet,
+            NL80211_CMD_SET_WOWLAN => Self::WowlanSet,
+            NL80211_CMD_CONNECT => Self::Connect,
+            NL80211_CMD_AUTHENTICATE => Self::Authenticate,
+            NL80211_CMD_ASSOCIATE => Self::Associate,
+            NL80211_CMD_SET_PMK => Self::PmkSet,
+            NL80211_CMD_DEL_PMK => Self::PmkDel,
+            NL80211_CMD_UPDATE_OWE_INFO => Self::UpdateOweInfo,
+            NL80211_CMD_SET_PMKSA => Self::PmksaSet,
+            NL80211_CMD_DEL_PMKSA => Self::PmksaDel,
+            NL80211_CMD_FLUSH_PMKSA => Self::PmksaFlush,
+            NL80211_CMD_JOIN_IBSS => Self::JoinIbss,
+            NL80211_CMD_LEAVE_IBSS => Self::LeaveIbss,
+            NL80211_CMD_JOIN_MESH => Self::JoinMesh,
+            NL80211_CMD_LEAVE_MESH => Self::LeaveMesh,
+            NL80211_CMD_GET_MESH_CONFIG => Self::MeshConfigGet,
+            NL80211_CMD_SET_MESH_CONFIG => Self::MeshConfigSet,
+            NL80211_CMD_GET_MPATH => Self::MpathGet,
+            NL80211_CMD_SET_MPATH => Self::MpathSet,
+            NL80211_CMD_NEW_MPATH => Self::MpathNew,
+            NL80211_CMD_DEL_MPATH => Self::MpathDel,
+            NL80211_CMD_GET_MPP => Self::MppGet,
+            NL80211_CMD_START_AP => Self::ApStart,
+            NL80211_CMD_SET_BEACON => Self::BeaconSet,
+            NL80211_CMD_STOP_AP => Self::ApStop,
+            NL80211_CMD_SET_BSS => Self::BssSet,
+            NL80211_CMD_SET_WIPHY => Self::WiPhySet,
+            NL80211_CMD_GET_FTM_RESPONDER_STATS => Self::FtmResponderStatsGet,
+            NL80211_CMD_PEER_MEASUREMENT_START => Self::PeerMeasurementStart,
+            NL80211_CMD_PEER_MEASUREMENT_RESULT => Self::PeerMeasurementResult,
+            NL80211_CMD_PEER_MEASUREMENT_COMPLETE => {
+                Self::PeerMeasurementComplete
+            }
+            NL80211_CMD_COLOR_CHANGE_REQUEST => Self::ColorChangeRequest,
+            NL80211_CMD_COLOR_CHANGE_STARTED => Self::ColorChangeStarted,
+            NL80211_CMD_COLOR_CHANGE_ABORTED => Self::ColorChangeAborted,
+            NL80211_CMD_COLOR_CHANGE_COMPLETED => Self::ColorChangeCompleted,
+            NL80211_CMD_SET_MAC_ACL => Self::MacAclSet,
+            NL80211_CMD_PROBE_CLIENT => Self::ProbeClient,
+            NL80211_CMD_FRAME => Self::Frame,
+            NL80211_CMD_REGISTER_BEACONS => Self::BeaconsRegister,
+            NL80211_CMD_REGISTER_FRAME => Self::FrameRegister,
+            NL80211_CMD_FRAME_TX_STATUS => Self::FrameTxStatus,
+            NL80211_CMD_REMAIN_ON_CHANNEL => Self::RemainOnChannel,
+            NL80211_CMD_CANCEL_REMAIN_ON_CHANNEL => Self::RemainOnChannelCancel,
+            NL80211_CMD_CHANNEL_SWITCH => Self::ChannelSwitch,
+            NL80211_CMD_CH_SWITCH_NOTIFY => Self::ChannelSwitchNotify,
+            NL80211_CMD_CH_SWITCH_STARTED_NOTIFY => {
+                Self::ChannelSwitchStartedNotify
+            }
+            NL80211_CMD_NOTIFY_RADAR => Self::RadarNotify,
+            NL80211_CMD_SET_CHANNEL => Self::ChannelSet,
+            NL80211_CMD_SET_WIPHY_NETNS => Self::WiPhyNetnsSet,
+            NL80211_CMD_GET_PROTOCOL_FEATURES => Self::ProtocolFeaturesGet,
+            NL80211_CMD_SET_POWER_SAVE => Self::PowerSaveSet,
+            NL80211_CMD_GET_POWER_SAVE => Self::PowerSaveGet,
+            NL80211_CMD_SET_CQM => Self::CqmSet,
+            NL80211_CMD_NOTIFY_CQM => Self::CqmNotify,
+            NL80211_CMD_TDLS_OPER => Self::TdlsOper,
+            NL80211_CMD_TDLS_MGMT => Self::TdlsMgmt,
+            NL80211_CMD_SET_NOACK_MAP => Self::NoackMapSet,
+            NL80211_CMD_SET_QOS_MAP => Self::QosMapSet,
+            NL80211_CMD_SET_TID_CONFIG => Self::TidConfigSet,
+            NL80211_CMD_ADD_TX_TS => Self::TxTsAdd,
+            NL80211_CMD_DEL_TX_TS => Self::TxTsDel,
+            NL80211_CMD_SET_MULTICAST_TO_UNICAST => Self::MulticastToUnicastSet,
+            NL80211_CMD_GET_COALESCE => Self::CoalesceGet,
+            NL80211_CMD_SET_COALESCE => Self::CoalesceSet,
+            NL80211_CMD_CRIT_PROTOCOL_START => Self::CritProtocolStart,
+            NL80211_CMD_CRIT_PROTOCOL_STOP => Self::CritProtocolStop,
+            NL80211_CMD_JOIN_OCB => Self::JoinOcb,
+            NL80211_CMD_LEAVE_OCB => Self::LeaveOcb,
+            NL80211_CMD_START_NAN => Self::NanStart,
+            NL80211_CMD_STOP_NAN => Self::NanStop,
+            NL80211_CMD_ADD_NAN_FUNCTION => Self::NanFunctionAdd,
+            NL80211_CMD_DEL_NAN_FUNCTION => Self::NanFunctionDel,
+            NL80211_CMD_CHANGE_NAN_CONFIG => Self::NanConfigChange,
+            NL80211_CMD_NAN_MATCH => Self::NanMatch,
+            _ => Self::Other(d),
+        }
+    }
 }
 
 impl From<Nl80211Cmd> for u8 {
     fn from(cmd: Nl80211Cmd) -> Self {
         match cmd {
+            Nl80211Cmd::WiPhyGet => NL80211_CMD_GET_WIPHY,
+            Nl80211Cmd::WiPhyNew => NL80211_CMD_NEW_WIPHY,
             Nl80211Cmd::InterfaceGet => NL80211_CMD_GET_INTERFACE,
+            Nl80211Cmd::InterfaceSet => NL80211_CMD_SET_INTERFACE,
             Nl80211Cmd::InterfaceNew => NL80211_CMD_NEW_INTERFACE,
+            Nl80211Cmd::KeyGet => NL80211_CMD_GET_KEY,
+            Nl80211Cmd::KeySet => NL80211_CMD_SET_KEY,
+            Nl80211Cmd::KeyNew => NL80211_CMD_NEW_KEY,
+            Nl80211Cmd::KeyDel => NL80211_CMD_DEL_KEY,
+            Nl80211Cmd::StationGet => NL80211_CMD_GET_STATION,
+            Nl80211Cmd::StationSet => NL80211_CMD_SET_STATION,
+            Nl80211Cmd::StationNew => NL80211_CMD_NEW_STATION,
+            Nl80211Cmd::ScanGet => NL80211_CMD_GET_SCAN,
+            Nl80211Cmd::ScanTrigger => NL80211_CMD_TRIGGER_SCAN,
+            Nl80211Cmd::NewScanResults => NL80211_CMD_NEW_SCAN_RESULTS,
+            Nl80211Cmd::ScanAborted => NL80211_CMD_SCAN_ABORTED,
+            Nl80211Cmd::RegGet => NL80211_CMD_GET_REG,
+            Nl80211Cmd::RegSet => NL80211_CMD_SET_REG,
+            Nl80211Cmd::RegRequestSet => NL80211_CMD_REQ_SET_REG,
+            Nl80211Cmd::SurveyGet => NL80211_CMD_GET_SURVEY,
+            Nl80211Cmd::NewSurveyResults => NL80211_CMD_NEW_SURVEY_RESULTS,
+            Nl80211Cmd::SchedScanStart => NL80211_CMD_START_SCHED_SCAN,
+            Nl80211Cmd::SchedScanStop => NL80211_CMD_STOP_SCHED_SCAN,
+            Nl80211Cmd::SchedScanResults => NL80211_CMD_SCHED_SCAN_RESULTS,
+            Nl80211Cmd::SchedScanStopped => NL80211_CMD_SCHED_SCAN_STOPPED,
+            Nl80211Cmd::WowlanGet => NL80211_CMD_GET_WOWLAN,
+            Nl80211Cmd::WowlanSet => NL80211_CMD_SET_WOWLAN,
+            Nl80211Cmd::Connect => NL80211_CMD_CONNECT,
+            Nl80211Cmd::Authenticate => NL80211_CMD_AUTHENTICATE,
+            Nl80211Cmd::Associate => NL80211_CMD_ASSOCIATE,
+            Nl80211Cmd::PmkSet => NL80211_CMD_SET_PMK,
+            Nl80211Cmd::PmkDel => NL80211_CMD_DEL_PMK,
+            Nl80211Cmd::UpdateOweInfo => NL80211_CMD_UPDATE_OWE_INFO,
+            Nl80211Cmd::PmksaSet => NL80211_CMD_SET_PMKSA,
+            Nl80211Cmd::PmksaDel => NL80211_CMD_DEL_PMKSA,
+            Nl80211Cmd::PmksaFlush => NL80211_CMD_FLUSH_PMKSA,
+            Nl80211Cmd::JoinIbss => NL80211_CMD_JOIN_IBSS,
+            Nl80211Cmd::LeaveIbss => NL80211_CMD_LEAVE_IBSS,
+            Nl80211Cmd::JoinMesh => NL80211_CMD_JOIN_MESH,
+            Nl80211Cmd::LeaveMesh => NL80211_CMD_LEAVE_MESH,
+            Nl80211Cmd::MeshConfigGet => NL80211_CMD_GET_MESH_CONFIG,
+            Nl80211Cmd::MeshConfigSet => NL80211_CMD_SET_MESH_CONFIG,
+            Nl80211Cmd::MpathGet => NL80211_CMD_GET_MPATH,
+            Nl80211Cmd::MpathSet => NL80211_CMD_SET_MPATH,
+            Nl80211Cmd::MpathNew => NL80211_CMD_NEW_MPATH,
+            Nl80211Cmd::MpathDel => NL80211_CMD_DEL_MPATH,
+            Nl80211Cmd::MppGet => NL80211_CMD_GET_MPP,
+            Nl80211Cmd::ApStart => NL80211_CMD_START_AP,
+            Nl80211Cmd::BeaconSet => NL80211_CMD_SET_BEACON,
+            Nl80211Cmd::ApStop => NL80211_CMD_STOP_AP,
+            Nl80211Cmd::BssSet => NL80211_CMD_SET_BSS,
+            Nl80211Cmd::WiPhySet => NL80211_CMD_SET_WIPHY,
+            Nl80211Cmd::FtmResponderStatsGet => {
+                NL80211_CMD_GET_FTM_RESPONDER_STATS
+            }
+            Nl80211Cmd::PeerMeasurementStart => {
+                NL80211_CMD_PEER_MEASUREMENT_START
+            }
+            Nl80211Cmd::PeerMeasurementResult => {
+                NL80211_CMD_PEER_MEASUREMENT_RESULT
+            }
+            Nl80211Cmd::PeerMeasurementComplete => {
+                NL80211_CMD_PEER_MEASUREMENT_COMPLETE
+            }
+            Nl80211Cmd::ColorChangeRequest => NL80211_CMD_COLOR_CHANGE_REQUEST,
+            Nl80211Cmd::ColorChangeStarted => NL80211_CMD_COLOR_CHANGE_STARTED,
+            Nl80211Cmd::ColorChangeAborted => NL80211_CMD_COLOR_CHANGE_ABORTED,
+            Nl80211Cmd::ColorChangeCompleted => {
+                NL80211_CMD_COLOR_CHANGE_COMPLETED
+            }
+            Nl80211Cmd::MacAclSet => NL80211_CMD_SET_MAC_ACL,
+            Nl80211Cmd::ProbeClient => NL80211_CMD_PROBE_CLIENT,
+            Nl80211Cmd::Frame => NL80211_CMD_FRAME,
+            Nl80211Cmd::BeaconsRegister => NL80211_CMD_REGISTER_BEACONS,
+            Nl80211Cmd::FrameRegister => NL80211_CMD_REGISTER_FRAME,
+            Nl80211Cmd::FrameTxStatus => NL80211_CMD_FRAME_TX_STATUS,
+            Nl80211Cmd::RemainOnChannel => NL80211_CMD_REMAIN_ON_CHANNEL,
+            Nl80211Cmd::RemainOnChannelCancel => {
+                NL80211_CMD_CANCEL_REMAIN_ON_CHANNEL
+            }
+            Nl80211Cmd::ChannelSwitch => NL80211_CMD_CHANNEL_SWITCH,
+            Nl80211Cmd::ChannelSwitchNotify => NL80211_CMD_CH_SWITCH_NOTIFY,
+            Nl80211Cmd::ChannelSwitchStartedNotify => {
+                NL80211_CMD_CH_SWITCH_STARTED_NOTIFY
+            }
+            Nl80211Cmd::RadarNotify => NL80211_CMD_NOTIFY_RADAR,
+            Nl80211Cmd::ChannelSet => NL80211_CMD_SET_CHANNEL,
+            Nl80211Cmd::WiPhyNetnsSet => NL80211_CMD_SET_WIPHY_NETNS,
+            Nl80211Cmd::ProtocolFeaturesGet => {
+                NL80211_CMD_GET_PROTOCOL_FEATURES
+            }
+            Nl80211Cmd::PowerSaveSet => NL80211_CMD_SET_POWER_SAVE,
+            Nl80211Cmd::PowerSaveGet => NL80211_CMD_GET_POWER_SAVE,
+            Nl80211Cmd::CqmSet => NL80211_CMD_SET_CQM,
+            Nl80211Cmd::CqmNotify => NL80211_CMD_NOTIFY_CQM,
+            Nl80211Cmd::TdlsOper => NL80211_CMD_TDLS_OPER,
+            Nl80211Cmd::TdlsMgmt => NL80211_CMD_TDLS_MGMT,
+            Nl80211Cmd::NoackMapSet => NL80211_CMD_SET_NOACK_MAP,
+            Nl80211Cmd::QosMapSet => NL80211_CMD_SET_QOS_MAP,
+            Nl80211Cmd::TidConfigSet => NL80211_CMD_SET_TID_CONFIG,
+            Nl80211Cmd::TxTsAdd => NL80211_CMD_ADD_TX_TS,
+            Nl80211Cmd::TxTsDel => NL80211_CMD_DEL_TX_TS,
+            Nl80211Cmd::MulticastToUnicastSet => {
+                NL80211_CMD_SET_MULTICAST_TO_UNICAST
+            }
+            Nl80211Cmd::CoalesceGet => NL80211_CMD_GET_COALESCE,
+            Nl80211Cmd::CoalesceSet => NL80211_CMD_SET_COALESCE,
+            Nl80211Cmd::CritProtocolStart => NL80211_CMD_CRIT_PROTOCOL_START,
+            Nl80211Cmd::CritProtocolStop => NL80211_CMD_CRIT_PROTOCOL_STOP,
+            Nl80211Cmd::JoinOcb => NL80211_CMD_JOIN_OCB,
+            Nl80211Cmd::LeaveOcb => NL80211_CMD_LEAVE_OCB,
+            Nl80211Cmd::NanStart => NL80211_CMD_START_NAN,
+            Nl80211Cmd::NanStop => NL80211_CMD_STOP_NAN,
+            Nl80211Cmd::NanFunctionAdd => NL80211_CMD_ADD_NAN_FUNCTION,
+            Nl80211Cmd::NanFunctionDel => NL80211_CMD_DEL_NAN_FUNCTION,
+            Nl80211Cmd::NanConfigChange => NL80211_CMD_CHANGE_NAN_CONFIG,
+            Nl80211Cmd::NanMatch => NL80211_CMD_NAN_MATCH,
+            Nl80211Cmd::Other(d) => d,
         }
     }
 }
@@ -54,6 +476,27 @@ impl Nl80211Message {
             nlas: vec![],
         }
     }
+
+    pub fn new_station_get(if_index: u32) -> Self {
+        Nl80211Message {
+            cmd: Nl80211Cmd::StationGet,
+            nlas: vec![Nl80211Attr::IfIndex(if_index)],
+        }
+    }
+
+    pub fn new_scan_get(if_index: u32) -> Self {
+        Nl80211Message {
+            cmd: Nl80211Cmd::ScanGet,
+            nlas: vec![Nl80211Attr::IfIndex(if_index)],
+        }
+    }
+
+    pub fn new_survey_get(if_index: u32) -> Self {
+        Nl80211Message {
+            cmd: Nl80211Cmd::SurveyGet,
+            nlas: vec![Nl80211Attr::IfIndex(if_index)],
+        }
+    }
 }
 
 impl Emitable for Nl80211Message {
@@ -66,13 +509,16 @@ impl Emitable for Nl80211Message {
     }
 }
 
-fn parse_nlas(buffer: &[u8]) -> Result<Vec<Nl80211Attr>, DecodeError> {
+fn parse_nlas(
+    buffer: &[u8],
+    cmd: Nl80211Cmd,
+) -> Result<Vec<Nl80211Attr>, DecodeError> {
     let mut nlas = Vec::new();
     for nla in NlasIterator::new(buffer) {
         let error_msg =
             format!("Failed to parse nl80211 message attribute {:?}", nla);
         let nla = &nla.context(error_msg.clone())?;
-        nlas.push(Nl80211Attr::parse(nla).context(error_msg)?);
+        nlas.push(Nl80211Attr::parse_with_cmd(nla, cmd).context(error_msg)?);
     }
     Ok(nlas)
 }
@@ -82,17 +528,74 @@ impl ParseableParametrized<[u8], GenlHeader> for Nl80211Message {
         buffer: &[u8],
         header: GenlHeader,
     ) -> Result<Self, DecodeError> {
-        Ok(match header.cmd {
-            NL80211_CMD_NEW_INTERFACE => Self {
-                cmd: Nl80211Cmd::InterfaceNew,
-                nlas: parse_nlas(buffer)?,
-            },
+        let cmd = match header.cmd {
+            NL80211_CMD_NEW_WIPHY => Nl80211Cmd::WiPhyNew,
+            NL80211_CMD_NEW_INTERFACE => Nl80211Cmd::InterfaceNew,
+            NL80211_CMD_NEW_KEY => Nl80211Cmd::KeyNew,
+            NL80211_CMD_NEW_STATION => Nl80211Cmd::StationNew,
+            NL80211_CMD_TRIGGER_SCAN => Nl80211Cmd::ScanTrigger,
+            NL80211_CMD_NEW_SCAN_RESULTS => Nl80211Cmd::NewScanResults,
+            NL80211_CMD_SCAN_ABORTED => Nl80211Cmd::ScanAborted,
+            NL80211_CMD_GET_REG => Nl80211Cmd::RegGet,
+            NL80211_CMD_NEW_SURVEY_RESULTS => Nl80211Cmd::NewSurveyResults,
+            NL80211_CMD_START_SCHED_SCAN => Nl80211Cmd::SchedScanStart,
+            NL80211_CMD_SCHED_SCAN_RESULTS => Nl80211Cmd::SchedScanResults,
+            NL80211_CMD_SCHED_SCAN_STOPPED => Nl80211Cmd::SchedScanStopped,
+            NL80211_CMD_CONNECT => Nl80211Cmd::Connect,
+            NL80211_CMD_GET_WOWLAN => Nl80211Cmd::WowlanGet,
+            NL80211_CMD_AUTHENTICATE => Nl80211Cmd::Authenticate,
+            NL80211_CMD_ASSOCIATE => Nl80211Cmd::Associate,
+            NL80211_CMD_UPDATE_OWE_INFO => Nl80211Cmd::UpdateOweInfo,
+            NL80211_CMD_JOIN_IBSS => Nl80211Cmd::JoinIbss,
+            NL80211_CMD_GET_MESH_CONFIG => Nl80211Cmd::MeshConfigGet,
+            NL80211_CMD_NEW_MPATH => Nl80211Cmd::MpathNew,
+            NL80211_CMD_START_AP => Nl80211Cmd::ApStart,
+            NL80211_CMD_STOP_AP => Nl80211Cmd::ApStop,
+            NL80211_CMD_GET_FTM_RESPONDER_STATS => {
+                Nl80211Cmd::FtmResponderStatsGet
+            }
+            NL80211_CMD_PEER_MEASUREMENT_RESULT => {
+                Nl80211Cmd::PeerMeasurementResult
+            }
+            NL80211_CMD_PEER_MEASUREMENT_COMPLETE => {
+                Nl80211Cmd::PeerMeasurementComplete
+            }
+            NL80211_CMD_COLOR_CHANGE_STARTED => Nl80211Cmd::ColorChangeStarted,
+            NL80211_CMD_COLOR_CHANGE_ABORTED => Nl80211Cmd::ColorChangeAborted,
+            NL80211_CMD_COLOR_CHANGE_COMPLETED => {
+                Nl80211Cmd::ColorChangeCompleted
+            }
+            NL80211_CMD_PROBE_CLIENT => Nl80211Cmd::ProbeClient,
+            NL80211_CMD_FRAME => Nl80211Cmd::Frame,
+            NL80211_CMD_FRAME_TX_STATUS => Nl80211Cmd::FrameTxStatus,
+            NL80211_CMD_REMAIN_ON_CHANNEL => Nl80211Cmd::RemainOnChannel,
+            NL80211_CMD_CANCEL_REMAIN_ON_CHANNEL => {
+                Nl80211Cmd::RemainOnChannelCancel
+            }
+            NL80211_CMD_CH_SWITCH_NOTIFY => Nl80211Cmd::ChannelSwitchNotify,
+            NL80211_CMD_CH_SWITCH_STARTED_NOTIFY => {
+                Nl80211Cmd::ChannelSwitchStartedNotify
+            }
+            NL80211_CMD_GET_PROTOCOL_FEATURES => {
+                Nl80211Cmd::ProtocolFeaturesGet
+            }
+            NL80211_CMD_GET_POWER_SAVE => Nl80211Cmd::PowerSaveGet,
+            NL80211_CMD_NOTIFY_CQM => Nl80211Cmd::CqmNotify,
+            NL80211_CMD_GET_COALESCE => Nl80211Cmd::CoalesceGet,
+            NL80211_CMD_CRIT_PROTOCOL_STOP => Nl80211Cmd::CritProtocolStop,
+            NL80211_CMD_ADD_NAN_FUNCTION => Nl80211Cmd::NanFunctionAdd,
+            NL80211_CMD_DEL_NAN_FUNCTION => Nl80211Cmd::NanFunctionDel,
+            NL80211_CMD_NAN_MATCH => Nl80211Cmd::NanMatch,
             cmd => {
                 return Err(DecodeError::from(format!(
                     "Unsupported nl80211 reply command: {}",
                     cmd
                 )))
             }
+        };
+        Ok(Self {
+            cmd,
+            nlas: parse_nlas(buffer, cmd)?,
         })
     }
 }
@@ -0,0 +1,60 @@
+// SPDX-License-Identifier: MIT
+
+use crate::{
+    nan::start::bands_to_bitmap, nl80211_execute_ack, Nl80211Attr, Nl80211Band,
+    Nl80211Cmd, Nl80211Error, Nl80211Handle, Nl80211Message,
+};
+
+pub struct Nl80211NanConfigChangeRequest {
+    handle: Nl80211Handle,
+    wdev: u64,
+    master_pref: Option<u8>,
+    bands: Option<u32>,
+}
+
+impl Nl80211NanConfigChangeRequest {
+    pub(crate) fn new(handle: Nl80211Handle, wdev: u64) -> Self {
+        Nl80211NanConfigChangeRequest {
+            handle,
+            wdev,
+            master_pref: None,
+            bands: None,
+        }
+    }
+
+    /// NAN master preference used in the master election, 1 to 254
+    pub fn master_pref(mut self, master_pref: u8) -> Self {
+        self.master_pref = Some(master_pref);
+        self
+    }
+
+    /// Bands to operate NAN on, the device picks when empty
+    pub fn bands(mut self, bands: &[Nl80211Band]) -> Self {
+        self.bands = Some(bands_to_bitmap(bands));
+        self
+    }
+
+    /// At least one of the master preference and the bands should be set.
+    pub async fn execute(self) -> Result<(), Nl80211Error> {
+        let Nl80211NanConfigChangeRequest {
+            mut handle,
+            wdev,
+            master_pref,
+            bands,
+        } = self;
+
+        let mut nlas = vec![Nl80211Attr::Wdev(wdev)];
+        if let Some(v) = master_pref {
+            nlas.push(Nl80211Attr::NanMasterPref(v));
+        }
+        if let Some(v) = bands {
+            nlas.push(Nl80211Attr::Bands(v));
+        }
+
+        let nl80211_msg = Nl80211Message {
+            cmd: Nl80211Cmd::NanConfigChange,
+            nlas,
+        };
+        nl80211_execute_ack(&mut handle, nl80211_msg).await
+    }
+}
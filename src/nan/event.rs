@@ -0,0 +1,59 @@
+// SPDX-License-Identifier: MIT
+
+use futures::{channel::mpsc::UnboundedReceiver, future, Stream, StreamExt};
+use genetlink::message::RawGenlMessage;
+use netlink_packet_core::NetlinkMessage;
+use netlink_sys::SocketAddr;
+
+use crate::{
+    frame::parse_unsolicited, Nl80211Attr, Nl80211Cmd, Nl80211Message,
+    Nl80211NanMatch,
+};
+
+/// Match of a local NAN function with a peer function
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct Nl80211NanMatchEvent {
+    pub wiphy: Option<u32>,
+    pub wdev: Option<u64>,
+    /// Cookie of the local function, as returned when adding it
+    pub cookie: Option<u64>,
+    pub matched: Nl80211NanMatch,
+}
+
+impl Nl80211NanMatchEvent {
+    /// Extract the event from a `Nl80211Cmd::NanMatch` message, `None` for
+    /// other messages
+    pub fn from_message(msg: &Nl80211Message) -> Option<Self> {
+        if msg.cmd != Nl80211Cmd::NanMatch {
+            return None;
+        }
+        let mut ret = Self::default();
+        let mut matched = None;
+        for nla in msg.nlas.iter() {
+            match nla {
+                Nl80211Attr::WiPhy(d) => ret.wiphy = Some(*d),
+                Nl80211Attr::Wdev(d) => ret.wdev = Some(*d),
+                Nl80211Attr::Cookie(d) => ret.cookie = Some(*d),
+                Nl80211Attr::NanMatch(d) => matched = Some(d.clone()),
+                _ => (),
+            }
+        }
+        ret.matched = matched?;
+        Some(ret)
+    }
+}
+
+/// Stream of the NAN match events received by the connection subscribed to
+/// the `nan` multicast group, built from the unsolicited messages returned
+/// by `new_connection()`. Other messages and those failed to parse are
+/// dropped.
+pub fn nl80211_nan_match_events(
+    messages: UnboundedReceiver<(NetlinkMessage<RawGenlMessage>, SocketAddr)>,
+) -> impl Stream<Item = Nl80211NanMatchEvent> {
+    messages.filter_map(|(msg, _)| {
+        future::ready(
+            parse_unsolicited(msg)
+                .and_then(|msg| Nl80211NanMatchEvent::from_message(&msg)),
+        )
+    })
+}
@@ -0,0 +1,162 @@
+// SPDX-License-Identifier: MIT
+
+use anyhow::Context;
+use netlink_packet_utils::{
+    nla::{DefaultNla, Nla, NlaBuffer, NlasIterator},
+    parsers::parse_mac,
+    DecodeError, Emitable, Parseable,
+};
+
+use crate::{Nl80211NanFunction, Nl80211NanFunctionNla};
+
+const NL80211_NAN_MATCH_FUNC_LOCAL: u16 = 1;
+const NL80211_NAN_MATCH_FUNC_PEER: u16 = 2;
+
+// The kernel reports the peer address inside the peer function using the
+// top level attribute kind
+const NL80211_ATTR_MAC: u16 = 6;
+
+const ETH_ALEN: usize = 6;
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum Nl80211NanMatchNla {
+    /// Local function which matched
+    FuncLocal(Nl80211NanFunction),
+    /// Peer function which matched and the address of the peer
+    FuncPeer(Nl80211NanFunction, [u8; ETH_ALEN]),
+    Other(DefaultNla),
+}
+
+impl Nla for Nl80211NanMatchNla {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::FuncLocal(ref d) => d.buffer_len(),
+            Self::FuncPeer(ref d, _) => {
+                d.buffer_len()
+                    + DefaultNla::new(NL80211_ATTR_MAC, vec![0; ETH_ALEN])
+                        .buffer_len()
+            }
+            Self::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::FuncLocal(_) => NL80211_NAN_MATCH_FUNC_LOCAL,
+            Self::FuncPeer(..) => NL80211_NAN_MATCH_FUNC_PEER,
+            Self::Other(attr) => attr.kind(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::FuncLocal(ref d) => d.emit(buffer),
+            Self::FuncPeer(ref d, mac) => {
+                let mac = DefaultNla::new(NL80211_ATTR_MAC, mac.to_vec());
+                mac.emit(buffer);
+                d.emit(&mut buffer[mac.buffer_len()..]);
+            }
+            Self::Other(ref attr) => attr.emit(buffer),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for Nl80211NanMatchNla
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            NL80211_NAN_MATCH_FUNC_LOCAL => {
+                Self::FuncLocal(Nl80211NanFunction::parse(buf)?)
+            }
+            NL80211_NAN_MATCH_FUNC_PEER => {
+                let err_msg = format!(
+                    "Invalid NL80211_NAN_MATCH_FUNC_PEER value {:?}",
+                    payload
+                );
+                let mut func = Nl80211NanFunction::default();
+                let mut mac = [0u8; ETH_ALEN];
+                for nla in NlasIterator::new(payload) {
+                    let nla = &nla.context(err_msg.clone())?;
+                    if nla.kind() == NL80211_ATTR_MAC {
+                        mac =
+                            parse_mac(nla.value()).context(err_msg.clone())?;
+                    } else {
+                        func.merge_nla(
+                            Nl80211NanFunctionNla::parse(nla)
+                                .context(err_msg.clone())?,
+                        );
+                    }
+                }
+                Self::FuncPeer(func, mac)
+            }
+            _ => Self::Other(
+                DefaultNla::parse(buf).context("invalid NLA (unknown kind)")?,
+            ),
+        })
+    }
+}
+
+/// Match between a local NAN function and a peer function
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct Nl80211NanMatch {
+    /// Local function, holding its type and instance ID
+    pub local: Nl80211NanFunction,
+    /// Peer function, holding its type, instance ID and service specific
+    /// information
+    pub peer: Nl80211NanFunction,
+    /// Address of the peer
+    pub peer_mac: [u8; ETH_ALEN],
+}
+
+impl Emitable for Nl80211NanMatch {
+    fn buffer_len(&self) -> usize {
+        Vec::<Nl80211NanMatchNla>::from(self)
+            .as_slice()
+            .buffer_len()
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        Vec::<Nl80211NanMatchNla>::from(self)
+            .as_slice()
+            .emit(buffer)
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for Nl80211NanMatch
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let mut ret = Self::default();
+        let payload = buf.value();
+        let err_msg =
+            format!("Invalid NL80211_ATTR_NAN_MATCH value {:?}", payload);
+        for nla in NlasIterator::new(payload) {
+            let nla = &nla.context(err_msg.clone())?;
+            match Nl80211NanMatchNla::parse(nla).context(err_msg.clone())? {
+                Nl80211NanMatchNla::FuncLocal(d) => ret.local = d,
+                Nl80211NanMatchNla::FuncPeer(d, mac) => {
+                    ret.peer = d;
+                    ret.peer_mac = mac;
+                }
+                Nl80211NanMatchNla::Other(attr) => {
+                    log::warn!(
+                        "Got unsupported NL80211_ATTR_NAN_MATCH value {:?}",
+                        attr
+                    )
+                }
+            }
+        }
+        Ok(ret)
+    }
+}
+
+impl From<&Nl80211NanMatch> for Vec<Nl80211NanMatchNla> {
+    fn from(v: &Nl80211NanMatch) -> Self {
+        vec![
+            Nl80211NanMatchNla::FuncLocal(v.local.clone()),
+            Nl80211NanMatchNla::FuncPeer(v.peer.clone(), v.peer_mac),
+        ]
+    }
+}
@@ -0,0 +1,75 @@
+// SPDX-License-Identifier: MIT
+
+use futures::TryStream;
+use netlink_packet_core::NLM_F_REQUEST;
+use netlink_packet_generic::GenlMessage;
+
+use crate::{
+    nl80211_execute, nl80211_execute_ack, Nl80211Attr, Nl80211Cmd,
+    Nl80211Error, Nl80211Handle, Nl80211Message, Nl80211NanFunction,
+};
+
+pub struct Nl80211NanFunctionAddRequest {
+    handle: Nl80211Handle,
+    wdev: u64,
+    func: Nl80211NanFunction,
+}
+
+impl Nl80211NanFunctionAddRequest {
+    pub(crate) fn new(
+        handle: Nl80211Handle,
+        wdev: u64,
+        func: Nl80211NanFunction,
+    ) -> Self {
+        Nl80211NanFunctionAddRequest { handle, wdev, func }
+    }
+
+    /// The reply holds `Nl80211Attr::Cookie` identifying the function and
+    /// `Nl80211Attr::NanFunc` with the instance ID assigned to it.
+    pub async fn execute(
+        self,
+    ) -> impl TryStream<Ok = GenlMessage<Nl80211Message>, Error = Nl80211Error>
+    {
+        let Nl80211NanFunctionAddRequest {
+            mut handle,
+            wdev,
+            func,
+        } = self;
+
+        let nl80211_msg = Nl80211Message {
+            cmd: Nl80211Cmd::NanFunctionAdd,
+            nlas: vec![Nl80211Attr::Wdev(wdev), Nl80211Attr::NanFunc(func)],
+        };
+        nl80211_execute(&mut handle, nl80211_msg, NLM_F_REQUEST).await
+    }
+}
+
+pub struct Nl80211NanFunctionDelRequest {
+    handle: Nl80211Handle,
+    wdev: u64,
+    cookie: u64,
+}
+
+impl Nl80211NanFunctionDelRequest {
+    pub(crate) fn new(handle: Nl80211Handle, wdev: u64, cookie: u64) -> Self {
+        Nl80211NanFunctionDelRequest {
+            handle,
+            wdev,
+            cookie,
+        }
+    }
+
+    pub async fn execute(self) -> Result<(), Nl80211Error> {
+        let Nl80211NanFunctionDelRequest {
+            mut handle,
+            wdev,
+            cookie,
+        } = self;
+
+        let nl80211_msg = Nl80211Message {
+            cmd: Nl80211Cmd::NanFunctionDel,
+            nlas: vec![Nl80211Attr::Wdev(wdev), Nl80211Attr::Cookie(cookie)],
+        };
+        nl80211_execute_ack(&mut handle, nl80211_msg).await
+    }
+}
@@ -0,0 +1,516 @@
+// SPDX-License-Identifier: MIT
+
+use anyhow::Context;
+use byteorder::{ByteOrder, NativeEndian};
+use netlink_packet_utils::{
+    nla::{DefaultNla, Nla, NlaBuffer, NlasIterator},
+    parsers::{parse_mac, parse_u32, parse_u8},
+    DecodeError, Emitable, Parseable,
+};
+
+use crate::{
+    nested::bytes_array_to_nlas, Nl80211NanFuncTermReason,
+    Nl80211NanFunctionType, Nl80211NanSrf,
+};
+
+const NL80211_NAN_FUNC_TYPE: u16 = 1;
+const NL80211_NAN_FUNC_SERVICE_ID: u16 = 2;
+const NL80211_NAN_FUNC_PUBLISH_TYPE: u16 = 3;
+const NL80211_NAN_FUNC_PUBLISH_BCAST: u16 = 4;
+const NL80211_NAN_FUNC_SUBSCRIBE_ACTIVE: u16 = 5;
+const NL80211_NAN_FUNC_FOLLOW_UP_ID: u16 = 6;
+const NL80211_NAN_FUNC_FOLLOW_UP_REQ_ID: u16 = 7;
+const NL80211_NAN_FUNC_FOLLOW_UP_DEST: u16 = 8;
+const NL80211_NAN_FUNC_CLOSE_RANGE: u16 = 9;
+const NL80211_NAN_FUNC_TTL: u16 = 10;
+const NL80211_NAN_FUNC_SERVICE_INFO: u16 = 11;
+const NL80211_NAN_FUNC_SRF: u16 = 12;
+const NL80211_NAN_FUNC_RX_MATCH_FILTER: u16 = 13;
+const NL80211_NAN_FUNC_TX_MATCH_FILTER: u16 = 14;
+const NL80211_NAN_FUNC_INSTANCE_ID: u16 = 15;
+const NL80211_NAN_FUNC_TERM_REASON: u16 = 16;
+
+const NL80211_NAN_SOLICITED_PUBLISH: u8 = 1 << 0;
+const NL80211_NAN_UNSOLICITED_PUBLISH: u8 = 1 << 1;
+
+const ETH_ALEN: usize = 6;
+const NL80211_NAN_FUNC_SERVICE_ID_LEN: usize = 6;
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum Nl80211NanFunctionNla {
+    Type(Nl80211NanFunctionType),
+    /// First 48 bits of the SHA-256 hash of the service name
+    ServiceId([u8; NL80211_NAN_FUNC_SERVICE_ID_LEN]),
+    /// Bitmap of the publish transmission types, bit 0 for solicited and
+    /// bit 1 for unsolicited
+    PublishType(u8),
+    /// Send solicited publish frames to the NAN broadcast address
+    PublishBcast,
+    /// Actively send subscribe frames
+    SubscribeActive,
+    /// Instance ID of the peer function the follow up is sent to
+    FollowUpId(u8),
+    /// Instance ID of the local function sending the follow up
+    FollowUpReqId(u8),
+    /// Address of the peer the follow up is sent to
+    FollowUpDest([u8; ETH_ALEN]),
+    /// Only match peers in close range, as defined by the device
+    CloseRange,
+    /// Number of discovery windows the function stays active for
+    Ttl(u32),
+    /// Raw service specific information
+    ServiceInfo(Vec<u8>),
+    /// Service response filter
+    Srf(Nl80211NanSrf),
+    /// Raw matching filters of received frames
+    RxMatchFilter(Vec<Vec<u8>>),
+    /// Raw matching filters of transmitted frames
+    TxMatchFilter(Vec<Vec<u8>>),
+    /// Instance ID assigned to the function by the kernel
+    InstanceId(u8),
+    /// Reason of the function termination
+    TermReason(Nl80211NanFuncTermReason),
+    Other(DefaultNla),
+}
+
+impl Nla for Nl80211NanFunctionNla {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::PublishBcast | Self::SubscribeActive | Self::CloseRange => 0,
+            Self::Type(_)
+            | Self::PublishType(_)
+            | Self::FollowUpId(_)
+            | Self::FollowUpReqId(_)
+            | Self::InstanceId(_)
+            | Self::TermReason(_) => 1,
+            Self::Ttl(_) => 4,
+            Self::ServiceId(_) => NL80211_NAN_FUNC_SERVICE_ID_LEN,
+            Self::FollowUpDest(_) => ETH_ALEN,
+            Self::ServiceInfo(ref d) => d.len(),
+            Self::Srf(ref d) => d.buffer_len(),
+            Self::RxMatchFilter(ref d) | Self::TxMatchFilter(ref d) => {
+                bytes_array_to_nlas(d).as_slice().buffer_len()
+            }
+            Self::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::Type(_) => NL80211_NAN_FUNC_TYPE,
+            Self::ServiceId(_) => NL80211_NAN_FUNC_SERVICE_ID,
+            Self::PublishType(_) => NL80211_NAN_FUNC_PUBLISH_TYPE,
+            Self::PublishBcast => NL80211_NAN_FUNC_PUBLISH_BCAST,
+            Self::SubscribeActive => NL80211_NAN_FUNC_SUBSCRIBE_ACTIVE,
+            Self::FollowUpId(_) => NL80211_NAN_FUNC_FOLLOW_UP_ID,
+            Self::FollowUpReqId(_) => NL80211_NAN_FUNC_FOLLOW_UP_REQ_ID,
+            Self::FollowUpDest(_) => NL80211_NAN_FUNC_FOLLOW_UP_DEST,
+            Self::CloseRange => NL80211_NAN_FUNC_CLOSE_RANGE,
+            Self::Ttl(_) => NL80211_NAN_FUNC_TTL,
+            Self::ServiceInfo(_) => NL80211_NAN_FUNC_SERVICE_INFO,
+            Self::Srf(_) => NL80211_NAN_FUNC_SRF,
+            Self::RxMatchFilter(_) => NL80211_NAN_FUNC_RX_MATCH_FILTER,
+            Self::TxMatchFilter(_) => NL80211_NAN_FUNC_TX_MATCH_FILTER,
+            Self::InstanceId(_) => NL80211_NAN_FUNC_INSTANCE_ID,
+            Self::TermReason(_) => NL80211_NAN_FUNC_TERM_REASON,
+            Self::Other(attr) => attr.kind(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::PublishBcast | Self::SubscribeActive | Self::CloseRange => (),
+            Self::PublishType(d)
+            | Self::FollowUpId(d)
+            | Self::FollowUpReqId(d)
+            | Self::InstanceId(d) => buffer[0] = *d,
+            Self::Type(d) => buffer[0] = (*d).into(),
+            Self::TermReason(d) => buffer[0] = (*d).into(),
+            Self::Ttl(d) => NativeEndian::write_u32(buffer, *d),
+            Self::ServiceId(ref d) => buffer[..d.len()].copy_from_slice(d),
+            Self::FollowUpDest(ref d) => buffer[..ETH_ALEN].copy_from_slice(d),
+            Self::ServiceInfo(ref d) => buffer[..d.len()].copy_from_slice(d),
+            Self::Srf(ref d) => d.emit(buffer),
+            Self::RxMatchFilter(ref d) | Self::TxMatchFilter(ref d) => {
+                bytes_array_to_nlas(d).as_slice().emit(buffer)
+            }
+            Self::Other(ref attr) => attr.emit(buffer),
+        }
+    }
+}
+
+fn parse_match_filter(payload: &[u8]) -> Result<Vec<Vec<u8>>, DecodeError> {
+    let mut filters = Vec::new();
+    for nla in NlasIterator::new(payload) {
+        filters.push(nla?.value().to_vec());
+    }
+    Ok(filters)
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for Nl80211NanFunctionNla
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            NL80211_NAN_FUNC_TYPE => {
+                let err_msg = format!(
+                    "Invalid NL80211_NAN_FUNC_TYPE value {:?}",
+                    payload
+                );
+                Self::Type(parse_u8(payload).context(err_msg)?.into())
+            }
+            NL80211_NAN_FUNC_SERVICE_ID => {
+                let err_msg = format!(
+                    "Invalid NL80211_NAN_FUNC_SERVICE_ID value {:?}",
+                    payload
+                );
+                let mut d = [0u8; NL80211_NAN_FUNC_SERVICE_ID_LEN];
+                d.copy_from_slice(
+                    payload
+                        .get(..NL80211_NAN_FUNC_SERVICE_ID_LEN)
+                        .ok_or_else(|| DecodeError::from(err_msg))?,
+                );
+                Self::ServiceId(d)
+            }
+            NL80211_NAN_FUNC_PUBLISH_TYPE => {
+                let err_msg = format!(
+                    "Invalid NL80211_NAN_FUNC_PUBLISH_TYPE value {:?}",
+                    payload
+                );
+                Self::PublishType(parse_u8(payload).context(err_msg)?)
+            }
+            NL80211_NAN_FUNC_PUBLISH_BCAST => Self::PublishBcast,
+            NL80211_NAN_FUNC_SUBSCRIBE_ACTIVE => Self::SubscribeActive,
+            NL80211_NAN_FUNC_FOLLOW_UP_ID => {
+                let err_msg = format!(
+                    "Invalid NL80211_NAN_FUNC_FOLLOW_UP_ID value {:?}",
+                    payload
+                );
+                Self::FollowUpId(parse_u8(payload).context(err_msg)?)
+            }
+            NL80211_NAN_FUNC_FOLLOW_UP_REQ_ID => {
+                let err_msg = format!(
+                    "Invalid NL80211_NAN_FUNC_FOLLOW_UP_REQ_ID value {:?}",
+                    payload
+                );
+                Self::FollowUpReqId(parse_u8(payload).context(err_msg)?)
+            }
+            NL80211_NAN_FUNC_FOLLOW_UP_DEST => {
+                let err_msg = format!(
+                    "Invalid NL80211_NAN_FUNC_FOLLOW_UP_DEST value {:?}",
+                    payload
+                );
+                Self::FollowUpDest(parse_mac(payload).context(err_msg)?)
+            }
+            NL80211_NAN_FUNC_CLOSE_RANGE => Self::CloseRange,
+            NL80211_NAN_FUNC_TTL => {
+                let err_msg =
+                    format!("Invalid NL80211_NAN_FUNC_TTL value {:?}", payload);
+                Self::Ttl(parse_u32(payload).context(err_msg)?)
+            }
+            NL80211_NAN_FUNC_SERVICE_INFO => {
+                Self::ServiceInfo(payload.to_vec())
+            }
+            NL80211_NAN_FUNC_SRF => Self::Srf(Nl80211NanSrf::parse(buf)?),
+            NL80211_NAN_FUNC_RX_MATCH_FILTER => {
+                let err_msg = format!(
+                    "Invalid NL80211_NAN_FUNC_RX_MATCH_FILTER value {:?}",
+                    payload
+                );
+                Self::RxMatchFilter(
+                    parse_match_filter(payload).context(err_msg)?,
+                )
+            }
+            NL80211_NAN_FUNC_TX_MATCH_FILTER => {
+                let err_msg = format!(
+                    "Invalid NL80211_NAN_FUNC_TX_MATCH_FILTER value {:?}",
+                    payload
+                );
+                Self::TxMatchFilter(
+                    parse_match_filter(payload).context(err_msg)?,
+                )
+            }
+            NL80211_NAN_FUNC_INSTANCE_ID => {
+                let err_msg = format!(
+                    "Invalid NL80211_NAN_FUNC_INSTANCE_ID value {:?}",
+                    payload
+                );
+                Self::InstanceId(parse_u8(payload).context(err_msg)?)
+            }
+            NL80211_NAN_FUNC_TERM_REASON => {
+                let err_msg = format!(
+                    "Invalid NL80211_NAN_FUNC_TERM_REASON value {:?}",
+                    payload
+                );
+                Self::TermReason(parse_u8(payload).context(err_msg)?.into())
+            }
+            _ => Self::Other(
+                DefaultNla::parse(buf).context("invalid NLA (unknown kind)")?,
+            ),
+        })
+    }
+}
+
+/// NAN service discovery function: a publish, subscribe or follow up
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct Nl80211NanFunction {
+    pub func_type: Option<Nl80211NanFunctionType>,
+    /// First 48 bits of the SHA-256 hash of the service name
+    pub service_id: Option<[u8; NL80211_NAN_FUNC_SERVICE_ID_LEN]>,
+    /// Bitmap of the publish transmission types, bit 0 for solicited and
+    /// bit 1 for unsolicited
+    pub publish_type: Option<u8>,
+    /// Send solicited publish frames to the NAN broadcast address
+    pub publish_bcast: bool,
+    /// Actively send subscribe frames
+    pub subscribe_active: bool,
+    /// Instance ID of the peer function the follow up is sent to
+    pub follow_up_id: Option<u8>,
+    /// Instance ID of the local function sending the follow up
+    pub follow_up_req_id: Option<u8>,
+    /// Address of the peer the follow up is sent to
+    pub follow_up_dest: Option<[u8; ETH_ALEN]>,
+    /// Only match peers in close range, as defined by the device
+    pub close_range: bool,
+    /// Number of discovery windows the function stays active for, forever
+    /// when not set
+    pub ttl: Option<u32>,
+    /// Raw service specific information
+    pub service_info: Option<Vec<u8>>,
+    /// Service response filter
+    pub srf: Option<Nl80211NanSrf>,
+    /// Raw matching filters of received frames
+    pub rx_match_filter: Option<Vec<Vec<u8>>>,
+    /// Raw matching filters of transmitted frames
+    pub tx_match_filter: Option<Vec<Vec<u8>>>,
+    /// Instance ID assigned to the function by the kernel
+    pub instance_id: Option<u8>,
+    /// Reason of the function termination
+    pub term_reason: Option<Nl80211NanFuncTermReason>,
+}
+
+impl Nl80211NanFunction {
+    /// Publish the service, in response to subscribe frames (solicited)
+    /// and/or unprompted (unsolicited)
+    pub fn publish(
+        service_id: [u8; NL80211_NAN_FUNC_SERVICE_ID_LEN],
+        solicited: bool,
+        unsolicited: bool,
+    ) -> Self {
+        let mut publish_type = 0;
+        if solicited {
+            publish_type |= NL80211_NAN_SOLICITED_PUBLISH;
+        }
+        if unsolicited {
+            publish_type |= NL80211_NAN_UNSOLICITED_PUBLISH;
+        }
+        Self {
+            func_type: Some(Nl80211NanFunctionType::Publish),
+            service_id: Some(service_id),
+            publish_type: Some(publish_type),
+            ..Default::default()
+        }
+    }
+
+    /// Subscribe to the service, sending subscribe frames when `active`
+    /// instead of only listening to publish frames
+    pub fn subscribe(
+        service_id: [u8; NL80211_NAN_FUNC_SERVICE_ID_LEN],
+        active: bool,
+    ) -> Self {
+        Self {
+            func_type: Some(Nl80211NanFunctionType::Subscribe),
+            service_id: Some(service_id),
+            subscribe_active: active,
+            ..Default::default()
+        }
+    }
+
+    /// Send a follow up from the local function `req_id` to the function
+    /// `peer_id` of the peer `dest`, as reported by the match event
+    pub fn follow_up(
+        service_id: [u8; NL80211_NAN_FUNC_SERVICE_ID_LEN],
+        peer_id: u8,
+        req_id: u8,
+        dest: [u8; ETH_ALEN],
+    ) -> Self {
+        Self {
+            func_type: Some(Nl80211NanFunctionType::FollowUp),
+            service_id: Some(service_id),
+            follow_up_id: Some(peer_id),
+            follow_up_req_id: Some(req_id),
+            follow_up_dest: Some(dest),
+            ..Default::default()
+        }
+    }
+
+    /// Send solicited publish frames to the NAN broadcast address
+    pub fn publish_bcast(mut self) -> Self {
+        self.publish_bcast = true;
+        self
+    }
+
+    /// Only match peers in close range, as defined by the device
+    pub fn close_range(mut self) -> Self {
+        self.close_range = true;
+        self
+    }
+
+    /// Number of discovery windows the function stays active for
+    pub fn ttl(mut self, ttl: u32) -> Self {
+        self.ttl = Some(ttl);
+        self
+    }
+
+    /// Raw service specific information, up to 255 bytes
+    pub fn service_info(mut self, info: Vec<u8>) -> Self {
+        self.service_info = Some(info);
+        self
+    }
+
+    pub fn srf(mut self, srf: Nl80211NanSrf) -> Self {
+        self.srf = Some(srf);
+        self
+    }
+
+    pub fn rx_match_filter(mut self, filters: Vec<Vec<u8>>) -> Self {
+        self.rx_match_filter = Some(filters);
+        self
+    }
+
+    pub fn tx_match_filter(mut self, filters: Vec<Vec<u8>>) -> Self {
+        self.tx_match_filter = Some(filters);
+        self
+    }
+
+    // Shared with the peer function of `NL80211_ATTR_NAN_MATCH` which also
+    // holds `NL80211_ATTR_MAC`
+    pub(crate) fn merge_nla(&mut self, nla: Nl80211NanFunctionNla) {
+        match nla {
+            Nl80211NanFunctionNla::Type(d) => self.func_type = Some(d),
+            Nl80211NanFunctionNla::ServiceId(d) => self.service_id = Some(d),
+            Nl80211NanFunctionNla::PublishType(d) => {
+                self.publish_type = Some(d)
+            }
+            Nl80211NanFunctionNla::PublishBcast => self.publish_bcast = true,
+            Nl80211NanFunctionNla::SubscribeActive => {
+                self.subscribe_active = true
+            }
+            Nl80211NanFunctionNla::FollowUpId(d) => self.follow_up_id = Some(d),
+            Nl80211NanFunctionNla::FollowUpReqId(d) => {
+                self.follow_up_req_id = Some(d)
+            }
+            Nl80211NanFunctionNla::FollowUpDest(d) => {
+                self.follow_up_dest = Some(d)
+            }
+            Nl80211NanFunctionNla::CloseRange => self.close_range = true,
+            Nl80211NanFunctionNla::Ttl(d) => self.ttl = Some(d),
+            Nl80211NanFunctionNla::ServiceInfo(d) => {
+                self.service_info = Some(d)
+            }
+            Nl80211NanFunctionNla::Srf(d) => self.srf = Some(d),
+            Nl80211NanFunctionNla::RxMatchFilter(d) => {
+                self.rx_match_filter = Some(d)
+            }
+            Nl80211NanFunctionNla::TxMatchFilter(d) => {
+                self.tx_match_filter = Some(d)
+            }
+            Nl80211NanFunctionNla::InstanceId(d) => self.instance_id = Some(d),
+            Nl80211NanFunctionNla::TermReason(d) => self.term_reason = Some(d),
+            Nl80211NanFunctionNla::Other(attr) => {
+                log::warn!(
+                    "Got unsupported NL80211_ATTR_NAN_FUNC value {:?}",
+                    attr
+                )
+            }
+        }
+    }
+}
+
+impl Emitable for Nl80211NanFunction {
+    fn buffer_len(&self) -> usize {
+        Vec::<Nl80211NanFunctionNla>::from(self)
+            .as_slice()
+            .buffer_len()
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        Vec::<Nl80211NanFunctionNla>::from(self)
+            .as_slice()
+            .emit(buffer)
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for Nl80211NanFunction
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let mut ret = Self::default();
+        let payload = buf.value();
+        let err_msg =
+            format!("Invalid NL80211_ATTR_NAN_FUNC value {:?}", payload);
+        for nla in NlasIterator::new(payload) {
+            let nla = &nla.context(err_msg.clone())?;
+            ret.merge_nla(
+                Nl80211NanFunctionNla::parse(nla).context(err_msg.clone())?,
+            );
+        }
+        Ok(ret)
+    }
+}
+
+impl From<&Nl80211NanFunction> for Vec<Nl80211NanFunctionNla> {
+    fn from(func: &Nl80211NanFunction) -> Self {
+        let mut nlas = Vec::new();
+        if let Some(v) = func.func_type {
+            nlas.push(Nl80211NanFunctionNla::Type(v));
+        }
+        if let Some(v) = func.service_id {
+            nlas.push(Nl80211NanFunctionNla::ServiceId(v));
+        }
+        if let Some(v) = func.publish_type {
+            nlas.push(Nl80211NanFunctionNla::PublishType(v));
+        }
+        if func.publish_bcast {
+            nlas.push(Nl80211NanFunctionNla::PublishBcast);
+        }
+        if func.subscribe_active {
+            nlas.push(Nl80211NanFunctionNla::SubscribeActive);
+        }
+        if let Some(v) = func.follow_up_id {
+            nlas.push(Nl80211NanFunctionNla::FollowUpId(v));
+        }
+        if let Some(v) = func.follow_up_req_id {
+            nlas.push(Nl80211NanFunctionNla::FollowUpReqId(v));
+        }
+        if let Some(v) = func.follow_up_dest {
+            nlas.push(Nl80211NanFunctionNla::FollowUpDest(v));
+        }
+        if func.close_range {
+            nlas.push(Nl80211NanFunctionNla::CloseRange);
+        }
+        if let Some(v) = func.ttl {
+            nlas.push(Nl80211NanFunctionNla::Ttl(v));
+        }
+        if let Some(v) = func.service_info.as_ref() {
+            nlas.push(Nl80211NanFunctionNla::ServiceInfo(v.clone()));
+        }
+        if let Some(v) = func.srf.as_ref() {
+            nlas.push(Nl80211NanFunctionNla::Srf(v.clone()));
+        }
+        if let Some(v) = func.rx_match_filter.as_ref() {
+            nlas.push(Nl80211NanFunctionNla::RxMatchFilter(v.clone()));
+        }
+        if let Some(v) = func.tx_match_filter.as_ref() {
+            nlas.push(Nl80211NanFunctionNla::TxMatchFilter(v.clone()));
+        }
+        if let Some(v) = func.instance_id {
+            nlas.push(Nl80211NanFunctionNla::InstanceId(v));
+        }
+        if let Some(v) = func.term_reason {
+            nlas.push(Nl80211NanFunctionNla::TermReason(v));
+        }
+        nlas
+    }
+}
@@ -0,0 +1,38 @@
+// SPDX-License-Identifier: MIT
+
+const NL80211_NAN_FUNC_PUBLISH: u8 = 0;
+const NL80211_NAN_FUNC_SUBSCRIBE: u8 = 1;
+const NL80211_NAN_FUNC_FOLLOW_UP: u8 = 2;
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Nl80211NanFunctionType {
+    /// Advertise a service
+    Publish,
+    /// Look for a service
+    Subscribe,
+    /// Exchange data with the peer function after a match
+    FollowUp,
+    Other(u8),
+}
+
+impl From<u8> for Nl80211NanFunctionType {
+    fn from(d: u8) -> Self {
+        match d {
+            NL80211_NAN_FUNC_PUBLISH => Self::Publish,
+            NL80211_NAN_FUNC_SUBSCRIBE => Self::Subscribe,
+            NL80211_NAN_FUNC_FOLLOW_UP => Self::FollowUp,
+            _ => Self::Other(d),
+        }
+    }
+}
+
+impl From<Nl80211NanFunctionType> for u8 {
+    fn from(v: Nl80211NanFunctionType) -> u8 {
+        match v {
+            Nl80211NanFunctionType::Publish => NL80211_NAN_FUNC_PUBLISH,
+            Nl80211NanFunctionType::Subscribe => NL80211_NAN_FUNC_SUBSCRIBE,
+            Nl80211NanFunctionType::FollowUp => NL80211_NAN_FUNC_FOLLOW_UP,
+            Nl80211NanFunctionType::Other(d) => d,
+        }
+    }
+}
@@ -0,0 +1,62 @@
+// SPDX-License-Identifier: MIT
+
+use crate::{
+    Nl80211Handle, Nl80211NanConfigChangeRequest, Nl80211NanFunction,
+    Nl80211NanFunctionAddRequest, Nl80211NanFunctionDelRequest,
+    Nl80211NanStartRequest, Nl80211NanStopRequest,
+};
+
+pub struct Nl80211NanHandle(Nl80211Handle);
+
+impl Nl80211NanHandle {
+    pub fn new(handle: Nl80211Handle) -> Self {
+        Nl80211NanHandle(handle)
+    }
+
+    /// Start NAN operation on specified NAN device with the master
+    /// preference used in the master election
+    /// (equivalent to `iw wdev WDEV nan start pref PREF`)
+    pub fn start(
+        &mut self,
+        wdev: u64,
+        master_pref: u8,
+    ) -> Nl80211NanStartRequest {
+        Nl80211NanStartRequest::new(self.0.clone(), wdev, master_pref)
+    }
+
+    /// Stop NAN operation on specified NAN device
+    /// (equivalent to `iw wdev WDEV nan stop`)
+    pub fn stop(&mut self, wdev: u64) -> Nl80211NanStopRequest {
+        Nl80211NanStopRequest::new(self.0.clone(), wdev)
+    }
+
+    /// Change the configuration of specified NAN device while running
+    /// (equivalent to `iw wdev WDEV nan config ...`)
+    pub fn change_config(
+        &mut self,
+        wdev: u64,
+    ) -> Nl80211NanConfigChangeRequest {
+        Nl80211NanConfigChangeRequest::new(self.0.clone(), wdev)
+    }
+
+    /// Add a publish, subscribe or follow up function to specified NAN
+    /// device
+    /// (equivalent to `iw wdev WDEV nan add_func ...`)
+    pub fn add_function(
+        &mut self,
+        wdev: u64,
+        func: Nl80211NanFunction,
+    ) -> Nl80211NanFunctionAddRequest {
+        Nl80211NanFunctionAddRequest::new(self.0.clone(), wdev, func)
+    }
+
+    /// Remove the function identified by the cookie returned when adding it
+    /// (equivalent to `iw wdev WDEV nan rm_func cookie COOKIE`)
+    pub fn del_function(
+        &mut self,
+        wdev: u64,
+        cookie: u64,
+    ) -> Nl80211NanFunctionDelRequest {
+        Nl80211NanFunctionDelRequest::new(self.0.clone(), wdev, cookie)
+    }
+}
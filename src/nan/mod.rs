@@ -0,0 +1,25 @@
+// SPDX-License-Identifier: MIT
+
+mod change_config;
+mod event;
+mod func_match;
+mod func_request;
+mod function;
+mod function_type;
+mod handle;
+mod srf;
+mod start;
+mod term_reason;
+
+pub use change_config::Nl80211NanConfigChangeRequest;
+pub use event::{nl80211_nan_match_events, Nl80211NanMatchEvent};
+pub use func_match::{Nl80211NanMatch, Nl80211NanMatchNla};
+pub use func_request::{
+    Nl80211NanFunctionAddRequest, Nl80211NanFunctionDelRequest,
+};
+pub use function::{Nl80211NanFunction, Nl80211NanFunctionNla};
+pub use function_type::Nl80211NanFunctionType;
+pub use handle::Nl80211NanHandle;
+pub use srf::{Nl80211NanSrf, Nl80211NanSrfNla};
+pub use start::{Nl80211NanStartRequest, Nl80211NanStopRequest};
+pub use term_reason::Nl80211NanFuncTermReason;
@@ -0,0 +1,195 @@
+// SPDX-License-Identifier: MIT
+
+use anyhow::Context;
+use netlink_packet_utils::{
+    nla::{DefaultNla, Nla, NlaBuffer, NlasIterator},
+    parsers::{parse_mac, parse_u8},
+    DecodeError, Emitable, Parseable,
+};
+
+use crate::nested::mac_addrs_to_nlas;
+
+const NL80211_NAN_SRF_INCLUDE: u16 = 1;
+const NL80211_NAN_SRF_BF: u16 = 2;
+const NL80211_NAN_SRF_BF_IDX: u16 = 3;
+const NL80211_NAN_SRF_MAC_ADDRS: u16 = 4;
+
+const ETH_ALEN: usize = 6;
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum Nl80211NanSrfNla {
+    /// Respond only to the listed peers instead of all but the listed peers
+    Include,
+    /// Bloom filter of the peer addresses
+    Bf(Vec<u8>),
+    /// Index of the bloom filter
+    BfIdx(u8),
+    /// Peer addresses
+    MacAddrs(Vec<[u8; ETH_ALEN]>),
+    Other(DefaultNla),
+}
+
+impl Nla for Nl80211NanSrfNla {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::Include => 0,
+            Self::Bf(ref d) => d.len(),
+            Self::BfIdx(_) => 1,
+            Self::MacAddrs(ref d) => {
+                mac_addrs_to_nlas(d).as_slice().buffer_len()
+            }
+            Self::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::Include => NL80211_NAN_SRF_INCLUDE,
+            Self::Bf(_) => NL80211_NAN_SRF_BF,
+            Self::BfIdx(_) => NL80211_NAN_SRF_BF_IDX,
+            Self::MacAddrs(_) => NL80211_NAN_SRF_MAC_ADDRS,
+            Self::Other(attr) => attr.kind(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Include => (),
+            Self::Bf(ref d) => buffer[..d.len()].copy_from_slice(d),
+            Self::BfIdx(d) => buffer[0] = *d,
+            Self::MacAddrs(ref d) => {
+                mac_addrs_to_nlas(d).as_slice().emit(buffer)
+            }
+            Self::Other(ref attr) => attr.emit(buffer),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for Nl80211NanSrfNla
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            NL80211_NAN_SRF_INCLUDE => Self::Include,
+            NL80211_NAN_SRF_BF => Self::Bf(payload.to_vec()),
+            NL80211_NAN_SRF_BF_IDX => {
+                let err_msg = format!(
+                    "Invalid NL80211_NAN_SRF_BF_IDX value {:?}",
+                    payload
+                );
+                Self::BfIdx(parse_u8(payload).context(err_msg)?)
+            }
+            NL80211_NAN_SRF_MAC_ADDRS => {
+                let err_msg = format!(
+                    "Invalid NL80211_NAN_SRF_MAC_ADDRS value {:?}",
+                    payload
+                );
+                let mut addrs = Vec::new();
+                for nla in NlasIterator::new(payload) {
+                    let nla = &nla.context(err_msg.clone())?;
+                    addrs
+                        .push(parse_mac(nla.value()).context(err_msg.clone())?);
+                }
+                Self::MacAddrs(addrs)
+            }
+            _ => Self::Other(
+                DefaultNla::parse(buf).context("invalid NLA (unknown kind)")?,
+            ),
+        })
+    }
+}
+
+/// Service response filter of a NAN function, selecting the peers to
+/// respond to either by address list or by bloom filter
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct Nl80211NanSrf {
+    /// Respond only to the listed peers instead of all but the listed peers
+    pub include: bool,
+    /// Bloom filter of the peer addresses
+    pub bf: Option<Vec<u8>>,
+    /// Index of the bloom filter, required with `bf`
+    pub bf_idx: Option<u8>,
+    /// Peer addresses, exclusive with `bf`
+    pub mac_addrs: Option<Vec<[u8; ETH_ALEN]>>,
+}
+
+impl Nl80211NanSrf {
+    /// Filter listing the peer addresses
+    pub fn new_mac_addrs(
+        include: bool,
+        mac_addrs: Vec<[u8; ETH_ALEN]>,
+    ) -> Self {
+        Self {
+            include,
+            mac_addrs: Some(mac_addrs),
+            ..Default::default()
+        }
+    }
+
+    /// Filter holding a bloom filter of the peer addresses
+    pub fn new_bloom_filter(include: bool, bf: Vec<u8>, bf_idx: u8) -> Self {
+        Self {
+            include,
+            bf: Some(bf),
+            bf_idx: Some(bf_idx),
+            ..Default::default()
+        }
+    }
+}
+
+impl Emitable for Nl80211NanSrf {
+    fn buffer_len(&self) -> usize {
+        Vec::<Nl80211NanSrfNla>::from(self).as_slice().buffer_len()
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        Vec::<Nl80211NanSrfNla>::from(self).as_slice().emit(buffer)
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for Nl80211NanSrf
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let mut ret = Self::default();
+        let payload = buf.value();
+        let err_msg =
+            format!("Invalid NL80211_NAN_FUNC_SRF value {:?}", payload);
+        for nla in NlasIterator::new(payload) {
+            let nla = &nla.context(err_msg.clone())?;
+            match Nl80211NanSrfNla::parse(nla).context(err_msg.clone())? {
+                Nl80211NanSrfNla::Include => ret.include = true,
+                Nl80211NanSrfNla::Bf(d) => ret.bf = Some(d),
+                Nl80211NanSrfNla::BfIdx(d) => ret.bf_idx = Some(d),
+                Nl80211NanSrfNla::MacAddrs(d) => ret.mac_addrs = Some(d),
+                Nl80211NanSrfNla::Other(attr) => {
+                    log::warn!(
+                        "Got unsupported NL80211_NAN_FUNC_SRF value {:?}",
+                        attr
+                    )
+                }
+            }
+        }
+        Ok(ret)
+    }
+}
+
+impl From<&Nl80211NanSrf> for Vec<Nl80211NanSrfNla> {
+    fn from(srf: &Nl80211NanSrf) -> Self {
+        let mut nlas = Vec::new();
+        if srf.include {
+            nlas.push(Nl80211NanSrfNla::Include);
+        }
+        if let Some(v) = srf.bf.as_ref() {
+            nlas.push(Nl80211NanSrfNla::Bf(v.clone()));
+        }
+        if let Some(v) = srf.bf_idx {
+            nlas.push(Nl80211NanSrfNla::BfIdx(v));
+        }
+        if let Some(v) = srf.mac_addrs.as_ref() {
+            nlas.push(Nl80211NanSrfNla::MacAddrs(v.clone()));
+        }
+        nlas
+    }
+}
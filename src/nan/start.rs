@@ -0,0 +1,84 @@
+// SPDX-License-Identifier: MIT
+
+use crate::{
+    nl80211_execute_ack, Nl80211Attr, Nl80211Band, Nl80211Cmd, Nl80211Error,
+    Nl80211Handle, Nl80211Message,
+};
+
+pub struct Nl80211NanStartRequest {
+    handle: Nl80211Handle,
+    wdev: u64,
+    master_pref: u8,
+    bands: Option<u32>,
+}
+
+impl Nl80211NanStartRequest {
+    pub(crate) fn new(
+        handle: Nl80211Handle,
+        wdev: u64,
+        master_pref: u8,
+    ) -> Self {
+        Nl80211NanStartRequest {
+            handle,
+            wdev,
+            master_pref,
+            bands: None,
+        }
+    }
+
+    /// Bands to operate NAN on, the device picks when not set
+    pub fn bands(mut self, bands: &[Nl80211Band]) -> Self {
+        self.bands = Some(bands_to_bitmap(bands));
+        self
+    }
+
+    pub async fn execute(self) -> Result<(), Nl80211Error> {
+        let Nl80211NanStartRequest {
+            mut handle,
+            wdev,
+            master_pref,
+            bands,
+        } = self;
+
+        let mut nlas = vec![
+            Nl80211Attr::Wdev(wdev),
+            Nl80211Attr::NanMasterPref(master_pref),
+        ];
+        if let Some(v) = bands {
+            nlas.push(Nl80211Attr::Bands(v));
+        }
+
+        let nl80211_msg = Nl80211Message {
+            cmd: Nl80211Cmd::NanStart,
+            nlas,
+        };
+        nl80211_execute_ack(&mut handle, nl80211_msg).await
+    }
+}
+
+pub(crate) fn bands_to_bitmap(bands: &[Nl80211Band]) -> u32 {
+    bands
+        .iter()
+        .fold(0, |bitmap, band| bitmap | (1 << u32::from(*band)))
+}
+
+pub struct Nl80211NanStopRequest {
+    handle: Nl80211Handle,
+    wdev: u64,
+}
+
+impl Nl80211NanStopRequest {
+    pub(crate) fn new(handle: Nl80211Handle, wdev: u64) -> Self {
+        Nl80211NanStopRequest { handle, wdev }
+    }
+
+    pub async fn execute(self) -> Result<(), Nl80211Error> {
+        let Nl80211NanStopRequest { mut handle, wdev } = self;
+
+        let nl80211_msg = Nl80211Message {
+            cmd: Nl80211Cmd::NanStop,
+            nlas: vec![Nl80211Attr::Wdev(wdev)],
+        };
+        nl80211_execute_ack(&mut handle, nl80211_msg).await
+    }
+}
@@ -0,0 +1,45 @@
+// SPDX-License-Identifier: MIT
+
+const NL80211_NAN_FUNC_TERM_REASON_USER_REQUEST: u8 = 0;
+const NL80211_NAN_FUNC_TERM_REASON_TTL_EXPIRED: u8 = 1;
+const NL80211_NAN_FUNC_TERM_REASON_ERROR: u8 = 2;
+
+/// Reason of a NAN function termination
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Nl80211NanFuncTermReason {
+    /// Deleted by userspace
+    UserRequest,
+    /// Number of discovery windows set as TTL elapsed
+    TtlExpired,
+    /// Terminated by the device
+    Error,
+    Other(u8),
+}
+
+impl From<u8> for Nl80211NanFuncTermReason {
+    fn from(d: u8) -> Self {
+        match d {
+            NL80211_NAN_FUNC_TERM_REASON_USER_REQUEST => Self::UserRequest,
+            NL80211_NAN_FUNC_TERM_REASON_TTL_EXPIRED => Self::TtlExpired,
+            NL80211_NAN_FUNC_TERM_REASON_ERROR => Self::Error,
+            _ => Self::Other(d),
+        }
+    }
+}
+
+impl From<Nl80211NanFuncTermReason> for u8 {
+    fn from(v: Nl80211NanFuncTermReason) -> u8 {
+        match v {
+            Nl80211NanFuncTermReason::UserRequest => {
+                NL80211_NAN_FUNC_TERM_REASON_USER_REQUEST
+            }
+            Nl80211NanFuncTermReason::TtlExpired => {
+                NL80211_NAN_FUNC_TERM_REASON_TTL_EXPIRED
+            }
+            Nl80211NanFuncTermReason::Error => {
+                NL80211_NAN_FUNC_TERM_REASON_ERROR
+            }
+            Nl80211NanFuncTermReason::Other(d) => d,
+        }
+    }
+}
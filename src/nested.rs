@@ -0,0 +1,58 @@
+// SPDX-License-Identifier: MIT
+
+use netlink_packet_utils::{
+    nla::{DefaultNla, Nla},
+    Emitable,
+};
+
+// Entry of nested attribute array. The kernel ignores the attribute kind of
+// array entries, we use the index starting from 1 like `iw` does.
+pub(crate) struct Nl80211NestedArrayEntry<'a, T> {
+    index: u16,
+    value: &'a T,
+}
+
+impl<'a, T: Emitable> Nla for Nl80211NestedArrayEntry<'a, T> {
+    fn value_len(&self) -> usize {
+        self.value.buffer_len()
+    }
+
+    fn kind(&self) -> u16 {
+        self.index
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        self.value.emit(buffer)
+    }
+}
+
+pub(crate) fn nested_array<T: Emitable>(
+    values: &[T],
+) -> Vec<Nl80211NestedArrayEntry<'_, T>> {
+    values
+        .iter()
+        .enumerate()
+        .map(|(i, value)| Nl80211NestedArrayEntry {
+            index: i as u16 + 1,
+            value,
+        })
+        .collect()
+}
+
+// Nested array of raw values, like the elements of NL80211_ATTR_MBSSID_ELEMS
+// or the match filters of a NAN function
+pub(crate) fn bytes_array_to_nlas(values: &[Vec<u8>]) -> Vec<DefaultNla> {
+    values
+        .iter()
+        .enumerate()
+        .map(|(i, v)| DefaultNla::new(i as u16 + 1, v.clone()))
+        .collect()
+}
+
+pub(crate) fn mac_addrs_to_nlas(addrs: &[[u8; 6]]) -> Vec<DefaultNla> {
+    addrs
+        .iter()
+        .enumerate()
+        .map(|(i, mac)| DefaultNla::new(i as u16 + 1, mac.to_vec()))
+        .collect()
+}
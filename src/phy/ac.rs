@@ -0,0 +1,44 @@
+// SPDX-License-Identifier: MIT
+
+const NL80211_AC_VO: u8 = 0;
+const NL80211_AC_VI: u8 = 1;
+const NL80211_AC_BE: u8 = 2;
+const NL80211_AC_BK: u8 = 3;
+
+/// Access category of 802.11e/WMM
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Nl80211Ac {
+    /// Voice
+    Vo,
+    /// Video
+    Vi,
+    /// Best effort
+    Be,
+    /// Background
+    Bk,
+    Other(u8),
+}
+
+impl From<u8> for Nl80211Ac {
+    fn from(d: u8) -> Self {
+        match d {
+            NL80211_AC_VO => Self::Vo,
+            NL80211_AC_VI => Self::Vi,
+            NL80211_AC_BE => Self::Be,
+            NL80211_AC_BK => Self::Bk,
+            _ => Self::Other(d),
+        }
+    }
+}
+
+impl From<Nl80211Ac> for u8 {
+    fn from(v: Nl80211Ac) -> u8 {
+        match v {
+            Nl80211Ac::Vo => NL80211_AC_VO,
+            Nl80211Ac::Vi => NL80211_AC_VI,
+            Nl80211Ac::Be => NL80211_AC_BE,
+            Nl80211Ac::Bk => NL80211_AC_BK,
+            Nl80211Ac::Other(d) => d,
+        }
+    }
+}
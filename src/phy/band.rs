@@ -0,0 +1,347 @@
+// SPDX-License-Identifier: MIT
+
+use anyhow::Context;
+use byteorder::{ByteOrder, NativeEndian};
+use netlink_packet_utils::{
+    nla::{DefaultNla, Nla, NlaBuffer, NlasIterator},
+    parsers::{parse_u16, parse_u32, parse_u8},
+    DecodeError, Emitable, Parseable,
+};
+
+use crate::{
+    nested::nested_array, Nl80211Band, Nl80211BandIftypeData, Nl80211Bitrate,
+    Nl80211Frequency, Nl80211HtCapability, Nl80211HtMcsInfo,
+    Nl80211VhtCapability, Nl80211VhtMcsInfo,
+};
+
+const NL80211_BAND_ATTR_FREQS: u16 = 1;
+const NL80211_BAND_ATTR_RATES: u16 = 2;
+const NL80211_BAND_ATTR_HT_MCS_SET: u16 = 3;
+const NL80211_BAND_ATTR_HT_CAPA: u16 = 4;
+const NL80211_BAND_ATTR_HT_AMPDU_FACTOR: u16 = 5;
+const NL80211_BAND_ATTR_HT_AMPDU_DENSITY: u16 = 6;
+const NL80211_BAND_ATTR_VHT_MCS_SET: u16 = 7;
+const NL80211_BAND_ATTR_VHT_CAPA: u16 = 8;
+const NL80211_BAND_ATTR_IFTYPE_DATA: u16 = 9;
+const NL80211_BAND_ATTR_EDMG_CHANNELS: u16 = 10;
+const NL80211_BAND_ATTR_EDMG_BW_CONFIG: u16 = 11;
+const NL80211_BAND_ATTR_S1G_MCS_NSS_SET: u16 = 12;
+const NL80211_BAND_ATTR_S1G_CAPA: u16 = 13;
+
+const S1G_MCS_NSS_SET_LEN: usize = 5;
+const S1G_CAPA_LEN: usize = 10;
+
+/// Capabilities of a band supported by the wiphy
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Nl80211WiPhyBand {
+    pub band: Nl80211Band,
+    pub info: Vec<Nl80211BandInfo>,
+}
+
+impl Nla for Nl80211WiPhyBand {
+    fn value_len(&self) -> usize {
+        self.info.as_slice().buffer_len()
+    }
+
+    fn kind(&self) -> u16 {
+        u32::from(self.band) as u16
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        self.info.as_slice().emit(buffer)
+    }
+}
+
+impl Nl80211WiPhyBand {
+    /// HT capabilities of this band, `None` if HT is not supported
+    pub fn ht_capability(&self) -> Option<Nl80211HtCapability> {
+        let mut ret = Nl80211HtCapability::default();
+        let mut found = false;
+        for info in self.info.as_slice() {
+            match info {
+                Nl80211BandInfo::HtCapa(d) => {
+                    ret.capa = *d;
+                    found = true;
+                }
+                Nl80211BandInfo::HtAmpduFactor(d) => ret.ampdu_factor = *d,
+                Nl80211BandInfo::HtAmpduDensity(d) => ret.ampdu_density = *d,
+                Nl80211BandInfo::HtMcsSet(d) => ret.mcs_set = *d,
+                _ => (),
+            }
+        }
+        if found {
+            Some(ret)
+        } else {
+            None
+        }
+    }
+
+    /// VHT capabilities of this band, `None` if VHT is not supported
+    pub fn vht_capability(&self) -> Option<Nl80211VhtCapability> {
+        let mut ret = Nl80211VhtCapability::default();
+        let mut found = false;
+        for info in self.info.as_slice() {
+            match info {
+                Nl80211BandInfo::VhtCapa(d) => {
+                    ret.capa = *d;
+                    found = true;
+                }
+                Nl80211BandInfo::VhtMcsSet(d) => ret.mcs_set = *d,
+                _ => (),
+            }
+        }
+        if found {
+            Some(ret)
+        } else {
+            None
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for Nl80211WiPhyBand
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        let err_msg =
+            format!("Invalid NL80211_ATTR_WIPHY_BANDS value {:?}", payload);
+        let mut info = Vec::new();
+        for nla in NlasIterator::new(payload) {
+            let nla = &nla.context(err_msg.clone())?;
+            info.push(Nl80211BandInfo::parse(nla).context(err_msg.clone())?);
+        }
+        Ok(Self {
+            band: Nl80211Band::from(buf.kind() as u32),
+            info,
+        })
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum Nl80211BandInfo {
+    /// Supported frequencies in this band
+    Freqs(Vec<Nl80211Frequency>),
+    /// Legacy bitrates supported in this band
+    Rates(Vec<Nl80211Bitrate>),
+    /// HT MCS set
+    HtMcsSet(Nl80211HtMcsInfo),
+    /// Raw HT Capability Information field
+    HtCapa(u16),
+    /// A-MPDU factor as in 802.11n
+    HtAmpduFactor(u8),
+    /// A-MPDU density as in 802.11n
+    HtAmpduDensity(u8),
+    /// VHT MCS and NSS set
+    VhtMcsSet(Nl80211VhtMcsInfo),
+    /// Raw VHT Capabilities Information field
+    VhtCapa(u32),
+    /// Capabilities per interface type, including HE capabilities
+    IftypeData(Vec<Nl80211BandIftypeData>),
+    /// Bitmap of the 2.16 GHz channels allowed for EDMG transmissions
+    EdmgChannels(u8),
+    /// EDMG channel BW configuration
+    EdmgBwConfig(u8),
+    /// Raw S1G supported S1G-MCS and NSS set
+    S1gMcsNssSet([u8; S1G_MCS_NSS_SET_LEN]),
+    /// Raw S1G Capabilities Information field
+    S1gCapa([u8; S1G_CAPA_LEN]),
+    Other(DefaultNla),
+}
+
+impl Nla for Nl80211BandInfo {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::Freqs(ref freqs) => {
+                nested_array(freqs).as_slice().buffer_len()
+            }
+            Self::Rates(ref rates) => {
+                nested_array(rates).as_slice().buffer_len()
+            }
+            Self::HtMcsSet(ref d) => d.buffer_len(),
+            Self::HtCapa(_) => 2,
+            Self::HtAmpduFactor(_)
+            | Self::HtAmpduDensity(_)
+            | Self::EdmgChannels(_)
+            | Self::EdmgBwConfig(_) => 1,
+            Self::VhtMcsSet(ref d) => d.buffer_len(),
+            Self::VhtCapa(_) => 4,
+            Self::IftypeData(ref d) => nested_array(d).as_slice().buffer_len(),
+            Self::S1gMcsNssSet(_) => S1G_MCS_NSS_SET_LEN,
+            Self::S1gCapa(_) => S1G_CAPA_LEN,
+            Self::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::Freqs(_) => NL80211_BAND_ATTR_FREQS,
+            Self::Rates(_) => NL80211_BAND_ATTR_RATES,
+            Self::HtMcsSet(_) => NL80211_BAND_ATTR_HT_MCS_SET,
+            Self::HtCapa(_) => NL80211_BAND_ATTR_HT_CAPA,
+            Self::HtAmpduFactor(_) => NL80211_BAND_ATTR_HT_AMPDU_FACTOR,
+            Self::HtAmpduDensity(_) => NL80211_BAND_ATTR_HT_AMPDU_DENSITY,
+            Self::VhtMcsSet(_) => NL80211_BAND_ATTR_VHT_MCS_SET,
+            Self::VhtCapa(_) => NL80211_BAND_ATTR_VHT_CAPA,
+            Self::IftypeData(_) => NL80211_BAND_ATTR_IFTYPE_DATA,
+            Self::EdmgChannels(_) => NL80211_BAND_ATTR_EDMG_CHANNELS,
+            Self::EdmgBwConfig(_) => NL80211_BAND_ATTR_EDMG_BW_CONFIG,
+            Self::S1gMcsNssSet(_) => NL80211_BAND_ATTR_S1G_MCS_NSS_SET,
+            Self::S1gCapa(_) => NL80211_BAND_ATTR_S1G_CAPA,
+            Self::Other(attr) => attr.kind(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Freqs(ref freqs) => {
+                nested_array(freqs).as_slice().emit(buffer)
+            }
+            Self::Rates(ref rates) => {
+                nested_array(rates).as_slice().emit(buffer)
+            }
+            Self::HtMcsSet(ref d) => d.emit(buffer),
+            Self::HtCapa(d) => NativeEndian::write_u16(buffer, *d),
+            Self::HtAmpduFactor(d)
+            | Self::HtAmpduDensity(d)
+            | Self::EdmgChannels(d)
+            | Self::EdmgBwConfig(d) => buffer[0] = *d,
+            Self::VhtMcsSet(ref d) => d.emit(buffer),
+            Self::VhtCapa(d) => NativeEndian::write_u32(buffer, *d),
+            Self::IftypeData(ref d) => nested_array(d).as_slice().emit(buffer),
+            Self::S1gMcsNssSet(ref d) => buffer[..d.len()].copy_from_slice(d),
+            Self::S1gCapa(ref d) => buffer[..d.len()].copy_from_slice(d),
+            Self::Other(ref attr) => attr.emit(buffer),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for Nl80211BandInfo
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            NL80211_BAND_ATTR_FREQS => {
+                let err_msg = format!(
+                    "Invalid NL80211_BAND_ATTR_FREQS value {:?}",
+                    payload
+                );
+                let mut freqs = Vec::new();
+                for nla in NlasIterator::new(payload) {
+                    let nla = &nla.context(err_msg.clone())?;
+                    freqs.push(
+                        Nl80211Frequency::parse(nla)
+                            .context(err_msg.clone())?,
+                    );
+                }
+                Self::Freqs(freqs)
+            }
+            NL80211_BAND_ATTR_RATES => {
+                let err_msg = format!(
+                    "Invalid NL80211_BAND_ATTR_RATES value {:?}",
+                    payload
+                );
+                let mut rates = Vec::new();
+                for nla in NlasIterator::new(payload) {
+                    let nla = &nla.context(err_msg.clone())?;
+                    rates.push(
+                        Nl80211Bitrate::parse(nla).context(err_msg.clone())?,
+                    );
+                }
+                Self::Rates(rates)
+            }
+            NL80211_BAND_ATTR_HT_MCS_SET => {
+                Self::HtMcsSet(Nl80211HtMcsInfo::parse(payload)?)
+            }
+            NL80211_BAND_ATTR_HT_CAPA => {
+                let err_msg = format!(
+                    "Invalid NL80211_BAND_ATTR_HT_CAPA value {:?}",
+                    payload
+                );
+                Self::HtCapa(parse_u16(payload).context(err_msg)?)
+            }
+            NL80211_BAND_ATTR_HT_AMPDU_FACTOR => {
+                let err_msg = format!(
+                    "Invalid NL80211_BAND_ATTR_HT_AMPDU_FACTOR value {:?}",
+                    payload
+                );
+                Self::HtAmpduFactor(parse_u8(payload).context(err_msg)?)
+            }
+            NL80211_BAND_ATTR_HT_AMPDU_DENSITY => {
+                let err_msg = format!(
+                    "Invalid NL80211_BAND_ATTR_HT_AMPDU_DENSITY value {:?}",
+                    payload
+                );
+                Self::HtAmpduDensity(parse_u8(payload).context(err_msg)?)
+            }
+            NL80211_BAND_ATTR_VHT_MCS_SET => {
+                Self::VhtMcsSet(Nl80211VhtMcsInfo::parse(payload)?)
+            }
+            NL80211_BAND_ATTR_VHT_CAPA => {
+                let err_msg = format!(
+                    "Invalid NL80211_BAND_ATTR_VHT_CAPA value {:?}",
+                    payload
+                );
+                Self::VhtCapa(parse_u32(payload).context(err_msg)?)
+            }
+            NL80211_BAND_ATTR_IFTYPE_DATA => {
+                let err_msg = format!(
+                    "Invalid NL80211_BAND_ATTR_IFTYPE_DATA value {:?}",
+                    payload
+                );
+                let mut data = Vec::new();
+                for nla in NlasIterator::new(payload) {
+                    let nla = &nla.context(err_msg.clone())?;
+                    data.push(
+                        Nl80211BandIftypeData::parse(nla)
+                            .context(err_msg.clone())?,
+                    );
+                }
+                Self::IftypeData(data)
+            }
+            NL80211_BAND_ATTR_EDMG_CHANNELS => {
+                let err_msg = format!(
+                    "Invalid NL80211_BAND_ATTR_EDMG_CHANNELS value {:?}",
+                    payload
+                );
+                Self::EdmgChannels(parse_u8(payload).context(err_msg)?)
+            }
+            NL80211_BAND_ATTR_EDMG_BW_CONFIG => {
+                let err_msg = format!(
+                    "Invalid NL80211_BAND_ATTR_EDMG_BW_CONFIG value {:?}",
+                    payload
+                );
+                Self::EdmgBwConfig(parse_u8(payload).context(err_msg)?)
+            }
+            NL80211_BAND_ATTR_S1G_MCS_NSS_SET => {
+                let err_msg = format!(
+                    "Invalid NL80211_BAND_ATTR_S1G_MCS_NSS_SET value {:?}",
+                    payload
+                );
+                let mut d = [0u8; S1G_MCS_NSS_SET_LEN];
+                d.copy_from_slice(
+                    payload
+                        .get(..S1G_MCS_NSS_SET_LEN)
+                        .ok_or_else(|| DecodeError::from(err_msg))?,
+                );
+                Self::S1gMcsNssSet(d)
+            }
+            NL80211_BAND_ATTR_S1G_CAPA => {
+                let err_msg = format!(
+                    "Invalid NL80211_BAND_ATTR_S1G_CAPA value {:?}",
+                    payload
+                );
+                let mut d = [0u8; S1G_CAPA_LEN];
+                d.copy_from_slice(
+                    payload
+                        .get(..S1G_CAPA_LEN)
+                        .ok_or_else(|| DecodeError::from(err_msg))?,
+                );
+                Self::S1gCapa(d)
+            }
+            _ => Self::Other(
+                DefaultNla::parse(buf).context("invalid NLA (unknown kind)")?,
+            ),
+        })
+    }
+}
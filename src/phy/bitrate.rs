@@ -0,0 +1,127 @@
+// SPDX-License-Identifier: MIT
+
+use anyhow::Context;
+use byteorder::{ByteOrder, NativeEndian};
+use netlink_packet_utils::{
+    nla::{DefaultNla, Nla, NlaBuffer, NlasIterator},
+    parsers::parse_u32,
+    DecodeError, Emitable, Parseable,
+};
+
+const NL80211_BITRATE_ATTR_RATE: u16 = 1;
+const NL80211_BITRATE_ATTR_2GHZ_SHORTPREAMBLE: u16 = 2;
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum Nl80211BitrateNla {
+    /// Bitrate in units of 100 kbps
+    Rate(u32),
+    /// Short preamble is supported in the 2.4 GHz band
+    ShortPreamble2Ghz,
+    Other(DefaultNla),
+}
+
+impl Nla for Nl80211BitrateNla {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::ShortPreamble2Ghz => 0,
+            Self::Rate(_) => 4,
+            Self::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::Rate(_) => NL80211_BITRATE_ATTR_RATE,
+            Self::ShortPreamble2Ghz => NL80211_BITRATE_ATTR_2GHZ_SHORTPREAMBLE,
+            Self::Other(attr) => attr.kind(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::ShortPreamble2Ghz => (),
+            Self::Rate(d) => NativeEndian::write_u32(buffer, *d),
+            Self::Other(ref attr) => attr.emit(buffer),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for Nl80211BitrateNla
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            NL80211_BITRATE_ATTR_RATE => {
+                let err_msg = format!(
+                    "Invalid NL80211_BITRATE_ATTR_RATE value {:?}",
+                    payload
+                );
+                Self::Rate(parse_u32(payload).context(err_msg)?)
+            }
+            NL80211_BITRATE_ATTR_2GHZ_SHORTPREAMBLE => Self::ShortPreamble2Ghz,
+            _ => Self::Other(
+                DefaultNla::parse(buf).context("invalid NLA (unknown kind)")?,
+            ),
+        })
+    }
+}
+
+/// Legacy bitrate supported by a band of the wiphy
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct Nl80211Bitrate {
+    /// Bitrate in units of 100 kbps
+    pub rate: Option<u32>,
+    /// Short preamble is supported in the 2.4 GHz band
+    pub short_preamble_2ghz: bool,
+}
+
+impl Emitable for Nl80211Bitrate {
+    fn buffer_len(&self) -> usize {
+        Vec::<Nl80211BitrateNla>::from(self).as_slice().buffer_len()
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        Vec::<Nl80211BitrateNla>::from(self).as_slice().emit(buffer)
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for Nl80211Bitrate
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let mut ret = Self::default();
+        let payload = buf.value();
+        let err_msg =
+            format!("Invalid NL80211_BAND_ATTR_RATES value {:?}", payload);
+        for nla in NlasIterator::new(payload) {
+            let nla = &nla.context(err_msg.clone())?;
+            match Nl80211BitrateNla::parse(nla).context(err_msg.clone())? {
+                Nl80211BitrateNla::Rate(d) => ret.rate = Some(d),
+                Nl80211BitrateNla::ShortPreamble2Ghz => {
+                    ret.short_preamble_2ghz = true
+                }
+                Nl80211BitrateNla::Other(attr) => {
+                    log::warn!(
+                        "Got unsupported NL80211_BAND_ATTR_RATES value {:?}",
+                        attr
+                    )
+                }
+            }
+        }
+        Ok(ret)
+    }
+}
+
+impl From<&Nl80211Bitrate> for Vec<Nl80211BitrateNla> {
+    fn from(rate: &Nl80211Bitrate) -> Self {
+        let mut nlas = Vec::new();
+        if let Some(v) = rate.rate {
+            nlas.push(Nl80211BitrateNla::Rate(v));
+        }
+        if rate.short_preamble_2ghz {
+            nlas.push(Nl80211BitrateNla::ShortPreamble2Ghz);
+        }
+        nlas
+    }
+}
@@ -0,0 +1,224 @@
+// SPDX-License-Identifier: MIT
+
+use anyhow::Context;
+use byteorder::{ByteOrder, NativeEndian};
+use netlink_packet_utils::{
+    nla::{DefaultNla, Nla, NlaBuffer, NlasIterator},
+    parsers::parse_u32,
+    DecodeError, Emitable, Parseable,
+};
+
+use crate::Nl80211InterfaceType;
+
+const NL80211_ATTR_IFTYPE: u16 = 5;
+const NL80211_ATTR_EXT_CAPA: u16 = 169;
+const NL80211_ATTR_EXT_CAPA_MASK: u16 = 170;
+
+const EXT_CAPA_BSS_TRANSITION: usize = 19;
+const EXT_CAPA_INTERWORKING: usize = 31;
+const EXT_CAPA_QOS_MAP: usize = 32;
+const EXT_CAPA_TDLS_SUPPORT: usize = 37;
+const EXT_CAPA_OPMODE_NOTIF: usize = 62;
+const EXT_CAPA_FTM_RESPONDER: usize = 70;
+const EXT_CAPA_FTM_INITIATOR: usize = 71;
+const EXT_CAPA_TWT_REQUESTER: usize = 77;
+const EXT_CAPA_TWT_RESPONDER: usize = 78;
+
+/// Extended Capabilities field as defined by IEEE 802.11
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct Nl80211ExtendedCapability(pub Vec<u8>);
+
+impl Nl80211ExtendedCapability {
+    /// Whether the capability bit is set
+    pub fn bit(&self, index: usize) -> bool {
+        self.0
+            .get(index / 8)
+            .map(|d| d & (1 << (index % 8)) > 0)
+            .unwrap_or_default()
+    }
+
+    /// BSS transition management (802.11v)
+    pub fn bss_transition(&self) -> bool {
+        self.bit(EXT_CAPA_BSS_TRANSITION)
+    }
+
+    pub fn interworking(&self) -> bool {
+        self.bit(EXT_CAPA_INTERWORKING)
+    }
+
+    pub fn qos_map(&self) -> bool {
+        self.bit(EXT_CAPA_QOS_MAP)
+    }
+
+    pub fn tdls_support(&self) -> bool {
+        self.bit(EXT_CAPA_TDLS_SUPPORT)
+    }
+
+    /// Operating mode notification
+    pub fn opmode_notification(&self) -> bool {
+        self.bit(EXT_CAPA_OPMODE_NOTIF)
+    }
+
+    /// Fine timing measurement responder
+    pub fn ftm_responder(&self) -> bool {
+        self.bit(EXT_CAPA_FTM_RESPONDER)
+    }
+
+    /// Fine timing measurement initiator
+    pub fn ftm_initiator(&self) -> bool {
+        self.bit(EXT_CAPA_FTM_INITIATOR)
+    }
+
+    /// Target wake time requester
+    pub fn twt_requester(&self) -> bool {
+        self.bit(EXT_CAPA_TWT_REQUESTER)
+    }
+
+    /// Target wake time responder
+    pub fn twt_responder(&self) -> bool {
+        self.bit(EXT_CAPA_TWT_RESPONDER)
+    }
+
+    pub(crate) fn parse(payload: &[u8]) -> Result<Self, DecodeError> {
+        Ok(Self(payload.to_vec()))
+    }
+}
+
+impl Emitable for Nl80211ExtendedCapability {
+    fn buffer_len(&self) -> usize {
+        self.0.len()
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        buffer[..self.0.len()].copy_from_slice(&self.0)
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum Nl80211IftypeExtCapaNla {
+    Iftype(Nl80211InterfaceType),
+    ExtCapa(Nl80211ExtendedCapability),
+    ExtCapaMask(Nl80211ExtendedCapability),
+    Other(DefaultNla),
+}
+
+impl Nla for Nl80211IftypeExtCapaNla {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::Iftype(_) => 4,
+            Self::ExtCapa(ref d) | Self::ExtCapaMask(ref d) => d.buffer_len(),
+            Self::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::Iftype(_) => NL80211_ATTR_IFTYPE,
+            Self::ExtCapa(_) => NL80211_ATTR_EXT_CAPA,
+            Self::ExtCapaMask(_) => NL80211_ATTR_EXT_CAPA_MASK,
+            Self::Other(attr) => attr.kind(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Iftype(d) => NativeEndian::write_u32(buffer, (*d).into()),
+            Self::ExtCapa(ref d) | Self::ExtCapaMask(ref d) => d.emit(buffer),
+            Self::Other(ref attr) => attr.emit(buffer),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for Nl80211IftypeExtCapaNla
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            NL80211_ATTR_IFTYPE => {
+                let err_msg =
+                    format!("Invalid NL80211_ATTR_IFTYPE value {:?}", payload);
+                Self::Iftype(parse_u32(payload).context(err_msg)?.into())
+            }
+            NL80211_ATTR_EXT_CAPA => {
+                Self::ExtCapa(Nl80211ExtendedCapability::parse(payload)?)
+            }
+            NL80211_ATTR_EXT_CAPA_MASK => {
+                Self::ExtCapaMask(Nl80211ExtendedCapability::parse(payload)?)
+            }
+            _ => Self::Other(
+                DefaultNla::parse(buf).context("invalid NLA (unknown kind)")?,
+            ),
+        })
+    }
+}
+
+/// Extended capabilities of the wiphy specific to an interface type
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct Nl80211IftypeExtCapa {
+    pub iftype: Option<Nl80211InterfaceType>,
+    pub ext_capa: Option<Nl80211ExtendedCapability>,
+    /// Bits of `ext_capa` which are valid
+    pub ext_capa_mask: Option<Nl80211ExtendedCapability>,
+}
+
+impl Emitable for Nl80211IftypeExtCapa {
+    fn buffer_len(&self) -> usize {
+        Vec::<Nl80211IftypeExtCapaNla>::from(self)
+            .as_slice()
+            .buffer_len()
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        Vec::<Nl80211IftypeExtCapaNla>::from(self)
+            .as_slice()
+            .emit(buffer)
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for Nl80211IftypeExtCapa
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let mut ret = Self::default();
+        let payload = buf.value();
+        let err_msg =
+            format!("Invalid NL80211_ATTR_IFTYPE_EXT_CAPA value {:?}", payload);
+        for nla in NlasIterator::new(payload) {
+            let nla = &nla.context(err_msg.clone())?;
+            match Nl80211IftypeExtCapaNla::parse(nla)
+                .context(err_msg.clone())?
+            {
+                Nl80211IftypeExtCapaNla::Iftype(d) => ret.iftype = Some(d),
+                Nl80211IftypeExtCapaNla::ExtCapa(d) => ret.ext_capa = Some(d),
+                Nl80211IftypeExtCapaNla::ExtCapaMask(d) => {
+                    ret.ext_capa_mask = Some(d)
+                }
+                Nl80211IftypeExtCapaNla::Other(attr) => {
+                    log::warn!(
+                        "Got unsupported NL80211_ATTR_IFTYPE_EXT_CAPA value \
+                         {:?}",
+                        attr
+                    )
+                }
+            }
+        }
+        Ok(ret)
+    }
+}
+
+impl From<&Nl80211IftypeExtCapa> for Vec<Nl80211IftypeExtCapaNla> {
+    fn from(capa: &Nl80211IftypeExtCapa) -> Self {
+        let mut nlas = Vec::new();
+        if let Some(v) = capa.iftype {
+            nlas.push(Nl80211IftypeExtCapaNla::Iftype(v));
+        }
+        if let Some(v) = capa.ext_capa.as_ref() {
+            nlas.push(Nl80211IftypeExtCapaNla::ExtCapa(v.clone()));
+        }
+        if let Some(v) = capa.ext_capa_mask.as_ref() {
+            nlas.push(Nl80211IftypeExtCapaNla::ExtCapaMask(v.clone()));
+        }
+        nlas
+    }
+}
@@ -0,0 +1,589 @@
+// SPDX-License-Identifier: MIT
+
+use netlink_packet_utils::{DecodeError, Emitable};
+
+const NL80211_EXT_FEATURE_VHT_IBSS: u32 = 0;
+const NL80211_EXT_FEATURE_RRM: u32 = 1;
+const NL80211_EXT_FEATURE_MU_MIMO_AIR_SNIFFER: u32 = 2;
+const NL80211_EXT_FEATURE_SCAN_START_TIME: u32 = 3;
+const NL80211_EXT_FEATURE_BSS_PARENT_TSF: u32 = 4;
+const NL80211_EXT_FEATURE_SET_SCAN_DWELL: u32 = 5;
+const NL80211_EXT_FEATURE_BEACON_RATE_LEGACY: u32 = 6;
+const NL80211_EXT_FEATURE_BEACON_RATE_HT: u32 = 7;
+const NL80211_EXT_FEATURE_BEACON_RATE_VHT: u32 = 8;
+const NL80211_EXT_FEATURE_FILS_STA: u32 = 9;
+const NL80211_EXT_FEATURE_MGMT_TX_RANDOM_TA: u32 = 10;
+const NL80211_EXT_FEATURE_MGMT_TX_RANDOM_TA_CONNECTED: u32 = 11;
+const NL80211_EXT_FEATURE_SCHED_SCAN_RELATIVE_RSSI: u32 = 12;
+const NL80211_EXT_FEATURE_CQM_RSSI_LIST: u32 = 13;
+const NL80211_EXT_FEATURE_FILS_SK_OFFLOAD: u32 = 14;
+const NL80211_EXT_FEATURE_4WAY_HANDSHAKE_STA_PSK: u32 = 15;
+const NL80211_EXT_FEATURE_4WAY_HANDSHAKE_STA_1X: u32 = 16;
+const NL80211_EXT_FEATURE_FILS_MAX_CHANNEL_TIME: u32 = 17;
+const NL80211_EXT_FEATURE_ACCEPT_BCAST_PROBE_RESP: u32 = 18;
+const NL80211_EXT_FEATURE_OCE_PROBE_REQ_HIGH_TX_RATE: u32 = 19;
+const NL80211_EXT_FEATURE_OCE_PROBE_REQ_DEFERRAL_SUPPRESSION: u32 = 20;
+const NL80211_EXT_FEATURE_MFP_OPTIONAL: u32 = 21;
+const NL80211_EXT_FEATURE_LOW_SPAN_SCAN: u32 = 22;
+const NL80211_EXT_FEATURE_LOW_POWER_SCAN: u32 = 23;
+const NL80211_EXT_FEATURE_HIGH_ACCURACY_SCAN: u32 = 24;
+const NL80211_EXT_FEATURE_DFS_OFFLOAD: u32 = 25;
+const NL80211_EXT_FEATURE_CONTROL_PORT_OVER_NL80211: u32 = 26;
+const NL80211_EXT_FEATURE_DATA_ACK_SIGNAL_SUPPORT: u32 = 27;
+const NL80211_EXT_FEATURE_TXQS: u32 = 28;
+const NL80211_EXT_FEATURE_SCAN_RANDOM_SN: u32 = 29;
+const NL80211_EXT_FEATURE_SCAN_MIN_PREQ_CONTENT: u32 = 30;
+const NL80211_EXT_FEATURE_CAN_REPLACE_PTK0: u32 = 31;
+const NL80211_EXT_FEATURE_ENABLE_FTM_RESPONDER: u32 = 32;
+const NL80211_EXT_FEATURE_AIRTIME_FAIRNESS: u32 = 33;
+const NL80211_EXT_FEATURE_AP_PMKSA_CACHING: u32 = 34;
+const NL80211_EXT_FEATURE_SCHED_SCAN_BAND_SPECIFIC_RSSI_THOLD: u32 = 35;
+const NL80211_EXT_FEATURE_EXT_KEY_ID: u32 = 36;
+const NL80211_EXT_FEATURE_STA_TX_PWR: u32 = 37;
+const NL80211_EXT_FEATURE_SAE_OFFLOAD: u32 = 38;
+const NL80211_EXT_FEATURE_VLAN_OFFLOAD: u32 = 39;
+const NL80211_EXT_FEATURE_AQL: u32 = 40;
+const NL80211_EXT_FEATURE_BEACON_PROTECTION: u32 = 41;
+const NL80211_EXT_FEATURE_CONTROL_PORT_NO_PREAUTH: u32 = 42;
+const NL80211_EXT_FEATURE_PROTECTED_TWT: u32 = 43;
+const NL80211_EXT_FEATURE_DEL_IBSS_STA: u32 = 44;
+const NL80211_EXT_FEATURE_MULTICAST_REGISTRATIONS: u32 = 45;
+const NL80211_EXT_FEATURE_BEACON_PROTECTION_CLIENT: u32 = 46;
+const NL80211_EXT_FEATURE_SCAN_FREQ_KHZ: u32 = 47;
+const NL80211_EXT_FEATURE_CONTROL_PORT_OVER_NL80211_TX_STATUS: u32 = 48;
+const NL80211_EXT_FEATURE_OPERATING_CHANNEL_VALIDATION: u32 = 49;
+const NL80211_EXT_FEATURE_4WAY_HANDSHAKE_AP_PSK: u32 = 50;
+const NL80211_EXT_FEATURE_SAE_OFFLOAD_AP: u32 = 51;
+const NL80211_EXT_FEATURE_FILS_DISCOVERY: u32 = 52;
+const NL80211_EXT_FEATURE_UNSOL_BCAST_PROBE_RESP: u32 = 53;
+const NL80211_EXT_FEATURE_BEACON_RATE_HE: u32 = 54;
+const NL80211_EXT_FEATURE_SECURE_LTF: u32 = 55;
+const NL80211_EXT_FEATURE_SECURE_RTT: u32 = 56;
+const NL80211_EXT_FEATURE_PROT_RANGE_NEGO_AND_MEASURE: u32 = 57;
+const NL80211_EXT_FEATURE_BSS_COLOR: u32 = 58;
+const NL80211_EXT_FEATURE_FILS_CRYPTO_OFFLOAD: u32 = 59;
+const NL80211_EXT_FEATURE_RADAR_BACKGROUND: u32 = 60;
+const NL80211_EXT_FEATURE_POWERED_ADDR_CHANGE: u32 = 61;
+
+/// Extended features a wiphy can advertise
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Nl80211ExtFeature {
+    /// This driver supports IBSS with VHT datarates
+    VhtIbss,
+    /// This driver supports RRM
+    Rrm,
+    /// This device supports MU-MIMO air sniffer which means that it can be
+    /// configured to hear packets from certain groups which can be configured
+    /// by the `NL80211_ATTR_MU_MIMO_GROUP_DATA` attribute, or can be configured
+    /// to follow a station by configuring the
+    /// `NL80211_ATTR_MU_MIMO_FOLLOW_MAC_ADDR` attribute
+    MuMimoAirSniffer,
+    /// This driver includes the actual time the scan started in scan results
+    /// event
+    ScanStartTime,
+    /// Per BSS, this driver reports the time the last beacon/probe was received
+    BssParentTsf,
+    /// This driver supports configuration of channel dwell time
+    SetScanDwell,
+    /// Driver supports beacon rate configuration (AP/mesh), supporting a legacy
+    /// (non HT/VHT) rate
+    BeaconRateLegacy,
+    /// Driver supports beacon rate configuration (AP/mesh) with HT rates
+    BeaconRateHt,
+    /// Driver supports beacon rate configuration (AP/mesh) with VHT rates
+    BeaconRateVht,
+    /// This driver supports Fast Initial Link Setup with user space SME
+    /// (`NL80211_CMD_AUTHENTICATE`) in station mode
+    FilsSta,
+    /// This driver supports randomized TA in `NL80211_CMD_FRAME` while not
+    /// associated
+    MgmtTxRandomTa,
+    /// This driver supports randomized TA in `NL80211_CMD_FRAME` while
+    /// associated
+    MgmtTxRandomTaConnected,
+    /// The driver supports sched_scan for reporting BSSs with better RSSI than
+    /// the current connected BSS (`NL80211_ATTR_SCHED_SCAN_RELATIVE_RSSI`)
+    SchedScanRelativeRssi,
+    /// With this driver the `NL80211_ATTR_CQM_RSSI_THOLD` attribute accepts a
+    /// list of zero or more RSSI threshold values to monitor rather than
+    /// exactly one threshold
+    CqmRssiList,
+    /// Driver SME supports FILS shared key authentication with
+    /// `NL80211_CMD_CONNECT`
+    FilsSkOffload,
+    /// Device wants to do 4-way handshake with PSK in station mode (PSK is
+    /// passed as part of the connect and associate commands), doing it in the
+    /// host might not be supported
+    FourWayHandshakeStaPsk,
+    /// Device wants to do doing 4-way handshake with 802.1X in station mode
+    /// (will pass EAP frames to the host and accept the set_pmk/del_pmk
+    /// commands), doing it in the host might not be supported
+    FourWayHandshakeSta1x,
+    /// Driver is capable of overriding the max channel attribute in the FILS
+    /// request params IE with the actual dwell time
+    FilsMaxChannelTime,
+    /// Driver accepts broadcast probe response
+    AcceptBcastProbeResp,
+    /// Driver supports sending the first probe request in each channel at rate
+    /// of at least 5.5Mbps
+    OceProbeReqHighTxRate,
+    /// Driver supports probe request tx deferral and suppression
+    OceProbeReqDeferralSuppression,
+    /// Driver supports the `NL80211_MFP_OPTIONAL` value in `NL80211_ATTR_USE_MFP`
+    MfpOptional,
+    /// Driver supports low span scan
+    LowSpanScan,
+    /// Driver supports low power scan
+    LowPowerScan,
+    /// Driver supports high accuracy scan
+    HighAccuracyScan,
+    /// HW/driver will offload DFS actions
+    DfsOffload,
+    /// Driver supports sending and receiving control port frames over nl80211
+    /// instead of the netdevice
+    ControlPortOverNl80211,
+    /// This driver supports ACK signal strength reporting for data frames,
+    /// also known as `NL80211_EXT_FEATURE_ACK_SIGNAL_SUPPORT`
+    DataAckSignalSupport,
+    /// Driver supports FQ-CoDel-enabled intermediate TXQs
+    Txqs,
+    /// Driver/device supports randomizing the SN in probe request frames if
+    /// requested by `NL80211_SCAN_FLAG_RANDOM_SN`
+    ScanRandomSn,
+    /// Driver/device can omit all data except for supported rates from the
+    /// probe request content if requested by the
+    /// `NL80211_SCAN_FLAG_MIN_PREQ_CONTENT` flag
+    ScanMinPreqContent,
+    /// Driver/device confirm that they are able to rekey an in-use key
+    /// correctly
+    CanReplacePtk0,
+    /// Driver supports enabling fine timing measurement responder role
+    EnableFtmResponder,
+    /// Driver supports getting airtime fairness for transmitted packets and has
+    /// enabled airtime fairness scheduling
+    AirtimeFairness,
+    /// Driver/device supports PMKSA caching (set/del PMKSA operations) in AP
+    /// mode
+    ApPmksaCaching,
+    /// Driver supports filtering of sched scan results using band specific RSSI
+    /// thresholds
+    SchedScanBandSpecificRssiThold,
+    /// Driver supports "Extended Key ID for Individually Addressed Frames" from
+    /// IEEE802.11-2016
+    ExtKeyId,
+    /// This driver supports controlling tx power to a station
+    StaTxPwr,
+    /// Device wants to do SAE authentication in station mode (SAE password is
+    /// passed as part of the connect command)
+    SaeOffload,
+    /// The driver supports a single netdev with VLAN tagged frames and separate
+    /// VLAN-specific netdevs added using vconfig similarly to the Ethernet case
+    VlanOffload,
+    /// The driver supports the Airtime Queue Limit (AQL) feature, which
+    /// prevents bufferbloat by using the expected transmission time to limit
+    /// the amount of data buffered in the hardware
+    Aql,
+    /// The driver supports Beacon protection and can receive key configuration
+    /// for BIGTK using key indexes 6 and 7
+    BeaconProtection,
+    /// The driver can disable the forwarding of preauth frames over the control
+    /// port
+    ControlPortNoPreauth,
+    /// Driver supports protected TWT frames
+    ProtectedTwt,
+    /// The driver supports removing stations in IBSS mode, essentially by
+    /// dropping their state
+    DelIbssSta,
+    /// Management frame registrations are possible for multicast frames and
+    /// those will be reported properly
+    MulticastRegistrations,
+    /// The driver supports Beacon protection as a client only and cannot
+    /// transmit protected beacons
+    BeaconProtectionClient,
+    /// This driver supports receiving and reporting scan request with
+    /// `NL80211_ATTR_SCAN_FREQ_KHZ`
+    ScanFreqKhz,
+    /// The driver can report tx status for control port over nl80211 tx
+    /// operations
+    ControlPortOverNl80211TxStatus,
+    /// Driver supports Operating Channel Validation (OCV) when using driver's
+    /// SME for RSNA handshakes
+    OperatingChannelValidation,
+    /// Device wants to do 4-way handshake with PSK in AP mode (PSK is passed as
+    /// part of the start AP command)
+    FourWayHandshakeApPsk,
+    /// Device wants to do SAE authentication in AP mode (SAE password is passed
+    /// as part of the start AP command)
+    SaeOffloadAp,
+    /// Driver/device supports FILS discovery frames transmission
+    FilsDiscovery,
+    /// Driver/device supports unsolicited broadcast probe response transmission
+    UnsolBcastProbeResp,
+    /// Driver supports beacon rate configuration (AP/mesh) with HE rates
+    BeaconRateHe,
+    /// Device supports secure LTF measurement exchange protocol
+    SecureLtf,
+    /// Device supports secure RTT measurement exchange protocol
+    SecureRtt,
+    /// Device supports management frame protection for all management frames
+    /// exchanged during the negotiation and range measurement procedure
+    ProtRangeNegoAndMeasure,
+    /// The driver supports BSS color collision detection and change
+    /// announcements
+    BssColor,
+    /// Driver running in AP mode supports FILS encryption and decryption for
+    /// (Re)Association Request and Response frames
+    FilsCryptoOffload,
+    /// Device supports background radar/CAC detection
+    RadarBackground,
+    /// Device can perform a MAC address change without having to bring the
+    /// underlying network device down first
+    PoweredAddrChange,
+    Other(u32),
+}
+
+impl From<u32> for Nl80211ExtFeature {
+    fn from(d: u32) -> Self {
+        match d {
+            NL80211_EXT_FEATURE_VHT_IBSS => Self::VhtIbss,
+            NL80211_EXT_FEATURE_RRM => Self::Rrm,
+            NL80211_EXT_FEATURE_MU_MIMO_AIR_SNIFFER => Self::MuMimoAirSniffer,
+            NL80211_EXT_FEATURE_SCAN_START_TIME => Self::ScanStartTime,
+            NL80211_EXT_FEATURE_BSS_PARENT_TSF => Self::BssParentTsf,
+            NL80211_EXT_FEATURE_SET_SCAN_DWELL => Self::SetScanDwell,
+            NL80211_EXT_FEATURE_BEACON_RATE_LEGACY => Self::BeaconRateLegacy,
+            NL80211_EXT_FEATURE_BEACON_RATE_HT => Self::BeaconRateHt,
+            NL80211_EXT_FEATURE_BEACON_RATE_VHT => Self::BeaconRateVht,
+            NL80211_EXT_FEATURE_FILS_STA => Self::FilsSta,
+            NL80211_EXT_FEATURE_MGMT_TX_RANDOM_TA => Self::MgmtTxRandomTa,
+            NL80211_EXT_FEATURE_MGMT_TX_RANDOM_TA_CONNECTED => {
+                Self::MgmtTxRandomTaConnected
+            }
+            NL80211_EXT_FEATURE_SCHED_SCAN_RELATIVE_RSSI => {
+                Self::SchedScanRelativeRssi
+            }
+            NL80211_EXT_FEATURE_CQM_RSSI_LIST => Self::CqmRssiList,
+            NL80211_EXT_FEATURE_FILS_SK_OFFLOAD => Self::FilsSkOffload,
+            NL80211_EXT_FEATURE_4WAY_HANDSHAKE_STA_PSK => {
+                Self::FourWayHandshakeStaPsk
+            }
+            NL80211_EXT_FEATURE_4WAY_HANDSHAKE_STA_1X => {
+                Self::FourWayHandshakeSta1x
+            }
+            NL80211_EXT_FEATURE_FILS_MAX_CHANNEL_TIME => {
+                Self::FilsMaxChannelTime
+            }
+            NL80211_EXT_FEATURE_ACCEPT_BCAST_PROBE_RESP => {
+                Self::AcceptBcastProbeResp
+            }
+            NL80211_EXT_FEATURE_OCE_PROBE_REQ_HIGH_TX_RATE => {
+                Self::OceProbeReqHighTxRate
+            }
+            NL80211_EXT_FEATURE_OCE_PROBE_REQ_DEFERRAL_SUPPRESSION => {
+                Self::OceProbeReqDeferralSuppression
+            }
+            NL80211_EXT_FEATURE_MFP_OPTIONAL => Self::MfpOptional,
+            NL80211_EXT_FEATURE_LOW_SPAN_SCAN => Self::LowSpanScan,
+            NL80211_EXT_FEATURE_LOW_POWER_SCAN => Self::LowPowerScan,
+            NL80211_EXT_FEATURE_HIGH_ACCURACY_SCAN => Self::HighAccuracyScan,
+            NL80211_EXT_FEATURE_DFS_OFFLOAD => Self::DfsOffload,
+            NL80211_EXT_FEATURE_CONTROL_PORT_OVER_NL80211 => {
+                Self::ControlPortOverNl80211
+            }
+            NL80211_EXT_FEATURE_DATA_ACK_SIGNAL_SUPPORT => {
+                Self::DataAckSignalSupport
+            }
+            NL80211_EXT_FEATURE_TXQS => Self::Txqs,
+            NL80211_EXT_FEATURE_SCAN_RANDOM_SN => Self::ScanRandomSn,
+            NL80211_EXT_FEATURE_SCAN_MIN_PREQ_CONTENT => {
+                Self::ScanMinPreqContent
+            }
+            NL80211_EXT_FEATURE_CAN_REPLACE_PTK0 => Self::CanReplacePtk0,
+            NL80211_EXT_FEATURE_ENABLE_FTM_RESPONDER => {
+                Self::EnableFtmResponder
+            }
+            NL80211_EXT_FEATURE_AIRTIME_FAIRNESS => Self::AirtimeFairness,
+            NL80211_EXT_FEATURE_AP_PMKSA_CACHING => Self::ApPmksaCaching,
+            NL80211_EXT_FEATURE_SCHED_SCAN_BAND_SPECIFIC_RSSI_THOLD => {
+                Self::SchedScanBandSpecificRssiThold
+            }
+            NL80211_EXT_FEATURE_EXT_KEY_ID => Self::ExtKeyId,
+            NL80211_EXT_FEATURE_STA_TX_PWR => Self::StaTxPwr,
+            NL80211_EXT_FEATURE_SAE_OFFLOAD => Self::SaeOffload,
+            NL80211_EXT_FEATURE_VLAN_OFFLOAD => Self::VlanOffload,
+            NL80211_EXT_FEATURE_AQL => Self::Aql,
+            NL80211_EXT_FEATURE_BEACON_PROTECTION => Self::BeaconProtection,
+            NL80211_EXT_FEATURE_CONTROL_PORT_NO_PREAUTH => {
+                Self::ControlPortNoPreauth
+            }
+            NL80211_EXT_FEATURE_PROTECTED_TWT => Self::ProtectedTwt,
+            NL80211_EXT_FEATURE_DEL_IBSS_STA => Self::DelIbssSta,
+            NL80211_EXT_FEATURE_MULTICAST_REGISTRATIONS => {
+                Self::MulticastRegistrations
+            }
+            NL80211_EXT_FEATURE_BEACON_PROTECTION_CLIENT => {
+                Self::BeaconProtectionClient
+            }
+            NL80211_EXT_FEATURE_SCAN_FREQ_KHZ => Self::ScanFreqKhz,
+            NL80211_EXT_FEATURE_CONTROL_PORT_OVER_NL80211_TX_STATUS => {
+                Self::ControlPortOverNl80211TxStatus
+            }
+            NL80211_EXT_FEATURE_OPERATING_CHANNEL_VALIDATION => {
+                Self::OperatingChannelValidation
+            }
+            NL80211_EXT_FEATURE_4WAY_HANDSHAKE_AP_PSK => {
+                Self::FourWayHandshakeApPsk
+            }
+            NL80211_EXT_FEATURE_SAE_OFFLOAD_AP => Self::SaeOffloadAp,
+            NL80211_EXT_FEATURE_FILS_DISCOVERY => Self::FilsDiscovery,
+            NL80211_EXT_FEATURE_UNSOL_BCAST_PROBE_RESP => {
+                Self::UnsolBcastProbeResp
+            }
+            NL80211_EXT_FEATURE_BEACON_RATE_HE => Self::BeaconRateHe,
+            NL80211_EXT_FEATURE_SECURE_LTF => Self::SecureLtf,
+            NL80211_EXT_FEATURE_SECURE_RTT => Self::SecureRtt,
+            NL80211_EXT_FEATURE_PROT_RANGE_NEGO_AND_MEASURE => {
+                Self::ProtRangeNegoAndMeasure
+            }
+            NL80211_EXT_FEATURE_BSS_COLOR => Self::BssColor,
+            NL80211_EXT_FEATURE_FILS_CRYPTO_OFFLOAD => Self::FilsCryptoOffload,
+            NL80211_EXT_FEATURE_RADAR_BACKGROUND => Self::RadarBackground,
+            NL80211_EXT_FEATURE_POWERED_ADDR_CHANGE => Self::PoweredAddrChange,
+            _ => Self::Other(d),
+        }
+    }
+}
+
+impl From<Nl80211ExtFeature> for u32 {
+    fn from(v: Nl80211ExtFeature) -> u32 {
+        match v {
+            Nl80211ExtFeature::VhtIbss => NL80211_EXT_FEATURE_VHT_IBSS,
+            Nl80211ExtFeature::Rrm => NL80211_EXT_FEATURE_RRM,
+            Nl80211ExtFeature::MuMimoAirSniffer => {
+                NL80211_EXT_FEATURE_MU_MIMO_AIR_SNIFFER
+            }
+            Nl80211ExtFeature::ScanStartTime => {
+                NL80211_EXT_FEATURE_SCAN_START_TIME
+            }
+            Nl80211ExtFeature::BssParentTsf => {
+                NL80211_EXT_FEATURE_BSS_PARENT_TSF
+            }
+            Nl80211ExtFeature::SetScanDwell => {
+                NL80211_EXT_FEATURE_SET_SCAN_DWELL
+            }
+            Nl80211ExtFeature::BeaconRateLegacy => {
+                NL80211_EXT_FEATURE_BEACON_RATE_LEGACY
+            }
+            Nl80211ExtFeature::BeaconRateHt => {
+                NL80211_EXT_FEATURE_BEACON_RATE_HT
+            }
+            Nl80211ExtFeature::BeaconRateVht => {
+                NL80211_EXT_FEATURE_BEACON_RATE_VHT
+            }
+            Nl80211ExtFeature::FilsSta => NL80211_EXT_FEATURE_FILS_STA,
+            Nl80211ExtFeature::MgmtTxRandomTa => {
+                NL80211_EXT_FEATURE_MGMT_TX_RANDOM_TA
+            }
+            Nl80211ExtFeature::MgmtTxRandomTaConnected => {
+                NL80211_EXT_FEATURE_MGMT_TX_RANDOM_TA_CONNECTED
+            }
+            Nl80211ExtFeature::SchedScanRelativeRssi => {
+                NL80211_EXT_FEATURE_SCHED_SCAN_RELATIVE_RSSI
+            }
+            Nl80211ExtFeature::CqmRssiList => NL80211_EXT_FEATURE_CQM_RSSI_LIST,
+            Nl80211ExtFeature::FilsSkOffload => {
+                NL80211_EXT_FEATURE_FILS_SK_OFFLOAD
+            }
+            Nl80211ExtFeature::FourWayHandshakeStaPsk => {
+                NL80211_EXT_FEATURE_4WAY_HANDSHAKE_STA_PSK
+            }
+            Nl80211ExtFeature::FourWayHandshakeSta1x => {
+                NL80211_EXT_FEATURE_4WAY_HANDSHAKE_STA_1X
+            }
+            Nl80211ExtFeature::FilsMaxChannelTime => {
+                NL80211_EXT_FEATURE_FILS_MAX_CHANNEL_TIME
+            }
+            Nl80211ExtFeature::AcceptBcastProbeResp => {
+                NL80211_EXT_FEATURE_ACCEPT_BCAST_PROBE_RESP
+            }
+            Nl80211ExtFeature::OceProbeReqHighTxRate => {
+                NL80211_EXT_FEATURE_OCE_PROBE_REQ_HIGH_TX_RATE
+            }
+            Nl80211ExtFeature::OceProbeReqDeferralSuppression => {
+                NL80211_EXT_FEATURE_OCE_PROBE_REQ_DEFERRAL_SUPPRESSION
+            }
+            Nl80211ExtFeature::MfpOptional => NL80211_EXT_FEATURE_MFP_OPTIONAL,
+            Nl80211ExtFeature::LowSpanScan => NL80211_EXT_FEATURE_LOW_SPAN_SCAN,
+            Nl80211ExtFeature::LowPowerScan => {
+                NL80211_EXT_FEATURE_LOW_POWER_SCAN
+            }
+            Nl80211ExtFeature::HighAccuracyScan => {
+                NL80211_EXT_FEATURE_HIGH_ACCURACY_SCAN
+            }
+            Nl80211ExtFeature::DfsOffload => NL80211_EXT_FEATURE_DFS_OFFLOAD,
+            Nl80211ExtFeature::ControlPortOverNl80211 => {
+                NL80211_EXT_FEATURE_CONTROL_PORT_OVER_NL80211
+            }
+            Nl80211ExtFeature::DataAckSignalSupport => {
+                NL80211_EXT_FEATURE_DATA_ACK_SIGNAL_SUPPORT
+            }
+            Nl80211ExtFeature::Txqs => NL80211_EXT_FEATURE_TXQS,
+            Nl80211ExtFeature::ScanRandomSn => {
+                NL80211_EXT_FEATURE_SCAN_RANDOM_SN
+            }
+            Nl80211ExtFeature::ScanMinPreqContent => {
+                NL80211_EXT_FEATURE_SCAN_MIN_PREQ_CONTENT
+            }
+            Nl80211ExtFeature::CanReplacePtk0 => {
+                NL80211_EXT_FEATURE_CAN_REPLACE_PTK0
+            }
+            Nl80211ExtFeature::EnableFtmResponder => {
+                NL80211_EXT_FEATURE_ENABLE_FTM_RESPONDER
+            }
+            Nl80211ExtFeature::AirtimeFairness => {
+                NL80211_EXT_FEATURE_AIRTIME_FAIRNESS
+            }
+            Nl80211ExtFeature::ApPmksaCaching => {
+                NL80211_EXT_FEATURE_AP_PMKSA_CACHING
+            }
+            Nl80211ExtFeature::SchedScanBandSpecificRssiThold => {
+                NL80211_EXT_FEATURE_SCHED_SCAN_BAND_SPECIFIC_RSSI_THOLD
+            }
+            Nl80211ExtFeature::ExtKeyId => NL80211_EXT_FEATURE_EXT_KEY_ID,
+            Nl80211ExtFeature::StaTxPwr => NL80211_EXT_FEATURE_STA_TX_PWR,
+            Nl80211ExtFeature::SaeOffload => NL80211_EXT_FEATURE_SAE_OFFLOAD,
+            Nl80211ExtFeature::VlanOffload => NL80211_EXT_FEATURE_VLAN_OFFLOAD,
+            Nl80211ExtFeature::Aql => NL80211_EXT_FEATURE_AQL,
+            Nl80211ExtFeature::BeaconProtection => {
+                NL80211_EXT_FEATURE_BEACON_PROTECTION
+            }
+            Nl80211ExtFeature::ControlPortNoPreauth => {
+                NL80211_EXT_FEATURE_CONTROL_PORT_NO_PREAUTH
+            }
+            Nl80211ExtFeature::ProtectedTwt => {
+                NL80211_EXT_FEATURE_PROTECTED_TWT
+            }
+            Nl80211ExtFeature::DelIbssSta => NL80211_EXT_FEATURE_DEL_IBSS_STA,
+            Nl80211ExtFeature::MulticastRegistrations => {
+                NL80211_EXT_FEATURE_MULTICAST_REGISTRATIONS
+            }
+            Nl80211ExtFeature::BeaconProtectionClient => {
+                NL80211_EXT_FEATURE_BEACON_PROTECTION_CLIENT
+            }
+            Nl80211ExtFeature::ScanFreqKhz => NL80211_EXT_FEATURE_SCAN_FREQ_KHZ,
+            Nl80211ExtFeature::ControlPortOverNl80211TxStatus => {
+                NL80211_EXT_FEATURE_CONTROL_PORT_OVER_NL80211_TX_STATUS
+            }
+            Nl80211ExtFeature::OperatingChannelValidation => {
+                NL80211_EXT_FEATURE_OPERATING_CHANNEL_VALIDATION
+            }
+            Nl80211ExtFeature::FourWayHandshakeApPsk => {
+                NL80211_EXT_FEATURE_4WAY_HANDSHAKE_AP_PSK
+            }
+            Nl80211ExtFeature::SaeOffloadAp => {
+                NL80211_EXT_FEATURE_SAE_OFFLOAD_AP
+            }
+            Nl80211ExtFeature::FilsDiscovery => {
+                NL80211_EXT_FEATURE_FILS_DISCOVERY
+            }
+            Nl80211ExtFeature::UnsolBcastProbeResp => {
+                NL80211_EXT_FEATURE_UNSOL_BCAST_PROBE_RESP
+            }
+            Nl80211ExtFeature::BeaconRateHe => {
+                NL80211_EXT_FEATURE_BEACON_RATE_HE
+            }
+            Nl80211ExtFeature::SecureLtf => NL80211_EXT_FEATURE_SECURE_LTF,
+            Nl80211ExtFeature::SecureRtt => NL80211_EXT_FEATURE_SECURE_RTT,
+            Nl80211ExtFeature::ProtRangeNegoAndMeasure => {
+                NL80211_EXT_FEATURE_PROT_RANGE_NEGO_AND_MEASURE
+            }
+            Nl80211ExtFeature::BssColor => NL80211_EXT_FEATURE_BSS_COLOR,
+            Nl80211ExtFeature::FilsCryptoOffload => {
+                NL80211_EXT_FEATURE_FILS_CRYPTO_OFFLOAD
+            }
+            Nl80211ExtFeature::RadarBackground => {
+                NL80211_EXT_FEATURE_RADAR_BACKGROUND
+            }
+            Nl80211ExtFeature::PoweredAddrChange => {
+                NL80211_EXT_FEATURE_POWERED_ADDR_CHANGE
+            }
+            Nl80211ExtFeature::Other(d) => d,
+        }
+    }
+}
+
+/// Extended features bitmap of a wiphy, indexed by [Nl80211ExtFeature]
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct Nl80211ExtFeatures(pub Vec<u8>);
+
+impl Nl80211ExtFeatures {
+    /// Whether the wiphy supports the extended feature
+    pub fn supports(&self, feature: Nl80211ExtFeature) -> bool {
+        let index = u32::from(feature) as usize;
+        self.0
+            .get(index / 8)
+            .map(|d| d & (1 << (index % 8)) > 0)
+            .unwrap_or_default()
+    }
+
+    /// All extended features supported by the wiphy
+    pub fn features(&self) -> Vec<Nl80211ExtFeature> {
+        let mut ret = Vec::new();
+        for (i, d) in self.0.iter().enumerate() {
+            for bit in 0..8 {
+                if d & (1 << bit) > 0 {
+                    ret.push(Nl80211ExtFeature::from((i * 8 + bit) as u32));
+                }
+            }
+        }
+        ret
+    }
+
+    pub(crate) fn parse(payload: &[u8]) -> Result<Self, DecodeError> {
+        Ok(Self(payload.to_vec()))
+    }
+}
+
+impl Emitable for Nl80211ExtFeatures {
+    fn buffer_len(&self) -> usize {
+        self.0.len()
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        buffer[..self.0.len()].copy_from_slice(&self.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn supports_uses_kernel_bit_index() {
+        // Bit 27 is DATA_ACK_SIGNAL_SUPPORT and bit 38 is SAE_OFFLOAD
+        let features = Nl80211ExtFeatures(vec![0, 0, 0, 0x08, 0x40]);
+        assert!(features.supports(Nl80211ExtFeature::DataAckSignalSupport));
+        assert!(features.supports(Nl80211ExtFeature::SaeOffload));
+        assert!(!features.supports(Nl80211ExtFeature::Txqs));
+        assert!(!features.supports(Nl80211ExtFeature::StaTxPwr));
+        assert_eq!(
+            features.features(),
+            vec![
+                Nl80211ExtFeature::DataAckSignalSupport,
+                Nl80211ExtFeature::SaeOffload,
+            ]
+        );
+    }
+
+    #[test]
+    fn feature_index_round_trip() {
+        for index in 0..64 {
+            assert_eq!(u32::from(Nl80211ExtFeature::from(index)), index);
+        }
+        assert_eq!(
+            Nl80211ExtFeature::from(61),
+            Nl80211ExtFeature::PoweredAddrChange
+        );
+        assert_eq!(Nl80211ExtFeature::from(62), Nl80211ExtFeature::Other(62));
+    }
+}
@@ -0,0 +1,568 @@
+// SPDX-License-Identifier: MIT
+
+use anyhow::Context;
+use byteorder::{ByteOrder, NativeEndian};
+use netlink_packet_utils::{
+    nla::{DefaultNla, Nla, NlaBuffer, NlasIterator},
+    parsers::{parse_u32, parse_u8},
+    DecodeError, Emitable, Parseable,
+};
+
+const NL80211_DFS_USABLE: u32 = 0;
+const NL80211_DFS_UNAVAILABLE: u32 = 1;
+const NL80211_DFS_AVAILABLE: u32 = 2;
+
+/// DFS state of a channel
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Nl80211DfsState {
+    /// The channel can be used, but channel availability check (CAC) must be
+    /// performed before using it for AP or IBSS
+    Usable,
+    /// A radar has been detected on this channel, it is therefore marked as
+    /// not available
+    Unavailable,
+    /// The channel has been CAC checked and is available
+    Available,
+    Other(u32),
+}
+
+impl From<u32> for Nl80211DfsState {
+    fn from(d: u32) -> Self {
+        match d {
+            NL80211_DFS_USABLE => Self::Usable,
+            NL80211_DFS_UNAVAILABLE => Self::Unavailable,
+            NL80211_DFS_AVAILABLE => Self::Available,
+            _ => Self::Other(d),
+        }
+    }
+}
+
+impl From<Nl80211DfsState> for u32 {
+    fn from(v: Nl80211DfsState) -> u32 {
+        match v {
+            Nl80211DfsState::Usable => NL80211_DFS_USABLE,
+            Nl80211DfsState::Unavailable => NL80211_DFS_UNAVAILABLE,
+            Nl80211DfsState::Available => NL80211_DFS_AVAILABLE,
+            Nl80211DfsState::Other(d) => d,
+        }
+    }
+}
+
+const NL80211_FREQUENCY_ATTR_FREQ: u16 = 1;
+const NL80211_FREQUENCY_ATTR_DISABLED: u16 = 2;
+const NL80211_FREQUENCY_ATTR_NO_IR: u16 = 3;
+const NL80211_FREQUENCY_ATTR_RADAR: u16 = 5;
+const NL80211_FREQUENCY_ATTR_MAX_TX_POWER: u16 = 6;
+const NL80211_FREQUENCY_ATTR_DFS_STATE: u16 = 7;
+const NL80211_FREQUENCY_ATTR_DFS_TIME: u16 = 8;
+const NL80211_FREQUENCY_ATTR_NO_HT40_MINUS: u16 = 9;
+const NL80211_FREQUENCY_ATTR_NO_HT40_PLUS: u16 = 10;
+const NL80211_FREQUENCY_ATTR_NO_80MHZ: u16 = 11;
+const NL80211_FREQUENCY_ATTR_NO_160MHZ: u16 = 12;
+const NL80211_FREQUENCY_ATTR_DFS_CAC_TIME: u16 = 13;
+const NL80211_FREQUENCY_ATTR_INDOOR_ONLY: u16 = 14;
+const NL80211_FREQUENCY_ATTR_IR_CONCURRENT: u16 = 15;
+const NL80211_FREQUENCY_ATTR_NO_20MHZ: u16 = 16;
+const NL80211_FREQUENCY_ATTR_NO_10MHZ: u16 = 17;
+const NL80211_FREQUENCY_ATTR_NO_HE: u16 = 19;
+const NL80211_FREQUENCY_ATTR_OFFSET: u16 = 20;
+const NL80211_FREQUENCY_ATTR_1MHZ: u16 = 21;
+const NL80211_FREQUENCY_ATTR_2MHZ: u16 = 22;
+const NL80211_FREQUENCY_ATTR_4MHZ: u16 = 23;
+const NL80211_FREQUENCY_ATTR_8MHZ: u16 = 24;
+const NL80211_FREQUENCY_ATTR_16MHZ: u16 = 25;
+const NL80211_FREQUENCY_ATTR_NO_320MHZ: u16 = 26;
+const NL80211_FREQUENCY_ATTR_NO_EHT: u16 = 27;
+const NL80211_FREQUENCY_ATTR_PSD: u16 = 28;
+const NL80211_FREQUENCY_ATTR_NO_6GHZ_VLP_CLIENT: u16 = 30;
+const NL80211_FREQUENCY_ATTR_NO_6GHZ_AFC_CLIENT: u16 = 31;
+const NL80211_FREQUENCY_ATTR_ALLOW_6GHZ_VLP_AP: u16 = 33;
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum Nl80211FrequencyNla {
+    /// Frequency in MHz
+    Freq(u32),
+    /// Channel is disabled in current regulatory domain
+    Disabled,
+    /// No mechanisms that initiate radiation are permitted on this channel
+    NoIr,
+    /// Radar detection is mandatory on this channel
+    Radar,
+    /// Maximum transmission power in mBm (100 * dBm)
+    MaxTxPower(u32),
+    /// Current state for DFS
+    DfsState(Nl80211DfsState),
+    /// Time in milliseconds since the DFS state was entered
+    DfsTime(u32),
+    /// HT40- isn't possible with this channel as the control channel
+    NoHt40Minus,
+    /// HT40+ isn't possible with this channel as the control channel
+    NoHt40Plus,
+    /// Any 80 MHz channel using this channel is not allowed
+    No80Mhz,
+    /// Any 160 MHz channel using this channel is not allowed
+    No160Mhz,
+    /// DFS CAC time in milliseconds
+    DfsCacTime(u32),
+    /// Only indoor use is permitted on this channel
+    IndoorOnly,
+    /// Initiating radiation is permitted when there is an interface already operating on the channel
+    IrConcurrent,
+    /// 20 MHz operation is not allowed on this channel
+    No20Mhz,
+    /// 10 MHz operation is not allowed on this channel
+    No10Mhz,
+    /// HE operation is not allowed on this channel
+    NoHe,
+    /// Frequency offset in KHz
+    Offset(u32),
+    /// 1 MHz operation is allowed on this S1G channel
+    Allow1Mhz,
+    /// 2 MHz operation is allowed on this S1G channel
+    Allow2Mhz,
+    /// 4 MHz operation is allowed on this S1G channel
+    Allow4Mhz,
+    /// 8 MHz operation is allowed on this S1G channel
+    Allow8Mhz,
+    /// 16 MHz operation is allowed on this S1G channel
+    Allow16Mhz,
+    /// Any 320 MHz channel using this channel is not allowed
+    No320Mhz,
+    /// EHT operation is not allowed on this channel
+    NoEht,
+    /// Power spectral density in dBm
+    Psd(i8),
+    /// Client connection to a VLP AP is not allowed on this channel
+    No6GhzVlpClient,
+    /// Client connection to an AFC (standard power) AP is not allowed on
+    /// this channel
+    No6GhzAfcClient,
+    /// VLP AP operation is allowed on this channel
+    Allow6GhzVlpAp,
+    Other(DefaultNla),
+}
+
+impl Nla for Nl80211FrequencyNla {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::Disabled
+            | Self::NoIr
+            | Self::Radar
+            | Self::NoHt40Minus
+            | Self::NoHt40Plus
+            | Self::No80Mhz
+            | Self::No160Mhz
+            | Self::IndoorOnly
+            | Self::IrConcurrent
+            | Self::No20Mhz
+            | Self::No10Mhz
+            | Self::NoHe
+            | Self::Allow1Mhz
+            | Self::Allow2Mhz
+            | Self::Allow4Mhz
+            | Self::Allow8Mhz
+            | Self::Allow16Mhz
+            | Self::No320Mhz
+            | Self::NoEht
+            | Self::No6GhzVlpClient
+            | Self::No6GhzAfcClient
+            | Self::Allow6GhzVlpAp => 0,
+            Self::Psd(_) => 1,
+            Self::Freq(_)
+            | Self::MaxTxPower(_)
+            | Self::DfsState(_)
+            | Self::DfsTime(_)
+            | Self::DfsCacTime(_)
+            | Self::Offset(_) => 4,
+            Self::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::Freq(_) => NL80211_FREQUENCY_ATTR_FREQ,
+            Self::Disabled => NL80211_FREQUENCY_ATTR_DISABLED,
+            Self::NoIr => NL80211_FREQUENCY_ATTR_NO_IR,
+            Self::Radar => NL80211_FREQUENCY_ATTR_RADAR,
+            Self::MaxTxPower(_) => NL80211_FREQUENCY_ATTR_MAX_TX_POWER,
+            Self::DfsState(_) => NL80211_FREQUENCY_ATTR_DFS_STATE,
+            Self::DfsTime(_) => NL80211_FREQUENCY_ATTR_DFS_TIME,
+            Self::NoHt40Minus => NL80211_FREQUENCY_ATTR_NO_HT40_MINUS,
+            Self::NoHt40Plus => NL80211_FREQUENCY_ATTR_NO_HT40_PLUS,
+            Self::No80Mhz => NL80211_FREQUENCY_ATTR_NO_80MHZ,
+            Self::No160Mhz => NL80211_FREQUENCY_ATTR_NO_160MHZ,
+            Self::DfsCacTime(_) => NL80211_FREQUENCY_ATTR_DFS_CAC_TIME,
+            Self::IndoorOnly => NL80211_FREQUENCY_ATTR_INDOOR_ONLY,
+            Self::IrConcurrent => NL80211_FREQUENCY_ATTR_IR_CONCURRENT,
+            Self::No20Mhz => NL80211_FREQUENCY_ATTR_NO_20MHZ,
+            Self::No10Mhz => NL80211_FREQUENCY_ATTR_NO_10MHZ,
+            Self::NoHe => NL80211_FREQUENCY_ATTR_NO_HE,
+            Self::Offset(_) => NL80211_FREQUENCY_ATTR_OFFSET,
+            Self::Allow1Mhz => NL80211_FREQUENCY_ATTR_1MHZ,
+            Self::Allow2Mhz => NL80211_FREQUENCY_ATTR_2MHZ,
+            Self::Allow4Mhz => NL80211_FREQUENCY_ATTR_4MHZ,
+            Self::Allow8Mhz => NL80211_FREQUENCY_ATTR_8MHZ,
+            Self::Allow16Mhz => NL80211_FREQUENCY_ATTR_16MHZ,
+            Self::No320Mhz => NL80211_FREQUENCY_ATTR_NO_320MHZ,
+            Self::NoEht => NL80211_FREQUENCY_ATTR_NO_EHT,
+            Self::Psd(_) => NL80211_FREQUENCY_ATTR_PSD,
+            Self::No6GhzVlpClient => NL80211_FREQUENCY_ATTR_NO_6GHZ_VLP_CLIENT,
+            Self::No6GhzAfcClient => NL80211_FREQUENCY_ATTR_NO_6GHZ_AFC_CLIENT,
+            Self::Allow6GhzVlpAp => NL80211_FREQUENCY_ATTR_ALLOW_6GHZ_VLP_AP,
+            Self::Other(attr) => attr.kind(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Disabled
+            | Self::NoIr
+            | Self::Radar
+            | Self::NoHt40Minus
+            | Self::NoHt40Plus
+            | Self::No80Mhz
+            | Self::No160Mhz
+            | Self::IndoorOnly
+            | Self::IrConcurrent
+            | Self::No20Mhz
+            | Self::No10Mhz
+            | Self::NoHe
+            | Self::Allow1Mhz
+            | Self::Allow2Mhz
+            | Self::Allow4Mhz
+            | Self::Allow8Mhz
+            | Self::Allow16Mhz
+            | Self::No320Mhz
+            | Self::NoEht
+            | Self::No6GhzVlpClient
+            | Self::No6GhzAfcClient
+            | Self::Allow6GhzVlpAp => (),
+            Self::Psd(d) => buffer[0] = *d as u8,
+            Self::Freq(d)
+            | Self::MaxTxPower(d)
+            | Self::DfsTime(d)
+            | Self::DfsCacTime(d)
+            | Self::Offset(d) => NativeEndian::write_u32(buffer, *d),
+            Self::DfsState(d) => NativeEndian::write_u32(buffer, (*d).into()),
+            Self::Other(ref attr) => attr.emit(buffer),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for Nl80211FrequencyNla
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            NL80211_FREQUENCY_ATTR_FREQ => {
+                let err_msg = format!(
+                    "Invalid NL80211_FREQUENCY_ATTR_FREQ value {:?}",
+                    payload
+                );
+                Self::Freq(parse_u32(payload).context(err_msg)?)
+            }
+            NL80211_FREQUENCY_ATTR_DISABLED => Self::Disabled,
+            NL80211_FREQUENCY_ATTR_NO_IR => Self::NoIr,
+            NL80211_FREQUENCY_ATTR_RADAR => Self::Radar,
+            NL80211_FREQUENCY_ATTR_MAX_TX_POWER => {
+                let err_msg = format!(
+                    "Invalid NL80211_FREQUENCY_ATTR_MAX_TX_POWER value {:?}",
+                    payload
+                );
+                Self::MaxTxPower(parse_u32(payload).context(err_msg)?)
+            }
+            NL80211_FREQUENCY_ATTR_DFS_STATE => {
+                let err_msg = format!(
+                    "Invalid NL80211_FREQUENCY_ATTR_DFS_STATE value {:?}",
+                    payload
+                );
+                Self::DfsState(parse_u32(payload).context(err_msg)?.into())
+            }
+            NL80211_FREQUENCY_ATTR_DFS_TIME => {
+                let err_msg = format!(
+                    "Invalid NL80211_FREQUENCY_ATTR_DFS_TIME value {:?}",
+                    payload
+                );
+                Self::DfsTime(parse_u32(payload).context(err_msg)?)
+            }
+            NL80211_FREQUENCY_ATTR_NO_HT40_MINUS => Self::NoHt40Minus,
+            NL80211_FREQUENCY_ATTR_NO_HT40_PLUS => Self::NoHt40Plus,
+            NL80211_FREQUENCY_ATTR_NO_80MHZ => Self::No80Mhz,
+            NL80211_FREQUENCY_ATTR_NO_160MHZ => Self::No160Mhz,
+            NL80211_FREQUENCY_ATTR_DFS_CAC_TIME => {
+                let err_msg = format!(
+                    "Invalid NL80211_FREQUENCY_ATTR_DFS_CAC_TIME value {:?}",
+                    payload
+                );
+                Self::DfsCacTime(parse_u32(payload).context(err_msg)?)
+            }
+            NL80211_FREQUENCY_ATTR_INDOOR_ONLY => Self::IndoorOnly,
+            NL80211_FREQUENCY_ATTR_IR_CONCURRENT => Self::IrConcurrent,
+            NL80211_FREQUENCY_ATTR_NO_20MHZ => Self::No20Mhz,
+            NL80211_FREQUENCY_ATTR_NO_10MHZ => Self::No10Mhz,
+            NL80211_FREQUENCY_ATTR_NO_HE => Self::NoHe,
+            NL80211_FREQUENCY_ATTR_OFFSET => {
+                let err_msg = format!(
+                    "Invalid NL80211_FREQUENCY_ATTR_OFFSET value {:?}",
+                    payload
+                );
+                Self::Offset(parse_u32(payload).context(err_msg)?)
+            }
+            NL80211_FREQUENCY_ATTR_1MHZ => Self::Allow1Mhz,
+            NL80211_FREQUENCY_ATTR_2MHZ => Self::Allow2Mhz,
+            NL80211_FREQUENCY_ATTR_4MHZ => Self::Allow4Mhz,
+            NL80211_FREQUENCY_ATTR_8MHZ => Self::Allow8Mhz,
+            NL80211_FREQUENCY_ATTR_16MHZ => Self::Allow16Mhz,
+            NL80211_FREQUENCY_ATTR_NO_320MHZ => Self::No320Mhz,
+            NL80211_FREQUENCY_ATTR_NO_EHT => Self::NoEht,
+            NL80211_FREQUENCY_ATTR_PSD => {
+                let err_msg = format!(
+                    "Invalid NL80211_FREQUENCY_ATTR_PSD value {:?}",
+                    payload
+                );
+                Self::Psd(parse_u8(payload).context(err_msg)? as i8)
+            }
+            NL80211_FREQUENCY_ATTR_NO_6GHZ_VLP_CLIENT => Self::No6GhzVlpClient,
+            NL80211_FREQUENCY_ATTR_NO_6GHZ_AFC_CLIENT => Self::No6GhzAfcClient,
+            NL80211_FREQUENCY_ATTR_ALLOW_6GHZ_VLP_AP => Self::Allow6GhzVlpAp,
+            _ => Self::Other(
+                DefaultNla::parse(buf).context("invalid NLA (unknown kind)")?,
+            ),
+        })
+    }
+}
+
+/// Channel supported by a band of the wiphy
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct Nl80211Frequency {
+    /// Frequency in MHz
+    pub freq: Option<u32>,
+    /// Channel is disabled in current regulatory domain
+    pub disabled: bool,
+    /// No mechanisms that initiate radiation are permitted on this channel
+    pub no_ir: bool,
+    /// Radar detection is mandatory on this channel
+    pub radar: bool,
+    /// Maximum transmission power in mBm (100 * dBm)
+    pub max_tx_power: Option<u32>,
+    /// Current state for DFS
+    pub dfs_state: Option<Nl80211DfsState>,
+    /// Time in milliseconds since the DFS state was entered
+    pub dfs_time: Option<u32>,
+    /// HT40- isn't possible with this channel as the control channel
+    pub no_ht40_minus: bool,
+    /// HT40+ isn't possible with this channel as the control channel
+    pub no_ht40_plus: bool,
+    /// Any 80 MHz channel using this channel is not allowed
+    pub no_80mhz: bool,
+    /// Any 160 MHz channel using this channel is not allowed
+    pub no_160mhz: bool,
+    /// DFS CAC time in milliseconds
+    pub dfs_cac_time: Option<u32>,
+    /// Only indoor use is permitted on this channel
+    pub indoor_only: bool,
+    /// Initiating radiation is permitted when there is an interface already operating on the channel
+    pub ir_concurrent: bool,
+    /// 20 MHz operation is not allowed on this channel
+    pub no_20mhz: bool,
+    /// 10 MHz operation is not allowed on this channel
+    pub no_10mhz: bool,
+    /// HE operation is not allowed on this channel
+    pub no_he: bool,
+    /// Frequency offset in KHz
+    pub offset: Option<u32>,
+    /// 1 MHz operation is allowed on this S1G channel
+    pub allow_1mhz: bool,
+    /// 2 MHz operation is allowed on this S1G channel
+    pub allow_2mhz: bool,
+    /// 4 MHz operation is allowed on this S1G channel
+    pub allow_4mhz: bool,
+    /// 8 MHz operation is allowed on this S1G channel
+    pub allow_8mhz: bool,
+    /// 16 MHz operation is allowed on this S1G channel
+    pub allow_16mhz: bool,
+    /// Any 320 MHz channel using this channel is not allowed
+    pub no_320mhz: bool,
+    /// EHT operation is not allowed on this channel
+    pub no_eht: bool,
+    /// Power spectral density in dBm
+    pub psd: Option<i8>,
+    /// Client connection to a VLP AP is not allowed on this channel
+    pub no_6ghz_vlp_client: bool,
+    /// Client connection to an AFC (standard power) AP is not allowed on
+    /// this channel
+    pub no_6ghz_afc_client: bool,
+    /// VLP AP operation is allowed on this channel
+    pub allow_6ghz_vlp_ap: bool,
+}
+
+impl Emitable for Nl80211Frequency {
+    fn buffer_len(&self) -> usize {
+        Vec::<Nl80211FrequencyNla>::from(self)
+            .as_slice()
+            .buffer_len()
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        Vec::<Nl80211FrequencyNla>::from(self)
+            .as_slice()
+            .emit(buffer)
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for Nl80211Frequency
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let mut ret = Self::default();
+        let payload = buf.value();
+        let err_msg =
+            format!("Invalid NL80211_BAND_ATTR_FREQS value {:?}", payload);
+        for nla in NlasIterator::new(payload) {
+            let nla = &nla.context(err_msg.clone())?;
+            match Nl80211FrequencyNla::parse(nla).context(err_msg.clone())? {
+                Nl80211FrequencyNla::Freq(d) => ret.freq = Some(d),
+                Nl80211FrequencyNla::Disabled => ret.disabled = true,
+                Nl80211FrequencyNla::NoIr => ret.no_ir = true,
+                Nl80211FrequencyNla::Radar => ret.radar = true,
+                Nl80211FrequencyNla::MaxTxPower(d) => {
+                    ret.max_tx_power = Some(d)
+                }
+                Nl80211FrequencyNla::DfsState(d) => ret.dfs_state = Some(d),
+                Nl80211FrequencyNla::DfsTime(d) => ret.dfs_time = Some(d),
+                Nl80211FrequencyNla::NoHt40Minus => ret.no_ht40_minus = true,
+                Nl80211FrequencyNla::NoHt40Plus => ret.no_ht40_plus = true,
+                Nl80211FrequencyNla::No80Mhz => ret.no_80mhz = true,
+                Nl80211FrequencyNla::No160Mhz => ret.no_160mhz = true,
+                Nl80211FrequencyNla::DfsCacTime(d) => {
+                    ret.dfs_cac_time = Some(d)
+                }
+                Nl80211FrequencyNla::IndoorOnly => ret.indoor_only = true,
+                Nl80211FrequencyNla::IrConcurrent => ret.ir_concurrent = true,
+                Nl80211FrequencyNla::No20Mhz => ret.no_20mhz = true,
+                Nl80211FrequencyNla::No10Mhz => ret.no_10mhz = true,
+                Nl80211FrequencyNla::NoHe => ret.no_he = true,
+                Nl80211FrequencyNla::Offset(d) => ret.offset = Some(d),
+                Nl80211FrequencyNla::Allow1Mhz => ret.allow_1mhz = true,
+                Nl80211FrequencyNla::Allow2Mhz => ret.allow_2mhz = true,
+                Nl80211FrequencyNla::Allow4Mhz => ret.allow_4mhz = true,
+                Nl80211FrequencyNla::Allow8Mhz => ret.allow_8mhz = true,
+                Nl80211FrequencyNla::Allow16Mhz => ret.allow_16mhz = true,
+                Nl80211FrequencyNla::No320Mhz => ret.no_320mhz = true,
+                Nl80211FrequencyNla::NoEht => ret.no_eht = true,
+                Nl80211FrequencyNla::Psd(d) => ret.psd = Some(d),
+                Nl80211FrequencyNla::No6GhzVlpClient => {
+                    ret.no_6ghz_vlp_client = true
+                }
+                Nl80211FrequencyNla::No6GhzAfcClient => {
+                    ret.no_6ghz_afc_client = true
+                }
+                Nl80211FrequencyNla::Allow6GhzVlpAp => {
+                    ret.allow_6ghz_vlp_ap = true
+                }
+                Nl80211FrequencyNla::Other(attr) => {
+                    log::warn!(
+                        "Got unsupported NL80211_BAND_ATTR_FREQS value {:?}",
+                        attr
+                    )
+                }
+            }
+        }
+        Ok(ret)
+    }
+}
+
+impl From<&Nl80211Frequency> for Vec<Nl80211FrequencyNla> {
+    fn from(freq: &Nl80211Frequency) -> Self {
+        let mut nlas = Vec::new();
+        if let Some(v) = freq.freq {
+            nlas.push(Nl80211FrequencyNla::Freq(v));
+        }
+        if freq.disabled {
+            nlas.push(Nl80211FrequencyNla::Disabled);
+        }
+        if freq.no_ir {
+            nlas.push(Nl80211FrequencyNla::NoIr);
+        }
+        if freq.radar {
+            nlas.push(Nl80211FrequencyNla::Radar);
+        }
+        if let Some(v) = freq.max_tx_power {
+            nlas.push(Nl80211FrequencyNla::MaxTxPower(v));
+        }
+        if let Some(v) = freq.dfs_state {
+            nlas.push(Nl80211FrequencyNla::DfsState(v));
+        }
+        if let Some(v) = freq.dfs_time {
+            nlas.push(Nl80211FrequencyNla::DfsTime(v));
+        }
+        if freq.no_ht40_minus {
+            nlas.push(Nl80211FrequencyNla::NoHt40Minus);
+        }
+        if freq.no_ht40_plus {
+            nlas.push(Nl80211FrequencyNla::NoHt40Plus);
+        }
+        if freq.no_80mhz {
+            nlas.push(Nl80211FrequencyNla::No80Mhz);
+        }
+        if freq.no_160mhz {
+            nlas.push(Nl80211FrequencyNla::No160Mhz);
+        }
+        if let Some(v) = freq.dfs_cac_time {
+            nlas.push(Nl80211FrequencyNla::DfsCacTime(v));
+        }
+        if freq.indoor_only {
+            nlas.push(Nl80211FrequencyNla::IndoorOnly);
+        }
+        if freq.ir_concurrent {
+            nlas.push(Nl80211FrequencyNla::IrConcurrent);
+        }
+        if freq.no_20mhz {
+            nlas.push(Nl80211FrequencyNla::No20Mhz);
+        }
+        if freq.no_10mhz {
+            nlas.push(Nl80211FrequencyNla::No10Mhz);
+        }
+        if freq.no_he {
+            nlas.push(Nl80211FrequencyNla::NoHe);
+        }
+        if let Some(v) = freq.offset {
+            nlas.push(Nl80211FrequencyNla::Offset(v));
+        }
+        if freq.allow_1mhz {
+            nlas.push(Nl80211FrequencyNla::Allow1Mhz);
+        }
+        if freq.allow_2mhz {
+            nlas.push(Nl80211FrequencyNla::Allow2Mhz);
+        }
+        if freq.allow_4mhz {
+            nlas.push(Nl80211FrequencyNla::Allow4Mhz);
+        }
+        if freq.allow_8mhz {
+            nlas.push(Nl80211FrequencyNla::Allow8Mhz);
+        }
+        if freq.allow_16mhz {
+            nlas.push(Nl80211FrequencyNla::Allow16Mhz);
+        }
+        if freq.no_320mhz {
+            nlas.push(Nl80211FrequencyNla::No320Mhz);
+        }
+        if freq.no_eht {
+            nlas.push(Nl80211FrequencyNla::NoEht);
+        }
+        if let Some(v) = freq.psd {
+            nlas.push(Nl80211FrequencyNla::Psd(v));
+        }
+        if freq.no_6ghz_vlp_client {
+            nlas.push(Nl80211FrequencyNla::No6GhzVlpClient);
+        }
+        if freq.no_6ghz_afc_client {
+            nlas.push(Nl80211FrequencyNla::No6GhzAfcClient);
+        }
+        if freq.allow_6ghz_vlp_ap {
+            nlas.push(Nl80211FrequencyNla::Allow6GhzVlpAp);
+        }
+        nlas
+    }
+}
@@ -0,0 +1,91 @@
+// SPDX-License-Identifier: MIT
+
+use futures::{
+    future::{self, Either},
+    FutureExt, TryStream, TryStreamExt,
+};
+use netlink_packet_core::{NLM_F_DUMP, NLM_F_REQUEST};
+use netlink_packet_generic::GenlMessage;
+
+use crate::{
+    nl80211_execute, Nl80211Attr, Nl80211Cmd, Nl80211Error, Nl80211Handle,
+    Nl80211Message, Nl80211ProtocolFeatures,
+};
+
+pub struct Nl80211PhyGetRequest {
+    handle: Nl80211Handle,
+    wiphy: Option<u32>,
+    name: Option<String>,
+}
+
+impl Nl80211PhyGetRequest {
+    pub(crate) fn new(handle: Nl80211Handle) -> Self {
+        Nl80211PhyGetRequest {
+            handle,
+            wiphy: None,
+            name: None,
+        }
+    }
+
+    /// Only query the wiphy with specified index
+    pub fn match_wiphy(mut self, wiphy: u32) -> Self {
+        self.wiphy = Some(wiphy);
+        self
+    }
+
+    /// Only query the wiphy with specified name, e.g. `phy0`
+    pub fn match_name(mut self, name: &str) -> Self {
+        self.name = Some(name.to_string());
+        self
+    }
+
+    /// When dumping all wiphys and the kernel supports
+    /// `Nl80211ProtocolFeatures::SPLIT_WIPHY_DUMP`, the information of a
+    /// single wiphy might be split into multiple messages sharing the same
+    /// `Nl80211Attr::WiPhy`.
+    pub async fn execute(
+        self,
+    ) -> impl TryStream<Ok = GenlMessage<Nl80211Message>, Error = Nl80211Error>
+    {
+        let Nl80211PhyGetRequest {
+            mut handle,
+            wiphy,
+            name,
+        } = self;
+
+        let mut nlas = Vec::new();
+        let flags = if wiphy.is_none() && name.is_none() {
+            match handle.protocol_features().await {
+                Ok(features) => {
+                    if features
+                        .contains(Nl80211ProtocolFeatures::SPLIT_WIPHY_DUMP)
+                    {
+                        nlas.push(Nl80211Attr::SplitWiPhyDump);
+                    }
+                }
+                Err(e) => {
+                    return Either::Right(future::err(e).into_stream());
+                }
+            }
+            NLM_F_REQUEST | NLM_F_DUMP
+        } else {
+            NLM_F_REQUEST
+        };
+        if let Some(wiphy) = wiphy {
+            nlas.push(Nl80211Attr::WiPhy(wiphy));
+        }
+        if let Some(name) = name {
+            nlas.push(Nl80211Attr::WiPhyName(name));
+        }
+
+        let nl80211_msg = Nl80211Message {
+            cmd: Nl80211Cmd::WiPhyGet,
+            nlas,
+        };
+        Either::Left(
+            nl80211_execute(&mut handle, nl80211_msg, flags)
+                .await
+                .into_stream(),
+        )
+    }
+}
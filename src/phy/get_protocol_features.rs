@@ -0,0 +1,41 @@
+// SPDX-License-Identifier: MIT
+
+use futures::TryStreamExt;
+use netlink_packet_core::NLM_F_REQUEST;
+
+use crate::{
+    nl80211_execute, Nl80211Attr, Nl80211Cmd, Nl80211Error, Nl80211Handle,
+    Nl80211Message, Nl80211ProtocolFeatures,
+};
+
+pub struct Nl80211ProtocolFeaturesGetRequest {
+    handle: Nl80211Handle,
+}
+
+impl Nl80211ProtocolFeaturesGetRequest {
+    pub(crate) fn new(handle: Nl80211Handle) -> Self {
+        Nl80211ProtocolFeaturesGetRequest { handle }
+    }
+
+    pub async fn execute(
+        self,
+    ) -> Result<Nl80211ProtocolFeatures, Nl80211Error> {
+        let Nl80211ProtocolFeaturesGetRequest { mut handle } = self;
+
+        let nl80211_msg = Nl80211Message {
+            cmd: Nl80211Cmd::ProtocolFeaturesGet,
+            nlas: Vec::new(),
+        };
+        let mut response =
+            nl80211_execute(&mut handle, nl80211_msg, NLM_F_REQUEST).await;
+        let mut features = Nl80211ProtocolFeatures::empty();
+        while let Some(msg) = response.try_next().await? {
+            for nla in msg.payload.nlas {
+                if let Nl80211Attr::ProtocolFeatures(d) = nla {
+                    features |= d;
+                }
+            }
+        }
+        Ok(features)
+    }
+}
@@ -0,0 +1,58 @@
+// SPDX-License-Identifier: MIT
+
+use std::os::unix::io::RawFd;
+
+use crate::{
+    Nl80211Handle, Nl80211PhyGetRequest, Nl80211PhyNetnsSetRequest,
+    Nl80211PhySetRequest, Nl80211ProtocolFeaturesGetRequest,
+};
+
+pub struct Nl80211PhyHandle(Nl80211Handle);
+
+impl Nl80211PhyHandle {
+    pub fn new(handle: Nl80211Handle) -> Self {
+        Nl80211PhyHandle(handle)
+    }
+
+    /// Retrieve the wireless physical devices
+    /// (equivalent to `iw phy`)
+    pub fn get(&mut self) -> Nl80211PhyGetRequest {
+        Nl80211PhyGetRequest::new(self.0.clone())
+    }
+
+    /// Retrieve the features of the nl80211 protocol supported by the
+    /// kernel
+    pub fn get_protocol_features(
+        &mut self,
+    ) -> Nl80211ProtocolFeaturesGetRequest {
+        Nl80211ProtocolFeaturesGetRequest::new(self.0.clone())
+    }
+
+    /// Change settings of the wiphy with specified index
+    /// (equivalent to `iw phy PHY set ...`)
+    pub fn set(&mut self, wiphy: u32) -> Nl80211PhySetRequest {
+        Nl80211PhySetRequest::new(self.0.clone(), wiphy)
+    }
+
+    /// Move the wiphy and its interfaces to the network namespace of
+    /// specified process
+    /// (equivalent to `iw phy PHY set netns PID`)
+    pub fn set_netns_by_pid(
+        &mut self,
+        wiphy: u32,
+        pid: u32,
+    ) -> Nl80211PhyNetnsSetRequest {
+        Nl80211PhyNetnsSetRequest::new_by_pid(self.0.clone(), wiphy, pid)
+    }
+
+    /// Move the wiphy and its interfaces to the network namespace referred
+    /// to by specified file descriptor
+    /// (equivalent to `iw phy PHY set netns name NSNAME`)
+    pub fn set_netns_by_fd(
+        &mut self,
+        wiphy: u32,
+        fd: RawFd,
+    ) -> Nl80211PhyNetnsSetRequest {
+        Nl80211PhyNetnsSetRequest::new_by_fd(self.0.clone(), wiphy, fd)
+    }
+}
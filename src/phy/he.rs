@@ -0,0 +1,97 @@
+// SPDX-License-Identifier: MIT
+
+use byteorder::{ByteOrder, LittleEndian};
+use netlink_packet_utils::{DecodeError, Emitable};
+
+pub(crate) const HE_MAC_CAP_LEN: usize = 6;
+pub(crate) const HE_PHY_CAP_LEN: usize = 11;
+
+const HE_MCS_NOT_SUPPORTED: u16 = 3;
+
+/// Supported HE-MCS and NSS set
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+pub struct Nl80211HeMcsNssSupp {
+    /// RX MCS map for channel width less than or equal to 80 MHz
+    pub rx_mcs_80: u16,
+    /// TX MCS map for channel width less than or equal to 80 MHz
+    pub tx_mcs_80: u16,
+    /// RX MCS map for 160 MHz channel width
+    pub rx_mcs_160: u16,
+    /// TX MCS map for 160 MHz channel width
+    pub tx_mcs_160: u16,
+    /// RX MCS map for 80+80 MHz channel width
+    pub rx_mcs_80p80: u16,
+    /// TX MCS map for 80+80 MHz channel width
+    pub tx_mcs_80p80: u16,
+}
+
+impl Nl80211HeMcsNssSupp {
+    pub(crate) const LENGTH: usize = 12;
+
+    /// Highest RX MCS index supported at 80 MHz or less for the number of
+    /// spatial streams (1 to 8), `None` if not supported.
+    pub fn rx_max_mcs_80(&self, nss: u8) -> Option<u8> {
+        max_mcs(self.rx_mcs_80, nss)
+    }
+
+    /// Highest TX MCS index supported at 80 MHz or less for the number of
+    /// spatial streams (1 to 8), `None` if not supported.
+    pub fn tx_max_mcs_80(&self, nss: u8) -> Option<u8> {
+        max_mcs(self.tx_mcs_80, nss)
+    }
+
+    /// Highest RX MCS index supported at 160 MHz for the number of spatial
+    /// streams (1 to 8), `None` if not supported.
+    pub fn rx_max_mcs_160(&self, nss: u8) -> Option<u8> {
+        max_mcs(self.rx_mcs_160, nss)
+    }
+
+    /// Highest TX MCS index supported at 160 MHz for the number of spatial
+    /// streams (1 to 8), `None` if not supported.
+    pub fn tx_max_mcs_160(&self, nss: u8) -> Option<u8> {
+        max_mcs(self.tx_mcs_160, nss)
+    }
+
+    pub(crate) fn parse(payload: &[u8]) -> Result<Self, DecodeError> {
+        if payload.len() < Self::LENGTH {
+            return Err(format!(
+                "Invalid NL80211_BAND_IFTYPE_ATTR_HE_CAP_MCS_SET {:?}",
+                payload
+            )
+            .into());
+        }
+        Ok(Self {
+            rx_mcs_80: LittleEndian::read_u16(&payload[0..2]),
+            tx_mcs_80: LittleEndian::read_u16(&payload[2..4]),
+            rx_mcs_160: LittleEndian::read_u16(&payload[4..6]),
+            tx_mcs_160: LittleEndian::read_u16(&payload[6..8]),
+            rx_mcs_80p80: LittleEndian::read_u16(&payload[8..10]),
+            tx_mcs_80p80: LittleEndian::read_u16(&payload[10..12]),
+        })
+    }
+}
+
+fn max_mcs(map: u16, nss: u8) -> Option<u8> {
+    if !(1..=8).contains(&nss) {
+        return None;
+    }
+    match (map >> ((nss - 1) * 2)) & 0b11 {
+        HE_MCS_NOT_SUPPORTED => None,
+        d => Some(7 + 2 * d as u8),
+    }
+}
+
+impl Emitable for Nl80211HeMcsNssSupp {
+    fn buffer_len(&self) -> usize {
+        Self::LENGTH
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        LittleEndian::write_u16(&mut buffer[0..2], self.rx_mcs_80);
+        LittleEndian::write_u16(&mut buffer[2..4], self.tx_mcs_80);
+        LittleEndian::write_u16(&mut buffer[4..6], self.rx_mcs_160);
+        LittleEndian::write_u16(&mut buffer[6..8], self.tx_mcs_160);
+        LittleEndian::write_u16(&mut buffer[8..10], self.rx_mcs_80p80);
+        LittleEndian::write_u16(&mut buffer[10..12], self.tx_mcs_80p80);
+    }
+}
@@ -0,0 +1,154 @@
+// SPDX-License-Identifier: MIT
+
+use byteorder::{ByteOrder, LittleEndian};
+use netlink_packet_utils::{DecodeError, Emitable};
+
+const HT_MCS_RX_MASK_LEN: usize = 10;
+
+const HT_CAP_LDPC_CODING: u16 = 1 << 0;
+const HT_CAP_SUP_WIDTH_20_40: u16 = 1 << 1;
+const HT_CAP_GRN_FLD: u16 = 1 << 4;
+const HT_CAP_SGI_20: u16 = 1 << 5;
+const HT_CAP_SGI_40: u16 = 1 << 6;
+const HT_CAP_TX_STBC: u16 = 1 << 7;
+const HT_CAP_MAX_AMSDU: u16 = 1 << 11;
+const HT_CAP_40MHZ_INTOLERANT: u16 = 1 << 14;
+
+/// HT MCS set supported by a band of the wiphy
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+pub struct Nl80211HtMcsInfo {
+    /// Bitmask of supported RX MCS indices 0 to 76
+    pub rx_mask: [u8; HT_MCS_RX_MASK_LEN],
+    /// Highest supported RX data rate in Mbps, 0 if not specified
+    pub rx_highest: u16,
+    /// Raw TX MCS set defined and TX parameters
+    pub tx_params: u8,
+}
+
+impl Nl80211HtMcsInfo {
+    pub(crate) const LENGTH: usize = 16;
+
+    /// Whether the RX MCS index is supported
+    pub fn rx_mcs_supported(&self, index: u8) -> bool {
+        self.rx_mask
+            .get(index as usize / 8)
+            .map(|d| d & (1 << (index % 8)) > 0)
+            .unwrap_or_default()
+    }
+
+    pub(crate) fn parse(payload: &[u8]) -> Result<Self, DecodeError> {
+        if payload.len() < Self::LENGTH {
+            return Err(format!(
+                "Invalid NL80211_BAND_ATTR_HT_MCS_SET {:?}",
+                payload
+            )
+            .into());
+        }
+        let mut ret = Self {
+            rx_highest: LittleEndian::read_u16(
+                &payload[HT_MCS_RX_MASK_LEN..HT_MCS_RX_MASK_LEN + 2],
+            ),
+            tx_params: payload[HT_MCS_RX_MASK_LEN + 2],
+            ..Default::default()
+        };
+        ret.rx_mask.copy_from_slice(&payload[..HT_MCS_RX_MASK_LEN]);
+        Ok(ret)
+    }
+}
+
+impl Emitable for Nl80211HtMcsInfo {
+    fn buffer_len(&self) -> usize {
+        Self::LENGTH
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        buffer[..Self::LENGTH].fill(0);
+        buffer[..HT_MCS_RX_MASK_LEN].copy_from_slice(&self.rx_mask);
+        LittleEndian::write_u16(
+            &mut buffer[HT_MCS_RX_MASK_LEN..HT_MCS_RX_MASK_LEN + 2],
+            self.rx_highest,
+        );
+        buffer[HT_MCS_RX_MASK_LEN + 2] = self.tx_params;
+    }
+}
+
+/// HT capabilities of a band of the wiphy, combined from
+/// `NL80211_BAND_ATTR_HT_CAPA`, `NL80211_BAND_ATTR_HT_AMPDU_FACTOR`,
+/// `NL80211_BAND_ATTR_HT_AMPDU_DENSITY` and `NL80211_BAND_ATTR_HT_MCS_SET`.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+pub struct Nl80211HtCapability {
+    /// Raw HT Capability Information field
+    pub capa: u16,
+    /// Maximum A-MPDU length factor
+    pub ampdu_factor: u8,
+    /// Minimum A-MPDU spacing
+    pub ampdu_density: u8,
+    pub mcs_set: Nl80211HtMcsInfo,
+}
+
+impl Nl80211HtCapability {
+    /// Whether LDPC coded packets can be received
+    pub fn ldpc_coding(&self) -> bool {
+        self.capa & HT_CAP_LDPC_CODING > 0
+    }
+
+    /// Whether both 20 MHz and 40 MHz channel width are supported,
+    /// otherwise only 20 MHz.
+    pub fn supports_40mhz(&self) -> bool {
+        self.capa & HT_CAP_SUP_WIDTH_20_40 > 0
+    }
+
+    /// SM power save mode: 0 for static, 1 for dynamic, 3 for disabled.
+    pub fn sm_power_save(&self) -> u8 {
+        ((self.capa >> 2) & 0b11) as u8
+    }
+
+    pub fn greenfield(&self) -> bool {
+        self.capa & HT_CAP_GRN_FLD > 0
+    }
+
+    /// Whether short guard interval is supported for 20 MHz packets
+    pub fn short_gi_20(&self) -> bool {
+        self.capa & HT_CAP_SGI_20 > 0
+    }
+
+    /// Whether short guard interval is supported for 40 MHz packets
+    pub fn short_gi_40(&self) -> bool {
+        self.capa & HT_CAP_SGI_40 > 0
+    }
+
+    pub fn tx_stbc(&self) -> bool {
+        self.capa & HT_CAP_TX_STBC > 0
+    }
+
+    /// Number of spatial streams supported for STBC reception
+    pub fn rx_stbc(&self) -> u8 {
+        ((self.capa >> 8) & 0b11) as u8
+    }
+
+    /// Maximum A-MSDU length in octets
+    pub fn max_amsdu_len(&self) -> u32 {
+        if self.capa & HT_CAP_MAX_AMSDU > 0 {
+            7935
+        } else {
+            3839
+        }
+    }
+
+    pub fn forty_mhz_intolerant(&self) -> bool {
+        self.capa & HT_CAP_40MHZ_INTOLERANT > 0
+    }
+
+    /// Maximum A-MPDU length in octets
+    pub fn max_ampdu_len(&self) -> u32 {
+        (1u32 << (13 + self.ampdu_factor.min(3))) - 1
+    }
+
+    /// Minimum A-MPDU spacing in nanoseconds, 0 for no restriction
+    pub fn min_mpdu_spacing_ns(&self) -> u32 {
+        match self.ampdu_density {
+            0 => 0,
+            d => 250 << (d.min(7) - 1),
+        }
+    }
+}
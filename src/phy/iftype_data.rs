@@ -0,0 +1,258 @@
+// SPDX-License-Identifier: MIT
+
+use anyhow::Context;
+use byteorder::{ByteOrder, LittleEndian};
+use netlink_packet_utils::{
+    nla::{DefaultNla, Nla, NlaBuffer, NlasIterator},
+    DecodeError, Emitable, Parseable,
+};
+
+use crate::{
+    iface::{iftypes_to_nlas, parse_iftypes},
+    phy::he::{HE_MAC_CAP_LEN, HE_PHY_CAP_LEN},
+    Nl80211HeMcsNssSupp, Nl80211InterfaceType,
+};
+
+const NL80211_BAND_IFTYPE_ATTR_IFTYPES: u16 = 1;
+const NL80211_BAND_IFTYPE_ATTR_HE_CAP_MAC: u16 = 2;
+const NL80211_BAND_IFTYPE_ATTR_HE_CAP_PHY: u16 = 3;
+const NL80211_BAND_IFTYPE_ATTR_HE_CAP_MCS_SET: u16 = 4;
+const NL80211_BAND_IFTYPE_ATTR_HE_CAP_PPE: u16 = 5;
+const NL80211_BAND_IFTYPE_ATTR_HE_6GHZ_CAPA: u16 = 6;
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum Nl80211BandIftypeDataNla {
+    /// Interface types this data applies to
+    Iftypes(Vec<Nl80211InterfaceType>),
+    /// Raw HE MAC Capabilities Information field
+    HeCapMac([u8; HE_MAC_CAP_LEN]),
+    /// Raw HE PHY Capabilities Information field
+    HeCapPhy([u8; HE_PHY_CAP_LEN]),
+    /// Supported HE-MCS and NSS set
+    HeCapMcsSet(Nl80211HeMcsNssSupp),
+    /// Raw PPE Thresholds field
+    HeCapPpe(Vec<u8>),
+    /// Raw HE 6 GHz Band Capabilities Information field
+    He6GhzCapa(u16),
+    Other(DefaultNla),
+}
+
+impl Nla for Nl80211BandIftypeDataNla {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::Iftypes(ref d) => iftypes_to_nlas(d).as_slice().buffer_len(),
+            Self::HeCapMac(_) => HE_MAC_CAP_LEN,
+            Self::HeCapPhy(_) => HE_PHY_CAP_LEN,
+            Self::HeCapMcsSet(ref d) => d.buffer_len(),
+            Self::HeCapPpe(ref d) => d.len(),
+            Self::He6GhzCapa(_) => 2,
+            Self::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::Iftypes(_) => NL80211_BAND_IFTYPE_ATTR_IFTYPES,
+            Self::HeCapMac(_) => NL80211_BAND_IFTYPE_ATTR_HE_CAP_MAC,
+            Self::HeCapPhy(_) => NL80211_BAND_IFTYPE_ATTR_HE_CAP_PHY,
+            Self::HeCapMcsSet(_) => NL80211_BAND_IFTYPE_ATTR_HE_CAP_MCS_SET,
+            Self::HeCapPpe(_) => NL80211_BAND_IFTYPE_ATTR_HE_CAP_PPE,
+            Self::He6GhzCapa(_) => NL80211_BAND_IFTYPE_ATTR_HE_6GHZ_CAPA,
+            Self::Other(attr) => attr.kind(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Iftypes(ref d) => iftypes_to_nlas(d).as_slice().emit(buffer),
+            Self::HeCapMac(ref d) => buffer[..d.len()].copy_from_slice(d),
+            Self::HeCapPhy(ref d) => buffer[..d.len()].copy_from_slice(d),
+            Self::HeCapMcsSet(ref d) => d.emit(buffer),
+            Self::HeCapPpe(ref d) => buffer[..d.len()].copy_from_slice(d),
+            Self::He6GhzCapa(d) => LittleEndian::write_u16(buffer, *d),
+            Self::Other(ref attr) => attr.emit(buffer),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for Nl80211BandIftypeDataNla
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            NL80211_BAND_IFTYPE_ATTR_IFTYPES => {
+                let err_msg = format!(
+                    "Invalid NL80211_BAND_IFTYPE_ATTR_IFTYPES value {:?}",
+                    payload
+                );
+                Self::Iftypes(parse_iftypes(payload).context(err_msg)?)
+            }
+            NL80211_BAND_IFTYPE_ATTR_HE_CAP_MAC => {
+                let err_msg = format!(
+                    "Invalid NL80211_BAND_IFTYPE_ATTR_HE_CAP_MAC value {:?}",
+                    payload
+                );
+                let mut d = [0u8; HE_MAC_CAP_LEN];
+                d.copy_from_slice(
+                    payload
+                        .get(..HE_MAC_CAP_LEN)
+                        .ok_or_else(|| DecodeError::from(err_msg))?,
+                );
+                Self::HeCapMac(d)
+            }
+            NL80211_BAND_IFTYPE_ATTR_HE_CAP_PHY => {
+                let err_msg = format!(
+                    "Invalid NL80211_BAND_IFTYPE_ATTR_HE_CAP_PHY value {:?}",
+                    payload
+                );
+                let mut d = [0u8; HE_PHY_CAP_LEN];
+                d.copy_from_slice(
+                    payload
+                        .get(..HE_PHY_CAP_LEN)
+                        .ok_or_else(|| DecodeError::from(err_msg))?,
+                );
+                Self::HeCapPhy(d)
+            }
+            NL80211_BAND_IFTYPE_ATTR_HE_CAP_MCS_SET => {
+                Self::HeCapMcsSet(Nl80211HeMcsNssSupp::parse(payload)?)
+            }
+            NL80211_BAND_IFTYPE_ATTR_HE_CAP_PPE => {
+                Self::HeCapPpe(payload.to_vec())
+            }
+            NL80211_BAND_IFTYPE_ATTR_HE_6GHZ_CAPA => {
+                let err_msg = format!(
+                    "Invalid NL80211_BAND_IFTYPE_ATTR_HE_6GHZ_CAPA value {:?}",
+                    payload
+                );
+                Self::He6GhzCapa(
+                    payload
+                        .get(..2)
+                        .map(LittleEndian::read_u16)
+                        .ok_or_else(|| DecodeError::from(err_msg))?,
+                )
+            }
+            _ => Self::Other(
+                DefaultNla::parse(buf).context("invalid NLA (unknown kind)")?,
+            ),
+        })
+    }
+}
+
+/// Capabilities of a band for a set of interface types
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct Nl80211BandIftypeData {
+    /// Interface types this data applies to
+    pub iftypes: Option<Vec<Nl80211InterfaceType>>,
+    /// Raw HE MAC Capabilities Information field
+    pub he_cap_mac: Option<[u8; HE_MAC_CAP_LEN]>,
+    /// Raw HE PHY Capabilities Information field
+    pub he_cap_phy: Option<[u8; HE_PHY_CAP_LEN]>,
+    /// Supported HE-MCS and NSS set
+    pub he_cap_mcs_set: Option<Nl80211HeMcsNssSupp>,
+    /// Raw PPE Thresholds field
+    pub he_cap_ppe: Option<Vec<u8>>,
+    /// Raw HE 6 GHz Band Capabilities Information field
+    pub he_6ghz_capa: Option<u16>,
+}
+
+impl Nl80211BandIftypeData {
+    /// Whether HE (802.11ax) is supported for these interface types
+    pub fn he_supported(&self) -> bool {
+        self.he_cap_phy.is_some()
+    }
+
+    /// Minimum MPDU start spacing in the 6 GHz band, encoded as the HT
+    /// A-MPDU density
+    pub fn he_6ghz_min_mpdu_start(&self) -> Option<u8> {
+        self.he_6ghz_capa.map(|d| (d & 0b111) as u8)
+    }
+
+    /// Maximum A-MPDU length exponent in the 6 GHz band
+    pub fn he_6ghz_max_ampdu_len_exp(&self) -> Option<u8> {
+        self.he_6ghz_capa.map(|d| ((d >> 3) & 0b111) as u8)
+    }
+}
+
+impl Emitable for Nl80211BandIftypeData {
+    fn buffer_len(&self) -> usize {
+        Vec::<Nl80211BandIftypeDataNla>::from(self)
+            .as_slice()
+            .buffer_len()
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        Vec::<Nl80211BandIftypeDataNla>::from(self)
+            .as_slice()
+            .emit(buffer)
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for Nl80211BandIftypeData
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let mut ret = Self::default();
+        let payload = buf.value();
+        let err_msg = format!(
+            "Invalid NL80211_BAND_ATTR_IFTYPE_DATA value {:?}",
+            payload
+        );
+        for nla in NlasIterator::new(payload) {
+            let nla = &nla.context(err_msg.clone())?;
+            match Nl80211BandIftypeDataNla::parse(nla)
+                .context(err_msg.clone())?
+            {
+                Nl80211BandIftypeDataNla::Iftypes(d) => ret.iftypes = Some(d),
+                Nl80211BandIftypeDataNla::HeCapMac(d) => {
+                    ret.he_cap_mac = Some(d)
+                }
+                Nl80211BandIftypeDataNla::HeCapPhy(d) => {
+                    ret.he_cap_phy = Some(d)
+                }
+                Nl80211BandIftypeDataNla::HeCapMcsSet(d) => {
+                    ret.he_cap_mcs_set = Some(d)
+                }
+                Nl80211BandIftypeDataNla::HeCapPpe(d) => {
+                    ret.he_cap_ppe = Some(d)
+                }
+                Nl80211BandIftypeDataNla::He6GhzCapa(d) => {
+                    ret.he_6ghz_capa = Some(d)
+                }
+                Nl80211BandIftypeDataNla::Other(attr) => {
+                    log::warn!(
+                        "Got unsupported NL80211_BAND_ATTR_IFTYPE_DATA \
+                         value {:?}",
+                        attr
+                    )
+                }
+            }
+        }
+        Ok(ret)
+    }
+}
+
+impl From<&Nl80211BandIftypeData> for Vec<Nl80211BandIftypeDataNla> {
+    fn from(data: &Nl80211BandIftypeData) -> Self {
+        let mut nlas = Vec::new();
+        if let Some(v) = data.iftypes.as_ref() {
+            nlas.push(Nl80211BandIftypeDataNla::Iftypes(v.clone()));
+        }
+        if let Some(v) = data.he_cap_mac {
+            nlas.push(Nl80211BandIftypeDataNla::HeCapMac(v));
+        }
+        if let Some(v) = data.he_cap_phy {
+            nlas.push(Nl80211BandIftypeDataNla::HeCapPhy(v));
+        }
+        if let Some(v) = data.he_cap_mcs_set {
+            nlas.push(Nl80211BandIftypeDataNla::HeCapMcsSet(v));
+        }
+        if let Some(v) = data.he_cap_ppe.as_ref() {
+            nlas.push(Nl80211BandIftypeDataNla::HeCapPpe(v.clone()));
+        }
+        if let Some(v) = data.he_6ghz_capa {
+            nlas.push(Nl80211BandIftypeDataNla::He6GhzCapa(v));
+        }
+        nlas
+    }
+}
@@ -0,0 +1,39 @@
+// SPDX-License-Identifier: MIT
+
+mod ac;
+mod band;
+mod bitrate;
+mod ext_capa;
+mod ext_feature;
+mod frequency;
+mod get;
+mod get_protocol_features;
+mod handle;
+mod he;
+mod ht;
+mod iftype_data;
+mod protocol_features;
+mod set;
+mod set_netns;
+mod txq_params;
+mod vht;
+
+pub use ac::Nl80211Ac;
+pub use band::{Nl80211BandInfo, Nl80211WiPhyBand};
+pub use bitrate::{Nl80211Bitrate, Nl80211BitrateNla};
+pub use ext_capa::{
+    Nl80211ExtendedCapability, Nl80211IftypeExtCapa, Nl80211IftypeExtCapaNla,
+};
+pub use ext_feature::{Nl80211ExtFeature, Nl80211ExtFeatures};
+pub use frequency::{Nl80211DfsState, Nl80211Frequency, Nl80211FrequencyNla};
+pub use get::Nl80211PhyGetRequest;
+pub use get_protocol_features::Nl80211ProtocolFeaturesGetRequest;
+pub use handle::Nl80211PhyHandle;
+pub use he::Nl80211HeMcsNssSupp;
+pub use ht::{Nl80211HtCapability, Nl80211HtMcsInfo};
+pub use iftype_data::{Nl80211BandIftypeData, Nl80211BandIftypeDataNla};
+pub use protocol_features::Nl80211ProtocolFeatures;
+pub use set::Nl80211PhySetRequest;
+pub use set_netns::Nl80211PhyNetnsSetRequest;
+pub use txq_params::{Nl80211TxqParams, Nl80211TxqParamsNla};
+pub use vht::{Nl80211VhtCapability, Nl80211VhtMcsInfo};
@@ -0,0 +1,15 @@
+// SPDX-License-Identifier: MIT
+
+use crate::macros::nl80211_bitflags;
+
+const NL80211_PROTOCOL_FEATURE_SPLIT_WIPHY_DUMP: u32 = 1 << 0;
+
+nl80211_bitflags! {
+    /// Features of the nl80211 protocol supported by the kernel, could be
+    /// combined via `|` operator.
+    pub struct Nl80211ProtocolFeatures: u32 {
+        /// Wiphy dumps could be split into multiple messages per wiphy when
+        /// requested by `Nl80211Attr::SplitWiPhyDump`
+        const SPLIT_WIPHY_DUMP = NL80211_PROTOCOL_FEATURE_SPLIT_WIPHY_DUMP;
+    }
+}
@@ -0,0 +1,158 @@
+// SPDX-License-Identifier: MIT
+
+use crate::{
+    nl80211_execute_ack, Nl80211Attr, Nl80211ChannelDef, Nl80211Cmd,
+    Nl80211Error, Nl80211Handle, Nl80211Message, Nl80211TxqParams,
+};
+
+pub struct Nl80211PhySetRequest {
+    handle: Nl80211Handle,
+    wiphy: u32,
+    if_index: Option<u32>,
+    txq_params: Vec<Nl80211TxqParams>,
+    chandef: Option<Nl80211ChannelDef>,
+    retry_short: Option<u8>,
+    retry_long: Option<u8>,
+    frag_threshold: Option<u32>,
+    rts_threshold: Option<u32>,
+    coverage_class: Option<u8>,
+    antenna: Option<(u32, u32)>,
+}
+
+impl Nl80211PhySetRequest {
+    pub(crate) fn new(handle: Nl80211Handle, wiphy: u32) -> Self {
+        Nl80211PhySetRequest {
+            handle,
+            wiphy,
+            if_index: None,
+            txq_params: Vec::new(),
+            chandef: None,
+            retry_short: None,
+            retry_long: None,
+            frag_threshold: None,
+            rts_threshold: None,
+            coverage_class: None,
+            antenna: None,
+        }
+    }
+
+    /// Interface of the wiphy, required by per-interface settings like TX
+    /// queue parameters
+    pub fn if_index(mut self, if_index: u32) -> Self {
+        self.if_index = Some(if_index);
+        self
+    }
+
+    /// Add EDCA parameters of an access category, only supported on access
+    /// point and P2P GO interfaces
+    pub fn txq_params(mut self, params: Nl80211TxqParams) -> Self {
+        self.txq_params.push(params);
+        self
+    }
+
+    /// Channel of the wiphy, checked for consistency before sending
+    /// (equivalent to `iw phy PHY set channel ...`)
+    pub fn chandef(mut self, chandef: Nl80211ChannelDef) -> Self {
+        self.chandef = Some(chandef);
+        self
+    }
+
+    /// Retry limit for frames not longer than the RTS threshold
+    /// (equivalent to `iw phy PHY set retry short LIMIT`)
+    pub fn retry_short(mut self, retry_short: u8) -> Self {
+        self.retry_short = Some(retry_short);
+        self
+    }
+
+    /// Retry limit for frames longer than the RTS threshold
+    /// (equivalent to `iw phy PHY set retry long LIMIT`)
+    pub fn retry_long(mut self, retry_long: u8) -> Self {
+        self.retry_long = Some(retry_long);
+        self
+    }
+
+    /// Fragmentation threshold in octets, `u32::MAX` to disable
+    /// (equivalent to `iw phy PHY set frag THRESHOLD`)
+    pub fn frag_threshold(mut self, frag_threshold: u32) -> Self {
+        self.frag_threshold = Some(frag_threshold);
+        self
+    }
+
+    /// RTS threshold in octets, `u32::MAX` to disable
+    /// (equivalent to `iw phy PHY set rts THRESHOLD`)
+    pub fn rts_threshold(mut self, rts_threshold: u32) -> Self {
+        self.rts_threshold = Some(rts_threshold);
+        self
+    }
+
+    /// Coverage class, adjusting the slot time and ACK timeout for long
+    /// distance links (equivalent to `iw phy PHY set coverage CLASS`)
+    pub fn coverage_class(mut self, coverage_class: u8) -> Self {
+        self.coverage_class = Some(coverage_class);
+        self
+    }
+
+    /// Bitmaps of the antennas used for transmitting and receiving, limited
+    /// to the available antennas reported by the wiphy
+    /// (equivalent to `iw phy PHY set antenna TX RX`)
+    pub fn antenna(mut self, tx: u32, rx: u32) -> Self {
+        self.antenna = Some((tx, rx));
+        self
+    }
+
+    pub async fn execute(self) -> Result<(), Nl80211Error> {
+        let Nl80211PhySetRequest {
+            mut handle,
+            wiphy,
+            if_index,
+            txq_params,
+            chandef,
+            retry_short,
+            retry_long,
+            frag_threshold,
+            rts_threshold,
+            coverage_class,
+            antenna,
+        } = self;
+
+        if let Some(chandef) = chandef.as_ref() {
+            chandef.validate()?;
+        }
+
+        let mut nlas = vec![Nl80211Attr::WiPhy(wiphy)];
+        if let Some(if_index) = if_index {
+            nlas.push(Nl80211Attr::IfIndex(if_index));
+        }
+        if !txq_params.is_empty() {
+            nlas.push(Nl80211Attr::WiPhyTxqParams(txq_params));
+        }
+        if let Some(chandef) = chandef {
+            nlas.extend(Vec::<Nl80211Attr>::from(&chandef));
+        }
+        if let Some(v) = retry_short {
+            nlas.push(Nl80211Attr::WiPhyRetryShort(v));
+        }
+        if let Some(v) = retry_long {
+            nlas.push(Nl80211Attr::WiPhyRetryLong(v));
+        }
+        if let Some(v) = frag_threshold {
+            nlas.push(Nl80211Attr::WiPhyFragThreshold(v));
+        }
+        if let Some(v) = rts_threshold {
+            nlas.push(Nl80211Attr::WiPhyRtsThreshold(v));
+        }
+        if let Some(v) = coverage_class {
+            nlas.push(Nl80211Attr::WiPhyCoverageClass(v));
+        }
+        if let Some((tx, rx)) = antenna {
+            nlas.push(Nl80211Attr::WiPhyAntennaTx(tx));
+            nlas.push(Nl80211Attr::WiPhyAntennaRx(rx));
+        }
+
+        let nl80211_msg = Nl80211Message {
+            cmd: Nl80211Cmd::WiPhySet,
+            nlas,
+        };
+        nl80211_execute_ack(&mut handle, nl80211_msg).await
+    }
+}
@@ -0,0 +1,54 @@
+// SPDX-License-Identifier: MIT
+
+use std::os::unix::io::RawFd;
+
+use crate::{
+    nl80211_execute_ack, Nl80211Attr, Nl80211Cmd, Nl80211Error, Nl80211Handle,
+    Nl80211Message,
+};
+
+pub struct Nl80211PhyNetnsSetRequest {
+    handle: Nl80211Handle,
+    wiphy: u32,
+    netns: Nl80211Attr,
+}
+
+impl Nl80211PhyNetnsSetRequest {
+    pub(crate) fn new_by_pid(
+        handle: Nl80211Handle,
+        wiphy: u32,
+        pid: u32,
+    ) -> Self {
+        Nl80211PhyNetnsSetRequest {
+            handle,
+            wiphy,
+            netns: Nl80211Attr::Pid(pid),
+        }
+    }
+
+    pub(crate) fn new_by_fd(
+        handle: Nl80211Handle,
+        wiphy: u32,
+        fd: RawFd,
+    ) -> Self {
+        Nl80211PhyNetnsSetRequest {
+            handle,
+            wiphy,
+            netns: Nl80211Attr::NetnsFd(fd as u32),
+        }
+    }
+
+    pub async fn execute(self) -> Result<(), Nl80211Error> {
+        let Nl80211PhyNetnsSetRequest {
+            mut handle,
+            wiphy,
+            netns,
+        } = self;
+
+        let nl80211_msg = Nl80211Message {
+            cmd: Nl80211Cmd::WiPhyNetnsSet,
+            nlas: vec![Nl80211Attr::WiPhy(wiphy), netns],
+        };
+        nl80211_execute_ack(&mut handle, nl80211_msg).await
+    }
+}
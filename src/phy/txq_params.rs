@@ -0,0 +1,216 @@
+// SPDX-License-Identifier: MIT
+
+use anyhow::Context;
+use byteorder::{ByteOrder, NativeEndian};
+use netlink_packet_utils::{
+    nla::{DefaultNla, Nla, NlaBuffer, NlasIterator},
+    parsers::{parse_u16, parse_u8},
+    DecodeError, Emitable, Parseable,
+};
+
+use crate::Nl80211Ac;
+
+const NL80211_TXQ_ATTR_AC: u16 = 1;
+const NL80211_TXQ_ATTR_TXOP: u16 = 2;
+const NL80211_TXQ_ATTR_CWMIN: u16 = 3;
+const NL80211_TXQ_ATTR_CWMAX: u16 = 4;
+const NL80211_TXQ_ATTR_AIFS: u16 = 5;
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum Nl80211TxqParamsNla {
+    Ac(Nl80211Ac),
+    /// Maximum burst time in units of 32 microseconds, 0 to disable
+    Txop(u16),
+    /// Minimum contention window in the form of 2^n-1 (1..32767)
+    Cwmin(u16),
+    /// Maximum contention window in the form of 2^n-1 (1..32767)
+    Cwmax(u16),
+    /// Arbitration interframe space in slots
+    Aifs(u8),
+    Other(DefaultNla),
+}
+
+impl Nla for Nl80211TxqParamsNla {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::Ac(_) | Self::Aifs(_) => 1,
+            Self::Txop(_) | Self::Cwmin(_) | Self::Cwmax(_) => 2,
+            Self::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::Ac(_) => NL80211_TXQ_ATTR_AC,
+            Self::Txop(_) => NL80211_TXQ_ATTR_TXOP,
+            Self::Cwmin(_) => NL80211_TXQ_ATTR_CWMIN,
+            Self::Cwmax(_) => NL80211_TXQ_ATTR_CWMAX,
+            Self::Aifs(_) => NL80211_TXQ_ATTR_AIFS,
+            Self::Other(attr) => attr.kind(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Aifs(d) => buffer[0] = *d,
+            Self::Txop(d) | Self::Cwmin(d) | Self::Cwmax(d) => {
+                NativeEndian::write_u16(buffer, *d)
+            }
+            Self::Ac(d) => buffer[0] = (*d).into(),
+            Self::Other(ref attr) => attr.emit(buffer),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for Nl80211TxqParamsNla
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            NL80211_TXQ_ATTR_AC => {
+                let err_msg = format!(
+                    "Invalid NL80211_TXQ_ATTR_AC \
+                     value {:?}",
+                    payload
+                );
+                Self::Ac(parse_u8(payload).context(err_msg)?.into())
+            }
+            NL80211_TXQ_ATTR_TXOP => {
+                let err_msg = format!(
+                    "Invalid NL80211_TXQ_ATTR_TXOP \
+                     value {:?}",
+                    payload
+                );
+                Self::Txop(parse_u16(payload).context(err_msg)?)
+            }
+            NL80211_TXQ_ATTR_CWMIN => {
+                let err_msg = format!(
+                    "Invalid NL80211_TXQ_ATTR_CWMIN \
+                     value {:?}",
+                    payload
+                );
+                Self::Cwmin(parse_u16(payload).context(err_msg)?)
+            }
+            NL80211_TXQ_ATTR_CWMAX => {
+                let err_msg = format!(
+                    "Invalid NL80211_TXQ_ATTR_CWMAX \
+                     value {:?}",
+                    payload
+                );
+                Self::Cwmax(parse_u16(payload).context(err_msg)?)
+            }
+            NL80211_TXQ_ATTR_AIFS => {
+                let err_msg = format!(
+                    "Invalid NL80211_TXQ_ATTR_AIFS \
+                     value {:?}",
+                    payload
+                );
+                Self::Aifs(parse_u8(payload).context(err_msg)?)
+            }
+            _ => Self::Other(
+                DefaultNla::parse(buf).context("invalid NLA (unknown kind)")?,
+            ),
+        })
+    }
+}
+
+/// EDCA parameters of an access category
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct Nl80211TxqParams {
+    pub ac: Option<Nl80211Ac>,
+    /// Maximum burst time in units of 32 microseconds, 0 to disable
+    pub txop: Option<u16>,
+    /// Minimum contention window in the form of 2^n-1 (1..32767)
+    pub cwmin: Option<u16>,
+    /// Maximum contention window in the form of 2^n-1 (1..32767)
+    pub cwmax: Option<u16>,
+    /// Arbitration interframe space in slots
+    pub aifs: Option<u8>,
+}
+
+impl Nl80211TxqParams {
+    /// The kernel requires all the parameters of the access category
+    pub fn new(
+        ac: Nl80211Ac,
+        txop: u16,
+        cwmin: u16,
+        cwmax: u16,
+        aifs: u8,
+    ) -> Self {
+        Self {
+            ac: Some(ac),
+            txop: Some(txop),
+            cwmin: Some(cwmin),
+            cwmax: Some(cwmax),
+            aifs: Some(aifs),
+        }
+    }
+}
+
+impl Emitable for Nl80211TxqParams {
+    fn buffer_len(&self) -> usize {
+        Vec::<Nl80211TxqParamsNla>::from(self)
+            .as_slice()
+            .buffer_len()
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        Vec::<Nl80211TxqParamsNla>::from(self)
+            .as_slice()
+            .emit(buffer)
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for Nl80211TxqParams
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let mut ret = Self::default();
+        let payload = buf.value();
+        let err_msg = format!(
+            "Invalid NL80211_ATTR_WIPHY_TXQ_PARAMS value {:?}",
+            payload
+        );
+        for nla in NlasIterator::new(payload) {
+            let nla = &nla.context(err_msg.clone())?;
+            match Nl80211TxqParamsNla::parse(nla).context(err_msg.clone())? {
+                Nl80211TxqParamsNla::Ac(d) => ret.ac = Some(d),
+                Nl80211TxqParamsNla::Txop(d) => ret.txop = Some(d),
+                Nl80211TxqParamsNla::Cwmin(d) => ret.cwmin = Some(d),
+                Nl80211TxqParamsNla::Cwmax(d) => ret.cwmax = Some(d),
+                Nl80211TxqParamsNla::Aifs(d) => ret.aifs = Some(d),
+                Nl80211TxqParamsNla::Other(attr) => {
+                    log::warn!(
+                        "Got unsupported NL80211_ATTR_WIPHY_TXQ_PARAMS \
+                         value {:?}",
+                        attr
+                    )
+                }
+            }
+        }
+        Ok(ret)
+    }
+}
+
+impl From<&Nl80211TxqParams> for Vec<Nl80211TxqParamsNla> {
+    fn from(params: &Nl80211TxqParams) -> Self {
+        let mut nlas = Vec::new();
+        if let Some(v) = params.ac {
+            nlas.push(Nl80211TxqParamsNla::Ac(v));
+        }
+        if let Some(v) = params.txop {
+            nlas.push(Nl80211TxqParamsNla::Txop(v));
+        }
+        if let Some(v) = params.cwmin {
+            nlas.push(Nl80211TxqParamsNla::Cwmin(v));
+        }
+        if let Some(v) = params.cwmax {
+            nlas.push(Nl80211TxqParamsNla::Cwmax(v));
+        }
+        if let Some(v) = params.aifs {
+            nlas.push(Nl80211TxqParamsNla::Aifs(v));
+        }
+        nlas
+    }
+}
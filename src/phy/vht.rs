@@ -0,0 +1,165 @@
+// SPDX-License-Identifier: MIT
+
+use byteorder::{ByteOrder, LittleEndian};
+use netlink_packet_utils::{DecodeError, Emitable};
+
+const VHT_CAP_RXLDPC: u32 = 1 << 4;
+const VHT_CAP_SHORT_GI_80: u32 = 1 << 5;
+const VHT_CAP_SHORT_GI_160: u32 = 1 << 6;
+const VHT_CAP_TXSTBC: u32 = 1 << 7;
+const VHT_CAP_SU_BEAMFORMER_CAPABLE: u32 = 1 << 11;
+const VHT_CAP_SU_BEAMFORMEE_CAPABLE: u32 = 1 << 12;
+const VHT_CAP_MU_BEAMFORMER_CAPABLE: u32 = 1 << 19;
+const VHT_CAP_MU_BEAMFORMEE_CAPABLE: u32 = 1 << 20;
+
+const VHT_MCS_NOT_SUPPORTED: u16 = 3;
+
+/// VHT MCS and NSS set supported by a band of the wiphy
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+pub struct Nl80211VhtMcsInfo {
+    /// RX MCS map, 2 bits for each spatial stream
+    pub rx_mcs_map: u16,
+    /// Highest supported RX data rate in Mbps, 0 if not specified
+    pub rx_highest: u16,
+    /// TX MCS map, 2 bits for each spatial stream
+    pub tx_mcs_map: u16,
+    /// Highest supported TX data rate in Mbps, 0 if not specified. The
+    /// highest bits are extended capabilities.
+    pub tx_highest: u16,
+}
+
+impl Nl80211VhtMcsInfo {
+    pub(crate) const LENGTH: usize = 8;
+
+    /// Highest RX MCS index supported for the number of spatial streams
+    /// (1 to 8), `None` if not supported.
+    pub fn rx_max_mcs(&self, nss: u8) -> Option<u8> {
+        max_mcs(self.rx_mcs_map, nss)
+    }
+
+    /// Highest TX MCS index supported for the number of spatial streams
+    /// (1 to 8), `None` if not supported.
+    pub fn tx_max_mcs(&self, nss: u8) -> Option<u8> {
+        max_mcs(self.tx_mcs_map, nss)
+    }
+
+    pub(crate) fn parse(payload: &[u8]) -> Result<Self, DecodeError> {
+        if payload.len() < Self::LENGTH {
+            return Err(format!(
+                "Invalid NL80211_BAND_ATTR_VHT_MCS_SET {:?}",
+                payload
+            )
+            .into());
+        }
+        Ok(Self {
+            rx_mcs_map: LittleEndian::read_u16(&payload[0..2]),
+            rx_highest: LittleEndian::read_u16(&payload[2..4]),
+            tx_mcs_map: LittleEndian::read_u16(&payload[4..6]),
+            tx_highest: LittleEndian::read_u16(&payload[6..8]),
+        })
+    }
+}
+
+fn max_mcs(map: u16, nss: u8) -> Option<u8> {
+    if !(1..=8).contains(&nss) {
+        return None;
+    }
+    match (map >> ((nss - 1) * 2)) & 0b11 {
+        VHT_MCS_NOT_SUPPORTED => None,
+        d => Some(7 + d as u8),
+    }
+}
+
+impl Emitable for Nl80211VhtMcsInfo {
+    fn buffer_len(&self) -> usize {
+        Self::LENGTH
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        LittleEndian::write_u16(&mut buffer[0..2], self.rx_mcs_map);
+        LittleEndian::write_u16(&mut buffer[2..4], self.rx_highest);
+        LittleEndian::write_u16(&mut buffer[4..6], self.tx_mcs_map);
+        LittleEndian::write_u16(&mut buffer[6..8], self.tx_highest);
+    }
+}
+
+/// VHT capabilities of a band of the wiphy, combined from
+/// `NL80211_BAND_ATTR_VHT_CAPA` and `NL80211_BAND_ATTR_VHT_MCS_SET`.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+pub struct Nl80211VhtCapability {
+    /// Raw VHT Capabilities Information field
+    pub capa: u32,
+    pub mcs_set: Nl80211VhtMcsInfo,
+}
+
+impl Nl80211VhtCapability {
+    /// Maximum MPDU length in octets
+    pub fn max_mpdu_len(&self) -> u32 {
+        match self.capa & 0b11 {
+            0 => 3895,
+            1 => 7991,
+            _ => 11454,
+        }
+    }
+
+    /// Raw supported channel width set: 0 for 80 MHz only, 1 for 160 MHz,
+    /// 2 for 160 MHz and 80+80 MHz.
+    pub fn supported_channel_width_set(&self) -> u8 {
+        ((self.capa >> 2) & 0b11) as u8
+    }
+
+    /// Whether 160 MHz channel width is supported
+    pub fn supports_160mhz(&self) -> bool {
+        matches!(self.supported_channel_width_set(), 1 | 2)
+    }
+
+    /// Whether 80+80 MHz channel width is supported
+    pub fn supports_80p80mhz(&self) -> bool {
+        self.supported_channel_width_set() == 2
+    }
+
+    pub fn rx_ldpc(&self) -> bool {
+        self.capa & VHT_CAP_RXLDPC > 0
+    }
+
+    /// Whether short guard interval is supported for 80 MHz packets
+    pub fn short_gi_80(&self) -> bool {
+        self.capa & VHT_CAP_SHORT_GI_80 > 0
+    }
+
+    /// Whether short guard interval is supported for 160 and 80+80 MHz
+    /// packets
+    pub fn short_gi_160(&self) -> bool {
+        self.capa & VHT_CAP_SHORT_GI_160 > 0
+    }
+
+    pub fn tx_stbc(&self) -> bool {
+        self.capa & VHT_CAP_TXSTBC > 0
+    }
+
+    /// Number of spatial streams supported for STBC reception
+    pub fn rx_stbc(&self) -> u8 {
+        ((self.capa >> 8) & 0b111) as u8
+    }
+
+    pub fn su_beamformer(&self) -> bool {
+        self.capa & VHT_CAP_SU_BEAMFORMER_CAPABLE > 0
+    }
+
+    pub fn su_beamformee(&self) -> bool {
+        self.capa & VHT_CAP_SU_BEAMFORMEE_CAPABLE > 0
+    }
+
+    pub fn mu_beamformer(&self) -> bool {
+        self.capa & VHT_CAP_MU_BEAMFORMER_CAPABLE > 0
+    }
+
+    pub fn mu_beamformee(&self) -> bool {
+        self.capa & VHT_CAP_MU_BEAMFORMEE_CAPABLE > 0
+    }
+
+    /// Maximum A-MPDU length in octets
+    pub fn max_ampdu_len(&self) -> u32 {
+        (1u32 << (13 + ((self.capa >> 23) & 0b111))) - 1
+    }
+}
@@ -0,0 +1,275 @@
+// SPDX-License-Identifier: MIT
+
+use anyhow::Context;
+use byteorder::{ByteOrder, NativeEndian};
+use netlink_packet_utils::{
+    nla::{DefaultNla, Nla, NlaBuffer, NlasIterator},
+    parsers::parse_u32,
+    DecodeError, Emitable, Parseable,
+};
+
+const NL80211_PMSR_FTM_CAPA_ATTR_ASAP: u16 = 1;
+const NL80211_PMSR_FTM_CAPA_ATTR_NON_ASAP: u16 = 2;
+const NL80211_PMSR_FTM_CAPA_ATTR_REQ_LCI: u16 = 3;
+const NL80211_PMSR_FTM_CAPA_ATTR_REQ_CIVICLOC: u16 = 4;
+const NL80211_PMSR_FTM_CAPA_ATTR_PREAMBLES: u16 = 5;
+const NL80211_PMSR_FTM_CAPA_ATTR_BANDWIDTHS: u16 = 6;
+const NL80211_PMSR_FTM_CAPA_ATTR_MAX_BURSTS_EXPONENT: u16 = 7;
+const NL80211_PMSR_FTM_CAPA_ATTR_MAX_FTMS_PER_BURST: u16 = 8;
+const NL80211_PMSR_FTM_CAPA_ATTR_TRIGGER_BASED: u16 = 9;
+const NL80211_PMSR_FTM_CAPA_ATTR_NON_TRIGGER_BASED: u16 = 10;
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum Nl80211PmsrFtmCapaNla {
+    /// ASAP mode is supported
+    Asap,
+    /// Non-ASAP mode is supported
+    NonAsap,
+    /// LCI data can be requested
+    ReqLci,
+    /// Civic location data can be requested
+    ReqCivicloc,
+    /// Bitmap of supported `Nl80211Preamble` values
+    Preambles(u32),
+    /// Bitmap of supported `Nl80211ChannelWidth` values
+    Bandwidths(u32),
+    /// Maximum number of bursts exponent, any when not set
+    MaxBurstsExponent(u32),
+    /// Maximum FTMs per burst, any when not set
+    MaxFtmsPerBurst(u32),
+    /// Trigger based ranging is supported
+    TriggerBased,
+    /// Non trigger based ranging is supported
+    NonTriggerBased,
+    Other(DefaultNla),
+}
+
+impl Nla for Nl80211PmsrFtmCapaNla {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::Asap
+            | Self::NonAsap
+            | Self::ReqLci
+            | Self::ReqCivicloc
+            | Self::TriggerBased
+            | Self::NonTriggerBased => 0,
+            Self::Preambles(_)
+            | Self::Bandwidths(_)
+            | Self::MaxBurstsExponent(_)
+            | Self::MaxFtmsPerBurst(_) => 4,
+            Self::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::Asap => NL80211_PMSR_FTM_CAPA_ATTR_ASAP,
+            Self::NonAsap => NL80211_PMSR_FTM_CAPA_ATTR_NON_ASAP,
+            Self::ReqLci => NL80211_PMSR_FTM_CAPA_ATTR_REQ_LCI,
+            Self::ReqCivicloc => NL80211_PMSR_FTM_CAPA_ATTR_REQ_CIVICLOC,
+            Self::Preambles(_) => NL80211_PMSR_FTM_CAPA_ATTR_PREAMBLES,
+            Self::Bandwidths(_) => NL80211_PMSR_FTM_CAPA_ATTR_BANDWIDTHS,
+            Self::MaxBurstsExponent(_) => {
+                NL80211_PMSR_FTM_CAPA_ATTR_MAX_BURSTS_EXPONENT
+            }
+            Self::MaxFtmsPerBurst(_) => {
+                NL80211_PMSR_FTM_CAPA_ATTR_MAX_FTMS_PER_BURST
+            }
+            Self::TriggerBased => NL80211_PMSR_FTM_CAPA_ATTR_TRIGGER_BASED,
+            Self::NonTriggerBased => {
+                NL80211_PMSR_FTM_CAPA_ATTR_NON_TRIGGER_BASED
+            }
+            Self::Other(attr) => attr.kind(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Asap
+            | Self::NonAsap
+            | Self::ReqLci
+            | Self::ReqCivicloc
+            | Self::TriggerBased
+            | Self::NonTriggerBased => (),
+            Self::Preambles(d)
+            | Self::Bandwidths(d)
+            | Self::MaxBurstsExponent(d)
+            | Self::MaxFtmsPerBurst(d) => NativeEndian::write_u32(buffer, *d),
+            Self::Other(ref attr) => attr.emit(buffer),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for Nl80211PmsrFtmCapaNla
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            NL80211_PMSR_FTM_CAPA_ATTR_ASAP => Self::Asap,
+            NL80211_PMSR_FTM_CAPA_ATTR_NON_ASAP => Self::NonAsap,
+            NL80211_PMSR_FTM_CAPA_ATTR_REQ_LCI => Self::ReqLci,
+            NL80211_PMSR_FTM_CAPA_ATTR_REQ_CIVICLOC => Self::ReqCivicloc,
+            NL80211_PMSR_FTM_CAPA_ATTR_PREAMBLES => {
+                let err_msg = format!(
+                    "Invalid NL80211_PMSR_FTM_CAPA_ATTR_PREAMBLES \
+                     value {:?}",
+                    payload
+                );
+                Self::Preambles(parse_u32(payload).context(err_msg)?)
+            }
+            NL80211_PMSR_FTM_CAPA_ATTR_BANDWIDTHS => {
+                let err_msg = format!(
+                    "Invalid NL80211_PMSR_FTM_CAPA_ATTR_BANDWIDTHS \
+                     value {:?}",
+                    payload
+                );
+                Self::Bandwidths(parse_u32(payload).context(err_msg)?)
+            }
+            NL80211_PMSR_FTM_CAPA_ATTR_MAX_BURSTS_EXPONENT => {
+                let err_msg = format!(
+                    "Invalid NL80211_PMSR_FTM_CAPA_ATTR_MAX_BURSTS_EXPONENT \
+                     value {:?}",
+                    payload
+                );
+                Self::MaxBurstsExponent(parse_u32(payload).context(err_msg)?)
+            }
+            NL80211_PMSR_FTM_CAPA_ATTR_MAX_FTMS_PER_BURST => {
+                let err_msg = format!(
+                    "Invalid NL80211_PMSR_FTM_CAPA_ATTR_MAX_FTMS_PER_BURST \
+                     value {:?}",
+                    payload
+                );
+                Self::MaxFtmsPerBurst(parse_u32(payload).context(err_msg)?)
+            }
+            NL80211_PMSR_FTM_CAPA_ATTR_TRIGGER_BASED => Self::TriggerBased,
+            NL80211_PMSR_FTM_CAPA_ATTR_NON_TRIGGER_BASED => {
+                Self::NonTriggerBased
+            }
+            _ => Self::Other(
+                DefaultNla::parse(buf).context("invalid NLA (unknown kind)")?,
+            ),
+        })
+    }
+}
+
+/// Fine timing measurement capabilities of the wiphy
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct Nl80211PmsrFtmCapa {
+    /// ASAP mode is supported
+    pub asap: bool,
+    /// Non-ASAP mode is supported
+    pub non_asap: bool,
+    /// LCI data can be requested
+    pub req_lci: bool,
+    /// Civic location data can be requested
+    pub req_civicloc: bool,
+    /// Bitmap of supported `Nl80211Preamble` values
+    pub preambles: Option<u32>,
+    /// Bitmap of supported `Nl80211ChannelWidth` values
+    pub bandwidths: Option<u32>,
+    /// Maximum number of bursts exponent, any when not set
+    pub max_bursts_exponent: Option<u32>,
+    /// Maximum FTMs per burst, any when not set
+    pub max_ftms_per_burst: Option<u32>,
+    /// Trigger based ranging is supported
+    pub trigger_based: bool,
+    /// Non trigger based ranging is supported
+    pub non_trigger_based: bool,
+}
+
+impl Emitable for Nl80211PmsrFtmCapa {
+    fn buffer_len(&self) -> usize {
+        Vec::<Nl80211PmsrFtmCapaNla>::from(self)
+            .as_slice()
+            .buffer_len()
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        Vec::<Nl80211PmsrFtmCapaNla>::from(self)
+            .as_slice()
+            .emit(buffer)
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for Nl80211PmsrFtmCapa
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let mut ret = Self::default();
+        let payload = buf.value();
+        let err_msg = format!(
+            "Invalid NL80211_PMSR_ATTR_TYPE_CAPA \
+             value {:?}",
+            payload
+        );
+        for nla in NlasIterator::new(payload) {
+            let nla = &nla.context(err_msg.clone())?;
+            match Nl80211PmsrFtmCapaNla::parse(nla).context(err_msg.clone())? {
+                Nl80211PmsrFtmCapaNla::Asap => ret.asap = true,
+                Nl80211PmsrFtmCapaNla::NonAsap => ret.non_asap = true,
+                Nl80211PmsrFtmCapaNla::ReqLci => ret.req_lci = true,
+                Nl80211PmsrFtmCapaNla::ReqCivicloc => ret.req_civicloc = true,
+                Nl80211PmsrFtmCapaNla::Preambles(d) => ret.preambles = Some(d),
+                Nl80211PmsrFtmCapaNla::Bandwidths(d) => {
+                    ret.bandwidths = Some(d)
+                }
+                Nl80211PmsrFtmCapaNla::MaxBurstsExponent(d) => {
+                    ret.max_bursts_exponent = Some(d)
+                }
+                Nl80211PmsrFtmCapaNla::MaxFtmsPerBurst(d) => {
+                    ret.max_ftms_per_burst = Some(d)
+                }
+                Nl80211PmsrFtmCapaNla::TriggerBased => ret.trigger_based = true,
+                Nl80211PmsrFtmCapaNla::NonTriggerBased => {
+                    ret.non_trigger_based = true
+                }
+                Nl80211PmsrFtmCapaNla::Other(attr) => {
+                    log::warn!(
+                        "Got unsupported NL80211_PMSR_ATTR_TYPE_CAPA \
+                         value {:?}",
+                        attr
+                    )
+                }
+            }
+        }
+        Ok(ret)
+    }
+}
+
+impl From<&Nl80211PmsrFtmCapa> for Vec<Nl80211PmsrFtmCapaNla> {
+    fn from(capa: &Nl80211PmsrFtmCapa) -> Self {
+        let mut nlas = Vec::new();
+        if capa.asap {
+            nlas.push(Nl80211PmsrFtmCapaNla::Asap);
+        }
+        if capa.non_asap {
+            nlas.push(Nl80211PmsrFtmCapaNla::NonAsap);
+        }
+        if capa.req_lci {
+            nlas.push(Nl80211PmsrFtmCapaNla::ReqLci);
+        }
+        if capa.req_civicloc {
+            nlas.push(Nl80211PmsrFtmCapaNla::ReqCivicloc);
+        }
+        if let Some(v) = capa.preambles {
+            nlas.push(Nl80211PmsrFtmCapaNla::Preambles(v));
+        }
+        if let Some(v) = capa.bandwidths {
+            nlas.push(Nl80211PmsrFtmCapaNla::Bandwidths(v));
+        }
+        if let Some(v) = capa.max_bursts_exponent {
+            nlas.push(Nl80211PmsrFtmCapaNla::MaxBurstsExponent(v));
+        }
+        if let Some(v) = capa.max_ftms_per_burst {
+            nlas.push(Nl80211PmsrFtmCapaNla::MaxFtmsPerBurst(v));
+        }
+        if capa.trigger_based {
+            nlas.push(Nl80211PmsrFtmCapaNla::TriggerBased);
+        }
+        if capa.non_trigger_based {
+            nlas.push(Nl80211PmsrFtmCapaNla::NonTriggerBased);
+        }
+        nlas
+    }
+}
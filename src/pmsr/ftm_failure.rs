@@ -0,0 +1,84 @@
+// SPDX-License-Identifier: MIT
+
+const NL80211_PMSR_FTM_FAILURE_UNSPECIFIED: u32 = 0;
+const NL80211_PMSR_FTM_FAILURE_NO_RESPONSE: u32 = 1;
+const NL80211_PMSR_FTM_FAILURE_REJECTED: u32 = 2;
+const NL80211_PMSR_FTM_FAILURE_WRONG_CHANNEL: u32 = 3;
+const NL80211_PMSR_FTM_FAILURE_PEER_NOT_CAPABLE: u32 = 4;
+const NL80211_PMSR_FTM_FAILURE_INVALID_TIMESTAMP: u32 = 5;
+const NL80211_PMSR_FTM_FAILURE_PEER_BUSY: u32 = 6;
+const NL80211_PMSR_FTM_FAILURE_BAD_CHANGED_PARAMS: u32 = 7;
+
+/// Reason of failed fine timing measurement
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Nl80211PmsrFtmFailureReason {
+    /// Unspecified failure, not used
+    Unspecified,
+    /// No response from the FTM responder
+    NoResponse,
+    /// FTM responder rejected measurement
+    Rejected,
+    /// The peer is known to be on a different channel
+    WrongChannel,
+    /// Peer is not capable of FTM
+    PeerNotCapable,
+    /// Invalid T1/T4 timestamps received
+    InvalidTimestamp,
+    /// Peer reports busy, retry after `busy_retry_time` of the response
+    PeerBusy,
+    /// Parameters were changed by the peer and are no longer supported
+    BadChangedParams,
+    Other(u32),
+}
+
+impl From<u32> for Nl80211PmsrFtmFailureReason {
+    fn from(d: u32) -> Self {
+        match d {
+            NL80211_PMSR_FTM_FAILURE_UNSPECIFIED => Self::Unspecified,
+            NL80211_PMSR_FTM_FAILURE_NO_RESPONSE => Self::NoResponse,
+            NL80211_PMSR_FTM_FAILURE_REJECTED => Self::Rejected,
+            NL80211_PMSR_FTM_FAILURE_WRONG_CHANNEL => Self::WrongChannel,
+            NL80211_PMSR_FTM_FAILURE_PEER_NOT_CAPABLE => Self::PeerNotCapable,
+            NL80211_PMSR_FTM_FAILURE_INVALID_TIMESTAMP => {
+                Self::InvalidTimestamp
+            }
+            NL80211_PMSR_FTM_FAILURE_PEER_BUSY => Self::PeerBusy,
+            NL80211_PMSR_FTM_FAILURE_BAD_CHANGED_PARAMS => {
+                Self::BadChangedParams
+            }
+            _ => Self::Other(d),
+        }
+    }
+}
+
+impl From<Nl80211PmsrFtmFailureReason> for u32 {
+    fn from(v: Nl80211PmsrFtmFailureReason) -> u32 {
+        match v {
+            Nl80211PmsrFtmFailureReason::Unspecified => {
+                NL80211_PMSR_FTM_FAILURE_UNSPECIFIED
+            }
+            Nl80211PmsrFtmFailureReason::NoResponse => {
+                NL80211_PMSR_FTM_FAILURE_NO_RESPONSE
+            }
+            Nl80211PmsrFtmFailureReason::Rejected => {
+                NL80211_PMSR_FTM_FAILURE_REJECTED
+            }
+            Nl80211PmsrFtmFailureReason::WrongChannel => {
+                NL80211_PMSR_FTM_FAILURE_WRONG_CHANNEL
+            }
+            Nl80211PmsrFtmFailureReason::PeerNotCapable => {
+                NL80211_PMSR_FTM_FAILURE_PEER_NOT_CAPABLE
+            }
+            Nl80211PmsrFtmFailureReason::InvalidTimestamp => {
+                NL80211_PMSR_FTM_FAILURE_INVALID_TIMESTAMP
+            }
+            Nl80211PmsrFtmFailureReason::PeerBusy => {
+                NL80211_PMSR_FTM_FAILURE_PEER_BUSY
+            }
+            Nl80211PmsrFtmFailureReason::BadChangedParams => {
+                NL80211_PMSR_FTM_FAILURE_BAD_CHANGED_PARAMS
+            }
+            Nl80211PmsrFtmFailureReason::Other(d) => d,
+        }
+    }
+}
@@ -0,0 +1,358 @@
+// SPDX-License-Identifier: MIT
+
+use anyhow::Context;
+use byteorder::{ByteOrder, NativeEndian};
+use netlink_packet_utils::{
+    nla::{DefaultNla, Nla, NlaBuffer, NlasIterator},
+    parsers::{parse_u16, parse_u32, parse_u8},
+    DecodeError, Emitable, Parseable,
+};
+
+use crate::Nl80211Preamble;
+
+const NL80211_PMSR_FTM_REQ_ATTR_ASAP: u16 = 1;
+const NL80211_PMSR_FTM_REQ_ATTR_PREAMBLE: u16 = 2;
+const NL80211_PMSR_FTM_REQ_ATTR_NUM_BURSTS_EXP: u16 = 3;
+const NL80211_PMSR_FTM_REQ_ATTR_BURST_PERIOD: u16 = 4;
+const NL80211_PMSR_FTM_REQ_ATTR_BURST_DURATION: u16 = 5;
+const NL80211_PMSR_FTM_REQ_ATTR_FTMS_PER_BURST: u16 = 6;
+const NL80211_PMSR_FTM_REQ_ATTR_NUM_FTMR_RETRIES: u16 = 7;
+const NL80211_PMSR_FTM_REQ_ATTR_REQUEST_LCI: u16 = 8;
+const NL80211_PMSR_FTM_REQ_ATTR_REQUEST_CIVICLOC: u16 = 9;
+const NL80211_PMSR_FTM_REQ_ATTR_TRIGGER_BASED: u16 = 10;
+const NL80211_PMSR_FTM_REQ_ATTR_NON_TRIGGER_BASED: u16 = 11;
+const NL80211_PMSR_FTM_REQ_ATTR_LMR_FEEDBACK: u16 = 12;
+const NL80211_PMSR_FTM_REQ_ATTR_BSS_COLOR: u16 = 13;
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum Nl80211PmsrFtmRequestNla {
+    /// ASAP mode requested
+    Asap,
+    /// Preamble type, optional for DMG
+    Preamble(Nl80211Preamble),
+    /// Number of bursts exponent (0-15), 15 for no preference
+    NumBurstsExp(u8),
+    /// Interval between bursts in units of 100 ms
+    BurstPeriod(u16),
+    /// Burst duration encoded as in IEEE 802.11-2016 Table 9-257 (0-15), 15
+    /// for no preference
+    BurstDuration(u8),
+    /// Number of successful FTM frames requested per burst (0-31), 0 for no
+    /// preference
+    FtmsPerBurst(u8),
+    /// Number of FTM Request frame retries
+    NumFtmrRetries(u8),
+    /// Request LCI data
+    RequestLci,
+    /// Request civic location data
+    RequestCivicloc,
+    /// Request trigger based ranging, EDCA based ranging is used when
+    /// neither trigger based nor non trigger based is requested
+    TriggerBased,
+    /// Request non trigger based ranging
+    NonTriggerBased,
+    /// Negotiate LMR feedback, only for trigger based or non trigger based
+    /// ranging
+    LmrFeedback,
+    /// BSS color of the responder, only for trigger based or non trigger
+    /// based ranging
+    BssColor(u8),
+    Other(DefaultNla),
+}
+
+impl Nla for Nl80211PmsrFtmRequestNla {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::Asap
+            | Self::RequestLci
+            | Self::RequestCivicloc
+            | Self::TriggerBased
+            | Self::NonTriggerBased
+            | Self::LmrFeedback => 0,
+            Self::NumBurstsExp(_)
+            | Self::BurstDuration(_)
+            | Self::FtmsPerBurst(_)
+            | Self::NumFtmrRetries(_)
+            | Self::BssColor(_) => 1,
+            Self::BurstPeriod(_) => 2,
+            Self::Preamble(_) => 4,
+            Self::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::Asap => NL80211_PMSR_FTM_REQ_ATTR_ASAP,
+            Self::Preamble(_) => NL80211_PMSR_FTM_REQ_ATTR_PREAMBLE,
+            Self::NumBurstsExp(_) => NL80211_PMSR_FTM_REQ_ATTR_NUM_BURSTS_EXP,
+            Self::BurstPeriod(_) => NL80211_PMSR_FTM_REQ_ATTR_BURST_PERIOD,
+            Self::BurstDuration(_) => NL80211_PMSR_FTM_REQ_ATTR_BURST_DURATION,
+            Self::FtmsPerBurst(_) => NL80211_PMSR_FTM_REQ_ATTR_FTMS_PER_BURST,
+            Self::NumFtmrRetries(_) => {
+                NL80211_PMSR_FTM_REQ_ATTR_NUM_FTMR_RETRIES
+            }
+            Self::RequestLci => NL80211_PMSR_FTM_REQ_ATTR_REQUEST_LCI,
+            Self::RequestCivicloc => NL80211_PMSR_FTM_REQ_ATTR_REQUEST_CIVICLOC,
+            Self::TriggerBased => NL80211_PMSR_FTM_REQ_ATTR_TRIGGER_BASED,
+            Self::NonTriggerBased => {
+                NL80211_PMSR_FTM_REQ_ATTR_NON_TRIGGER_BASED
+            }
+            Self::LmrFeedback => NL80211_PMSR_FTM_REQ_ATTR_LMR_FEEDBACK,
+            Self::BssColor(_) => NL80211_PMSR_FTM_REQ_ATTR_BSS_COLOR,
+            Self::Other(attr) => attr.kind(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Asap
+            | Self::RequestLci
+            | Self::RequestCivicloc
+            | Self::TriggerBased
+            | Self::NonTriggerBased
+            | Self::LmrFeedback => (),
+            Self::NumBurstsExp(d)
+            | Self::BurstDuration(d)
+            | Self::FtmsPerBurst(d)
+            | Self::NumFtmrRetries(d)
+            | Self::BssColor(d) => buffer[0] = *d,
+            Self::BurstPeriod(d) => NativeEndian::write_u16(buffer, *d),
+            Self::Preamble(d) => NativeEndian::write_u32(buffer, (*d).into()),
+            Self::Other(ref attr) => attr.emit(buffer),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for Nl80211PmsrFtmRequestNla
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            NL80211_PMSR_FTM_REQ_ATTR_ASAP => Self::Asap,
+            NL80211_PMSR_FTM_REQ_ATTR_PREAMBLE => {
+                let err_msg = format!(
+                    "Invalid NL80211_PMSR_FTM_REQ_ATTR_PREAMBLE \
+                     value {:?}",
+                    payload
+                );
+                Self::Preamble(parse_u32(payload).context(err_msg)?.into())
+            }
+            NL80211_PMSR_FTM_REQ_ATTR_NUM_BURSTS_EXP => {
+                let err_msg = format!(
+                    "Invalid NL80211_PMSR_FTM_REQ_ATTR_NUM_BURSTS_EXP \
+                     value {:?}",
+                    payload
+                );
+                Self::NumBurstsExp(parse_u8(payload).context(err_msg)?)
+            }
+            NL80211_PMSR_FTM_REQ_ATTR_BURST_PERIOD => {
+                let err_msg = format!(
+                    "Invalid NL80211_PMSR_FTM_REQ_ATTR_BURST_PERIOD \
+                     value {:?}",
+                    payload
+                );
+                Self::BurstPeriod(parse_u16(payload).context(err_msg)?)
+            }
+            NL80211_PMSR_FTM_REQ_ATTR_BURST_DURATION => {
+                let err_msg = format!(
+                    "Invalid NL80211_PMSR_FTM_REQ_ATTR_BURST_DURATION \
+                     value {:?}",
+                    payload
+                );
+                Self::BurstDuration(parse_u8(payload).context(err_msg)?)
+            }
+            NL80211_PMSR_FTM_REQ_ATTR_FTMS_PER_BURST => {
+                let err_msg = format!(
+                    "Invalid NL80211_PMSR_FTM_REQ_ATTR_FTMS_PER_BURST \
+                     value {:?}",
+                    payload
+                );
+                Self::FtmsPerBurst(parse_u8(payload).context(err_msg)?)
+            }
+            NL80211_PMSR_FTM_REQ_ATTR_NUM_FTMR_RETRIES => {
+                let err_msg = format!(
+                    "Invalid NL80211_PMSR_FTM_REQ_ATTR_NUM_FTMR_RETRIES \
+                     value {:?}",
+                    payload
+                );
+                Self::NumFtmrRetries(parse_u8(payload).context(err_msg)?)
+            }
+            NL80211_PMSR_FTM_REQ_ATTR_REQUEST_LCI => Self::RequestLci,
+            NL80211_PMSR_FTM_REQ_ATTR_REQUEST_CIVICLOC => Self::RequestCivicloc,
+            NL80211_PMSR_FTM_REQ_ATTR_TRIGGER_BASED => Self::TriggerBased,
+            NL80211_PMSR_FTM_REQ_ATTR_NON_TRIGGER_BASED => {
+                Self::NonTriggerBased
+            }
+            NL80211_PMSR_FTM_REQ_ATTR_LMR_FEEDBACK => Self::LmrFeedback,
+            NL80211_PMSR_FTM_REQ_ATTR_BSS_COLOR => {
+                let err_msg = format!(
+                    "Invalid NL80211_PMSR_FTM_REQ_ATTR_BSS_COLOR \
+                     value {:?}",
+                    payload
+                );
+                Self::BssColor(parse_u8(payload).context(err_msg)?)
+            }
+            _ => Self::Other(
+                DefaultNla::parse(buf).context("invalid NLA (unknown kind)")?,
+            ),
+        })
+    }
+}
+
+/// Fine timing measurement request parameters of a peer
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct Nl80211PmsrFtmRequest {
+    /// ASAP mode requested
+    pub asap: bool,
+    /// Preamble type, optional for DMG
+    pub preamble: Option<Nl80211Preamble>,
+    /// Number of bursts exponent (0-15), 15 for no preference
+    pub num_bursts_exp: Option<u8>,
+    /// Interval between bursts in units of 100 ms
+    pub burst_period: Option<u16>,
+    /// Burst duration encoded as in IEEE 802.11-2016 Table 9-257 (0-15), 15
+    /// for no preference
+    pub burst_duration: Option<u8>,
+    /// Number of successful FTM frames requested per burst (0-31), 0 for no
+    /// preference
+    pub ftms_per_burst: Option<u8>,
+    /// Number of FTM Request frame retries
+    pub num_ftmr_retries: Option<u8>,
+    /// Request LCI data
+    pub request_lci: bool,
+    /// Request civic location data
+    pub request_civicloc: bool,
+    /// Request trigger based ranging, EDCA based ranging is used when
+    /// neither trigger based nor non trigger based is requested
+    pub trigger_based: bool,
+    /// Request non trigger based ranging
+    pub non_trigger_based: bool,
+    /// Negotiate LMR feedback, only for trigger based or non trigger based
+    /// ranging
+    pub lmr_feedback: bool,
+    /// BSS color of the responder, only for trigger based or non trigger
+    /// based ranging
+    pub bss_color: Option<u8>,
+}
+
+impl Emitable for Nl80211PmsrFtmRequest {
+    fn buffer_len(&self) -> usize {
+        Vec::<Nl80211PmsrFtmRequestNla>::from(self)
+            .as_slice()
+            .buffer_len()
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        Vec::<Nl80211PmsrFtmRequestNla>::from(self)
+            .as_slice()
+            .emit(buffer)
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for Nl80211PmsrFtmRequest
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let mut ret = Self::default();
+        let payload = buf.value();
+        let err_msg = format!(
+            "Invalid NL80211_PMSR_REQ_ATTR_DATA \
+             value {:?}",
+            payload
+        );
+        for nla in NlasIterator::new(payload) {
+            let nla = &nla.context(err_msg.clone())?;
+            match Nl80211PmsrFtmRequestNla::parse(nla)
+                .context(err_msg.clone())?
+            {
+                Nl80211PmsrFtmRequestNla::Asap => ret.asap = true,
+                Nl80211PmsrFtmRequestNla::Preamble(d) => ret.preamble = Some(d),
+                Nl80211PmsrFtmRequestNla::NumBurstsExp(d) => {
+                    ret.num_bursts_exp = Some(d)
+                }
+                Nl80211PmsrFtmRequestNla::BurstPeriod(d) => {
+                    ret.burst_period = Some(d)
+                }
+                Nl80211PmsrFtmRequestNla::BurstDuration(d) => {
+                    ret.burst_duration = Some(d)
+                }
+                Nl80211PmsrFtmRequestNla::FtmsPerBurst(d) => {
+                    ret.ftms_per_burst = Some(d)
+                }
+                Nl80211PmsrFtmRequestNla::NumFtmrRetries(d) => {
+                    ret.num_ftmr_retries = Some(d)
+                }
+                Nl80211PmsrFtmRequestNla::RequestLci => ret.request_lci = true,
+                Nl80211PmsrFtmRequestNla::RequestCivicloc => {
+                    ret.request_civicloc = true
+                }
+                Nl80211PmsrFtmRequestNla::TriggerBased => {
+                    ret.trigger_based = true
+                }
+                Nl80211PmsrFtmRequestNla::NonTriggerBased => {
+                    ret.non_trigger_based = true
+                }
+                Nl80211PmsrFtmRequestNla::LmrFeedback => {
+                    ret.lmr_feedback = true
+                }
+                Nl80211PmsrFtmRequestNla::BssColor(d) => {
+                    ret.bss_color = Some(d)
+                }
+                Nl80211PmsrFtmRequestNla::Other(attr) => {
+                    log::warn!(
+                        "Got unsupported NL80211_PMSR_REQ_ATTR_DATA value {:?}",
+                        attr
+                    )
+                }
+            }
+        }
+        Ok(ret)
+    }
+}
+
+impl From<&Nl80211PmsrFtmRequest> for Vec<Nl80211PmsrFtmRequestNla> {
+    fn from(req: &Nl80211PmsrFtmRequest) -> Self {
+        let mut nlas = Vec::new();
+        if req.asap {
+            nlas.push(Nl80211PmsrFtmRequestNla::Asap);
+        }
+        if let Some(v) = req.preamble {
+            nlas.push(Nl80211PmsrFtmRequestNla::Preamble(v));
+        }
+        if let Some(v) = req.num_bursts_exp {
+            nlas.push(Nl80211PmsrFtmRequestNla::NumBurstsExp(v));
+        }
+        if let Some(v) = req.burst_period {
+            nlas.push(Nl80211PmsrFtmRequestNla::BurstPeriod(v));
+        }
+        if let Some(v) = req.burst_duration {
+            nlas.push(Nl80211PmsrFtmRequestNla::BurstDuration(v));
+        }
+        if let Some(v) = req.ftms_per_burst {
+            nlas.push(Nl80211PmsrFtmRequestNla::FtmsPerBurst(v));
+        }
+        if let Some(v) = req.num_ftmr_retries {
+            nlas.push(Nl80211PmsrFtmRequestNla::NumFtmrRetries(v));
+        }
+        if req.request_lci {
+            nlas.push(Nl80211PmsrFtmRequestNla::RequestLci);
+        }
+        if req.request_civicloc {
+            nlas.push(Nl80211PmsrFtmRequestNla::RequestCivicloc);
+        }
+        if req.trigger_based {
+            nlas.push(Nl80211PmsrFtmRequestNla::TriggerBased);
+        }
+        if req.non_trigger_based {
+            nlas.push(Nl80211PmsrFtmRequestNla::NonTriggerBased);
+        }
+        if req.lmr_feedback {
+            nlas.push(Nl80211PmsrFtmRequestNla::LmrFeedback);
+        }
+        if let Some(v) = req.bss_color {
+            nlas.push(Nl80211PmsrFtmRequestNla::BssColor(v));
+        }
+        nlas
+    }
+}
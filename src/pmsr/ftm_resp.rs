@@ -0,0 +1,521 @@
+// SPDX-License-Identifier: MIT
+
+use anyhow::Context;
+use byteorder::{ByteOrder, NativeEndian};
+use netlink_packet_utils::{
+    nla::{DefaultNla, Nla, NlaBuffer, NlasIterator},
+    parsers::{parse_i32, parse_u32, parse_u64, parse_u8},
+    DecodeError, Emitable, Parseable,
+};
+
+use crate::{Nl80211PmsrFtmFailureReason, Nl80211RateInfo};
+
+const NL80211_PMSR_FTM_RESP_ATTR_FAIL_REASON: u16 = 1;
+const NL80211_PMSR_FTM_RESP_ATTR_BURST_INDEX: u16 = 2;
+const NL80211_PMSR_FTM_RESP_ATTR_NUM_FTMR_ATTEMPTS: u16 = 3;
+const NL80211_PMSR_FTM_RESP_ATTR_NUM_FTMR_SUCCESSES: u16 = 4;
+const NL80211_PMSR_FTM_RESP_ATTR_BUSY_RETRY_TIME: u16 = 5;
+const NL80211_PMSR_FTM_RESP_ATTR_NUM_BURSTS_EXP: u16 = 6;
+const NL80211_PMSR_FTM_RESP_ATTR_BURST_DURATION: u16 = 7;
+const NL80211_PMSR_FTM_RESP_ATTR_FTMS_PER_BURST: u16 = 8;
+const NL80211_PMSR_FTM_RESP_ATTR_RSSI_AVG: u16 = 9;
+const NL80211_PMSR_FTM_RESP_ATTR_RSSI_SPREAD: u16 = 10;
+const NL80211_PMSR_FTM_RESP_ATTR_TX_RATE: u16 = 11;
+const NL80211_PMSR_FTM_RESP_ATTR_RX_RATE: u16 = 12;
+const NL80211_PMSR_FTM_RESP_ATTR_RTT_AVG: u16 = 13;
+const NL80211_PMSR_FTM_RESP_ATTR_RTT_VARIANCE: u16 = 14;
+const NL80211_PMSR_FTM_RESP_ATTR_RTT_SPREAD: u16 = 15;
+const NL80211_PMSR_FTM_RESP_ATTR_DIST_AVG: u16 = 16;
+const NL80211_PMSR_FTM_RESP_ATTR_DIST_VARIANCE: u16 = 17;
+const NL80211_PMSR_FTM_RESP_ATTR_DIST_SPREAD: u16 = 18;
+const NL80211_PMSR_FTM_RESP_ATTR_LCI: u16 = 19;
+const NL80211_PMSR_FTM_RESP_ATTR_CIVICLOC: u16 = 20;
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum Nl80211PmsrFtmResponseNla {
+    FailReason(Nl80211PmsrFtmFailureReason),
+    /// Index of the burst when bursts are reported as separate results
+    BurstIndex(u32),
+    /// Number of FTM Request frames transmitted
+    NumFtmrAttempts(u32),
+    /// Number of FTM Request frames acknowledged
+    NumFtmrSuccesses(u32),
+    /// Retry time in seconds received from the busy peer
+    BusyRetryTime(u32),
+    /// Number of bursts exponent used by the responder
+    NumBurstsExp(u8),
+    /// Burst duration used by the responder
+    BurstDuration(u8),
+    /// Number of FTMs per burst used by the responder
+    FtmsPerBurst(u8),
+    /// Average RSSI across all FTM frames in 0.5 dBm
+    RssiAvg(i32),
+    /// RSSI spread across all FTM frames in 0.5 dBm
+    RssiSpread(i32),
+    /// Bitrate used for the response to the FTM frame
+    TxRate(Nl80211RateInfo),
+    /// Bitrate used by the responder for the FTM frame
+    RxRate(Nl80211RateInfo),
+    /// Average round trip time in picoseconds
+    RttAvg(i64),
+    /// Round trip time variance in square picoseconds
+    RttVariance(u64),
+    /// Round trip time spread in picoseconds
+    RttSpread(u64),
+    /// Average distance in millimeters
+    DistAvg(i64),
+    /// Distance variance in square millimeters
+    DistVariance(u64),
+    /// Distance spread in millimeters
+    DistSpread(u64),
+    /// Raw Measurement Report element content with LCI data of the peer,
+    /// starting with the Measurement Token
+    Lci(Vec<u8>),
+    /// Raw Measurement Report element content with civic location of the
+    /// peer, starting with the Measurement Token
+    Civicloc(Vec<u8>),
+    Other(DefaultNla),
+}
+
+impl Nla for Nl80211PmsrFtmResponseNla {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::NumBurstsExp(_)
+            | Self::BurstDuration(_)
+            | Self::FtmsPerBurst(_) => 1,
+            Self::FailReason(_)
+            | Self::BurstIndex(_)
+            | Self::NumFtmrAttempts(_)
+            | Self::NumFtmrSuccesses(_)
+            | Self::BusyRetryTime(_)
+            | Self::RssiAvg(_)
+            | Self::RssiSpread(_) => 4,
+            Self::RttAvg(_)
+            | Self::RttVariance(_)
+            | Self::RttSpread(_)
+            | Self::DistAvg(_)
+            | Self::DistVariance(_)
+            | Self::DistSpread(_) => 8,
+            Self::TxRate(ref d) => d.buffer_len(),
+            Self::RxRate(ref d) => d.buffer_len(),
+            Self::Lci(ref d) => d.len(),
+            Self::Civicloc(ref d) => d.len(),
+            Self::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::FailReason(_) => NL80211_PMSR_FTM_RESP_ATTR_FAIL_REASON,
+            Self::BurstIndex(_) => NL80211_PMSR_FTM_RESP_ATTR_BURST_INDEX,
+            Self::NumFtmrAttempts(_) => {
+                NL80211_PMSR_FTM_RESP_ATTR_NUM_FTMR_ATTEMPTS
+            }
+            Self::NumFtmrSuccesses(_) => {
+                NL80211_PMSR_FTM_RESP_ATTR_NUM_FTMR_SUCCESSES
+            }
+            Self::BusyRetryTime(_) => {
+                NL80211_PMSR_FTM_RESP_ATTR_BUSY_RETRY_TIME
+            }
+            Self::NumBurstsExp(_) => NL80211_PMSR_FTM_RESP_ATTR_NUM_BURSTS_EXP,
+            Self::BurstDuration(_) => NL80211_PMSR_FTM_RESP_ATTR_BURST_DURATION,
+            Self::FtmsPerBurst(_) => NL80211_PMSR_FTM_RESP_ATTR_FTMS_PER_BURST,
+            Self::RssiAvg(_) => NL80211_PMSR_FTM_RESP_ATTR_RSSI_AVG,
+            Self::RssiSpread(_) => NL80211_PMSR_FTM_RESP_ATTR_RSSI_SPREAD,
+            Self::TxRate(_) => NL80211_PMSR_FTM_RESP_ATTR_TX_RATE,
+            Self::RxRate(_) => NL80211_PMSR_FTM_RESP_ATTR_RX_RATE,
+            Self::RttAvg(_) => NL80211_PMSR_FTM_RESP_ATTR_RTT_AVG,
+            Self::RttVariance(_) => NL80211_PMSR_FTM_RESP_ATTR_RTT_VARIANCE,
+            Self::RttSpread(_) => NL80211_PMSR_FTM_RESP_ATTR_RTT_SPREAD,
+            Self::DistAvg(_) => NL80211_PMSR_FTM_RESP_ATTR_DIST_AVG,
+            Self::DistVariance(_) => NL80211_PMSR_FTM_RESP_ATTR_DIST_VARIANCE,
+            Self::DistSpread(_) => NL80211_PMSR_FTM_RESP_ATTR_DIST_SPREAD,
+            Self::Lci(_) => NL80211_PMSR_FTM_RESP_ATTR_LCI,
+            Self::Civicloc(_) => NL80211_PMSR_FTM_RESP_ATTR_CIVICLOC,
+            Self::Other(attr) => attr.kind(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::NumBurstsExp(d)
+            | Self::BurstDuration(d)
+            | Self::FtmsPerBurst(d) => buffer[0] = *d,
+            Self::BurstIndex(d)
+            | Self::NumFtmrAttempts(d)
+            | Self::NumFtmrSuccesses(d)
+            | Self::BusyRetryTime(d) => NativeEndian::write_u32(buffer, *d),
+            Self::RssiAvg(d) | Self::RssiSpread(d) => {
+                NativeEndian::write_i32(buffer, *d)
+            }
+            Self::RttVariance(d)
+            | Self::RttSpread(d)
+            | Self::DistVariance(d)
+            | Self::DistSpread(d) => NativeEndian::write_u64(buffer, *d),
+            Self::RttAvg(d) | Self::DistAvg(d) => {
+                NativeEndian::write_i64(buffer, *d)
+            }
+            Self::FailReason(d) => NativeEndian::write_u32(buffer, (*d).into()),
+            Self::TxRate(ref d) => d.emit(buffer),
+            Self::RxRate(ref d) => d.emit(buffer),
+            Self::Lci(ref d) => buffer[..d.len()].copy_from_slice(d),
+            Self::Civicloc(ref d) => buffer[..d.len()].copy_from_slice(d),
+            Self::Other(ref attr) => attr.emit(buffer),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for Nl80211PmsrFtmResponseNla
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            NL80211_PMSR_FTM_RESP_ATTR_FAIL_REASON => {
+                let err_msg = format!(
+                    "Invalid NL80211_PMSR_FTM_RESP_ATTR_FAIL_REASON \
+                     value {:?}",
+                    payload
+                );
+                Self::FailReason(parse_u32(payload).context(err_msg)?.into())
+            }
+            NL80211_PMSR_FTM_RESP_ATTR_BURST_INDEX => {
+                let err_msg = format!(
+                    "Invalid NL80211_PMSR_FTM_RESP_ATTR_BURST_INDEX \
+                     value {:?}",
+                    payload
+                );
+                Self::BurstIndex(parse_u32(payload).context(err_msg)?)
+            }
+            NL80211_PMSR_FTM_RESP_ATTR_NUM_FTMR_ATTEMPTS => {
+                let err_msg = format!(
+                    "Invalid NL80211_PMSR_FTM_RESP_ATTR_NUM_FTMR_ATTEMPTS \
+                     value {:?}",
+                    payload
+                );
+                Self::NumFtmrAttempts(parse_u32(payload).context(err_msg)?)
+            }
+            NL80211_PMSR_FTM_RESP_ATTR_NUM_FTMR_SUCCESSES => {
+                let err_msg = format!(
+                    "Invalid NL80211_PMSR_FTM_RESP_ATTR_NUM_FTMR_SUCCESSES \
+                     value {:?}",
+                    payload
+                );
+                Self::NumFtmrSuccesses(parse_u32(payload).context(err_msg)?)
+            }
+            NL80211_PMSR_FTM_RESP_ATTR_BUSY_RETRY_TIME => {
+                let err_msg = format!(
+                    "Invalid NL80211_PMSR_FTM_RESP_ATTR_BUSY_RETRY_TIME \
+                     value {:?}",
+                    payload
+                );
+                Self::BusyRetryTime(parse_u32(payload).context(err_msg)?)
+            }
+            NL80211_PMSR_FTM_RESP_ATTR_NUM_BURSTS_EXP => {
+                let err_msg = format!(
+                    "Invalid NL80211_PMSR_FTM_RESP_ATTR_NUM_BURSTS_EXP \
+                     value {:?}",
+                    payload
+                );
+                Self::NumBurstsExp(parse_u8(payload).context(err_msg)?)
+            }
+            NL80211_PMSR_FTM_RESP_ATTR_BURST_DURATION => {
+                let err_msg = format!(
+                    "Invalid NL80211_PMSR_FTM_RESP_ATTR_BURST_DURATION \
+                     value {:?}",
+                    payload
+                );
+                Self::BurstDuration(parse_u8(payload).context(err_msg)?)
+            }
+            NL80211_PMSR_FTM_RESP_ATTR_FTMS_PER_BURST => {
+                let err_msg = format!(
+                    "Invalid NL80211_PMSR_FTM_RESP_ATTR_FTMS_PER_BURST \
+                     value {:?}",
+                    payload
+                );
+                Self::FtmsPerBurst(parse_u8(payload).context(err_msg)?)
+            }
+            NL80211_PMSR_FTM_RESP_ATTR_RSSI_AVG => {
+                let err_msg = format!(
+                    "Invalid NL80211_PMSR_FTM_RESP_ATTR_RSSI_AVG \
+                     value {:?}",
+                    payload
+                );
+                Self::RssiAvg(parse_i32(payload).context(err_msg)?)
+            }
+            NL80211_PMSR_FTM_RESP_ATTR_RSSI_SPREAD => {
+                let err_msg = format!(
+                    "Invalid NL80211_PMSR_FTM_RESP_ATTR_RSSI_SPREAD \
+                     value {:?}",
+                    payload
+                );
+                Self::RssiSpread(parse_i32(payload).context(err_msg)?)
+            }
+            NL80211_PMSR_FTM_RESP_ATTR_TX_RATE => {
+                Self::TxRate(Nl80211RateInfo::parse(buf)?)
+            }
+            NL80211_PMSR_FTM_RESP_ATTR_RX_RATE => {
+                Self::RxRate(Nl80211RateInfo::parse(buf)?)
+            }
+            NL80211_PMSR_FTM_RESP_ATTR_RTT_AVG => {
+                let err_msg = format!(
+                    "Invalid NL80211_PMSR_FTM_RESP_ATTR_RTT_AVG \
+                     value {:?}",
+                    payload
+                );
+                Self::RttAvg(parse_u64(payload).context(err_msg)? as i64)
+            }
+            NL80211_PMSR_FTM_RESP_ATTR_RTT_VARIANCE => {
+                let err_msg = format!(
+                    "Invalid NL80211_PMSR_FTM_RESP_ATTR_RTT_VARIANCE \
+                     value {:?}",
+                    payload
+                );
+                Self::RttVariance(parse_u64(payload).context(err_msg)?)
+            }
+            NL80211_PMSR_FTM_RESP_ATTR_RTT_SPREAD => {
+                let err_msg = format!(
+                    "Invalid NL80211_PMSR_FTM_RESP_ATTR_RTT_SPREAD \
+                     value {:?}",
+                    payload
+                );
+                Self::RttSpread(parse_u64(payload).context(err_msg)?)
+            }
+            NL80211_PMSR_FTM_RESP_ATTR_DIST_AVG => {
+                let err_msg = format!(
+                    "Invalid NL80211_PMSR_FTM_RESP_ATTR_DIST_AVG \
+                     value {:?}",
+                    payload
+                );
+                Self::DistAvg(parse_u64(payload).context(err_msg)? as i64)
+            }
+            NL80211_PMSR_FTM_RESP_ATTR_DIST_VARIANCE => {
+                let err_msg = format!(
+                    "Invalid NL80211_PMSR_FTM_RESP_ATTR_DIST_VARIANCE \
+                     value {:?}",
+                    payload
+                );
+                Self::DistVariance(parse_u64(payload).context(err_msg)?)
+            }
+            NL80211_PMSR_FTM_RESP_ATTR_DIST_SPREAD => {
+                let err_msg = format!(
+                    "Invalid NL80211_PMSR_FTM_RESP_ATTR_DIST_SPREAD \
+                     value {:?}",
+                    payload
+                );
+                Self::DistSpread(parse_u64(payload).context(err_msg)?)
+            }
+            NL80211_PMSR_FTM_RESP_ATTR_LCI => Self::Lci(payload.to_vec()),
+            NL80211_PMSR_FTM_RESP_ATTR_CIVICLOC => {
+                Self::Civicloc(payload.to_vec())
+            }
+            _ => Self::Other(
+                DefaultNla::parse(buf).context("invalid NLA (unknown kind)")?,
+            ),
+        })
+    }
+}
+
+/// Fine timing measurement result of a peer
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct Nl80211PmsrFtmResponse {
+    pub fail_reason: Option<Nl80211PmsrFtmFailureReason>,
+    /// Index of the burst when bursts are reported as separate results
+    pub burst_index: Option<u32>,
+    /// Number of FTM Request frames transmitted
+    pub num_ftmr_attempts: Option<u32>,
+    /// Number of FTM Request frames acknowledged
+    pub num_ftmr_successes: Option<u32>,
+    /// Retry time in seconds received from the busy peer
+    pub busy_retry_time: Option<u32>,
+    /// Number of bursts exponent used by the responder
+    pub num_bursts_exp: Option<u8>,
+    /// Burst duration used by the responder
+    pub burst_duration: Option<u8>,
+    /// Number of FTMs per burst used by the responder
+    pub ftms_per_burst: Option<u8>,
+    /// Average RSSI across all FTM frames in 0.5 dBm
+    pub rssi_avg: Option<i32>,
+    /// RSSI spread across all FTM frames in 0.5 dBm
+    pub rssi_spread: Option<i32>,
+    /// Bitrate used for the response to the FTM frame
+    pub tx_rate: Option<Nl80211RateInfo>,
+    /// Bitrate used by the responder for the FTM frame
+    pub rx_rate: Option<Nl80211RateInfo>,
+    /// Average round trip time in picoseconds
+    pub rtt_avg: Option<i64>,
+    /// Round trip time variance in square picoseconds
+    pub rtt_variance: Option<u64>,
+    /// Round trip time spread in picoseconds
+    pub rtt_spread: Option<u64>,
+    /// Average distance in millimeters
+    pub dist_avg: Option<i64>,
+    /// Distance variance in square millimeters
+    pub dist_variance: Option<u64>,
+    /// Distance spread in millimeters
+    pub dist_spread: Option<u64>,
+    /// Raw Measurement Report element content with LCI data of the peer,
+    /// starting with the Measurement Token
+    pub lci: Option<Vec<u8>>,
+    /// Raw Measurement Report element content with civic location of the
+    /// peer, starting with the Measurement Token
+    pub civicloc: Option<Vec<u8>>,
+}
+
+impl Emitable for Nl80211PmsrFtmResponse {
+    fn buffer_len(&self) -> usize {
+        Vec::<Nl80211PmsrFtmResponseNla>::from(self)
+            .as_slice()
+            .buffer_len()
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        Vec::<Nl80211PmsrFtmResponseNla>::from(self)
+            .as_slice()
+            .emit(buffer)
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for Nl80211PmsrFtmResponse
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let mut ret = Self::default();
+        let payload = buf.value();
+        let err_msg = format!(
+            "Invalid NL80211_PMSR_RESP_ATTR_DATA \
+             value {:?}",
+            payload
+        );
+        for nla in NlasIterator::new(payload) {
+            let nla = &nla.context(err_msg.clone())?;
+            match Nl80211PmsrFtmResponseNla::parse(nla)
+                .context(err_msg.clone())?
+            {
+                Nl80211PmsrFtmResponseNla::FailReason(d) => {
+                    ret.fail_reason = Some(d)
+                }
+                Nl80211PmsrFtmResponseNla::BurstIndex(d) => {
+                    ret.burst_index = Some(d)
+                }
+                Nl80211PmsrFtmResponseNla::NumFtmrAttempts(d) => {
+                    ret.num_ftmr_attempts = Some(d)
+                }
+                Nl80211PmsrFtmResponseNla::NumFtmrSuccesses(d) => {
+                    ret.num_ftmr_successes = Some(d)
+                }
+                Nl80211PmsrFtmResponseNla::BusyRetryTime(d) => {
+                    ret.busy_retry_time = Some(d)
+                }
+                Nl80211PmsrFtmResponseNla::NumBurstsExp(d) => {
+                    ret.num_bursts_exp = Some(d)
+                }
+                Nl80211PmsrFtmResponseNla::BurstDuration(d) => {
+                    ret.burst_duration = Some(d)
+                }
+                Nl80211PmsrFtmResponseNla::FtmsPerBurst(d) => {
+                    ret.ftms_per_burst = Some(d)
+                }
+                Nl80211PmsrFtmResponseNla::RssiAvg(d) => ret.rssi_avg = Some(d),
+                Nl80211PmsrFtmResponseNla::RssiSpread(d) => {
+                    ret.rssi_spread = Some(d)
+                }
+                Nl80211PmsrFtmResponseNla::TxRate(d) => ret.tx_rate = Some(d),
+                Nl80211PmsrFtmResponseNla::RxRate(d) => ret.rx_rate = Some(d),
+                Nl80211PmsrFtmResponseNla::RttAvg(d) => ret.rtt_avg = Some(d),
+                Nl80211PmsrFtmResponseNla::RttVariance(d) => {
+                    ret.rtt_variance = Some(d)
+                }
+                Nl80211PmsrFtmResponseNla::RttSpread(d) => {
+                    ret.rtt_spread = Some(d)
+                }
+                Nl80211PmsrFtmResponseNla::DistAvg(d) => ret.dist_avg = Some(d),
+                Nl80211PmsrFtmResponseNla::DistVariance(d) => {
+                    ret.dist_variance = Some(d)
+                }
+                Nl80211PmsrFtmResponseNla::DistSpread(d) => {
+                    ret.dist_spread = Some(d)
+                }
+                Nl80211PmsrFtmResponseNla::Lci(d) => ret.lci = Some(d),
+                Nl80211PmsrFtmResponseNla::Civicloc(d) => {
+                    ret.civicloc = Some(d)
+                }
+                Nl80211PmsrFtmResponseNla::Other(attr) => {
+                    log::warn!(
+                        "Got unsupported NL80211_PMSR_RESP_ATTR_DATA \
+                         value {:?}",
+                        attr
+                    )
+                }
+            }
+        }
+        Ok(ret)
+    }
+}
+
+impl From<&Nl80211PmsrFtmResponse> for Vec<Nl80211PmsrFtmResponseNla> {
+    fn from(resp: &Nl80211PmsrFtmResponse) -> Self {
+        let mut nlas = Vec::new();
+        if let Some(v) = resp.fail_reason {
+            nlas.push(Nl80211PmsrFtmResponseNla::FailReason(v));
+        }
+        if let Some(v) = resp.burst_index {
+            nlas.push(Nl80211PmsrFtmResponseNla::BurstIndex(v));
+        }
+        if let Some(v) = resp.num_ftmr_attempts {
+            nlas.push(Nl80211PmsrFtmResponseNla::NumFtmrAttempts(v));
+        }
+        if let Some(v) = resp.num_ftmr_successes {
+            nlas.push(Nl80211PmsrFtmResponseNla::NumFtmrSuccesses(v));
+        }
+        if let Some(v) = resp.busy_retry_time {
+            nlas.push(Nl80211PmsrFtmResponseNla::BusyRetryTime(v));
+        }
+        if let Some(v) = resp.num_bursts_exp {
+            nlas.push(Nl80211PmsrFtmResponseNla::NumBurstsExp(v));
+        }
+        if let Some(v) = resp.burst_duration {
+            nlas.push(Nl80211PmsrFtmResponseNla::BurstDuration(v));
+        }
+        if let Some(v) = resp.ftms_per_burst {
+            nlas.push(Nl80211PmsrFtmResponseNla::FtmsPerBurst(v));
+        }
+        if let Some(v) = resp.rssi_avg {
+            nlas.push(Nl80211PmsrFtmResponseNla::RssiAvg(v));
+        }
+        if let Some(v) = resp.rssi_spread {
+            nlas.push(Nl80211PmsrFtmResponseNla::RssiSpread(v));
+        }
+        if let Some(v) = resp.tx_rate.as_ref() {
+            nlas.push(Nl80211PmsrFtmResponseNla::TxRate(v.clone()));
+        }
+        if let Some(v) = resp.rx_rate.as_ref() {
+            nlas.push(Nl80211PmsrFtmResponseNla::RxRate(v.clone()));
+        }
+        if let Some(v) = resp.rtt_avg {
+            nlas.push(Nl80211PmsrFtmResponseNla::RttAvg(v));
+        }
+        if let Some(v) = resp.rtt_variance {
+            nlas.push(Nl80211PmsrFtmResponseNla::RttVariance(v));
+        }
+        if let Some(v) = resp.rtt_spread {
+            nlas.push(Nl80211PmsrFtmResponseNla::RttSpread(v));
+        }
+        if let Some(v) = resp.dist_avg {
+            nlas.push(Nl80211PmsrFtmResponseNla::DistAvg(v));
+        }
+        if let Some(v) = resp.dist_variance {
+            nlas.push(Nl80211PmsrFtmResponseNla::DistVariance(v));
+        }
+        if let Some(v) = resp.dist_spread {
+            nlas.push(Nl80211PmsrFtmResponseNla::DistSpread(v));
+        }
+        if let Some(v) = resp.lci.as_ref() {
+            nlas.push(Nl80211PmsrFtmResponseNla::Lci(v.clone()));
+        }
+        if let Some(v) = resp.civicloc.as_ref() {
+            nlas.push(Nl80211PmsrFtmResponseNla::Civicloc(v.clone()));
+        }
+        nlas
+    }
+}
@@ -0,0 +1,23 @@
+// SPDX-License-Identifier: MIT
+
+use crate::{Nl80211Handle, Nl80211PeerMeasurementStartRequest};
+
+pub struct Nl80211PeerMeasurementHandle(Nl80211Handle);
+
+impl Nl80211PeerMeasurementHandle {
+    pub fn new(handle: Nl80211Handle) -> Self {
+        Nl80211PeerMeasurementHandle(handle)
+    }
+
+    /// Start measurement with peers on specified interface, the results are
+    /// sent to the socket of the request in
+    /// `Nl80211Cmd::PeerMeasurementResult` messages followed by a
+    /// `Nl80211Cmd::PeerMeasurementComplete` message.
+    /// (equivalent to `iw dev DEVICE measurement ftm_request ...`)
+    pub fn start(
+        &mut self,
+        if_index: u32,
+    ) -> Nl80211PeerMeasurementStartRequest {
+        Nl80211PeerMeasurementStartRequest::new(self.0.clone(), if_index)
+    }
+}
@@ -0,0 +1,36 @@
+// SPDX-License-Identifier: MIT
+
+mod ftm_capa;
+mod ftm_failure;
+mod ftm_req;
+mod ftm_resp;
+mod handle;
+mod peer;
+mod pmsr_attr;
+mod preamble;
+mod request;
+mod response;
+mod start;
+mod status;
+
+pub use ftm_capa::{Nl80211PmsrFtmCapa, Nl80211PmsrFtmCapaNla};
+pub use ftm_failure::Nl80211PmsrFtmFailureReason;
+pub use ftm_req::{Nl80211PmsrFtmRequest, Nl80211PmsrFtmRequestNla};
+pub use ftm_resp::{Nl80211PmsrFtmResponse, Nl80211PmsrFtmResponseNla};
+pub use handle::Nl80211PeerMeasurementHandle;
+pub use peer::{Nl80211PmsrPeer, Nl80211PmsrPeerNla};
+pub use pmsr_attr::{
+    Nl80211PeerMeasurement, Nl80211PeerMeasurementNla, Nl80211PmsrTypeCapa,
+    Nl80211PmsrTypeCapaNla,
+};
+pub use preamble::Nl80211Preamble;
+pub use request::{
+    Nl80211PmsrRequest, Nl80211PmsrRequestData, Nl80211PmsrRequestDataNla,
+    Nl80211PmsrRequestNla,
+};
+pub use response::{
+    Nl80211PmsrResponse, Nl80211PmsrResponseData, Nl80211PmsrResponseDataNla,
+    Nl80211PmsrResponseNla,
+};
+pub use start::Nl80211PeerMeasurementStartRequest;
+pub use status::Nl80211PmsrStatus;
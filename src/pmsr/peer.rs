@@ -0,0 +1,184 @@
+// SPDX-License-Identifier: MIT
+
+use anyhow::Context;
+use netlink_packet_utils::{
+    nla::{DefaultNla, Nla, NlaBuffer, NlasIterator},
+    parsers::parse_mac,
+    DecodeError, Emitable, Parseable,
+};
+
+use crate::{
+    Nl80211ChannelDef, Nl80211PmsrFtmRequest, Nl80211PmsrRequest,
+    Nl80211PmsrRequestData, Nl80211PmsrResponse,
+};
+
+const ETH_ALEN: usize = 6;
+
+const NL80211_PMSR_PEER_ATTR_ADDR: u16 = 1;
+const NL80211_PMSR_PEER_ATTR_CHAN: u16 = 2;
+const NL80211_PMSR_PEER_ATTR_REQ: u16 = 3;
+const NL80211_PMSR_PEER_ATTR_RESP: u16 = 4;
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum Nl80211PmsrPeerNla {
+    Addr([u8; ETH_ALEN]),
+    /// Channel of the peer
+    Chan(Box<Nl80211ChannelDef>),
+    Req(Nl80211PmsrRequest),
+    Resp(Box<Nl80211PmsrResponse>),
+    Other(DefaultNla),
+}
+
+impl Nla for Nl80211PmsrPeerNla {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::Addr(_) => ETH_ALEN,
+            Self::Chan(ref d) => d.buffer_len(),
+            Self::Req(ref d) => d.buffer_len(),
+            Self::Resp(ref d) => d.buffer_len(),
+            Self::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::Addr(_) => NL80211_PMSR_PEER_ATTR_ADDR,
+            Self::Chan(_) => NL80211_PMSR_PEER_ATTR_CHAN,
+            Self::Req(_) => NL80211_PMSR_PEER_ATTR_REQ,
+            Self::Resp(_) => NL80211_PMSR_PEER_ATTR_RESP,
+            Self::Other(attr) => attr.kind(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Addr(ref d) => buffer[..ETH_ALEN].copy_from_slice(d),
+            Self::Chan(ref d) => d.emit(buffer),
+            Self::Req(ref d) => d.emit(buffer),
+            Self::Resp(ref d) => d.emit(buffer),
+            Self::Other(ref attr) => attr.emit(buffer),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for Nl80211PmsrPeerNla
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            NL80211_PMSR_PEER_ATTR_ADDR => {
+                let err_msg = format!(
+                    "Invalid NL80211_PMSR_PEER_ATTR_ADDR \
+                     value {:?}",
+                    payload
+                );
+                Self::Addr(parse_mac(payload).context(err_msg)?)
+            }
+            NL80211_PMSR_PEER_ATTR_CHAN => {
+                Self::Chan(Box::new(Nl80211ChannelDef::parse(buf)?))
+            }
+            NL80211_PMSR_PEER_ATTR_REQ => {
+                Self::Req(Nl80211PmsrRequest::parse(buf)?)
+            }
+            NL80211_PMSR_PEER_ATTR_RESP => {
+                Self::Resp(Box::new(Nl80211PmsrResponse::parse(buf)?))
+            }
+            _ => Self::Other(
+                DefaultNla::parse(buf).context("invalid NLA (unknown kind)")?,
+            ),
+        })
+    }
+}
+
+/// Peer of a measurement request or result
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct Nl80211PmsrPeer {
+    pub addr: Option<[u8; ETH_ALEN]>,
+    /// Channel of the peer
+    pub chan: Option<Nl80211ChannelDef>,
+    pub req: Option<Nl80211PmsrRequest>,
+    pub resp: Option<Nl80211PmsrResponse>,
+}
+
+impl Nl80211PmsrPeer {
+    /// Fine timing measurement with the peer on specified channel
+    pub fn ftm(
+        addr: [u8; ETH_ALEN],
+        chan: Nl80211ChannelDef,
+        ftm: Nl80211PmsrFtmRequest,
+    ) -> Self {
+        Self {
+            addr: Some(addr),
+            chan: Some(chan),
+            req: Some(Nl80211PmsrRequest {
+                data: Some(Nl80211PmsrRequestData { ftm: Some(ftm) }),
+                get_ap_tsf: false,
+            }),
+            resp: None,
+        }
+    }
+}
+
+impl Emitable for Nl80211PmsrPeer {
+    fn buffer_len(&self) -> usize {
+        Vec::<Nl80211PmsrPeerNla>::from(self)
+            .as_slice()
+            .buffer_len()
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        Vec::<Nl80211PmsrPeerNla>::from(self)
+            .as_slice()
+            .emit(buffer)
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for Nl80211PmsrPeer
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let mut ret = Self::default();
+        let payload = buf.value();
+        let err_msg = format!(
+            "Invalid NL80211_PMSR_ATTR_PEERS \
+             value {:?}",
+            payload
+        );
+        for nla in NlasIterator::new(payload) {
+            let nla = &nla.context(err_msg.clone())?;
+            match Nl80211PmsrPeerNla::parse(nla).context(err_msg.clone())? {
+                Nl80211PmsrPeerNla::Addr(d) => ret.addr = Some(d),
+                Nl80211PmsrPeerNla::Chan(d) => ret.chan = Some(*d),
+                Nl80211PmsrPeerNla::Req(d) => ret.req = Some(d),
+                Nl80211PmsrPeerNla::Resp(d) => ret.resp = Some(*d),
+                Nl80211PmsrPeerNla::Other(attr) => {
+                    log::warn!(
+                        "Got unsupported NL80211_PMSR_ATTR_PEERS value {:?}",
+                        attr
+                    )
+                }
+            }
+        }
+        Ok(ret)
+    }
+}
+
+impl From<&Nl80211PmsrPeer> for Vec<Nl80211PmsrPeerNla> {
+    fn from(peer: &Nl80211PmsrPeer) -> Self {
+        let mut nlas = Vec::new();
+        if let Some(v) = peer.addr {
+            nlas.push(Nl80211PmsrPeerNla::Addr(v));
+        }
+        if let Some(v) = peer.chan {
+            nlas.push(Nl80211PmsrPeerNla::Chan(Box::new(v)));
+        }
+        if let Some(v) = peer.req.as_ref() {
+            nlas.push(Nl80211PmsrPeerNla::Req(v.clone()));
+        }
+        if let Some(v) = peer.resp.as_ref() {
+            nlas.push(Nl80211PmsrPeerNla::Resp(Box::new(v.clone())));
+        }
+        nlas
+    }
+}
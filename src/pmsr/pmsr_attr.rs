@@ -0,0 +1,301 @@
+// SPDX-License-Identifier: MIT
+
+use anyhow::Context;
+use byteorder::{ByteOrder, NativeEndian};
+use netlink_packet_utils::{
+    nla::{DefaultNla, Nla, NlaBuffer, NlasIterator},
+    parsers::parse_u32,
+    DecodeError, Emitable, Parseable,
+};
+
+use crate::{nested::nested_array, Nl80211PmsrFtmCapa, Nl80211PmsrPeer};
+
+const NL80211_PMSR_TYPE_FTM: u16 = 1;
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum Nl80211PmsrTypeCapaNla {
+    /// Fine timing measurement
+    Ftm(Nl80211PmsrFtmCapa),
+    Other(DefaultNla),
+}
+
+impl Nla for Nl80211PmsrTypeCapaNla {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::Ftm(ref d) => d.buffer_len(),
+            Self::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::Ftm(_) => NL80211_PMSR_TYPE_FTM,
+            Self::Other(attr) => attr.kind(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Ftm(ref d) => d.emit(buffer),
+            Self::Other(ref attr) => attr.emit(buffer),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for Nl80211PmsrTypeCapaNla
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        Ok(match buf.kind() {
+            NL80211_PMSR_TYPE_FTM => Self::Ftm(Nl80211PmsrFtmCapa::parse(buf)?),
+            _ => Self::Other(
+                DefaultNla::parse(buf).context("invalid NLA (unknown kind)")?,
+            ),
+        })
+    }
+}
+
+/// Measurement type specific capabilities
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct Nl80211PmsrTypeCapa {
+    /// Fine timing measurement
+    pub ftm: Option<Nl80211PmsrFtmCapa>,
+}
+
+impl Emitable for Nl80211PmsrTypeCapa {
+    fn buffer_len(&self) -> usize {
+        Vec::<Nl80211PmsrTypeCapaNla>::from(self)
+            .as_slice()
+            .buffer_len()
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        Vec::<Nl80211PmsrTypeCapaNla>::from(self)
+            .as_slice()
+            .emit(buffer)
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for Nl80211PmsrTypeCapa
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let mut ret = Self::default();
+        let payload = buf.value();
+        let err_msg = format!(
+            "Invalid NL80211_PMSR_ATTR_TYPE_CAPA \
+             value {:?}",
+            payload
+        );
+        for nla in NlasIterator::new(payload) {
+            let nla = &nla.context(err_msg.clone())?;
+            match Nl80211PmsrTypeCapaNla::parse(nla).context(err_msg.clone())? {
+                Nl80211PmsrTypeCapaNla::Ftm(d) => ret.ftm = Some(d),
+                Nl80211PmsrTypeCapaNla::Other(attr) => {
+                    log::warn!(
+                        "Got unsupported NL80211_PMSR_ATTR_TYPE_CAPA \
+                         value {:?}",
+                        attr
+                    )
+                }
+            }
+        }
+        Ok(ret)
+    }
+}
+
+impl From<&Nl80211PmsrTypeCapa> for Vec<Nl80211PmsrTypeCapaNla> {
+    fn from(capa: &Nl80211PmsrTypeCapa) -> Self {
+        let mut nlas = Vec::new();
+        if let Some(v) = capa.ftm.as_ref() {
+            nlas.push(Nl80211PmsrTypeCapaNla::Ftm(v.clone()));
+        }
+        nlas
+    }
+}
+
+const NL80211_PMSR_ATTR_MAX_PEERS: u16 = 1;
+const NL80211_PMSR_ATTR_REPORT_AP_TSF: u16 = 2;
+const NL80211_PMSR_ATTR_RANDOMIZE_MAC_ADDR: u16 = 3;
+const NL80211_PMSR_ATTR_TYPE_CAPA: u16 = 4;
+const NL80211_PMSR_ATTR_PEERS: u16 = 5;
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum Nl80211PeerMeasurementNla {
+    /// Maximum number of peers in a single request, capability only
+    MaxPeers(u32),
+    /// TSF of the connected AP can be reported in results, capability only
+    ReportApTsf,
+    /// MAC address randomization is supported, capability only
+    RandomizeMacAddr,
+    /// Measurement type specific capabilities
+    TypeCapa(Nl80211PmsrTypeCapa),
+    Peers(Vec<Nl80211PmsrPeer>),
+    Other(DefaultNla),
+}
+
+impl Nla for Nl80211PeerMeasurementNla {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::ReportApTsf | Self::RandomizeMacAddr => 0,
+            Self::MaxPeers(_) => 4,
+            Self::TypeCapa(ref d) => d.buffer_len(),
+            Self::Peers(ref d) => nested_array(d).as_slice().buffer_len(),
+            Self::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::MaxPeers(_) => NL80211_PMSR_ATTR_MAX_PEERS,
+            Self::ReportApTsf => NL80211_PMSR_ATTR_REPORT_AP_TSF,
+            Self::RandomizeMacAddr => NL80211_PMSR_ATTR_RANDOMIZE_MAC_ADDR,
+            Self::TypeCapa(_) => NL80211_PMSR_ATTR_TYPE_CAPA,
+            Self::Peers(_) => NL80211_PMSR_ATTR_PEERS,
+            Self::Other(attr) => attr.kind(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::ReportApTsf | Self::RandomizeMacAddr => (),
+            Self::MaxPeers(d) => NativeEndian::write_u32(buffer, *d),
+            Self::TypeCapa(ref d) => d.emit(buffer),
+            Self::Peers(ref d) => nested_array(d).as_slice().emit(buffer),
+            Self::Other(ref attr) => attr.emit(buffer),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for Nl80211PeerMeasurementNla
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            NL80211_PMSR_ATTR_MAX_PEERS => {
+                let err_msg = format!(
+                    "Invalid NL80211_PMSR_ATTR_MAX_PEERS \
+                     value {:?}",
+                    payload
+                );
+                Self::MaxPeers(parse_u32(payload).context(err_msg)?)
+            }
+            NL80211_PMSR_ATTR_REPORT_AP_TSF => Self::ReportApTsf,
+            NL80211_PMSR_ATTR_RANDOMIZE_MAC_ADDR => Self::RandomizeMacAddr,
+            NL80211_PMSR_ATTR_TYPE_CAPA => {
+                Self::TypeCapa(Nl80211PmsrTypeCapa::parse(buf)?)
+            }
+            NL80211_PMSR_ATTR_PEERS => {
+                let err_msg = format!(
+                    "Invalid NL80211_PMSR_ATTR_PEERS \
+                     value {:?}",
+                    payload
+                );
+                let mut values = Vec::new();
+                for nla in NlasIterator::new(payload) {
+                    let nla = &nla.context(err_msg.clone())?;
+                    values.push(
+                        Nl80211PmsrPeer::parse(nla).context(err_msg.clone())?,
+                    );
+                }
+                Self::Peers(values)
+            }
+            _ => Self::Other(
+                DefaultNla::parse(buf).context("invalid NLA (unknown kind)")?,
+            ),
+        })
+    }
+}
+
+/// Peer measurement request, result or capabilities of the wiphy
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct Nl80211PeerMeasurement {
+    /// Maximum number of peers in a single request, capability only
+    pub max_peers: Option<u32>,
+    /// TSF of the connected AP can be reported in results, capability only
+    pub report_ap_tsf: bool,
+    /// MAC address randomization is supported, capability only
+    pub randomize_mac_addr: bool,
+    /// Measurement type specific capabilities
+    pub type_capa: Option<Nl80211PmsrTypeCapa>,
+    pub peers: Vec<Nl80211PmsrPeer>,
+}
+
+impl Emitable for Nl80211PeerMeasurement {
+    fn buffer_len(&self) -> usize {
+        Vec::<Nl80211PeerMeasurementNla>::from(self)
+            .as_slice()
+            .buffer_len()
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        Vec::<Nl80211PeerMeasurementNla>::from(self)
+            .as_slice()
+            .emit(buffer)
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for Nl80211PeerMeasurement
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let mut ret = Self::default();
+        let payload = buf.value();
+        let err_msg = format!(
+            "Invalid NL80211_ATTR_PEER_MEASUREMENTS \
+             value {:?}",
+            payload
+        );
+        for nla in NlasIterator::new(payload) {
+            let nla = &nla.context(err_msg.clone())?;
+            match Nl80211PeerMeasurementNla::parse(nla)
+                .context(err_msg.clone())?
+            {
+                Nl80211PeerMeasurementNla::MaxPeers(d) => {
+                    ret.max_peers = Some(d)
+                }
+                Nl80211PeerMeasurementNla::ReportApTsf => {
+                    ret.report_ap_tsf = true
+                }
+                Nl80211PeerMeasurementNla::RandomizeMacAddr => {
+                    ret.randomize_mac_addr = true
+                }
+                Nl80211PeerMeasurementNla::TypeCapa(d) => {
+                    ret.type_capa = Some(d)
+                }
+                Nl80211PeerMeasurementNla::Peers(v) => ret.peers = v,
+                Nl80211PeerMeasurementNla::Other(attr) => {
+                    log::warn!(
+                        "Got unsupported NL80211_ATTR_PEER_MEASUREMENTS \
+                         value {:?}",
+                        attr
+                    )
+                }
+            }
+        }
+        Ok(ret)
+    }
+}
+
+impl From<&Nl80211PeerMeasurement> for Vec<Nl80211PeerMeasurementNla> {
+    fn from(pmsr: &Nl80211PeerMeasurement) -> Self {
+        let mut nlas = Vec::new();
+        if let Some(v) = pmsr.max_peers {
+            nlas.push(Nl80211PeerMeasurementNla::MaxPeers(v));
+        }
+        if pmsr.report_ap_tsf {
+            nlas.push(Nl80211PeerMeasurementNla::ReportApTsf);
+        }
+        if pmsr.randomize_mac_addr {
+            nlas.push(Nl80211PeerMeasurementNla::RandomizeMacAddr);
+        }
+        if let Some(v) = pmsr.type_capa.as_ref() {
+            nlas.push(Nl80211PeerMeasurementNla::TypeCapa(v.clone()));
+        }
+        if !pmsr.peers.is_empty() {
+            nlas.push(Nl80211PeerMeasurementNla::Peers(pmsr.peers.clone()));
+        }
+        nlas
+    }
+}
@@ -0,0 +1,49 @@
+// SPDX-License-Identifier: MIT
+
+const NL80211_PREAMBLE_LEGACY: u32 = 0;
+const NL80211_PREAMBLE_HT: u32 = 1;
+const NL80211_PREAMBLE_VHT: u32 = 2;
+const NL80211_PREAMBLE_DMG: u32 = 3;
+const NL80211_PREAMBLE_HE: u32 = 4;
+
+/// Frame preamble type
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Nl80211Preamble {
+    /// Legacy (HR/DSSS, OFDM, ERP PHY) preamble
+    Legacy,
+    /// HT preamble
+    Ht,
+    /// VHT preamble
+    Vht,
+    /// DMG preamble
+    Dmg,
+    /// HE preamble
+    He,
+    Other(u32),
+}
+
+impl From<u32> for Nl80211Preamble {
+    fn from(d: u32) -> Self {
+        match d {
+            NL80211_PREAMBLE_LEGACY => Self::Legacy,
+            NL80211_PREAMBLE_HT => Self::Ht,
+            NL80211_PREAMBLE_VHT => Self::Vht,
+            NL80211_PREAMBLE_DMG => Self::Dmg,
+            NL80211_PREAMBLE_HE => Self::He,
+            _ => Self::Other(d),
+        }
+    }
+}
+
+impl From<Nl80211Preamble> for u32 {
+    fn from(v: Nl80211Preamble) -> u32 {
+        match v {
+            Nl80211Preamble::Legacy => NL80211_PREAMBLE_LEGACY,
+            Nl80211Preamble::Ht => NL80211_PREAMBLE_HT,
+            Nl80211Preamble::Vht => NL80211_PREAMBLE_VHT,
+            Nl80211Preamble::Dmg => NL80211_PREAMBLE_DMG,
+            Nl80211Preamble::He => NL80211_PREAMBLE_HE,
+            Nl80211Preamble::Other(d) => d,
+        }
+    }
+}
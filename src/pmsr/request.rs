@@ -0,0 +1,232 @@
+// SPDX-License-Identifier: MIT
+
+use anyhow::Context;
+use netlink_packet_utils::{
+    nla::{DefaultNla, Nla, NlaBuffer, NlasIterator},
+    DecodeError, Emitable, Parseable,
+};
+
+use crate::Nl80211PmsrFtmRequest;
+
+const NL80211_PMSR_TYPE_FTM: u16 = 1;
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum Nl80211PmsrRequestDataNla {
+    /// Fine timing measurement
+    Ftm(Nl80211PmsrFtmRequest),
+    Other(DefaultNla),
+}
+
+impl Nla for Nl80211PmsrRequestDataNla {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::Ftm(ref d) => d.buffer_len(),
+            Self::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::Ftm(_) => NL80211_PMSR_TYPE_FTM,
+            Self::Other(attr) => attr.kind(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Ftm(ref d) => d.emit(buffer),
+            Self::Other(ref attr) => attr.emit(buffer),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for Nl80211PmsrRequestDataNla
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        Ok(match buf.kind() {
+            NL80211_PMSR_TYPE_FTM => {
+                Self::Ftm(Nl80211PmsrFtmRequest::parse(buf)?)
+            }
+            _ => Self::Other(
+                DefaultNla::parse(buf).context("invalid NLA (unknown kind)")?,
+            ),
+        })
+    }
+}
+
+/// Measurement type specific request parameters
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct Nl80211PmsrRequestData {
+    /// Fine timing measurement
+    pub ftm: Option<Nl80211PmsrFtmRequest>,
+}
+
+impl Emitable for Nl80211PmsrRequestData {
+    fn buffer_len(&self) -> usize {
+        Vec::<Nl80211PmsrRequestDataNla>::from(self)
+            .as_slice()
+            .buffer_len()
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        Vec::<Nl80211PmsrRequestDataNla>::from(self)
+            .as_slice()
+            .emit(buffer)
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for Nl80211PmsrRequestData
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let mut ret = Self::default();
+        let payload = buf.value();
+        let err_msg = format!(
+            "Invalid NL80211_PMSR_REQ_ATTR_DATA \
+             value {:?}",
+            payload
+        );
+        for nla in NlasIterator::new(payload) {
+            let nla = &nla.context(err_msg.clone())?;
+            match Nl80211PmsrRequestDataNla::parse(nla)
+                .context(err_msg.clone())?
+            {
+                Nl80211PmsrRequestDataNla::Ftm(d) => ret.ftm = Some(d),
+                Nl80211PmsrRequestDataNla::Other(attr) => {
+                    log::warn!(
+                        "Got unsupported NL80211_PMSR_REQ_ATTR_DATA value {:?}",
+                        attr
+                    )
+                }
+            }
+        }
+        Ok(ret)
+    }
+}
+
+impl From<&Nl80211PmsrRequestData> for Vec<Nl80211PmsrRequestDataNla> {
+    fn from(data: &Nl80211PmsrRequestData) -> Self {
+        let mut nlas = Vec::new();
+        if let Some(v) = data.ftm.as_ref() {
+            nlas.push(Nl80211PmsrRequestDataNla::Ftm(v.clone()));
+        }
+        nlas
+    }
+}
+
+const NL80211_PMSR_REQ_ATTR_DATA: u16 = 1;
+const NL80211_PMSR_REQ_ATTR_GET_AP_TSF: u16 = 2;
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum Nl80211PmsrRequestNla {
+    Data(Nl80211PmsrRequestData),
+    /// Include TSF of the connected AP in the result
+    GetApTsf,
+    Other(DefaultNla),
+}
+
+impl Nla for Nl80211PmsrRequestNla {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::GetApTsf => 0,
+            Self::Data(ref d) => d.buffer_len(),
+            Self::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::Data(_) => NL80211_PMSR_REQ_ATTR_DATA,
+            Self::GetApTsf => NL80211_PMSR_REQ_ATTR_GET_AP_TSF,
+            Self::Other(attr) => attr.kind(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::GetApTsf => (),
+            Self::Data(ref d) => d.emit(buffer),
+            Self::Other(ref attr) => attr.emit(buffer),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for Nl80211PmsrRequestNla
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        Ok(match buf.kind() {
+            NL80211_PMSR_REQ_ATTR_DATA => {
+                Self::Data(Nl80211PmsrRequestData::parse(buf)?)
+            }
+            NL80211_PMSR_REQ_ATTR_GET_AP_TSF => Self::GetApTsf,
+            _ => Self::Other(
+                DefaultNla::parse(buf).context("invalid NLA (unknown kind)")?,
+            ),
+        })
+    }
+}
+
+/// Measurement request of a peer
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct Nl80211PmsrRequest {
+    pub data: Option<Nl80211PmsrRequestData>,
+    /// Include TSF of the connected AP in the result
+    pub get_ap_tsf: bool,
+}
+
+impl Emitable for Nl80211PmsrRequest {
+    fn buffer_len(&self) -> usize {
+        Vec::<Nl80211PmsrRequestNla>::from(self)
+            .as_slice()
+            .buffer_len()
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        Vec::<Nl80211PmsrRequestNla>::from(self)
+            .as_slice()
+            .emit(buffer)
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for Nl80211PmsrRequest
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let mut ret = Self::default();
+        let payload = buf.value();
+        let err_msg = format!(
+            "Invalid NL80211_PMSR_PEER_ATTR_REQ \
+             value {:?}",
+            payload
+        );
+        for nla in NlasIterator::new(payload) {
+            let nla = &nla.context(err_msg.clone())?;
+            match Nl80211PmsrRequestNla::parse(nla).context(err_msg.clone())? {
+                Nl80211PmsrRequestNla::Data(d) => ret.data = Some(d),
+                Nl80211PmsrRequestNla::GetApTsf => ret.get_ap_tsf = true,
+                Nl80211PmsrRequestNla::Other(attr) => {
+                    log::warn!(
+                        "Got unsupported NL80211_PMSR_PEER_ATTR_REQ value {:?}",
+                        attr
+                    )
+                }
+            }
+        }
+        Ok(ret)
+    }
+}
+
+impl From<&Nl80211PmsrRequest> for Vec<Nl80211PmsrRequestNla> {
+    fn from(req: &Nl80211PmsrRequest) -> Self {
+        let mut nlas = Vec::new();
+        if let Some(v) = req.data.as_ref() {
+            nlas.push(Nl80211PmsrRequestNla::Data(v.clone()));
+        }
+        if req.get_ap_tsf {
+            nlas.push(Nl80211PmsrRequestNla::GetApTsf);
+        }
+        nlas
+    }
+}
@@ -0,0 +1,295 @@
+// SPDX-License-Identifier: MIT
+
+use anyhow::Context;
+use byteorder::{ByteOrder, NativeEndian};
+use netlink_packet_utils::{
+    nla::{DefaultNla, Nla, NlaBuffer, NlasIterator},
+    parsers::{parse_u32, parse_u64},
+    DecodeError, Emitable, Parseable,
+};
+
+use crate::{Nl80211PmsrFtmResponse, Nl80211PmsrStatus};
+
+const NL80211_PMSR_TYPE_FTM: u16 = 1;
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum Nl80211PmsrResponseDataNla {
+    /// Fine timing measurement
+    Ftm(Box<Nl80211PmsrFtmResponse>),
+    Other(DefaultNla),
+}
+
+impl Nla for Nl80211PmsrResponseDataNla {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::Ftm(ref d) => d.buffer_len(),
+            Self::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::Ftm(_) => NL80211_PMSR_TYPE_FTM,
+            Self::Other(attr) => attr.kind(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Ftm(ref d) => d.emit(buffer),
+            Self::Other(ref attr) => attr.emit(buffer),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for Nl80211PmsrResponseDataNla
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        Ok(match buf.kind() {
+            NL80211_PMSR_TYPE_FTM => {
+                Self::Ftm(Box::new(Nl80211PmsrFtmResponse::parse(buf)?))
+            }
+            _ => Self::Other(
+                DefaultNla::parse(buf).context("invalid NLA (unknown kind)")?,
+            ),
+        })
+    }
+}
+
+/// Measurement type specific results
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct Nl80211PmsrResponseData {
+    /// Fine timing measurement
+    pub ftm: Option<Nl80211PmsrFtmResponse>,
+}
+
+impl Emitable for Nl80211PmsrResponseData {
+    fn buffer_len(&self) -> usize {
+        Vec::<Nl80211PmsrResponseDataNla>::from(self)
+            .as_slice()
+            .buffer_len()
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        Vec::<Nl80211PmsrResponseDataNla>::from(self)
+            .as_slice()
+            .emit(buffer)
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for Nl80211PmsrResponseData
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let mut ret = Self::default();
+        let payload = buf.value();
+        let err_msg = format!(
+            "Invalid NL80211_PMSR_RESP_ATTR_DATA \
+             value {:?}",
+            payload
+        );
+        for nla in NlasIterator::new(payload) {
+            let nla = &nla.context(err_msg.clone())?;
+            match Nl80211PmsrResponseDataNla::parse(nla)
+                .context(err_msg.clone())?
+            {
+                Nl80211PmsrResponseDataNla::Ftm(d) => ret.ftm = Some(*d),
+                Nl80211PmsrResponseDataNla::Other(attr) => {
+                    log::warn!(
+                        "Got unsupported NL80211_PMSR_RESP_ATTR_DATA \
+                         value {:?}",
+                        attr
+                    )
+                }
+            }
+        }
+        Ok(ret)
+    }
+}
+
+impl From<&Nl80211PmsrResponseData> for Vec<Nl80211PmsrResponseDataNla> {
+    fn from(data: &Nl80211PmsrResponseData) -> Self {
+        let mut nlas = Vec::new();
+        if let Some(v) = data.ftm.as_ref() {
+            nlas.push(Nl80211PmsrResponseDataNla::Ftm(Box::new(v.clone())));
+        }
+        nlas
+    }
+}
+
+const NL80211_PMSR_RESP_ATTR_DATA: u16 = 1;
+const NL80211_PMSR_RESP_ATTR_STATUS: u16 = 2;
+const NL80211_PMSR_RESP_ATTR_HOST_TIME: u16 = 3;
+const NL80211_PMSR_RESP_ATTR_AP_TSF: u16 = 4;
+const NL80211_PMSR_RESP_ATTR_FINAL: u16 = 5;
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum Nl80211PmsrResponseNla {
+    Data(Box<Nl80211PmsrResponseData>),
+    Status(Nl80211PmsrStatus),
+    /// `CLOCK_BOOTTIME` in nanoseconds when the result was measured
+    HostTime(u64),
+    /// TSF of the connected AP in microseconds when the result was measured
+    ApTsf(u64),
+    /// Last result of the peer, partial results are not final
+    Final,
+    Other(DefaultNla),
+}
+
+impl Nla for Nl80211PmsrResponseNla {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::Final => 0,
+            Self::Status(_) => 4,
+            Self::HostTime(_) | Self::ApTsf(_) => 8,
+            Self::Data(ref d) => d.buffer_len(),
+            Self::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::Data(_) => NL80211_PMSR_RESP_ATTR_DATA,
+            Self::Status(_) => NL80211_PMSR_RESP_ATTR_STATUS,
+            Self::HostTime(_) => NL80211_PMSR_RESP_ATTR_HOST_TIME,
+            Self::ApTsf(_) => NL80211_PMSR_RESP_ATTR_AP_TSF,
+            Self::Final => NL80211_PMSR_RESP_ATTR_FINAL,
+            Self::Other(attr) => attr.kind(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Final => (),
+            Self::HostTime(d) | Self::ApTsf(d) => {
+                NativeEndian::write_u64(buffer, *d)
+            }
+            Self::Status(d) => NativeEndian::write_u32(buffer, (*d).into()),
+            Self::Data(ref d) => d.emit(buffer),
+            Self::Other(ref attr) => attr.emit(buffer),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for Nl80211PmsrResponseNla
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            NL80211_PMSR_RESP_ATTR_DATA => {
+                Self::Data(Box::new(Nl80211PmsrResponseData::parse(buf)?))
+            }
+            NL80211_PMSR_RESP_ATTR_STATUS => {
+                let err_msg = format!(
+                    "Invalid NL80211_PMSR_RESP_ATTR_STATUS \
+                     value {:?}",
+                    payload
+                );
+                Self::Status(parse_u32(payload).context(err_msg)?.into())
+            }
+            NL80211_PMSR_RESP_ATTR_HOST_TIME => {
+                let err_msg = format!(
+                    "Invalid NL80211_PMSR_RESP_ATTR_HOST_TIME \
+                     value {:?}",
+                    payload
+                );
+                Self::HostTime(parse_u64(payload).context(err_msg)?)
+            }
+            NL80211_PMSR_RESP_ATTR_AP_TSF => {
+                let err_msg = format!(
+                    "Invalid NL80211_PMSR_RESP_ATTR_AP_TSF \
+                     value {:?}",
+                    payload
+                );
+                Self::ApTsf(parse_u64(payload).context(err_msg)?)
+            }
+            NL80211_PMSR_RESP_ATTR_FINAL => Self::Final,
+            _ => Self::Other(
+                DefaultNla::parse(buf).context("invalid NLA (unknown kind)")?,
+            ),
+        })
+    }
+}
+
+/// Measurement result of a peer
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct Nl80211PmsrResponse {
+    pub data: Option<Nl80211PmsrResponseData>,
+    pub status: Option<Nl80211PmsrStatus>,
+    /// `CLOCK_BOOTTIME` in nanoseconds when the result was measured
+    pub host_time: Option<u64>,
+    /// TSF of the connected AP in microseconds when the result was measured
+    pub ap_tsf: Option<u64>,
+    /// Last result of the peer, partial results are not final
+    pub is_final: bool,
+}
+
+impl Emitable for Nl80211PmsrResponse {
+    fn buffer_len(&self) -> usize {
+        Vec::<Nl80211PmsrResponseNla>::from(self)
+            .as_slice()
+            .buffer_len()
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        Vec::<Nl80211PmsrResponseNla>::from(self)
+            .as_slice()
+            .emit(buffer)
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for Nl80211PmsrResponse
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let mut ret = Self::default();
+        let payload = buf.value();
+        let err_msg = format!(
+            "Invalid NL80211_PMSR_PEER_ATTR_RESP \
+             value {:?}",
+            payload
+        );
+        for nla in NlasIterator::new(payload) {
+            let nla = &nla.context(err_msg.clone())?;
+            match Nl80211PmsrResponseNla::parse(nla).context(err_msg.clone())? {
+                Nl80211PmsrResponseNla::Data(d) => ret.data = Some(*d),
+                Nl80211PmsrResponseNla::Status(d) => ret.status = Some(d),
+                Nl80211PmsrResponseNla::HostTime(d) => ret.host_time = Some(d),
+                Nl80211PmsrResponseNla::ApTsf(d) => ret.ap_tsf = Some(d),
+                Nl80211PmsrResponseNla::Final => ret.is_final = true,
+                Nl80211PmsrResponseNla::Other(attr) => {
+                    log::warn!(
+                        "Got unsupported NL80211_PMSR_PEER_ATTR_RESP \
+                         value {:?}",
+                        attr
+                    )
+                }
+            }
+        }
+        Ok(ret)
+    }
+}
+
+impl From<&Nl80211PmsrResponse> for Vec<Nl80211PmsrResponseNla> {
+    fn from(resp: &Nl80211PmsrResponse) -> Self {
+        let mut nlas = Vec::new();
+        if let Some(v) = resp.data.as_ref() {
+            nlas.push(Nl80211PmsrResponseNla::Data(Box::new(v.clone())));
+        }
+        if let Some(v) = resp.status {
+            nlas.push(Nl80211PmsrResponseNla::Status(v));
+        }
+        if let Some(v) = resp.host_time {
+            nlas.push(Nl80211PmsrResponseNla::HostTime(v));
+        }
+        if let Some(v) = resp.ap_tsf {
+            nlas.push(Nl80211PmsrResponseNla::ApTsf(v));
+        }
+        if resp.is_final {
+            nlas.push(Nl80211PmsrResponseNla::Final);
+        }
+        nlas
+    }
+}
@@ -0,0 +1,81 @@
+// SPDX-License-Identifier: MIT
+
+use crate::{
+    nl80211_execute_ack_cookie, Nl80211Attr, Nl80211Cmd, Nl80211Error,
+    Nl80211Handle, Nl80211Message, Nl80211PeerMeasurement, Nl80211PmsrPeer,
+};
+
+pub struct Nl80211PeerMeasurementStartRequest {
+    handle: Nl80211Handle,
+    if_index: u32,
+    peers: Vec<Nl80211PmsrPeer>,
+    timeout: Option<u32>,
+    random_mac: Option<([u8; 6], [u8; 6])>,
+}
+
+impl Nl80211PeerMeasurementStartRequest {
+    pub(crate) fn new(handle: Nl80211Handle, if_index: u32) -> Self {
+        Nl80211PeerMeasurementStartRequest {
+            handle,
+            if_index,
+            peers: Vec::new(),
+            timeout: None,
+            random_mac: None,
+        }
+    }
+
+    /// Add a peer to measure with
+    pub fn peer(mut self, peer: Nl80211PmsrPeer) -> Self {
+        self.peers.push(peer);
+        self
+    }
+
+    /// Abort the measurement after specified milliseconds
+    pub fn timeout(mut self, timeout: u32) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Use random source MAC address for the measurement. The bits set in
+    /// `mask` are taken from `mac`, the others are randomized.
+    pub fn random_mac(mut self, mac: [u8; 6], mask: [u8; 6]) -> Self {
+        self.random_mac = Some((mac, mask));
+        self
+    }
+
+    /// Return the cookie identifying the measurement in the
+    /// `Nl80211Cmd::PeerMeasurementResult` and
+    /// `Nl80211Cmd::PeerMeasurementComplete` messages. The kernel reports it
+    /// in the extended ACK, hence `None` unless `NETLINK_EXT_ACK` is enabled
+    /// on the socket via `set_ext_ack(true)`.
+    pub async fn execute(self) -> Result<Option<u64>, Nl80211Error> {
+        let Nl80211PeerMeasurementStartRequest {
+            mut handle,
+            if_index,
+            peers,
+            timeout,
+            random_mac,
+        } = self;
+
+        let mut nlas = vec![
+            Nl80211Attr::IfIndex(if_index),
+            Nl80211Attr::PeerMeasurements(Nl80211PeerMeasurement {
+                peers,
+                ..Default::default()
+            }),
+        ];
+        if let Some(timeout) = timeout {
+            nlas.push(Nl80211Attr::Timeout(timeout));
+        }
+        if let Some((mac, mask)) = random_mac {
+            nlas.push(Nl80211Attr::Mac(mac));
+            nlas.push(Nl80211Attr::MacMask(mask));
+        }
+
+        let nl80211_msg = Nl80211Message {
+            cmd: Nl80211Cmd::PeerMeasurementStart,
+            nlas,
+        };
+        nl80211_execute_ack_cookie(&mut handle, nl80211_msg).await
+    }
+}
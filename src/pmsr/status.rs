@@ -0,0 +1,45 @@
+// SPDX-License-Identifier: MIT
+
+const NL80211_PMSR_STATUS_SUCCESS: u32 = 0;
+const NL80211_PMSR_STATUS_REFUSED: u32 = 1;
+const NL80211_PMSR_STATUS_TIMEOUT: u32 = 2;
+const NL80211_PMSR_STATUS_FAILURE: u32 = 3;
+
+/// Status of peer measurement result
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Nl80211PmsrStatus {
+    /// Measurement completed successfully
+    Success,
+    /// Measurement was locally refused
+    Refused,
+    /// Measurement timed out
+    Timeout,
+    /// Measurement failed, a type-dependent reason may be available in the
+    /// response data
+    Failure,
+    Other(u32),
+}
+
+impl From<u32> for Nl80211PmsrStatus {
+    fn from(d: u32) -> Self {
+        match d {
+            NL80211_PMSR_STATUS_SUCCESS => Self::Success,
+            NL80211_PMSR_STATUS_REFUSED => Self::Refused,
+            NL80211_PMSR_STATUS_TIMEOUT => Self::Timeout,
+            NL80211_PMSR_STATUS_FAILURE => Self::Failure,
+            _ => Self::Other(d),
+        }
+    }
+}
+
+impl From<Nl80211PmsrStatus> for u32 {
+    fn from(v: Nl80211PmsrStatus) -> u32 {
+        match v {
+            Nl80211PmsrStatus::Success => NL80211_PMSR_STATUS_SUCCESS,
+            Nl80211PmsrStatus::Refused => NL80211_PMSR_STATUS_REFUSED,
+            Nl80211PmsrStatus::Timeout => NL80211_PMSR_STATUS_TIMEOUT,
+            Nl80211PmsrStatus::Failure => NL80211_PMSR_STATUS_FAILURE,
+            Nl80211PmsrStatus::Other(d) => d,
+        }
+    }
+}
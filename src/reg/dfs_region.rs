@@ -0,0 +1,40 @@
+// SPDX-License-Identifier: MIT
+
+const NL80211_DFS_UNSET: u8 = 0;
+const NL80211_DFS_FCC: u8 = 1;
+const NL80211_DFS_ETSI: u8 = 2;
+const NL80211_DFS_JP: u8 = 3;
+
+/// DFS regulatory region
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Nl80211DfsRegion {
+    Unset,
+    Fcc,
+    Etsi,
+    Jp,
+    Other(u8),
+}
+
+impl From<u8> for Nl80211DfsRegion {
+    fn from(d: u8) -> Self {
+        match d {
+            NL80211_DFS_UNSET => Self::Unset,
+            NL80211_DFS_FCC => Self::Fcc,
+            NL80211_DFS_ETSI => Self::Etsi,
+            NL80211_DFS_JP => Self::Jp,
+            _ => Self::Other(d),
+        }
+    }
+}
+
+impl From<Nl80211DfsRegion> for u8 {
+    fn from(v: Nl80211DfsRegion) -> u8 {
+        match v {
+            Nl80211DfsRegion::Unset => NL80211_DFS_UNSET,
+            Nl80211DfsRegion::Fcc => NL80211_DFS_FCC,
+            Nl80211DfsRegion::Etsi => NL80211_DFS_ETSI,
+            Nl80211DfsRegion::Jp => NL80211_DFS_JP,
+            Nl80211DfsRegion::Other(d) => d,
+        }
+    }
+}
@@ -0,0 +1,45 @@
+// SPDX-License-Identifier: MIT
+
+use crate::macros::nl80211_bitflags;
+
+nl80211_bitflags! {
+    /// Regulatory rule flags, could be combined via `|` operator.
+    pub struct Nl80211RegRuleFlags: u32 {
+        /// OFDM modulation not allowed
+        const NO_OFDM = 1 << 0;
+        /// CCK modulation not allowed
+        const NO_CCK = 1 << 1;
+        /// Indoor operation not allowed
+        const NO_INDOOR = 1 << 2;
+        /// Outdoor operation not allowed
+        const NO_OUTDOOR = 1 << 3;
+        /// DFS support is required to be used
+        const DFS = 1 << 4;
+        /// This is only for Point To Point links
+        const PTP_ONLY = 1 << 5;
+        /// This is only for Point To Multi Point links
+        const PTMP_ONLY = 1 << 6;
+        /// No mechanisms that initiate radiation are allowed, this includes
+        /// probe requests or modes of operation that require beaconing
+        const NO_IR = 1 << 7;
+        /// Maximum available bandwidth should be calculated base on
+        /// contiguous rules and wider channels will be allowed to cross
+        /// multiple contiguous rules
+        const AUTO_BW = 1 << 11;
+        /// Initiating radiation is allowed when there is an interface already
+        /// operating on the channel
+        const IR_CONCURRENT = 1 << 12;
+        /// Channels can't be used in HT40- operation
+        const NO_HT40MINUS = 1 << 13;
+        /// Channels can't be used in HT40+ operation
+        const NO_HT40PLUS = 1 << 14;
+        /// 80MHz operation not allowed
+        const NO_80MHZ = 1 << 15;
+        /// 160MHz operation not allowed
+        const NO_160MHZ = 1 << 16;
+        /// HE operation not allowed
+        const NO_HE = 1 << 17;
+        /// 320MHz operation not allowed
+        const NO_320MHZ = 1 << 18;
+    }
+}
@@ -0,0 +1,59 @@
+// SPDX-License-Identifier: MIT
+
+use futures::TryStream;
+use netlink_packet_core::{NLM_F_DUMP, NLM_F_REQUEST};
+use netlink_packet_generic::GenlMessage;
+
+use crate::{
+    nl80211_execute, Nl80211Attr, Nl80211Cmd, Nl80211Error, Nl80211Handle,
+    Nl80211Message,
+};
+
+pub struct Nl80211RegGetRequest {
+    handle: Nl80211Handle,
+    dump: bool,
+    wiphy: Option<u32>,
+}
+
+impl Nl80211RegGetRequest {
+    pub(crate) fn new(handle: Nl80211Handle, dump: bool) -> Self {
+        Nl80211RegGetRequest {
+            handle,
+            dump,
+            wiphy: None,
+        }
+    }
+
+    /// Retrieve the regulatory domain of specified wiphy instead of the
+    /// global one. Only valid for wiphy with self-managed regulatory domain.
+    pub fn wiphy(mut self, wiphy: u32) -> Self {
+        self.wiphy = Some(wiphy);
+        self
+    }
+
+    pub async fn execute(
+        self,
+    ) -> impl TryStream<Ok = GenlMessage<Nl80211Message>, Error = Nl80211Error>
+    {
+        let Nl80211RegGetRequest {
+            mut handle,
+            dump,
+            wiphy,
+        } = self;
+
+        let mut nlas = Vec::new();
+        if let Some(wiphy) = wiphy {
+            nlas.push(Nl80211Attr::WiPhy(wiphy));
+        }
+        let nl80211_msg = Nl80211Message {
+            cmd: Nl80211Cmd::RegGet,
+            nlas,
+        };
+        let flags = if dump {
+            NLM_F_REQUEST | NLM_F_DUMP
+        } else {
+            NLM_F_REQUEST
+        };
+        nl80211_execute(&mut handle, nl80211_msg, flags).await
+    }
+}
@@ -0,0 +1,39 @@
+// SPDX-License-Identifier: MIT
+
+use crate::{
+    Nl80211Handle, Nl80211RegGetRequest, Nl80211RegRulesSetRequest,
+    Nl80211RegSetRequest,
+};
+
+pub struct Nl80211RegHandle(Nl80211Handle);
+
+impl Nl80211RegHandle {
+    pub fn new(handle: Nl80211Handle) -> Self {
+        Nl80211RegHandle(handle)
+    }
+
+    /// Retrieve the current regulatory domain
+    /// (equivalent to `iw reg get`)
+    pub fn get(&mut self) -> Nl80211RegGetRequest {
+        Nl80211RegGetRequest::new(self.0.clone(), false)
+    }
+
+    /// Retrieve the global regulatory domain along with the regulatory
+    /// domains of all wiphys with self-managed regulatory domain
+    /// (equivalent to `iw reg get` on self-managed devices)
+    pub fn dump(&mut self) -> Nl80211RegGetRequest {
+        Nl80211RegGetRequest::new(self.0.clone(), true)
+    }
+
+    /// Hint kernel about the country we are in by ISO-3166-alpha2 code
+    /// (equivalent to `iw reg set ALPHA2`)
+    pub fn set(&mut self, alpha2: &str) -> Nl80211RegSetRequest {
+        Nl80211RegSetRequest::new(self.0.clone(), alpha2)
+    }
+
+    /// Program the regulatory rules of the regulatory domain, this is
+    /// normally done by CRDA in response to kernel requests.
+    pub fn set_rules(&mut self, alpha2: &str) -> Nl80211RegRulesSetRequest {
+        Nl80211RegRulesSetRequest::new(self.0.clone(), alpha2)
+    }
+}
@@ -0,0 +1,15 @@
+// SPDX-License-Identifier: MIT
+
+mod dfs_region;
+mod flags;
+mod get;
+mod handle;
+mod rule;
+mod set;
+
+pub use dfs_region::Nl80211DfsRegion;
+pub use flags::Nl80211RegRuleFlags;
+pub use get::Nl80211RegGetRequest;
+pub use handle::Nl80211RegHandle;
+pub use rule::{Nl80211RegRule, Nl80211RegRuleNla};
+pub use set::{Nl80211RegRulesSetRequest, Nl80211RegSetRequest};
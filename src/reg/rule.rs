@@ -0,0 +1,238 @@
+// SPDX-License-Identifier: MIT
+
+use anyhow::Context;
+use byteorder::{ByteOrder, NativeEndian};
+use netlink_packet_utils::{
+    nla::{DefaultNla, Nla, NlaBuffer, NlasIterator},
+    parsers::parse_u32,
+    DecodeError, Emitable, Parseable,
+};
+
+use crate::Nl80211RegRuleFlags;
+
+const NL80211_ATTR_REG_RULE_FLAGS: u16 = 1;
+const NL80211_ATTR_FREQ_RANGE_START: u16 = 2;
+const NL80211_ATTR_FREQ_RANGE_END: u16 = 3;
+const NL80211_ATTR_FREQ_RANGE_MAX_BW: u16 = 4;
+const NL80211_ATTR_POWER_RULE_MAX_ANT_GAIN: u16 = 5;
+const NL80211_ATTR_POWER_RULE_MAX_EIRP: u16 = 6;
+const NL80211_ATTR_DFS_CAC_TIME: u16 = 7;
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum Nl80211RegRuleNla {
+    Flags(Nl80211RegRuleFlags),
+    /// Starting frequency of the range in KHz
+    FreqRangeStart(u32),
+    /// Ending frequency of the range in KHz
+    FreqRangeEnd(u32),
+    /// Maximum allowed bandwidth in KHz
+    FreqRangeMaxBw(u32),
+    /// Maximum allowed antenna gain in mBi (100 * dBi)
+    PowerRuleMaxAntGain(u32),
+    /// Maximum allowed EIRP in mBm (100 * dBm)
+    PowerRuleMaxEirp(u32),
+    /// DFS CAC time in milliseconds
+    DfsCacTime(u32),
+    Other(DefaultNla),
+}
+
+impl Nla for Nl80211RegRuleNla {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::Flags(_)
+            | Self::FreqRangeStart(_)
+            | Self::FreqRangeEnd(_)
+            | Self::FreqRangeMaxBw(_)
+            | Self::PowerRuleMaxAntGain(_)
+            | Self::PowerRuleMaxEirp(_)
+            | Self::DfsCacTime(_) => 4,
+            Self::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::Flags(_) => NL80211_ATTR_REG_RULE_FLAGS,
+            Self::FreqRangeStart(_) => NL80211_ATTR_FREQ_RANGE_START,
+            Self::FreqRangeEnd(_) => NL80211_ATTR_FREQ_RANGE_END,
+            Self::FreqRangeMaxBw(_) => NL80211_ATTR_FREQ_RANGE_MAX_BW,
+            Self::PowerRuleMaxAntGain(_) => {
+                NL80211_ATTR_POWER_RULE_MAX_ANT_GAIN
+            }
+            Self::PowerRuleMaxEirp(_) => NL80211_ATTR_POWER_RULE_MAX_EIRP,
+            Self::DfsCacTime(_) => NL80211_ATTR_DFS_CAC_TIME,
+            Self::Other(attr) => attr.kind(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::FreqRangeStart(d)
+            | Self::FreqRangeEnd(d)
+            | Self::FreqRangeMaxBw(d)
+            | Self::PowerRuleMaxAntGain(d)
+            | Self::PowerRuleMaxEirp(d)
+            | Self::DfsCacTime(d) => NativeEndian::write_u32(buffer, *d),
+            Self::Flags(d) => NativeEndian::write_u32(buffer, (*d).into()),
+            Self::Other(ref attr) => attr.emit(buffer),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for Nl80211RegRuleNla
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            NL80211_ATTR_REG_RULE_FLAGS => {
+                let err_msg = format!(
+                    "Invalid NL80211_ATTR_REG_RULE_FLAGS value {:?}",
+                    payload
+                );
+                Self::Flags(parse_u32(payload).context(err_msg)?.into())
+            }
+            NL80211_ATTR_FREQ_RANGE_START => {
+                let err_msg = format!(
+                    "Invalid NL80211_ATTR_FREQ_RANGE_START value {:?}",
+                    payload
+                );
+                Self::FreqRangeStart(parse_u32(payload).context(err_msg)?)
+            }
+            NL80211_ATTR_FREQ_RANGE_END => {
+                let err_msg = format!(
+                    "Invalid NL80211_ATTR_FREQ_RANGE_END value {:?}",
+                    payload
+                );
+                Self::FreqRangeEnd(parse_u32(payload).context(err_msg)?)
+            }
+            NL80211_ATTR_FREQ_RANGE_MAX_BW => {
+                let err_msg = format!(
+                    "Invalid NL80211_ATTR_FREQ_RANGE_MAX_BW value {:?}",
+                    payload
+                );
+                Self::FreqRangeMaxBw(parse_u32(payload).context(err_msg)?)
+            }
+            NL80211_ATTR_POWER_RULE_MAX_ANT_GAIN => {
+                let err_msg = format!(
+                    "Invalid NL80211_ATTR_POWER_RULE_MAX_ANT_GAIN value {:?}",
+                    payload
+                );
+                Self::PowerRuleMaxAntGain(parse_u32(payload).context(err_msg)?)
+            }
+            NL80211_ATTR_POWER_RULE_MAX_EIRP => {
+                let err_msg = format!(
+                    "Invalid NL80211_ATTR_POWER_RULE_MAX_EIRP value {:?}",
+                    payload
+                );
+                Self::PowerRuleMaxEirp(parse_u32(payload).context(err_msg)?)
+            }
+            NL80211_ATTR_DFS_CAC_TIME => {
+                let err_msg = format!(
+                    "Invalid NL80211_ATTR_DFS_CAC_TIME value {:?}",
+                    payload
+                );
+                Self::DfsCacTime(parse_u32(payload).context(err_msg)?)
+            }
+            _ => Self::Other(
+                DefaultNla::parse(buf).context("invalid NLA (unknown kind)")?,
+            ),
+        })
+    }
+}
+
+/// Regulatory rule of a frequency range
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct Nl80211RegRule {
+    pub flags: Option<Nl80211RegRuleFlags>,
+    /// Starting frequency of the range in KHz
+    pub freq_range_start: Option<u32>,
+    /// Ending frequency of the range in KHz
+    pub freq_range_end: Option<u32>,
+    /// Maximum allowed bandwidth in KHz
+    pub freq_range_max_bw: Option<u32>,
+    /// Maximum allowed antenna gain in mBi (100 * dBi)
+    pub power_rule_max_ant_gain: Option<u32>,
+    /// Maximum allowed EIRP in mBm (100 * dBm)
+    pub power_rule_max_eirp: Option<u32>,
+    /// DFS CAC time in milliseconds
+    pub dfs_cac_time: Option<u32>,
+}
+
+impl Emitable for Nl80211RegRule {
+    fn buffer_len(&self) -> usize {
+        Vec::<Nl80211RegRuleNla>::from(self).as_slice().buffer_len()
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        Vec::<Nl80211RegRuleNla>::from(self).as_slice().emit(buffer)
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for Nl80211RegRule
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let mut ret = Self::default();
+        let payload = buf.value();
+        let err_msg =
+            format!("Invalid NL80211_ATTR_REG_RULES value {:?}", payload);
+        for nla in NlasIterator::new(payload) {
+            let nla = &nla.context(err_msg.clone())?;
+            match Nl80211RegRuleNla::parse(nla).context(err_msg.clone())? {
+                Nl80211RegRuleNla::Flags(d) => ret.flags = Some(d),
+                Nl80211RegRuleNla::FreqRangeStart(d) => {
+                    ret.freq_range_start = Some(d)
+                }
+                Nl80211RegRuleNla::FreqRangeEnd(d) => {
+                    ret.freq_range_end = Some(d)
+                }
+                Nl80211RegRuleNla::FreqRangeMaxBw(d) => {
+                    ret.freq_range_max_bw = Some(d)
+                }
+                Nl80211RegRuleNla::PowerRuleMaxAntGain(d) => {
+                    ret.power_rule_max_ant_gain = Some(d)
+                }
+                Nl80211RegRuleNla::PowerRuleMaxEirp(d) => {
+                    ret.power_rule_max_eirp = Some(d)
+                }
+                Nl80211RegRuleNla::DfsCacTime(d) => ret.dfs_cac_time = Some(d),
+                Nl80211RegRuleNla::Other(attr) => {
+                    log::warn!(
+                        "Got unsupported NL80211_ATTR_REG_RULES value {:?}",
+                        attr
+                    )
+                }
+            }
+        }
+        Ok(ret)
+    }
+}
+
+impl From<&Nl80211RegRule> for Vec<Nl80211RegRuleNla> {
+    fn from(rule: &Nl80211RegRule) -> Self {
+        let mut nlas = Vec::new();
+        if let Some(v) = rule.flags {
+            nlas.push(Nl80211RegRuleNla::Flags(v));
+        }
+        if let Some(v) = rule.freq_range_start {
+            nlas.push(Nl80211RegRuleNla::FreqRangeStart(v));
+        }
+        if let Some(v) = rule.freq_range_end {
+            nlas.push(Nl80211RegRuleNla::FreqRangeEnd(v));
+        }
+        if let Some(v) = rule.freq_range_max_bw {
+            nlas.push(Nl80211RegRuleNla::FreqRangeMaxBw(v));
+        }
+        if let Some(v) = rule.power_rule_max_ant_gain {
+            nlas.push(Nl80211RegRuleNla::PowerRuleMaxAntGain(v));
+        }
+        if let Some(v) = rule.power_rule_max_eirp {
+            nlas.push(Nl80211RegRuleNla::PowerRuleMaxEirp(v));
+        }
+        if let Some(v) = rule.dfs_cac_time {
+            nlas.push(Nl80211RegRuleNla::DfsCacTime(v));
+        }
+        nlas
+    }
+}
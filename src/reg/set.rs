@@ -0,0 +1,81 @@
+// SPDX-License-Identifier: MIT
+
+use crate::{
+    nl80211_execute_ack, Nl80211Attr, Nl80211Cmd, Nl80211DfsRegion,
+    Nl80211Error, Nl80211Handle, Nl80211Message, Nl80211RegRule,
+};
+
+pub struct Nl80211RegSetRequest {
+    handle: Nl80211Handle,
+    alpha2: String,
+}
+
+impl Nl80211RegSetRequest {
+    pub(crate) fn new(handle: Nl80211Handle, alpha2: &str) -> Self {
+        Nl80211RegSetRequest {
+            handle,
+            alpha2: alpha2.to_string(),
+        }
+    }
+
+    pub async fn execute(self) -> Result<(), Nl80211Error> {
+        let Nl80211RegSetRequest { mut handle, alpha2 } = self;
+
+        let nl80211_msg = Nl80211Message {
+            cmd: Nl80211Cmd::RegRequestSet,
+            nlas: vec![Nl80211Attr::RegAlpha2(alpha2)],
+        };
+        nl80211_execute_ack(&mut handle, nl80211_msg).await
+    }
+}
+
+pub struct Nl80211RegRulesSetRequest {
+    handle: Nl80211Handle,
+    alpha2: String,
+    dfs_region: Option<Nl80211DfsRegion>,
+    rules: Vec<Nl80211RegRule>,
+}
+
+impl Nl80211RegRulesSetRequest {
+    pub(crate) fn new(handle: Nl80211Handle, alpha2: &str) -> Self {
+        Nl80211RegRulesSetRequest {
+            handle,
+            alpha2: alpha2.to_string(),
+            dfs_region: None,
+            rules: Vec::new(),
+        }
+    }
+
+    /// DFS region of the regulatory domain
+    pub fn dfs_region(mut self, dfs_region: Nl80211DfsRegion) -> Self {
+        self.dfs_region = Some(dfs_region);
+        self
+    }
+
+    /// Add a rule of permitted frequency range
+    pub fn rule(mut self, rule: Nl80211RegRule) -> Self {
+        self.rules.push(rule);
+        self
+    }
+
+    pub async fn execute(self) -> Result<(), Nl80211Error> {
+        let Nl80211RegRulesSetRequest {
+            mut handle,
+            alpha2,
+            dfs_region,
+            rules,
+        } = self;
+
+        let mut nlas = vec![Nl80211Attr::RegAlpha2(alpha2)];
+        if let Some(dfs_region) = dfs_region {
+            nlas.push(Nl80211Attr::DfsRegion(dfs_region));
+        }
+        nlas.push(Nl80211Attr::RegRules(rules));
+
+        let nl80211_msg = Nl80211Message {
+            cmd: Nl80211Cmd::RegSet,
+            nlas,
+        };
+        nl80211_execute_ack(&mut handle, nl80211_msg).await
+    }
+}
@@ -0,0 +1,564 @@
+// SPDX-License-Identifier: MIT
+
+use anyhow::Context;
+use byteorder::{ByteOrder, NativeEndian};
+use netlink_packet_utils::{
+    nla::{DefaultNla, Nla, NlaBuffer, NlasIterator},
+    parsers::{
+        parse_i32, parse_mac, parse_u16, parse_u32, parse_u64, parse_u8,
+    },
+    DecodeError, Emitable, Parseable,
+};
+
+const NL80211_BSS_BSSID: u16 = 1;
+const NL80211_BSS_FREQUENCY: u16 = 2;
+const NL80211_BSS_TSF: u16 = 3;
+const NL80211_BSS_BEACON_INTERVAL: u16 = 4;
+const NL80211_BSS_CAPABILITY: u16 = 5;
+const NL80211_BSS_INFORMATION_ELEMENTS: u16 = 6;
+const NL80211_BSS_SIGNAL_MBM: u16 = 7;
+const NL80211_BSS_SIGNAL_UNSPEC: u16 = 8;
+const NL80211_BSS_STATUS: u16 = 9;
+const NL80211_BSS_SEEN_MS_AGO: u16 = 10;
+const NL80211_BSS_BEACON_IES: u16 = 11;
+const NL80211_BSS_CHAN_WIDTH: u16 = 12;
+const NL80211_BSS_BEACON_TSF: u16 = 13;
+const NL80211_BSS_PRESP_DATA: u16 = 14;
+const NL80211_BSS_LAST_SEEN_BOOTTIME: u16 = 15;
+const NL80211_BSS_PARENT_TSF: u16 = 17;
+const NL80211_BSS_PARENT_BSSID: u16 = 18;
+const NL80211_BSS_CHAIN_SIGNAL: u16 = 19;
+const NL80211_BSS_FREQUENCY_OFFSET: u16 = 20;
+const NL80211_BSS_MLO_LINK_ID: u16 = 21;
+const NL80211_BSS_MLD_ADDR: u16 = 22;
+
+const ETH_ALEN: usize = 6;
+
+const NL80211_BSS_STATUS_AUTHENTICATED: u32 = 0;
+const NL80211_BSS_STATUS_ASSOCIATED: u32 = 1;
+const NL80211_BSS_STATUS_IBSS_JOINED: u32 = 2;
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Nl80211BssStatus {
+    Authenticated,
+    Associated,
+    IbssJoined,
+    Other(u32),
+}
+
+impl From<u32> for Nl80211BssStatus {
+    fn from(d: u32) -> Self {
+        match d {
+            NL80211_BSS_STATUS_AUTHENTICATED => Self::Authenticated,
+            NL80211_BSS_STATUS_ASSOCIATED => Self::Associated,
+            NL80211_BSS_STATUS_IBSS_JOINED => Self::IbssJoined,
+            _ => Self::Other(d),
+        }
+    }
+}
+
+impl From<Nl80211BssStatus> for u32 {
+    fn from(v: Nl80211BssStatus) -> u32 {
+        match v {
+            Nl80211BssStatus::Authenticated => NL80211_BSS_STATUS_AUTHENTICATED,
+            Nl80211BssStatus::Associated => NL80211_BSS_STATUS_ASSOCIATED,
+            Nl80211BssStatus::IbssJoined => NL80211_BSS_STATUS_IBSS_JOINED,
+            Nl80211BssStatus::Other(d) => d,
+        }
+    }
+}
+
+const NL80211_BSS_CHAN_WIDTH_20: u32 = 0;
+const NL80211_BSS_CHAN_WIDTH_10: u32 = 1;
+const NL80211_BSS_CHAN_WIDTH_5: u32 = 2;
+const NL80211_BSS_CHAN_WIDTH_1: u32 = 3;
+const NL80211_BSS_CHAN_WIDTH_2: u32 = 4;
+
+/// Channel width of the control channel of BSS
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Nl80211BssScanWidth {
+    Mhz(u32),
+    Other(u32),
+}
+
+impl From<u32> for Nl80211BssScanWidth {
+    fn from(d: u32) -> Self {
+        match d {
+            NL80211_BSS_CHAN_WIDTH_20 => Self::Mhz(20),
+            NL80211_BSS_CHAN_WIDTH_10 => Self::Mhz(10),
+            NL80211_BSS_CHAN_WIDTH_5 => Self::Mhz(5),
+            NL80211_BSS_CHAN_WIDTH_1 => Self::Mhz(1),
+            NL80211_BSS_CHAN_WIDTH_2 => Self::Mhz(2),
+            _ => Self::Other(d),
+        }
+    }
+}
+
+impl From<Nl80211BssScanWidth> for u32 {
+    fn from(v: Nl80211BssScanWidth) -> u32 {
+        match v {
+            Nl80211BssScanWidth::Mhz(20) => NL80211_BSS_CHAN_WIDTH_20,
+            Nl80211BssScanWidth::Mhz(10) => NL80211_BSS_CHAN_WIDTH_10,
+            Nl80211BssScanWidth::Mhz(5) => NL80211_BSS_CHAN_WIDTH_5,
+            Nl80211BssScanWidth::Mhz(1) => NL80211_BSS_CHAN_WIDTH_1,
+            Nl80211BssScanWidth::Mhz(2) => NL80211_BSS_CHAN_WIDTH_2,
+            Nl80211BssScanWidth::Mhz(_) => {
+                log::warn!("Invalid Nl80211BssScanWidth {:?}", v);
+                u32::MAX
+            }
+            Nl80211BssScanWidth::Other(d) => d,
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum Nl80211BssNla {
+    Bssid([u8; ETH_ALEN]),
+    /// Frequency in MHz
+    Frequency(u32),
+    /// TSF of the received probe response/beacon
+    Tsf(u64),
+    /// Beacon interval in TU
+    BeaconInterval(u16),
+    Capability(u16),
+    /// Raw information elements from the probe response/beacon
+    InformationElements(Vec<u8>),
+    /// Signal strength in mBm (100 * dBm)
+    SignalMbm(i32),
+    /// Signal strength in unspecified units, scaled to 0..100
+    SignalUnspec(u8),
+    Status(Nl80211BssStatus),
+    /// Age of this BSS entry in milliseconds
+    SeenMsAgo(u32),
+    /// Raw information elements from beacon
+    BeaconIes(Vec<u8>),
+    ChanWidth(Nl80211BssScanWidth),
+    /// TSF of the last received beacon
+    BeaconTsf(u64),
+    /// The information elements and TSF are from probe response
+    ProbeResponseData,
+    /// CLOCK_BOOTTIME timestamp in nanoseconds when this entry was last
+    /// updated by a received frame
+    LastSeenBoottime(u64),
+    ParentTsf(u64),
+    ParentBssid([u8; ETH_ALEN]),
+    /// Per-chain signal strength in dBm, index is antenna number
+    ChainSignal(Vec<i8>),
+    /// Frequency offset in KHz
+    FrequencyOffset(u32),
+    MloLinkId(u8),
+    MldAddr([u8; ETH_ALEN]),
+    Other(DefaultNla),
+}
+
+fn chain_signal_to_nlas(signals: &[i8]) -> Vec<DefaultNla> {
+    signals
+        .iter()
+        .enumerate()
+        .map(|(i, signal)| DefaultNla::new(i as u16, vec![*signal as u8]))
+        .collect()
+}
+
+impl Nla for Nl80211BssNla {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::Bssid(_) | Self::ParentBssid(_) | Self::MldAddr(_) => {
+                ETH_ALEN
+            }
+            Self::Frequency(_)
+            | Self::SignalMbm(_)
+            | Self::Status(_)
+            | Self::SeenMsAgo(_)
+            | Self::ChanWidth(_)
+            | Self::FrequencyOffset(_) => 4,
+            Self::Tsf(_)
+            | Self::BeaconTsf(_)
+            | Self::LastSeenBoottime(_)
+            | Self::ParentTsf(_) => 8,
+            Self::BeaconInterval(_) | Self::Capability(_) => 2,
+            Self::SignalUnspec(_) | Self::MloLinkId(_) => 1,
+            Self::InformationElements(ref v) | Self::BeaconIes(ref v) => {
+                v.len()
+            }
+            Self::ProbeResponseData => 0,
+            Self::ChainSignal(ref v) => {
+                chain_signal_to_nlas(v).as_slice().buffer_len()
+            }
+            Self::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::Bssid(_) => NL80211_BSS_BSSID,
+            Self::Frequency(_) => NL80211_BSS_FREQUENCY,
+            Self::Tsf(_) => NL80211_BSS_TSF,
+            Self::BeaconInterval(_) => NL80211_BSS_BEACON_INTERVAL,
+            Self::Capability(_) => NL80211_BSS_CAPABILITY,
+            Self::InformationElements(_) => NL80211_BSS_INFORMATION_ELEMENTS,
+            Self::SignalMbm(_) => NL80211_BSS_SIGNAL_MBM,
+            Self::SignalUnspec(_) => NL80211_BSS_SIGNAL_UNSPEC,
+            Self::Status(_) => NL80211_BSS_STATUS,
+            Self::SeenMsAgo(_) => NL80211_BSS_SEEN_MS_AGO,
+            Self::BeaconIes(_) => NL80211_BSS_BEACON_IES,
+            Self::ChanWidth(_) => NL80211_BSS_CHAN_WIDTH,
+            Self::BeaconTsf(_) => NL80211_BSS_BEACON_TSF,
+            Self::ProbeResponseData => NL80211_BSS_PRESP_DATA,
+            Self::LastSeenBoottime(_) => NL80211_BSS_LAST_SEEN_BOOTTIME,
+            Self::ParentTsf(_) => NL80211_BSS_PARENT_TSF,
+            Self::ParentBssid(_) => NL80211_BSS_PARENT_BSSID,
+            Self::ChainSignal(_) => NL80211_BSS_CHAIN_SIGNAL,
+            Self::FrequencyOffset(_) => NL80211_BSS_FREQUENCY_OFFSET,
+            Self::MloLinkId(_) => NL80211_BSS_MLO_LINK_ID,
+            Self::MldAddr(_) => NL80211_BSS_MLD_ADDR,
+            Self::Other(attr) => attr.kind(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Bssid(ref s)
+            | Self::ParentBssid(ref s)
+            | Self::MldAddr(ref s) => buffer.copy_from_slice(s),
+            Self::Frequency(d)
+            | Self::SeenMsAgo(d)
+            | Self::FrequencyOffset(d) => NativeEndian::write_u32(buffer, *d),
+            Self::SignalMbm(d) => NativeEndian::write_i32(buffer, *d),
+            Self::Status(d) => NativeEndian::write_u32(buffer, (*d).into()),
+            Self::ChanWidth(d) => NativeEndian::write_u32(buffer, (*d).into()),
+            Self::Tsf(d)
+            | Self::BeaconTsf(d)
+            | Self::LastSeenBoottime(d)
+            | Self::ParentTsf(d) => NativeEndian::write_u64(buffer, *d),
+            Self::BeaconInterval(d) | Self::Capability(d) => {
+                NativeEndian::write_u16(buffer, *d)
+            }
+            Self::SignalUnspec(d) | Self::MloLinkId(d) => buffer[0] = *d,
+            Self::InformationElements(ref v) | Self::BeaconIes(ref v) => {
+                buffer[..v.len()].copy_from_slice(v)
+            }
+            Self::ProbeResponseData => (),
+            Self::ChainSignal(ref v) => {
+                chain_signal_to_nlas(v).as_slice().emit(buffer)
+            }
+            Self::Other(ref attr) => attr.emit(buffer),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for Nl80211BssNla
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            NL80211_BSS_BSSID => {
+                let err_msg =
+                    format!("Invalid NL80211_BSS_BSSID value {:?}", payload);
+                Self::Bssid(parse_mac(payload).context(err_msg)?)
+            }
+            NL80211_BSS_FREQUENCY => {
+                let err_msg = format!(
+                    "Invalid NL80211_BSS_FREQUENCY value {:?}",
+                    payload
+                );
+                Self::Frequency(parse_u32(payload).context(err_msg)?)
+            }
+            NL80211_BSS_TSF => {
+                let err_msg =
+                    format!("Invalid NL80211_BSS_TSF value {:?}", payload);
+                Self::Tsf(parse_u64(payload).context(err_msg)?)
+            }
+            NL80211_BSS_BEACON_INTERVAL => {
+                let err_msg = format!(
+                    "Invalid NL80211_BSS_BEACON_INTERVAL value {:?}",
+                    payload
+                );
+                Self::BeaconInterval(parse_u16(payload).context(err_msg)?)
+            }
+            NL80211_BSS_CAPABILITY => {
+                let err_msg = format!(
+                    "Invalid NL80211_BSS_CAPABILITY value {:?}",
+                    payload
+                );
+                Self::Capability(parse_u16(payload).context(err_msg)?)
+            }
+            NL80211_BSS_INFORMATION_ELEMENTS => {
+                Self::InformationElements(payload.to_vec())
+            }
+            NL80211_BSS_SIGNAL_MBM => {
+                let err_msg = format!(
+                    "Invalid NL80211_BSS_SIGNAL_MBM value {:?}",
+                    payload
+                );
+                Self::SignalMbm(parse_i32(payload).context(err_msg)?)
+            }
+            NL80211_BSS_SIGNAL_UNSPEC => {
+                let err_msg = format!(
+                    "Invalid NL80211_BSS_SIGNAL_UNSPEC value {:?}",
+                    payload
+                );
+                Self::SignalUnspec(parse_u8(payload).context(err_msg)?)
+            }
+            NL80211_BSS_STATUS => {
+                let err_msg =
+                    format!("Invalid NL80211_BSS_STATUS value {:?}", payload);
+                Self::Status(parse_u32(payload).context(err_msg)?.into())
+            }
+            NL80211_BSS_SEEN_MS_AGO => {
+                let err_msg = format!(
+                    "Invalid NL80211_BSS_SEEN_MS_AGO value {:?}",
+                    payload
+                );
+                Self::SeenMsAgo(parse_u32(payload).context(err_msg)?)
+            }
+            NL80211_BSS_BEACON_IES => Self::BeaconIes(payload.to_vec()),
+            NL80211_BSS_CHAN_WIDTH => {
+                let err_msg = format!(
+                    "Invalid NL80211_BSS_CHAN_WIDTH value {:?}",
+                    payload
+                );
+                Self::ChanWidth(parse_u32(payload).context(err_msg)?.into())
+            }
+            NL80211_BSS_BEACON_TSF => {
+                let err_msg = format!(
+                    "Invalid NL80211_BSS_BEACON_TSF value {:?}",
+                    payload
+                );
+                Self::BeaconTsf(parse_u64(payload).context(err_msg)?)
+            }
+            NL80211_BSS_PRESP_DATA => Self::ProbeResponseData,
+            NL80211_BSS_LAST_SEEN_BOOTTIME => {
+                let err_msg = format!(
+                    "Invalid NL80211_BSS_LAST_SEEN_BOOTTIME value {:?}",
+                    payload
+                );
+                Self::LastSeenBoottime(parse_u64(payload).context(err_msg)?)
+            }
+            NL80211_BSS_PARENT_TSF => {
+                let err_msg = format!(
+                    "Invalid NL80211_BSS_PARENT_TSF value {:?}",
+                    payload
+                );
+                Self::ParentTsf(parse_u64(payload).context(err_msg)?)
+            }
+            NL80211_BSS_PARENT_BSSID => {
+                let err_msg = format!(
+                    "Invalid NL80211_BSS_PARENT_BSSID value {:?}",
+                    payload
+                );
+                Self::ParentBssid(parse_mac(payload).context(err_msg)?)
+            }
+            NL80211_BSS_CHAIN_SIGNAL => {
+                let err_msg = format!(
+                    "Invalid NL80211_BSS_CHAIN_SIGNAL value {:?}",
+                    payload
+                );
+                let mut signals = Vec::new();
+                for nla in NlasIterator::new(payload) {
+                    let nla = &nla.context(err_msg.clone())?;
+                    signals
+                        .push(parse_u8(nla.value()).context(err_msg.clone())?
+                            as i8);
+                }
+                Self::ChainSignal(signals)
+            }
+            NL80211_BSS_FREQUENCY_OFFSET => {
+                let err_msg = format!(
+                    "Invalid NL80211_BSS_FREQUENCY_OFFSET value {:?}",
+                    payload
+                );
+                Self::FrequencyOffset(parse_u32(payload).context(err_msg)?)
+            }
+            NL80211_BSS_MLO_LINK_ID => {
+                let err_msg = format!(
+                    "Invalid NL80211_BSS_MLO_LINK_ID value {:?}",
+                    payload
+                );
+                Self::MloLinkId(parse_u8(payload).context(err_msg)?)
+            }
+            NL80211_BSS_MLD_ADDR => {
+                let err_msg =
+                    format!("Invalid NL80211_BSS_MLD_ADDR value {:?}", payload);
+                Self::MldAddr(parse_mac(payload).context(err_msg)?)
+            }
+            _ => Self::Other(
+                DefaultNla::parse(buf).context("invalid NLA (unknown kind)")?,
+            ),
+        })
+    }
+}
+
+/// BSS found by scan
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct Nl80211Bss {
+    pub bssid: Option<[u8; ETH_ALEN]>,
+    /// Frequency in MHz
+    pub frequency: Option<u32>,
+    /// Frequency offset in KHz
+    pub frequency_offset: Option<u32>,
+    /// TSF of the received probe response/beacon
+    pub tsf: Option<u64>,
+    /// Beacon interval in TU
+    pub beacon_interval: Option<u16>,
+    pub capability: Option<u16>,
+    /// Raw information elements from the probe response/beacon
+    pub information_elements: Option<Vec<u8>>,
+    /// Signal strength in mBm (100 * dBm)
+    pub signal_mbm: Option<i32>,
+    /// Signal strength in unspecified units, scaled to 0..100
+    pub signal_unspec: Option<u8>,
+    /// Only set if this BSS is in use
+    pub status: Option<Nl80211BssStatus>,
+    /// Age of this BSS entry in milliseconds
+    pub seen_ms_ago: Option<u32>,
+    /// Raw information elements from beacon, not present if no beacon
+    /// received yet
+    pub beacon_ies: Option<Vec<u8>>,
+    pub chan_width: Option<Nl80211BssScanWidth>,
+    /// TSF of the last received beacon
+    pub beacon_tsf: Option<u64>,
+    /// The `information_elements` and `tsf` are known to be from probe
+    /// response
+    pub probe_response_data: bool,
+    /// CLOCK_BOOTTIME timestamp in nanoseconds when this entry was last
+    /// updated by a received frame
+    pub last_seen_boottime: Option<u64>,
+    pub parent_tsf: Option<u64>,
+    pub parent_bssid: Option<[u8; ETH_ALEN]>,
+    /// Per-chain signal strength in dBm, index is antenna number
+    pub chain_signal: Vec<i8>,
+    pub mlo_link_id: Option<u8>,
+    pub mld_addr: Option<[u8; ETH_ALEN]>,
+}
+
+impl Emitable for Nl80211Bss {
+    fn buffer_len(&self) -> usize {
+        Vec::<Nl80211BssNla>::from(self).as_slice().buffer_len()
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        Vec::<Nl80211BssNla>::from(self).as_slice().emit(buffer)
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for Nl80211Bss {
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let mut ret = Self::default();
+        let payload = buf.value();
+        let err_msg = format!("Invalid NL80211_ATTR_BSS value {:?}", payload);
+        for nla in NlasIterator::new(payload) {
+            let nla = &nla.context(err_msg.clone())?;
+            match Nl80211BssNla::parse(nla).context(err_msg.clone())? {
+                Nl80211BssNla::Bssid(s) => ret.bssid = Some(s),
+                Nl80211BssNla::Frequency(d) => ret.frequency = Some(d),
+                Nl80211BssNla::Tsf(d) => ret.tsf = Some(d),
+                Nl80211BssNla::BeaconInterval(d) => {
+                    ret.beacon_interval = Some(d)
+                }
+                Nl80211BssNla::Capability(d) => ret.capability = Some(d),
+                Nl80211BssNla::InformationElements(v) => {
+                    ret.information_elements = Some(v)
+                }
+                Nl80211BssNla::SignalMbm(d) => ret.signal_mbm = Some(d),
+                Nl80211BssNla::SignalUnspec(d) => ret.signal_unspec = Some(d),
+                Nl80211BssNla::Status(d) => ret.status = Some(d),
+                Nl80211BssNla::SeenMsAgo(d) => ret.seen_ms_ago = Some(d),
+                Nl80211BssNla::BeaconIes(v) => ret.beacon_ies = Some(v),
+                Nl80211BssNla::ChanWidth(d) => ret.chan_width = Some(d),
+                Nl80211BssNla::BeaconTsf(d) => ret.beacon_tsf = Some(d),
+                Nl80211BssNla::ProbeResponseData => {
+                    ret.probe_response_data = true
+                }
+                Nl80211BssNla::LastSeenBoottime(d) => {
+                    ret.last_seen_boottime = Some(d)
+                }
+                Nl80211BssNla::ParentTsf(d) => ret.parent_tsf = Some(d),
+                Nl80211BssNla::ParentBssid(s) => ret.parent_bssid = Some(s),
+                Nl80211BssNla::ChainSignal(v) => ret.chain_signal = v,
+                Nl80211BssNla::FrequencyOffset(d) => {
+                    ret.frequency_offset = Some(d)
+                }
+                Nl80211BssNla::MloLinkId(d) => ret.mlo_link_id = Some(d),
+                Nl80211BssNla::MldAddr(s) => ret.mld_addr = Some(s),
+                Nl80211BssNla::Other(attr) => {
+                    log::warn!(
+                        "Got unsupported NL80211_ATTR_BSS value {:?}",
+                        attr
+                    )
+                }
+            }
+        }
+        Ok(ret)
+    }
+}
+
+impl From<&Nl80211Bss> for Vec<Nl80211BssNla> {
+    fn from(bss: &Nl80211Bss) -> Self {
+        let mut nlas = Vec::new();
+        if let Some(v) = bss.bssid {
+            nlas.push(Nl80211BssNla::Bssid(v));
+        }
+        if let Some(v) = bss.frequency {
+            nlas.push(Nl80211BssNla::Frequency(v));
+        }
+        if let Some(v) = bss.frequency_offset {
+            nlas.push(Nl80211BssNla::FrequencyOffset(v));
+        }
+        if let Some(v) = bss.tsf {
+            nlas.push(Nl80211BssNla::Tsf(v));
+        }
+        if let Some(v) = bss.beacon_interval {
+            nlas.push(Nl80211BssNla::BeaconInterval(v));
+        }
+        if let Some(v) = bss.capability {
+            nlas.push(Nl80211BssNla::Capability(v));
+        }
+        if let Some(v) = bss.information_elements.as_ref() {
+            nlas.push(Nl80211BssNla::InformationElements(v.clone()));
+        }
+        if let Some(v) = bss.signal_mbm {
+            nlas.push(Nl80211BssNla::SignalMbm(v));
+        }
+        if let Some(v) = bss.signal_unspec {
+            nlas.push(Nl80211BssNla::SignalUnspec(v));
+        }
+        if let Some(v) = bss.status {
+            nlas.push(Nl80211BssNla::Status(v));
+        }
+        if let Some(v) = bss.seen_ms_ago {
+            nlas.push(Nl80211BssNla::SeenMsAgo(v));
+        }
+        if let Some(v) = bss.beacon_ies.as_ref() {
+            nlas.push(Nl80211BssNla::BeaconIes(v.clone()));
+        }
+        if let Some(v) = bss.chan_width {
+            nlas.push(Nl80211BssNla::ChanWidth(v));
+        }
+        if let Some(v) = bss.beacon_tsf {
+            nlas.push(Nl80211BssNla::BeaconTsf(v));
+        }
+        if bss.probe_response_data {
+            nlas.push(Nl80211BssNla::ProbeResponseData);
+        }
+        if let Some(v) = bss.last_seen_boottime {
+            nlas.push(Nl80211BssNla::LastSeenBoottime(v));
+        }
+        if let Some(v) = bss.parent_tsf {
+            nlas.push(Nl80211BssNla::ParentTsf(v));
+        }
+        if let Some(v) = bss.parent_bssid {
+            nlas.push(Nl80211BssNla::ParentBssid(v));
+        }
+        if !bss.chain_signal.is_empty() {
+            nlas.push(Nl80211BssNla::ChainSignal(bss.chain_signal.clone()));
+        }
+        if let Some(v) = bss.mlo_link_id {
+            nlas.push(Nl80211BssNla::MloLinkId(v));
+        }
+        if let Some(v) = bss.mld_addr {
+            nlas.push(Nl80211BssNla::MldAddr(v));
+        }
+        nlas
+    }
+}
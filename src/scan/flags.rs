@@ -0,0 +1,41 @@
+// SPDX-License-Identifier: MIT
+
+use crate::macros::nl80211_bitflags;
+
+nl80211_bitflags! {
+    /// Scan request control flags, could be combined via `|` operator.
+    pub struct Nl80211ScanFlags: u32 {
+        /// Scan request has low priority
+        const LOW_PRIORITY = 1 << 0;
+        /// Flush cache before scanning
+        const FLUSH = 1 << 1;
+        /// Force a scan even if the interface is configured as AP and the
+        /// beacon interval is already set
+        const AP = 1 << 2;
+        /// Use a random MAC address for this scan
+        const RANDOM_ADDR = 1 << 3;
+        /// Fill the dwell time in the FILS request parameters IE in the probe
+        /// request
+        const FILS_MAX_CHANNEL_TIME = 1 << 4;
+        /// Accept broadcast probe responses
+        const ACCEPT_BCAST_PROBE_RESP = 1 << 5;
+        /// Send probe request frames at rate of at least 5.5M
+        const OCE_PROBE_REQ_HIGH_TX_RATE = 1 << 6;
+        /// Allow probe request tx deferral and suppression
+        const OCE_PROBE_REQ_DEFERRAL_SUPPRESSION = 1 << 7;
+        /// Span/duration of the scan is low
+        const LOW_SPAN = 1 << 8;
+        /// Scan using lower power
+        const LOW_POWER = 1 << 9;
+        /// Scan for a high accuracy result
+        const HIGH_ACCURACY = 1 << 10;
+        /// Randomize the sequence number in probe request frames
+        const RANDOM_SN = 1 << 11;
+        /// Only use minimal probe request frame content
+        const MIN_PREQ_CONTENT = 1 << 12;
+        /// Frequencies are in kHz instead of MHz
+        const FREQ_KHZ = 1 << 13;
+        /// Scan for collocated APs reported by 2.4/5 GHz APs
+        const COLOCATED_6GHZ = 1 << 14;
+    }
+}
@@ -0,0 +1,41 @@
+// SPDX-License-Identifier: MIT
+
+use crate::{
+    Nl80211Handle, Nl80211ScanGetRequest, Nl80211ScanScheduleRequest,
+    Nl80211ScanScheduleStopRequest, Nl80211ScanTriggerRequest,
+};
+
+pub struct Nl80211ScanHandle(Nl80211Handle);
+
+impl Nl80211ScanHandle {
+    pub fn new(handle: Nl80211Handle) -> Self {
+        Nl80211ScanHandle(handle)
+    }
+
+    /// Retrieve the current scan results of specified interface
+    /// (equivalent to `iw dev DEVICE scan dump`)
+    pub fn dump(&mut self, if_index: u32) -> Nl80211ScanGetRequest {
+        Nl80211ScanGetRequest::new(self.0.clone(), if_index)
+    }
+
+    /// Request a new scan on specified interface
+    /// (equivalent to `iw dev DEVICE scan trigger`)
+    pub fn trigger(&mut self, if_index: u32) -> Nl80211ScanTriggerRequest {
+        Nl80211ScanTriggerRequest::new(self.0.clone(), if_index)
+    }
+
+    /// Start scheduled scan on specified interface
+    /// (equivalent to `iw dev DEVICE scan sched_start`)
+    pub fn schedule(&mut self, if_index: u32) -> Nl80211ScanScheduleRequest {
+        Nl80211ScanScheduleRequest::new(self.0.clone(), if_index)
+    }
+
+    /// Stop scheduled scan on specified interface
+    /// (equivalent to `iw dev DEVICE scan sched_stop`)
+    pub fn stop_schedule(
+        &mut self,
+        if_index: u32,
+    ) -> Nl80211ScanScheduleStopRequest {
+        Nl80211ScanScheduleStopRequest::new(self.0.clone(), if_index)
+    }
+}
@@ -0,0 +1,249 @@
+// SPDX-License-Identifier: MIT
+
+use anyhow::Context;
+use byteorder::{ByteOrder, NativeEndian};
+use netlink_packet_utils::{
+    nla::{DefaultNla, Nla, NlaBuffer, NlasIterator},
+    parsers::{parse_i32, parse_mac, parse_string},
+    DecodeError, Emitable, Parseable,
+};
+
+use crate::Nl80211Band;
+
+const NL80211_SCHED_SCAN_MATCH_ATTR_SSID: u16 = 1;
+const NL80211_SCHED_SCAN_MATCH_ATTR_RSSI: u16 = 2;
+const NL80211_SCHED_SCAN_MATCH_ATTR_BSSID: u16 = 5;
+const NL80211_SCHED_SCAN_MATCH_PER_BAND_RSSI: u16 = 6;
+
+const ETH_ALEN: usize = 6;
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum Nl80211SchedScanMatchNla {
+    Ssid(String),
+    /// RSSI threshold in dBm
+    Rssi(i32),
+    Bssid([u8; ETH_ALEN]),
+    /// Band specific minimum RSSI threshold in dBm
+    PerBandRssi(Vec<(Nl80211Band, i32)>),
+    Other(DefaultNla),
+}
+
+fn per_band_rssi_to_nlas(values: &[(Nl80211Band, i32)]) -> Vec<DefaultNla> {
+    values
+        .iter()
+        .map(|(band, rssi)| {
+            let mut buffer = vec![0u8; 4];
+            NativeEndian::write_i32(&mut buffer, *rssi);
+            DefaultNla::new(u32::from(*band) as u16, buffer)
+        })
+        .collect()
+}
+
+impl Nla for Nl80211SchedScanMatchNla {
+    fn value_len(&self) -> usize {
+        match self {
+            // The SSID should not be NULL terminated
+            Self::Ssid(ref s) => s.len(),
+            Self::Rssi(_) => 4,
+            Self::Bssid(_) => ETH_ALEN,
+            Self::PerBandRssi(ref v) => {
+                per_band_rssi_to_nlas(v).as_slice().buffer_len()
+            }
+            Self::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::Ssid(_) => NL80211_SCHED_SCAN_MATCH_ATTR_SSID,
+            Self::Rssi(_) => NL80211_SCHED_SCAN_MATCH_ATTR_RSSI,
+            Self::Bssid(_) => NL80211_SCHED_SCAN_MATCH_ATTR_BSSID,
+            Self::PerBandRssi(_) => NL80211_SCHED_SCAN_MATCH_PER_BAND_RSSI,
+            Self::Other(attr) => attr.kind(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Ssid(ref s) => buffer.copy_from_slice(s.as_bytes()),
+            Self::Rssi(d) => NativeEndian::write_i32(buffer, *d),
+            Self::Bssid(ref s) => buffer.copy_from_slice(s),
+            Self::PerBandRssi(ref v) => {
+                per_band_rssi_to_nlas(v).as_slice().emit(buffer)
+            }
+            Self::Other(ref attr) => attr.emit(buffer),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for Nl80211SchedScanMatchNla
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            NL80211_SCHED_SCAN_MATCH_ATTR_SSID => {
+                let err_msg = format!(
+                    "Invalid NL80211_SCHED_SCAN_MATCH_ATTR_SSID value {:?}",
+                    payload
+                );
+                Self::Ssid(parse_string(payload).context(err_msg)?)
+            }
+            NL80211_SCHED_SCAN_MATCH_ATTR_RSSI => {
+                let err_msg = format!(
+                    "Invalid NL80211_SCHED_SCAN_MATCH_ATTR_RSSI value {:?}",
+                    payload
+                );
+                Self::Rssi(parse_i32(payload).context(err_msg)?)
+            }
+            NL80211_SCHED_SCAN_MATCH_ATTR_BSSID => {
+                let err_msg = format!(
+                    "Invalid NL80211_SCHED_SCAN_MATCH_ATTR_BSSID value {:?}",
+                    payload
+                );
+                Self::Bssid(parse_mac(payload).context(err_msg)?)
+            }
+            NL80211_SCHED_SCAN_MATCH_PER_BAND_RSSI => {
+                let err_msg = format!(
+                    "Invalid NL80211_SCHED_SCAN_MATCH_PER_BAND_RSSI value {:?}",
+                    payload
+                );
+                let mut values = Vec::new();
+                for nla in NlasIterator::new(payload) {
+                    let nla = &nla.context(err_msg.clone())?;
+                    values.push((
+                        Nl80211Band::from(nla.kind() as u32),
+                        parse_i32(nla.value()).context(err_msg.clone())?,
+                    ));
+                }
+                Self::PerBandRssi(values)
+            }
+            _ => Self::Other(
+                DefaultNla::parse(buf).context("invalid NLA (unknown kind)")?,
+            ),
+        })
+    }
+}
+
+/// Filter of scheduled scan results, only BSS matching any of the match sets
+/// will be reported.
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct Nl80211SchedScanMatchSet {
+    /// Only report BSS with matching SSID, cannot be used with `bssid`
+    pub ssid: Option<String>,
+    /// Only report BSS with matching BSSID, cannot be used with `ssid`
+    pub bssid: Option<[u8; ETH_ALEN]>,
+    /// RSSI threshold in dBm for reporting a BSS
+    pub rssi: Option<i32>,
+    /// Band specific minimum RSSI threshold in dBm
+    pub per_band_rssi: Vec<(Nl80211Band, i32)>,
+}
+
+impl Emitable for Nl80211SchedScanMatchSet {
+    fn buffer_len(&self) -> usize {
+        Vec::<Nl80211SchedScanMatchNla>::from(self)
+            .as_slice()
+            .buffer_len()
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        Vec::<Nl80211SchedScanMatchNla>::from(self)
+            .as_slice()
+            .emit(buffer)
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for Nl80211SchedScanMatchSet
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let mut ret = Self::default();
+        let payload = buf.value();
+        let err_msg = format!(
+            "Invalid NL80211_ATTR_SCHED_SCAN_MATCH value {:?}",
+            payload
+        );
+        for nla in NlasIterator::new(payload) {
+            let nla = &nla.context(err_msg.clone())?;
+            match Nl80211SchedScanMatchNla::parse(nla)
+                .context(err_msg.clone())?
+            {
+                Nl80211SchedScanMatchNla::Ssid(s) => ret.ssid = Some(s),
+                Nl80211SchedScanMatchNla::Rssi(d) => ret.rssi = Some(d),
+                Nl80211SchedScanMatchNla::Bssid(s) => ret.bssid = Some(s),
+                Nl80211SchedScanMatchNla::PerBandRssi(v) => {
+                    ret.per_band_rssi = v
+                }
+                Nl80211SchedScanMatchNla::Other(attr) => {
+                    log::warn!(
+                        "Got unsupported NL80211_ATTR_SCHED_SCAN_MATCH \
+                        value {:?}",
+                        attr
+                    )
+                }
+            }
+        }
+        Ok(ret)
+    }
+}
+
+impl From<&Nl80211SchedScanMatchSet> for Vec<Nl80211SchedScanMatchNla> {
+    fn from(set: &Nl80211SchedScanMatchSet) -> Self {
+        let mut nlas = Vec::new();
+        if let Some(ssid) = set.ssid.as_ref() {
+            nlas.push(Nl80211SchedScanMatchNla::Ssid(ssid.clone()));
+        }
+        if let Some(bssid) = set.bssid {
+            nlas.push(Nl80211SchedScanMatchNla::Bssid(bssid));
+        }
+        if let Some(rssi) = set.rssi {
+            nlas.push(Nl80211SchedScanMatchNla::Rssi(rssi));
+        }
+        if !set.per_band_rssi.is_empty() {
+            nlas.push(Nl80211SchedScanMatchNla::PerBandRssi(
+                set.per_band_rssi.clone(),
+            ));
+        }
+        nlas
+    }
+}
+
+/// Adjust the RSSI of BSS in specified band before comparing with relative
+/// RSSI threshold.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct Nl80211RssiAdjust {
+    pub band: Nl80211Band,
+    /// RSSI delta in dB
+    pub delta: i8,
+}
+
+impl Nl80211RssiAdjust {
+    pub(crate) const LENGTH: usize = 2;
+
+    pub(crate) fn parse(payload: &[u8]) -> Result<Self, DecodeError> {
+        if payload.len() < Self::LENGTH {
+            return Err(format!(
+                "Invalid length of NL80211_ATTR_SCHED_SCAN_RSSI_ADJUST, \
+                expected length {} got {:?}",
+                Self::LENGTH,
+                payload
+            )
+            .into());
+        }
+        Ok(Self {
+            band: Nl80211Band::from(payload[0] as u32),
+            delta: payload[1] as i8,
+        })
+    }
+}
+
+impl Emitable for Nl80211RssiAdjust {
+    fn buffer_len(&self) -> usize {
+        Self::LENGTH
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        buffer[0] = u32::from(self.band) as u8;
+        buffer[1] = self.delta as u8;
+    }
+}
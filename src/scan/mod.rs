@@ -0,0 +1,23 @@
+// SPDX-License-Identifier: MIT
+
+mod bss;
+mod flags;
+mod get;
+mod handle;
+mod match_set;
+mod schedule;
+mod trigger;
+
+pub use bss::{
+    Nl80211Bss, Nl80211BssNla, Nl80211BssScanWidth, Nl80211BssStatus,
+};
+pub use flags::Nl80211ScanFlags;
+pub use get::Nl80211ScanGetRequest;
+pub use handle::Nl80211ScanHandle;
+pub use match_set::{
+    Nl80211RssiAdjust, Nl80211SchedScanMatchNla, Nl80211SchedScanMatchSet,
+};
+pub use schedule::{
+    Nl80211ScanScheduleRequest, Nl80211ScanScheduleStopRequest,
+};
+pub use trigger::Nl80211ScanTriggerRequest;
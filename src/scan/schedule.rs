@@ -0,0 +1,162 @@
+// SPDX-License-Identifier: MIT
+
+use crate::{
+    nl80211_execute_ack, Nl80211Attr, Nl80211Cmd, Nl80211Error, Nl80211Handle,
+    Nl80211Message, Nl80211RssiAdjust, Nl80211ScanFlags,
+    Nl80211SchedScanMatchSet,
+};
+
+pub struct Nl80211ScanScheduleRequest {
+    handle: Nl80211Handle,
+    if_index: u32,
+    interval: Option<u32>,
+    ssids: Vec<String>,
+    frequencies: Vec<u32>,
+    flags: Option<Nl80211ScanFlags>,
+    random_mac: Option<([u8; 6], [u8; 6])>,
+    match_sets: Vec<Nl80211SchedScanMatchSet>,
+    relative_rssi: Option<i8>,
+    rssi_adjust: Option<Nl80211RssiAdjust>,
+}
+
+impl Nl80211ScanScheduleRequest {
+    pub(crate) fn new(handle: Nl80211Handle, if_index: u32) -> Self {
+        Nl80211ScanScheduleRequest {
+            handle,
+            if_index,
+            interval: None,
+            ssids: Vec::new(),
+            frequencies: Vec::new(),
+            flags: None,
+            random_mac: None,
+            match_sets: Vec::new(),
+            relative_rssi: None,
+            rssi_adjust: None,
+        }
+    }
+
+    /// Interval in milliseconds between scan cycles
+    pub fn interval(mut self, interval: u32) -> Self {
+        self.interval = Some(interval);
+        self
+    }
+
+    /// Probe for specified SSID. If no SSID defined, passive scan will be
+    /// used.
+    pub fn ssid(mut self, ssid: &str) -> Self {
+        self.ssids.push(ssid.to_string());
+        self
+    }
+
+    /// Only scan on specified frequency (MHz)
+    pub fn frequency(mut self, freq: u32) -> Self {
+        self.frequencies.push(freq);
+        self
+    }
+
+    /// Only report BSS matching any of the match sets
+    pub fn match_set(mut self, match_set: Nl80211SchedScanMatchSet) -> Self {
+        self.match_sets.push(match_set);
+        self
+    }
+
+    /// Only report BSS with RSSI better than current connected BSS by
+    /// specified dB
+    pub fn relative_rssi(mut self, relative_rssi: i8) -> Self {
+        self.relative_rssi = Some(relative_rssi);
+        self
+    }
+
+    pub fn rssi_adjust(mut self, rssi_adjust: Nl80211RssiAdjust) -> Self {
+        self.rssi_adjust = Some(rssi_adjust);
+        self
+    }
+
+    /// Scan control flags
+    pub fn flags(mut self, flags: Nl80211ScanFlags) -> Self {
+        self.flags = Some(flags);
+        self
+    }
+
+    /// Use random source MAC address for probe requests. The bits set in
+    /// `mask` are taken from `mac`, the others are randomized, for example
+    /// `ff:ff:ff:00:00:00` keeps the OUI of `mac`.
+    pub fn random_mac(mut self, mac: [u8; 6], mask: [u8; 6]) -> Self {
+        self.random_mac = Some((mac, mask));
+        self
+    }
+
+    pub async fn execute(self) -> Result<(), Nl80211Error> {
+        let Nl80211ScanScheduleRequest {
+            mut handle,
+            if_index,
+            interval,
+            ssids,
+            frequencies,
+            mut flags,
+            random_mac,
+            match_sets,
+            relative_rssi,
+            rssi_adjust,
+        } = self;
+
+        let mut nlas = vec![Nl80211Attr::IfIndex(if_index)];
+        if let Some(interval) = interval {
+            nlas.push(Nl80211Attr::SchedScanInterval(interval));
+        }
+        if !ssids.is_empty() {
+            nlas.push(Nl80211Attr::ScanSsids(ssids));
+        }
+        if !frequencies.is_empty() {
+            nlas.push(Nl80211Attr::ScanFrequencies(frequencies));
+        }
+        if let Some((mac, mask)) = random_mac {
+            flags =
+                Some(flags.unwrap_or_default() | Nl80211ScanFlags::RANDOM_ADDR);
+            nlas.push(Nl80211Attr::Mac(mac));
+            nlas.push(Nl80211Attr::MacMask(mask));
+        }
+        if let Some(flags) = flags {
+            nlas.push(Nl80211Attr::ScanFlags(flags));
+        }
+        if !match_sets.is_empty() {
+            nlas.push(Nl80211Attr::SchedScanMatch(match_sets));
+        }
+        if let Some(relative_rssi) = relative_rssi {
+            nlas.push(Nl80211Attr::SchedScanRelativeRssi(relative_rssi));
+        }
+        if let Some(rssi_adjust) = rssi_adjust {
+            nlas.push(Nl80211Attr::SchedScanRssiAdjust(rssi_adjust));
+        }
+
+        let nl80211_msg = Nl80211Message {
+            cmd: Nl80211Cmd::SchedScanStart,
+            nlas,
+        };
+        nl80211_execute_ack(&mut handle, nl80211_msg).await
+    }
+}
+
+pub struct Nl80211ScanScheduleStopRequest {
+    handle: Nl80211Handle,
+    if_index: u32,
+}
+
+impl Nl80211ScanScheduleStopRequest {
+    pub(crate) fn new(handle: Nl80211Handle, if_index: u32) -> Self {
+        Nl80211ScanScheduleStopRequest { handle, if_index }
+    }
+
+    pub async fn execute(self) -> Result<(), Nl80211Error> {
+        let Nl80211ScanScheduleStopRequest {
+            mut handle,
+            if_index,
+        } = self;
+
+        let nl80211_msg = Nl80211Message {
+            cmd: Nl80211Cmd::SchedScanStop,
+            nlas: vec![Nl80211Attr::IfIndex(if_index)],
+        };
+        nl80211_execute_ack(&mut handle, nl80211_msg).await
+    }
+}
@@ -0,0 +1,115 @@
+// SPDX-License-Identifier: MIT
+
+use crate::{
+    nl80211_execute_ack, Nl80211Attr, Nl80211Cmd, Nl80211Error, Nl80211Handle,
+    Nl80211Message, Nl80211ScanFlags,
+};
+
+pub struct Nl80211ScanTriggerRequest {
+    handle: Nl80211Handle,
+    if_index: u32,
+    ssids: Vec<String>,
+    frequencies: Vec<u32>,
+    flags: Option<Nl80211ScanFlags>,
+    random_mac: Option<([u8; 6], [u8; 6])>,
+    extra_ies: Option<Vec<u8>>,
+    passive: bool,
+}
+
+impl Nl80211ScanTriggerRequest {
+    pub(crate) fn new(handle: Nl80211Handle, if_index: u32) -> Self {
+        Nl80211ScanTriggerRequest {
+            handle,
+            if_index,
+            ssids: Vec::new(),
+            frequencies: Vec::new(),
+            flags: None,
+            random_mac: None,
+            extra_ies: None,
+            passive: false,
+        }
+    }
+
+    /// Probe for specified SSID. If no SSID defined, wildcard SSID will be
+    /// used for active scan.
+    pub fn ssid(mut self, ssid: &str) -> Self {
+        self.ssids.push(ssid.to_string());
+        self
+    }
+
+    /// Only scan on specified frequency (MHz). If no frequency defined, all
+    /// supported frequencies will be scanned.
+    pub fn frequency(mut self, freq: u32) -> Self {
+        self.frequencies.push(freq);
+        self
+    }
+
+    /// Extra information elements appended to the probe request
+    pub fn extra_ies(mut self, ies: Vec<u8>) -> Self {
+        self.extra_ies = Some(ies);
+        self
+    }
+
+    /// Passive scan will not send out probe request, SSIDs defined will be
+    /// ignored.
+    pub fn passive(mut self, passive: bool) -> Self {
+        self.passive = passive;
+        self
+    }
+
+    /// Scan control flags
+    pub fn flags(mut self, flags: Nl80211ScanFlags) -> Self {
+        self.flags = Some(flags);
+        self
+    }
+
+    /// Use random source MAC address for probe requests. The bits set in
+    /// `mask` are taken from `mac`, the others are randomized, for example
+    /// `ff:ff:ff:00:00:00` keeps the OUI of `mac`.
+    pub fn random_mac(mut self, mac: [u8; 6], mask: [u8; 6]) -> Self {
+        self.random_mac = Some((mac, mask));
+        self
+    }
+
+    pub async fn execute(self) -> Result<(), Nl80211Error> {
+        let Nl80211ScanTriggerRequest {
+            mut handle,
+            if_index,
+            mut ssids,
+            frequencies,
+            mut flags,
+            random_mac,
+            extra_ies,
+            passive,
+        } = self;
+
+        let mut nlas = vec![Nl80211Attr::IfIndex(if_index)];
+        if !passive {
+            if ssids.is_empty() {
+                ssids.push(String::new());
+            }
+            nlas.push(Nl80211Attr::ScanSsids(ssids));
+        }
+        if !frequencies.is_empty() {
+            nlas.push(Nl80211Attr::ScanFrequencies(frequencies));
+        }
+        if let Some((mac, mask)) = random_mac {
+            flags =
+                Some(flags.unwrap_or_default() | Nl80211ScanFlags::RANDOM_ADDR);
+            nlas.push(Nl80211Attr::Mac(mac));
+            nlas.push(Nl80211Attr::MacMask(mask));
+        }
+        if let Some(flags) = flags {
+            nlas.push(Nl80211Attr::ScanFlags(flags));
+        }
+        if let Some(ies) = extra_ies {
+            nlas.push(Nl80211Attr::Ie(ies));
+        }
+
+        let nl80211_msg = Nl80211Message {
+            cmd: Nl80211Cmd::ScanTrigger,
+            nlas,
+        };
+        nl80211_execute_ack(&mut handle, nl80211_msg).await
+    }
+}
@@ -0,0 +1,173 @@
+// SPDX-License-Identifier: MIT
+
+use byteorder::{ByteOrder, NativeEndian};
+use netlink_packet_utils::{DecodeError, Emitable};
+
+const NL80211_STA_FLAG_AUTHORIZED: u16 = 1;
+const NL80211_STA_FLAG_SHORT_PREAMBLE: u16 = 2;
+const NL80211_STA_FLAG_WME: u16 = 3;
+const NL80211_STA_FLAG_MFP: u16 = 4;
+const NL80211_STA_FLAG_AUTHENTICATED: u16 = 5;
+const NL80211_STA_FLAG_TDLS_PEER: u16 = 6;
+const NL80211_STA_FLAG_ASSOCIATED: u16 = 7;
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Nl80211StationFlag {
+    /// Station is authorized (802.1X)
+    Authorized,
+    /// Station is capable of receiving frames with short barker preamble
+    ShortPreamble,
+    /// Station is WME/QoS capable
+    Wme,
+    /// Station uses management frame protection
+    Mfp,
+    /// Station is authenticated
+    Authenticated,
+    /// Station is a TDLS peer
+    TdlsPeer,
+    /// Station is associated
+    Associated,
+    Other(u16),
+}
+
+impl From<u16> for Nl80211StationFlag {
+    fn from(d: u16) -> Self {
+        match d {
+            NL80211_STA_FLAG_AUTHORIZED => Self::Authorized,
+            NL80211_STA_FLAG_SHORT_PREAMBLE => Self::ShortPreamble,
+            NL80211_STA_FLAG_WME => Self::Wme,
+            NL80211_STA_FLAG_MFP => Self::Mfp,
+            NL80211_STA_FLAG_AUTHENTICATED => Self::Authenticated,
+            NL80211_STA_FLAG_TDLS_PEER => Self::TdlsPeer,
+            NL80211_STA_FLAG_ASSOCIATED => Self::Associated,
+            _ => Self::Other(d),
+        }
+    }
+}
+
+impl From<Nl80211StationFlag> for u16 {
+    fn from(v: Nl80211StationFlag) -> u16 {
+        match v {
+            Nl80211StationFlag::Authorized => NL80211_STA_FLAG_AUTHORIZED,
+            Nl80211StationFlag::ShortPreamble => {
+                NL80211_STA_FLAG_SHORT_PREAMBLE
+            }
+            Nl80211StationFlag::Wme => NL80211_STA_FLAG_WME,
+            Nl80211StationFlag::Mfp => NL80211_STA_FLAG_MFP,
+            Nl80211StationFlag::Authenticated => NL80211_STA_FLAG_AUTHENTICATED,
+            Nl80211StationFlag::TdlsPeer => NL80211_STA_FLAG_TDLS_PEER,
+            Nl80211StationFlag::Associated => NL80211_STA_FLAG_ASSOCIATED,
+            Nl80211StationFlag::Other(d) => d,
+        }
+    }
+}
+
+/// Station flags to change, only the flags included in the mask are
+/// modified.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+pub struct Nl80211StationFlagUpdate {
+    /// Bit mask of the station flags to change
+    pub mask: u32,
+    /// Value of the station flags in `mask`
+    pub set: u32,
+}
+
+impl Nl80211StationFlagUpdate {
+    pub(crate) const LENGTH: usize = 8;
+
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    // Bit of the flag in the u32 bitmaps, `None` for flags out of range
+    fn flag_bit(flag: Nl80211StationFlag) -> Option<u32> {
+        1u32.checked_shl(u16::from(flag).into())
+    }
+
+    /// Set or clear specified station flag, flags not fitting in the 32 bits
+    /// bitmap are ignored
+    pub fn flag(mut self, flag: Nl80211StationFlag, enabled: bool) -> Self {
+        let bit = match Self::flag_bit(flag) {
+            Some(bit) => bit,
+            None => {
+                log::warn!("Ignoring out of range station flag {:?}", flag);
+                return self;
+            }
+        };
+        self.mask |= bit;
+        if enabled {
+            self.set |= bit;
+        } else {
+            self.set &= !bit;
+        }
+        self
+    }
+
+    /// Whether specified station flag is set, `None` if the flag is not
+    /// included in the mask
+    pub fn get(&self, flag: Nl80211StationFlag) -> Option<bool> {
+        let bit = Self::flag_bit(flag)?;
+        if self.mask & bit > 0 {
+            Some(self.set & bit > 0)
+        } else {
+            None
+        }
+    }
+
+    pub(crate) fn parse(payload: &[u8]) -> Result<Self, DecodeError> {
+        if payload.len() < Self::LENGTH {
+            return Err(format!(
+                "Invalid length of struct nl80211_sta_flag_update, \
+                expected length {} got {:?}",
+                Self::LENGTH,
+                payload
+            )
+            .into());
+        }
+        Ok(Self {
+            mask: NativeEndian::read_u32(&payload[..4]),
+            set: NativeEndian::read_u32(&payload[4..8]),
+        })
+    }
+}
+
+impl Emitable for Nl80211StationFlagUpdate {
+    fn buffer_len(&self) -> usize {
+        Self::LENGTH
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        NativeEndian::write_u32(&mut buffer[..4], self.mask);
+        NativeEndian::write_u32(&mut buffer[4..8], self.set);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn flag_update_round_trip() {
+        let update = Nl80211StationFlagUpdate::new()
+            .flag(Nl80211StationFlag::Authorized, true)
+            .flag(Nl80211StationFlag::Mfp, false);
+        assert_eq!(update.get(Nl80211StationFlag::Authorized), Some(true));
+        assert_eq!(update.get(Nl80211StationFlag::Mfp), Some(false));
+        assert_eq!(update.get(Nl80211StationFlag::Wme), None);
+
+        let mut buffer = vec![0u8; update.buffer_len()];
+        update.emit(&mut buffer);
+        assert_eq!(Nl80211StationFlagUpdate::parse(&buffer).unwrap(), update);
+    }
+
+    #[test]
+    fn flag_update_out_of_range() {
+        let update = Nl80211StationFlagUpdate::new()
+            .flag(Nl80211StationFlag::Other(31), true)
+            .flag(Nl80211StationFlag::Other(32), true)
+            .flag(Nl80211StationFlag::Other(u16::MAX), true);
+        assert_eq!(update.mask, 1 << 31);
+        assert_eq!(update.set, 1 << 31);
+        assert_eq!(update.get(Nl80211StationFlag::Other(32)), None);
+    }
+}
@@ -0,0 +1,32 @@
+// SPDX-License-Identifier: MIT
+
+use futures::TryStream;
+use netlink_packet_core::{NLM_F_DUMP, NLM_F_REQUEST};
+use netlink_packet_generic::GenlMessage;
+
+use crate::{nl80211_execute, Nl80211Error, Nl80211Handle, Nl80211Message};
+
+pub struct Nl80211StationGetRequest {
+    handle: Nl80211Handle,
+    if_index: u32,
+}
+
+impl Nl80211StationGetRequest {
+    pub(crate) fn new(handle: Nl80211Handle, if_index: u32) -> Self {
+        Nl80211StationGetRequest { handle, if_index }
+    }
+
+    pub async fn execute(
+        self,
+    ) -> impl TryStream<Ok = GenlMessage<Nl80211Message>, Error = Nl80211Error>
+    {
+        let Nl80211StationGetRequest {
+            mut handle,
+            if_index,
+        } = self;
+
+        let nl80211_msg = Nl80211Message::new_station_get(if_index);
+        nl80211_execute(&mut handle, nl80211_msg, NLM_F_REQUEST | NLM_F_DUMP)
+            .await
+    }
+}
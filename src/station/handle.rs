@@ -0,0 +1,48 @@
+// SPDX-License-Identifier: MIT
+
+use crate::{
+    Nl80211Handle, Nl80211StationGetRequest, Nl80211StationNewRequest,
+    Nl80211StationSetRequest,
+};
+
+pub struct Nl80211StationHandle(Nl80211Handle);
+
+impl Nl80211StationHandle {
+    pub fn new(handle: Nl80211Handle) -> Self {
+        Nl80211StationHandle(handle)
+    }
+
+    /// Retrieve the stations associated with specified interface
+    /// (equivalent to `iw dev DEVICE station dump`)
+    pub fn dump(&mut self, if_index: u32) -> Nl80211StationGetRequest {
+        Nl80211StationGetRequest::new(self.0.clone(), if_index)
+    }
+
+    /// Change the parameters of specified station
+    /// (equivalent to `iw dev DEVICE station set MAC_ADDRESS ...`)
+    pub fn set(
+        &mut self,
+        if_index: u32,
+        mac: [u8; 6],
+    ) -> Nl80211StationSetRequest {
+        Nl80211StationSetRequest::new(self.0.clone(), if_index, mac)
+    }
+
+    /// Add a station to specified AP or mesh interface, used by userspace
+    /// mesh peering management to add the mesh peers
+    pub fn add(
+        &mut self,
+        if_index: u32,
+        mac: [u8; 6],
+        supported_rates: Vec<u8>,
+        listen_interval: u16,
+    ) -> Nl80211StationNewRequest {
+        Nl80211StationNewRequest::new(
+            self.0.clone(),
+            if_index,
+            mac,
+            supported_rates,
+            listen_interval,
+        )
+    }
+}
@@ -0,0 +1,22 @@
+// SPDX-License-Identifier: MIT
+
+mod flags;
+mod get;
+mod handle;
+mod new;
+mod plink;
+mod rate_info;
+mod set;
+mod station_info;
+
+pub use flags::{Nl80211StationFlag, Nl80211StationFlagUpdate};
+pub use get::Nl80211StationGetRequest;
+pub use handle::Nl80211StationHandle;
+pub use new::Nl80211StationNewRequest;
+pub use plink::{Nl80211PlinkAction, Nl80211PlinkState};
+pub use rate_info::{
+    Nl80211GuardInterval, Nl80211RateInfo, Nl80211RateInfoBandwidth,
+    Nl80211RateInfoNla,
+};
+pub use set::Nl80211StationSetRequest;
+pub use station_info::{Nl80211StationInfo, Nl80211StationInfoNla};
@@ -0,0 +1,105 @@
+// SPDX-License-Identifier: MIT
+
+use crate::{
+    nl80211_execute_ack, Nl80211Attr, Nl80211Cmd, Nl80211Error, Nl80211Handle,
+    Nl80211Message, Nl80211PlinkAction, Nl80211PlinkState, Nl80211StationFlag,
+    Nl80211StationFlagUpdate,
+};
+
+pub struct Nl80211StationNewRequest {
+    handle: Nl80211Handle,
+    if_index: u32,
+    mac: [u8; 6],
+    supported_rates: Vec<u8>,
+    listen_interval: u16,
+    aid: Option<u16>,
+    flags: Option<Nl80211StationFlagUpdate>,
+    plink_action: Option<Nl80211PlinkAction>,
+    plink_state: Option<Nl80211PlinkState>,
+}
+
+impl Nl80211StationNewRequest {
+    pub(crate) fn new(
+        handle: Nl80211Handle,
+        if_index: u32,
+        mac: [u8; 6],
+        supported_rates: Vec<u8>,
+        listen_interval: u16,
+    ) -> Self {
+        Nl80211StationNewRequest {
+            handle,
+            if_index,
+            mac,
+            supported_rates,
+            listen_interval,
+            aid: None,
+            flags: None,
+            plink_action: None,
+            plink_state: None,
+        }
+    }
+
+    /// Association ID of the station, or the AID assigned by the mesh peer
+    pub fn aid(mut self, aid: u16) -> Self {
+        self.aid = Some(aid);
+        self
+    }
+
+    /// Set or clear specified station flag
+    pub fn flag(mut self, flag: Nl80211StationFlag, enabled: bool) -> Self {
+        self.flags = Some(self.flags.unwrap_or_default().flag(flag, enabled));
+        self
+    }
+
+    /// Action to perform on the mesh peer link
+    pub fn plink_action(mut self, action: Nl80211PlinkAction) -> Self {
+        self.plink_action = Some(action);
+        self
+    }
+
+    /// Initial state of the mesh peer link, only allowed when mesh peering
+    /// management is handled by userspace
+    pub fn plink_state(mut self, state: Nl80211PlinkState) -> Self {
+        self.plink_state = Some(state);
+        self
+    }
+
+    pub async fn execute(self) -> Result<(), Nl80211Error> {
+        let Nl80211StationNewRequest {
+            mut handle,
+            if_index,
+            mac,
+            supported_rates,
+            listen_interval,
+            aid,
+            flags,
+            plink_action,
+            plink_state,
+        } = self;
+
+        let mut nlas = vec![
+            Nl80211Attr::IfIndex(if_index),
+            Nl80211Attr::Mac(mac),
+            Nl80211Attr::StationSupportedRates(supported_rates),
+            Nl80211Attr::StationListenInterval(listen_interval),
+        ];
+        if let Some(aid) = aid {
+            nlas.push(Nl80211Attr::StationAid(aid));
+        }
+        if let Some(flags) = flags {
+            nlas.push(Nl80211Attr::StationFlags2(flags));
+        }
+        if let Some(action) = plink_action {
+            nlas.push(Nl80211Attr::StationPlinkAction(action));
+        }
+        if let Some(state) = plink_state {
+            nlas.push(Nl80211Attr::StationPlinkState(state));
+        }
+
+        let nl80211_msg = Nl80211Message {
+            cmd: Nl80211Cmd::StationNew,
+            nlas,
+        };
+        nl80211_execute_ack(&mut handle, nl80211_msg).await
+    }
+}
@@ -0,0 +1,96 @@
+// SPDX-License-Identifier: MIT
+
+const NL80211_PLINK_ACTION_NO_ACTION: u8 = 0;
+const NL80211_PLINK_ACTION_OPEN: u8 = 1;
+const NL80211_PLINK_ACTION_BLOCK: u8 = 2;
+
+/// Action to perform on the mesh peer link
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Nl80211PlinkAction {
+    NoAction,
+    Open,
+    Block,
+    Other(u8),
+}
+
+impl From<u8> for Nl80211PlinkAction {
+    fn from(d: u8) -> Self {
+        match d {
+            NL80211_PLINK_ACTION_NO_ACTION => Self::NoAction,
+            NL80211_PLINK_ACTION_OPEN => Self::Open,
+            NL80211_PLINK_ACTION_BLOCK => Self::Block,
+            _ => Self::Other(d),
+        }
+    }
+}
+
+impl From<Nl80211PlinkAction> for u8 {
+    fn from(v: Nl80211PlinkAction) -> u8 {
+        match v {
+            Nl80211PlinkAction::NoAction => NL80211_PLINK_ACTION_NO_ACTION,
+            Nl80211PlinkAction::Open => NL80211_PLINK_ACTION_OPEN,
+            Nl80211PlinkAction::Block => NL80211_PLINK_ACTION_BLOCK,
+            Nl80211PlinkAction::Other(d) => d,
+        }
+    }
+}
+
+const NL80211_PLINK_LISTEN: u8 = 0;
+const NL80211_PLINK_OPN_SNT: u8 = 1;
+const NL80211_PLINK_OPN_RCVD: u8 = 2;
+const NL80211_PLINK_CNF_RCVD: u8 = 3;
+const NL80211_PLINK_ESTAB: u8 = 4;
+const NL80211_PLINK_HOLDING: u8 = 5;
+const NL80211_PLINK_BLOCKED: u8 = 6;
+
+/// State of the mesh peer link
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Nl80211PlinkState {
+    /// Initial state, considered the implicit state of non existent mesh peer
+    /// links
+    Listen,
+    /// Mesh plink open frame has been sent to this mesh peer
+    OpenSent,
+    /// Mesh plink open frame has been received from this mesh peer
+    OpenReceived,
+    /// Mesh plink confirm frame has been received from this mesh peer
+    ConfirmReceived,
+    /// Mesh peer link is established
+    Established,
+    /// Mesh peer link is being closed or cancelled
+    Holding,
+    /// All frames transmitted from this mesh plink are discarded, except for
+    /// authentication frames
+    Blocked,
+    Other(u8),
+}
+
+impl From<u8> for Nl80211PlinkState {
+    fn from(d: u8) -> Self {
+        match d {
+            NL80211_PLINK_LISTEN => Self::Listen,
+            NL80211_PLINK_OPN_SNT => Self::OpenSent,
+            NL80211_PLINK_OPN_RCVD => Self::OpenReceived,
+            NL80211_PLINK_CNF_RCVD => Self::ConfirmReceived,
+            NL80211_PLINK_ESTAB => Self::Established,
+            NL80211_PLINK_HOLDING => Self::Holding,
+            NL80211_PLINK_BLOCKED => Self::Blocked,
+            _ => Self::Other(d),
+        }
+    }
+}
+
+impl From<Nl80211PlinkState> for u8 {
+    fn from(v: Nl80211PlinkState) -> u8 {
+        match v {
+            Nl80211PlinkState::Listen => NL80211_PLINK_LISTEN,
+            Nl80211PlinkState::OpenSent => NL80211_PLINK_OPN_SNT,
+            Nl80211PlinkState::OpenReceived => NL80211_PLINK_OPN_RCVD,
+            Nl80211PlinkState::ConfirmReceived => NL80211_PLINK_CNF_RCVD,
+            Nl80211PlinkState::Established => NL80211_PLINK_ESTAB,
+            Nl80211PlinkState::Holding => NL80211_PLINK_HOLDING,
+            Nl80211PlinkState::Blocked => NL80211_PLINK_BLOCKED,
+            Nl80211PlinkState::Other(d) => d,
+        }
+    }
+}
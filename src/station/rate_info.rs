@@ -0,0 +1,530 @@
+// SPDX-License-Identifier: MIT
+
+use anyhow::Context;
+use byteorder::{ByteOrder, NativeEndian};
+use netlink_packet_utils::{
+    nla::{DefaultNla, Nla, NlaBuffer, NlasIterator},
+    parsers::{parse_u16, parse_u32, parse_u8},
+    DecodeError, Emitable, Parseable,
+};
+
+const NL80211_RATE_INFO_BITRATE: u16 = 1;
+const NL80211_RATE_INFO_MCS: u16 = 2;
+const NL80211_RATE_INFO_40_MHZ_WIDTH: u16 = 3;
+const NL80211_RATE_INFO_SHORT_GI: u16 = 4;
+const NL80211_RATE_INFO_BITRATE32: u16 = 5;
+const NL80211_RATE_INFO_VHT_MCS: u16 = 6;
+const NL80211_RATE_INFO_VHT_NSS: u16 = 7;
+const NL80211_RATE_INFO_80_MHZ_WIDTH: u16 = 8;
+const NL80211_RATE_INFO_80P80_MHZ_WIDTH: u16 = 9;
+const NL80211_RATE_INFO_160_MHZ_WIDTH: u16 = 10;
+const NL80211_RATE_INFO_10_MHZ_WIDTH: u16 = 11;
+const NL80211_RATE_INFO_5_MHZ_WIDTH: u16 = 12;
+const NL80211_RATE_INFO_HE_MCS: u16 = 13;
+const NL80211_RATE_INFO_HE_NSS: u16 = 14;
+const NL80211_RATE_INFO_HE_GI: u16 = 15;
+const NL80211_RATE_INFO_HE_DCM: u16 = 16;
+const NL80211_RATE_INFO_HE_RU_ALLOC: u16 = 17;
+const NL80211_RATE_INFO_320_MHZ_WIDTH: u16 = 18;
+const NL80211_RATE_INFO_EHT_MCS: u16 = 19;
+const NL80211_RATE_INFO_EHT_NSS: u16 = 20;
+const NL80211_RATE_INFO_EHT_GI: u16 = 21;
+const NL80211_RATE_INFO_EHT_RU_ALLOC: u16 = 22;
+
+// `enum nl80211_eht_gi` shares the values of `enum nl80211_he_gi`
+const NL80211_RATE_INFO_HE_GI_0_8: u8 = 0;
+const NL80211_RATE_INFO_HE_GI_1_6: u8 = 1;
+const NL80211_RATE_INFO_HE_GI_3_2: u8 = 2;
+
+/// Guard interval of HE and EHT rates
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Nl80211GuardInterval {
+    /// 0.8 usec
+    Nanos800,
+    /// 1.6 usec
+    Nanos1600,
+    /// 3.2 usec
+    Nanos3200,
+    Other(u8),
+}
+
+impl From<u8> for Nl80211GuardInterval {
+    fn from(d: u8) -> Self {
+        match d {
+            NL80211_RATE_INFO_HE_GI_0_8 => Self::Nanos800,
+            NL80211_RATE_INFO_HE_GI_1_6 => Self::Nanos1600,
+            NL80211_RATE_INFO_HE_GI_3_2 => Self::Nanos3200,
+            _ => Self::Other(d),
+        }
+    }
+}
+
+impl From<Nl80211GuardInterval> for u8 {
+    fn from(v: Nl80211GuardInterval) -> u8 {
+        match v {
+            Nl80211GuardInterval::Nanos800 => NL80211_RATE_INFO_HE_GI_0_8,
+            Nl80211GuardInterval::Nanos1600 => NL80211_RATE_INFO_HE_GI_1_6,
+            Nl80211GuardInterval::Nanos3200 => NL80211_RATE_INFO_HE_GI_3_2,
+            Nl80211GuardInterval::Other(d) => d,
+        }
+    }
+}
+
+/// Channel width used for transmitting the frame
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+pub enum Nl80211RateInfoBandwidth {
+    Mhz5,
+    Mhz10,
+    #[default]
+    Mhz20,
+    Mhz40,
+    Mhz80,
+    Mhz80P80,
+    Mhz160,
+    Mhz320,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum Nl80211RateInfoNla {
+    /// Total bitrate in unit of 100 kbps
+    Bitrate(u16),
+    /// Total bitrate in unit of 100 kbps, used when it cannot fit into
+    /// `Bitrate`
+    Bitrate32(u32),
+    /// HT MCS index
+    Mcs(u8),
+    Width40Mhz,
+    ShortGi,
+    VhtMcs(u8),
+    VhtNss(u8),
+    Width80Mhz,
+    Width80P80Mhz,
+    Width160Mhz,
+    Width10Mhz,
+    Width5Mhz,
+    HeMcs(u8),
+    HeNss(u8),
+    HeGi(Nl80211GuardInterval),
+    /// HE DCM value(0 or 1)
+    HeDcm(u8),
+    /// HE RU allocation, index of `enum nl80211_he_ru_alloc`
+    HeRuAlloc(u8),
+    Width320Mhz,
+    EhtMcs(u8),
+    EhtNss(u8),
+    EhtGi(Nl80211GuardInterval),
+    /// EHT RU allocation, index of `enum nl80211_eht_ru_alloc`
+    EhtRuAlloc(u8),
+    Other(DefaultNla),
+}
+
+impl Nla for Nl80211RateInfoNla {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::Width40Mhz
+            | Self::ShortGi
+            | Self::Width80Mhz
+            | Self::Width80P80Mhz
+            | Self::Width160Mhz
+            | Self::Width10Mhz
+            | Self::Width5Mhz
+            | Self::Width320Mhz => 0,
+            Self::Mcs(_)
+            | Self::VhtMcs(_)
+            | Self::VhtNss(_)
+            | Self::HeMcs(_)
+            | Self::HeNss(_)
+            | Self::HeGi(_)
+            | Self::HeDcm(_)
+            | Self::HeRuAlloc(_)
+            | Self::EhtMcs(_)
+            | Self::EhtNss(_)
+            | Self::EhtGi(_)
+            | Self::EhtRuAlloc(_) => 1,
+            Self::Bitrate(_) => 2,
+            Self::Bitrate32(_) => 4,
+            Self::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::Bitrate(_) => NL80211_RATE_INFO_BITRATE,
+            Self::Mcs(_) => NL80211_RATE_INFO_MCS,
+            Self::Width40Mhz => NL80211_RATE_INFO_40_MHZ_WIDTH,
+            Self::ShortGi => NL80211_RATE_INFO_SHORT_GI,
+            Self::Bitrate32(_) => NL80211_RATE_INFO_BITRATE32,
+            Self::VhtMcs(_) => NL80211_RATE_INFO_VHT_MCS,
+            Self::VhtNss(_) => NL80211_RATE_INFO_VHT_NSS,
+            Self::Width80Mhz => NL80211_RATE_INFO_80_MHZ_WIDTH,
+            Self::Width80P80Mhz => NL80211_RATE_INFO_80P80_MHZ_WIDTH,
+            Self::Width160Mhz => NL80211_RATE_INFO_160_MHZ_WIDTH,
+            Self::Width10Mhz => NL80211_RATE_INFO_10_MHZ_WIDTH,
+            Self::Width5Mhz => NL80211_RATE_INFO_5_MHZ_WIDTH,
+            Self::HeMcs(_) => NL80211_RATE_INFO_HE_MCS,
+            Self::HeNss(_) => NL80211_RATE_INFO_HE_NSS,
+            Self::HeGi(_) => NL80211_RATE_INFO_HE_GI,
+            Self::HeDcm(_) => NL80211_RATE_INFO_HE_DCM,
+            Self::HeRuAlloc(_) => NL80211_RATE_INFO_HE_RU_ALLOC,
+            Self::Width320Mhz => NL80211_RATE_INFO_320_MHZ_WIDTH,
+            Self::EhtMcs(_) => NL80211_RATE_INFO_EHT_MCS,
+            Self::EhtNss(_) => NL80211_RATE_INFO_EHT_NSS,
+            Self::EhtGi(_) => NL80211_RATE_INFO_EHT_GI,
+            Self::EhtRuAlloc(_) => NL80211_RATE_INFO_EHT_RU_ALLOC,
+            Self::Other(attr) => attr.kind(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Width40Mhz
+            | Self::ShortGi
+            | Self::Width80Mhz
+            | Self::Width80P80Mhz
+            | Self::Width160Mhz
+            | Self::Width10Mhz
+            | Self::Width5Mhz
+            | Self::Width320Mhz => (),
+            Self::Mcs(d)
+            | Self::VhtMcs(d)
+            | Self::VhtNss(d)
+            | Self::HeMcs(d)
+            | Self::HeNss(d)
+            | Self::HeDcm(d)
+            | Self::HeRuAlloc(d)
+            | Self::EhtMcs(d)
+            | Self::EhtNss(d)
+            | Self::EhtRuAlloc(d) => buffer[0] = *d,
+            Self::HeGi(d) | Self::EhtGi(d) => buffer[0] = (*d).into(),
+            Self::Bitrate(d) => NativeEndian::write_u16(buffer, *d),
+            Self::Bitrate32(d) => NativeEndian::write_u32(buffer, *d),
+            Self::Other(ref attr) => attr.emit(buffer),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for Nl80211RateInfoNla
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            NL80211_RATE_INFO_BITRATE => {
+                let err_msg = format!(
+                    "Invalid NL80211_RATE_INFO_BITRATE value {:?}",
+                    payload
+                );
+                Self::Bitrate(parse_u16(payload).context(err_msg)?)
+            }
+            NL80211_RATE_INFO_MCS => {
+                let err_msg = format!(
+                    "Invalid NL80211_RATE_INFO_MCS value {:?}",
+                    payload
+                );
+                Self::Mcs(parse_u8(payload).context(err_msg)?)
+            }
+            NL80211_RATE_INFO_40_MHZ_WIDTH => Self::Width40Mhz,
+            NL80211_RATE_INFO_SHORT_GI => Self::ShortGi,
+            NL80211_RATE_INFO_BITRATE32 => {
+                let err_msg = format!(
+                    "Invalid NL80211_RATE_INFO_BITRATE32 value {:?}",
+                    payload
+                );
+                Self::Bitrate32(parse_u32(payload).context(err_msg)?)
+            }
+            NL80211_RATE_INFO_VHT_MCS => {
+                let err_msg = format!(
+                    "Invalid NL80211_RATE_INFO_VHT_MCS value {:?}",
+                    payload
+                );
+                Self::VhtMcs(parse_u8(payload).context(err_msg)?)
+            }
+            NL80211_RATE_INFO_VHT_NSS => {
+                let err_msg = format!(
+                    "Invalid NL80211_RATE_INFO_VHT_NSS value {:?}",
+                    payload
+                );
+                Self::VhtNss(parse_u8(payload).context(err_msg)?)
+            }
+            NL80211_RATE_INFO_80_MHZ_WIDTH => Self::Width80Mhz,
+            NL80211_RATE_INFO_80P80_MHZ_WIDTH => Self::Width80P80Mhz,
+            NL80211_RATE_INFO_160_MHZ_WIDTH => Self::Width160Mhz,
+            NL80211_RATE_INFO_10_MHZ_WIDTH => Self::Width10Mhz,
+            NL80211_RATE_INFO_5_MHZ_WIDTH => Self::Width5Mhz,
+            NL80211_RATE_INFO_HE_MCS => {
+                let err_msg = format!(
+                    "Invalid NL80211_RATE_INFO_HE_MCS value {:?}",
+                    payload
+                );
+                Self::HeMcs(parse_u8(payload).context(err_msg)?)
+            }
+            NL80211_RATE_INFO_HE_NSS => {
+                let err_msg = format!(
+                    "Invalid NL80211_RATE_INFO_HE_NSS value {:?}",
+                    payload
+                );
+                Self::HeNss(parse_u8(payload).context(err_msg)?)
+            }
+            NL80211_RATE_INFO_HE_GI => {
+                let err_msg = format!(
+                    "Invalid NL80211_RATE_INFO_HE_GI value {:?}",
+                    payload
+                );
+                Self::HeGi(parse_u8(payload).context(err_msg)?.into())
+            }
+            NL80211_RATE_INFO_HE_DCM => {
+                let err_msg = format!(
+                    "Invalid NL80211_RATE_INFO_HE_DCM value {:?}",
+                    payload
+                );
+                Self::HeDcm(parse_u8(payload).context(err_msg)?)
+            }
+            NL80211_RATE_INFO_HE_RU_ALLOC => {
+                let err_msg = format!(
+                    "Invalid NL80211_RATE_INFO_HE_RU_ALLOC value {:?}",
+                    payload
+                );
+                Self::HeRuAlloc(parse_u8(payload).context(err_msg)?)
+            }
+            NL80211_RATE_INFO_320_MHZ_WIDTH => Self::Width320Mhz,
+            NL80211_RATE_INFO_EHT_MCS => {
+                let err_msg = format!(
+                    "Invalid NL80211_RATE_INFO_EHT_MCS value {:?}",
+                    payload
+                );
+                Self::EhtMcs(parse_u8(payload).context(err_msg)?)
+            }
+            NL80211_RATE_INFO_EHT_NSS => {
+                let err_msg = format!(
+                    "Invalid NL80211_RATE_INFO_EHT_NSS value {:?}",
+                    payload
+                );
+                Self::EhtNss(parse_u8(payload).context(err_msg)?)
+            }
+            NL80211_RATE_INFO_EHT_GI => {
+                let err_msg = format!(
+                    "Invalid NL80211_RATE_INFO_EHT_GI value {:?}",
+                    payload
+                );
+                Self::EhtGi(parse_u8(payload).context(err_msg)?.into())
+            }
+            NL80211_RATE_INFO_EHT_RU_ALLOC => {
+                let err_msg = format!(
+                    "Invalid NL80211_RATE_INFO_EHT_RU_ALLOC value {:?}",
+                    payload
+                );
+                Self::EhtRuAlloc(parse_u8(payload).context(err_msg)?)
+            }
+            _ => Self::Other(
+                DefaultNla::parse(buf).context("invalid NLA (unknown kind)")?,
+            ),
+        })
+    }
+}
+
+/// Bitrate information of a station
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct Nl80211RateInfo {
+    /// Total bitrate in unit of 100 kbps
+    pub bitrate: Option<u16>,
+    /// Total bitrate in unit of 100 kbps
+    pub bitrate32: Option<u32>,
+    pub bandwidth: Nl80211RateInfoBandwidth,
+    pub short_gi: bool,
+    /// HT MCS index
+    pub mcs: Option<u8>,
+    pub vht_mcs: Option<u8>,
+    pub vht_nss: Option<u8>,
+    pub he_mcs: Option<u8>,
+    pub he_nss: Option<u8>,
+    pub he_gi: Option<Nl80211GuardInterval>,
+    pub he_dcm: Option<u8>,
+    pub he_ru_alloc: Option<u8>,
+    pub eht_mcs: Option<u8>,
+    pub eht_nss: Option<u8>,
+    pub eht_gi: Option<Nl80211GuardInterval>,
+    pub eht_ru_alloc: Option<u8>,
+}
+
+impl Nl80211RateInfo {
+    /// Total bitrate in kbps, prefer the 32 bits value when available.
+    /// `None` if not reported or exceeding `u32::MAX` kbps.
+    pub fn bitrate_kbps(&self) -> Option<u32> {
+        self.bitrate32
+            .or(self.bitrate.map(u32::from))
+            .and_then(|rate| rate.checked_mul(100))
+    }
+}
+
+impl Emitable for Nl80211RateInfo {
+    fn buffer_len(&self) -> usize {
+        Vec::<Nl80211RateInfoNla>::from(self)
+            .as_slice()
+            .buffer_len()
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        Vec::<Nl80211RateInfoNla>::from(self)
+            .as_slice()
+            .emit(buffer)
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for Nl80211RateInfo
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let mut ret = Self::default();
+        let payload = buf.value();
+        let err_msg = format!("Invalid NL80211_STA_INFO bitrate {:?}", payload);
+        for nla in NlasIterator::new(payload) {
+            let nla = &nla.context(err_msg.clone())?;
+            match Nl80211RateInfoNla::parse(nla).context(err_msg.clone())? {
+                Nl80211RateInfoNla::Bitrate(d) => ret.bitrate = Some(d),
+                Nl80211RateInfoNla::Bitrate32(d) => ret.bitrate32 = Some(d),
+                Nl80211RateInfoNla::Mcs(d) => ret.mcs = Some(d),
+                Nl80211RateInfoNla::Width40Mhz => {
+                    ret.bandwidth = Nl80211RateInfoBandwidth::Mhz40
+                }
+                Nl80211RateInfoNla::ShortGi => ret.short_gi = true,
+                Nl80211RateInfoNla::VhtMcs(d) => ret.vht_mcs = Some(d),
+                Nl80211RateInfoNla::VhtNss(d) => ret.vht_nss = Some(d),
+                Nl80211RateInfoNla::Width80Mhz => {
+                    ret.bandwidth = Nl80211RateInfoBandwidth::Mhz80
+                }
+                Nl80211RateInfoNla::Width80P80Mhz => {
+                    ret.bandwidth = Nl80211RateInfoBandwidth::Mhz80P80
+                }
+                Nl80211RateInfoNla::Width160Mhz => {
+                    ret.bandwidth = Nl80211RateInfoBandwidth::Mhz160
+                }
+                Nl80211RateInfoNla::Width10Mhz => {
+                    ret.bandwidth = Nl80211RateInfoBandwidth::Mhz10
+                }
+                Nl80211RateInfoNla::Width5Mhz => {
+                    ret.bandwidth = Nl80211RateInfoBandwidth::Mhz5
+                }
+                Nl80211RateInfoNla::HeMcs(d) => ret.he_mcs = Some(d),
+                Nl80211RateInfoNla::HeNss(d) => ret.he_nss = Some(d),
+                Nl80211RateInfoNla::HeGi(d) => ret.he_gi = Some(d),
+                Nl80211RateInfoNla::HeDcm(d) => ret.he_dcm = Some(d),
+                Nl80211RateInfoNla::HeRuAlloc(d) => ret.he_ru_alloc = Some(d),
+                Nl80211RateInfoNla::Width320Mhz => {
+                    ret.bandwidth = Nl80211RateInfoBandwidth::Mhz320
+                }
+                Nl80211RateInfoNla::EhtMcs(d) => ret.eht_mcs = Some(d),
+                Nl80211RateInfoNla::EhtNss(d) => ret.eht_nss = Some(d),
+                Nl80211RateInfoNla::EhtGi(d) => ret.eht_gi = Some(d),
+                Nl80211RateInfoNla::EhtRuAlloc(d) => ret.eht_ru_alloc = Some(d),
+                Nl80211RateInfoNla::Other(attr) => {
+                    log::warn!(
+                        "Got unsupported NL80211_STA_INFO bitrate value {:?}",
+                        attr
+                    )
+                }
+            }
+        }
+        Ok(ret)
+    }
+}
+
+impl From<&Nl80211RateInfo> for Vec<Nl80211RateInfoNla> {
+    fn from(info: &Nl80211RateInfo) -> Self {
+        let mut nlas = Vec::new();
+        if let Some(v) = info.bitrate {
+            nlas.push(Nl80211RateInfoNla::Bitrate(v));
+        }
+        if let Some(v) = info.bitrate32 {
+            nlas.push(Nl80211RateInfoNla::Bitrate32(v));
+        }
+        match info.bandwidth {
+            Nl80211RateInfoBandwidth::Mhz5 => {
+                nlas.push(Nl80211RateInfoNla::Width5Mhz)
+            }
+            Nl80211RateInfoBandwidth::Mhz10 => {
+                nlas.push(Nl80211RateInfoNla::Width10Mhz)
+            }
+            Nl80211RateInfoBandwidth::Mhz20 => (),
+            Nl80211RateInfoBandwidth::Mhz40 => {
+                nlas.push(Nl80211RateInfoNla::Width40Mhz)
+            }
+            Nl80211RateInfoBandwidth::Mhz80 => {
+                nlas.push(Nl80211RateInfoNla::Width80Mhz)
+            }
+            Nl80211RateInfoBandwidth::Mhz80P80 => {
+                nlas.push(Nl80211RateInfoNla::Width80P80Mhz)
+            }
+            Nl80211RateInfoBandwidth::Mhz160 => {
+                nlas.push(Nl80211RateInfoNla::Width160Mhz)
+            }
+            Nl80211RateInfoBandwidth::Mhz320 => {
+                nlas.push(Nl80211RateInfoNla::Width320Mhz)
+            }
+        }
+        if info.short_gi {
+            nlas.push(Nl80211RateInfoNla::ShortGi);
+        }
+        if let Some(v) = info.mcs {
+            nlas.push(Nl80211RateInfoNla::Mcs(v));
+        }
+        if let Some(v) = info.vht_mcs {
+            nlas.push(Nl80211RateInfoNla::VhtMcs(v));
+        }
+        if let Some(v) = info.vht_nss {
+            nlas.push(Nl80211RateInfoNla::VhtNss(v));
+        }
+        if let Some(v) = info.he_mcs {
+            nlas.push(Nl80211RateInfoNla::HeMcs(v));
+        }
+        if let Some(v) = info.he_nss {
+            nlas.push(Nl80211RateInfoNla::HeNss(v));
+        }
+        if let Some(v) = info.he_gi {
+            nlas.push(Nl80211RateInfoNla::HeGi(v));
+        }
+        if let Some(v) = info.he_dcm {
+            nlas.push(Nl80211RateInfoNla::HeDcm(v));
+        }
+        if let Some(v) = info.he_ru_alloc {
+            nlas.push(Nl80211RateInfoNla::HeRuAlloc(v));
+        }
+        if let Some(v) = info.eht_mcs {
+            nlas.push(Nl80211RateInfoNla::EhtMcs(v));
+        }
+        if let Some(v) = info.eht_nss {
+            nlas.push(Nl80211RateInfoNla::EhtNss(v));
+        }
+        if let Some(v) = info.eht_gi {
+            nlas.push(Nl80211RateInfoNla::EhtGi(v));
+        }
+        if let Some(v) = info.eht_ru_alloc {
+            nlas.push(Nl80211RateInfoNla::EhtRuAlloc(v));
+        }
+        nlas
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bitrate_kbps() {
+        let info = Nl80211RateInfo {
+            bitrate: Some(65),
+            ..Default::default()
+        };
+        assert_eq!(info.bitrate_kbps(), Some(6500));
+        let info = Nl80211RateInfo {
+            bitrate: Some(u16::MAX),
+            bitrate32: Some(28_824),
+            ..Default::default()
+        };
+        assert_eq!(info.bitrate_kbps(), Some(2_882_400));
+        let info = Nl80211RateInfo {
+            bitrate32: Some(u32::MAX),
+            ..Default::default()
+        };
+        assert_eq!(info.bitrate_kbps(), None);
+    }
+}
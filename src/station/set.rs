@@ -0,0 +1,93 @@
+// SPDX-License-Identifier: MIT
+
+use crate::{
+    nl80211_execute_ack, Nl80211Attr, Nl80211Cmd, Nl80211Error, Nl80211Handle,
+    Nl80211Message, Nl80211PlinkAction, Nl80211PlinkState, Nl80211StationFlag,
+    Nl80211StationFlagUpdate,
+};
+
+pub struct Nl80211StationSetRequest {
+    handle: Nl80211Handle,
+    if_index: u32,
+    mac: [u8; 6],
+    flags: Option<Nl80211StationFlagUpdate>,
+    plink_action: Option<Nl80211PlinkAction>,
+    plink_state: Option<Nl80211PlinkState>,
+    airtime_weight: Option<u16>,
+}
+
+impl Nl80211StationSetRequest {
+    pub(crate) fn new(
+        handle: Nl80211Handle,
+        if_index: u32,
+        mac: [u8; 6],
+    ) -> Self {
+        Nl80211StationSetRequest {
+            handle,
+            if_index,
+            mac,
+            flags: None,
+            plink_action: None,
+            plink_state: None,
+            airtime_weight: None,
+        }
+    }
+
+    /// Set or clear specified station flag, other flags are untouched
+    pub fn flag(mut self, flag: Nl80211StationFlag, enabled: bool) -> Self {
+        self.flags = Some(self.flags.unwrap_or_default().flag(flag, enabled));
+        self
+    }
+
+    /// Action to perform on the mesh peer link
+    pub fn plink_action(mut self, action: Nl80211PlinkAction) -> Self {
+        self.plink_action = Some(action);
+        self
+    }
+
+    /// Move the mesh peer link to specified state, only allowed when mesh
+    /// peering management is handled by userspace
+    pub fn plink_state(mut self, state: Nl80211PlinkState) -> Self {
+        self.plink_state = Some(state);
+        self
+    }
+
+    /// Airtime weight used by the airtime fairness scheduler
+    pub fn airtime_weight(mut self, weight: u16) -> Self {
+        self.airtime_weight = Some(weight);
+        self
+    }
+
+    pub async fn execute(self) -> Result<(), Nl80211Error> {
+        let Nl80211StationSetRequest {
+            mut handle,
+            if_index,
+            mac,
+            flags,
+            plink_action,
+            plink_state,
+            airtime_weight,
+        } = self;
+
+        let mut nlas =
+            vec![Nl80211Attr::IfIndex(if_index), Nl80211Attr::Mac(mac)];
+        if let Some(flags) = flags {
+            nlas.push(Nl80211Attr::StationFlags2(flags));
+        }
+        if let Some(action) = plink_action {
+            nlas.push(Nl80211Attr::StationPlinkAction(action));
+        }
+        if let Some(state) = plink_state {
+            nlas.push(Nl80211Attr::StationPlinkState(state));
+        }
+        if let Some(weight) = airtime_weight {
+            nlas.push(Nl80211Attr::AirtimeWeight(weight));
+        }
+
+        let nl80211_msg = Nl80211Message {
+            cmd: Nl80211Cmd::StationSet,
+            nlas,
+        };
+        nl80211_execute_ack(&mut handle, nl80211_msg).await
+    }
+}
@@ -0,0 +1,819 @@
+// SPDX-License-Identifier: MIT
+
+use anyhow::Context;
+use byteorder::{ByteOrder, NativeEndian};
+use netlink_packet_utils::{
+    nla::{DefaultNla, Nla, NlaBuffer, NlasIterator},
+    parsers::{parse_u16, parse_u32, parse_u64, parse_u8},
+    DecodeError, Emitable, Parseable,
+};
+
+use crate::{Nl80211PlinkState, Nl80211RateInfo, Nl80211StationFlagUpdate};
+
+const NL80211_STA_INFO_INACTIVE_TIME: u16 = 1;
+const NL80211_STA_INFO_RX_BYTES: u16 = 2;
+const NL80211_STA_INFO_TX_BYTES: u16 = 3;
+const NL80211_STA_INFO_LLID: u16 = 4;
+const NL80211_STA_INFO_PLID: u16 = 5;
+const NL80211_STA_INFO_PLINK_STATE: u16 = 6;
+const NL80211_STA_INFO_SIGNAL: u16 = 7;
+const NL80211_STA_INFO_TX_BITRATE: u16 = 8;
+const NL80211_STA_INFO_RX_PACKETS: u16 = 9;
+const NL80211_STA_INFO_TX_PACKETS: u16 = 10;
+const NL80211_STA_INFO_TX_RETRIES: u16 = 11;
+const NL80211_STA_INFO_TX_FAILED: u16 = 12;
+const NL80211_STA_INFO_SIGNAL_AVG: u16 = 13;
+const NL80211_STA_INFO_RX_BITRATE: u16 = 14;
+const NL80211_STA_INFO_CONNECTED_TIME: u16 = 16;
+const NL80211_STA_INFO_STA_FLAGS: u16 = 17;
+const NL80211_STA_INFO_BEACON_LOSS: u16 = 18;
+const NL80211_STA_INFO_RX_BYTES64: u16 = 23;
+const NL80211_STA_INFO_TX_BYTES64: u16 = 24;
+const NL80211_STA_INFO_CHAIN_SIGNAL: u16 = 25;
+const NL80211_STA_INFO_CHAIN_SIGNAL_AVG: u16 = 26;
+const NL80211_STA_INFO_EXPECTED_THROUGHPUT: u16 = 27;
+const NL80211_STA_INFO_RX_DROP_MISC: u16 = 28;
+const NL80211_STA_INFO_BEACON_RX: u16 = 29;
+const NL80211_STA_INFO_BEACON_SIGNAL_AVG: u16 = 30;
+const NL80211_STA_INFO_RX_DURATION: u16 = 32;
+const NL80211_STA_INFO_ACK_SIGNAL: u16 = 34;
+const NL80211_STA_INFO_ACK_SIGNAL_AVG: u16 = 35;
+const NL80211_STA_INFO_RX_MPDUS: u16 = 36;
+const NL80211_STA_INFO_FCS_ERROR_COUNT: u16 = 37;
+const NL80211_STA_INFO_TX_DURATION: u16 = 39;
+const NL80211_STA_INFO_ASSOC_AT_BOOTTIME: u16 = 42;
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum Nl80211StationInfoNla {
+    /// Time since last activity in milliseconds
+    InactiveTime(u32),
+    /// Total received bytes (MPDU length) from this station
+    RxBytes(u32),
+    /// Total transmitted bytes (MPDU length) to this station
+    TxBytes(u32),
+    /// The station's mesh LLID
+    Llid(u16),
+    /// The station's mesh PLID
+    Plid(u16),
+    /// State of the mesh peer link with the station
+    PlinkState(Nl80211PlinkState),
+    /// Signal strength of last received PPDU in dBm
+    Signal(i8),
+    /// Current unicast TX rate
+    TxBitrate(Nl80211RateInfo),
+    /// Total received packet (MSDUs and MMPDUs) from this station
+    RxPackets(u32),
+    /// Total transmitted packets (MSDUs and MMPDUs) to this station
+    TxPackets(u32),
+    /// Total retries (MPDUs) to this station
+    TxRetries(u32),
+    /// Total failed packets (MPDUs) to this station
+    TxFailed(u32),
+    /// Signal strength average in dBm
+    SignalAvg(i8),
+    /// Last unicast data frame RX rate
+    RxBitrate(Nl80211RateInfo),
+    /// Time since the station is last connected in seconds
+    ConnectedTime(u32),
+    StationFlags(Nl80211StationFlagUpdate),
+    /// Count of times beacon loss was detected
+    BeaconLoss(u32),
+    /// Total received bytes (MPDU length) from this station
+    RxBytes64(u64),
+    /// Total transmitted bytes (MPDU length) to this station
+    TxBytes64(u64),
+    /// Per-chain signal strength of last PPDU in dBm
+    ChainSignal(Vec<i8>),
+    /// Per-chain signal strength average in dBm
+    ChainSignalAvg(Vec<i8>),
+    /// Expected throughput in kbps
+    ExpectedThroughput(u32),
+    /// RX packets dropped for unspecified reasons
+    RxDropMisc(u64),
+    /// Number of beacons received from this peer
+    BeaconRx(u64),
+    /// Signal strength average for beacons only in dBm
+    BeaconSignalAvg(i8),
+    /// Aggregate PPDU duration for all frames received from the station in microseconds
+    RxDuration(u64),
+    /// Signal strength of the last ACK frame in dBm
+    AckSignal(i8),
+    /// Average signal strength of ACK frames in dBm
+    AckSignalAvg(i8),
+    /// Total number of received packets (MPDUs) from this station
+    RxMpdus(u32),
+    /// Total number of packets (MPDUs) received from this station with an FCS error
+    FcsErrorCount(u32),
+    /// Aggregate PPDU duration for all frames sent to the station in microseconds
+    TxDuration(u64),
+    /// CLOCK_BOOTTIME timestamp of station's association in nanoseconds
+    AssocAtBoottime(u64),
+    Other(DefaultNla),
+}
+
+fn chain_signal_to_nlas(signals: &[i8]) -> Vec<DefaultNla> {
+    signals
+        .iter()
+        .enumerate()
+        .map(|(i, signal)| DefaultNla::new(i as u16, vec![*signal as u8]))
+        .collect()
+}
+
+impl Nla for Nl80211StationInfoNla {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::Signal(_)
+            | Self::SignalAvg(_)
+            | Self::BeaconSignalAvg(_)
+            | Self::AckSignal(_)
+            | Self::AckSignalAvg(_)
+            | Self::PlinkState(_) => 1,
+            Self::Llid(_) | Self::Plid(_) => 2,
+            Self::InactiveTime(_)
+            | Self::RxBytes(_)
+            | Self::TxBytes(_)
+            | Self::RxPackets(_)
+            | Self::TxPackets(_)
+            | Self::TxRetries(_)
+            | Self::TxFailed(_)
+            | Self::ConnectedTime(_)
+            | Self::BeaconLoss(_)
+            | Self::ExpectedThroughput(_)
+            | Self::RxMpdus(_)
+            | Self::FcsErrorCount(_) => 4,
+            Self::RxBytes64(_)
+            | Self::TxBytes64(_)
+            | Self::RxDropMisc(_)
+            | Self::BeaconRx(_)
+            | Self::RxDuration(_)
+            | Self::TxDuration(_)
+            | Self::AssocAtBoottime(_) => 8,
+            Self::ChainSignal(ref v) | Self::ChainSignalAvg(ref v) => {
+                chain_signal_to_nlas(v).as_slice().buffer_len()
+            }
+            Self::TxBitrate(ref d) | Self::RxBitrate(ref d) => d.buffer_len(),
+            Self::StationFlags(ref d) => d.buffer_len(),
+            Self::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::InactiveTime(_) => NL80211_STA_INFO_INACTIVE_TIME,
+            Self::RxBytes(_) => NL80211_STA_INFO_RX_BYTES,
+            Self::TxBytes(_) => NL80211_STA_INFO_TX_BYTES,
+            Self::Llid(_) => NL80211_STA_INFO_LLID,
+            Self::Plid(_) => NL80211_STA_INFO_PLID,
+            Self::PlinkState(_) => NL80211_STA_INFO_PLINK_STATE,
+            Self::Signal(_) => NL80211_STA_INFO_SIGNAL,
+            Self::TxBitrate(_) => NL80211_STA_INFO_TX_BITRATE,
+            Self::RxPackets(_) => NL80211_STA_INFO_RX_PACKETS,
+            Self::TxPackets(_) => NL80211_STA_INFO_TX_PACKETS,
+            Self::TxRetries(_) => NL80211_STA_INFO_TX_RETRIES,
+            Self::TxFailed(_) => NL80211_STA_INFO_TX_FAILED,
+            Self::SignalAvg(_) => NL80211_STA_INFO_SIGNAL_AVG,
+            Self::RxBitrate(_) => NL80211_STA_INFO_RX_BITRATE,
+            Self::ConnectedTime(_) => NL80211_STA_INFO_CONNECTED_TIME,
+            Self::StationFlags(_) => NL80211_STA_INFO_STA_FLAGS,
+            Self::BeaconLoss(_) => NL80211_STA_INFO_BEACON_LOSS,
+            Self::RxBytes64(_) => NL80211_STA_INFO_RX_BYTES64,
+            Self::TxBytes64(_) => NL80211_STA_INFO_TX_BYTES64,
+            Self::ChainSignal(_) => NL80211_STA_INFO_CHAIN_SIGNAL,
+            Self::ChainSignalAvg(_) => NL80211_STA_INFO_CHAIN_SIGNAL_AVG,
+            Self::ExpectedThroughput(_) => NL80211_STA_INFO_EXPECTED_THROUGHPUT,
+            Self::RxDropMisc(_) => NL80211_STA_INFO_RX_DROP_MISC,
+            Self::BeaconRx(_) => NL80211_STA_INFO_BEACON_RX,
+            Self::BeaconSignalAvg(_) => NL80211_STA_INFO_BEACON_SIGNAL_AVG,
+            Self::RxDuration(_) => NL80211_STA_INFO_RX_DURATION,
+            Self::AckSignal(_) => NL80211_STA_INFO_ACK_SIGNAL,
+            Self::AckSignalAvg(_) => NL80211_STA_INFO_ACK_SIGNAL_AVG,
+            Self::RxMpdus(_) => NL80211_STA_INFO_RX_MPDUS,
+            Self::FcsErrorCount(_) => NL80211_STA_INFO_FCS_ERROR_COUNT,
+            Self::TxDuration(_) => NL80211_STA_INFO_TX_DURATION,
+            Self::AssocAtBoottime(_) => NL80211_STA_INFO_ASSOC_AT_BOOTTIME,
+            Self::Other(attr) => attr.kind(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Signal(d)
+            | Self::SignalAvg(d)
+            | Self::BeaconSignalAvg(d)
+            | Self::AckSignal(d)
+            | Self::AckSignalAvg(d) => buffer[0] = *d as u8,
+            Self::PlinkState(d) => buffer[0] = (*d).into(),
+            Self::Llid(d) | Self::Plid(d) => {
+                NativeEndian::write_u16(buffer, *d)
+            }
+            Self::InactiveTime(d)
+            | Self::RxBytes(d)
+            | Self::TxBytes(d)
+            | Self::RxPackets(d)
+            | Self::TxPackets(d)
+            | Self::TxRetries(d)
+            | Self::TxFailed(d)
+            | Self::ConnectedTime(d)
+            | Self::BeaconLoss(d)
+            | Self::ExpectedThroughput(d)
+            | Self::RxMpdus(d)
+            | Self::FcsErrorCount(d) => NativeEndian::write_u32(buffer, *d),
+            Self::RxBytes64(d)
+            | Self::TxBytes64(d)
+            | Self::RxDropMisc(d)
+            | Self::BeaconRx(d)
+            | Self::RxDuration(d)
+            | Self::TxDuration(d)
+            | Self::AssocAtBoottime(d) => NativeEndian::write_u64(buffer, *d),
+            Self::ChainSignal(ref v) | Self::ChainSignalAvg(ref v) => {
+                chain_signal_to_nlas(v).as_slice().emit(buffer)
+            }
+            Self::TxBitrate(ref d) | Self::RxBitrate(ref d) => d.emit(buffer),
+            Self::StationFlags(ref d) => d.emit(buffer),
+            Self::Other(ref attr) => attr.emit(buffer),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for Nl80211StationInfoNla
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            NL80211_STA_INFO_INACTIVE_TIME => {
+                let err_msg = format!(
+                    "Invalid NL80211_STA_INFO_INACTIVE_TIME value {:?}",
+                    payload
+                );
+                Self::InactiveTime(parse_u32(payload).context(err_msg)?)
+            }
+            NL80211_STA_INFO_RX_BYTES => {
+                let err_msg = format!(
+                    "Invalid NL80211_STA_INFO_RX_BYTES value {:?}",
+                    payload
+                );
+                Self::RxBytes(parse_u32(payload).context(err_msg)?)
+            }
+            NL80211_STA_INFO_TX_BYTES => {
+                let err_msg = format!(
+                    "Invalid NL80211_STA_INFO_TX_BYTES value {:?}",
+                    payload
+                );
+                Self::TxBytes(parse_u32(payload).context(err_msg)?)
+            }
+            NL80211_STA_INFO_LLID => {
+                let err_msg = format!(
+                    "Invalid NL80211_STA_INFO_LLID value {:?}",
+                    payload
+                );
+                Self::Llid(parse_u16(payload).context(err_msg)?)
+            }
+            NL80211_STA_INFO_PLID => {
+                let err_msg = format!(
+                    "Invalid NL80211_STA_INFO_PLID value {:?}",
+                    payload
+                );
+                Self::Plid(parse_u16(payload).context(err_msg)?)
+            }
+            NL80211_STA_INFO_PLINK_STATE => {
+                let err_msg = format!(
+                    "Invalid NL80211_STA_INFO_PLINK_STATE value {:?}",
+                    payload
+                );
+                Self::PlinkState(parse_u8(payload).context(err_msg)?.into())
+            }
+            NL80211_STA_INFO_SIGNAL => {
+                let err_msg = format!(
+                    "Invalid NL80211_STA_INFO_SIGNAL value {:?}",
+                    payload
+                );
+                Self::Signal(parse_u8(payload).context(err_msg)? as i8)
+            }
+            NL80211_STA_INFO_TX_BITRATE => {
+                Self::TxBitrate(Nl80211RateInfo::parse(buf)?)
+            }
+            NL80211_STA_INFO_RX_PACKETS => {
+                let err_msg = format!(
+                    "Invalid NL80211_STA_INFO_RX_PACKETS value {:?}",
+                    payload
+                );
+                Self::RxPackets(parse_u32(payload).context(err_msg)?)
+            }
+            NL80211_STA_INFO_TX_PACKETS => {
+                let err_msg = format!(
+                    "Invalid NL80211_STA_INFO_TX_PACKETS value {:?}",
+                    payload
+                );
+                Self::TxPackets(parse_u32(payload).context(err_msg)?)
+            }
+            NL80211_STA_INFO_TX_RETRIES => {
+                let err_msg = format!(
+                    "Invalid NL80211_STA_INFO_TX_RETRIES value {:?}",
+                    payload
+                );
+                Self::TxRetries(parse_u32(payload).context(err_msg)?)
+            }
+            NL80211_STA_INFO_TX_FAILED => {
+                let err_msg = format!(
+                    "Invalid NL80211_STA_INFO_TX_FAILED value {:?}",
+                    payload
+                );
+                Self::TxFailed(parse_u32(payload).context(err_msg)?)
+            }
+            NL80211_STA_INFO_SIGNAL_AVG => {
+                let err_msg = format!(
+                    "Invalid NL80211_STA_INFO_SIGNAL_AVG value {:?}",
+                    payload
+                );
+                Self::SignalAvg(parse_u8(payload).context(err_msg)? as i8)
+            }
+            NL80211_STA_INFO_RX_BITRATE => {
+                Self::RxBitrate(Nl80211RateInfo::parse(buf)?)
+            }
+            NL80211_STA_INFO_CONNECTED_TIME => {
+                let err_msg = format!(
+                    "Invalid NL80211_STA_INFO_CONNECTED_TIME value {:?}",
+                    payload
+                );
+                Self::ConnectedTime(parse_u32(payload).context(err_msg)?)
+            }
+            NL80211_STA_INFO_STA_FLAGS => {
+                Self::StationFlags(Nl80211StationFlagUpdate::parse(payload)?)
+            }
+            NL80211_STA_INFO_BEACON_LOSS => {
+                let err_msg = format!(
+                    "Invalid NL80211_STA_INFO_BEACON_LOSS value {:?}",
+                    payload
+                );
+                Self::BeaconLoss(parse_u32(payload).context(err_msg)?)
+            }
+            NL80211_STA_INFO_RX_BYTES64 => {
+                let err_msg = format!(
+                    "Invalid NL80211_STA_INFO_RX_BYTES64 value {:?}",
+                    payload
+                );
+                Self::RxBytes64(parse_u64(payload).context(err_msg)?)
+            }
+            NL80211_STA_INFO_TX_BYTES64 => {
+                let err_msg = format!(
+                    "Invalid NL80211_STA_INFO_TX_BYTES64 value {:?}",
+                    payload
+                );
+                Self::TxBytes64(parse_u64(payload).context(err_msg)?)
+            }
+            NL80211_STA_INFO_CHAIN_SIGNAL => {
+                let err_msg = format!(
+                    "Invalid NL80211_STA_INFO_CHAIN_SIGNAL value {:?}",
+                    payload
+                );
+                let mut signals = Vec::new();
+                for nla in NlasIterator::new(payload) {
+                    let nla = &nla.context(err_msg.clone())?;
+                    signals
+                        .push(parse_u8(nla.value()).context(err_msg.clone())?
+                            as i8);
+                }
+                Self::ChainSignal(signals)
+            }
+            NL80211_STA_INFO_CHAIN_SIGNAL_AVG => {
+                let err_msg = format!(
+                    "Invalid NL80211_STA_INFO_CHAIN_SIGNAL_AVG value {:?}",
+                    payload
+                );
+                let mut signals = Vec::new();
+                for nla in NlasIterator::new(payload) {
+                    let nla = &nla.context(err_msg.clone())?;
+                    signals
+                        .push(parse_u8(nla.value()).context(err_msg.clone())?
+                            as i8);
+                }
+                Self::ChainSignalAvg(signals)
+            }
+            NL80211_STA_INFO_EXPECTED_THROUGHPUT => {
+                let err_msg = format!(
+                    "Invalid NL80211_STA_INFO_EXPECTED_THROUGHPUT value {:?}",
+                    payload
+                );
+                Self::ExpectedThroughput(parse_u32(payload).context(err_msg)?)
+            }
+            NL80211_STA_INFO_RX_DROP_MISC => {
+                let err_msg = format!(
+                    "Invalid NL80211_STA_INFO_RX_DROP_MISC value {:?}",
+                    payload
+                );
+                Self::RxDropMisc(parse_u64(payload).context(err_msg)?)
+            }
+            NL80211_STA_INFO_BEACON_RX => {
+                let err_msg = format!(
+                    "Invalid NL80211_STA_INFO_BEACON_RX value {:?}",
+                    payload
+                );
+                Self::BeaconRx(parse_u64(payload).context(err_msg)?)
+            }
+            NL80211_STA_INFO_BEACON_SIGNAL_AVG => {
+                let err_msg = format!(
+                    "Invalid NL80211_STA_INFO_BEACON_SIGNAL_AVG value {:?}",
+                    payload
+                );
+                Self::BeaconSignalAvg(parse_u8(payload).context(err_msg)? as i8)
+            }
+            NL80211_STA_INFO_RX_DURATION => {
+                let err_msg = format!(
+                    "Invalid NL80211_STA_INFO_RX_DURATION value {:?}",
+                    payload
+                );
+                Self::RxDuration(parse_u64(payload).context(err_msg)?)
+            }
+            NL80211_STA_INFO_ACK_SIGNAL => {
+                let err_msg = format!(
+                    "Invalid NL80211_STA_INFO_ACK_SIGNAL value {:?}",
+                    payload
+                );
+                Self::AckSignal(parse_u8(payload).context(err_msg)? as i8)
+            }
+            NL80211_STA_INFO_ACK_SIGNAL_AVG => {
+                let err_msg = format!(
+                    "Invalid NL80211_STA_INFO_ACK_SIGNAL_AVG value {:?}",
+                    payload
+                );
+                Self::AckSignalAvg(parse_u8(payload).context(err_msg)? as i8)
+            }
+            NL80211_STA_INFO_RX_MPDUS => {
+                let err_msg = format!(
+                    "Invalid NL80211_STA_INFO_RX_MPDUS value {:?}",
+                    payload
+                );
+                Self::RxMpdus(parse_u32(payload).context(err_msg)?)
+            }
+            NL80211_STA_INFO_FCS_ERROR_COUNT => {
+                let err_msg = format!(
+                    "Invalid NL80211_STA_INFO_FCS_ERROR_COUNT value {:?}",
+                    payload
+                );
+                Self::FcsErrorCount(parse_u32(payload).context(err_msg)?)
+            }
+            NL80211_STA_INFO_TX_DURATION => {
+                let err_msg = format!(
+                    "Invalid NL80211_STA_INFO_TX_DURATION value {:?}",
+                    payload
+                );
+                Self::TxDuration(parse_u64(payload).context(err_msg)?)
+            }
+            NL80211_STA_INFO_ASSOC_AT_BOOTTIME => {
+                let err_msg = format!(
+                    "Invalid NL80211_STA_INFO_ASSOC_AT_BOOTTIME value {:?}",
+                    payload
+                );
+                Self::AssocAtBoottime(parse_u64(payload).context(err_msg)?)
+            }
+            _ => Self::Other(
+                DefaultNla::parse(buf).context("invalid NLA (unknown kind)")?,
+            ),
+        })
+    }
+}
+
+/// Station statistics reported by kernel
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct Nl80211StationInfo {
+    /// Time since last activity in milliseconds
+    pub inactive_time: Option<u32>,
+    /// Total received bytes (MPDU length) from this station
+    pub rx_bytes: Option<u32>,
+    /// Total transmitted bytes (MPDU length) to this station
+    pub tx_bytes: Option<u32>,
+    /// The station's mesh LLID
+    pub llid: Option<u16>,
+    /// The station's mesh PLID
+    pub plid: Option<u16>,
+    /// State of the mesh peer link with the station
+    pub plink_state: Option<Nl80211PlinkState>,
+    /// Signal strength of last received PPDU in dBm
+    pub signal: Option<i8>,
+    /// Current unicast TX rate
+    pub tx_bitrate: Option<Nl80211RateInfo>,
+    /// Total received packet (MSDUs and MMPDUs) from this station
+    pub rx_packets: Option<u32>,
+    /// Total transmitted packets (MSDUs and MMPDUs) to this station
+    pub tx_packets: Option<u32>,
+    /// Total retries (MPDUs) to this station
+    pub tx_retries: Option<u32>,
+    /// Total failed packets (MPDUs) to this station
+    pub tx_failed: Option<u32>,
+    /// Signal strength average in dBm
+    pub signal_avg: Option<i8>,
+    /// Last unicast data frame RX rate
+    pub rx_bitrate: Option<Nl80211RateInfo>,
+    /// Time since the station is last connected in seconds
+    pub connected_time: Option<u32>,
+    pub station_flags: Option<Nl80211StationFlagUpdate>,
+    /// Count of times beacon loss was detected
+    pub beacon_loss: Option<u32>,
+    /// Total received bytes (MPDU length) from this station
+    pub rx_bytes64: Option<u64>,
+    /// Total transmitted bytes (MPDU length) to this station
+    pub tx_bytes64: Option<u64>,
+    /// Per-chain signal strength of last PPDU in dBm
+    pub chain_signal: Vec<i8>,
+    /// Per-chain signal strength average in dBm
+    pub chain_signal_avg: Vec<i8>,
+    /// Expected throughput in kbps
+    pub expected_throughput: Option<u32>,
+    /// RX packets dropped for unspecified reasons
+    pub rx_drop_misc: Option<u64>,
+    /// Number of beacons received from this peer
+    pub beacon_rx: Option<u64>,
+    /// Signal strength average for beacons only in dBm
+    pub beacon_signal_avg: Option<i8>,
+    /// Aggregate PPDU duration for all frames received from the station in microseconds
+    pub rx_duration: Option<u64>,
+    /// Signal strength of the last ACK frame in dBm
+    pub ack_signal: Option<i8>,
+    /// Average signal strength of ACK frames in dBm
+    pub ack_signal_avg: Option<i8>,
+    /// Total number of received packets (MPDUs) from this station
+    pub rx_mpdus: Option<u32>,
+    /// Total number of packets (MPDUs) received from this station with an FCS error
+    pub fcs_error_count: Option<u32>,
+    /// Aggregate PPDU duration for all frames sent to the station in microseconds
+    pub tx_duration: Option<u64>,
+    /// CLOCK_BOOTTIME timestamp of station's association in nanoseconds
+    pub assoc_at_boottime: Option<u64>,
+}
+
+impl Emitable for Nl80211StationInfo {
+    fn buffer_len(&self) -> usize {
+        Vec::<Nl80211StationInfoNla>::from(self)
+            .as_slice()
+            .buffer_len()
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        Vec::<Nl80211StationInfoNla>::from(self)
+            .as_slice()
+            .emit(buffer)
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for Nl80211StationInfo
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let mut ret = Self::default();
+        let payload = buf.value();
+        let err_msg =
+            format!("Invalid NL80211_ATTR_STA_INFO value {:?}", payload);
+        for nla in NlasIterator::new(payload) {
+            let nla = &nla.context(err_msg.clone())?;
+            match Nl80211StationInfoNla::parse(nla).context(err_msg.clone())? {
+                Nl80211StationInfoNla::InactiveTime(d) => {
+                    ret.inactive_time = Some(d)
+                }
+                Nl80211StationInfoNla::RxBytes(d) => ret.rx_bytes = Some(d),
+                Nl80211StationInfoNla::TxBytes(d) => ret.tx_bytes = Some(d),
+                Nl80211StationInfoNla::Llid(d) => ret.llid = Some(d),
+                Nl80211StationInfoNla::Plid(d) => ret.plid = Some(d),
+                Nl80211StationInfoNla::PlinkState(d) => {
+                    ret.plink_state = Some(d)
+                }
+                Nl80211StationInfoNla::Signal(d) => ret.signal = Some(d),
+                Nl80211StationInfoNla::TxBitrate(d) => ret.tx_bitrate = Some(d),
+                Nl80211StationInfoNla::RxPackets(d) => ret.rx_packets = Some(d),
+                Nl80211StationInfoNla::TxPackets(d) => ret.tx_packets = Some(d),
+                Nl80211StationInfoNla::TxRetries(d) => ret.tx_retries = Some(d),
+                Nl80211StationInfoNla::TxFailed(d) => ret.tx_failed = Some(d),
+                Nl80211StationInfoNla::SignalAvg(d) => ret.signal_avg = Some(d),
+                Nl80211StationInfoNla::RxBitrate(d) => ret.rx_bitrate = Some(d),
+                Nl80211StationInfoNla::ConnectedTime(d) => {
+                    ret.connected_time = Some(d)
+                }
+                Nl80211StationInfoNla::StationFlags(d) => {
+                    ret.station_flags = Some(d)
+                }
+                Nl80211StationInfoNla::BeaconLoss(d) => {
+                    ret.beacon_loss = Some(d)
+                }
+                Nl80211StationInfoNla::RxBytes64(d) => ret.rx_bytes64 = Some(d),
+                Nl80211StationInfoNla::TxBytes64(d) => ret.tx_bytes64 = Some(d),
+                Nl80211StationInfoNla::ChainSignal(v) => ret.chain_signal = v,
+                Nl80211StationInfoNla::ChainSignalAvg(v) => {
+                    ret.chain_signal_avg = v
+                }
+                Nl80211StationInfoNla::ExpectedThroughput(d) => {
+                    ret.expected_throughput = Some(d)
+                }
+                Nl80211StationInfoNla::RxDropMisc(d) => {
+                    ret.rx_drop_misc = Some(d)
+                }
+                Nl80211StationInfoNla::BeaconRx(d) => ret.beacon_rx = Some(d),
+                Nl80211StationInfoNla::BeaconSignalAvg(d) => {
+                    ret.beacon_signal_avg = Some(d)
+                }
+                Nl80211StationInfoNla::RxDuration(d) => {
+                    ret.rx_duration = Some(d)
+                }
+                Nl80211StationInfoNla::AckSignal(d) => ret.ack_signal = Some(d),
+                Nl80211StationInfoNla::AckSignalAvg(d) => {
+                    ret.ack_signal_avg = Some(d)
+                }
+                Nl80211StationInfoNla::RxMpdus(d) => ret.rx_mpdus = Some(d),
+                Nl80211StationInfoNla::FcsErrorCount(d) => {
+                    ret.fcs_error_count = Some(d)
+                }
+                Nl80211StationInfoNla::TxDuration(d) => {
+                    ret.tx_duration = Some(d)
+                }
+                Nl80211StationInfoNla::AssocAtBoottime(d) => {
+                    ret.assoc_at_boottime = Some(d)
+                }
+                Nl80211StationInfoNla::Other(attr) => {
+                    log::warn!(
+                        "Got unsupported NL80211_ATTR_STA_INFO value {:?}",
+                        attr
+                    )
+                }
+            }
+        }
+        Ok(ret)
+    }
+}
+
+impl From<&Nl80211StationInfo> for Vec<Nl80211StationInfoNla> {
+    fn from(info: &Nl80211StationInfo) -> Self {
+        let mut nlas = Vec::new();
+        if let Some(v) = info.inactive_time {
+            nlas.push(Nl80211StationInfoNla::InactiveTime(v));
+        }
+        if let Some(v) = info.rx_bytes {
+            nlas.push(Nl80211StationInfoNla::RxBytes(v));
+        }
+        if let Some(v) = info.tx_bytes {
+            nlas.push(Nl80211StationInfoNla::TxBytes(v));
+        }
+        if let Some(v) = info.llid {
+            nlas.push(Nl80211StationInfoNla::Llid(v));
+        }
+        if let Some(v) = info.plid {
+            nlas.push(Nl80211StationInfoNla::Plid(v));
+        }
+        if let Some(v) = info.plink_state {
+            nlas.push(Nl80211StationInfoNla::PlinkState(v));
+        }
+        if let Some(v) = info.signal {
+            nlas.push(Nl80211StationInfoNla::Signal(v));
+        }
+        if let Some(v) = info.tx_bitrate.as_ref() {
+            nlas.push(Nl80211StationInfoNla::TxBitrate(v.clone()));
+        }
+        if let Some(v) = info.rx_packets {
+            nlas.push(Nl80211StationInfoNla::RxPackets(v));
+        }
+        if let Some(v) = info.tx_packets {
+            nlas.push(Nl80211StationInfoNla::TxPackets(v));
+        }
+        if let Some(v) = info.tx_retries {
+            nlas.push(Nl80211StationInfoNla::TxRetries(v));
+        }
+        if let Some(v) = info.tx_failed {
+            nlas.push(Nl80211StationInfoNla::TxFailed(v));
+        }
+        if let Some(v) = info.signal_avg {
+            nlas.push(Nl80211StationInfoNla::SignalAvg(v));
+        }
+        if let Some(v) = info.rx_bitrate.as_ref() {
+            nlas.push(Nl80211StationInfoNla::RxBitrate(v.clone()));
+        }
+        if let Some(v) = info.connected_time {
+            nlas.push(Nl80211StationInfoNla::ConnectedTime(v));
+        }
+        if let Some(v) = info.station_flags {
+            nlas.push(Nl80211StationInfoNla::StationFlags(v));
+        }
+        if let Some(v) = info.beacon_loss {
+            nlas.push(Nl80211StationInfoNla::BeaconLoss(v));
+        }
+        if let Some(v) = info.rx_bytes64 {
+            nlas.push(Nl80211StationInfoNla::RxBytes64(v));
+        }
+        if let Some(v) = info.tx_bytes64 {
+            nlas.push(Nl80211StationInfoNla::TxBytes64(v));
+        }
+        if !info.chain_signal.is_empty() {
+            nlas.push(Nl80211StationInfoNla::ChainSignal(
+                info.chain_signal.clone(),
+            ));
+        }
+        if !info.chain_signal_avg.is_empty() {
+            nlas.push(Nl80211StationInfoNla::ChainSignalAvg(
+                info.chain_signal_avg.clone(),
+            ));
+        }
+        if let Some(v) = info.expected_throughput {
+            nlas.push(Nl80211StationInfoNla::ExpectedThroughput(v));
+        }
+        if let Some(v) = info.rx_drop_misc {
+            nlas.push(Nl80211StationInfoNla::RxDropMisc(v));
+        }
+        if let Some(v) = info.beacon_rx {
+            nlas.push(Nl80211StationInfoNla::BeaconRx(v));
+        }
+        if let Some(v) = info.beacon_signal_avg {
+            nlas.push(Nl80211StationInfoNla::BeaconSignalAvg(v));
+        }
+        if let Some(v) = info.rx_duration {
+            nlas.push(Nl80211StationInfoNla::RxDuration(v));
+        }
+        if let Some(v) = info.ack_signal {
+            nlas.push(Nl80211StationInfoNla::AckSignal(v));
+        }
+        if let Some(v) = info.ack_signal_avg {
+            nlas.push(Nl80211StationInfoNla::AckSignalAvg(v));
+        }
+        if let Some(v) = info.rx_mpdus {
+            nlas.push(Nl80211StationInfoNla::RxMpdus(v));
+        }
+        if let Some(v) = info.fcs_error_count {
+            nlas.push(Nl80211StationInfoNla::FcsErrorCount(v));
+        }
+        if let Some(v) = info.tx_duration {
+            nlas.push(Nl80211StationInfoNla::TxDuration(v));
+        }
+        if let Some(v) = info.assoc_at_boottime {
+            nlas.push(Nl80211StationInfoNla::AssocAtBoottime(v));
+        }
+        nlas
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const NL80211_STA_INFO_BSS_PARAM: u16 = 15;
+    const NL80211_STA_BSS_PARAM_DTIM_PERIOD: u16 = 4;
+    const NLA_F_NESTED: u16 = 1 << 15;
+
+    fn emit_nlas(nlas: &[DefaultNla]) -> Vec<u8> {
+        let mut buffer = vec![0u8; nlas.buffer_len()];
+        nlas.emit(&mut buffer);
+        buffer
+    }
+
+    fn parse_info(payload: Vec<u8>) -> Nl80211StationInfo {
+        let buffer = emit_nlas(&[DefaultNla::new(1, payload)]);
+        Nl80211StationInfo::parse(&NlaBuffer::new_checked(&buffer[..]).unwrap())
+            .unwrap()
+    }
+
+    #[test]
+    fn parse_mesh_peer_with_bss_param() {
+        let bss_param = emit_nlas(&[DefaultNla::new(
+            NL80211_STA_BSS_PARAM_DTIM_PERIOD,
+            vec![2],
+        )]);
+        let payload = emit_nlas(&[
+            DefaultNla::new(
+                NL80211_STA_INFO_INACTIVE_TIME,
+                30u32.to_ne_bytes().to_vec(),
+            ),
+            DefaultNla::new(
+                NL80211_STA_INFO_LLID,
+                0x1234u16.to_ne_bytes().to_vec(),
+            ),
+            DefaultNla::new(NL80211_STA_INFO_PLINK_STATE, vec![4]),
+            DefaultNla::new(NL80211_STA_INFO_SIGNAL, vec![-42i8 as u8]),
+            DefaultNla::new(
+                NL80211_STA_INFO_BSS_PARAM | NLA_F_NESTED,
+                bss_param,
+            ),
+            DefaultNla::new(
+                NL80211_STA_INFO_CONNECTED_TIME,
+                60u32.to_ne_bytes().to_vec(),
+            ),
+        ]);
+
+        let info = parse_info(payload);
+        assert_eq!(info.inactive_time, Some(30));
+        assert_eq!(info.llid, Some(0x1234));
+        assert_eq!(info.plink_state, Some(Nl80211PlinkState::Established));
+        assert_eq!(info.signal, Some(-42));
+        assert_eq!(info.connected_time, Some(60));
+    }
+
+    #[test]
+    fn round_trip() {
+        let info = Nl80211StationInfo {
+            rx_bytes64: Some(1 << 33),
+            plid: Some(7),
+            plink_state: Some(Nl80211PlinkState::OpenSent),
+            signal: Some(-60),
+            chain_signal: vec![-61, -63],
+            assoc_at_boottime: Some(123_456_789),
+            ..Default::default()
+        };
+        let mut payload = vec![0u8; info.buffer_len()];
+        info.emit(&mut payload);
+        assert_eq!(parse_info(payload), info);
+    }
+}
@@ -0,0 +1,9 @@
+// SPDX-License-Identifier: MIT
+
+mod get;
+mod handle;
+mod survey_info;
+
+pub use get::Nl80211SurveyGetRequest;
+pub use handle::Nl80211SurveyHandle;
+pub use survey_info::{Nl80211SurveyInfo, Nl80211SurveyInfoNla};
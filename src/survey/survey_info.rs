@@ -0,0 +1,398 @@
+// SPDX-License-Identifier: MIT
+
+use anyhow::Context;
+use byteorder::{ByteOrder, NativeEndian};
+use netlink_packet_utils::{
+    nla::{DefaultNla, Nla, NlaBuffer, NlasIterator},
+    parsers::{parse_u32, parse_u64, parse_u8},
+    DecodeError, Emitable, Parseable,
+};
+
+const NL80211_SURVEY_INFO_FREQUENCY: u16 = 1;
+const NL80211_SURVEY_INFO_NOISE: u16 = 2;
+const NL80211_SURVEY_INFO_IN_USE: u16 = 3;
+const NL80211_SURVEY_INFO_TIME: u16 = 4;
+const NL80211_SURVEY_INFO_TIME_BUSY: u16 = 5;
+const NL80211_SURVEY_INFO_TIME_EXT_BUSY: u16 = 6;
+const NL80211_SURVEY_INFO_TIME_RX: u16 = 7;
+const NL80211_SURVEY_INFO_TIME_TX: u16 = 8;
+const NL80211_SURVEY_INFO_TIME_SCAN: u16 = 9;
+const NL80211_SURVEY_INFO_TIME_BSS_RX: u16 = 11;
+const NL80211_SURVEY_INFO_FREQUENCY_OFFSET: u16 = 12;
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum Nl80211SurveyInfoNla {
+    /// Center frequency of channel in MHz
+    Frequency(u32),
+    /// Noise level of channel in dBm
+    Noise(i8),
+    /// Channel is currently being used
+    InUse,
+    /// Time in milliseconds that the radio was on the channel
+    Time(u64),
+    /// Time in milliseconds the primary channel was sensed busy
+    TimeBusy(u64),
+    /// Time in milliseconds the extension channel was sensed busy
+    TimeExtBusy(u64),
+    /// Time in milliseconds the radio spent receiving data
+    TimeRx(u64),
+    /// Time in milliseconds the radio spent transmitting data
+    TimeTx(u64),
+    /// Time in milliseconds the radio spent for scan
+    TimeScan(u64),
+    /// Time in milliseconds the radio spent receiving data on a local BSS
+    TimeBssRx(u64),
+    /// Center frequency offset in KHz
+    FrequencyOffset(u32),
+    Other(DefaultNla),
+}
+
+impl Nla for Nl80211SurveyInfoNla {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::InUse => 0,
+            Self::Noise(_) => 1,
+            Self::Frequency(_) | Self::FrequencyOffset(_) => 4,
+            Self::Time(_)
+            | Self::TimeBusy(_)
+            | Self::TimeExtBusy(_)
+            | Self::TimeRx(_)
+            | Self::TimeTx(_)
+            | Self::TimeScan(_)
+            | Self::TimeBssRx(_) => 8,
+            Self::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::Frequency(_) => NL80211_SURVEY_INFO_FREQUENCY,
+            Self::Noise(_) => NL80211_SURVEY_INFO_NOISE,
+            Self::InUse => NL80211_SURVEY_INFO_IN_USE,
+            Self::Time(_) => NL80211_SURVEY_INFO_TIME,
+            Self::TimeBusy(_) => NL80211_SURVEY_INFO_TIME_BUSY,
+            Self::TimeExtBusy(_) => NL80211_SURVEY_INFO_TIME_EXT_BUSY,
+            Self::TimeRx(_) => NL80211_SURVEY_INFO_TIME_RX,
+            Self::TimeTx(_) => NL80211_SURVEY_INFO_TIME_TX,
+            Self::TimeScan(_) => NL80211_SURVEY_INFO_TIME_SCAN,
+            Self::TimeBssRx(_) => NL80211_SURVEY_INFO_TIME_BSS_RX,
+            Self::FrequencyOffset(_) => NL80211_SURVEY_INFO_FREQUENCY_OFFSET,
+            Self::Other(attr) => attr.kind(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::InUse => (),
+            Self::Noise(d) => buffer[0] = *d as u8,
+            Self::Frequency(d) | Self::FrequencyOffset(d) => {
+                NativeEndian::write_u32(buffer, *d)
+            }
+            Self::Time(d)
+            | Self::TimeBusy(d)
+            | Self::TimeExtBusy(d)
+            | Self::TimeRx(d)
+            | Self::TimeTx(d)
+            | Self::TimeScan(d)
+            | Self::TimeBssRx(d) => NativeEndian::write_u64(buffer, *d),
+            Self::Other(ref attr) => attr.emit(buffer),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for Nl80211SurveyInfoNla
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            NL80211_SURVEY_INFO_FREQUENCY => {
+                let err_msg = format!(
+                    "Invalid NL80211_SURVEY_INFO_FREQUENCY value {:?}",
+                    payload
+                );
+                Self::Frequency(parse_u32(payload).context(err_msg)?)
+            }
+            NL80211_SURVEY_INFO_NOISE => {
+                let err_msg = format!(
+                    "Invalid NL80211_SURVEY_INFO_NOISE value {:?}",
+                    payload
+                );
+                Self::Noise(parse_u8(payload).context(err_msg)? as i8)
+            }
+            NL80211_SURVEY_INFO_IN_USE => Self::InUse,
+            NL80211_SURVEY_INFO_TIME => {
+                let err_msg = format!(
+                    "Invalid NL80211_SURVEY_INFO_TIME value {:?}",
+                    payload
+                );
+                Self::Time(parse_u64(payload).context(err_msg)?)
+            }
+            NL80211_SURVEY_INFO_TIME_BUSY => {
+                let err_msg = format!(
+                    "Invalid NL80211_SURVEY_INFO_TIME_BUSY value {:?}",
+                    payload
+                );
+                Self::TimeBusy(parse_u64(payload).context(err_msg)?)
+            }
+            NL80211_SURVEY_INFO_TIME_EXT_BUSY => {
+                let err_msg = format!(
+                    "Invalid NL80211_SURVEY_INFO_TIME_EXT_BUSY value {:?}",
+                    payload
+                );
+                Self::TimeExtBusy(parse_u64(payload).context(err_msg)?)
+            }
+            NL80211_SURVEY_INFO_TIME_RX => {
+                let err_msg = format!(
+                    "Invalid NL80211_SURVEY_INFO_TIME_RX value {:?}",
+                    payload
+                );
+                Self::TimeRx(parse_u64(payload).context(err_msg)?)
+            }
+            NL80211_SURVEY_INFO_TIME_TX => {
+                let err_msg = format!(
+                    "Invalid NL80211_SURVEY_INFO_TIME_TX value {:?}",
+                    payload
+                );
+                Self::TimeTx(parse_u64(payload).context(err_msg)?)
+            }
+            NL80211_SURVEY_INFO_TIME_SCAN => {
+                let err_msg = format!(
+                    "Invalid NL80211_SURVEY_INFO_TIME_SCAN value {:?}",
+                    payload
+                );
+                Self::TimeScan(parse_u64(payload).context(err_msg)?)
+            }
+            NL80211_SURVEY_INFO_TIME_BSS_RX => {
+                let err_msg = format!(
+                    "Invalid NL80211_SURVEY_INFO_TIME_BSS_RX value {:?}",
+                    payload
+                );
+                Self::TimeBssRx(parse_u64(payload).context(err_msg)?)
+            }
+            NL80211_SURVEY_INFO_FREQUENCY_OFFSET => {
+                let err_msg = format!(
+                    "Invalid NL80211_SURVEY_INFO_FREQUENCY_OFFSET value {:?}",
+                    payload
+                );
+                Self::FrequencyOffset(parse_u32(payload).context(err_msg)?)
+            }
+            _ => Self::Other(
+                DefaultNla::parse(buf).context("invalid NLA (unknown kind)")?,
+            ),
+        })
+    }
+}
+
+/// Channel survey data
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct Nl80211SurveyInfo {
+    /// Center frequency of channel in MHz
+    pub frequency: Option<u32>,
+    /// Noise level of channel in dBm
+    pub noise: Option<i8>,
+    /// Channel is currently being used
+    pub in_use: bool,
+    /// Time in milliseconds that the radio was on the channel
+    pub time: Option<u64>,
+    /// Time in milliseconds the primary channel was sensed busy
+    pub time_busy: Option<u64>,
+    /// Time in milliseconds the extension channel was sensed busy
+    pub time_ext_busy: Option<u64>,
+    /// Time in milliseconds the radio spent receiving data
+    pub time_rx: Option<u64>,
+    /// Time in milliseconds the radio spent transmitting data
+    pub time_tx: Option<u64>,
+    /// Time in milliseconds the radio spent for scan
+    pub time_scan: Option<u64>,
+    /// Time in milliseconds the radio spent receiving data on a local BSS
+    pub time_bss_rx: Option<u64>,
+    /// Center frequency offset in KHz
+    pub frequency_offset: Option<u32>,
+}
+
+impl Nl80211SurveyInfo {
+    /// Percentage of time the channel was sensed busy between `previous`
+    /// survey of the same channel and this one.
+    /// Return `None` if the busy time is not reported, the surveys are for
+    /// different channels or the counters were reset in between.
+    pub fn busy_percentage_since(&self, previous: &Self) -> Option<f64> {
+        if self.frequency != previous.frequency {
+            return None;
+        }
+        let time = self.time?.checked_sub(previous.time?)?;
+        let busy = self.time_busy?.checked_sub(previous.time_busy?)?;
+        if time == 0 {
+            None
+        } else {
+            Some(busy as f64 * 100.0 / time as f64)
+        }
+    }
+
+    /// Busy percentage of each channel(MHz) found in both `previous` and
+    /// `current` survey dumps.
+    pub fn channel_utilization(
+        previous: &[Self],
+        current: &[Self],
+    ) -> Vec<(u32, f64)> {
+        current
+            .iter()
+            .filter_map(|cur| {
+                let freq = cur.frequency?;
+                let prev =
+                    previous.iter().find(|p| p.frequency == Some(freq))?;
+                Some((freq, cur.busy_percentage_since(prev)?))
+            })
+            .collect()
+    }
+}
+
+impl Emitable for Nl80211SurveyInfo {
+    fn buffer_len(&self) -> usize {
+        Vec::<Nl80211SurveyInfoNla>::from(self)
+            .as_slice()
+            .buffer_len()
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        Vec::<Nl80211SurveyInfoNla>::from(self)
+            .as_slice()
+            .emit(buffer)
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for Nl80211SurveyInfo
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let mut ret = Self::default();
+        let payload = buf.value();
+        let err_msg =
+            format!("Invalid NL80211_ATTR_SURVEY_INFO value {:?}", payload);
+        for nla in NlasIterator::new(payload) {
+            let nla = &nla.context(err_msg.clone())?;
+            match Nl80211SurveyInfoNla::parse(nla).context(err_msg.clone())? {
+                Nl80211SurveyInfoNla::Frequency(d) => ret.frequency = Some(d),
+                Nl80211SurveyInfoNla::Noise(d) => ret.noise = Some(d),
+                Nl80211SurveyInfoNla::InUse => ret.in_use = true,
+                Nl80211SurveyInfoNla::Time(d) => ret.time = Some(d),
+                Nl80211SurveyInfoNla::TimeBusy(d) => ret.time_busy = Some(d),
+                Nl80211SurveyInfoNla::TimeExtBusy(d) => {
+                    ret.time_ext_busy = Some(d)
+                }
+                Nl80211SurveyInfoNla::TimeRx(d) => ret.time_rx = Some(d),
+                Nl80211SurveyInfoNla::TimeTx(d) => ret.time_tx = Some(d),
+                Nl80211SurveyInfoNla::TimeScan(d) => ret.time_scan = Some(d),
+                Nl80211SurveyInfoNla::TimeBssRx(d) => ret.time_bss_rx = Some(d),
+                Nl80211SurveyInfoNla::FrequencyOffset(d) => {
+                    ret.frequency_offset = Some(d)
+                }
+                Nl80211SurveyInfoNla::Other(attr) => {
+                    log::warn!(
+                        "Got unsupported NL80211_ATTR_SURVEY_INFO value {:?}",
+                        attr
+                    )
+                }
+            }
+        }
+        Ok(ret)
+    }
+}
+
+impl From<&Nl80211SurveyInfo> for Vec<Nl80211SurveyInfoNla> {
+    fn from(info: &Nl80211SurveyInfo) -> Self {
+        let mut nlas = Vec::new();
+        if let Some(v) = info.frequency {
+            nlas.push(Nl80211SurveyInfoNla::Frequency(v));
+        }
+        if let Some(v) = info.noise {
+            nlas.push(Nl80211SurveyInfoNla::Noise(v));
+        }
+        if info.in_use {
+            nlas.push(Nl80211SurveyInfoNla::InUse);
+        }
+        if let Some(v) = info.time {
+            nlas.push(Nl80211SurveyInfoNla::Time(v));
+        }
+        if let Some(v) = info.time_busy {
+            nlas.push(Nl80211SurveyInfoNla::TimeBusy(v));
+        }
+        if let Some(v) = info.time_ext_busy {
+            nlas.push(Nl80211SurveyInfoNla::TimeExtBusy(v));
+        }
+        if let Some(v) = info.time_rx {
+            nlas.push(Nl80211SurveyInfoNla::TimeRx(v));
+        }
+        if let Some(v) = info.time_tx {
+            nlas.push(Nl80211SurveyInfoNla::TimeTx(v));
+        }
+        if let Some(v) = info.time_scan {
+            nlas.push(Nl80211SurveyInfoNla::TimeScan(v));
+        }
+        if let Some(v) = info.time_bss_rx {
+            nlas.push(Nl80211SurveyInfoNla::TimeBssRx(v));
+        }
+        if let Some(v) = info.frequency_offset {
+            nlas.push(Nl80211SurveyInfoNla::FrequencyOffset(v));
+        }
+        nlas
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn survey(frequency: u32, time: u64, time_busy: u64) -> Nl80211SurveyInfo {
+        Nl80211SurveyInfo {
+            frequency: Some(frequency),
+            time: Some(time),
+            time_busy: Some(time_busy),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn busy_percentage() {
+        let previous = survey(2412, 1000, 100);
+        assert_eq!(
+            survey(2412, 1400, 200).busy_percentage_since(&previous),
+            Some(25.0)
+        );
+        // Different channel
+        assert_eq!(
+            survey(2437, 1400, 200).busy_percentage_since(&previous),
+            None
+        );
+        // Counters reset
+        assert_eq!(
+            survey(2412, 500, 50).busy_percentage_since(&previous),
+            None
+        );
+        // No time elapsed
+        assert_eq!(
+            survey(2412, 1000, 100).busy_percentage_since(&previous),
+            None
+        );
+        // Busy time not reported
+        let current = Nl80211SurveyInfo {
+            time_busy: None,
+            ..survey(2412, 1400, 0)
+        };
+        assert_eq!(current.busy_percentage_since(&previous), None);
+    }
+
+    #[test]
+    fn channel_utilization() {
+        let previous = [survey(2412, 1000, 100), survey(2437, 1000, 0)];
+        let current = [
+            survey(2437, 2000, 500),
+            survey(2462, 2000, 500),
+            survey(2412, 2000, 100),
+        ];
+        assert_eq!(
+            Nl80211SurveyInfo::channel_utilization(&previous, &current),
+            vec![(2437, 50.0), (2412, 0.0)]
+        );
+    }
+}
@@ -0,0 +1,85 @@
+// SPDX-License-Identifier: MIT
+
+// TDLS Action field values defined by IEEE 802.11
+const WLAN_TDLS_SETUP_REQUEST: u8 = 0;
+const WLAN_TDLS_SETUP_RESPONSE: u8 = 1;
+const WLAN_TDLS_SETUP_CONFIRM: u8 = 2;
+const WLAN_TDLS_TEARDOWN: u8 = 3;
+const WLAN_TDLS_PEER_TRAFFIC_INDICATION: u8 = 4;
+const WLAN_TDLS_CHANNEL_SWITCH_REQUEST: u8 = 5;
+const WLAN_TDLS_CHANNEL_SWITCH_RESPONSE: u8 = 6;
+const WLAN_TDLS_PEER_PSM_REQUEST: u8 = 7;
+const WLAN_TDLS_PEER_PSM_RESPONSE: u8 = 8;
+const WLAN_TDLS_PEER_TRAFFIC_RESPONSE: u8 = 9;
+const WLAN_TDLS_DISCOVERY_REQUEST: u8 = 10;
+// Public Action field value of the TDLS Discovery Response, which is sent
+// directly instead of through the AP
+const WLAN_PUB_ACTION_TDLS_DISCOVER_RES: u8 = 14;
+
+/// TDLS action frame to send
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Nl80211TdlsAction {
+    SetupRequest,
+    SetupResponse,
+    SetupConfirm,
+    Teardown,
+    PeerTrafficIndication,
+    ChannelSwitchRequest,
+    ChannelSwitchResponse,
+    PeerPsmRequest,
+    PeerPsmResponse,
+    PeerTrafficResponse,
+    DiscoveryRequest,
+    DiscoveryResponse,
+    Other(u8),
+}
+
+impl From<u8> for Nl80211TdlsAction {
+    fn from(d: u8) -> Self {
+        match d {
+            WLAN_TDLS_SETUP_REQUEST => Self::SetupRequest,
+            WLAN_TDLS_SETUP_RESPONSE => Self::SetupResponse,
+            WLAN_TDLS_SETUP_CONFIRM => Self::SetupConfirm,
+            WLAN_TDLS_TEARDOWN => Self::Teardown,
+            WLAN_TDLS_PEER_TRAFFIC_INDICATION => Self::PeerTrafficIndication,
+            WLAN_TDLS_CHANNEL_SWITCH_REQUEST => Self::ChannelSwitchRequest,
+            WLAN_TDLS_CHANNEL_SWITCH_RESPONSE => Self::ChannelSwitchResponse,
+            WLAN_TDLS_PEER_PSM_REQUEST => Self::PeerPsmRequest,
+            WLAN_TDLS_PEER_PSM_RESPONSE => Self::PeerPsmResponse,
+            WLAN_TDLS_PEER_TRAFFIC_RESPONSE => Self::PeerTrafficResponse,
+            WLAN_TDLS_DISCOVERY_REQUEST => Self::DiscoveryRequest,
+            WLAN_PUB_ACTION_TDLS_DISCOVER_RES => Self::DiscoveryResponse,
+            _ => Self::Other(d),
+        }
+    }
+}
+
+impl From<Nl80211TdlsAction> for u8 {
+    fn from(v: Nl80211TdlsAction) -> u8 {
+        match v {
+            Nl80211TdlsAction::SetupRequest => WLAN_TDLS_SETUP_REQUEST,
+            Nl80211TdlsAction::SetupResponse => WLAN_TDLS_SETUP_RESPONSE,
+            Nl80211TdlsAction::SetupConfirm => WLAN_TDLS_SETUP_CONFIRM,
+            Nl80211TdlsAction::Teardown => WLAN_TDLS_TEARDOWN,
+            Nl80211TdlsAction::PeerTrafficIndication => {
+                WLAN_TDLS_PEER_TRAFFIC_INDICATION
+            }
+            Nl80211TdlsAction::ChannelSwitchRequest => {
+                WLAN_TDLS_CHANNEL_SWITCH_REQUEST
+            }
+            Nl80211TdlsAction::ChannelSwitchResponse => {
+                WLAN_TDLS_CHANNEL_SWITCH_RESPONSE
+            }
+            Nl80211TdlsAction::PeerPsmRequest => WLAN_TDLS_PEER_PSM_REQUEST,
+            Nl80211TdlsAction::PeerPsmResponse => WLAN_TDLS_PEER_PSM_RESPONSE,
+            Nl80211TdlsAction::PeerTrafficResponse => {
+                WLAN_TDLS_PEER_TRAFFIC_RESPONSE
+            }
+            Nl80211TdlsAction::DiscoveryRequest => WLAN_TDLS_DISCOVERY_REQUEST,
+            Nl80211TdlsAction::DiscoveryResponse => {
+                WLAN_PUB_ACTION_TDLS_DISCOVER_RES
+            }
+            Nl80211TdlsAction::Other(d) => d,
+        }
+    }
+}
@@ -0,0 +1,105 @@
+// SPDX-License-Identifier: MIT
+
+use crate::{
+    nl80211_execute_ack, Nl80211Attr, Nl80211Cmd, Nl80211Error, Nl80211Handle,
+    Nl80211Message, Nl80211TdlsAction,
+};
+
+const ETH_ALEN: usize = 6;
+
+pub struct Nl80211TdlsMgmtRequest {
+    handle: Nl80211Handle,
+    if_index: u32,
+    peer: [u8; ETH_ALEN],
+    action: Nl80211TdlsAction,
+    dialog_token: u8,
+    status_code: u16,
+    peer_capability: Option<u32>,
+    initiator: bool,
+    ie: Option<Vec<u8>>,
+}
+
+impl Nl80211TdlsMgmtRequest {
+    pub(crate) fn new(
+        handle: Nl80211Handle,
+        if_index: u32,
+        peer: [u8; ETH_ALEN],
+        action: Nl80211TdlsAction,
+        dialog_token: u8,
+    ) -> Self {
+        Nl80211TdlsMgmtRequest {
+            handle,
+            if_index,
+            peer,
+            action,
+            dialog_token,
+            status_code: 0,
+            peer_capability: None,
+            initiator: false,
+            ie: None,
+        }
+    }
+
+    /// Status code of setup responses and confirms, or reason code of
+    /// teardowns, 0 (success) by default
+    pub fn status_code(mut self, status_code: u16) -> Self {
+        self.status_code = status_code;
+        self
+    }
+
+    /// Raw TDLS peer capability flags, `NL80211_TDLS_PEER_*` bits
+    pub fn peer_capability(mut self, peer_capability: u32) -> Self {
+        self.peer_capability = Some(peer_capability);
+        self
+    }
+
+    /// Whether this station initiated the TDLS setup, determining the
+    /// address order of the Link Identifier element
+    pub fn initiator(mut self, initiator: bool) -> Self {
+        self.initiator = initiator;
+        self
+    }
+
+    /// Raw information elements appended to the frame
+    pub fn ie(mut self, ie: Vec<u8>) -> Self {
+        self.ie = Some(ie);
+        self
+    }
+
+    pub async fn execute(self) -> Result<(), Nl80211Error> {
+        let Nl80211TdlsMgmtRequest {
+            mut handle,
+            if_index,
+            peer,
+            action,
+            dialog_token,
+            status_code,
+            peer_capability,
+            initiator,
+            ie,
+        } = self;
+
+        let mut nlas = vec![
+            Nl80211Attr::IfIndex(if_index),
+            Nl80211Attr::Mac(peer),
+            Nl80211Attr::TdlsAction(action),
+            Nl80211Attr::TdlsDialogToken(dialog_token),
+            Nl80211Attr::StatusCode(status_code),
+        ];
+        if let Some(v) = peer_capability {
+            nlas.push(Nl80211Attr::TdlsPeerCapability(v));
+        }
+        if initiator {
+            nlas.push(Nl80211Attr::TdlsInitiator);
+        }
+        if let Some(v) = ie {
+            nlas.push(Nl80211Attr::Ie(v));
+        }
+
+        let nl80211_msg = Nl80211Message {
+            cmd: Nl80211Cmd::TdlsMgmt,
+            nlas,
+        };
+        nl80211_execute_ack(&mut handle, nl80211_msg).await
+    }
+}
@@ -0,0 +1,11 @@
+// SPDX-License-Identifier: MIT
+
+mod action;
+mod mgmt;
+mod oper;
+mod operation;
+
+pub use action::Nl80211TdlsAction;
+pub use mgmt::Nl80211TdlsMgmtRequest;
+pub use oper::Nl80211TdlsOperRequest;
+pub use operation::Nl80211TdlsOperation;
@@ -0,0 +1,50 @@
+// SPDX-License-Identifier: MIT
+
+use crate::{
+    nl80211_execute_ack, Nl80211Attr, Nl80211Cmd, Nl80211Error, Nl80211Handle,
+    Nl80211Message, Nl80211TdlsOperation,
+};
+
+const ETH_ALEN: usize = 6;
+
+pub struct Nl80211TdlsOperRequest {
+    handle: Nl80211Handle,
+    if_index: u32,
+    peer: [u8; ETH_ALEN],
+    operation: Nl80211TdlsOperation,
+}
+
+impl Nl80211TdlsOperRequest {
+    pub(crate) fn new(
+        handle: Nl80211Handle,
+        if_index: u32,
+        peer: [u8; ETH_ALEN],
+        operation: Nl80211TdlsOperation,
+    ) -> Self {
+        Nl80211TdlsOperRequest {
+            handle,
+            if_index,
+            peer,
+            operation,
+        }
+    }
+
+    pub async fn execute(self) -> Result<(), Nl80211Error> {
+        let Nl80211TdlsOperRequest {
+            mut handle,
+            if_index,
+            peer,
+            operation,
+        } = self;
+
+        let nl80211_msg = Nl80211Message {
+            cmd: Nl80211Cmd::TdlsOper,
+            nlas: vec![
+                Nl80211Attr::IfIndex(if_index),
+                Nl80211Attr::Mac(peer),
+                Nl80211Attr::TdlsOperation(operation),
+            ],
+        };
+        nl80211_execute_ack(&mut handle, nl80211_msg).await
+    }
+}
@@ -0,0 +1,50 @@
+// SPDX-License-Identifier: MIT
+
+const NL80211_TDLS_DISCOVERY_REQ: u8 = 0;
+const NL80211_TDLS_SETUP: u8 = 1;
+const NL80211_TDLS_TEARDOWN: u8 = 2;
+const NL80211_TDLS_ENABLE_LINK: u8 = 3;
+const NL80211_TDLS_DISABLE_LINK: u8 = 4;
+
+/// TDLS operation requested from a driver handling TDLS setup internally, or
+/// link state change after userspace finished the TDLS exchange
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Nl80211TdlsOperation {
+    /// Send a TDLS discovery request
+    DiscoveryReq,
+    /// Set up a TDLS link with the peer
+    Setup,
+    /// Tear down the TDLS link with the peer
+    Teardown,
+    /// Enable the TDLS link after the setup exchange completed
+    EnableLink,
+    /// Disable the TDLS link after sending or receiving a teardown
+    DisableLink,
+    Other(u8),
+}
+
+impl From<u8> for Nl80211TdlsOperation {
+    fn from(d: u8) -> Self {
+        match d {
+            NL80211_TDLS_DISCOVERY_REQ => Self::DiscoveryReq,
+            NL80211_TDLS_SETUP => Self::Setup,
+            NL80211_TDLS_TEARDOWN => Self::Teardown,
+            NL80211_TDLS_ENABLE_LINK => Self::EnableLink,
+            NL80211_TDLS_DISABLE_LINK => Self::DisableLink,
+            _ => Self::Other(d),
+        }
+    }
+}
+
+impl From<Nl80211TdlsOperation> for u8 {
+    fn from(v: Nl80211TdlsOperation) -> u8 {
+        match v {
+            Nl80211TdlsOperation::DiscoveryReq => NL80211_TDLS_DISCOVERY_REQ,
+            Nl80211TdlsOperation::Setup => NL80211_TDLS_SETUP,
+            Nl80211TdlsOperation::Teardown => NL80211_TDLS_TEARDOWN,
+            Nl80211TdlsOperation::EnableLink => NL80211_TDLS_ENABLE_LINK,
+            Nl80211TdlsOperation::DisableLink => NL80211_TDLS_DISABLE_LINK,
+            Nl80211TdlsOperation::Other(d) => d,
+        }
+    }
+}
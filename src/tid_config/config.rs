@@ -0,0 +1,410 @@
+// SPDX-License-Identifier: MIT
+
+use anyhow::Context;
+use byteorder::{ByteOrder, NativeEndian};
+use netlink_packet_utils::{
+    nla::{DefaultNla, Nla, NlaBuffer, NlasIterator},
+    parsers::{parse_u16, parse_u64, parse_u8},
+    DecodeError, Emitable, Parseable,
+};
+
+use crate::{Nl80211TxRateSetting, Nl80211TxRates};
+
+const NL80211_TID_CONFIG_ATTR_VIF_SUPP: u16 = 2;
+const NL80211_TID_CONFIG_ATTR_PEER_SUPP: u16 = 3;
+const NL80211_TID_CONFIG_ATTR_OVERRIDE: u16 = 4;
+const NL80211_TID_CONFIG_ATTR_TIDS: u16 = 5;
+const NL80211_TID_CONFIG_ATTR_NOACK: u16 = 6;
+const NL80211_TID_CONFIG_ATTR_RETRY_SHORT: u16 = 7;
+const NL80211_TID_CONFIG_ATTR_RETRY_LONG: u16 = 8;
+const NL80211_TID_CONFIG_ATTR_AMPDU_CTRL: u16 = 9;
+const NL80211_TID_CONFIG_ATTR_RTSCTS_CTRL: u16 = 10;
+const NL80211_TID_CONFIG_ATTR_AMSDU_CTRL: u16 = 11;
+const NL80211_TID_CONFIG_ATTR_TX_RATE_TYPE: u16 = 12;
+const NL80211_TID_CONFIG_ATTR_TX_RATE: u16 = 13;
+
+const NL80211_TID_CONFIG_ENABLE: u8 = 0;
+const NL80211_TID_CONFIG_DISABLE: u8 = 1;
+
+fn tid_config_state_to_u8(enable: bool) -> u8 {
+    if enable {
+        NL80211_TID_CONFIG_ENABLE
+    } else {
+        NL80211_TID_CONFIG_DISABLE
+    }
+}
+
+fn parse_tid_config_state(payload: &[u8]) -> Result<bool, DecodeError> {
+    Ok(parse_u8(payload)? == NL80211_TID_CONFIG_ENABLE)
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum Nl80211TidConfigNla {
+    /// Bitmap of the TID configuration attributes supported per interface,
+    /// bit N set for attribute kind N
+    VifSupp(u64),
+    /// Bitmap of the TID configuration attributes supported per peer, bit N
+    /// set for attribute kind N
+    PeerSupp(u64),
+    /// Reset the per-peer configurations of the TIDs to the interface
+    /// configuration
+    Override,
+    /// TIDs to configure, bit N set for TID N
+    Tids(u16),
+    /// Whether frames are sent without requesting an acknowledgement
+    Noack(bool),
+    /// Retry limit for frames not longer than the RTS threshold, the
+    /// maximum supported value in the wiphy dump
+    RetryShort(u8),
+    /// Retry limit for frames longer than the RTS threshold, the maximum
+    /// supported value in the wiphy dump
+    RetryLong(u8),
+    /// Whether A-MPDU aggregation is enabled
+    AmpduCtrl(bool),
+    /// Whether RTS/CTS protection is enabled
+    RtsctsCtrl(bool),
+    /// Whether A-MSDU aggregation is enabled
+    AmsduCtrl(bool),
+    /// How the `TxRate` rates are applied
+    TxRateType(Nl80211TxRateSetting),
+    /// TX rates of data frames per band
+    TxRate(Vec<Nl80211TxRates>),
+    Other(DefaultNla),
+}
+
+impl Nla for Nl80211TidConfigNla {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::Override => 0,
+            Self::Noack(_)
+            | Self::RetryShort(_)
+            | Self::RetryLong(_)
+            | Self::AmpduCtrl(_)
+            | Self::RtsctsCtrl(_)
+            | Self::AmsduCtrl(_)
+            | Self::TxRateType(_) => 1,
+            Self::Tids(_) => 2,
+            Self::VifSupp(_) | Self::PeerSupp(_) => 8,
+            Self::TxRate(ref d) => d.as_slice().buffer_len(),
+            Self::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::VifSupp(_) => NL80211_TID_CONFIG_ATTR_VIF_SUPP,
+            Self::PeerSupp(_) => NL80211_TID_CONFIG_ATTR_PEER_SUPP,
+            Self::Override => NL80211_TID_CONFIG_ATTR_OVERRIDE,
+            Self::Tids(_) => NL80211_TID_CONFIG_ATTR_TIDS,
+            Self::Noack(_) => NL80211_TID_CONFIG_ATTR_NOACK,
+            Self::RetryShort(_) => NL80211_TID_CONFIG_ATTR_RETRY_SHORT,
+            Self::RetryLong(_) => NL80211_TID_CONFIG_ATTR_RETRY_LONG,
+            Self::AmpduCtrl(_) => NL80211_TID_CONFIG_ATTR_AMPDU_CTRL,
+            Self::RtsctsCtrl(_) => NL80211_TID_CONFIG_ATTR_RTSCTS_CTRL,
+            Self::AmsduCtrl(_) => NL80211_TID_CONFIG_ATTR_AMSDU_CTRL,
+            Self::TxRateType(_) => NL80211_TID_CONFIG_ATTR_TX_RATE_TYPE,
+            Self::TxRate(_) => NL80211_TID_CONFIG_ATTR_TX_RATE,
+            Self::Other(attr) => attr.kind(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Override => (),
+            Self::RetryShort(d) | Self::RetryLong(d) => buffer[0] = *d,
+            Self::Tids(d) => NativeEndian::write_u16(buffer, *d),
+            Self::VifSupp(d) | Self::PeerSupp(d) => {
+                NativeEndian::write_u64(buffer, *d)
+            }
+            Self::Noack(d)
+            | Self::AmpduCtrl(d)
+            | Self::RtsctsCtrl(d)
+            | Self::AmsduCtrl(d) => buffer[0] = tid_config_state_to_u8(*d),
+            Self::TxRateType(d) => buffer[0] = (*d).into(),
+            Self::TxRate(ref d) => d.as_slice().emit(buffer),
+            Self::Other(ref attr) => attr.emit(buffer),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for Nl80211TidConfigNla
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            NL80211_TID_CONFIG_ATTR_VIF_SUPP => {
+                let err_msg = format!(
+                    "Invalid NL80211_TID_CONFIG_ATTR_VIF_SUPP value {:?}",
+                    payload
+                );
+                Self::VifSupp(parse_u64(payload).context(err_msg)?)
+            }
+            NL80211_TID_CONFIG_ATTR_PEER_SUPP => {
+                let err_msg = format!(
+                    "Invalid NL80211_TID_CONFIG_ATTR_PEER_SUPP value {:?}",
+                    payload
+                );
+                Self::PeerSupp(parse_u64(payload).context(err_msg)?)
+            }
+            NL80211_TID_CONFIG_ATTR_OVERRIDE => Self::Override,
+            NL80211_TID_CONFIG_ATTR_TIDS => {
+                let err_msg = format!(
+                    "Invalid NL80211_TID_CONFIG_ATTR_TIDS value {:?}",
+                    payload
+                );
+                Self::Tids(parse_u16(payload).context(err_msg)?)
+            }
+            NL80211_TID_CONFIG_ATTR_NOACK => {
+                let err_msg = format!(
+                    "Invalid NL80211_TID_CONFIG_ATTR_NOACK value {:?}",
+                    payload
+                );
+                Self::Noack(parse_tid_config_state(payload).context(err_msg)?)
+            }
+            NL80211_TID_CONFIG_ATTR_RETRY_SHORT => {
+                let err_msg = format!(
+                    "Invalid NL80211_TID_CONFIG_ATTR_RETRY_SHORT value {:?}",
+                    payload
+                );
+                Self::RetryShort(parse_u8(payload).context(err_msg)?)
+            }
+            NL80211_TID_CONFIG_ATTR_RETRY_LONG => {
+                let err_msg = format!(
+                    "Invalid NL80211_TID_CONFIG_ATTR_RETRY_LONG value {:?}",
+                    payload
+                );
+                Self::RetryLong(parse_u8(payload).context(err_msg)?)
+            }
+            NL80211_TID_CONFIG_ATTR_AMPDU_CTRL => {
+                let err_msg = format!(
+                    "Invalid NL80211_TID_CONFIG_ATTR_AMPDU_CTRL value {:?}",
+                    payload
+                );
+                Self::AmpduCtrl(
+                    parse_tid_config_state(payload).context(err_msg)?,
+                )
+            }
+            NL80211_TID_CONFIG_ATTR_RTSCTS_CTRL => {
+                let err_msg = format!(
+                    "Invalid NL80211_TID_CONFIG_ATTR_RTSCTS_CTRL value {:?}",
+                    payload
+                );
+                Self::RtsctsCtrl(
+                    parse_tid_config_state(payload).context(err_msg)?,
+                )
+            }
+            NL80211_TID_CONFIG_ATTR_AMSDU_CTRL => {
+                let err_msg = format!(
+                    "Invalid NL80211_TID_CONFIG_ATTR_AMSDU_CTRL value {:?}",
+                    payload
+                );
+                Self::AmsduCtrl(
+                    parse_tid_config_state(payload).context(err_msg)?,
+                )
+            }
+            NL80211_TID_CONFIG_ATTR_TX_RATE_TYPE => {
+                let err_msg = format!(
+                    "Invalid NL80211_TID_CONFIG_ATTR_TX_RATE_TYPE value {:?}",
+                    payload
+                );
+                Self::TxRateType(parse_u8(payload).context(err_msg)?.into())
+            }
+            NL80211_TID_CONFIG_ATTR_TX_RATE => {
+                let err_msg = format!(
+                    "Invalid NL80211_TID_CONFIG_ATTR_TX_RATE value {:?}",
+                    payload
+                );
+                let mut rates = Vec::new();
+                for nla in NlasIterator::new(payload) {
+                    let nla = &nla.context(err_msg.clone())?;
+                    rates.push(
+                        Nl80211TxRates::parse(nla).context(err_msg.clone())?,
+                    );
+                }
+                Self::TxRate(rates)
+            }
+            _ => Self::Other(
+                DefaultNla::parse(buf).context("invalid NLA (unknown kind)")?,
+            ),
+        })
+    }
+}
+
+/// Configuration of a set of TIDs, applied to all peers of the interface or
+/// to a single peer. Settings not set are left unchanged.
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct Nl80211TidConfig {
+    /// Reset the per-peer configurations of the TIDs to the interface
+    /// configuration, only valid for interface configuration
+    pub override_peers: bool,
+    /// TIDs to configure, bit N set for TID N
+    pub tids: u16,
+    /// Whether frames are sent without requesting an acknowledgement
+    pub noack: Option<bool>,
+    /// Retry limit for frames not longer than the RTS threshold
+    pub retry_short: Option<u8>,
+    /// Retry limit for frames longer than the RTS threshold
+    pub retry_long: Option<u8>,
+    /// Whether A-MPDU aggregation is enabled
+    pub ampdu: Option<bool>,
+    /// Whether RTS/CTS protection is enabled
+    pub rtscts: Option<bool>,
+    /// Whether A-MSDU aggregation is enabled
+    pub amsdu: Option<bool>,
+    /// How `tx_rate` is applied
+    pub tx_rate_type: Option<Nl80211TxRateSetting>,
+    /// TX rates of data frames per band
+    pub tx_rate: Option<Vec<Nl80211TxRates>>,
+}
+
+impl Nl80211TidConfig {
+    /// Configuration of the TIDs in the `tids` bitmap, bit N set for TID N
+    pub fn new(tids: u16) -> Self {
+        Self {
+            tids,
+            ..Default::default()
+        }
+    }
+
+    /// Reset the per-peer configurations of the TIDs to the interface
+    /// configuration, only valid without peer
+    pub fn override_peers(mut self) -> Self {
+        self.override_peers = true;
+        self
+    }
+
+    pub fn noack(mut self, enable: bool) -> Self {
+        self.noack = Some(enable);
+        self
+    }
+
+    pub fn retry_short(mut self, limit: u8) -> Self {
+        self.retry_short = Some(limit);
+        self
+    }
+
+    pub fn retry_long(mut self, limit: u8) -> Self {
+        self.retry_long = Some(limit);
+        self
+    }
+
+    pub fn ampdu(mut self, enable: bool) -> Self {
+        self.ampdu = Some(enable);
+        self
+    }
+
+    pub fn rtscts(mut self, enable: bool) -> Self {
+        self.rtscts = Some(enable);
+        self
+    }
+
+    pub fn amsdu(mut self, enable: bool) -> Self {
+        self.amsdu = Some(enable);
+        self
+    }
+
+    /// Let the rate control algorithm select any rate
+    pub fn tx_rate_auto(mut self) -> Self {
+        self.tx_rate_type = Some(Nl80211TxRateSetting::Automatic);
+        self.tx_rate = None;
+        self
+    }
+
+    /// Limit the rate control algorithm to the `rates`
+    pub fn tx_rate_limited(mut self, rates: Vec<Nl80211TxRates>) -> Self {
+        self.tx_rate_type = Some(Nl80211TxRateSetting::Limited);
+        self.tx_rate = Some(rates);
+        self
+    }
+
+    /// Use a fixed rate, which should be the single rate allowed by `rates`
+    pub fn tx_rate_fixed(mut self, rates: Vec<Nl80211TxRates>) -> Self {
+        self.tx_rate_type = Some(Nl80211TxRateSetting::Fixed);
+        self.tx_rate = Some(rates);
+        self
+    }
+}
+
+impl Emitable for Nl80211TidConfig {
+    fn buffer_len(&self) -> usize {
+        Vec::<Nl80211TidConfigNla>::from(self)
+            .as_slice()
+            .buffer_len()
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        Vec::<Nl80211TidConfigNla>::from(self)
+            .as_slice()
+            .emit(buffer)
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for Nl80211TidConfig
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let mut ret = Self::default();
+        let payload = buf.value();
+        let err_msg =
+            format!("Invalid NL80211_ATTR_TID_CONFIG value {:?}", payload);
+        for nla in NlasIterator::new(payload) {
+            let nla = &nla.context(err_msg.clone())?;
+            match Nl80211TidConfigNla::parse(nla).context(err_msg.clone())? {
+                Nl80211TidConfigNla::Override => ret.override_peers = true,
+                Nl80211TidConfigNla::Tids(d) => ret.tids = d,
+                Nl80211TidConfigNla::Noack(d) => ret.noack = Some(d),
+                Nl80211TidConfigNla::RetryShort(d) => ret.retry_short = Some(d),
+                Nl80211TidConfigNla::RetryLong(d) => ret.retry_long = Some(d),
+                Nl80211TidConfigNla::AmpduCtrl(d) => ret.ampdu = Some(d),
+                Nl80211TidConfigNla::RtsctsCtrl(d) => ret.rtscts = Some(d),
+                Nl80211TidConfigNla::AmsduCtrl(d) => ret.amsdu = Some(d),
+                Nl80211TidConfigNla::TxRateType(d) => {
+                    ret.tx_rate_type = Some(d)
+                }
+                Nl80211TidConfigNla::TxRate(d) => ret.tx_rate = Some(d),
+                attr => {
+                    log::warn!(
+                        "Got unsupported NL80211_ATTR_TID_CONFIG value {:?}",
+                        attr
+                    )
+                }
+            }
+        }
+        Ok(ret)
+    }
+}
+
+impl From<&Nl80211TidConfig> for Vec<Nl80211TidConfigNla> {
+    fn from(conf: &Nl80211TidConfig) -> Self {
+        let mut nlas = vec![Nl80211TidConfigNla::Tids(conf.tids)];
+        if conf.override_peers {
+            nlas.push(Nl80211TidConfigNla::Override);
+        }
+        if let Some(v) = conf.noack {
+            nlas.push(Nl80211TidConfigNla::Noack(v));
+        }
+        if let Some(v) = conf.retry_short {
+            nlas.push(Nl80211TidConfigNla::RetryShort(v));
+        }
+        if let Some(v) = conf.retry_long {
+            nlas.push(Nl80211TidConfigNla::RetryLong(v));
+        }
+        if let Some(v) = conf.ampdu {
+            nlas.push(Nl80211TidConfigNla::AmpduCtrl(v));
+        }
+        if let Some(v) = conf.rtscts {
+            nlas.push(Nl80211TidConfigNla::RtsctsCtrl(v));
+        }
+        if let Some(v) = conf.amsdu {
+            nlas.push(Nl80211TidConfigNla::AmsduCtrl(v));
+        }
+        if let Some(v) = conf.tx_rate_type {
+            nlas.push(Nl80211TidConfigNla::TxRateType(v));
+        }
+        if let Some(v) = conf.tx_rate.as_ref() {
+            nlas.push(Nl80211TidConfigNla::TxRate(v.clone()));
+        }
+        nlas
+    }
+}
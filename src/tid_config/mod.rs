@@ -0,0 +1,11 @@
+// SPDX-License-Identifier: MIT
+
+mod config;
+mod rate_setting;
+mod set;
+mod support;
+
+pub use config::{Nl80211TidConfig, Nl80211TidConfigNla};
+pub use rate_setting::Nl80211TxRateSetting;
+pub use set::Nl80211TidConfigSetRequest;
+pub use support::Nl80211TidConfigSupport;
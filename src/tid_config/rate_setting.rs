@@ -0,0 +1,40 @@
+// SPDX-License-Identifier: MIT
+
+const NL80211_TX_RATE_AUTOMATIC: u8 = 0;
+const NL80211_TX_RATE_LIMITED: u8 = 1;
+const NL80211_TX_RATE_FIXED: u8 = 2;
+
+/// How the configured TX rates are applied
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Nl80211TxRateSetting {
+    /// Rate selected by the rate control algorithm, configured TX rates are
+    /// ignored
+    Automatic,
+    /// Rate control algorithm limited to the configured TX rates
+    Limited,
+    /// Fixed to the single configured TX rate
+    Fixed,
+    Other(u8),
+}
+
+impl From<u8> for Nl80211TxRateSetting {
+    fn from(d: u8) -> Self {
+        match d {
+            NL80211_TX_RATE_AUTOMATIC => Self::Automatic,
+            NL80211_TX_RATE_LIMITED => Self::Limited,
+            NL80211_TX_RATE_FIXED => Self::Fixed,
+            _ => Self::Other(d),
+        }
+    }
+}
+
+impl From<Nl80211TxRateSetting> for u8 {
+    fn from(v: Nl80211TxRateSetting) -> u8 {
+        match v {
+            Nl80211TxRateSetting::Automatic => NL80211_TX_RATE_AUTOMATIC,
+            Nl80211TxRateSetting::Limited => NL80211_TX_RATE_LIMITED,
+            Nl80211TxRateSetting::Fixed => NL80211_TX_RATE_FIXED,
+            Nl80211TxRateSetting::Other(d) => d,
+        }
+    }
+}
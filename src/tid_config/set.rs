@@ -0,0 +1,59 @@
+// SPDX-License-Identifier: MIT
+
+use crate::{
+    nl80211_execute_ack, Nl80211Attr, Nl80211Cmd, Nl80211Error, Nl80211Handle,
+    Nl80211Message, Nl80211TidConfig,
+};
+
+pub struct Nl80211TidConfigSetRequest {
+    handle: Nl80211Handle,
+    if_index: u32,
+    peer: Option<[u8; 6]>,
+    configs: Vec<Nl80211TidConfig>,
+}
+
+impl Nl80211TidConfigSetRequest {
+    pub(crate) fn new(handle: Nl80211Handle, if_index: u32) -> Self {
+        Nl80211TidConfigSetRequest {
+            handle,
+            if_index,
+            peer: None,
+            configs: Vec::new(),
+        }
+    }
+
+    /// Override the interface configuration for this peer only
+    pub fn peer(mut self, mac: [u8; 6]) -> Self {
+        self.peer = Some(mac);
+        self
+    }
+
+    /// Append a configuration of a set of TIDs
+    pub fn config(mut self, config: Nl80211TidConfig) -> Self {
+        self.configs.push(config);
+        self
+    }
+
+    /// The kernel rejects settings not listed in the `vif_supp` or
+    /// `peer_supp` bitmaps of the wiphy `Nl80211TidConfigSupport`.
+    pub async fn execute(self) -> Result<(), Nl80211Error> {
+        let Nl80211TidConfigSetRequest {
+            mut handle,
+            if_index,
+            peer,
+            configs,
+        } = self;
+
+        let mut nlas = vec![Nl80211Attr::IfIndex(if_index)];
+        if let Some(mac) = peer {
+            nlas.push(Nl80211Attr::Mac(mac));
+        }
+        nlas.push(Nl80211Attr::TidConfig(configs));
+
+        let nl80211_msg = Nl80211Message {
+            cmd: Nl80211Cmd::TidConfigSet,
+            nlas,
+        };
+        nl80211_execute_ack(&mut handle, nl80211_msg).await
+    }
+}
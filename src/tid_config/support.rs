@@ -0,0 +1,88 @@
+// SPDX-License-Identifier: MIT
+
+use anyhow::Context;
+use netlink_packet_utils::{
+    nla::{NlaBuffer, NlasIterator},
+    DecodeError, Emitable, Parseable,
+};
+
+use crate::Nl80211TidConfigNla;
+
+/// TID configuration supported by the wiphy
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct Nl80211TidConfigSupport {
+    /// Bitmap of the TID configuration attributes supported per interface,
+    /// bit N set for attribute kind N
+    pub vif_supp: Option<u64>,
+    /// Bitmap of the TID configuration attributes supported per peer, bit N
+    /// set for attribute kind N
+    pub peer_supp: Option<u64>,
+    /// Maximum retry limit for frames not longer than the RTS threshold
+    pub max_retry_short: Option<u8>,
+    /// Maximum retry limit for frames longer than the RTS threshold
+    pub max_retry_long: Option<u8>,
+}
+
+impl Emitable for Nl80211TidConfigSupport {
+    fn buffer_len(&self) -> usize {
+        Vec::<Nl80211TidConfigNla>::from(self)
+            .as_slice()
+            .buffer_len()
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        Vec::<Nl80211TidConfigNla>::from(self)
+            .as_slice()
+            .emit(buffer)
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for Nl80211TidConfigSupport
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let mut ret = Self::default();
+        let payload = buf.value();
+        let err_msg =
+            format!("Invalid NL80211_ATTR_TID_CONFIG value {:?}", payload);
+        for nla in NlasIterator::new(payload) {
+            let nla = &nla.context(err_msg.clone())?;
+            match Nl80211TidConfigNla::parse(nla).context(err_msg.clone())? {
+                Nl80211TidConfigNla::VifSupp(d) => ret.vif_supp = Some(d),
+                Nl80211TidConfigNla::PeerSupp(d) => ret.peer_supp = Some(d),
+                Nl80211TidConfigNla::RetryShort(d) => {
+                    ret.max_retry_short = Some(d)
+                }
+                Nl80211TidConfigNla::RetryLong(d) => {
+                    ret.max_retry_long = Some(d)
+                }
+                attr => {
+                    log::warn!(
+                        "Got unsupported NL80211_ATTR_TID_CONFIG value {:?}",
+                        attr
+                    )
+                }
+            }
+        }
+        Ok(ret)
+    }
+}
+
+impl From<&Nl80211TidConfigSupport> for Vec<Nl80211TidConfigNla> {
+    fn from(supp: &Nl80211TidConfigSupport) -> Self {
+        let mut nlas = Vec::new();
+        if let Some(v) = supp.vif_supp {
+            nlas.push(Nl80211TidConfigNla::VifSupp(v));
+        }
+        if let Some(v) = supp.peer_supp {
+            nlas.push(Nl80211TidConfigNla::PeerSupp(v));
+        }
+        if let Some(v) = supp.max_retry_short {
+            nlas.push(Nl80211TidConfigNla::RetryShort(v));
+        }
+        if let Some(v) = supp.max_retry_long {
+            nlas.push(Nl80211TidConfigNla::RetryLong(v));
+        }
+        nlas
+    }
+}
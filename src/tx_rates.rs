@@ -0,0 +1,211 @@
+// SPDX-License-Identifier: MIT
+
+use anyhow::Context;
+use byteorder::{ByteOrder, NativeEndian};
+use netlink_packet_utils::{
+    nla::{DefaultNla, Nla, NlaBuffer, NlasIterator},
+    DecodeError, Emitable, Parseable,
+};
+
+use crate::Nl80211Band;
+
+const NL80211_TXRATE_LEGACY: u16 = 1;
+const NL80211_TXRATE_HT: u16 = 2;
+const NL80211_TXRATE_VHT: u16 = 3;
+const NL80211_TXRATE_HE: u16 = 5;
+
+const NL80211_VHT_NSS_MAX: usize = 8;
+const NL80211_HE_NSS_MAX: usize = 8;
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum Nl80211TxRateNla {
+    /// Legacy rates in units of 500 kbps
+    Legacy(Vec<u8>),
+    /// HT MCS indexes
+    Ht(Vec<u8>),
+    /// VHT MCS bitmap per number of spatial streams, bit N set for MCS N
+    Vht([u16; NL80211_VHT_NSS_MAX]),
+    /// HE MCS bitmap per number of spatial streams, bit N set for MCS N
+    He([u16; NL80211_HE_NSS_MAX]),
+    Other(DefaultNla),
+}
+
+impl Nla for Nl80211TxRateNla {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::Legacy(ref d) | Self::Ht(ref d) => d.len(),
+            Self::Vht(ref d) | Self::He(ref d) => d.len() * 2,
+            Self::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::Legacy(_) => NL80211_TXRATE_LEGACY,
+            Self::Ht(_) => NL80211_TXRATE_HT,
+            Self::Vht(_) => NL80211_TXRATE_VHT,
+            Self::He(_) => NL80211_TXRATE_HE,
+            Self::Other(attr) => attr.kind(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Legacy(ref d) | Self::Ht(ref d) => {
+                buffer[..d.len()].copy_from_slice(d)
+            }
+            Self::Vht(ref d) | Self::He(ref d) => {
+                NativeEndian::write_u16_into(d, &mut buffer[..d.len() * 2])
+            }
+            Self::Other(ref attr) => attr.emit(buffer),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for Nl80211TxRateNla
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            NL80211_TXRATE_LEGACY => Self::Legacy(payload.to_vec()),
+            NL80211_TXRATE_HT => Self::Ht(payload.to_vec()),
+            NL80211_TXRATE_VHT => {
+                let err_msg =
+                    format!("Invalid NL80211_TXRATE_VHT value {:?}", payload);
+                Self::Vht(parse_mcs_bitmaps(payload).context(err_msg)?)
+            }
+            NL80211_TXRATE_HE => {
+                let err_msg =
+                    format!("Invalid NL80211_TXRATE_HE value {:?}", payload);
+                Self::He(parse_mcs_bitmaps(payload).context(err_msg)?)
+            }
+            _ => Self::Other(
+                DefaultNla::parse(buf).context("invalid NLA (unknown kind)")?,
+            ),
+        })
+    }
+}
+
+fn parse_mcs_bitmaps(payload: &[u8]) -> Result<[u16; 8], DecodeError> {
+    let mut ret = [0u16; 8];
+    if payload.len() < ret.len() * 2 {
+        return Err(DecodeError::from("Payload too short"));
+    }
+    NativeEndian::read_u16_into(&payload[..ret.len() * 2], &mut ret);
+    Ok(ret)
+}
+
+/// Rates of a band allowed for transmission. Rate types not set keep all the
+/// rates supported by the wiphy, while setting a rate type not supported by
+/// the wiphy is rejected. A beacon rate requires exactly one allowed rate,
+/// for example an HT MCS with empty legacy rates.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Nl80211TxRates {
+    pub band: Nl80211Band,
+    /// Legacy rates in units of 500 kbps
+    pub legacy: Option<Vec<u8>>,
+    /// HT MCS indexes
+    pub ht: Option<Vec<u8>>,
+    /// VHT MCS bitmap per number of spatial streams, bit N set for MCS N
+    pub vht: Option<[u16; NL80211_VHT_NSS_MAX]>,
+    /// HE MCS bitmap per number of spatial streams, bit N set for MCS N
+    pub he: Option<[u16; NL80211_HE_NSS_MAX]>,
+}
+
+impl Nl80211TxRates {
+    pub fn new(band: Nl80211Band) -> Self {
+        Self {
+            band,
+            legacy: None,
+            ht: None,
+            vht: None,
+            he: None,
+        }
+    }
+
+    /// Allowed legacy rates in units of 500 kbps, e.g. 12 for 6 Mbps
+    pub fn legacy(mut self, rates: Vec<u8>) -> Self {
+        self.legacy = Some(rates);
+        self
+    }
+
+    /// Allowed HT MCS indexes
+    pub fn ht(mut self, mcs: Vec<u8>) -> Self {
+        self.ht = Some(mcs);
+        self
+    }
+
+    /// Allowed VHT MCS bitmap per number of spatial streams
+    pub fn vht(mut self, mcs: [u16; NL80211_VHT_NSS_MAX]) -> Self {
+        self.vht = Some(mcs);
+        self
+    }
+
+    /// Allowed HE MCS bitmap per number of spatial streams
+    pub fn he(mut self, mcs: [u16; NL80211_HE_NSS_MAX]) -> Self {
+        self.he = Some(mcs);
+        self
+    }
+}
+
+impl From<&Nl80211TxRates> for Vec<Nl80211TxRateNla> {
+    fn from(rates: &Nl80211TxRates) -> Self {
+        let mut nlas = Vec::new();
+        if let Some(v) = rates.legacy.as_ref() {
+            nlas.push(Nl80211TxRateNla::Legacy(v.clone()));
+        }
+        if let Some(v) = rates.ht.as_ref() {
+            nlas.push(Nl80211TxRateNla::Ht(v.clone()));
+        }
+        if let Some(v) = rates.vht {
+            nlas.push(Nl80211TxRateNla::Vht(v));
+        }
+        if let Some(v) = rates.he {
+            nlas.push(Nl80211TxRateNla::He(v));
+        }
+        nlas
+    }
+}
+
+// The attribute kind is the band
+impl Nla for Nl80211TxRates {
+    fn value_len(&self) -> usize {
+        Vec::<Nl80211TxRateNla>::from(self).as_slice().buffer_len()
+    }
+
+    fn kind(&self) -> u16 {
+        u32::from(self.band) as u16
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        Vec::<Nl80211TxRateNla>::from(self).as_slice().emit(buffer)
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for Nl80211TxRates
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let mut ret = Self::new(Nl80211Band::from(buf.kind() as u32));
+        let payload = buf.value();
+        let err_msg =
+            format!("Invalid NL80211_ATTR_TX_RATES value {:?}", payload);
+        for nla in NlasIterator::new(payload) {
+            let nla = &nla.context(err_msg.clone())?;
+            match Nl80211TxRateNla::parse(nla).context(err_msg.clone())? {
+                Nl80211TxRateNla::Legacy(d) => ret.legacy = Some(d),
+                Nl80211TxRateNla::Ht(d) => ret.ht = Some(d),
+                Nl80211TxRateNla::Vht(d) => ret.vht = Some(d),
+                Nl80211TxRateNla::He(d) => ret.he = Some(d),
+                Nl80211TxRateNla::Other(attr) => {
+                    log::warn!(
+                        "Got unsupported NL80211_ATTR_TX_RATES value {:?}",
+                        attr
+                    )
+                }
+            }
+        }
+        Ok(ret)
+    }
+}
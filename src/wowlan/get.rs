@@ -0,0 +1,36 @@
+// SPDX-License-Identifier: MIT
+
+use futures::TryStream;
+use netlink_packet_core::NLM_F_REQUEST;
+use netlink_packet_generic::GenlMessage;
+
+use crate::{
+    nl80211_execute, Nl80211Attr, Nl80211Cmd, Nl80211Error, Nl80211Handle,
+    Nl80211Message,
+};
+
+pub struct Nl80211WowlanGetRequest {
+    handle: Nl80211Handle,
+    wiphy: u32,
+}
+
+impl Nl80211WowlanGetRequest {
+    pub(crate) fn new(handle: Nl80211Handle, wiphy: u32) -> Self {
+        Nl80211WowlanGetRequest { handle, wiphy }
+    }
+
+    /// The reply holds `Nl80211Attr::WowlanTriggers` only when Wake on WLAN
+    /// is enabled.
+    pub async fn execute(
+        self,
+    ) -> impl TryStream<Ok = GenlMessage<Nl80211Message>, Error = Nl80211Error>
+    {
+        let Nl80211WowlanGetRequest { mut handle, wiphy } = self;
+
+        let nl80211_msg = Nl80211Message {
+            cmd: Nl80211Cmd::WowlanGet,
+            nlas: vec![Nl80211Attr::WiPhy(wiphy)],
+        };
+        nl80211_execute(&mut handle, nl80211_msg, NLM_F_REQUEST).await
+    }
+}
@@ -0,0 +1,24 @@
+// SPDX-License-Identifier: MIT
+
+use crate::{Nl80211Handle, Nl80211WowlanGetRequest, Nl80211WowlanSetRequest};
+
+pub struct Nl80211WowlanHandle(Nl80211Handle);
+
+impl Nl80211WowlanHandle {
+    pub fn new(handle: Nl80211Handle) -> Self {
+        Nl80211WowlanHandle(handle)
+    }
+
+    /// Retrieve the Wake on WLAN triggers configured on specified wiphy
+    /// (equivalent to `iw phy PHY wowlan show`)
+    pub fn get(&mut self, wiphy: u32) -> Nl80211WowlanGetRequest {
+        Nl80211WowlanGetRequest::new(self.0.clone(), wiphy)
+    }
+
+    /// Configure the Wake on WLAN triggers of specified wiphy, Wake on WLAN
+    /// is disabled when no trigger is set
+    /// (equivalent to `iw phy PHY wowlan enable ...`)
+    pub fn set(&mut self, wiphy: u32) -> Nl80211WowlanSetRequest {
+        Nl80211WowlanSetRequest::new(self.0.clone(), wiphy)
+    }
+}
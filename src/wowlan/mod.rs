@@ -0,0 +1,24 @@
+// SPDX-License-Identifier: MIT
+
+mod get;
+mod handle;
+mod pattern;
+mod set;
+mod tcp;
+mod triggers;
+mod triggers_supported;
+
+pub use get::Nl80211WowlanGetRequest;
+pub use handle::Nl80211WowlanHandle;
+pub use pattern::{
+    Nl80211PacketPattern, Nl80211PacketPatternNla, Nl80211PatternSupport,
+};
+pub use set::Nl80211WowlanSetRequest;
+pub use tcp::{
+    Nl80211WowlanTcp, Nl80211WowlanTcpDataSeq, Nl80211WowlanTcpDataToken,
+    Nl80211WowlanTcpNla,
+};
+pub use triggers::{Nl80211WowlanTriggerNla, Nl80211WowlanTriggers};
+pub use triggers_supported::{
+    Nl80211WowlanTriggersSupported, Nl80211WowlanTriggersSupportedNla,
+};
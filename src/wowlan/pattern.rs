@@ -0,0 +1,222 @@
+// SPDX-License-Identifier: MIT
+
+use anyhow::Context;
+use byteorder::{ByteOrder, NativeEndian};
+use netlink_packet_utils::{
+    nla::{DefaultNla, Nla, NlaBuffer, NlasIterator},
+    parsers::parse_u32,
+    DecodeError, Emitable, Parseable,
+};
+
+const NL80211_PKTPAT_MASK: u16 = 1;
+const NL80211_PKTPAT_PATTERN: u16 = 2;
+const NL80211_PKTPAT_OFFSET: u16 = 3;
+
+/// Packet pattern matching capabilities of the wiphy
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+pub struct Nl80211PatternSupport {
+    /// Maximum number of patterns
+    pub max_patterns: u32,
+    /// Minimum length of a pattern in bytes
+    pub min_pattern_len: u32,
+    /// Maximum length of a pattern in bytes
+    pub max_pattern_len: u32,
+    /// Maximum packet offset the pattern can be matched at
+    pub max_pkt_offset: u32,
+}
+
+impl Nl80211PatternSupport {
+    pub(crate) const LENGTH: usize = 16;
+
+    pub(crate) fn parse(payload: &[u8]) -> Result<Self, DecodeError> {
+        if payload.len() < Self::LENGTH {
+            return Err(format!(
+                "Invalid NL80211_WOWLAN_TRIG_PKT_PATTERN {:?}",
+                payload
+            )
+            .into());
+        }
+        Ok(Self {
+            max_patterns: NativeEndian::read_u32(&payload[0..4]),
+            min_pattern_len: NativeEndian::read_u32(&payload[4..8]),
+            max_pattern_len: NativeEndian::read_u32(&payload[8..12]),
+            max_pkt_offset: NativeEndian::read_u32(&payload[12..16]),
+        })
+    }
+}
+
+impl Emitable for Nl80211PatternSupport {
+    fn buffer_len(&self) -> usize {
+        Self::LENGTH
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        NativeEndian::write_u32(&mut buffer[0..4], self.max_patterns);
+        NativeEndian::write_u32(&mut buffer[4..8], self.min_pattern_len);
+        NativeEndian::write_u32(&mut buffer[8..12], self.max_pattern_len);
+        NativeEndian::write_u32(&mut buffer[12..16], self.max_pkt_offset);
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum Nl80211PacketPatternNla {
+    /// Bitmask of the pattern bytes to match, bit 0 of the first byte
+    /// selects the first byte of the pattern
+    Mask(Vec<u8>),
+    /// Bytes to match
+    Pattern(Vec<u8>),
+    /// Offset of the pattern in the packet
+    Offset(u32),
+    Other(DefaultNla),
+}
+
+impl Nla for Nl80211PacketPatternNla {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::Offset(_) => 4,
+            Self::Mask(ref d) => d.len(),
+            Self::Pattern(ref d) => d.len(),
+            Self::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::Mask(_) => NL80211_PKTPAT_MASK,
+            Self::Pattern(_) => NL80211_PKTPAT_PATTERN,
+            Self::Offset(_) => NL80211_PKTPAT_OFFSET,
+            Self::Other(attr) => attr.kind(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Offset(d) => NativeEndian::write_u32(buffer, *d),
+            Self::Mask(ref d) => buffer[..d.len()].copy_from_slice(d),
+            Self::Pattern(ref d) => buffer[..d.len()].copy_from_slice(d),
+            Self::Other(ref attr) => attr.emit(buffer),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for Nl80211PacketPatternNla
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            NL80211_PKTPAT_MASK => Self::Mask(payload.to_vec()),
+            NL80211_PKTPAT_PATTERN => Self::Pattern(payload.to_vec()),
+            NL80211_PKTPAT_OFFSET => {
+                let err_msg = format!(
+                    "Invalid NL80211_PKTPAT_OFFSET value {:?}",
+                    payload
+                );
+                Self::Offset(parse_u32(payload).context(err_msg)?)
+            }
+            _ => Self::Other(
+                DefaultNla::parse(buf).context("invalid NLA (unknown kind)")?,
+            ),
+        })
+    }
+}
+
+/// Packet pattern to match received frames against
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct Nl80211PacketPattern {
+    /// Bitmask of the pattern bytes to match, bit 0 of the first byte
+    /// selects the first byte of the pattern
+    pub mask: Option<Vec<u8>>,
+    /// Bytes to match
+    pub pattern: Option<Vec<u8>>,
+    /// Offset of the pattern in the packet
+    pub offset: Option<u32>,
+}
+
+impl Nl80211PacketPattern {
+    /// Pattern matching all of the specified bytes
+    pub fn new(pattern: &[u8]) -> Self {
+        let mut mask = vec![0u8; pattern.len().div_ceil(8)];
+        for i in 0..pattern.len() {
+            mask[i / 8] |= 1 << (i % 8);
+        }
+        Self {
+            mask: Some(mask),
+            pattern: Some(pattern.to_vec()),
+            offset: None,
+        }
+    }
+
+    /// Only match the pattern bytes whose bit is set in the mask
+    pub fn mask(mut self, mask: &[u8]) -> Self {
+        self.mask = Some(mask.to_vec());
+        self
+    }
+
+    /// Match the pattern at specified offset of the packet
+    pub fn offset(mut self, offset: u32) -> Self {
+        self.offset = Some(offset);
+        self
+    }
+}
+
+impl Emitable for Nl80211PacketPattern {
+    fn buffer_len(&self) -> usize {
+        Vec::<Nl80211PacketPatternNla>::from(self)
+            .as_slice()
+            .buffer_len()
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        Vec::<Nl80211PacketPatternNla>::from(self)
+            .as_slice()
+            .emit(buffer)
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for Nl80211PacketPattern
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let mut ret = Self::default();
+        let payload = buf.value();
+        let err_msg = format!(
+            "Invalid NL80211_WOWLAN_TRIG_PKT_PATTERN value {:?}",
+            payload
+        );
+        for nla in NlasIterator::new(payload) {
+            let nla = &nla.context(err_msg.clone())?;
+            match Nl80211PacketPatternNla::parse(nla)
+                .context(err_msg.clone())?
+            {
+                Nl80211PacketPatternNla::Mask(d) => ret.mask = Some(d),
+                Nl80211PacketPatternNla::Pattern(d) => ret.pattern = Some(d),
+                Nl80211PacketPatternNla::Offset(d) => ret.offset = Some(d),
+                Nl80211PacketPatternNla::Other(attr) => {
+                    log::warn!(
+                        "Got unsupported NL80211_WOWLAN_TRIG_PKT_PATTERN \
+                         value {:?}",
+                        attr
+                    )
+                }
+            }
+        }
+        Ok(ret)
+    }
+}
+
+impl From<&Nl80211PacketPattern> for Vec<Nl80211PacketPatternNla> {
+    fn from(pattern: &Nl80211PacketPattern) -> Self {
+        let mut nlas = Vec::new();
+        if let Some(v) = pattern.mask.as_ref() {
+            nlas.push(Nl80211PacketPatternNla::Mask(v.clone()));
+        }
+        if let Some(v) = pattern.pattern.as_ref() {
+            nlas.push(Nl80211PacketPatternNla::Pattern(v.clone()));
+        }
+        if let Some(v) = pattern.offset {
+            nlas.push(Nl80211PacketPatternNla::Offset(v));
+        }
+        nlas
+    }
+}
@@ -0,0 +1,104 @@
+// SPDX-License-Identifier: MIT
+
+use crate::{
+    nl80211_execute_ack, Nl80211Attr, Nl80211Cmd, Nl80211Error, Nl80211Handle,
+    Nl80211Message, Nl80211PacketPattern, Nl80211WowlanTcp,
+    Nl80211WowlanTriggers,
+};
+
+pub struct Nl80211WowlanSetRequest {
+    handle: Nl80211Handle,
+    wiphy: u32,
+    triggers: Nl80211WowlanTriggers,
+}
+
+impl Nl80211WowlanSetRequest {
+    pub(crate) fn new(handle: Nl80211Handle, wiphy: u32) -> Self {
+        Nl80211WowlanSetRequest {
+            handle,
+            wiphy,
+            triggers: Nl80211WowlanTriggers::default(),
+        }
+    }
+
+    /// Replace all the triggers
+    pub fn triggers(mut self, triggers: Nl80211WowlanTriggers) -> Self {
+        self.triggers = triggers;
+        self
+    }
+
+    /// Wake up on any activity
+    pub fn any(mut self) -> Self {
+        self.triggers.any = true;
+        self
+    }
+
+    /// Wake up on disconnect
+    pub fn disconnect(mut self) -> Self {
+        self.triggers.disconnect = true;
+        self
+    }
+
+    /// Wake up on magic packet
+    pub fn magic_packet(mut self) -> Self {
+        self.triggers.magic_pkt = true;
+        self
+    }
+
+    /// Wake up on packet matching specified pattern, can be used multiple
+    /// times
+    pub fn pattern(mut self, pattern: Nl80211PacketPattern) -> Self {
+        self.triggers.pkt_pattern.push(pattern);
+        self
+    }
+
+    /// Wake up on GTK rekey failure
+    pub fn gtk_rekey_failure(mut self) -> Self {
+        self.triggers.gtk_rekey_failure = true;
+        self
+    }
+
+    /// Wake up on EAP Identity Request packet
+    pub fn eap_ident_request(mut self) -> Self {
+        self.triggers.eap_ident_request = true;
+        self
+    }
+
+    /// Wake up on 4-way handshake
+    pub fn four_way_handshake(mut self) -> Self {
+        self.triggers.four_way_handshake = true;
+        self
+    }
+
+    /// Wake up when rfkill is released
+    pub fn rfkill_release(mut self) -> Self {
+        self.triggers.rfkill_release = true;
+        self
+    }
+
+    /// Wake up on events of specified TCP connection
+    pub fn tcp_connection(mut self, tcp: Nl80211WowlanTcp) -> Self {
+        self.triggers.tcp_connection = Some(tcp);
+        self
+    }
+
+    pub async fn execute(self) -> Result<(), Nl80211Error> {
+        let Nl80211WowlanSetRequest {
+            mut handle,
+            wiphy,
+            triggers,
+        } = self;
+
+        let mut nlas = vec![Nl80211Attr::WiPhy(wiphy)];
+        // Kernel disables Wake on WLAN when no trigger is given
+        if triggers != Nl80211WowlanTriggers::default() {
+            nlas.push(Nl80211Attr::WowlanTriggers(triggers));
+        }
+
+        let nl80211_msg = Nl80211Message {
+            cmd: Nl80211Cmd::WowlanSet,
+            nlas,
+        };
+        nl80211_execute_ack(&mut handle, nl80211_msg).await
+    }
+}
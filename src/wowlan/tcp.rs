@@ -0,0 +1,391 @@
+// SPDX-License-Identifier: MIT
+
+use std::net::Ipv4Addr;
+
+use anyhow::Context;
+use byteorder::{BigEndian, ByteOrder, NativeEndian};
+use netlink_packet_utils::{
+    nla::{DefaultNla, Nla, NlaBuffer, NlasIterator},
+    parsers::{parse_mac, parse_u16, parse_u32, parse_u32_be},
+    DecodeError, Emitable, Parseable,
+};
+
+const ETH_ALEN: usize = 6;
+
+/// Sequence number inserted into the TCP data payload
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+pub struct Nl80211WowlanTcpDataSeq {
+    /// Initial sequence number
+    pub start: u32,
+    /// Offset of the sequence number in the payload
+    pub offset: u32,
+    /// Length of the sequence number in bytes
+    pub len: u32,
+}
+
+impl Nl80211WowlanTcpDataSeq {
+    pub(crate) const LENGTH: usize = 12;
+
+    pub(crate) fn parse(payload: &[u8]) -> Result<Self, DecodeError> {
+        if payload.len() < Self::LENGTH {
+            return Err(format!(
+                "Invalid NL80211_WOWLAN_TCP_DATA_PAYLOAD_SEQ {:?}",
+                payload
+            )
+            .into());
+        }
+        Ok(Self {
+            start: NativeEndian::read_u32(&payload[0..4]),
+            offset: NativeEndian::read_u32(&payload[4..8]),
+            len: NativeEndian::read_u32(&payload[8..12]),
+        })
+    }
+}
+
+impl Emitable for Nl80211WowlanTcpDataSeq {
+    fn buffer_len(&self) -> usize {
+        Self::LENGTH
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        NativeEndian::write_u32(&mut buffer[0..4], self.start);
+        NativeEndian::write_u32(&mut buffer[4..8], self.offset);
+        NativeEndian::write_u32(&mut buffer[8..12], self.len);
+    }
+}
+
+/// Tokens inserted into the TCP data payload, a new token of `len` bytes is
+/// taken from `token_stream` for each packet.
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct Nl80211WowlanTcpDataToken {
+    /// Offset of the token in the payload
+    pub offset: u32,
+    /// Length of a single token in bytes
+    pub len: u32,
+    pub token_stream: Vec<u8>,
+}
+
+impl Nl80211WowlanTcpDataToken {
+    const HEADER_LENGTH: usize = 8;
+
+    pub(crate) fn parse(payload: &[u8]) -> Result<Self, DecodeError> {
+        if payload.len() < Self::HEADER_LENGTH {
+            return Err(format!(
+                "Invalid NL80211_WOWLAN_TCP_DATA_PAYLOAD_TOKEN {:?}",
+                payload
+            )
+            .into());
+        }
+        Ok(Self {
+            offset: NativeEndian::read_u32(&payload[0..4]),
+            len: NativeEndian::read_u32(&payload[4..8]),
+            token_stream: payload[Self::HEADER_LENGTH..].to_vec(),
+        })
+    }
+}
+
+impl Emitable for Nl80211WowlanTcpDataToken {
+    fn buffer_len(&self) -> usize {
+        Self::HEADER_LENGTH + self.token_stream.len()
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        NativeEndian::write_u32(&mut buffer[0..4], self.offset);
+        NativeEndian::write_u32(&mut buffer[4..8], self.len);
+        buffer[Self::HEADER_LENGTH..self.buffer_len()]
+            .copy_from_slice(&self.token_stream);
+    }
+}
+
+const NL80211_WOWLAN_TCP_SRC_IPV4: u16 = 1;
+const NL80211_WOWLAN_TCP_DST_IPV4: u16 = 2;
+const NL80211_WOWLAN_TCP_DST_MAC: u16 = 3;
+const NL80211_WOWLAN_TCP_SRC_PORT: u16 = 4;
+const NL80211_WOWLAN_TCP_DST_PORT: u16 = 5;
+const NL80211_WOWLAN_TCP_DATA_PAYLOAD: u16 = 6;
+const NL80211_WOWLAN_TCP_DATA_PAYLOAD_SEQ: u16 = 7;
+const NL80211_WOWLAN_TCP_DATA_PAYLOAD_TOKEN: u16 = 8;
+const NL80211_WOWLAN_TCP_DATA_INTERVAL: u16 = 9;
+const NL80211_WOWLAN_TCP_WAKE_PAYLOAD: u16 = 10;
+const NL80211_WOWLAN_TCP_WAKE_MASK: u16 = 11;
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum Nl80211WowlanTcpNla {
+    /// Source IPv4 address
+    SrcIpv4(Ipv4Addr),
+    /// Destination IPv4 address
+    DstIpv4(Ipv4Addr),
+    /// MAC address of the destination or of the next hop gateway
+    DstMac([u8; ETH_ALEN]),
+    /// Source port, chosen by the kernel when not specified
+    SrcPort(u16),
+    /// Destination port
+    DstPort(u16),
+    /// Payload sent periodically over the connection
+    DataPayload(Vec<u8>),
+    /// Sequence number inserted into the data payload
+    DataPayloadSeq(Nl80211WowlanTcpDataSeq),
+    /// Tokens inserted into the data payload
+    DataPayloadToken(Nl80211WowlanTcpDataToken),
+    /// Interval in seconds for sending the data payload
+    DataInterval(u32),
+    /// Received payload waking up the host
+    WakePayload(Vec<u8>),
+    /// Bitmask of the wake payload bytes to match
+    WakeMask(Vec<u8>),
+    Other(DefaultNla),
+}
+
+impl Nla for Nl80211WowlanTcpNla {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::SrcPort(_) | Self::DstPort(_) => 2,
+            Self::DataInterval(_) => 4,
+            Self::SrcIpv4(_) => 4,
+            Self::DstIpv4(_) => 4,
+            Self::DstMac(_) => ETH_ALEN,
+            Self::DataPayload(ref d) => d.len(),
+            Self::DataPayloadSeq(ref d) => d.buffer_len(),
+            Self::DataPayloadToken(ref d) => d.buffer_len(),
+            Self::WakePayload(ref d) => d.len(),
+            Self::WakeMask(ref d) => d.len(),
+            Self::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::SrcIpv4(_) => NL80211_WOWLAN_TCP_SRC_IPV4,
+            Self::DstIpv4(_) => NL80211_WOWLAN_TCP_DST_IPV4,
+            Self::DstMac(_) => NL80211_WOWLAN_TCP_DST_MAC,
+            Self::SrcPort(_) => NL80211_WOWLAN_TCP_SRC_PORT,
+            Self::DstPort(_) => NL80211_WOWLAN_TCP_DST_PORT,
+            Self::DataPayload(_) => NL80211_WOWLAN_TCP_DATA_PAYLOAD,
+            Self::DataPayloadSeq(_) => NL80211_WOWLAN_TCP_DATA_PAYLOAD_SEQ,
+            Self::DataPayloadToken(_) => NL80211_WOWLAN_TCP_DATA_PAYLOAD_TOKEN,
+            Self::DataInterval(_) => NL80211_WOWLAN_TCP_DATA_INTERVAL,
+            Self::WakePayload(_) => NL80211_WOWLAN_TCP_WAKE_PAYLOAD,
+            Self::WakeMask(_) => NL80211_WOWLAN_TCP_WAKE_MASK,
+            Self::Other(attr) => attr.kind(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::SrcPort(d) | Self::DstPort(d) => {
+                NativeEndian::write_u16(buffer, *d)
+            }
+            Self::DataInterval(d) => NativeEndian::write_u32(buffer, *d),
+            Self::SrcIpv4(d) => BigEndian::write_u32(buffer, u32::from(*d)),
+            Self::DstIpv4(d) => BigEndian::write_u32(buffer, u32::from(*d)),
+            Self::DstMac(ref d) => buffer[..ETH_ALEN].copy_from_slice(d),
+            Self::DataPayload(ref d) => buffer[..d.len()].copy_from_slice(d),
+            Self::DataPayloadSeq(ref d) => d.emit(buffer),
+            Self::DataPayloadToken(ref d) => d.emit(buffer),
+            Self::WakePayload(ref d) => buffer[..d.len()].copy_from_slice(d),
+            Self::WakeMask(ref d) => buffer[..d.len()].copy_from_slice(d),
+            Self::Other(ref attr) => attr.emit(buffer),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for Nl80211WowlanTcpNla
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            NL80211_WOWLAN_TCP_SRC_IPV4 => {
+                let err_msg = format!(
+                    "Invalid NL80211_WOWLAN_TCP_SRC_IPV4 value {:?}",
+                    payload
+                );
+                Self::SrcIpv4(Ipv4Addr::from(
+                    parse_u32_be(payload).context(err_msg)?,
+                ))
+            }
+            NL80211_WOWLAN_TCP_DST_IPV4 => {
+                let err_msg = format!(
+                    "Invalid NL80211_WOWLAN_TCP_DST_IPV4 value {:?}",
+                    payload
+                );
+                Self::DstIpv4(Ipv4Addr::from(
+                    parse_u32_be(payload).context(err_msg)?,
+                ))
+            }
+            NL80211_WOWLAN_TCP_DST_MAC => {
+                let err_msg = format!(
+                    "Invalid NL80211_WOWLAN_TCP_DST_MAC value {:?}",
+                    payload
+                );
+                Self::DstMac(parse_mac(payload).context(err_msg)?)
+            }
+            NL80211_WOWLAN_TCP_SRC_PORT => {
+                let err_msg = format!(
+                    "Invalid NL80211_WOWLAN_TCP_SRC_PORT value {:?}",
+                    payload
+                );
+                Self::SrcPort(parse_u16(payload).context(err_msg)?)
+            }
+            NL80211_WOWLAN_TCP_DST_PORT => {
+                let err_msg = format!(
+                    "Invalid NL80211_WOWLAN_TCP_DST_PORT value {:?}",
+                    payload
+                );
+                Self::DstPort(parse_u16(payload).context(err_msg)?)
+            }
+            NL80211_WOWLAN_TCP_DATA_PAYLOAD => {
+                Self::DataPayload(payload.to_vec())
+            }
+            NL80211_WOWLAN_TCP_DATA_PAYLOAD_SEQ => {
+                Self::DataPayloadSeq(Nl80211WowlanTcpDataSeq::parse(payload)?)
+            }
+            NL80211_WOWLAN_TCP_DATA_PAYLOAD_TOKEN => Self::DataPayloadToken(
+                Nl80211WowlanTcpDataToken::parse(payload)?,
+            ),
+            NL80211_WOWLAN_TCP_DATA_INTERVAL => {
+                let err_msg = format!(
+                    "Invalid NL80211_WOWLAN_TCP_DATA_INTERVAL value {:?}",
+                    payload
+                );
+                Self::DataInterval(parse_u32(payload).context(err_msg)?)
+            }
+            NL80211_WOWLAN_TCP_WAKE_PAYLOAD => {
+                Self::WakePayload(payload.to_vec())
+            }
+            NL80211_WOWLAN_TCP_WAKE_MASK => Self::WakeMask(payload.to_vec()),
+            _ => Self::Other(
+                DefaultNla::parse(buf).context("invalid NLA (unknown kind)")?,
+            ),
+        })
+    }
+}
+
+/// TCP connection the device maintains while suspended, waking up the host
+/// on receiving a matching packet or on connection loss
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct Nl80211WowlanTcp {
+    /// Source IPv4 address
+    pub src_ipv4: Option<Ipv4Addr>,
+    /// Destination IPv4 address
+    pub dst_ipv4: Option<Ipv4Addr>,
+    /// MAC address of the destination or of the next hop gateway
+    pub dst_mac: Option<[u8; ETH_ALEN]>,
+    /// Source port, chosen by the kernel when not specified
+    pub src_port: Option<u16>,
+    /// Destination port
+    pub dst_port: Option<u16>,
+    /// Payload sent periodically over the connection
+    pub data_payload: Option<Vec<u8>>,
+    /// Sequence number inserted into the data payload
+    pub data_payload_seq: Option<Nl80211WowlanTcpDataSeq>,
+    /// Tokens inserted into the data payload
+    pub data_payload_token: Option<Nl80211WowlanTcpDataToken>,
+    /// Interval in seconds for sending the data payload
+    pub data_interval: Option<u32>,
+    /// Received payload waking up the host
+    pub wake_payload: Option<Vec<u8>>,
+    /// Bitmask of the wake payload bytes to match
+    pub wake_mask: Option<Vec<u8>>,
+}
+
+impl Emitable for Nl80211WowlanTcp {
+    fn buffer_len(&self) -> usize {
+        Vec::<Nl80211WowlanTcpNla>::from(self)
+            .as_slice()
+            .buffer_len()
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        Vec::<Nl80211WowlanTcpNla>::from(self)
+            .as_slice()
+            .emit(buffer)
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for Nl80211WowlanTcp
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let mut ret = Self::default();
+        let payload = buf.value();
+        let err_msg = format!(
+            "Invalid NL80211_WOWLAN_TRIG_TCP_CONNECTION value {:?}",
+            payload
+        );
+        for nla in NlasIterator::new(payload) {
+            let nla = &nla.context(err_msg.clone())?;
+            match Nl80211WowlanTcpNla::parse(nla).context(err_msg.clone())? {
+                Nl80211WowlanTcpNla::SrcIpv4(d) => ret.src_ipv4 = Some(d),
+                Nl80211WowlanTcpNla::DstIpv4(d) => ret.dst_ipv4 = Some(d),
+                Nl80211WowlanTcpNla::DstMac(d) => ret.dst_mac = Some(d),
+                Nl80211WowlanTcpNla::SrcPort(d) => ret.src_port = Some(d),
+                Nl80211WowlanTcpNla::DstPort(d) => ret.dst_port = Some(d),
+                Nl80211WowlanTcpNla::DataPayload(d) => {
+                    ret.data_payload = Some(d)
+                }
+                Nl80211WowlanTcpNla::DataPayloadSeq(d) => {
+                    ret.data_payload_seq = Some(d)
+                }
+                Nl80211WowlanTcpNla::DataPayloadToken(d) => {
+                    ret.data_payload_token = Some(d)
+                }
+                Nl80211WowlanTcpNla::DataInterval(d) => {
+                    ret.data_interval = Some(d)
+                }
+                Nl80211WowlanTcpNla::WakePayload(d) => {
+                    ret.wake_payload = Some(d)
+                }
+                Nl80211WowlanTcpNla::WakeMask(d) => ret.wake_mask = Some(d),
+                Nl80211WowlanTcpNla::Other(attr) => {
+                    log::warn!(
+                        "Got unsupported NL80211_WOWLAN_TRIG_TCP_CONNECTION \
+                         value {:?}",
+                        attr
+                    )
+                }
+            }
+        }
+        Ok(ret)
+    }
+}
+
+impl From<&Nl80211WowlanTcp> for Vec<Nl80211WowlanTcpNla> {
+    fn from(tcp: &Nl80211WowlanTcp) -> Self {
+        let mut nlas = Vec::new();
+        if let Some(v) = tcp.src_ipv4 {
+            nlas.push(Nl80211WowlanTcpNla::SrcIpv4(v));
+        }
+        if let Some(v) = tcp.dst_ipv4 {
+            nlas.push(Nl80211WowlanTcpNla::DstIpv4(v));
+        }
+        if let Some(v) = tcp.dst_mac {
+            nlas.push(Nl80211WowlanTcpNla::DstMac(v));
+        }
+        if let Some(v) = tcp.src_port {
+            nlas.push(Nl80211WowlanTcpNla::SrcPort(v));
+        }
+        if let Some(v) = tcp.dst_port {
+            nlas.push(Nl80211WowlanTcpNla::DstPort(v));
+        }
+        if let Some(v) = tcp.data_payload.as_ref() {
+            nlas.push(Nl80211WowlanTcpNla::DataPayload(v.clone()));
+        }
+        if let Some(v) = tcp.data_payload_seq {
+            nlas.push(Nl80211WowlanTcpNla::DataPayloadSeq(v));
+        }
+        if let Some(v) = tcp.data_payload_token.as_ref() {
+            nlas.push(Nl80211WowlanTcpNla::DataPayloadToken(v.clone()));
+        }
+        if let Some(v) = tcp.data_interval {
+            nlas.push(Nl80211WowlanTcpNla::DataInterval(v));
+        }
+        if let Some(v) = tcp.wake_payload.as_ref() {
+            nlas.push(Nl80211WowlanTcpNla::WakePayload(v.clone()));
+        }
+        if let Some(v) = tcp.wake_mask.as_ref() {
+            nlas.push(Nl80211WowlanTcpNla::WakeMask(v.clone()));
+        }
+        nlas
+    }
+}
@@ -0,0 +1,247 @@
+// SPDX-License-Identifier: MIT
+
+use anyhow::Context;
+use netlink_packet_utils::{
+    nla::{DefaultNla, Nla, NlaBuffer, NlasIterator},
+    DecodeError, Emitable, Parseable,
+};
+
+use crate::{nested::nested_array, Nl80211PacketPattern, Nl80211WowlanTcp};
+
+const NL80211_WOWLAN_TRIG_ANY: u16 = 1;
+const NL80211_WOWLAN_TRIG_DISCONNECT: u16 = 2;
+const NL80211_WOWLAN_TRIG_MAGIC_PKT: u16 = 3;
+const NL80211_WOWLAN_TRIG_PKT_PATTERN: u16 = 4;
+const NL80211_WOWLAN_TRIG_GTK_REKEY_FAILURE: u16 = 6;
+const NL80211_WOWLAN_TRIG_EAP_IDENT_REQUEST: u16 = 7;
+const NL80211_WOWLAN_TRIG_4WAY_HANDSHAKE: u16 = 8;
+const NL80211_WOWLAN_TRIG_RFKILL_RELEASE: u16 = 9;
+const NL80211_WOWLAN_TRIG_TCP_CONNECTION: u16 = 14;
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum Nl80211WowlanTriggerNla {
+    /// Wake up on any activity, only for devices keeping the connection
+    /// while suspended
+    Any,
+    /// Wake up on disconnect
+    Disconnect,
+    /// Wake up on magic packet
+    MagicPkt,
+    /// Wake up on any of the packet patterns
+    PktPattern(Vec<Nl80211PacketPattern>),
+    /// Wake up on GTK rekey failure
+    GtkRekeyFailure,
+    /// Wake up on EAP Identity Request packet
+    EapIdentRequest,
+    /// Wake up on 4-way handshake
+    FourWayHandshake,
+    /// Wake up when rfkill is released
+    RfkillRelease,
+    /// Wake up on TCP connection events
+    TcpConnection(Nl80211WowlanTcp),
+    Other(DefaultNla),
+}
+
+impl Nla for Nl80211WowlanTriggerNla {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::Any
+            | Self::Disconnect
+            | Self::MagicPkt
+            | Self::GtkRekeyFailure
+            | Self::EapIdentRequest
+            | Self::FourWayHandshake
+            | Self::RfkillRelease => 0,
+            Self::PktPattern(ref d) => nested_array(d).as_slice().buffer_len(),
+            Self::TcpConnection(ref d) => d.buffer_len(),
+            Self::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::Any => NL80211_WOWLAN_TRIG_ANY,
+            Self::Disconnect => NL80211_WOWLAN_TRIG_DISCONNECT,
+            Self::MagicPkt => NL80211_WOWLAN_TRIG_MAGIC_PKT,
+            Self::PktPattern(_) => NL80211_WOWLAN_TRIG_PKT_PATTERN,
+            Self::GtkRekeyFailure => NL80211_WOWLAN_TRIG_GTK_REKEY_FAILURE,
+            Self::EapIdentRequest => NL80211_WOWLAN_TRIG_EAP_IDENT_REQUEST,
+            Self::FourWayHandshake => NL80211_WOWLAN_TRIG_4WAY_HANDSHAKE,
+            Self::RfkillRelease => NL80211_WOWLAN_TRIG_RFKILL_RELEASE,
+            Self::TcpConnection(_) => NL80211_WOWLAN_TRIG_TCP_CONNECTION,
+            Self::Other(attr) => attr.kind(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Any
+            | Self::Disconnect
+            | Self::MagicPkt
+            | Self::GtkRekeyFailure
+            | Self::EapIdentRequest
+            | Self::FourWayHandshake
+            | Self::RfkillRelease => (),
+            Self::PktPattern(ref d) => nested_array(d).as_slice().emit(buffer),
+            Self::TcpConnection(ref d) => d.emit(buffer),
+            Self::Other(ref attr) => attr.emit(buffer),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for Nl80211WowlanTriggerNla
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            NL80211_WOWLAN_TRIG_ANY => Self::Any,
+            NL80211_WOWLAN_TRIG_DISCONNECT => Self::Disconnect,
+            NL80211_WOWLAN_TRIG_MAGIC_PKT => Self::MagicPkt,
+            NL80211_WOWLAN_TRIG_PKT_PATTERN => {
+                let err_msg = format!(
+                    "Invalid NL80211_WOWLAN_TRIG_PKT_PATTERN value {:?}",
+                    payload
+                );
+                let mut values = Vec::new();
+                for nla in NlasIterator::new(payload) {
+                    let nla = &nla.context(err_msg.clone())?;
+                    values.push(
+                        Nl80211PacketPattern::parse(nla)
+                            .context(err_msg.clone())?,
+                    );
+                }
+                Self::PktPattern(values)
+            }
+            NL80211_WOWLAN_TRIG_GTK_REKEY_FAILURE => Self::GtkRekeyFailure,
+            NL80211_WOWLAN_TRIG_EAP_IDENT_REQUEST => Self::EapIdentRequest,
+            NL80211_WOWLAN_TRIG_4WAY_HANDSHAKE => Self::FourWayHandshake,
+            NL80211_WOWLAN_TRIG_RFKILL_RELEASE => Self::RfkillRelease,
+            NL80211_WOWLAN_TRIG_TCP_CONNECTION => {
+                Self::TcpConnection(Nl80211WowlanTcp::parse(buf)?)
+            }
+            _ => Self::Other(
+                DefaultNla::parse(buf).context("invalid NLA (unknown kind)")?,
+            ),
+        })
+    }
+}
+
+/// Wake on WLAN triggers
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct Nl80211WowlanTriggers {
+    /// Wake up on any activity, only for devices keeping the connection
+    /// while suspended
+    pub any: bool,
+    /// Wake up on disconnect
+    pub disconnect: bool,
+    /// Wake up on magic packet
+    pub magic_pkt: bool,
+    /// Wake up on any of the packet patterns
+    pub pkt_pattern: Vec<Nl80211PacketPattern>,
+    /// Wake up on GTK rekey failure
+    pub gtk_rekey_failure: bool,
+    /// Wake up on EAP Identity Request packet
+    pub eap_ident_request: bool,
+    /// Wake up on 4-way handshake
+    pub four_way_handshake: bool,
+    /// Wake up when rfkill is released
+    pub rfkill_release: bool,
+    /// Wake up on TCP connection events
+    pub tcp_connection: Option<Nl80211WowlanTcp>,
+}
+
+impl Emitable for Nl80211WowlanTriggers {
+    fn buffer_len(&self) -> usize {
+        Vec::<Nl80211WowlanTriggerNla>::from(self)
+            .as_slice()
+            .buffer_len()
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        Vec::<Nl80211WowlanTriggerNla>::from(self)
+            .as_slice()
+            .emit(buffer)
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for Nl80211WowlanTriggers
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let mut ret = Self::default();
+        let payload = buf.value();
+        let err_msg =
+            format!("Invalid NL80211_ATTR_WOWLAN_TRIGGERS value {:?}", payload);
+        for nla in NlasIterator::new(payload) {
+            let nla = &nla.context(err_msg.clone())?;
+            match Nl80211WowlanTriggerNla::parse(nla)
+                .context(err_msg.clone())?
+            {
+                Nl80211WowlanTriggerNla::Any => ret.any = true,
+                Nl80211WowlanTriggerNla::Disconnect => ret.disconnect = true,
+                Nl80211WowlanTriggerNla::MagicPkt => ret.magic_pkt = true,
+                Nl80211WowlanTriggerNla::PktPattern(v) => ret.pkt_pattern = v,
+                Nl80211WowlanTriggerNla::GtkRekeyFailure => {
+                    ret.gtk_rekey_failure = true
+                }
+                Nl80211WowlanTriggerNla::EapIdentRequest => {
+                    ret.eap_ident_request = true
+                }
+                Nl80211WowlanTriggerNla::FourWayHandshake => {
+                    ret.four_way_handshake = true
+                }
+                Nl80211WowlanTriggerNla::RfkillRelease => {
+                    ret.rfkill_release = true
+                }
+                Nl80211WowlanTriggerNla::TcpConnection(d) => {
+                    ret.tcp_connection = Some(d)
+                }
+                Nl80211WowlanTriggerNla::Other(attr) => {
+                    log::warn!(
+                        "Got unsupported NL80211_ATTR_WOWLAN_TRIGGERS \
+                         value {:?}",
+                        attr
+                    )
+                }
+            }
+        }
+        Ok(ret)
+    }
+}
+
+impl From<&Nl80211WowlanTriggers> for Vec<Nl80211WowlanTriggerNla> {
+    fn from(triggers: &Nl80211WowlanTriggers) -> Self {
+        let mut nlas = Vec::new();
+        if triggers.any {
+            nlas.push(Nl80211WowlanTriggerNla::Any);
+        }
+        if triggers.disconnect {
+            nlas.push(Nl80211WowlanTriggerNla::Disconnect);
+        }
+        if triggers.magic_pkt {
+            nlas.push(Nl80211WowlanTriggerNla::MagicPkt);
+        }
+        if !triggers.pkt_pattern.is_empty() {
+            nlas.push(Nl80211WowlanTriggerNla::PktPattern(
+                triggers.pkt_pattern.clone(),
+            ));
+        }
+        if triggers.gtk_rekey_failure {
+            nlas.push(Nl80211WowlanTriggerNla::GtkRekeyFailure);
+        }
+        if triggers.eap_ident_request {
+            nlas.push(Nl80211WowlanTriggerNla::EapIdentRequest);
+        }
+        if triggers.four_way_handshake {
+            nlas.push(Nl80211WowlanTriggerNla::FourWayHandshake);
+        }
+        if triggers.rfkill_release {
+            nlas.push(Nl80211WowlanTriggerNla::RfkillRelease);
+        }
+        if let Some(v) = triggers.tcp_connection.as_ref() {
+            nlas.push(Nl80211WowlanTriggerNla::TcpConnection(v.clone()));
+        }
+        nlas
+    }
+}
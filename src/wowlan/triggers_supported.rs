@@ -0,0 +1,279 @@
+// SPDX-License-Identifier: MIT
+
+use anyhow::Context;
+use byteorder::{ByteOrder, NativeEndian};
+use netlink_packet_utils::{
+    nla::{DefaultNla, Nla, NlaBuffer, NlasIterator},
+    parsers::parse_u32,
+    DecodeError, Emitable, Parseable,
+};
+
+use crate::Nl80211PatternSupport;
+
+const NL80211_WOWLAN_TRIG_ANY: u16 = 1;
+const NL80211_WOWLAN_TRIG_DISCONNECT: u16 = 2;
+const NL80211_WOWLAN_TRIG_MAGIC_PKT: u16 = 3;
+const NL80211_WOWLAN_TRIG_PKT_PATTERN: u16 = 4;
+const NL80211_WOWLAN_TRIG_GTK_REKEY_SUPPORTED: u16 = 5;
+const NL80211_WOWLAN_TRIG_GTK_REKEY_FAILURE: u16 = 6;
+const NL80211_WOWLAN_TRIG_EAP_IDENT_REQUEST: u16 = 7;
+const NL80211_WOWLAN_TRIG_4WAY_HANDSHAKE: u16 = 8;
+const NL80211_WOWLAN_TRIG_RFKILL_RELEASE: u16 = 9;
+const NL80211_WOWLAN_TRIG_TCP_CONNECTION: u16 = 14;
+const NL80211_WOWLAN_TRIG_NET_DETECT: u16 = 18;
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum Nl80211WowlanTriggersSupportedNla {
+    /// Wake up on any activity
+    Any,
+    /// Wake up on disconnect
+    Disconnect,
+    /// Wake up on magic packet
+    MagicPkt,
+    /// Wake up on packet patterns with these limits
+    PktPattern(Nl80211PatternSupport),
+    /// GTK rekeying is supported by the device during suspend
+    GtkRekeySupported,
+    /// Wake up on GTK rekey failure
+    GtkRekeyFailure,
+    /// Wake up on EAP Identity Request packet
+    EapIdentRequest,
+    /// Wake up on 4-way handshake
+    FourWayHandshake,
+    /// Wake up when rfkill is released
+    RfkillRelease,
+    /// Wake up on TCP connection events
+    TcpConnection,
+    /// Wake up when a configured network is detected, holding the maximum
+    /// number of match sets
+    NetDetect(u32),
+    Other(DefaultNla),
+}
+
+impl Nla for Nl80211WowlanTriggersSupportedNla {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::Any
+            | Self::Disconnect
+            | Self::MagicPkt
+            | Self::GtkRekeySupported
+            | Self::GtkRekeyFailure
+            | Self::EapIdentRequest
+            | Self::FourWayHandshake
+            | Self::RfkillRelease
+            | Self::TcpConnection => 0,
+            Self::NetDetect(_) => 4,
+            Self::PktPattern(ref d) => d.buffer_len(),
+            Self::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::Any => NL80211_WOWLAN_TRIG_ANY,
+            Self::Disconnect => NL80211_WOWLAN_TRIG_DISCONNECT,
+            Self::MagicPkt => NL80211_WOWLAN_TRIG_MAGIC_PKT,
+            Self::PktPattern(_) => NL80211_WOWLAN_TRIG_PKT_PATTERN,
+            Self::GtkRekeySupported => NL80211_WOWLAN_TRIG_GTK_REKEY_SUPPORTED,
+            Self::GtkRekeyFailure => NL80211_WOWLAN_TRIG_GTK_REKEY_FAILURE,
+            Self::EapIdentRequest => NL80211_WOWLAN_TRIG_EAP_IDENT_REQUEST,
+            Self::FourWayHandshake => NL80211_WOWLAN_TRIG_4WAY_HANDSHAKE,
+            Self::RfkillRelease => NL80211_WOWLAN_TRIG_RFKILL_RELEASE,
+            Self::TcpConnection => NL80211_WOWLAN_TRIG_TCP_CONNECTION,
+            Self::NetDetect(_) => NL80211_WOWLAN_TRIG_NET_DETECT,
+            Self::Other(attr) => attr.kind(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Any
+            | Self::Disconnect
+            | Self::MagicPkt
+            | Self::GtkRekeySupported
+            | Self::GtkRekeyFailure
+            | Self::EapIdentRequest
+            | Self::FourWayHandshake
+            | Self::RfkillRelease
+            | Self::TcpConnection => (),
+            Self::NetDetect(d) => NativeEndian::write_u32(buffer, *d),
+            Self::PktPattern(ref d) => d.emit(buffer),
+            Self::Other(ref attr) => attr.emit(buffer),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for Nl80211WowlanTriggersSupportedNla
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            NL80211_WOWLAN_TRIG_ANY => Self::Any,
+            NL80211_WOWLAN_TRIG_DISCONNECT => Self::Disconnect,
+            NL80211_WOWLAN_TRIG_MAGIC_PKT => Self::MagicPkt,
+            NL80211_WOWLAN_TRIG_PKT_PATTERN => {
+                Self::PktPattern(Nl80211PatternSupport::parse(payload)?)
+            }
+            NL80211_WOWLAN_TRIG_GTK_REKEY_SUPPORTED => Self::GtkRekeySupported,
+            NL80211_WOWLAN_TRIG_GTK_REKEY_FAILURE => Self::GtkRekeyFailure,
+            NL80211_WOWLAN_TRIG_EAP_IDENT_REQUEST => Self::EapIdentRequest,
+            NL80211_WOWLAN_TRIG_4WAY_HANDSHAKE => Self::FourWayHandshake,
+            NL80211_WOWLAN_TRIG_RFKILL_RELEASE => Self::RfkillRelease,
+            NL80211_WOWLAN_TRIG_TCP_CONNECTION => Self::TcpConnection,
+            NL80211_WOWLAN_TRIG_NET_DETECT => {
+                let err_msg = format!(
+                    "Invalid NL80211_WOWLAN_TRIG_NET_DETECT value {:?}",
+                    payload
+                );
+                Self::NetDetect(parse_u32(payload).context(err_msg)?)
+            }
+            _ => Self::Other(
+                DefaultNla::parse(buf).context("invalid NLA (unknown kind)")?,
+            ),
+        })
+    }
+}
+
+/// Wake on WLAN triggers supported by the wiphy
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct Nl80211WowlanTriggersSupported {
+    /// Wake up on any activity
+    pub any: bool,
+    /// Wake up on disconnect
+    pub disconnect: bool,
+    /// Wake up on magic packet
+    pub magic_pkt: bool,
+    /// Wake up on packet patterns with these limits
+    pub pkt_pattern: Option<Nl80211PatternSupport>,
+    /// GTK rekeying is supported by the device during suspend
+    pub gtk_rekey_supported: bool,
+    /// Wake up on GTK rekey failure
+    pub gtk_rekey_failure: bool,
+    /// Wake up on EAP Identity Request packet
+    pub eap_ident_request: bool,
+    /// Wake up on 4-way handshake
+    pub four_way_handshake: bool,
+    /// Wake up when rfkill is released
+    pub rfkill_release: bool,
+    /// Wake up on TCP connection events
+    pub tcp_connection: bool,
+    /// Wake up when a configured network is detected, holding the maximum
+    /// number of match sets
+    pub net_detect: Option<u32>,
+}
+
+impl Emitable for Nl80211WowlanTriggersSupported {
+    fn buffer_len(&self) -> usize {
+        Vec::<Nl80211WowlanTriggersSupportedNla>::from(self)
+            .as_slice()
+            .buffer_len()
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        Vec::<Nl80211WowlanTriggersSupportedNla>::from(self)
+            .as_slice()
+            .emit(buffer)
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for Nl80211WowlanTriggersSupported
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let mut ret = Self::default();
+        let payload = buf.value();
+        let err_msg = format!(
+            "Invalid NL80211_ATTR_WOWLAN_TRIGGERS_SUPPORTED value {:?}",
+            payload
+        );
+        for nla in NlasIterator::new(payload) {
+            let nla = &nla.context(err_msg.clone())?;
+            match Nl80211WowlanTriggersSupportedNla::parse(nla)
+                .context(err_msg.clone())?
+            {
+                Nl80211WowlanTriggersSupportedNla::Any => ret.any = true,
+                Nl80211WowlanTriggersSupportedNla::Disconnect => {
+                    ret.disconnect = true
+                }
+                Nl80211WowlanTriggersSupportedNla::MagicPkt => {
+                    ret.magic_pkt = true
+                }
+                Nl80211WowlanTriggersSupportedNla::PktPattern(d) => {
+                    ret.pkt_pattern = Some(d)
+                }
+                Nl80211WowlanTriggersSupportedNla::GtkRekeySupported => {
+                    ret.gtk_rekey_supported = true
+                }
+                Nl80211WowlanTriggersSupportedNla::GtkRekeyFailure => {
+                    ret.gtk_rekey_failure = true
+                }
+                Nl80211WowlanTriggersSupportedNla::EapIdentRequest => {
+                    ret.eap_ident_request = true
+                }
+                Nl80211WowlanTriggersSupportedNla::FourWayHandshake => {
+                    ret.four_way_handshake = true
+                }
+                Nl80211WowlanTriggersSupportedNla::RfkillRelease => {
+                    ret.rfkill_release = true
+                }
+                Nl80211WowlanTriggersSupportedNla::TcpConnection => {
+                    ret.tcp_connection = true
+                }
+                Nl80211WowlanTriggersSupportedNla::NetDetect(d) => {
+                    ret.net_detect = Some(d)
+                }
+                Nl80211WowlanTriggersSupportedNla::Other(attr) => {
+                    log::warn!(
+                        "Got unsupported NL80211_ATTR_WOWLAN_TRIGGERS_SUPPORTED \
+                         value {:?}",
+                        attr
+                    )
+                }
+            }
+        }
+        Ok(ret)
+    }
+}
+
+impl From<&Nl80211WowlanTriggersSupported>
+    for Vec<Nl80211WowlanTriggersSupportedNla>
+{
+    fn from(triggers: &Nl80211WowlanTriggersSupported) -> Self {
+        let mut nlas = Vec::new();
+        if triggers.any {
+            nlas.push(Nl80211WowlanTriggersSupportedNla::Any);
+        }
+        if triggers.disconnect {
+            nlas.push(Nl80211WowlanTriggersSupportedNla::Disconnect);
+        }
+        if triggers.magic_pkt {
+            nlas.push(Nl80211WowlanTriggersSupportedNla::MagicPkt);
+        }
+        if let Some(v) = triggers.pkt_pattern {
+            nlas.push(Nl80211WowlanTriggersSupportedNla::PktPattern(v));
+        }
+        if triggers.gtk_rekey_supported {
+            nlas.push(Nl80211WowlanTriggersSupportedNla::GtkRekeySupported);
+        }
+        if triggers.gtk_rekey_failure {
+            nlas.push(Nl80211WowlanTriggersSupportedNla::GtkRekeyFailure);
+        }
+        if triggers.eap_ident_request {
+            nlas.push(Nl80211WowlanTriggersSupportedNla::EapIdentRequest);
+        }
+        if triggers.four_way_handshake {
+            nlas.push(Nl80211WowlanTriggersSupportedNla::FourWayHandshake);
+        }
+        if triggers.rfkill_release {
+            nlas.push(Nl80211WowlanTriggersSupportedNla::RfkillRelease);
+        }
+        if triggers.tcp_connection {
+            nlas.push(Nl80211WowlanTriggersSupportedNla::TcpConnection);
+        }
+        if let Some(v) = triggers.net_detect {
+            nlas.push(Nl80211WowlanTriggersSupportedNla::NetDetect(v));
+        }
+        nlas
+    }
+}